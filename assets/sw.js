@@ -0,0 +1,60 @@
+// Service worker that makes the app available offline after the first load.
+// Requests are served from the network when possible so that updates are picked up immediately,
+// and from the cache when the network is unreachable.
+
+const CACHE_NAME = "raphael-xiv";
+
+// Trunk appends a content hash to the names of build outputs, e.g. "raphael-xiv-0123456789abcdef_bg.wasm"
+const FILE_HASH = /-[0-9a-f]{16}(?=[._])/;
+
+self.addEventListener("install", () => {
+    self.skipWaiting();
+});
+
+self.addEventListener("activate", (event) => {
+    event.waitUntil(self.clients.claim());
+});
+
+// Removes the outputs of previous builds, which would otherwise pile up in the cache after every update
+async function removeOutdated(cache, url) {
+    if (!FILE_HASH.test(url.pathname)) {
+        return;
+    }
+    const unhashed = url.pathname.replace(FILE_HASH, "");
+    for (const request of await cache.keys()) {
+        const pathname = new URL(request.url).pathname;
+        if (pathname !== url.pathname && pathname.replace(FILE_HASH, "") === unhashed) {
+            await cache.delete(request);
+        }
+    }
+}
+
+async function networkFirst(request) {
+    const cache = await caches.open(CACHE_NAME);
+    try {
+        const response = await fetch(request);
+        if (response.ok) {
+            await cache.put(request, response.clone());
+            await removeOutdated(cache, new URL(request.url));
+        }
+        return response;
+    } catch (error) {
+        // Pages are cached by their URL, any of them serves the app when offline
+        const cached =
+            (await cache.match(request)) ||
+            (request.mode === "navigate" && (await cache.match("./", { ignoreSearch: true })));
+        if (cached) {
+            return cached;
+        }
+        throw error;
+    }
+}
+
+self.addEventListener("fetch", (event) => {
+    const url = new URL(event.request.url);
+    // Ads and other third-party requests are left to the browser
+    if (event.request.method !== "GET" || url.origin !== self.location.origin) {
+        return;
+    }
+    event.respondWith(networkFirst(event.request));
+});
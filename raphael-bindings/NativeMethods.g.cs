@@ -78,6 +78,7 @@ namespace Raphael
         QuickInnovation,
         ImmaculateMend,
         TrainedPerfection,
+        CarefulObservation,
     }
 
     public enum LevelFilter : byte
@@ -37,6 +37,7 @@ enum class Action : uint8_t {
   QuickInnovation,
   ImmaculateMend,
   TrainedPerfection,
+  CarefulObservation,
 };
 
 enum class LevelFilter : uint8_t {
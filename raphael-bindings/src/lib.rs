@@ -1,8 +1,8 @@
 use std::sync::{Arc, LazyLock, Mutex};
 
 use log::Log;
-use raphael_sim::{ActionMask, Settings};
-use raphael_solver::{AtomicFlag, MacroSolver, SolverSettings};
+use raphael_sim::{ActionMask, Settings, TraitOverrides};
+use raphael_solver::{AtomicFlag, MacroSolver, SolverProgress, SolverSettings};
 
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
@@ -60,6 +60,7 @@ pub enum Action {
     QuickInnovation,
     ImmaculateMend,
     TrainedPerfection,
+    CarefulObservation,
 }
 
 // This should produce an error if raphael_sim::Action is changed
@@ -97,6 +98,7 @@ impl From<raphael_sim::Action> for Action {
             raphael_sim::Action::QuickInnovation => Self::QuickInnovation,
             raphael_sim::Action::ImmaculateMend => Self::ImmaculateMend,
             raphael_sim::Action::TrainedPerfection => Self::TrainedPerfection,
+            raphael_sim::Action::CarefulObservation => Self::CarefulObservation,
         }
     }
 }
@@ -138,8 +140,9 @@ impl From<SolveArgs> for SolverSettings {
             allowed_actions: ActionMask::from_bits(value.action_mask),
             adversarial: value.adversarial,
             backload_progress: value.backload_progress,
+            traits: TraitOverrides::none(),
         };
-        Self { simulator_settings }
+        Self::new(simulator_settings)
     }
 }
 
@@ -211,7 +214,7 @@ pub extern "C" fn solve(args: &SolveArgs) {
             (args.on_start)(flag.as_ptr());
 
             let settings = SolverSettings::from(*args);
-            let solution_callback: Box<dyn Fn(&[raphael_sim::Action])> =
+            let solution_callback: Box<dyn Fn(&[raphael_sim::Action]) + Send + Sync> =
                 if let Some(cb) = args.on_suggest_solution {
                     Box::new(move |actions| {
                         cb(actions.as_ptr() as *const Action, actions.len());
@@ -219,13 +222,14 @@ pub extern "C" fn solve(args: &SolveArgs) {
                 } else {
                     Box::new(|_| {})
                 };
-            let progress_callback: Box<dyn Fn(usize)> = if let Some(cb) = args.on_progress {
-                Box::new(move |progress| {
-                    cb(progress);
-                })
-            } else {
-                Box::new(|_| {})
-            };
+            let progress_callback: Box<dyn Fn(SolverProgress) + Send + Sync> =
+                if let Some(cb) = args.on_progress {
+                    Box::new(move |progress| {
+                        cb(progress.nodes_visited);
+                    })
+                } else {
+                    Box::new(|_| {})
+                };
 
             let mut solver =
                 MacroSolver::new(settings, solution_callback, progress_callback, flag.clone());
@@ -0,0 +1,265 @@
+use clap::Args;
+use raphael_sim::{ActionMask, Settings, SimulationState, TraitOverrides};
+use raphael_solver::{AtomicFlag, MacroSolver, SolverSettings};
+use serde::{Deserialize, Serialize};
+
+#[derive(Args, Debug)]
+pub struct BenchArgs {
+    /// Results of an earlier run saved with '--save', e.g. of another solver build. The change of every scenario relative to it is printed
+    #[arg(long, value_name = "PATH")]
+    pub baseline: Option<std::path::PathBuf>,
+
+    /// Save the results as JSON, to be used as the baseline of a later run
+    #[arg(long, value_name = "PATH")]
+    pub save: Option<std::path::PathBuf>,
+
+    /// Only run the scenarios whose name contains this text
+    #[arg(long)]
+    pub filter: Option<String>,
+
+    /// Maximum number of threads available to the solver
+    #[arg(long)]
+    pub threads: Option<usize>,
+}
+
+struct Scenario {
+    name: &'static str,
+    settings: Settings,
+}
+
+const fn regular_settings(
+    max_cp: u16,
+    max_durability: u16,
+    max_progress: u16,
+    max_quality: u16,
+    base_progress: u16,
+    base_quality: u16,
+    job_level: u8,
+) -> Settings {
+    Settings {
+        max_cp,
+        max_durability,
+        max_progress,
+        max_quality,
+        base_progress,
+        base_quality,
+        job_level,
+        allowed_actions: ActionMask::regular(),
+        adversarial: false,
+        backload_progress: false,
+        traits: TraitOverrides::none(),
+    }
+}
+
+/// Recipes and stats of the exhaustive solver tests, plus some variants that exercise other parts of the solver
+const SCENARIOS: &[Scenario] = &[
+    Scenario {
+        name: "rinascita_3700_3280",
+        settings: regular_settings(680, 70, 5060, 12628, 229, 224, 90),
+    },
+    Scenario {
+        name: "pactmaker_3240_3130",
+        settings: regular_settings(600, 70, 4300, 12800, 200, 215, 90),
+    },
+    Scenario {
+        name: "diadochos_4021_3660",
+        settings: regular_settings(640, 70, 6600, 14040, 249, 247, 90),
+    },
+    Scenario {
+        name: "rarefied_tacos_de_carne_asada_4785_4758",
+        settings: regular_settings(646, 80, 6600, 12000, 256, 265, 100),
+    },
+    Scenario {
+        name: "stuffed_peppers_2",
+        settings: regular_settings(646, 80, 6300, 11400, 289, 360, 100),
+    },
+    Scenario {
+        name: "claro_walnut_lumber_4900_4800",
+        settings: regular_settings(620, 40, 3000, 11000, 300, 368, 100),
+    },
+    Scenario {
+        name: "rarefied_tacos_de_carne_asada_4785_4758_adversarial",
+        settings: Settings {
+            adversarial: true,
+            ..regular_settings(646, 80, 6600, 12000, 256, 265, 100)
+        },
+    },
+    Scenario {
+        name: "stuffed_peppers_2_backload_progress",
+        settings: Settings {
+            backload_progress: true,
+            ..regular_settings(646, 80, 6300, 11400, 289, 360, 100)
+        },
+    },
+];
+
+/// Measurements of a single scenario.
+/// The solver tables are counted instead of measuring memory, since they make up nearly all of it and don't depend on the allocator.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ScenarioResult {
+    name: String,
+    seconds: f64,
+    /// Number of states in the solver tables
+    states: usize,
+    /// Number of Pareto values stored for these states
+    pareto_values: usize,
+    /// Capped Quality of the rotation, `None` if there was no solution
+    quality: Option<u32>,
+    steps: Option<usize>,
+}
+
+fn run_scenario(scenario: &Scenario) -> ScenarioResult {
+    let solver_settings = SolverSettings::new(scenario.settings);
+    let mut solver = MacroSolver::new(
+        solver_settings,
+        Box::new(|_| {}),
+        Box::new(|_| {}),
+        AtomicFlag::new(),
+    );
+    let timer = std::time::Instant::now();
+    let actions = solver.solve().ok();
+    let seconds = timer.elapsed().as_secs_f64();
+    let stats = solver.runtime_stats();
+    let quality = actions.as_ref().map(|actions| {
+        let final_state =
+            SimulationState::from_macro(&scenario.settings, actions).expect("Invalid rotation");
+        std::cmp::min(final_state.quality, solver_settings.max_quality())
+    });
+    ScenarioResult {
+        name: scenario.name.to_owned(),
+        seconds,
+        states: stats.finish_states
+            + stats.quality_ub_stats.parallel_states
+            + stats.quality_ub_stats.sequential_states
+            + stats.step_lb_stats.parallel_states,
+        pareto_values: stats.quality_ub_stats.pareto_values + stats.step_lb_stats.pareto_values,
+        quality,
+        steps: actions.map(|actions| actions.len()),
+    }
+}
+
+/// Relative change in percent, e.g. "+12.3%"
+fn relative_change(baseline: f64, current: f64) -> String {
+    match baseline == 0.0 {
+        true => "-".to_owned(),
+        false => format!("{:+.1}%", (current - baseline) / baseline * 100.0),
+    }
+}
+
+fn optional_change(baseline: Option<i64>, current: Option<i64>) -> String {
+    match (baseline, current) {
+        (Some(baseline), Some(current)) => format!("{:+}", current - baseline),
+        (None, None) => "-".to_owned(),
+        _ => "changed".to_owned(),
+    }
+}
+
+fn optional_value<T: std::fmt::Display>(value: Option<T>) -> String {
+    value.map_or_else(|| "none".to_owned(), |value| value.to_string())
+}
+
+fn print_result(result: &ScenarioResult, baseline: Option<&ScenarioResult>, comparing: bool) {
+    match comparing && baseline.is_none() {
+        true => println!("{} (not in baseline)", result.name),
+        false => println!("{}", result.name),
+    }
+    let print_row = |label: &str, value: String, change: Option<String>| match change {
+        Some(change) => println!("  {label:<14} {value:>10} {change}"),
+        None => println!("  {label:<14} {value:>10}"),
+    };
+    print_row(
+        "time:",
+        format!("{:.3}s", result.seconds),
+        baseline.map(|baseline| relative_change(baseline.seconds, result.seconds)),
+    );
+    print_row(
+        "states:",
+        result.states.to_string(),
+        baseline.map(|baseline| relative_change(baseline.states as f64, result.states as f64)),
+    );
+    print_row(
+        "pareto values:",
+        result.pareto_values.to_string(),
+        baseline.map(|baseline| {
+            relative_change(baseline.pareto_values as f64, result.pareto_values as f64)
+        }),
+    );
+    print_row(
+        "quality:",
+        optional_value(result.quality),
+        baseline.map(|baseline| {
+            optional_change(
+                baseline.quality.map(i64::from),
+                result.quality.map(i64::from),
+            )
+        }),
+    );
+    print_row(
+        "steps:",
+        optional_value(result.steps),
+        baseline.map(|baseline| {
+            optional_change(
+                baseline.steps.map(|steps| steps as i64),
+                result.steps.map(|steps| steps as i64),
+            )
+        }),
+    );
+}
+
+pub fn execute(args: &BenchArgs) {
+    if let Some(threads) = args.threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .unwrap();
+    }
+
+    let baseline: Vec<ScenarioResult> = match &args.baseline {
+        Some(path) => {
+            let json = std::fs::read_to_string(path)
+                .unwrap_or_else(|error| panic!("Unable to read {}: {error}", path.display()));
+            serde_json::from_str(&json)
+                .unwrap_or_else(|error| panic!("Unable to parse baseline: {error}"))
+        }
+        None => Vec::new(),
+    };
+
+    let mut results = Vec::new();
+    // total time of the scenarios that are in the baseline, in the baseline and in this run
+    let mut compared_seconds = (0.0, 0.0);
+    let mut compared_scenarios = 0;
+    for scenario in SCENARIOS {
+        if args
+            .filter
+            .as_ref()
+            .is_some_and(|filter| !scenario.name.contains(filter.as_str()))
+        {
+            continue;
+        }
+        let result = run_scenario(scenario);
+        let baseline_result = baseline
+            .iter()
+            .find(|baseline| baseline.name == result.name);
+        print_result(&result, baseline_result, args.baseline.is_some());
+        if let Some(baseline_result) = baseline_result {
+            compared_seconds.0 += baseline_result.seconds;
+            compared_seconds.1 += result.seconds;
+            compared_scenarios += 1;
+        }
+        results.push(result);
+    }
+
+    if args.baseline.is_some() {
+        println!(
+            "\nTotal time of the {compared_scenarios} scenarios in the baseline: {:.3}s {}",
+            compared_seconds.1,
+            relative_change(compared_seconds.0, compared_seconds.1)
+        );
+    }
+
+    if let Some(path) = &args.save {
+        let json = serde_json::to_string_pretty(&results).unwrap();
+        std::fs::write(path, json)
+            .unwrap_or_else(|error| panic!("Unable to write {}: {error}", path.display()));
+    }
+}
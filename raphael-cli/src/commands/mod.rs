@@ -1,2 +1,4 @@
+pub mod bench;
+pub mod replay;
 pub mod search;
 pub mod solve;
@@ -0,0 +1,259 @@
+use clap::Args;
+use raphael_data::{CrafterStats, RECIPES, get_game_settings};
+use raphael_sim::{ACTION_DATA, Action, Condition, Settings, SimulationState, Synthesis};
+
+#[derive(Args, Debug)]
+pub struct ReplayArgs {
+    /// Craft log to replay. Each craft starts with a line 'craft,<RECIPE_ID>,<CRAFTSMANSHIP>,<CONTROL>,<CP>,<LEVEL>[,<INITIAL_QUALITY>]', where the stats include food and potion,
+    /// followed by one line per step in the format '<ACTION>,<CONDITION>,<PROGRESS>,<QUALITY>,<DURABILITY>,<CP>' with the condition the action was used in and the values shown after it.
+    /// Action and condition names are matched ignoring case, spaces and punctuation, e.g. 'Waste Not II' and 'waste-not2' are both accepted. Empty lines and lines starting with '#' are ignored.
+    /// Careful Observation is accepted as well, it leaves the values unchanged and can be used up to 3 times per craft
+    pub log: std::path::PathBuf,
+
+    /// Keep replaying the steps of a craft after the first divergence instead of skipping to the next craft
+    #[arg(long, default_value_t = false)]
+    pub continue_on_divergence: bool,
+}
+
+#[derive(Debug)]
+struct LoggedCraft {
+    line: usize,
+    recipe_id: u32,
+    crafter_stats: CrafterStats,
+    initial_quality: u16,
+    steps: Vec<LoggedStep>,
+}
+
+#[derive(Debug)]
+struct LoggedStep {
+    line: usize,
+    action: Action,
+    condition: Option<Condition>,
+    values: StepValues,
+}
+
+/// Values shown in the craft window after a step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct StepValues {
+    progress: u32,
+    quality: u32,
+    durability: u16,
+    cp: u16,
+}
+
+impl std::fmt::Display for StepValues {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "progress {}, quality {}, durability {}, cp {}",
+            self.progress, self.quality, self.durability, self.cp
+        )
+    }
+}
+
+enum ReplayOutcome {
+    Matched,
+    Diverged,
+    /// The craft can't be replayed, e.g. because it uses conditions the simulator doesn't model
+    Skipped(String),
+}
+
+/// Lowercase name without spaces and punctuation, with a trailing Roman numeral II written as 2
+fn normalize_name(name: &str) -> String {
+    let normalized: String = name
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    match normalized.strip_suffix("ii") {
+        Some(prefix) => format!("{prefix}2"),
+        None => normalized,
+    }
+}
+
+fn parse_action(name: &str) -> Result<Action, String> {
+    let normalized = normalize_name(name);
+    ACTION_DATA
+        .iter()
+        .map(|action_data| action_data.action)
+        .find(|action| normalize_name(&format!("{action:?}")) == normalized)
+        .ok_or_else(|| format!("unknown action '{name}'"))
+}
+
+/// Good Omen is not simulated and parses to `None`
+fn parse_condition(name: &str) -> Result<Option<Condition>, String> {
+    match normalize_name(name).as_str() {
+        "normal" => Ok(Some(Condition::Normal)),
+        "good" => Ok(Some(Condition::Good)),
+        "excellent" => Ok(Some(Condition::Excellent)),
+        "poor" => Ok(Some(Condition::Poor)),
+        "centered" => Ok(Some(Condition::Centered)),
+        "sturdy" => Ok(Some(Condition::Sturdy)),
+        "pliant" => Ok(Some(Condition::Pliant)),
+        "malleable" => Ok(Some(Condition::Malleable)),
+        "primed" => Ok(Some(Condition::Primed)),
+        "goodomen" => Ok(None),
+        _ => Err(format!("unknown condition '{name}'")),
+    }
+}
+
+fn parse_field<T: std::str::FromStr>(
+    fields: &[&str],
+    index: usize,
+    name: &str,
+) -> Result<T, String> {
+    let field = fields.get(index).ok_or_else(|| format!("missing {name}"))?;
+    field
+        .parse()
+        .map_err(|_| format!("{name} '{field}' is not a number"))
+}
+
+fn parse_line(crafts: &mut Vec<LoggedCraft>, fields: &[&str], line: usize) -> Result<(), String> {
+    if fields[0].eq_ignore_ascii_case("craft") {
+        crafts.push(LoggedCraft {
+            line,
+            recipe_id: parse_field(fields, 1, "recipe ID")?,
+            crafter_stats: CrafterStats {
+                craftsmanship: parse_field(fields, 2, "craftsmanship")?,
+                control: parse_field(fields, 3, "control")?,
+                cp: parse_field(fields, 4, "CP")?,
+                level: parse_field(fields, 5, "level")?,
+                // the log shows which actions were used, so all of them are allowed
+                manipulation: true,
+                heart_and_soul: true,
+                quick_innovation: true,
+            },
+            initial_quality: match fields.len() > 6 {
+                true => parse_field(fields, 6, "initial quality")?,
+                false => 0,
+            },
+            steps: Vec::new(),
+        });
+        return Ok(());
+    }
+    let craft = crafts
+        .last_mut()
+        .ok_or("step before the first 'craft' line")?;
+    craft.steps.push(LoggedStep {
+        line,
+        action: parse_action(fields[0])?,
+        condition: parse_condition(fields.get(1).ok_or("missing condition")?)?,
+        values: StepValues {
+            progress: parse_field(fields, 2, "progress")?,
+            quality: parse_field(fields, 3, "quality")?,
+            durability: parse_field(fields, 4, "durability")?,
+            cp: parse_field(fields, 5, "CP")?,
+        },
+    });
+    Ok(())
+}
+
+fn parse_log(log: &str) -> Result<Vec<LoggedCraft>, String> {
+    let mut crafts = Vec::new();
+    for (index, line) in log.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        parse_line(&mut crafts, &fields, index + 1)
+            .map_err(|error| format!("line {}: {error}", index + 1))?;
+    }
+    Ok(crafts)
+}
+
+/// Values the game would show for `state`, which caps Progress and Quality at their max
+fn shown_values(state: &SimulationState, settings: &Settings, initial_quality: u16) -> StepValues {
+    StepValues {
+        progress: std::cmp::min(state.progress, u32::from(settings.max_progress)),
+        quality: std::cmp::min(
+            state.quality + u32::from(initial_quality),
+            u32::from(settings.max_quality),
+        ),
+        durability: state.durability,
+        cp: state.cp,
+    }
+}
+
+fn replay_craft(craft: &LoggedCraft, continue_on_divergence: bool) -> ReplayOutcome {
+    let Some(recipe) = RECIPES.get(&craft.recipe_id) else {
+        return ReplayOutcome::Skipped(format!("unknown recipe ID {}", craft.recipe_id));
+    };
+    if let Some(step) = craft.steps.iter().find(|step| step.condition.is_none()) {
+        return ReplayOutcome::Skipped(format!(
+            "line {}: the Good Omen condition is not simulated",
+            step.line
+        ));
+    }
+    let settings = get_game_settings(*recipe, None, craft.crafter_stats, None, None);
+    let mut synthesis = Synthesis::new(SimulationState::new(&settings));
+    let mut diverged = false;
+    for step in &craft.steps {
+        let condition = step.condition.unwrap_or_default();
+        let action = step.action;
+        synthesis = match synthesis.use_action(action, condition, &settings) {
+            Ok(synthesis) => synthesis,
+            Err(error) => {
+                println!(
+                    "  line {}: {action:?} ({condition:?}) failed in the simulator: {error}",
+                    step.line
+                );
+                return ReplayOutcome::Diverged;
+            }
+        };
+        let state = &mut synthesis.state;
+        let simulated = shown_values(state, &settings, craft.initial_quality);
+        if simulated != step.values {
+            println!("  line {}: {:?} ({condition:?})", step.line, step.action);
+            println!("    logged:    {}", step.values);
+            println!("    simulated: {}", simulated);
+            if !continue_on_divergence {
+                return ReplayOutcome::Diverged;
+            }
+            diverged = true;
+            // continue from the logged values so that later steps are compared on their own
+            state.progress = step.values.progress;
+            state.quality = step
+                .values
+                .quality
+                .saturating_sub(u32::from(craft.initial_quality));
+            state.durability = step.values.durability;
+            state.cp = step.values.cp;
+        }
+    }
+    match diverged {
+        true => ReplayOutcome::Diverged,
+        false => ReplayOutcome::Matched,
+    }
+}
+
+pub fn execute(args: &ReplayArgs) {
+    let log = std::fs::read_to_string(&args.log)
+        .unwrap_or_else(|error| panic!("Unable to read {}: {error}", args.log.display()));
+    let crafts = parse_log(&log).unwrap_or_else(|error| panic!("Unable to parse log: {error}"));
+
+    let (mut matched, mut diverged, mut skipped) = (0, 0, 0);
+    for craft in &crafts {
+        println!(
+            "Craft at line {} (recipe {}, {} steps)",
+            craft.line,
+            craft.recipe_id,
+            craft.steps.len()
+        );
+        match replay_craft(craft, args.continue_on_divergence) {
+            ReplayOutcome::Matched => matched += 1,
+            ReplayOutcome::Diverged => diverged += 1,
+            ReplayOutcome::Skipped(reason) => {
+                println!("  skipped: {reason}");
+                skipped += 1;
+            }
+        }
+    }
+    println!(
+        "\n{} crafts: {matched} matched, {diverged} diverged, {skipped} skipped",
+        crafts.len()
+    );
+    if diverged != 0 {
+        std::process::exit(1);
+    }
+}
@@ -31,6 +31,7 @@ pub enum SearchLanguage {
     FR,
     JP,
     CN,
+    TW,
 }
 
 impl From<SearchLanguage> for Locale {
@@ -41,6 +42,7 @@ impl From<SearchLanguage> for Locale {
             SearchLanguage::FR => Locale::FR,
             SearchLanguage::JP => Locale::JP,
             SearchLanguage::CN => Locale::CN,
+            SearchLanguage::TW => Locale::TW,
         }
     }
 }
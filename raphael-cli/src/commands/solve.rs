@@ -1,10 +1,14 @@
-use clap::Args;
+use clap::error::ErrorKind;
+use clap::{Args, ValueEnum};
 use log::error;
 use raphael_data::{
     CrafterStats, CustomRecipeOverrides, MEALS, POTIONS, RECIPES, get_game_settings,
 };
-use raphael_sim::SimulationState;
-use raphael_solver::{AtomicFlag, MacroSolver, SolverSettings};
+use raphael_sim::{Action, Condition, SimulationState};
+use raphael_solver::{
+    ActionLimits, AtomicFlag, DecisionTree, MacroSolver, OpenerConditionsError, OpenerPreference,
+    SolverSettings, StepBranch,
+};
 
 #[derive(Args, Debug)]
 pub struct SolveArgs {
@@ -24,6 +28,10 @@ pub struct SolveArgs {
     #[arg(long, num_args = 3, value_names = ["LEVEL", "BASE_PROGRESS", "BASE_QUALITY"], requires = "custom_recipe")]
     pub override_base_increases: Vec<u16>,
 
+    /// Makes the custom recipe level-synced, i.e. its recipe level follows the job level up to this level. RLVL is ignored if this argument is provided
+    #[arg(long, value_name = "LEVEL", requires = "custom_recipe", conflicts_with = "override_base_increases", value_parser = clap::value_parser!(u8).range(1..=100))]
+    pub max_level_scaling: Option<u8>,
+
     /// Craftsmanship rating
     #[arg(short, long, requires_all(["control", "cp"]), required_unless_present = "stats")]
     pub craftsmanship: Option<u16>,
@@ -64,6 +72,10 @@ pub struct SolveArgs {
     #[arg(long, default_value_t = false)]
     pub quick_innovation: bool,
 
+    /// Exclude Trained Eye even when the job level allows it
+    #[arg(long, default_value_t = false)]
+    pub no_trained_eye: bool,
+
     /// Set initial quality, value is clamped to 100% quality
     #[arg(long, alias = "initial")]
     pub initial_quality: Option<u16>,
@@ -88,10 +100,54 @@ pub struct SolveArgs {
     #[arg(long, default_value_t = false)]
     pub backload_progress: bool,
 
+    /// Prefer backloading Progress, but only as long as it doesn't cost any Quality
+    #[arg(long, default_value_t = false, conflicts_with = "backload_progress")]
+    pub prefer_backload_progress: bool,
+
+    /// Start the macro with this action
+    #[arg(long, value_enum, ignore_case = true, conflicts_with = "forbid_opener")]
+    pub opener: Option<OpenerArg>,
+
+    /// Don't start the macro with this action
+    #[arg(long, value_enum, ignore_case = true)]
+    pub forbid_opener: Option<OpenerArg>,
+
+    /// Assume this condition for the first step of the macro instead of Normal.
+    /// Must be one of the conditions the recipe can roll
+    #[arg(long, value_enum, ignore_case = true)]
+    pub first_step_condition: Option<ConditionArg>,
+
+    /// Assume this condition for the second step of the macro instead of Normal.
+    /// Must be one of the conditions the recipe can roll
+    #[arg(long, value_enum, ignore_case = true)]
+    pub second_step_condition: Option<ConditionArg>,
+
+    /// Print a decision tree that picks the actions by the condition of their step instead of a single macro.
+    /// The first step is always Normal, and steps after the ones that are branched on are assumed to be Normal, since conditions further ahead are not modeled
+    #[arg(long, default_value_t = false, conflicts_with_all(["first_step_condition", "second_step_condition", "output_variables"]))]
+    pub decision_tree: bool,
+
+    /// Number of steps after the first whose condition the decision tree branches on.
+    /// The number of solves grows exponentially with it
+    #[arg(long, default_value_t = 2, requires = "decision_tree")]
+    pub decision_tree_depth: usize,
+
+    /// Only use Progress-increasing actions while Durability is at least this high
+    #[arg(long, default_value_t = 0)]
+    pub min_synthesis_durability: u16,
+
+    /// Use an action at most this many times, in the format '<ACTION>=<LIMIT>', e.g. 'observe=1'
+    #[arg(long, value_name = "ACTION=LIMIT", value_parser = parse_action_limit)]
+    pub action_limit: Vec<(ActionArg, u8)>,
+
     /// Maximum number of threads available to the solver
     #[arg(long)]
     pub threads: Option<usize>,
 
+    /// Give up the search after this many seconds
+    #[arg(long, value_name = "SECONDS")]
+    pub time_limit: Option<u64>,
+
     /// Output the provided list of variables. The output is deliminated by the output-field-separator
     ///
     /// <IDENTIFIER> can be any of the following: `recipe_id`, `item_id`, `recipe`, `food`, `potion`, `craftsmanship`, `control`, `cp`, `crafter_stats`, `settings`, `initial_quality`, `target_quality`, `recipe_max_quality`, `actions`, `final_state`, `state_quality`, `final_quality`, `steps`, `duration`.
@@ -104,6 +160,134 @@ pub struct SolveArgs {
     output_field_separator: String,
 }
 
+#[derive(Copy, Clone, ValueEnum, Debug)]
+pub enum OpenerArg {
+    Reflect,
+    MuscleMemory,
+    TrainedEye,
+}
+
+impl From<OpenerArg> for Action {
+    fn from(val: OpenerArg) -> Self {
+        match val {
+            OpenerArg::Reflect => Self::Reflect,
+            OpenerArg::MuscleMemory => Self::MuscleMemory,
+            OpenerArg::TrainedEye => Self::TrainedEye,
+        }
+    }
+}
+
+#[derive(Copy, Clone, ValueEnum, Debug)]
+pub enum ConditionArg {
+    Normal,
+    Good,
+    Excellent,
+    Poor,
+    Centered,
+    Sturdy,
+    Pliant,
+    Malleable,
+    Primed,
+}
+
+impl From<ConditionArg> for Condition {
+    fn from(val: ConditionArg) -> Self {
+        match val {
+            ConditionArg::Normal => Self::Normal,
+            ConditionArg::Good => Self::Good,
+            ConditionArg::Excellent => Self::Excellent,
+            ConditionArg::Poor => Self::Poor,
+            ConditionArg::Centered => Self::Centered,
+            ConditionArg::Sturdy => Self::Sturdy,
+            ConditionArg::Pliant => Self::Pliant,
+            ConditionArg::Malleable => Self::Malleable,
+            ConditionArg::Primed => Self::Primed,
+        }
+    }
+}
+
+#[derive(Copy, Clone, ValueEnum, Debug)]
+pub enum ActionArg {
+    BasicSynthesis,
+    BasicTouch,
+    MasterMend,
+    Observe,
+    TricksOfTheTrade,
+    WasteNot,
+    Veneration,
+    StandardTouch,
+    GreatStrides,
+    Innovation,
+    WasteNot2,
+    ByregotsBlessing,
+    PreciseTouch,
+    MuscleMemory,
+    CarefulSynthesis,
+    Manipulation,
+    PrudentTouch,
+    AdvancedTouch,
+    Reflect,
+    PreparatoryTouch,
+    Groundwork,
+    DelicateSynthesis,
+    IntensiveSynthesis,
+    TrainedEye,
+    HeartAndSoul,
+    PrudentSynthesis,
+    TrainedFinesse,
+    RefinedTouch,
+    QuickInnovation,
+    ImmaculateMend,
+    TrainedPerfection,
+}
+
+impl From<ActionArg> for Action {
+    fn from(val: ActionArg) -> Self {
+        match val {
+            ActionArg::BasicSynthesis => Self::BasicSynthesis,
+            ActionArg::BasicTouch => Self::BasicTouch,
+            ActionArg::MasterMend => Self::MasterMend,
+            ActionArg::Observe => Self::Observe,
+            ActionArg::TricksOfTheTrade => Self::TricksOfTheTrade,
+            ActionArg::WasteNot => Self::WasteNot,
+            ActionArg::Veneration => Self::Veneration,
+            ActionArg::StandardTouch => Self::StandardTouch,
+            ActionArg::GreatStrides => Self::GreatStrides,
+            ActionArg::Innovation => Self::Innovation,
+            ActionArg::WasteNot2 => Self::WasteNot2,
+            ActionArg::ByregotsBlessing => Self::ByregotsBlessing,
+            ActionArg::PreciseTouch => Self::PreciseTouch,
+            ActionArg::MuscleMemory => Self::MuscleMemory,
+            ActionArg::CarefulSynthesis => Self::CarefulSynthesis,
+            ActionArg::Manipulation => Self::Manipulation,
+            ActionArg::PrudentTouch => Self::PrudentTouch,
+            ActionArg::AdvancedTouch => Self::AdvancedTouch,
+            ActionArg::Reflect => Self::Reflect,
+            ActionArg::PreparatoryTouch => Self::PreparatoryTouch,
+            ActionArg::Groundwork => Self::Groundwork,
+            ActionArg::DelicateSynthesis => Self::DelicateSynthesis,
+            ActionArg::IntensiveSynthesis => Self::IntensiveSynthesis,
+            ActionArg::TrainedEye => Self::TrainedEye,
+            ActionArg::HeartAndSoul => Self::HeartAndSoul,
+            ActionArg::PrudentSynthesis => Self::PrudentSynthesis,
+            ActionArg::TrainedFinesse => Self::TrainedFinesse,
+            ActionArg::RefinedTouch => Self::RefinedTouch,
+            ActionArg::QuickInnovation => Self::QuickInnovation,
+            ActionArg::ImmaculateMend => Self::ImmaculateMend,
+            ActionArg::TrainedPerfection => Self::TrainedPerfection,
+        }
+    }
+}
+
+fn parse_action_limit(s: &str) -> Result<(ActionArg, u8), String> {
+    const PARSE_ERROR_STRING: &str =
+        "Action limit is not parsable. Action limits must have the format '<ACTION>=<LIMIT>'";
+    let (action, limit) = s.split_once('=').ok_or(PARSE_ERROR_STRING)?;
+    let action = ActionArg::from_str(action, true)?;
+    let limit = limit.parse().map_err(|_| PARSE_ERROR_STRING.to_owned())?;
+    Ok((action, limit))
+}
+
 fn parse_consumable(s: &str) -> Result<ConsumableArg, String> {
     const PARSE_ERROR_STRING: &str =
         "Consumable is not parsable. Consumables must have the format '<ITEM_ID>[,HQ]'";
@@ -157,6 +341,84 @@ fn map_and_clamp_hq_ingredients(recipe: &raphael_data::Recipe, hq_ingredients: [
     modified_hq_ingredients
 }
 
+fn new_solver(solver_settings: SolverSettings, time_limit: Option<u64>) -> MacroSolver<'static> {
+    let mut solver = MacroSolver::new(
+        solver_settings,
+        Box::new(|_| {}),
+        Box::new(|_| {}),
+        AtomicFlag::new(),
+    );
+    solver.set_time_limit(time_limit.map(std::time::Duration::from_secs));
+    solver
+}
+
+fn format_conditions(conditions: &[Condition]) -> String {
+    conditions
+        .iter()
+        .map(|condition| format!("{condition:?}"))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn print_branches(
+    branches: &[StepBranch],
+    step: usize,
+    initial_quality: u16,
+    recipe_max_quality: u16,
+) {
+    let indent = "  ".repeat(step - 1);
+    for branch in branches {
+        let label = if branch.is_default && branches.len() > 1 {
+            "otherwise".to_owned()
+        } else {
+            format!("on {}", format_conditions(&branch.conditions))
+        };
+        if branch.next_steps.is_empty() {
+            let actions: Vec<String> = branch
+                .actions
+                .iter()
+                .map(|action| format!("{action:?}"))
+                .collect();
+            println!(
+                "{indent}Step {step} {label}: {} (Quality {}/{})",
+                actions.join(", "),
+                branch.quality + u32::from(initial_quality),
+                recipe_max_quality
+            );
+        } else {
+            println!("{indent}Step {step} {label}: {:?}", branch.actions[0]);
+            print_branches(
+                &branch.next_steps,
+                step + 1,
+                initial_quality,
+                recipe_max_quality,
+            );
+        }
+    }
+}
+
+fn print_decision_tree(
+    tree: &DecisionTree,
+    depth: usize,
+    initial_quality: u16,
+    recipe_max_quality: u16,
+) {
+    println!(
+        "Decision tree, all steps after step {} are assumed to be Normal:",
+        depth + 1
+    );
+    println!(
+        "\nStep 1 on {:?}: {:?}",
+        tree.first_step.condition, tree.first_step.action
+    );
+    print_branches(
+        &tree.first_step.next_steps,
+        2,
+        initial_quality,
+        recipe_max_quality,
+    );
+}
+
 pub fn execute(args: &SolveArgs) {
     if args.recipe_id.is_none() && args.item_id.is_none() && args.custom_recipe.is_empty() {
         error!(
@@ -177,7 +439,7 @@ pub fn execute(args: &SolveArgs) {
         raphael_data::Recipe {
             job_id: 0,
             item_id: 0,
-            max_level_scaling: 0,
+            max_level_scaling: args.max_level_scaling.unwrap_or(0),
             recipe_level: args.custom_recipe[0],
             progress_factor: 0,
             quality_factor: 0,
@@ -305,12 +567,16 @@ pub fn execute(args: &SolveArgs) {
             max_durability_override: args.custom_recipe[3],
             base_progress_override: Some(args.override_base_increases[1]),
             base_quality_override: Some(args.override_base_increases[2]),
+            ..Default::default()
         })
     };
     let mut settings =
         get_game_settings(recipe, custom_recipe_overrides, crafter_stats, food, potion);
     settings.adversarial = args.adversarial;
     settings.backload_progress = args.backload_progress;
+    if args.no_trained_eye {
+        settings.allowed_actions = settings.allowed_actions.remove(Action::TrainedEye);
+    }
 
     let target_quality = match args.target_quality {
         Some(target) => target.clamp(0, settings.max_quality),
@@ -337,19 +603,93 @@ pub fn execute(args: &SolveArgs) {
     let recipe_max_quality = settings.max_quality;
     settings.max_quality = target_quality.saturating_sub(initial_quality);
 
+    let conditions = raphael_data::modeled_conditions(recipe);
+    for (arg, condition) in [
+        ("--first-step-condition", args.first_step_condition),
+        ("--second-step-condition", args.second_step_condition),
+    ] {
+        if let Some(condition) = condition.map(Condition::from)
+            && !conditions.contains(&condition)
+        {
+            clap::Error::raw(
+                ErrorKind::InvalidValue,
+                format!(
+                    "invalid value for '{arg}': the recipe can't roll the condition {condition:?}\n"
+                ),
+            )
+            .exit();
+        }
+    }
+
     let solver_settings = SolverSettings {
-        simulator_settings: settings,
+        prefer_backload_progress: args.prefer_backload_progress,
+        opener: match (args.opener, args.forbid_opener) {
+            (Some(action), _) => OpenerPreference::Force(action.into()),
+            (None, Some(action)) => OpenerPreference::Forbid(action.into()),
+            (None, None) => OpenerPreference::Any,
+        },
+        min_synthesis_durability: args.min_synthesis_durability,
+        action_limits: args
+            .action_limit
+            .iter()
+            .fold(ActionLimits::unlimited(), |limits, &(action, limit)| {
+                limits.with_limit(action.into(), limit)
+            }),
+        ..SolverSettings::new(settings)
+    };
+    let opener_conditions = [args.first_step_condition, args.second_step_condition]
+        .map(|condition| condition.map_or(Condition::Normal, Condition::from));
+    let solver_settings = match solver_settings
+        .with_opener_conditions(opener_conditions, recipe.is_expert)
+    {
+        Ok(solver_settings) => solver_settings,
+        Err(OpenerConditionsError::ImpossibleSequence { .. }) => clap::Error::raw(
+            ErrorKind::ArgumentConflict,
+            "the step conditions can't be rolled in a row: Excellent is always followed by Poor, Poor only follows Excellent and the steps after the second step are Normal\n",
+        )
+        .exit(),
+        Err(OpenerConditionsError::UnavailableCondition(condition)) => clap::Error::raw(
+            ErrorKind::InvalidValue,
+            format!("the recipe can't roll the condition {condition:?}\n"),
+        )
+        .exit(),
     };
 
-    let mut solver = MacroSolver::new(
-        solver_settings,
-        Box::new(|_| {}),
-        Box::new(|_| {}),
-        AtomicFlag::new(),
+    if args.decision_tree {
+        // consecutive solves in the same conditions share the precomputed tables
+        let mut cached_solver: Option<MacroSolver> = None;
+        let tree = DecisionTree::build(
+            solver_settings,
+            SimulationState::new(&settings),
+            &conditions,
+            args.decision_tree_depth,
+            |solver_settings, state| {
+                let solver = match cached_solver.take() {
+                    Some(solver) if *solver.settings() == solver_settings => solver,
+                    _ => new_solver(solver_settings, args.time_limit),
+                };
+                cached_solver.insert(solver).solve_from_in_conditions(state)
+            },
+        )
+        .expect("Failed to solve");
+        print_decision_tree(
+            &tree,
+            args.decision_tree_depth,
+            initial_quality,
+            recipe_max_quality,
+        );
+        return;
+    }
+    let actions = new_solver(solver_settings, args.time_limit)
+        .solve()
+        .expect("Failed to solve");
+
+    let (final_state, errors) = SimulationState::from_macro_in_conditions(
+        &settings,
+        &actions,
+        &solver_settings.opener_conditions,
     );
-    let actions = solver.solve().expect("Failed to solve");
-
-    let final_state = SimulationState::from_macro(&settings, &actions).unwrap();
+    assert!(errors.iter().all(Result::is_ok));
     let state_quality = final_state.quality;
     let final_quality = state_quality + u32::from(initial_quality);
     let steps = actions.len();
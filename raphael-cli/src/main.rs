@@ -18,6 +18,10 @@ enum Commands {
     Search(commands::search::SearchArgs),
     /// Solve a crafting rotation
     Solve(commands::solve::SolveArgs),
+    /// Replay craft logs through the simulator and report where it diverges from the game
+    Replay(commands::replay::ReplayArgs),
+    /// Benchmark the solver on a fixed set of scenarios and compare the results with an earlier run
+    Bench(commands::bench::BenchArgs),
 }
 
 fn main() {
@@ -31,5 +35,7 @@ fn main() {
     match &cli.command {
         Commands::Search(args) => commands::search::execute(args),
         Commands::Solve(args) => commands::solve::execute(args),
+        Commands::Replay(args) => commands::replay::execute(args),
+        Commands::Bench(args) => commands::bench::execute(args),
     }
 }
@@ -0,0 +1,351 @@
+use crate::SheetData;
+
+/// Crafting actions exist once for every crafting job, with identical level requirements and CP costs.
+/// Only the Carpenter rows are used.
+const CARPENTER_CLASS_JOB_ID: i32 = 8;
+
+/// Class job IDs of the crafting jobs, from Carpenter to Culinarian
+pub const CRAFTING_CLASS_JOB_IDS: std::ops::RangeInclusive<i32> = 8..=15;
+
+#[derive(Debug, Clone)]
+pub struct CraftAction {
+    pub id: u32,
+    pub name: String,
+    pub class_job_id: i32,
+    pub level: u32,
+    pub cp_cost: u32,
+    pub icon_id: u32,
+}
+
+impl SheetData for CraftAction {
+    const SHEET: &'static str = "CraftAction";
+    const REQUIRED_FIELDS: &[&str] = &["Name", "ClassJob", "ClassJobLevel", "Cost", "Icon"];
+
+    fn row_id(&self) -> u32 {
+        self.id
+    }
+
+    fn from_json(value: &json::JsonValue) -> Option<Self> {
+        let fields = &value["fields"];
+        Some(Self {
+            id: value["row_id"].as_u32().unwrap(),
+            name: fields["Name"].as_str().unwrap().to_owned(),
+            class_job_id: fields["ClassJob"]["value"].as_i32().unwrap_or(-1),
+            level: fields["ClassJobLevel"].as_u32().unwrap(),
+            cp_cost: fields["Cost"].as_u32().unwrap(),
+            icon_id: fields["Icon"]["id"].as_u32().unwrap(),
+        })
+    }
+}
+
+/// Buff actions (e.g. Veneration, Manipulation) are in the "Action" sheet instead of the "CraftAction" sheet.
+#[derive(Debug, Clone)]
+pub struct BuffAction {
+    pub id: u32,
+    pub name: String,
+    pub class_job_id: i32,
+    pub level: u32,
+    pub cp_cost: u32,
+    pub icon_id: u32,
+}
+
+impl SheetData for BuffAction {
+    const SHEET: &'static str = "Action";
+    const REQUIRED_FIELDS: &[&str] = &[
+        "Name",
+        "ClassJob",
+        "ClassJobLevel",
+        "PrimaryCostValue",
+        "Icon",
+    ];
+
+    fn row_id(&self) -> u32 {
+        self.id
+    }
+
+    fn from_json(value: &json::JsonValue) -> Option<Self> {
+        let fields = &value["fields"];
+        Some(Self {
+            id: value["row_id"].as_u32().unwrap(),
+            name: fields["Name"].as_str().unwrap().to_owned(),
+            class_job_id: fields["ClassJob"]["value"].as_i32().unwrap_or(-1),
+            level: fields["ClassJobLevel"].as_u32().unwrap(),
+            cp_cost: fields["PrimaryCostValue"].as_u32().unwrap(),
+            icon_id: fields["Icon"]["id"].as_u32().unwrap(),
+        })
+    }
+}
+
+/// Durability costs, potencies and combo CP costs are not part of the game sheets, so they are maintained here.
+struct ActionEffect {
+    action: &'static str,
+    name: &'static str,
+    combo_cp_cost: Option<u32>,
+    durability_cost: u32,
+    progress_potency: u32,
+    enhanced_progress_potency: Option<(u32, u32)>,
+    quality_potency: u32,
+}
+
+impl ActionEffect {
+    const fn new(action: &'static str, name: &'static str) -> Self {
+        Self {
+            action,
+            name,
+            combo_cp_cost: None,
+            durability_cost: 0,
+            progress_potency: 0,
+            enhanced_progress_potency: None,
+            quality_potency: 0,
+        }
+    }
+
+    const fn combo_cp(self, combo_cp_cost: u32) -> Self {
+        Self {
+            combo_cp_cost: Some(combo_cp_cost),
+            ..self
+        }
+    }
+
+    const fn durability(self, durability_cost: u32) -> Self {
+        Self {
+            durability_cost,
+            ..self
+        }
+    }
+
+    const fn progress(self, progress_potency: u32) -> Self {
+        Self {
+            progress_potency,
+            ..self
+        }
+    }
+
+    const fn enhanced_progress(self, trait_level: u32, progress_potency: u32) -> Self {
+        Self {
+            enhanced_progress_potency: Some((trait_level, progress_potency)),
+            ..self
+        }
+    }
+
+    const fn quality(self, quality_potency: u32) -> Self {
+        Self {
+            quality_potency,
+            ..self
+        }
+    }
+}
+
+/// `action` is the name of the `raphael_sim::Action` variant, `name` is the English name used in the game sheets.
+const ACTION_EFFECTS: &[ActionEffect] = &[
+    ActionEffect::new("BasicSynthesis", "Basic Synthesis")
+        .durability(10)
+        .progress(100)
+        .enhanced_progress(31, 120),
+    ActionEffect::new("BasicTouch", "Basic Touch")
+        .durability(10)
+        .quality(100),
+    ActionEffect::new("MasterMend", "Master's Mend"),
+    ActionEffect::new("Observe", "Observe"),
+    ActionEffect::new("TricksOfTheTrade", "Tricks of the Trade"),
+    ActionEffect::new("WasteNot", "Waste Not"),
+    ActionEffect::new("Veneration", "Veneration"),
+    ActionEffect::new("StandardTouch", "Standard Touch")
+        .combo_cp(18)
+        .durability(10)
+        .quality(125),
+    ActionEffect::new("GreatStrides", "Great Strides"),
+    ActionEffect::new("Innovation", "Innovation"),
+    ActionEffect::new("WasteNot2", "Waste Not II"),
+    ActionEffect::new("ByregotsBlessing", "Byregot's Blessing")
+        .durability(10)
+        .quality(100),
+    ActionEffect::new("PreciseTouch", "Precise Touch")
+        .durability(10)
+        .quality(150),
+    ActionEffect::new("MuscleMemory", "Muscle Memory")
+        .durability(10)
+        .progress(300),
+    ActionEffect::new("CarefulSynthesis", "Careful Synthesis")
+        .durability(10)
+        .progress(150)
+        .enhanced_progress(82, 180),
+    ActionEffect::new("Manipulation", "Manipulation"),
+    ActionEffect::new("PrudentTouch", "Prudent Touch")
+        .durability(5)
+        .quality(100),
+    ActionEffect::new("AdvancedTouch", "Advanced Touch")
+        .combo_cp(18)
+        .durability(10)
+        .quality(150),
+    ActionEffect::new("Reflect", "Reflect")
+        .durability(10)
+        .quality(300),
+    ActionEffect::new("PreparatoryTouch", "Preparatory Touch")
+        .durability(20)
+        .quality(200),
+    ActionEffect::new("Groundwork", "Groundwork")
+        .durability(20)
+        .progress(300)
+        .enhanced_progress(86, 360),
+    ActionEffect::new("DelicateSynthesis", "Delicate Synthesis")
+        .durability(10)
+        .progress(100)
+        .enhanced_progress(94, 150)
+        .quality(100),
+    ActionEffect::new("IntensiveSynthesis", "Intensive Synthesis")
+        .durability(10)
+        .progress(400),
+    ActionEffect::new("TrainedEye", "Trained Eye").durability(10),
+    ActionEffect::new("HeartAndSoul", "Heart and Soul"),
+    ActionEffect::new("PrudentSynthesis", "Prudent Synthesis")
+        .durability(5)
+        .progress(180),
+    ActionEffect::new("TrainedFinesse", "Trained Finesse").quality(100),
+    ActionEffect::new("RefinedTouch", "Refined Touch")
+        .durability(10)
+        .quality(100),
+    ActionEffect::new("QuickInnovation", "Quick Innovation"),
+    ActionEffect::new("ImmaculateMend", "Immaculate Mend"),
+    ActionEffect::new("TrainedPerfection", "Trained Perfection"),
+    ActionEffect::new("CarefulObservation", "Careful Observation"),
+];
+
+#[derive(Debug, Clone, Copy)]
+pub struct ActionData {
+    pub action: &'static str,
+    pub level_requirement: u32,
+    pub cp_cost: u32,
+    pub combo_cp_cost: Option<u32>,
+    pub durability_cost: u32,
+    pub progress_potency: u32,
+    pub enhanced_progress_potency: Option<(u32, u32)>,
+    pub quality_potency: u32,
+}
+
+impl std::fmt::Display for ActionData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ActionData {{ ")?;
+        write!(f, "action: Action::{}, ", self.action)?;
+        write!(f, "level_requirement: {}, ", self.level_requirement)?;
+        write!(f, "cp_cost: {}, ", self.cp_cost)?;
+        match self.combo_cp_cost {
+            Some(cp_cost) => write!(f, "combo_cp_cost: Some({cp_cost}), ")?,
+            None => write!(f, "combo_cp_cost: None, ")?,
+        }
+        write!(f, "durability_cost: {}, ", self.durability_cost)?;
+        write!(f, "progress_potency: {}, ", self.progress_potency)?;
+        match self.enhanced_progress_potency {
+            Some((level, potency)) => {
+                write!(f, "enhanced_progress_potency: Some(({level}, {potency})), ")?
+            }
+            None => write!(f, "enhanced_progress_potency: None, ")?,
+        }
+        write!(f, "quality_potency: {}, ", self.quality_potency)?;
+        write!(f, "}}")?;
+        Ok(())
+    }
+}
+
+pub fn instantiate_actions(
+    craft_actions: &[CraftAction],
+    buff_actions: &[BuffAction],
+) -> Vec<ActionData> {
+    let sheet_entries: Vec<_> = craft_actions
+        .iter()
+        .map(|action| {
+            (
+                &action.name,
+                action.class_job_id,
+                action.level,
+                action.cp_cost,
+            )
+        })
+        .chain(buff_actions.iter().map(|action| {
+            (
+                &action.name,
+                action.class_job_id,
+                action.level,
+                action.cp_cost,
+            )
+        }))
+        .filter(|(_, class_job_id, _, _)| *class_job_id == CARPENTER_CLASS_JOB_ID)
+        .collect();
+
+    let mut actions = Vec::new();
+    for effect in ACTION_EFFECTS {
+        // Some actions have multiple rows, the one that is learned first is the relevant one
+        let (_, _, level, cp_cost) = sheet_entries
+            .iter()
+            .filter(|(name, _, _, _)| name.as_str() == effect.name)
+            .min_by_key(|(_, _, level, _)| *level)
+            .unwrap_or_else(|| panic!("\"{}\" not found in action sheets", effect.name));
+        actions.push(ActionData {
+            action: effect.action,
+            level_requirement: *level,
+            cp_cost: *cp_cost,
+            combo_cp_cost: effect.combo_cp_cost,
+            durability_cost: effect.durability_cost,
+            progress_potency: effect.progress_potency,
+            enhanced_progress_potency: effect.enhanced_progress_potency,
+            quality_potency: effect.quality_potency,
+        });
+    }
+    actions
+}
+
+/// Icon of an action as it appears for one crafting job
+#[derive(Debug, Clone, Copy)]
+pub struct ActionIcon {
+    pub action: &'static str,
+    pub class_job_id: i32,
+    pub icon_id: u32,
+}
+
+/// Icons of all actions for all crafting jobs, ordered by job and then by action
+pub fn instantiate_action_icons(
+    craft_actions: &[CraftAction],
+    buff_actions: &[BuffAction],
+) -> Vec<ActionIcon> {
+    let sheet_entries: Vec<_> = craft_actions
+        .iter()
+        .map(|action| {
+            (
+                &action.name,
+                action.class_job_id,
+                action.level,
+                action.icon_id,
+            )
+        })
+        .chain(buff_actions.iter().map(|action| {
+            (
+                &action.name,
+                action.class_job_id,
+                action.level,
+                action.icon_id,
+            )
+        }))
+        .collect();
+
+    let mut action_icons = Vec::new();
+    for class_job_id in CRAFTING_CLASS_JOB_IDS {
+        for effect in ACTION_EFFECTS {
+            let (_, _, _, icon_id) = sheet_entries
+                .iter()
+                .filter(|(name, job, _, _)| name.as_str() == effect.name && *job == class_job_id)
+                .min_by_key(|(_, _, level, _)| *level)
+                .unwrap_or_else(|| {
+                    panic!(
+                        "\"{}\" of class job {class_job_id} not found in action sheets",
+                        effect.name
+                    )
+                });
+            action_icons.push(ActionIcon {
+                action: effect.action,
+                class_job_id,
+                icon_id: *icon_id,
+            });
+        }
+    }
+    action_icons
+}
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+use image::RgbaImage;
+use image::imageops::FilterType;
+
+use crate::{ActionIcon, CRAFTING_CLASS_JOB_IDS};
+
+/// Size of the icons in the atlas, in pixels
+const ICON_SIZE: u32 = 64;
+
+/// Packs the action icons of every job into a single-row image, `{dst_dir}/atlas-{job_id}.webp`,
+/// and writes the position of every icon to `dst_table` for the app to look up.
+/// All jobs share the same layout, so the table only lists every action once.
+/// `icons` are the images of the icons in `action_icons` by icon ID.
+pub fn generate_action_icon_atlases(
+    dst_dir: &str,
+    dst_table: &str,
+    action_icons: &[ActionIcon],
+    icons: &HashMap<u32, RgbaImage>,
+) {
+    let columns = action_icons.len() / CRAFTING_CLASS_JOB_IDS.count();
+    let (width, height) = (columns as u32 * ICON_SIZE, ICON_SIZE);
+
+    let mut writer = BufWriter::new(File::create(dst_table).unwrap());
+    writeln!(
+        &mut writer,
+        "ActionIconAtlas {{ width: {width}, height: {height}, icon_size: {ICON_SIZE}, icons: &["
+    )
+    .unwrap();
+    for (x, action_icon) in (0..)
+        .step_by(ICON_SIZE as usize)
+        .zip(&action_icons[..columns])
+    {
+        writeln!(
+            &mut writer,
+            "ActionIcon {{ action: Action::{}, x: {x}, }},",
+            action_icon.action,
+        )
+        .unwrap();
+    }
+    writeln!(&mut writer, "] }}").unwrap();
+
+    for job_icons in action_icons.chunks(columns) {
+        let job_id = job_icons[0].class_job_id - CRAFTING_CLASS_JOB_IDS.start();
+        let mut atlas = RgbaImage::new(width, height);
+        for (x, (action_icon, layout_icon)) in (0..)
+            .step_by(ICON_SIZE as usize)
+            .zip(job_icons.iter().zip(&action_icons[..columns]))
+        {
+            assert_eq!(action_icon.action, layout_icon.action);
+            let icon = &icons[&action_icon.icon_id];
+            let icon = image::imageops::resize(icon, ICON_SIZE, ICON_SIZE, FilterType::Lanczos3);
+            image::imageops::replace(&mut atlas, &icon, x, 0);
+        }
+        let dst_atlas = format!("{dst_dir}/atlas-{job_id}.webp");
+        atlas
+            .save_with_format(&dst_atlas, image::ImageFormat::WebP)
+            .unwrap();
+        log::info!("action icon atlas exported to \"{dst_atlas}\"");
+    }
+}
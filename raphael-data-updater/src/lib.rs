@@ -13,6 +13,12 @@ pub use item::{Item, ItemName};
 mod consumable;
 pub use consumable::{Consumable, ItemAction, ItemFood, instantiate_consumables};
 
+mod action;
+pub use action::{
+    ActionData, ActionIcon, BuffAction, CRAFTING_CLASS_JOB_IDS, CraftAction,
+    instantiate_action_icons, instantiate_actions,
+};
+
 pub trait SheetData: Sized {
     const SHEET: &'static str;
     const REQUIRED_FIELDS: &[&str];
@@ -22,3 +28,12 @@ pub trait SheetData: Sized {
 
 mod font;
 pub use font::generate_font_subset;
+
+mod icon_atlas;
+pub use icon_atlas::generate_action_icon_atlases;
+
+mod local_install;
+pub use local_install::LocalInstall;
+
+mod traditional_chinese;
+pub use traditional_chinese::TraditionalChineseConverter;
@@ -0,0 +1,59 @@
+//! Reads game data directly from the SqPack files of a local game installation.
+//! This allows regenerating data of regions that xivapi doesn't serve, such as CN and KR, as soon as the client is patched.
+
+use std::path::{Path, PathBuf};
+
+use ironworks::{
+    Ironworks,
+    excel::{Excel, Language},
+    sqpack::{Install, SqPack},
+};
+
+use crate::ItemName;
+
+/// Column of `Name` in the `Item` sheet
+const ITEM_NAME_COLUMN: usize = 9;
+
+pub struct LocalInstall {
+    path: PathBuf,
+    excel: Excel,
+}
+
+impl LocalInstall {
+    /// `path` is the root directory of the installation, i.e. the directory that contains `game`
+    pub fn at(path: &Path, language: Language) -> Self {
+        let ironworks = Ironworks::new().with_resource(SqPack::new(Install::at(path)));
+        Self {
+            path: path.to_owned(),
+            excel: Excel::new(ironworks).with_default_language(language),
+        }
+    }
+
+    /// Version of the game files, e.g. `2025.04.16.0000.0000`
+    pub fn game_version(&self) -> String {
+        let path = self.path.join("game").join("ffxivgame.ver");
+        std::fs::read_to_string(&path)
+            .unwrap_or_else(|error| panic!("Failed to read \"{}\": {error}", path.display()))
+            .trim()
+            .to_owned()
+    }
+
+    pub fn item_names(&self) -> Vec<ItemName> {
+        let sheet = self.excel.sheet("Item").unwrap();
+        let item_names: Vec<_> = sheet
+            .into_iter()
+            .filter_map(|row| {
+                let name = row.field(ITEM_NAME_COLUMN).ok()?.into_string().ok()?;
+                Some(ItemName {
+                    id: row.row_id(),
+                    name: name.to_string().replace('­', ""),
+                })
+            })
+            .collect();
+        log::debug!(
+            "\"Item\": total read from local install: {}",
+            item_names.len()
+        );
+        item_names
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::io::Write;
 use std::{fs::File, io::BufWriter};
 
@@ -96,6 +96,17 @@ fn export_potions(consumables: &[Consumable]) {
     log::info!("potions exported to \"{}\"", path.display());
 }
 
+fn export_actions(actions: &[ActionData]) {
+    let path = std::path::absolute("./raphael-sim/data/actions.rs").unwrap();
+    let mut writer = BufWriter::new(File::create(&path).unwrap());
+    writeln!(&mut writer, "&[").unwrap();
+    for action in actions.iter() {
+        writeln!(&mut writer, "{action},").unwrap();
+    }
+    writeln!(&mut writer, "]").unwrap();
+    log::info!("actions exported to \"{}\"", path.display());
+}
+
 fn export_item_names(item_names: &[ItemName], lang: &str) {
     let mut phf_map = phf_codegen::Map::new();
     for item_name in item_names {
@@ -107,6 +118,97 @@ fn export_item_names(item_names: &[ItemName], lang: &str) {
     log::info!("item names exported to \"{}\"", path.display());
 }
 
+fn export_game_versions(game_versions: &[(&str, String)]) {
+    let path = std::path::absolute("./raphael-data/data/game_versions.rs").unwrap();
+    let mut writer = BufWriter::new(File::create(&path).unwrap());
+    writeln!(&mut writer, "&[").unwrap();
+    for (region, game_version) in game_versions {
+        writeln!(&mut writer, "(\"{region}\", \"{game_version}\"),").unwrap();
+    }
+    writeln!(&mut writer, "]").unwrap();
+    log::info!("game versions exported to \"{}\"", path.display());
+}
+
+/// High resolution images of the given icons, by icon ID
+async fn fetch_icons(icon_ids: HashSet<u32>) -> HashMap<u32, image::RgbaImage> {
+    let mut icons = HashMap::new();
+    for icon_id in icon_ids {
+        let query = format!(
+            "https://v2.xivapi.com/api/asset?path=ui/icon/{:06}/{icon_id:06}_hr1.tex&format=png",
+            icon_id / 1000 * 1000,
+        );
+        let response = reqwest::get(query).await.unwrap();
+        let icon = image::load_from_memory(&response.bytes().await.unwrap()).unwrap();
+        icons.insert(icon_id, icon.to_rgba8());
+    }
+    log::debug!("icons: total fetched: {}", icons.len());
+    icons
+}
+
+/// Latest game version known to xivapi, which serves the data of the Global client
+async fn fetch_global_game_version() -> String {
+    let response = reqwest::get("https://v2.xivapi.com/api/version")
+        .await
+        .unwrap();
+    let json = json::parse(&response.text().await.unwrap()).unwrap();
+    json["versions"]
+        .members()
+        .map(|version| {
+            version["names"]
+                .members()
+                .filter_map(json::JsonValue::as_str)
+                .collect::<Vec<_>>()
+        })
+        .find(|names| names.contains(&"latest"))
+        .and_then(|names| names.into_iter().find(|name| *name != "latest"))
+        .unwrap_or("latest")
+        .to_owned()
+}
+
+async fn fetch_opencc_dictionary(name: &str) -> String {
+    let url = format!(
+        "https://raw.githubusercontent.com/BYVoid/OpenCC/master/data/dictionary/{name}.txt"
+    );
+    let response = reqwest::get(url).await.unwrap();
+    response.text().await.unwrap()
+}
+
+/// Root directory of a local installation of a regional client, given as e.g. `--cn-install <path>`
+fn install_path(option: &str) -> Option<std::path::PathBuf> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == option {
+            return args.next().map(std::path::PathBuf::from);
+        }
+    }
+    None
+}
+
+/// Item names of a regional client, falling back to English for items the client doesn't have yet
+fn localized_item_names(
+    item_names_en: &[ItemName],
+    item_names: &HashMap<u32, String>,
+) -> Vec<ItemName> {
+    item_names_en
+        .iter()
+        .map(|item_name| ItemName {
+            id: item_name.id,
+            name: match item_names.get(&item_name.id) {
+                Some(name) if !name.is_empty() => name.clone(),
+                _ => item_name.name.clone(),
+            },
+        })
+        .collect()
+}
+
+fn read_local_item_names(install: &LocalInstall) -> HashMap<u32, String> {
+    install
+        .item_names()
+        .into_iter()
+        .map(|item_name| (item_name.id, item_name.name))
+        .collect()
+}
+
 #[tokio::main]
 async fn main() {
     env_logger::builder().format_timestamp(None).init();
@@ -118,12 +220,15 @@ async fn main() {
     let items = tokio::spawn(async { fetch_and_parse::<Item>("en").await });
     let item_actions = tokio::spawn(async { fetch_and_parse::<ItemAction>("en").await });
     let item_foods = tokio::spawn(async { fetch_and_parse::<ItemFood>("en").await });
+    let craft_actions = tokio::spawn(async { fetch_and_parse::<CraftAction>("en").await });
+    let buff_actions = tokio::spawn(async { fetch_and_parse::<BuffAction>("en").await });
 
     let item_names_en = tokio::spawn(async { fetch_and_parse::<ItemName>("en").await });
     let item_names_de = tokio::spawn(async { fetch_and_parse::<ItemName>("de").await });
     let item_names_fr = tokio::spawn(async { fetch_and_parse::<ItemName>("fr").await });
     let item_names_jp = tokio::spawn(async { fetch_and_parse::<ItemName>("ja").await });
     // let item_names_kr = tokio::spawn(async { fetch_and_parse::<ItemName>("kr").await });
+    let global_game_version = tokio::spawn(fetch_global_game_version());
 
     let rlvls = rlvls.await.unwrap();
     let level_adjust_table_entries = level_adjust_table_entries.await.unwrap();
@@ -134,26 +239,61 @@ async fn main() {
     let item_foods = item_foods.await.unwrap();
     let (meals, potions) = instantiate_consumables(&items, item_actions, item_foods);
 
+    let craft_actions = craft_actions.await.unwrap();
+    let buff_actions = buff_actions.await.unwrap();
+    let actions = instantiate_actions(&craft_actions, &buff_actions);
+    let action_icons = instantiate_action_icons(&craft_actions, &buff_actions);
+    let icons = fetch_icons(
+        action_icons
+            .iter()
+            .map(|action_icon| action_icon.icon_id)
+            .collect(),
+    )
+    .await;
+
     let mut item_names_en = item_names_en.await.unwrap();
     let mut item_names_de = item_names_de.await.unwrap();
     let mut item_names_fr = item_names_fr.await.unwrap();
     let mut item_names_jp = item_names_jp.await.unwrap();
     // let mut item_names_kr = item_names_kr.await.unwrap();
 
-    let items_cn: Vec<_> = csv::Reader::from_path("../ffxiv-datamining-cn/Item.csv").unwrap()
-        .records().skip(2).collect();
-    let mut item_names_cn: Vec<_> = item_names_en.iter().map(|item_name| ItemName {
-        id: item_name.id,
-        name: 'v: {
-            if let Some(item_cn) = items_cn.get(item_name.id as usize) {
-                let name_cn = &item_cn.as_ref().unwrap()[1];
-                if !name_cn.is_empty() {
-                    break 'v name_cn.to_string()
-                }
-            }
-            item_name.name.clone()
-        },
-    }).collect();
+    let mut game_versions = vec![("Global", global_game_version.await.unwrap())];
+
+    // The CN and KR clients aren't served by xivapi, so their item names are read from local installs if given.
+    // Without a local install, CN item names are read from a CSV dump of the CN game data and KR item names are kept as is.
+    let item_names_cn = match install_path("--cn-install") {
+        Some(path) => {
+            let install = LocalInstall::at(&path, ironworks::excel::Language::ChineseSimplified);
+            game_versions.push(("CN", install.game_version()));
+            read_local_item_names(&install)
+        }
+        None => csv::Reader::from_path("../ffxiv-datamining-cn/Item.csv")
+            .unwrap()
+            .records()
+            .skip(2)
+            .map(|record| record.unwrap())
+            .map(|record| (record[0].parse().unwrap(), record[1].to_string()))
+            .collect(),
+    };
+    let mut item_names_cn = localized_item_names(&item_names_en, &item_names_cn);
+    // Traditional Chinese item names are converted from the Simplified Chinese ones
+    let converter = TraditionalChineseConverter::new(
+        &fetch_opencc_dictionary("STPhrases").await,
+        &fetch_opencc_dictionary("STCharacters").await,
+        &fetch_opencc_dictionary("TWVariants").await,
+    );
+    let mut item_names_tw: Vec<_> = item_names_cn
+        .iter()
+        .map(|item_name| ItemName {
+            id: item_name.id,
+            name: converter.convert(&item_name.name),
+        })
+        .collect();
+    let mut item_names_kr = install_path("--kr-install").map(|path| {
+        let install = LocalInstall::at(&path, ironworks::excel::Language::Korean);
+        game_versions.push(("KR", install.game_version()));
+        localized_item_names(&item_names_en, &read_local_item_names(&install))
+    });
 
     // For some reason some recipes have items with ID 0 as their result
     recipes.retain(|recipe| recipe.item_id != 0);
@@ -193,7 +333,10 @@ async fn main() {
     item_names_fr.retain(|item_name| necessary_items.contains(&item_name.id));
     item_names_jp.retain(|item_name| necessary_items.contains(&item_name.id));
     item_names_cn.retain(|item_name| necessary_items.contains(&item_name.id));
-    // item_names_kr.retain(|item_name| necessary_items.contains(&item_name.id));
+    item_names_tw.retain(|item_name| necessary_items.contains(&item_name.id));
+    if let Some(item_names_kr) = item_names_kr.as_mut() {
+        item_names_kr.retain(|item_name| necessary_items.contains(&item_name.id));
+    }
 
     export_rlvls(&rlvls);
     export_level_adjust_table(&level_adjust_table_entries);
@@ -201,13 +344,24 @@ async fn main() {
     export_meals(&meals);
     export_potions(&potions);
     export_items(&items);
+    export_actions(&actions);
+    generate_action_icon_atlases(
+        "./assets/action-icons",
+        "./raphael-data/data/action_icons.rs",
+        &action_icons,
+        &icons,
+    );
 
     export_item_names(&item_names_en, "en");
     export_item_names(&item_names_de, "de");
     export_item_names(&item_names_fr, "fr");
     export_item_names(&item_names_jp, "jp");
     export_item_names(&item_names_cn, "cn");
-    // export_item_names(&item_names_kr, "kr");
+    export_item_names(&item_names_tw, "tw");
+    if let Some(item_names_kr) = &item_names_kr {
+        export_item_names(item_names_kr, "kr");
+    }
+    export_game_versions(&game_versions);
 
     generate_font_subset(
         "./assets/fonts/M_PLUS_1_Code/subset.ttf",
@@ -215,6 +369,7 @@ async fn main() {
         &[
             "./raphael-data/src/locales.rs",
             "./raphael-data/data/item_names_jp.rs",
+            "./src/translations.rs",
             "マクロ次へ製作完成了",
         ],
     );
@@ -224,9 +379,19 @@ async fn main() {
         &[
             "./raphael-data/src/locales.rs",
             "./raphael-data/data/item_names_cn.rs",
+            "./src/translations.rs",
             "宏已制作完成",
         ],
     );
+    // Noto Sans SC covers the Traditional Chinese characters as well, in the glyph style of Simplified Chinese
+    generate_font_subset(
+        "./assets/fonts/Noto_Sans_SC/subset_tw.ttf",
+        "./assets/fonts/Noto_Sans_SC/static/NotoSansSC-Light.ttf",
+        &[
+            "./raphael-data/src/locales.rs",
+            "./raphael-data/data/item_names_tw.rs",
+        ],
+    );
     generate_font_subset(
         "./assets/fonts/Noto_Sans_KR/subset.ttf",
         "./assets/fonts/Noto_Sans_KR/static/NotoSansKR-Light.ttf",
@@ -11,6 +11,7 @@ pub struct RecipeLevel {
     pub quality_div: u32,
     pub progress_mod: u32,
     pub quality_mod: u32,
+    pub conditions_flag: u32,
 }
 
 impl SheetData for RecipeLevel {
@@ -24,6 +25,7 @@ impl SheetData for RecipeLevel {
         "QualityDivider",
         "ProgressModifier",
         "QualityModifier",
+        "ConditionsFlag",
     ];
 
     fn row_id(&self) -> u32 {
@@ -42,6 +44,7 @@ impl SheetData for RecipeLevel {
             quality_div: fields["QualityDivider"].as_u32().unwrap(),
             progress_mod: fields["ProgressModifier"].as_u32().unwrap(),
             quality_mod: fields["QualityModifier"].as_u32().unwrap(),
+            conditions_flag: fields["ConditionsFlag"].as_u32().unwrap(),
         })
     }
 }
@@ -57,6 +60,7 @@ impl std::fmt::Display for RecipeLevel {
         write!(f, "quality_div: {}, ", self.quality_div)?;
         write!(f, "progress_mod: {}, ", self.progress_mod)?;
         write!(f, "quality_mod: {}, ", self.quality_mod)?;
+        write!(f, "conditions_flag: {}, ", self.conditions_flag)?;
         write!(f, "}}")?;
         Ok(())
     }
@@ -0,0 +1,88 @@
+//! Converts Simplified Chinese to Traditional Chinese as used in Taiwan,
+//! with the dictionaries of OpenCC (https://github.com/BYVoid/OpenCC).
+
+use std::collections::HashMap;
+
+pub struct TraditionalChineseConverter {
+    phrases: HashMap<String, String>,
+    /// Length of the longest phrase, in characters
+    max_phrase_length: usize,
+    characters: HashMap<char, String>,
+    variants: HashMap<char, String>,
+}
+
+impl TraditionalChineseConverter {
+    /// Takes the contents of the OpenCC dictionaries `STPhrases.txt`, `STCharacters.txt` and `TWVariants.txt`
+    pub fn new(phrases: &str, characters: &str, variants: &str) -> Self {
+        let phrases: HashMap<String, String> = parse_dictionary(phrases)
+            .map(|(from, to)| (from.to_owned(), to.to_owned()))
+            .collect();
+        let max_phrase_length = phrases
+            .keys()
+            .map(|phrase| phrase.chars().count())
+            .max()
+            .unwrap_or(0);
+        Self {
+            phrases,
+            max_phrase_length,
+            characters: parse_character_dictionary(characters),
+            variants: parse_character_dictionary(variants),
+        }
+    }
+
+    pub fn convert(&self, text: &str) -> String {
+        let chars: Vec<char> = text.chars().collect();
+        let mut converted = String::new();
+        let mut index = 0;
+        while index < chars.len() {
+            // the longest matching phrase takes precedence over single characters
+            let max_length = self.max_phrase_length.min(chars.len() - index);
+            let phrase = (2..=max_length).rev().find_map(|length| {
+                let phrase: String = chars[index..index + length].iter().collect();
+                self.phrases.get(&phrase).map(|to| (length, to))
+            });
+            if let Some((length, to)) = phrase {
+                converted.push_str(to);
+                index += length;
+                continue;
+            }
+            let c = chars[index];
+            let to = self
+                .characters
+                .get(&c)
+                .cloned()
+                .unwrap_or_else(|| c.to_string());
+            converted.push_str(&to);
+            index += 1;
+        }
+        converted
+            .chars()
+            .map(|c| {
+                self.variants
+                    .get(&c)
+                    .cloned()
+                    .unwrap_or_else(|| c.to_string())
+            })
+            .collect()
+    }
+}
+
+/// Each line is the text to convert and the space-separated candidates, of which the first one is used
+fn parse_dictionary(content: &str) -> impl Iterator<Item = (&str, &str)> {
+    content.lines().filter_map(|line| {
+        let (from, to) = line.split_once('\t')?;
+        Some((from, to.split(' ').next()?))
+    })
+}
+
+fn parse_character_dictionary(content: &str) -> HashMap<char, String> {
+    parse_dictionary(content)
+        .filter_map(|(from, to)| {
+            let mut chars = from.chars();
+            match (chars.next(), chars.next()) {
+                (Some(from), None) => Some((from, to.to_owned())),
+                _ => None,
+            }
+        })
+        .collect()
+}
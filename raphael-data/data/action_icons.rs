@@ -0,0 +1,34 @@
+ActionIconAtlas { width: 2048, height: 64, icon_size: 64, icons: &[
+ActionIcon { action: Action::BasicSynthesis, x: 0, },
+ActionIcon { action: Action::BasicTouch, x: 64, },
+ActionIcon { action: Action::MasterMend, x: 128, },
+ActionIcon { action: Action::Observe, x: 192, },
+ActionIcon { action: Action::TricksOfTheTrade, x: 256, },
+ActionIcon { action: Action::WasteNot, x: 320, },
+ActionIcon { action: Action::Veneration, x: 384, },
+ActionIcon { action: Action::StandardTouch, x: 448, },
+ActionIcon { action: Action::GreatStrides, x: 512, },
+ActionIcon { action: Action::Innovation, x: 576, },
+ActionIcon { action: Action::WasteNot2, x: 640, },
+ActionIcon { action: Action::ByregotsBlessing, x: 704, },
+ActionIcon { action: Action::PreciseTouch, x: 768, },
+ActionIcon { action: Action::MuscleMemory, x: 832, },
+ActionIcon { action: Action::CarefulSynthesis, x: 896, },
+ActionIcon { action: Action::Manipulation, x: 960, },
+ActionIcon { action: Action::PrudentTouch, x: 1024, },
+ActionIcon { action: Action::AdvancedTouch, x: 1088, },
+ActionIcon { action: Action::Reflect, x: 1152, },
+ActionIcon { action: Action::PreparatoryTouch, x: 1216, },
+ActionIcon { action: Action::Groundwork, x: 1280, },
+ActionIcon { action: Action::DelicateSynthesis, x: 1344, },
+ActionIcon { action: Action::IntensiveSynthesis, x: 1408, },
+ActionIcon { action: Action::TrainedEye, x: 1472, },
+ActionIcon { action: Action::HeartAndSoul, x: 1536, },
+ActionIcon { action: Action::PrudentSynthesis, x: 1600, },
+ActionIcon { action: Action::TrainedFinesse, x: 1664, },
+ActionIcon { action: Action::RefinedTouch, x: 1728, },
+ActionIcon { action: Action::QuickInnovation, x: 1792, },
+ActionIcon { action: Action::ImmaculateMend, x: 1856, },
+ActionIcon { action: Action::TrainedPerfection, x: 1920, },
+ActionIcon { action: Action::CarefulObservation, x: 1984, },
+] }
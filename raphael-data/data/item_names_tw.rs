@@ -0,0 +1,12671 @@
+::phf::Map {
+    key: 12913932095322966823,
+    disps: &[
+        (0, 0),
+        (0, 19),
+        (0, 100),
+        (0, 2),
+        (0, 23),
+        (0, 0),
+        (0, 309),
+        (0, 0),
+        (0, 2),
+        (0, 14),
+        (0, 9),
+        (0, 512),
+        (0, 685),
+        (0, 8),
+        (0, 1),
+        (0, 1),
+        (0, 0),
+        (0, 11),
+        (0, 0),
+        (0, 2),
+        (0, 2),
+        (0, 1),
+        (0, 30),
+        (0, 3),
+        (0, 0),
+        (0, 304),
+        (0, 1014),
+        (0, 4),
+        (0, 8),
+        (0, 457),
+        (0, 7),
+        (0, 16),
+        (0, 83),
+        (0, 6),
+        (0, 15),
+        (0, 0),
+        (0, 8),
+        (0, 54),
+        (0, 149),
+        (0, 1299),
+        (0, 706),
+        (0, 448),
+        (0, 1),
+        (0, 298),
+        (0, 117),
+        (0, 12),
+        (0, 52),
+        (0, 66),
+        (0, 199),
+        (0, 6),
+        (0, 0),
+        (0, 68),
+        (0, 631),
+        (0, 0),
+        (0, 730),
+        (0, 28),
+        (0, 178),
+        (0, 111),
+        (0, 2),
+        (0, 479),
+        (0, 30),
+        (0, 45),
+        (0, 1),
+        (0, 727),
+        (0, 0),
+        (0, 207),
+        (0, 544),
+        (0, 1183),
+        (0, 44),
+        (0, 217),
+        (0, 241),
+        (0, 8),
+        (0, 15),
+        (0, 1),
+        (0, 5),
+        (0, 5),
+        (0, 131),
+        (0, 987),
+        (0, 1),
+        (0, 64),
+        (0, 448),
+        (0, 1097),
+        (0, 261),
+        (0, 139),
+        (0, 1),
+        (0, 297),
+        (0, 99),
+        (0, 117),
+        (0, 4),
+        (0, 99),
+        (0, 1),
+        (0, 0),
+        (0, 0),
+        (0, 0),
+        (0, 21),
+        (0, 80),
+        (0, 363),
+        (0, 1),
+        (0, 95),
+        (0, 0),
+        (0, 89),
+        (0, 8),
+        (0, 0),
+        (0, 54),
+        (0, 5),
+        (0, 1),
+        (0, 889),
+        (0, 182),
+        (0, 264),
+        (0, 1),
+        (0, 0),
+        (0, 22),
+        (0, 42),
+        (0, 83),
+        (0, 11),
+        (0, 62),
+        (0, 19),
+        (0, 15),
+        (0, 0),
+        (0, 4),
+        (0, 44),
+        (0, 91),
+        (0, 10),
+        (0, 132),
+        (0, 18),
+        (0, 0),
+        (0, 9),
+        (0, 644),
+        (0, 0),
+        (0, 7),
+        (0, 1),
+        (0, 38),
+        (0, 1597),
+        (0, 0),
+        (0, 1),
+        (0, 197),
+        (0, 1),
+        (0, 18),
+        (0, 10),
+        (0, 95),
+        (0, 168),
+        (0, 79),
+        (0, 0),
+        (0, 73),
+        (0, 14),
+        (0, 0),
+        (0, 78),
+        (0, 205),
+        (0, 404),
+        (0, 205),
+        (0, 11),
+        (0, 255),
+        (0, 629),
+        (0, 75),
+        (0, 83),
+        (0, 1),
+        (0, 2),
+        (0, 38),
+        (0, 493),
+        (0, 9),
+        (0, 547),
+        (0, 1),
+        (0, 1),
+        (0, 263),
+        (0, 3),
+        (0, 748),
+        (0, 769),
+        (0, 0),
+        (0, 51),
+        (0, 45),
+        (0, 177),
+        (0, 79),
+        (0, 27),
+        (0, 140),
+        (0, 55),
+        (0, 53),
+        (0, 30),
+        (0, 5),
+        (0, 364),
+        (0, 0),
+        (0, 179),
+        (0, 173),
+        (0, 1),
+        (0, 322),
+        (0, 863),
+        (0, 1442),
+        (0, 92),
+        (0, 718),
+        (0, 0),
+        (0, 35),
+        (0, 468),
+        (0, 3),
+        (0, 123),
+        (0, 725),
+        (0, 57),
+        (0, 1307),
+        (0, 499),
+        (0, 28),
+        (0, 169),
+        (0, 44),
+        (0, 9),
+        (0, 2),
+        (0, 187),
+        (0, 169),
+        (0, 1688),
+        (0, 48),
+        (0, 2),
+        (0, 251),
+        (0, 3),
+        (0, 941),
+        (0, 230),
+        (0, 630),
+        (0, 89),
+        (0, 506),
+        (0, 503),
+        (0, 3),
+        (0, 4),
+        (0, 35),
+        (0, 11),
+        (0, 1141),
+        (0, 122),
+        (0, 33),
+        (0, 55),
+        (0, 155),
+        (0, 5),
+        (0, 780),
+        (0, 258),
+        (0, 16),
+        (0, 18),
+        (0, 51),
+        (0, 118),
+        (0, 28),
+        (0, 73),
+        (0, 196),
+        (0, 18),
+        (0, 17),
+        (0, 258),
+        (0, 0),
+        (0, 1),
+        (0, 152),
+        (0, 23),
+        (0, 1022),
+        (0, 1542),
+        (0, 468),
+        (0, 90),
+        (0, 10),
+        (0, 254),
+        (0, 1314),
+        (0, 289),
+        (0, 6),
+        (0, 16),
+        (0, 15),
+        (0, 55),
+        (0, 31),
+        (0, 8),
+        (0, 232),
+        (0, 1),
+        (0, 31),
+        (0, 2),
+        (0, 188),
+        (0, 170),
+        (0, 1),
+        (0, 1029),
+        (0, 4),
+        (0, 43),
+        (0, 1),
+        (0, 4),
+        (0, 602),
+        (0, 22),
+        (0, 1326),
+        (0, 0),
+        (0, 17),
+        (0, 96),
+        (0, 27),
+        (0, 215),
+        (0, 123),
+        (0, 256),
+        (0, 487),
+        (0, 378),
+        (0, 141),
+        (0, 877),
+        (0, 19),
+        (0, 2),
+        (0, 434),
+        (0, 299),
+        (0, 24),
+        (0, 72),
+        (0, 1),
+        (0, 337),
+        (0, 9),
+        (0, 28),
+        (0, 447),
+        (1, 244),
+        (0, 118),
+        (0, 15),
+        (0, 442),
+        (0, 1),
+        (0, 22),
+        (0, 92),
+        (0, 51),
+        (0, 167),
+        (0, 22),
+        (0, 0),
+        (0, 154),
+        (0, 90),
+        (0, 39),
+        (0, 0),
+        (0, 3),
+        (0, 2),
+        (0, 2555),
+        (0, 59),
+        (0, 27),
+        (0, 88),
+        (0, 434),
+        (0, 103),
+        (0, 0),
+        (0, 758),
+        (0, 20),
+        (0, 0),
+        (0, 29),
+        (0, 203),
+        (0, 230),
+        (0, 26),
+        (0, 0),
+        (0, 25),
+        (0, 0),
+        (0, 12),
+        (0, 2131),
+        (0, 13),
+        (0, 63),
+        (0, 212),
+        (0, 0),
+        (0, 32),
+        (0, 373),
+        (0, 25),
+        (0, 152),
+        (0, 42),
+        (0, 170),
+        (0, 8),
+        (0, 0),
+        (0, 46),
+        (0, 12),
+        (0, 22),
+        (0, 1),
+        (0, 49),
+        (0, 1577),
+        (0, 9),
+        (0, 319),
+        (0, 10),
+        (0, 5),
+        (0, 1),
+        (0, 8),
+        (0, 9),
+        (0, 44),
+        (0, 29),
+        (0, 3),
+        (0, 60),
+        (0, 22),
+        (0, 231),
+        (0, 320),
+        (0, 1054),
+        (0, 30),
+        (0, 1),
+        (0, 164),
+        (0, 129),
+        (0, 2),
+        (0, 692),
+        (0, 11),
+        (0, 8),
+        (0, 0),
+        (0, 65),
+        (0, 626),
+        (0, 2),
+        (0, 19),
+        (0, 4),
+        (0, 338),
+        (0, 170),
+        (0, 27),
+        (0, 48),
+        (0, 5),
+        (0, 8),
+        (0, 436),
+        (0, 42),
+        (0, 69),
+        (0, 0),
+        (0, 8),
+        (0, 17),
+        (0, 10),
+        (0, 1291),
+        (0, 473),
+        (0, 4),
+        (0, 96),
+        (0, 1162),
+        (0, 986),
+        (0, 0),
+        (0, 0),
+        (0, 118),
+        (0, 42),
+        (0, 6),
+        (0, 2),
+        (0, 483),
+        (0, 136),
+        (0, 0),
+        (0, 152),
+        (0, 7),
+        (0, 3),
+        (0, 6),
+        (0, 1057),
+        (0, 1),
+        (0, 3),
+        (0, 9),
+        (0, 19),
+        (0, 775),
+        (0, 1200),
+        (0, 8),
+        (0, 557),
+        (0, 10),
+        (0, 153),
+        (0, 3),
+        (0, 3),
+        (0, 1933),
+        (0, 14),
+        (0, 35),
+        (0, 2),
+        (0, 60),
+        (0, 1),
+        (0, 599),
+        (0, 9),
+        (0, 222),
+        (0, 0),
+        (0, 11),
+        (0, 0),
+        (0, 397),
+        (0, 107),
+        (0, 1),
+        (0, 7),
+        (0, 106),
+        (0, 30),
+        (0, 1),
+        (0, 227),
+        (0, 176),
+        (0, 177),
+        (0, 33),
+        (0, 1202),
+        (0, 506),
+        (0, 100),
+        (0, 282),
+        (0, 2),
+        (0, 0),
+        (0, 45),
+        (0, 0),
+        (0, 2),
+        (0, 0),
+        (0, 36),
+        (0, 1510),
+        (0, 964),
+        (0, 0),
+        (0, 247),
+        (0, 22),
+        (0, 128),
+        (0, 2),
+        (0, 4),
+        (0, 330),
+        (0, 1448),
+        (0, 1),
+        (0, 357),
+        (0, 635),
+        (0, 3),
+        (0, 11),
+        (0, 4064),
+        (0, 17),
+        (0, 235),
+        (0, 6),
+        (0, 209),
+        (0, 111),
+        (0, 1025),
+        (0, 827),
+        (0, 2),
+        (0, 167),
+        (0, 201),
+        (0, 36),
+        (0, 2736),
+        (0, 761),
+        (0, 68),
+        (0, 42),
+        (0, 70),
+        (0, 393),
+        (0, 8),
+        (0, 127),
+        (0, 24),
+        (0, 1387),
+        (0, 198),
+        (0, 167),
+        (0, 8),
+        (0, 0),
+        (0, 2),
+        (0, 207),
+        (0, 0),
+        (0, 65),
+        (0, 1946),
+        (0, 6),
+        (0, 795),
+        (0, 69),
+        (0, 108),
+        (0, 9),
+        (0, 63),
+        (0, 4),
+        (0, 1),
+        (0, 9),
+        (0, 15),
+        (0, 60),
+        (0, 18),
+        (0, 503),
+        (0, 132),
+        (0, 58),
+        (0, 23),
+        (0, 4),
+        (0, 152),
+        (0, 159),
+        (0, 1),
+        (0, 10),
+        (0, 370),
+        (0, 41),
+        (0, 23),
+        (0, 145),
+        (0, 214),
+        (0, 654),
+        (0, 3),
+        (0, 0),
+        (0, 197),
+        (0, 1),
+        (0, 325),
+        (0, 183),
+        (0, 5),
+        (0, 598),
+        (0, 0),
+        (0, 588),
+        (0, 609),
+        (0, 322),
+        (0, 79),
+        (0, 58),
+        (0, 65),
+        (0, 0),
+        (0, 1),
+        (0, 87),
+        (0, 34),
+        (0, 2253),
+        (0, 1233),
+        (0, 774),
+        (0, 26),
+        (0, 2065),
+        (0, 4),
+        (0, 4),
+        (0, 110),
+        (0, 1727),
+        (0, 20),
+        (0, 0),
+        (0, 871),
+        (0, 8),
+        (0, 4),
+        (0, 9),
+        (0, 2),
+        (0, 245),
+        (0, 450),
+        (0, 5),
+        (0, 1484),
+        (0, 21),
+        (0, 1210),
+        (0, 8),
+        (0, 3),
+        (0, 117),
+        (0, 1),
+        (0, 8),
+        (0, 111),
+        (0, 27),
+        (0, 608),
+        (0, 1076),
+        (0, 1688),
+        (0, 2),
+        (0, 0),
+        (0, 0),
+        (0, 129),
+        (0, 1),
+        (0, 0),
+        (0, 4),
+        (0, 0),
+        (0, 14),
+        (0, 0),
+        (0, 252),
+        (0, 9),
+        (0, 17),
+        (0, 4),
+        (0, 1741),
+        (0, 87),
+        (0, 354),
+        (0, 0),
+        (0, 48),
+        (0, 27),
+        (0, 721),
+        (0, 9),
+        (0, 314),
+        (0, 3),
+        (0, 473),
+        (0, 15),
+        (0, 128),
+        (0, 898),
+        (0, 4),
+        (0, 92),
+        (0, 1),
+        (0, 2274),
+        (0, 83),
+        (0, 161),
+        (0, 0),
+        (0, 82),
+        (0, 19),
+        (0, 242),
+        (0, 1),
+        (0, 343),
+        (0, 149),
+        (0, 2),
+        (0, 114),
+        (0, 13),
+        (0, 45),
+        (0, 12),
+        (0, 0),
+        (0, 5),
+        (0, 6),
+        (0, 4323),
+        (0, 2166),
+        (0, 704),
+        (0, 130),
+        (0, 417),
+        (0, 86),
+        (0, 93),
+        (0, 60),
+        (0, 0),
+        (0, 9),
+        (0, 649),
+        (0, 0),
+        (0, 647),
+        (0, 1002),
+        (0, 12),
+        (0, 24),
+        (0, 41),
+        (0, 21),
+        (0, 74),
+        (0, 2),
+        (0, 404),
+        (0, 19),
+        (0, 468),
+        (0, 54),
+        (0, 302),
+        (0, 4),
+        (0, 19),
+        (0, 55),
+        (0, 232),
+        (0, 17),
+        (0, 360),
+        (0, 1071),
+        (0, 0),
+        (0, 14),
+        (0, 606),
+        (0, 35),
+        (0, 1),
+        (0, 675),
+        (0, 36),
+        (0, 437),
+        (0, 32),
+        (0, 0),
+        (0, 34),
+        (0, 589),
+        (0, 452),
+        (0, 25),
+        (0, 44),
+        (0, 1350),
+        (0, 1518),
+        (0, 11),
+        (0, 14),
+        (0, 35),
+        (0, 27),
+        (0, 454),
+        (0, 81),
+        (0, 44),
+        (0, 65),
+        (0, 143),
+        (0, 5),
+        (0, 129),
+        (0, 437),
+        (0, 403),
+        (0, 335),
+        (0, 2330),
+        (0, 16),
+        (0, 537),
+        (0, 154),
+        (0, 136),
+        (0, 203),
+        (0, 257),
+        (0, 947),
+        (0, 1),
+        (0, 104),
+        (0, 19),
+        (0, 2011),
+        (0, 53),
+        (0, 98),
+        (0, 284),
+        (0, 442),
+        (0, 304),
+        (0, 12),
+        (0, 6),
+        (0, 550),
+        (0, 6),
+        (0, 670),
+        (0, 86),
+        (0, 112),
+        (0, 13),
+        (0, 190),
+        (0, 41),
+        (0, 9),
+        (0, 200),
+        (0, 340),
+        (0, 27),
+        (0, 14),
+        (0, 415),
+        (0, 181),
+        (0, 183),
+        (0, 274),
+        (0, 563),
+        (0, 82),
+        (0, 5),
+        (0, 82),
+        (0, 49),
+        (0, 11),
+        (0, 7),
+        (0, 465),
+        (0, 142),
+        (0, 617),
+        (0, 55),
+        (0, 5),
+        (0, 0),
+        (0, 49),
+        (0, 6),
+        (0, 0),
+        (0, 19),
+        (0, 7),
+        (0, 34),
+        (0, 1),
+        (0, 1135),
+        (0, 3180),
+        (0, 80),
+        (0, 3),
+        (0, 0),
+        (0, 0),
+        (0, 827),
+        (0, 0),
+        (0, 51),
+        (0, 3006),
+        (0, 17),
+        (0, 2),
+        (0, 131),
+        (0, 1),
+        (0, 17),
+        (0, 83),
+        (0, 2194),
+        (0, 4409),
+        (0, 848),
+        (0, 1569),
+        (0, 51),
+        (0, 8),
+        (0, 153),
+        (0, 182),
+        (0, 181),
+        (0, 1157),
+        (0, 793),
+        (0, 10),
+        (0, 166),
+        (0, 12),
+        (0, 11),
+        (0, 2),
+        (0, 417),
+        (0, 714),
+        (0, 115),
+        (0, 31),
+        (0, 12),
+        (0, 923),
+        (0, 98),
+        (0, 77),
+        (0, 66),
+        (0, 526),
+        (0, 3),
+        (0, 65),
+        (0, 29),
+        (0, 703),
+        (0, 620),
+        (0, 8731),
+        (0, 0),
+        (0, 136),
+        (0, 190),
+        (0, 4601),
+        (0, 8),
+        (0, 0),
+        (0, 12),
+        (0, 82),
+        (0, 4),
+        (0, 5),
+        (0, 9),
+        (0, 245),
+        (0, 134),
+        (0, 116),
+        (0, 151),
+        (0, 1),
+        (0, 3694),
+        (0, 107),
+        (0, 3),
+        (0, 3),
+        (0, 370),
+        (0, 465),
+        (0, 0),
+        (0, 10),
+        (0, 139),
+        (0, 36),
+        (0, 890),
+        (0, 18),
+        (0, 698),
+        (0, 131),
+        (0, 88),
+        (0, 137),
+        (0, 2),
+        (0, 1330),
+        (0, 0),
+        (0, 22),
+        (0, 0),
+        (0, 99),
+        (0, 23),
+        (0, 102),
+        (0, 1024),
+        (0, 67),
+        (0, 411),
+        (0, 90),
+        (0, 60),
+        (0, 121),
+        (0, 1012),
+        (0, 419),
+        (0, 466),
+        (0, 6),
+        (0, 160),
+        (0, 67),
+        (0, 298),
+        (0, 5),
+        (0, 389),
+        (0, 149),
+        (0, 56),
+        (0, 6),
+        (0, 264),
+        (0, 825),
+        (0, 768),
+        (0, 94),
+        (0, 95),
+        (0, 17),
+        (0, 194),
+        (1, 7),
+        (0, 15),
+        (0, 1),
+        (0, 180),
+        (0, 42),
+        (0, 0),
+        (0, 352),
+        (0, 0),
+        (0, 9),
+        (0, 334),
+        (0, 45),
+        (0, 48),
+        (0, 123),
+        (0, 88),
+        (0, 795),
+        (0, 391),
+        (0, 84),
+        (0, 1),
+        (0, 52),
+        (0, 5),
+        (0, 3253),
+        (0, 324),
+        (0, 0),
+        (0, 90),
+        (0, 404),
+        (0, 85),
+        (0, 137),
+        (0, 0),
+        (0, 271),
+        (0, 1043),
+        (0, 74),
+        (0, 44),
+        (0, 172),
+        (0, 1),
+        (0, 9),
+        (0, 212),
+        (0, 891),
+        (0, 10),
+        (0, 303),
+        (0, 234),
+        (0, 1),
+        (0, 17),
+        (0, 13),
+        (0, 9),
+        (0, 1154),
+        (0, 23),
+        (0, 730),
+        (0, 111),
+        (0, 0),
+        (0, 3),
+        (0, 1),
+        (0, 1012),
+        (0, 95),
+        (0, 202),
+        (0, 1260),
+        (0, 123),
+        (0, 8),
+        (0, 0),
+        (0, 2286),
+        (0, 2),
+        (0, 14),
+        (0, 126),
+        (0, 208),
+        (0, 240),
+        (0, 342),
+        (0, 418),
+        (0, 4195),
+        (0, 17),
+        (0, 2233),
+        (0, 1004),
+        (0, 126),
+        (1, 2),
+        (0, 1330),
+        (0, 0),
+        (0, 154),
+        (0, 9),
+        (0, 411),
+        (0, 2),
+        (0, 1515),
+        (0, 30),
+        (0, 14),
+        (0, 359),
+        (0, 310),
+        (0, 1166),
+        (0, 86),
+        (0, 1139),
+        (0, 15),
+        (0, 42),
+        (0, 16),
+        (0, 23),
+        (0, 46),
+        (0, 62),
+        (0, 21),
+        (0, 120),
+        (0, 306),
+        (0, 449),
+        (0, 654),
+        (0, 45),
+        (0, 87),
+        (0, 0),
+        (0, 105),
+        (0, 67),
+        (0, 2),
+        (0, 471),
+        (0, 306),
+        (0, 936),
+        (0, 35),
+        (0, 1313),
+        (0, 59),
+        (0, 4),
+        (0, 3457),
+        (0, 121),
+        (0, 314),
+        (0, 16),
+        (0, 2425),
+        (0, 14),
+        (0, 1105),
+        (0, 73),
+        (0, 543),
+        (0, 3113),
+        (0, 278),
+        (0, 0),
+        (0, 22),
+        (0, 9),
+        (0, 59),
+        (0, 13),
+        (0, 616),
+        (0, 2163),
+        (0, 2),
+        (0, 14),
+        (0, 3),
+        (0, 2876),
+        (0, 3),
+        (0, 42),
+        (0, 24),
+        (0, 40),
+        (0, 188),
+        (0, 36),
+        (0, 592),
+        (0, 11),
+        (0, 88),
+        (0, 455),
+        (0, 354),
+        (0, 284),
+        (0, 7),
+        (0, 626),
+        (0, 62),
+        (0, 1),
+        (0, 6),
+        (0, 0),
+        (0, 1),
+        (0, 23),
+        (0, 352),
+        (0, 907),
+        (0, 1228),
+        (0, 4),
+        (0, 2978),
+        (0, 10),
+        (0, 2330),
+        (0, 1194),
+        (0, 238),
+        (0, 1399),
+        (0, 152),
+        (0, 256),
+        (0, 7),
+        (0, 1354),
+        (0, 1),
+        (0, 0),
+        (0, 555),
+        (0, 3),
+        (0, 3),
+        (0, 200),
+        (0, 4),
+        (0, 1),
+        (0, 691),
+        (0, 349),
+        (0, 1731),
+        (0, 926),
+        (0, 0),
+        (0, 991),
+        (0, 1416),
+        (0, 56),
+        (0, 5),
+        (0, 2593),
+        (0, 149),
+        (0, 68),
+        (0, 121),
+        (0, 1853),
+        (0, 236),
+        (0, 0),
+        (0, 46),
+        (0, 42),
+        (0, 2681),
+        (0, 699),
+        (0, 1931),
+        (0, 58),
+        (0, 380),
+        (0, 0),
+        (0, 9),
+        (0, 38),
+        (0, 156),
+        (0, 433),
+        (0, 3659),
+        (0, 379),
+        (0, 601),
+        (0, 352),
+        (0, 204),
+        (0, 265),
+        (0, 372),
+        (0, 233),
+        (0, 26),
+        (0, 29),
+        (0, 24),
+        (0, 24),
+        (0, 255),
+        (0, 26),
+        (0, 9),
+        (0, 262),
+        (0, 129),
+        (0, 15),
+        (0, 81),
+        (0, 7),
+        (0, 692),
+        (0, 126),
+        (0, 3),
+        (0, 12),
+        (0, 191),
+        (0, 1610),
+        (0, 3962),
+        (0, 112),
+        (0, 234),
+        (0, 0),
+        (0, 1),
+        (0, 305),
+        (0, 170),
+        (0, 5),
+        (0, 1339),
+        (0, 146),
+        (0, 749),
+        (0, 373),
+        (0, 0),
+        (0, 1),
+        (0, 1065),
+        (0, 1632),
+        (0, 14),
+        (0, 251),
+        (0, 19),
+        (0, 902),
+        (0, 160),
+        (0, 187),
+        (0, 4691),
+        (0, 179),
+        (0, 368),
+        (0, 102),
+        (0, 754),
+        (0, 2028),
+        (0, 574),
+        (0, 14),
+        (0, 452),
+        (0, 18),
+        (0, 3),
+        (0, 413),
+        (0, 0),
+        (0, 69),
+        (0, 131),
+        (0, 107),
+        (0, 7),
+        (0, 966),
+        (0, 682),
+        (0, 31),
+        (0, 0),
+        (0, 1863),
+        (0, 626),
+        (0, 1451),
+        (0, 2),
+        (0, 10),
+        (0, 2862),
+        (0, 106),
+        (0, 3924),
+        (0, 1577),
+        (0, 5),
+        (0, 1038),
+        (0, 9),
+        (0, 24),
+        (0, 190),
+        (0, 236),
+        (0, 48),
+        (0, 758),
+        (0, 1152),
+        (0, 3625),
+        (0, 7104),
+        (0, 162),
+        (0, 22),
+        (0, 2314),
+        (0, 433),
+        (0, 542),
+        (0, 47),
+        (0, 7),
+        (0, 32),
+        (0, 1),
+        (0, 187),
+        (0, 303),
+        (0, 108),
+        (0, 1589),
+        (0, 173),
+        (0, 1329),
+        (0, 1351),
+        (0, 32),
+        (0, 84),
+        (0, 76),
+        (0, 963),
+        (0, 118),
+        (0, 1),
+        (0, 0),
+        (0, 162),
+        (0, 376),
+        (0, 255),
+        (0, 43),
+        (0, 404),
+        (0, 6),
+        (0, 836),
+        (0, 360),
+        (0, 270),
+        (0, 1),
+        (0, 72),
+        (0, 208),
+        (0, 333),
+        (0, 1456),
+        (0, 65),
+        (0, 6),
+        (0, 36),
+        (0, 2),
+        (0, 873),
+        (0, 1277),
+        (0, 5),
+        (0, 2328),
+        (0, 0),
+        (0, 52),
+        (0, 1),
+        (0, 33),
+        (0, 472),
+        (0, 52),
+        (0, 0),
+        (0, 1627),
+        (0, 413),
+        (0, 24),
+        (0, 102),
+        (0, 181),
+        (0, 578),
+        (0, 1008),
+        (0, 21),
+        (0, 1089),
+        (0, 1),
+        (0, 210),
+        (0, 1305),
+        (0, 693),
+        (0, 1),
+        (0, 2847),
+        (0, 747),
+        (0, 40),
+        (0, 845),
+        (0, 105),
+        (0, 48),
+        (0, 49),
+        (0, 150),
+        (0, 1),
+        (0, 1849),
+        (0, 104),
+        (0, 5),
+        (0, 3),
+        (0, 24),
+        (0, 77),
+        (0, 2),
+        (0, 2),
+        (0, 1287),
+        (0, 1),
+        (0, 4855),
+        (0, 614),
+        (0, 828),
+        (0, 1),
+        (0, 28),
+        (0, 16),
+        (0, 90),
+        (0, 156),
+        (0, 11),
+        (0, 3347),
+        (0, 260),
+        (0, 17),
+        (0, 99),
+        (0, 3),
+        (0, 5213),
+        (0, 10),
+        (0, 12),
+        (0, 399),
+        (0, 533),
+        (0, 252),
+        (0, 562),
+        (0, 2796),
+        (0, 267),
+        (0, 244),
+        (0, 2542),
+        (0, 10),
+        (0, 2001),
+        (0, 7463),
+        (0, 8),
+        (0, 507),
+        (0, 230),
+        (0, 18),
+        (0, 127),
+        (0, 825),
+        (0, 11),
+        (0, 913),
+        (0, 719),
+        (0, 319),
+        (0, 0),
+        (0, 542),
+        (0, 8),
+        (0, 335),
+        (0, 181),
+        (0, 136),
+        (0, 1),
+        (0, 1501),
+        (0, 17),
+        (0, 1),
+        (0, 49),
+        (0, 5),
+        (0, 47),
+        (0, 5644),
+        (0, 4007),
+        (0, 275),
+        (0, 5),
+        (0, 63),
+        (0, 749),
+        (0, 410),
+        (0, 16),
+        (0, 101),
+        (0, 7),
+        (0, 3),
+        (0, 4),
+        (0, 30),
+        (0, 1),
+        (0, 98),
+        (0, 20),
+        (0, 1983),
+        (0, 78),
+        (0, 159),
+        (0, 25),
+        (0, 63),
+        (0, 1037),
+        (0, 2),
+        (0, 178),
+        (0, 1533),
+        (0, 105),
+        (0, 355),
+        (0, 127),
+        (0, 2569),
+        (0, 352),
+        (0, 29),
+        (0, 8),
+        (0, 118),
+        (0, 1609),
+        (0, 7),
+        (0, 0),
+        (0, 313),
+        (0, 146),
+        (0, 1943),
+        (0, 279),
+        (0, 1012),
+        (0, 23),
+        (0, 2336),
+        (0, 44),
+        (0, 0),
+        (0, 2594),
+        (0, 513),
+        (0, 1),
+        (0, 37),
+        (0, 5244),
+        (0, 1078),
+        (0, 4),
+        (0, 48),
+        (0, 395),
+        (0, 886),
+        (0, 43),
+        (0, 1592),
+        (0, 578),
+        (0, 880),
+        (0, 848),
+        (0, 3326),
+        (0, 3868),
+        (0, 425),
+        (0, 2338),
+        (0, 11),
+        (0, 0),
+        (0, 5),
+        (0, 117),
+        (0, 53),
+        (0, 29),
+        (0, 200),
+        (0, 4),
+        (0, 64),
+        (0, 135),
+        (0, 227),
+        (0, 23),
+        (0, 16),
+        (0, 4922),
+        (0, 1150),
+        (0, 4839),
+        (0, 500),
+        (0, 698),
+        (0, 1236),
+        (0, 9),
+        (0, 5349),
+        (0, 389),
+        (0, 101),
+        (0, 670),
+        (0, 1),
+        (0, 5729),
+        (0, 0),
+        (0, 0),
+        (0, 1278),
+        (0, 24),
+        (0, 128),
+        (0, 2700),
+        (0, 0),
+        (0, 544),
+        (0, 879),
+        (0, 64),
+        (0, 2137),
+        (0, 381),
+        (0, 108),
+        (0, 0),
+        (0, 3),
+        (0, 2877),
+        (0, 10),
+        (0, 147),
+        (0, 1936),
+        (0, 78),
+        (0, 0),
+        (0, 30),
+        (0, 2),
+        (0, 59),
+        (1, 154),
+        (0, 4025),
+        (0, 3164),
+        (0, 2),
+        (0, 2429),
+        (0, 56),
+        (0, 0),
+        (0, 16),
+        (0, 4852),
+        (0, 35),
+        (0, 60),
+        (0, 62),
+        (0, 610),
+        (0, 2341),
+        (0, 2),
+        (0, 19),
+        (0, 24),
+        (0, 33),
+        (0, 0),
+        (0, 11),
+        (0, 18),
+        (0, 2429),
+        (0, 3179),
+        (0, 835),
+        (0, 633),
+        (0, 1),
+        (0, 2),
+        (0, 877),
+        (0, 14),
+        (0, 12),
+        (0, 1357),
+        (0, 499),
+        (0, 111),
+        (0, 6),
+        (0, 743),
+        (0, 4),
+        (0, 4081),
+        (0, 540),
+        (0, 3),
+        (0, 3810),
+        (0, 4015),
+        (0, 270),
+        (0, 688),
+        (0, 2),
+        (0, 1427),
+        (0, 337),
+        (0, 373),
+        (0, 346),
+        (0, 1002),
+        (1, 0),
+        (0, 3196),
+        (0, 81),
+        (0, 4),
+        (0, 1),
+        (0, 21),
+        (0, 2588),
+        (0, 1172),
+        (0, 2093),
+        (0, 777),
+        (0, 101),
+        (0, 1120),
+        (0, 64),
+        (0, 1),
+        (0, 152),
+        (0, 3),
+        (0, 113),
+        (0, 3002),
+        (0, 1229),
+        (0, 3363),
+        (0, 568),
+        (0, 0),
+        (0, 8),
+        (0, 2519),
+        (0, 173),
+        (0, 1724),
+        (0, 96),
+        (0, 2345),
+        (0, 490),
+        (0, 4476),
+        (0, 5),
+        (0, 6),
+        (0, 0),
+        (0, 3213),
+        (0, 73),
+        (0, 1889),
+        (0, 577),
+        (0, 28),
+        (0, 142),
+        (0, 346),
+        (0, 85),
+        (0, 1706),
+        (0, 3023),
+        (0, 89),
+        (0, 1385),
+        (0, 1187),
+        (0, 928),
+        (0, 2019),
+        (0, 162),
+        (0, 1),
+        (0, 38),
+        (0, 3149),
+        (0, 7498),
+        (0, 140),
+        (0, 2),
+        (0, 2),
+        (0, 2881),
+        (0, 139),
+        (0, 3),
+        (0, 1),
+        (0, 2214),
+        (0, 14),
+        (0, 455),
+        (0, 4790),
+        (0, 3),
+        (0, 1),
+        (0, 339),
+        (0, 545),
+        (0, 42),
+        (0, 163),
+        (0, 414),
+        (1, 3017),
+        (0, 68),
+        (0, 85),
+        (0, 3),
+        (0, 70),
+        (0, 7186),
+        (0, 3411),
+        (0, 4009),
+        (0, 1035),
+        (0, 36),
+        (0, 3262),
+        (0, 13),
+        (0, 5),
+        (0, 4131),
+        (0, 17),
+        (0, 30),
+        (0, 20),
+        (0, 6),
+        (0, 2),
+        (0, 5083),
+        (0, 1144),
+        (0, 10),
+        (0, 15),
+        (0, 281),
+        (0, 583),
+        (0, 26),
+        (0, 1153),
+        (0, 3),
+        (0, 3947),
+        (0, 4111),
+        (0, 23),
+        (0, 523),
+        (0, 0),
+        (0, 169),
+        (0, 6564),
+        (0, 117),
+        (0, 29),
+        (0, 2748),
+        (0, 4792),
+        (0, 155),
+        (0, 726),
+        (0, 21),
+        (0, 980),
+        (0, 138),
+        (0, 11),
+        (0, 84),
+        (0, 1396),
+        (0, 1320),
+        (0, 0),
+        (0, 597),
+        (0, 1),
+        (0, 200),
+        (0, 3078),
+        (0, 1),
+        (0, 79),
+        (0, 5515),
+        (0, 90),
+        (0, 2558),
+        (0, 3086),
+        (0, 6998),
+        (0, 14),
+        (1, 2248),
+        (0, 29),
+        (0, 103),
+        (0, 142),
+        (0, 3029),
+        (0, 65),
+        (0, 2676),
+        (0, 1796),
+        (0, 241),
+        (0, 391),
+        (0, 500),
+        (0, 7702),
+        (0, 8),
+        (0, 2350),
+        (0, 1068),
+        (0, 1524),
+        (0, 16),
+        (0, 3463),
+        (0, 0),
+        (0, 74),
+        (0, 1848),
+        (0, 110),
+        (0, 5105),
+        (0, 879),
+        (1, 7892),
+        (0, 847),
+        (0, 16),
+        (0, 1936),
+        (0, 1881),
+        (0, 2536),
+        (0, 22),
+        (0, 127),
+        (0, 5),
+        (0, 2116),
+        (0, 3535),
+        (0, 2),
+        (0, 43),
+        (0, 26),
+        (0, 734),
+        (0, 1998),
+        (0, 218),
+        (0, 87),
+        (1, 1533),
+        (0, 1183),
+        (0, 375),
+        (0, 6879),
+        (0, 3),
+        (0, 180),
+        (0, 3206),
+        (0, 20),
+        (0, 324),
+        (0, 454),
+        (0, 10433),
+        (0, 0),
+        (0, 859),
+        (0, 2),
+        (0, 0),
+        (0, 711),
+        (0, 429),
+        (0, 3999),
+        (0, 2014),
+        (1, 4906),
+        (0, 22),
+        (0, 15),
+        (0, 476),
+        (0, 310),
+        (0, 37),
+        (0, 10),
+        (0, 112),
+        (0, 651),
+        (0, 8657),
+        (0, 41),
+        (1, 311),
+        (0, 1995),
+        (0, 497),
+        (0, 776),
+        (0, 2497),
+        (0, 182),
+        (0, 41),
+        (0, 2),
+        (0, 354),
+        (0, 6),
+        (0, 2832),
+        (0, 1384),
+        (0, 21),
+        (0, 6293),
+        (0, 76),
+        (0, 40),
+        (0, 8),
+        (0, 4683),
+        (0, 123),
+        (0, 360),
+        (0, 1254),
+        (0, 459),
+        (0, 4966),
+        (0, 64),
+        (0, 182),
+        (0, 485),
+        (0, 3),
+        (0, 3463),
+        (0, 6212),
+        (0, 4142),
+        (0, 3),
+        (0, 5860),
+        (0, 19),
+        (0, 2540),
+        (0, 230),
+        (0, 686),
+        (0, 2406),
+        (0, 106),
+        (0, 1511),
+        (0, 120),
+        (0, 5500),
+        (1, 3657),
+        (0, 791),
+        (0, 2),
+        (0, 228),
+        (0, 127),
+        (0, 1),
+        (0, 15),
+        (0, 291),
+        (0, 4440),
+        (0, 4353),
+        (0, 4848),
+        (0, 669),
+        (0, 0),
+        (0, 53),
+        (0, 242),
+        (0, 70),
+        (0, 2622),
+        (0, 9),
+        (0, 1439),
+        (0, 1171),
+        (0, 14),
+        (0, 15),
+        (0, 33),
+        (0, 1466),
+        (0, 42),
+        (0, 89),
+        (0, 4),
+        (0, 19),
+        (0, 0),
+        (0, 276),
+        (0, 1),
+        (0, 7),
+        (0, 1151),
+        (0, 3604),
+        (0, 22),
+        (0, 2912),
+        (0, 2734),
+        (0, 1589),
+        (0, 6709),
+        (0, 8),
+        (0, 236),
+        (0, 79),
+        (0, 93),
+        (0, 712),
+        (0, 4),
+        (0, 1),
+        (0, 8),
+        (0, 2888),
+        (0, 428),
+        (0, 505),
+        (0, 680),
+        (0, 550),
+        (0, 10),
+        (0, 2086),
+        (0, 5886),
+        (0, 0),
+        (0, 4067),
+        (0, 6035),
+        (0, 1323),
+        (0, 34),
+        (0, 2809),
+        (0, 2891),
+        (0, 36),
+        (1, 634),
+        (0, 14),
+        (0, 43),
+        (0, 164),
+        (0, 325),
+        (0, 108),
+        (0, 1681),
+        (0, 203),
+        (0, 0),
+        (0, 3491),
+        (0, 667),
+        (0, 5),
+        (0, 0),
+        (0, 43),
+        (0, 1355),
+        (0, 2303),
+        (0, 10365),
+        (0, 0),
+        (0, 1121),
+        (0, 43),
+        (0, 658),
+        (0, 700),
+        (0, 55),
+        (0, 10062),
+        (0, 12),
+        (0, 4042),
+        (0, 7700),
+        (0, 65),
+        (0, 4885),
+        (0, 2949),
+        (0, 260),
+        (0, 115),
+        (0, 5083),
+        (0, 3),
+        (0, 906),
+        (0, 390),
+        (0, 25),
+        (0, 70),
+        (0, 7),
+        (1, 4175),
+        (0, 69),
+        (1, 3546),
+        (0, 1176),
+        (1, 9975),
+        (0, 5),
+        (0, 9),
+        (0, 77),
+        (0, 2689),
+        (0, 932),
+        (0, 342),
+        (0, 895),
+        (0, 66),
+        (0, 18),
+        (0, 426),
+        (0, 630),
+        (0, 685),
+        (0, 1672),
+        (0, 856),
+        (1, 10116),
+        (0, 146),
+        (0, 7),
+        (0, 2127),
+        (0, 1047),
+        (0, 96),
+        (0, 1568),
+        (0, 47),
+        (0, 285),
+        (0, 370),
+        (0, 5695),
+        (0, 94),
+        (0, 35),
+        (1, 3557),
+        (0, 21),
+        (0, 105),
+        (0, 232),
+        (0, 0),
+        (1, 3207),
+        (0, 42),
+        (1, 2132),
+        (0, 1154),
+        (0, 1605),
+        (0, 2659),
+        (0, 27),
+        (0, 13),
+        (0, 1467),
+        (0, 4166),
+        (0, 2217),
+        (0, 8),
+        (0, 6586),
+        (0, 11),
+        (0, 1581),
+        (0, 37),
+        (0, 149),
+        (0, 2971),
+        (0, 54),
+        (0, 116),
+        (0, 1),
+        (0, 71),
+        (0, 4430),
+        (0, 1140),
+        (0, 283),
+        (0, 737),
+        (0, 3),
+        (1, 8649),
+        (0, 4400),
+        (0, 420),
+        (0, 2),
+        (0, 553),
+        (0, 261),
+        (0, 462),
+        (0, 0),
+        (0, 42),
+        (0, 4),
+        (0, 262),
+        (0, 278),
+        (3, 16),
+        (0, 2302),
+        (0, 0),
+        (0, 165),
+        (0, 132),
+        (0, 190),
+        (0, 101),
+        (0, 1298),
+        (0, 9860),
+        (0, 6597),
+        (0, 81),
+        (0, 287),
+        (0, 2183),
+        (0, 3716),
+        (0, 1786),
+        (0, 23),
+        (0, 4076),
+        (0, 21),
+        (0, 450),
+        (1, 4567),
+        (0, 3491),
+        (0, 6745),
+        (0, 26),
+        (0, 6159),
+        (0, 575),
+        (0, 18),
+        (1, 1491),
+        (0, 0),
+        (0, 2607),
+        (1, 5512),
+        (0, 128),
+        (0, 167),
+        (0, 14),
+        (0, 0),
+        (0, 1613),
+        (0, 0),
+        (0, 96),
+        (0, 506),
+        (0, 1),
+        (0, 59),
+        (0, 706),
+        (0, 37),
+        (0, 2212),
+        (0, 76),
+        (0, 3529),
+        (0, 149),
+        (0, 5313),
+        (0, 401),
+        (0, 30),
+        (0, 132),
+        (0, 7),
+        (0, 961),
+        (0, 67),
+        (0, 991),
+        (0, 1049),
+        (0, 8002),
+        (0, 484),
+        (0, 431),
+        (2, 5451),
+        (1, 1262),
+        (0, 0),
+        (0, 595),
+        (0, 364),
+        (0, 42),
+        (0, 362),
+        (0, 6262),
+        (0, 2770),
+        (0, 539),
+        (0, 1),
+        (0, 1995),
+        (0, 4),
+        (0, 59),
+        (0, 21),
+        (0, 12),
+        (0, 2991),
+        (0, 2614),
+        (0, 1193),
+        (0, 462),
+        (0, 4180),
+        (0, 5),
+        (0, 33),
+        (0, 9208),
+        (0, 70),
+        (0, 20),
+        (0, 1715),
+        (0, 3930),
+        (0, 7690),
+        (0, 1081),
+        (0, 4750),
+        (0, 330),
+        (0, 1926),
+        (0, 85),
+        (0, 9),
+        (0, 1585),
+        (0, 4403),
+        (0, 148),
+        (0, 711),
+        (0, 1797),
+        (0, 74),
+        (0, 438),
+        (0, 71),
+        (0, 5),
+        (0, 1),
+        (1, 114),
+        (0, 6073),
+        (0, 2163),
+        (0, 20),
+        (0, 407),
+        (0, 4876),
+        (0, 0),
+        (0, 189),
+        (0, 78),
+        (0, 1677),
+        (0, 1509),
+        (0, 6554),
+        (0, 618),
+        (0, 52),
+        (0, 1559),
+        (0, 7175),
+        (2, 1940),
+        (0, 52),
+        (3, 5176),
+        (0, 7187),
+        (0, 141),
+        (0, 20),
+        (1, 6079),
+        (0, 396),
+        (0, 1264),
+        (0, 29),
+        (0, 2348),
+        (0, 7161),
+        (0, 1800),
+        (0, 19),
+        (0, 579),
+        (0, 7674),
+        (0, 431),
+        (0, 3508),
+        (0, 8126),
+        (0, 0),
+        (0, 6),
+        (0, 2470),
+        (0, 1689),
+        (0, 0),
+        (0, 56),
+        (0, 2),
+        (0, 19),
+        (0, 340),
+        (0, 464),
+        (0, 2),
+        (0, 3721),
+        (0, 3810),
+        (0, 2813),
+        (0, 8518),
+        (0, 9036),
+        (0, 5817),
+        (0, 487),
+        (0, 379),
+        (0, 465),
+        (0, 89),
+        (0, 174),
+        (2, 339),
+        (0, 431),
+        (0, 0),
+        (0, 11),
+        (0, 232),
+        (0, 157),
+        (0, 70),
+        (0, 18),
+        (0, 141),
+        (0, 111),
+        (0, 50),
+        (0, 7214),
+        (0, 4),
+        (0, 17),
+        (0, 9828),
+        (0, 5),
+        (0, 669),
+        (0, 3151),
+        (0, 21),
+        (0, 886),
+        (0, 1),
+        (0, 1245),
+        (0, 0),
+        (0, 576),
+        (0, 1138),
+        (0, 0),
+        (1, 4345),
+        (0, 2108),
+        (0, 1999),
+        (0, 3688),
+        (0, 5312),
+        (0, 921),
+        (0, 4),
+        (0, 165),
+        (0, 3),
+        (0, 1515),
+        (0, 689),
+        (0, 1123),
+        (0, 933),
+        (0, 1853),
+        (0, 0),
+        (0, 5825),
+        (0, 5),
+        (0, 3),
+        (0, 60),
+        (0, 7234),
+        (0, 91),
+        (0, 556),
+        (0, 13),
+        (0, 1516),
+        (0, 1194),
+        (0, 0),
+        (0, 0),
+        (0, 1960),
+        (0, 167),
+        (0, 252),
+        (0, 2706),
+        (0, 5),
+        (0, 221),
+        (0, 10423),
+        (0, 70),
+        (0, 1746),
+        (0, 99),
+        (1, 2643),
+        (0, 21),
+        (0, 1),
+        (0, 1571),
+        (0, 84),
+        (0, 3931),
+        (0, 0),
+        (0, 19),
+        (0, 5103),
+        (0, 49),
+        (0, 2),
+        (0, 8450),
+        (0, 17),
+        (0, 0),
+        (0, 137),
+        (0, 214),
+    ],
+    entries: &[
+        (15602, "圓桌之弓"),
+        (25632, "白橡木復合弓"),
+        (18569, "石青禦敵手鐲"),
+        (21924, "白虎裝甲"),
+        (9738, "抹茶組合"),
+        (11028, "皇金精准耳墜"),
+        (26290, "矮人銀禦敵短靴"),
+        (27156, "愈瘡木紡車"),
+        (31822, "總督法杖"),
+        (9305, "徵糧工作褲"),
+        (31215, "第三期重建用的藥湯"),
+        (33276, "磚塊櫃台"),
+        (1604, "梣木石刃棒"),
+        (41515, "白色魯冰花頭花"),
+        (33284, "盧恩置物架"),
+        (42879, "舊日王國小刀"),
+        (3064, "新來者工作坎肩"),
+        (6453, "綠洲石制圍牆"),
+        (35452, "鐵木巧匠耳墜"),
+        (21941, "阿拉米格長外衣"),
+        (26299, "矮人銀強襲鏈甲"),
+        (16674, "星絨治愈打底褲"),
+        (17984, "寶石獸綠雕"),
+        (5064, "白銀錠"),
+        (42201, "粉綠柱石禦敵耳墜"),
+        (26023, "青金石禦敵指環"),
+        (4442, "日長石戒指"),
+        (27877, "海鮮燴飯"),
+        (13041, "錦衣大師長褲"),
+        (34414, "賽加羚羊革強襲厚底鞋"),
+        (29437, "伊修加德新型強襲打底褲"),
+        (27148, "矮人銀鶴嘴鋤"),
+        (34448, "鉍金治愈手環"),
+        (39668, "繼業者精准護膝褲"),
+        (44413, "探求永恆圓頭錘"),
+        (42312, "白黃金花劍"),
+        (23104, "白色大麗花頭花"),
+        (36048, "桓娑鳥肉咖喱"),
+        (43312, "犎牛嗶嘰大地軟甲褲"),
+        (31829, "總督輪刃"),
+        (41863, "混金制敵頭甲"),
+        (48505, "長期保存用的纖維"),
+        (10665, "靈銀雙劍機關盾"),
+        (28824, "空賊精准坎肩（複製品）"),
+        (10858, "獅鷲革游擊手套"),
+        (11066, "靈銀詠咒手鐲"),
+        (10873, "古鳥革游擊短靴"),
+        (2116, "血角長杖"),
+        (30479, "唯美大地手環"),
+        (45981, "3級耐力之寶水"),
+        (47917, "霓虹街頭工裝褲"),
+        (19837, "暮暉牛肉湯"),
+        (18997, "鈀金強襲頭冠"),
+        (27712, "鈦銅塊"),
+        (48437, "急救用的月面仙人刺革"),
+        (48495, "改良設備用的窗簾"),
+        (34543, "錳鋼打刀"),
+        (7974, "傘葉無花果盆栽"),
+        (38542, "夢羽妖靈王利爪"),
+        (16638, "半熊革魔導典"),
+        (25701, "島狼治愈手鐲"),
+        (4351, "白珍珠項環"),
+        (28590, "叛逆外套"),
+        (18260, "白銅治愈手鐲"),
+        (46028, "儀仗振空擺"),
+        (2496, "黑鐵平底鍋"),
+        (8377, "厚土牧杖"),
+        (42345, "白黃金治愈眼罩"),
+        (19834, "豆腐味噌汁"),
+        (29809, "第二期重建用的手錘"),
+        (31065, "收藏用皇金之書"),
+        (4306, "獸骨項鍊"),
+        (42939, "舊日王國精准手環"),
+        (25966, "靈青禦敵頭盔"),
+        (18254, "白銅精准項環"),
+        (3235, "煉金大衣"),
+        (13016, "珍木大師工作褲"),
+        (4754, "農家奶酪"),
+        (2889, "鈷鐵頭盔"),
+        (22890, "夜鋼蒸餾器"),
+        (34537, "錳鋼明火槍"),
+        (13677, "空賊詠咒長衣"),
+        (7136, "鋪瓦內牆"),
+        (12083, "羅波那裝甲"),
+        (3664, "野豬革雷華手箍"),
+        (34230, "輝翠銀長劍"),
+        (3205, "纖維長外衣"),
+        (24175, "紅色馬蹄蓮頭花"),
+        (34562, "錳鋼升龍手套"),
+        (39634, "繼業者復合弓"),
+        (26152, "纏尾蛟革游擊半指護手"),
+        (42870, "舊日王國寬刃劍"),
+        (8668, "鈷鐵短刀"),
+        (5072, "黑鐵板"),
+        (15971, "蒼穹騎士圓桌"),
+        (16717, "巧力之強化仙藥"),
+        (45974, "破布木木材"),
+        (29443, "伊修加德新型精准打底褲"),
+        (4390, "玫瑰金衝鋒項環"),
+        (26159, "力山羊治愈短裙"),
+        (31922, "第四期重建用的鐵釘"),
+        (46012, "儀仗巨斧"),
+        (25685, "白麻詠咒騎兵褲"),
+        (35076, "古典風格強攻耳墜"),
+        (29420, "伊修加德新型圓月輪"),
+        (22887, "夜鋼工藝錘"),
+        (15558, "驚雷風暴"),
+        (10102, "項圈鈴"),
+        (6551, "綠洲衣櫃"),
+        (9291, "駿鷹眼罩"),
+        (7102, "對龍城塞作戰桌"),
+        (17332, "空鼠治愈格鬥服"),
+        (10741, "聖虹制敵垮褲"),
+        (20703, "東洋描花門"),
+        (27794, "2級智力之幻水"),
+        (13000, "騎士游擊外套"),
+        (48604, "基地麵包籃"),
+        (20395, "硬銀花劍"),
+        (3479, "民兵三角褲"),
+        (39779, "面向收藏家的莫古力綠雕"),
+        (27078, "深金橫頭錘"),
+        (18664, "鉬鋼火彈"),
+        (18716, "鉬鋼強攻耳墜"),
+        (43910, "迎曦之民樣式的裝幀部件"),
+        (35868, "開心披肩鬥篷"),
+        (3106, "棉絨短袍"),
+        (4472, "橄欖石戒指"),
+        (8783, "淋浴器"),
+        (44435, "探求永恆巧匠工作褲"),
+        (34599, "藍鋯石詠咒手環"),
+        (14134, "果香特飲"),
+        (26154, "纏尾蛟革游擊戰靴"),
+        (27902, "管弦樂琴樂譜：精神錯亂"),
+        (47225, "月球車的維修零件"),
+        (47593, "宇宙探索用的月凝膠"),
+        (34453, "鉍金治愈指環"),
+        (16627, "重合金長劍"),
+        (18660, "鉬鋼三尖槍"),
+        (3835, "白鋼鱗脛甲"),
+        (42048, "河岸石強攻耳墜"),
+        (9331, "煙肉麥穗麵包"),
+        (3843, "野豬革大地尖頭靴"),
+        (27653, "黑鐵頭甲"),
+        (3946, "絹布尖頭靴"),
+        (24277, "僧厥鑲茄子"),
+        (48451, "統一規格的宇宙皮革"),
+        (31047, "收藏用聖虹布草帽"),
+        (43286, "銀狼革半指護手"),
+        (10876, "白鈦游擊鎧靴"),
+        (18733, "鈀金治愈盔甲戒指"),
+        (44099, "圖拉爾菠蘿蛋糕"),
+        (6630, "海濱櫃台"),
+        (43976, "圖拉爾玉米油"),
+        (3201, "纖維束腰獵衣"),
+        (1942, "蟹甲戰弓"),
+        (7069, "革匠工作台"),
+        (6564, "冥鬼櫥櫃"),
+        (3849, "亞麻高跟鞋"),
+        (8117, "青磷水箱元件"),
+        (8104, "空氣動力元件"),
+        (8371, "旋風神願"),
+        (18514, "玉鋼太刀"),
+        (7137, "金屬內牆"),
+        (20473, "道士長褲"),
+        (6678, "林間花簇地毯"),
+        (7807, "終結油灰"),
+        (19655, "玉綢七分褲"),
+        (35427, "絳紅草布巧匠打底褲"),
+        (39635, "繼業者左手劍"),
+        (32233, "玩偶展示櫃"),
+        (6572, "魔界花書櫃"),
+        (48412, "宇宙探索用的縫針"),
+        (31033, "收藏用長頸駝革外套"),
+        (6353, "木制海濱別墅房頂"),
+        (10100, "硅化木寶珠"),
+        (3182, "古典總管短上衣"),
+        (38673, "萊韋耶勒爾吊燈"),
+        (20748, "現代鎧甲"),
+        (28854, "空鼠強襲格鬥服（複製品）"),
+        (15736, "剪裁機部件"),
+        (48114, "木框隔離牆"),
+        (48470, "改良設備用的花簇地毯"),
+        (5285, "盜龍革"),
+        (4361, "綠金衝鋒項環"),
+        (4304, "鞣革項環"),
+        (4860, "黑果醋"),
+        (15586, "偃武戰斧"),
+        (4559, "聖靈藥"),
+        (18680, "瞪羚革制敵護甲"),
+        (35765, "靈青奪命鐮"),
+        (18371, "卸鐵制敵脛甲"),
+        (6979, "高地罩衫"),
+        (8805, "壁燈"),
+        (31857, "總督游擊護臂"),
+        (13042, "錦衣大師矮靴"),
+        (31104, "改良用白銀塊"),
+        (42158, "鈷鎢刺劍"),
+        (18379, "猛虎革精准寬邊帽"),
+        (26003, "野蠻盜龍革詠咒大衣"),
+        (31159, "酒桶的部件"),
+        (35778, "石金蛇石針"),
+        (19888, "耐力之藥湯"),
+        (44429, "探求永恆廚刀"),
+        (28160, "蔬菜園"),
+        (27127, "鈦銅鶴嘴鋤"),
+        (16684, "閃爍強攻耳墜"),
+        (40404, "樸素寢室眼罩"),
+        (23874, "羅蘭莓撻"),
+        (35838, "黃金聯結零件"),
+        (12567, "獅鷲革"),
+        (19751, "櫸木戒指"),
+        (39644, "繼業者小劍"),
+        (4105, "白銀巧匠手鍊"),
+        (41261, "面向收藏家的炸蟹餅"),
+        (48600, "長期保存用的果凍飲料"),
+        (10936, "古鳥革詠咒手套"),
+        (3125, "亞麻襯衫"),
+        (13641, "空賊禦敵外套"),
+        (32227, "手工餅乾組合"),
+        (4717, "石子湯"),
+        (46085, "儀仗治愈指環"),
+        (8855, "1級精煉以太轉輪"),
+        (36077, "裸麥粉"),
+        (34688, "完滿木魔導牧杖"),
+        (21739, "地懶革游擊高筒靴"),
+        (18518, "玉鋼禦敵罩衣"),
+        (21738, "真麻游擊馬褲"),
+        (14135, "蘋果卷"),
+        (11069, "蛋白石詠咒手鐲"),
+        (3229, "木匠長外衣"),
+        (22554, "梯子"),
+        (3766, "鞣革筒靴"),
+        (42360, "白黃金禦敵項環"),
+        (12214, "1級游戲以太轉輪"),
+        (27215, "魔銀指環"),
+        (6440, "海濱懸掛門牌"),
+        (2367, "青銅鑄甲錘"),
+        (8807, "馬茲拉雅槍魚標本"),
+        (27229, "愈瘡木手鐲"),
+        (7511, "羊絨詠咒長袍"),
+        (15557, "驚雷萬鈞"),
+        (15825, "管弦樂琴樂譜：蒼翼"),
+        (7484, "鎢鋼重鎧靴"),
+        (28742, "重建用的錘子"),
+        (44202, "收藏用卡扎納爾指環"),
+        (43648, "鐵木橢圓筆"),
+        (34737, "星點石英精准耳墜"),
+        (14245, "管弦樂琴樂譜：異想天開"),
+        (27885, "新薯沙拉"),
+        (19836, "豬肉醬湯"),
+        (47097, "宇宙探索用的鍛冶工作台"),
+        (42930, "舊日王國治愈耳墜"),
+        (35440, "迷彩絨大地短衣"),
+        (12082, "俾斯麥裝甲"),
+        (34706, "球粒隕石制敵上裝"),
+        (13876, "亞拉戈高位詠咒馬褲（複製品）"),
+        (11878, "精金橫頭錘"),
+        (18688, "瞪羚革強襲襯褲"),
+        (22743, "常用菜刀的部件"),
+        (18248, "白銅強攻耳墜"),
+        (35356, "鉍金平斬"),
+        (13893, "龍神禦敵戰甲（複製品）"),
+        (35840, "有型的文具套裝"),
+        (5487, "蒸餾水"),
+        (10810, "古鳥革精准手套"),
+        (48618, "宇宙探索用的綠滴糖"),
+        (31652, "收藏用愈瘡木砂輪機"),
+        (1697, "蟾蜍鋼拳"),
+        (43216, "五加木紡車"),
+        (22922, "地懶革大地項鍊"),
+        (18351, "卸鐵巨劍"),
+        (37843, "7級智力之幻藥"),
+        (5336, "亞麻線"),
+        (9062, "白金治愈頭飾"),
+        (15427, "邪龍裝甲"),
+        (10855, "白鈦游擊臂甲"),
+        (13090, "多瑪芒草"),
+        (18515, "翠銀花劍"),
+        (27300, "妖靈王緹坦妮雅的掛件"),
+        (32236, "畫作展示櫃"),
+        (8282, "祖母綠耳墜"),
+        (10923, "飛龍革詠咒假面"),
+        (16557, "索菲婭裝甲"),
+        (9074, "奇蜥詠咒手套"),
+        (22914, "山師草履"),
+        (12544, "星紅石"),
+        (11921, "精金銼刀"),
+        (48582, "紅色宇宙煉金藥"),
+        (11056, "煙晶治愈戒指"),
+        (38769, "面向收藏家的紅地毯"),
+        (48530, "運送資材用的繩索"),
+        (13315, "樟木治愈腕環"),
+        (34091, "伽迦象革魔導典"),
+        (4647, "水蜥肉排"),
+        (2111, "羚羊角長杖"),
+        (15720, "假果實"),
+        (33874, "紅色蝴蝶蘭耳飾"),
+        (44049, "植物成長劑11型"),
+        (15561, "驚雷爍光"),
+        (26110, "鈦銅戰斧"),
+        (11013, "靈銀精准項鍊"),
+        (34558, "金毗羅鰐革貝希摩斯女王腿甲"),
+        (35657, "收藏用山雞革魔導書"),
+        (28844, "空鼠禦敵格鬥服（複製品）"),
+        (15819, "管弦樂琴樂譜：水手遇難"),
+        (25964, "靈青輪刃"),
+        (36317, "輝煌工具的第一次加工部件：煉金"),
+        (28167, "水果攤"),
+        (34556, "錳鋼貝希摩斯女王戰甲"),
+        (46079, "儀仗精准手鐲"),
+        (44886, "王室睡床"),
+        (26335, "透鋰長石精准項環"),
+        (48404, "宇宙探索用的護目鏡"),
+        (19567, "玉鋼碎石錘"),
+        (21715, "夜鋼禦敵鎧靴"),
+        (40645, "仿制金庫"),
+        (33283, "矮書櫃"),
+        (6530, "林間沙發"),
+        (14047, "毛絨絨洋蔥王子"),
+        (16707, "星絨"),
+        (41510, "藍色魯冰花頭花"),
+        (43220, "奧闊鉻鐵園藝鐮"),
+        (24502, "金幣袋"),
+        (14831, "遠徵腿套"),
+        (38920, "研究者大地皮靴"),
+        (38246, "法外執行者長褲"),
+        (26306, "海燕革精准手套"),
+        (19654, "瞪羚革巧匠手套"),
+        (42207, "深紅木強攻項鍊"),
+        (2499, "爆彈煎鍋"),
+        (11952, "白鈦鐮刀"),
+        (10794, "安菲瑟龍革強襲高筒靴"),
+        (42307, "鈦金匕首"),
+        (42175, "獰豹革制敵高筒靴"),
+        (43304, "犎牛嗶嘰巧匠皮帽"),
+        (17330, "空鼠游擊鐵鈎筒靴"),
+        (36045, "皇帝濃湯"),
+        (31116, "改良用粘合劑"),
+        (3144, "亞麻巧匠短上衣"),
+        (31192, "第三期重建用的木箱"),
+        (1983, "風華幻杖"),
+        (30828, "朱焰手甲"),
+        (48429, "無人機用的微型齒輪"),
+        (25705, "島狼精准指環"),
+        (12605, "祝聖水"),
+        (8002, "花壇"),
+        (8794, "林間開架書櫃"),
+        (4380, "民兵項環"),
+        (42325, "白黃金制敵半假面"),
+        (36875, "木制雪峰公館外牆"),
+        (3164, "古典鱗鎧"),
+        (43314, "落雷絹巧匠無檐帽"),
+        (26331, "透鋰長石治愈耳墜"),
+        (27133, "沙柚木砂輪機"),
+        (5485, "亞麻油"),
+        (25863, "硬水鋁石禦敵指環"),
+        (2466, "民兵紡車"),
+        (27200, "海燕革長靴"),
+        (35563, "群星壁掛"),
+        (14926, "長頸駝革狩獵靴"),
+        (12221, "鐵刀木"),
+        (6200, "小山羊玩偶"),
+        (16908, "強化玻璃板"),
+        (27366, "卸鐵輪刃"),
+        (16678, "星絨詠咒長衫"),
+        (24252, "烏侖代硬木木材"),
+        (36133, "青君騎士武具"),
+        (7559, "軟軟墊子"),
+        (10939, "巨龍革詠咒手套"),
+        (2942, "絹布治愈寬邊帽"),
+        (5588, "玫瑰金耳飾"),
+        (35330, "馬栗木釣竿"),
+        (31027, "收藏用石金太陽儀"),
+        (44415, "探求永恆制革刀"),
+        (37835, "7級剛力之幻水"),
+        (36864, "石制雪峰小屋房頂"),
+        (43210, "五加木釣竿"),
+        (38899, "研究者手斧"),
+        (9771, "星月枝形吊燈"),
+        (39869, "蜜浸火龍果"),
+        (17556, "黑渦團軍需品部件"),
+        (27939, "玻璃革七分褲"),
+        (31959, "第四期重建用的特供幻藥"),
+        (1686, "黃銅指虎"),
+        (24572, "伊修加德菜餚必需品的部件"),
+        (29958, "鈷鐵合金錠"),
+        (31858, "總督游擊打底褲"),
+        (15823, "管弦樂琴樂譜：大橋上的死鬥"),
+        (37815, "復興治愈戒指"),
+        (48521, "宇宙探索用的狩獵帽"),
+        (30850, "青君短銃"),
+        (34531, "錳鋼拳套"),
+        (36230, "5級智力之幻水"),
+        (18208, "牛鬼革禦敵軟甲褲"),
+        (39423, "簡易噴泉"),
+        (16702, "閃爍詠咒指環"),
+        (44212, "收藏用嵌齒象革襯褲"),
+        (15733, "調整後的石材"),
+        (48629, "工人的營養餐"),
+        (27647, "盜龍革狩獵靴"),
+        (20391, "靈銀花劍"),
+        (19640, "葛布大地及膝褲"),
+        (15560, "驚雷風行"),
+        (8356, "獄火神爪"),
+        (2358, "黑鐵銼刀"),
+        (10599, "靈銀雙刃斧"),
+        (26113, "鈦銅小刀"),
+        (27202, "白橡木耳墜"),
+        (3367, "巨蟾蜍革襯褲"),
+        (17856, "管弦樂琴樂譜：靜寂的星空"),
+        (34416, "賽加羚羊革精准長衣"),
+        (18105, "鋰輝石詠咒手鐲"),
+        (24862, "綠色鈴蘭頭花"),
+        (43238, "鈷鎢研鉢"),
+        (48267, "宇宙探索用的布料"),
+        (4254, "紅珊瑚耳墜"),
+        (12014, "聖雪松腕環"),
+        (36902, "雪峰柵欄圍牆"),
+        (2329, "青銅羊角錘"),
+        (3804, "黑鐵鱗脛甲"),
+        (48361, "宇宙鈦鐵釘"),
+        (26008, "白梣木禦敵耳墜"),
+        (26286, "矮人銀禦敵頭盔"),
+        (3491, "漁人休閒褲"),
+        (29967, "3級意力之幻水"),
+        (10594, "白鈦拳鋒"),
+        (38908, "研究者廚刀"),
+        (30075, "神龍夢幻短銃"),
+        (10797, "聖虹精准狩獵帽"),
+        (2369, "青銅圓頭錘"),
+        (9052, "奇蜥三角帽"),
+        (6512, "古堡火籠"),
+        (48341, "宇宙探索用的硬質鉚釘"),
+        (48251, "統一規格的合金"),
+        (42350, "鈦金詠咒頭盔"),
+        (34683, "完滿木魔導長槍"),
+        (15600, "圓桌之斧"),
+        (42310, "白黃金量天儀"),
+        (4584, "損血劇毒藥"),
+        (19839, "游牧民風味肉餅"),
+        (31873, "總督禦敵耳墜"),
+        (48502, "作業用的繩索"),
+        (6474, "照明系統"),
+        (11060, "詠咒虹布緞帶"),
+        (4654, "炒跳蜥蛋"),
+        (2534, "青銅碎石錘"),
+        (44248, "圖拉爾商人皮鞋"),
+        (12582, "祝聖栗木木材"),
+        (34694, "球粒隕石魔導刺劍"),
+        (4433, "白珊瑚戒指"),
+        (35374, "錳鋼銼刀"),
+        (42520, "黑星石強攻手鐲"),
+        (22486, "管弦樂琴樂譜：天雷"),
+        (30443, "唯美工藝錘"),
+        (37746, "復興復合弓"),
+        (15161, "東方廊椅"),
+        (13015, "珍木大師手套"),
+        (10742, "長頸駝革制敵馬褲"),
+        (6475, "莫古信箱"),
+        (26018, "野蠻盜龍革禦敵手鐲"),
+        (17853, "管弦樂琴樂譜：啓示"),
+        (13048, "烹飪大師廚帽"),
+        (22894, "黑柳釣竿"),
+        (24591, "雨兜"),
+        (42911, "舊日王國精准鎧靴"),
+        (23804, "羅剎弓士手甲"),
+        (10868, "青麻游擊軟甲褲"),
+        (42515, "黑星石強攻項環"),
+        (31891, "總督治愈戒指"),
+        (42931, "舊日王國詠咒耳墜"),
+        (34128, "黑麻治愈打底褲"),
+        (47921, "保鏢半指手套"),
+        (3531, "鞣革連指輕手套"),
+        (39658, "繼業者制敵護膝褲"),
+        (48400, "無人機用的透鏡"),
+        (29477, "伊修加德新型治愈手環"),
+        (4675, "清燉羚羊肉"),
+        (3547, "硬革手箍"),
+        (18991, "鈀金制敵頭冠"),
+        (35463, "完滿木巧匠指環"),
+        (26186, "三重石治愈指環"),
+        (26278, "矮人銀魔導書"),
+        (48515, "加工檢驗用的採集工作服"),
+        (39784, "面向收藏家的虎爪"),
+        (39697, "繼業者精准手環"),
+        (14733, "荒神布游擊棉甲"),
+        (25042, "無限鬼神之弓"),
+        (29432, "伊修加德新型制敵皮靴"),
+        (35445, "紫黃晶巧匠項鍊"),
+        (28803, "橙色風鈴頭花"),
+        (2456, "業餘紡車"),
+        (42211, "粉綠柱石禦敵手鐲"),
+        (4853, "黃油"),
+        (43233, "鈷鎢銼刀"),
+        (35566, "花樣蠟燭套裝"),
+        (40405, "樸素寢室襯衫"),
+        (6399, "混合制綠洲別墅外牆"),
+        (34578, "絳紅草布白獅鷲褲襪"),
+        (10722, "白鈦制敵鏈甲"),
+        (22450, "2級智力之藥湯"),
+        (3706, "木匠手套"),
+        (27224, "矮人銀手鐲"),
+        (4622, "御風妙藥"),
+        (46043, "儀仗強襲坎肩"),
+        (4661, "鹽烤鯉魚"),
+        (26271, "愈瘡木矮人刺槍"),
+        (35046, "古典風格騎士長衣"),
+        (20429, "無盡雲神刺劍"),
+        (10919, "安菲瑟龍革治愈高筒靴"),
+        (5288, "駿鷹革"),
+        (7574, "新薯冷湯"),
+        (28743, "重建用的煎鍋"),
+        (31831, "總督禦敵頭冠"),
+        (44157, "剛力之寶藥"),
+        (5183, "碧璽"),
+        (44979, "皓光游擊槍"),
+        (13803, "亞拉戈圓盾（複製品）"),
+        (30999, "收藏用鈦銅鶴嘴鋤"),
+        (20696, "混合制東洋別墅外牆"),
+        (25707, "島狼詠咒指環"),
+        (2628, "弓角"),
+        (29448, "伊修加德新型游擊手套"),
+        (5184, "琥珀"),
+        (31113, "改良用迦迦納怪鳥革"),
+        (1974, "冰華幻杖"),
+        (2520, "青銅鶴嘴鋤"),
+        (6442, "綠洲懸掛門牌"),
+        (41830, "巧克力馬克杯"),
+        (15738, "烹調所需品"),
+        (34154, "紫黃晶詠咒指環"),
+        (11017, "黃水晶精准項環"),
+        (12562, "古鳥革"),
+        (10942, "星絹布詠咒禮服手套"),
+        (41895, "五加木精准耳墜"),
+        (48132, "澤蓮尼婭之劍展示台"),
+        (2174, "秘銀之書"),
+        (20715, "東洋石制圍牆"),
+        (3777, "硬革筒靴"),
+        (17940, "多瑪壽喜燒"),
+        (38883, "塗料調和用品"),
+        (2565, "白鋼鐮刀"),
+        (6436, "綠洲側壁煙囪"),
+        (18055, "硬鋼制敵頭盔"),
+        (29411, "伊修加德新型左輪槍"),
+        (27901, "管弦樂琴樂譜：天使醒來"),
+        (4214, "日長石耳墜"),
+        (43264, "卡扎納爾橫頭錘"),
+        (35651, "收藏用黑麻帽"),
+        (8818, "林間矮燈"),
+        (7117, "寶石獸庭院長椅"),
+        (24278, "奶酪甜甜圈"),
+        (18986, "鉻鐵禦敵戰甲"),
+        (17309, "空鼠禦敵馬褲"),
+        (3177, "野豬革皮衣"),
+        (47221, "宇宙探索用的碧璽水晶"),
+        (18672, "鉬鋼鳶盾"),
+        (48258, "統一規格的壓縮纖維板"),
+        (18041, "山毛櫸法杖"),
+        (23862, "湯鍋燉菜"),
+        (33299, "鍛冶工作台"),
+        (28868, "空鼠治愈面具（複製品）"),
+        (15730, "採集工具修理部件"),
+        (24331, "邪龍怨影魔導典"),
+        (19529, "卸鐵圓頭錘"),
+        (35395, "球粒隕石銼刀"),
+        (43240, "鈷鎢碎石錘"),
+        (5063, "黃銅錠"),
+        (48450, "宇宙飛線用的皮革"),
+        (4582, "睡眠猛毒藥"),
+        (46031, "儀仗輕盾"),
+        (26017, "野蠻盜龍革詠咒項鍊"),
+        (34406, "賽加羚羊革制敵長衣"),
+        (39637, "繼業者左輪槍"),
+        (2774, "秘銀眼鏡"),
+        (1891, "楓木短弓"),
+        (16718, "耐力之強化仙藥"),
+        (18715, "鉬鋼禦敵耳墜"),
+        (35081, "古典風格強攻項環"),
+        (35664, "收藏用巨人魚子泥沙拉"),
+        (30463, "唯美巧匠半指手套"),
+        (28810, "空賊禦敵手鎧（複製品）"),
+        (4262, "碧璽耳墜"),
+        (7609, "羊絨布"),
+        (31184, "第三期重建用的合板"),
+        (5804, "深淵藍染劑"),
+        (3237, "礦工工作衫"),
+        (24634, "青帝"),
+        (7620, "稀硫酸"),
+        (11930, "金雲母砂輪機"),
+        (46068, "儀仗強攻耳墜"),
+        (30456, "唯美紡車"),
+        (4672, "油炸鯕鰍塊"),
+        (18261, "白銅詠咒手鐲"),
+        (6511, "千獄亡者草"),
+        (39695, "繼業者禦敵手環"),
+        (30822, "月讀命幽夜文書"),
+        (18420, "星尖石精准指環"),
+        (34287, "棕櫚精准耳夾"),
+        (19905, "植物成長劑8型"),
+        (2321, "白鋼雕紋手鋸"),
+        (42336, "嵌齒象革精准釘刺坎肩"),
+        (4508, "琥珀戒指"),
+        (21830, "推車櫃台"),
+        (13897, "龍神制敵開面盔（複製品）"),
+        (47716, "宇宙探索用的蘑菇濃湯"),
+        (11005, "貴橄欖石強攻耳墜"),
+        (5375, "柚木木材"),
+        (42029, "錘頭鰐革精准護臂"),
+        (41911, "混金治愈指環"),
+        (34697, "球粒隕石魔導飛翼"),
+        (4388, "駿鷹革項環"),
+        (27205, "白橡木指環"),
+        (36710, "條約巧匠半指手套"),
+        (6590, "莊園豪華暖爐"),
+        (48113, "東洋底櫃"),
+        (11070, "皇金詠咒手鐲"),
+        (15914, "賢王莫古法杖"),
+        (26172, "三重石詠咒耳墜"),
+        (36334, "輝煌工具的第三次加工部件：烹調"),
+        (2303, "玄鋼小圓盾"),
+        (27104, "靈青蒸餾器"),
+        (41865, "銀狼革制敵半指護手"),
+        (19998, "真獅鷲革"),
+        (2229, "鞣革皮盾"),
+        (6508, "黃金谷白骨樹"),
+        (31208, "第三期重建用的梯子"),
+        (20775, "基拉巴尼亞的特產"),
+        (41876, "雪木棉精准短裙"),
+        (39780, "面向收藏家的烏賊墨汁長棍麵包"),
+        (2699, "棉布兜帽"),
+        (7538, "水手甲板鞋"),
+        (17311, "空鼠制敵面具"),
+        (2554, "秘銀手斧"),
+        (44227, "收藏用燉煮羊駝肉排"),
+        (48453, "基地熊皮地毯"),
+        (11000, "靈銀強攻耳墜"),
+        (2244, "古典重盾"),
+        (1683, "牡羊角爪"),
+        (18564, "石青禦敵項環"),
+        (10755, "飛龍革強襲眼罩"),
+        (38576, "仿制圓形彩繪玻璃窗"),
+        (48364, "無人機用的金屬絲"),
+        (2755, "白鋼頭盔"),
+        (30084, "神龍夢幻環刃"),
+        (42152, "獰豹革魔導典"),
+        (27655, "改良型白鋼重甲"),
+        (2474, "白鋼蒸餾器"),
+        (14058, "古代野獸的頭骨標本"),
+        (18711, "瞪羚革詠咒護臂"),
+        (34734, "蛇牛革詠咒皮靴"),
+        (2406, "砂岩砂輪機"),
+        (2911, "民兵開面盔"),
+        (12101, "宴會圓桌"),
+        (27871, "焗黃新薯"),
+        (9058, "白金精准手鐲"),
+        (8667, "秘銀輔劍"),
+        (46084, "儀仗精准指環"),
+        (23167, "上級恢復藥"),
+        (31924, "第四期重建用的鐵環"),
+        (10785, "巨龍革強襲馬褲"),
+        (37776, "復興強襲皮鞋"),
+        (4381, "特務項環"),
+        (15806, "管弦樂琴樂譜：人王"),
+        (36126, "邪龍怨影騎士武具"),
+        (21834, "藥劑台"),
+        (13867, "亞拉戈高位精准筒靴（複製品）"),
+        (22904, "夜鋼鐮刀"),
+        (29966, "3級智力之幻水"),
+        (6655, "喝水碧企鵝"),
+        (10912, "奇美纖維治愈褲襪"),
+        (5203, "水眼石"),
+        (29795, "第二期重建用的金屬錠"),
+        (19896, "2級剛力之靈水"),
+        (48308, "特制金匠工作台"),
+        (3795, "新來者長靴"),
+        (26344, "透鋰長石強攻指環"),
+        (13895, "龍神禦敵垮褲（複製品）"),
+        (37699, "獺獺噴泉建造用部件"),
+        (16653, "閃爍強襲頭冠"),
+        (18554, "嗶嘰詠咒緊身衣"),
+        (40661, "斯卡米留尼之劍展示台"),
+        (38544, "夢羽妖靈王長槍"),
+        (18102, "鋰輝石強攻手鐲"),
+        (5781, "口花綠染劑"),
+        (25703, "島狼禦敵指環"),
+        (32943, "4級意力之幻水"),
+        (27768, "1級巧力之幻水"),
+        (31000, "收藏用靈銀角盔"),
+        (8821, "綠洲路燈"),
+        (34541, "金毗羅鰐革魔導典"),
+        (15957, "野馬級飛空艇模型"),
+        (18107, "鋰輝石強攻指環"),
+        (48475, "研究用的皮革細繩"),
+        (22550, "皮包"),
+        (29798, "第二期重建用的墨水"),
+        (15509, "加隆德大地矮靴"),
+        (35365, "錳鋼工藝錘"),
+        (8037, "高級銀絲織錦"),
+        (13655, "空賊強襲軟甲褲"),
+        (14716, "光銀禦敵鎧靴"),
+        (31838, "總督制敵鏈甲"),
+        (19728, "落葉松耳墜"),
+        (25953, "靈青小刀"),
+        (39722, "8級剛力之幻水"),
+        (19967, "薔薇輝石"),
+        (33607, "白帝經典"),
+        (13812, "亞拉戈制敵軟甲褲（複製品）"),
+        (36189, "鐵雲母磨刀石"),
+        (13683, "虹布馬褲"),
+        (34109, "伽迦象革制敵長靴"),
+        (35320, "輝翠銀手鋸"),
+        (6621, "木制文件櫃"),
+        (4627, "御冰秘藥"),
+        (48177, "聖輝長弓"),
+        (42456, "卡扎納爾復合弓"),
+        (10908, "虹布治愈長褲"),
+        (25049, "無限鬼神之典"),
+        (2059, "黃銅咒錘"),
+        (42946, "舊日王國詠咒指環"),
+        (4226, "白銀耳夾"),
+        (20268, "黑色雛菊頭花"),
+        (27102, "靈青圓革刀"),
+        (35348, "鉍金貓小胖煎鍋"),
+        (39321, "紫色牽牛花頭花"),
+        (48544, "罐裝應急食品"),
+        (42908, "舊日王國精准上衣"),
+        (44988, "皓光天星盤"),
+        (3851, "毒蜥蜴革尖頭靴"),
+        (44438, "探求永恆大地外套"),
+        (34234, "棕櫚長弓"),
+        (27079, "深金圓頭錘"),
+        (32221, "玫瑰花架"),
+        (11010, "獅鷲利爪強攻戒指"),
+        (24181, "白色馬蹄蓮頭花"),
+        (35034, "古典風格小劍"),
+        (44217, "收藏用薄絹腿套"),
+        (48416, "基地浴缸"),
+        (25799, "綠飄龍革魔導典"),
+        (44148, "優純銀錠"),
+        (35571, "木制百葉簾"),
+        (38549, "夢羽妖靈王牧杖"),
+        (6404, "林間古典窗"),
+        (12025, "獅鷲革項環"),
+        (12011, "聖雪松項鍊"),
+        (48410, "改良設備用的暖爐"),
+        (43272, "卡扎納爾手斧"),
+        (41822, "東方裝飾門窗"),
+        (9310, "工匠砂輪機"),
+        (27173, "仙子棉巧匠袖套"),
+        (37803, "復興強攻項鍊"),
+        (35324, "輝翠銀制革刀"),
+        (33286, "酒杯吊架"),
+        (7015, "瓦片"),
+        (28169, "書攤"),
+        (25807, "深金禦敵輕甲"),
+        (2727, "棉絨頭巾"),
+        (43912, "布涅瓦發泡水"),
+        (34421, "賽加羚羊革游擊短衣"),
+        (35997, "白色紫燈花耳環"),
+        (19832, "根菜沙拉"),
+        (33696, "浪人的篝火"),
+        (18190, "折鋼指虎"),
+        (8381, "厚土壁盾"),
+        (33889, "再燃驟然狂怒之欲"),
+        (18717, "鉬鋼精准耳墜"),
+        (28819, "空賊強襲短衣（複製品）"),
+        (44978, "皓光戰斧"),
+        (25640, "石金量天儀"),
+        (2139, "星盤杖"),
+        (15610, "圓桌之儀"),
+        (48456, "基地皮革椅子"),
+        (31161, "保護引擎的部件"),
+        (48553, "改良設備用的混凝土"),
+        (4119, "天青石手鐲"),
+        (44189, "收藏用克拉洛胡桃木砂輪機"),
+        (24321, "邪龍怨影之拳"),
+        (18695, "瞪羚革精准筒靴"),
+        (4314, "山羊革項環"),
+        (31933, "第四期重建用的皮袋"),
+        (34124, "伽迦象革游擊皮靴"),
+        (7994, "睡蓮水盆"),
+        (4431, "黃銅巧匠戒指"),
+        (3218, "古典鎖子鎧"),
+        (48290, "統一規格的木材"),
+        (3099, "巨蟾蜍革皮衣"),
+        (12853, "煎牛肝菌"),
+        (21745, "地懶革治愈高筒靴"),
+        (36078, "北洋食鹽"),
+        (44221, "收藏用銀狼革魔導書"),
+        (4092, "鞣革手環"),
+        (19011, "真獅鷲革游擊手套"),
+        (10533, "旋風神提"),
+        (11051, "蛋白石治愈耳墜"),
+        (34601, "藍鋯石強攻指環"),
+        (48263, "宇宙探索用的硬化木板"),
+        (29650, "改良用的月光絲"),
+        (11063, "蛋白石詠咒項環"),
+        (6532, "海濱長沙發"),
+        (13013, "珍木大師皮帽"),
+        (6351, "混合制海濱公館房頂"),
+        (5754, "南瓜橙染劑"),
+        (20264, "綠色雛菊頭花"),
+        (4091, "紅銅手鍊"),
+        (3669, "龜甲鱗指套"),
+        (29823, "第二期重建用的藥湯"),
+        (9306, "徵糧工作靴"),
+        (19033, "榧木強攻項環"),
+        (18245, "牛鬼革木鞋"),
+        (8560, "弦月睡褲"),
+        (36250, "金毗羅鰐革"),
+        (26275, "矮人銀手槍"),
+        (34548, "錳鋼戰鐮"),
+        (10667, "白鈦重盾"),
+        (27110, "靈青銼刀"),
+        (42145, "鈷鎢指虎"),
+        (14721, "荒神革制敵腿甲靴"),
+        (19001, "真獅鷲革強襲戰靴"),
+        (2108, "蟹甲獸骨長杖"),
+        (11884, "硬銀雕金錘"),
+        (19887, "巧力之藥湯"),
+        (44826, "綠色卡特蘭頭花"),
+        (2447, "新來者縫針"),
+        (26012, "白梣木詠咒耳墜"),
+        (42023, "高山亞麻強襲上裝"),
+        (6577, "林間隔離牆"),
+        (43283, "卡扎納爾園藝鐮"),
+        (8365, "旋風神抓"),
+        (44846, "光學纖維"),
+        (10730, "精金制敵手鎧"),
+        (13742, "清蒸風神魚"),
+        (22744, "謝禮"),
+        (18551, "魔象革治愈法靴"),
+        (23823, "羅剎術士筒袴"),
+        (19892, "1級巧力之靈水"),
+        (6533, "林間圓沙發"),
+        (7619, "番茄醬"),
+        (19006, "真獅鷲革精准馬褲"),
+        (23779, "羅剎文書"),
+        (34545, "錳鋼刺刀"),
+        (3530, "鞣革半指護手"),
+        (31827, "總督刺劍"),
+        (37791, "復興治愈皮鞋"),
+        (4671, "油炸鹽漬鱈魚丸"),
+        (4560, "高級聖靈藥"),
+        (19735, "牛鬼革手環"),
+        (18250, "白銅治愈耳墜"),
+        (27227, "愈瘡木耳墜"),
+        (16819, "管弦樂琴樂譜：雪上的足跡"),
+        (22568, "衣樓模型"),
+        (35436, "蛇牛革巧匠半指手套"),
+        (30990, "收藏用精金鈎斧"),
+        (30477, "唯美大地耳墜"),
+        (28970, "餐具架"),
+        (37331, "豪勇細劍"),
+        (41881, "雪木棉游擊打底褲"),
+        (27109, "靈青羊角錘"),
+        (48537, "統一規格的泥漿"),
+        (26308, "矮人銀精准短靴"),
+        (22891, "夜鋼煎鍋"),
+        (14742, "荒神革詠咒頭甲"),
+        (19848, "海藻鹽"),
+        (42054, "河岸石精准項環"),
+        (48575, "面向兔兔族的混凝土"),
+        (14927, "青麻短袖罩衣"),
+        (37756, "復興刺劍"),
+        (48407, "月球車用的長桿"),
+        (37811, "復興詠咒手鐲"),
+        (17569, "蓓天翼龍革"),
+        (28805, "白色風鈴頭花"),
+        (29464, "伊修加德新型禦敵耳墜"),
+        (43332, "相思木耳環"),
+        (2239, "鑲鐵小圓盾"),
+        (27114, "白梣木紡車"),
+        (38759, "面向收藏家的化妝鏡"),
+        (22729, "禮品部件"),
+        (27255, "勇人族的菜丸子"),
+        (25992, "野蠻盜龍革游擊護臂"),
+        (33673, "鑽石神兵裝甲"),
+        (28470, "中庸手斧"),
+        (17906, "樣品台鐘"),
+        (11962, "青麻巧匠工作坎肩"),
+        (44249, "圖拉爾旅行者襯衫"),
+        (37813, "復興強攻戒指"),
+        (43258, "相思木紡車"),
+        (3503, "絹布治愈馬褲"),
+        (12892, "樺木糖漿"),
+        (37789, "復興治愈手套"),
+        (27741, "纏尾蛟革"),
+        (12104, "戈耳狄寫字檯"),
+        (36355, "管弦樂琴樂譜：你的答案"),
+        (28166, "麵包攤"),
+        (17540, "綠色櫻花頭花"),
+        (5174, "天青石"),
+        (8358, "獄火神槍"),
+        (28874, "空鼠詠咒格鬥服（複製品）"),
+        (9627, "1級持久飼料"),
+        (10587, "靈銀火舌"),
+        (7498, "羊絨精准狩獵帽"),
+        (25036, "神聖女神之刺劍"),
+        (9237, "賢王莫古匕首"),
+        (7987, "林間軟墊"),
+        (25678, "斯劍虎革治愈護臂"),
+        (42359, "白黃金詠咒耳墜"),
+        (30480, "唯美大地指環"),
+        (3649, "秘銀重手鎧"),
+        (34579, "錳鋼白獅鷲筒靴"),
+        (11985, "長頸駝革翻邊帽"),
+        (23832, "羅剎鬥士首飾"),
+        (38586, "青藍之手隔離牆"),
+        (5744, "日落橙染劑"),
+        (13790, "亞拉戈劍（複製品）"),
+        (28165, "鮮花攤"),
+        (48491, "宇宙探索用的窗簾"),
+        (25837, "虹繭治愈上裝"),
+        (31031, "收藏用古鳥革鐘形帽"),
+        (24330, "邪龍怨影魔導書"),
+        (6429, "海濱遮蓬"),
+        (43223, "鈷鎢圓頭錘"),
+        (48464, "加工檢驗用的宇宙皮革"),
+        (42202, "粉綠柱石強攻耳墜"),
+        (14746, "荒神革詠咒長靴"),
+        (27722, "青金石"),
+        (4130, "野豬革手環"),
+        (28477, "中庸大地皮帽"),
+        (29446, "伊修加德新型游擊禮帽"),
+        (34689, "完滿木魔導咒杖"),
+        (48394, "無人機用的耐高溫鐵板"),
+        (27996, "2級巧力之幻藥"),
+        (19617, "迦迦納怪鳥革翻邊帽"),
+        (35793, "永暗獎杯"),
+        (28720, "蒜炒貽貝"),
+        (9035, "白金禦敵戒指"),
+        (42172, "鈷鎢制敵坎肩"),
+        (20393, "硬銀刺劍"),
+        (1855, "秘銀騎槍"),
+        (11933, "精金平斬"),
+        (38922, "研究者巧匠項鍊"),
+        (3405, "野豬革馬褲"),
+        (30495, "龍族用工匠工具部件"),
+        (18418, "星尖石禦敵指環"),
+        (19807, "多瑪茶"),
+        (7151, "冬貝利枝形吊燈"),
+        (2242, "黑鐵步兵盾"),
+        (44411, "探求永恆手鋸"),
+        (38861, "燈飾替換部件"),
+        (44051, "大聖水"),
+        (2812, "秘銀頭盔"),
+        (3621, "亞麻短手套"),
+        (6617, "綠洲書桌"),
+        (4268, "綠松石耳墜"),
+        (21755, "清銀精准耳墜"),
+        (42215, "粉綠柱石詠咒手鐲"),
+        (42150, "深紅木法杖"),
+        (2961, "草布無領襯衫"),
+        (19650, "嗶嘰大地垮褲"),
+        (18963, "琅玕詠咒腕環"),
+        (42364, "白黃金詠咒項環"),
+        (10654, "長頸駝革魔導典"),
+        (7094, "寶石獸台燈"),
+        (34535, "錳鋼小刀"),
+        (34449, "鉍金詠咒手環"),
+        (5201, "土眼石"),
+        (42206, "深紅木禦敵項鍊"),
+        (2516, "秘銀廚刀"),
+        (31009, "收藏用卸鐵短袖罩衣"),
+        (10871, "安菲瑟龍革游擊襯褲"),
+        (42467, "卡扎納爾槍刃"),
+        (5489, "水銀"),
+        (5801, "皇室藍染劑"),
+        (16669, "半熊革游擊護腿"),
+        (42066, "河岸石詠咒指環"),
+        (22909, "鬼師草履"),
+        (22532, "獺獺吊頂行燈"),
+        (28843, "空鼠禦敵面具（複製品）"),
+        (10910, "青麻治愈燈籠褲"),
+        (2941, "絹布詠咒寬邊帽"),
+        (7612, "雲杉合板"),
+        (3249, "特務厚袍"),
+        (44076, "番茄水煮魚"),
+        (41256, "面向收藏家的小號"),
+        (11077, "貴橄欖石詠咒耳墜"),
+        (1833, "黑鐵戰戈"),
+        (42162, "鈷鎢振空擺"),
+        (39315, "沙龍服務員長裙"),
+        (10618, "白鈦輔劍"),
+        (10926, "安菲瑟龍革詠咒寬邊帽"),
+        (48375, "基地儲物櫃"),
+        (28830, "空賊游擊手套（複製品）"),
+        (13040, "錦衣大師手套"),
+        (34413, "雪亞麻強襲打底褲"),
+        (41861, "雪木棉禦敵打底褲"),
+        (39596, "管弦樂琴樂譜：魂火鑄就"),
+        (18411, "星尖石治愈項環"),
+        (44085, "紙杯甜松糕"),
+        (28873, "空鼠詠咒面具（複製品）"),
+        (35862, "紅緞帶"),
+        (34236, "輝翠銀巨劍"),
+        (18989, "鉻鐵禦敵鎧靴"),
+        (40681, "井泵"),
+        (10624, "白鈦大劍"),
+        (48483, "宇宙灰革"),
+        (9055, "奇蜥襯褲"),
+        (29492, "3級剛力之幻藥"),
+        (39631, "繼業者指虎"),
+        (39380, "妖靈門"),
+        (28815, "空賊制敵手鎧（複製品）"),
+        (31055, "收藏用玉綢圍裙"),
+        (3490, "樵夫工作褲"),
+        (19884, "魔匠藥茶"),
+        (43327, "混金指環"),
+        (40662, "凶水四天王像"),
+        (48567, "基地觀賞植物"),
+        (34143, "紫黃晶治愈項環"),
+        (43975, "泡沫奶油"),
+        (26132, "鈦銅制敵頭甲"),
+        (42015, "高山亞麻禦敵騎兵褲"),
+        (35457, "金毗羅鰐革大地項鍊"),
+        (30077, "神龍夢幻咒杖"),
+        (6587, "冬貝利落地燈"),
+        (10640, "雪人長杖"),
+        (12852, "扁桃仁牛角麵包"),
+        (28863, "空鼠游擊面具（複製品）"),
+        (34439, "弗里金詠咒耳夾"),
+        (5082, "黑鐵環"),
+        (19027, "鈀金禦敵耳墜"),
+        (17310, "空鼠禦敵鐵鈎筒靴"),
+        (41108, "雪頂莫古泡泡冰"),
+        (31849, "總督精准兜帽"),
+        (19558, "瞪羚角釣竿"),
+        (31073, "收藏用靈青魔導典"),
+        (18370, "葛布制敵長裙"),
+        (28509, "中庸羊角錘"),
+        (18086, "赤麻詠咒束胸"),
+        (13726, "黑鐵鐘錘"),
+        (36688, "條約橫頭錘"),
+        (13256, "平頂帽"),
+        (46000, "奶酪木薯包"),
+        (39649, "繼業者之盾"),
+        (36887, "雪峰內牆"),
+        (8664, "白鋼匕首"),
+        (8015, "陶瓷地板"),
+        (35565, "配藥工作台"),
+        (4594, "剛力之藥"),
+        (34386, "鉍金巨劍"),
+        (24325, "邪龍怨影小刀"),
+        (25044, "無限鬼神之大劍"),
+        (48439, "研究用的皮革"),
+        (36042, "魚蔬清湯"),
+        (36316, "輝煌工具的第一次加工部件：裁縫"),
+        (43287, "雪木棉軟甲褲"),
+        (27840, "烏賊墨汁"),
+        (9621, "1級適應飼料"),
+        (11915, "靈銀羊角錘"),
+        (34239, "白鑞咒杖"),
+        (35372, "鐵木釣竿"),
+        (10950, "虹布詠咒長褲"),
+        (3131, "亞麻長袍"),
+        (31097, "紫色秋英頭花"),
+        (26497, "中庸詠咒項環"),
+        (18049, "硬鋼禦敵頭盔"),
+        (36067, "賢人漢堡"),
+        (23828, "羅剎弓士耳飾"),
+        (18035, "硬鋼頭人斧"),
+        (19541, "板岩砂輪機"),
+        (10937, "飛龍革詠咒手套"),
+        (39664, "繼業者強襲皮靴"),
+        (42521, "黑星石精准手鐲"),
+        (42304, "相思木法杖"),
+        (35071, "古典風格旗手長衣"),
+        (13749, "翼手龍革"),
+        (8109, "變速元件"),
+        (30134, "綠色風信子頭花"),
+        (42346, "嵌齒象革治愈釘刺坎肩"),
+        (4286, "玫瑰金耳環"),
+        (2393, "業餘雕金錘"),
+        (7103, "巨龍棋子"),
+        (18677, "鉬鋼禦敵脛甲"),
+        (22427, "夜鋼錠"),
+        (27660, "鈷鐵面罩"),
+        (4719, "鹽韭洋蔥湯"),
+        (28801, "黃色風鈴頭花"),
+        (23184, "古木樹液"),
+        (4311, "獸牙項鍊"),
+        (35631, "收藏用輝翠銀短銃"),
+        (27155, "矮人銀平斬"),
+        (43266, "卡扎納爾工藝錘"),
+        (27185, "纏尾蛟革巧匠工作靴"),
+        (12594, "虹線"),
+        (28991, "陳舊桌椅組合"),
+        (31229, "第三期重建用的特供圍裙"),
+        (18381, "猛虎革精准護手"),
+        (21699, "黑柳大弓"),
+        (39384, "東方彩繪玻璃窗"),
+        (10611, "聖雪松復合弓"),
+        (5084, "鈷鐵環"),
+        (10688, "精金禦敵手鎧"),
+        (8859, "1級旅人以太轉輪"),
+        (44981, "皓光屠刀"),
+        (18229, "紅玉棉布游擊頭巾"),
+        (35082, "古典風格精准項環"),
+        (6609, "妖精方桌"),
+        (26294, "海燕革制敵手套"),
+        (27855, "焦糖"),
+        (22918, "黑柳巧匠腕環"),
+        (11061, "靈銀詠咒項鍊"),
+        (27786, "剛力之幻藥"),
+        (36063, "星外洋蔥湯"),
+        (6355, "石制海濱別墅房頂"),
+        (37373, "仿制雪峰方窗"),
+        (1963, "綠葉梣木幻杖"),
+        (22551, "皮鞋組合"),
+        (18512, "魔象革魔導典"),
+        (15542, "光銀平底鍋"),
+        (28733, "重建用的貨車"),
+        (19997, "瞪羚革"),
+        (45017, "管弦樂琴樂譜：同行之路"),
+        (15734, "煙霧果實"),
+        (6468, "綠洲豪華吊燈"),
+        (42502, "落雷絹治愈軟甲褲"),
+        (16598, "長頸駝革毛邊靴"),
+        (3172, "呢絨束腰獵衣"),
+        (25958, "靈青魔導書"),
+        (15970, "壁掛格子置物架"),
+        (13284, "伊弗利特之形"),
+        (31171, "充當貨款的商品"),
+        (44980, "皓光長弓"),
+        (26473, "中庸游擊打底褲"),
+        (13840, "亞拉戈高位手杖（複製品）"),
+        (34417, "賽加羚羊革精准手套"),
+        (10607, "白鈦戰叉"),
+        (18203, "白銅刺劍"),
+        (48449, "改良設備用的皮革"),
+        (21733, "地懶革精准高筒靴"),
+        (28714, "礬土磨刀石"),
+        (23797, "羅剎拳士羽織"),
+        (34704, "球粒隕石禦敵鎖甲靴"),
+        (43253, "鈦金羊角錘"),
+        (44061, "嵌齒象革"),
+        (17314, "空鼠制敵馬褲"),
+        (35091, "古典風格強攻指環"),
+        (39698, "繼業者治愈手環"),
+        (3096, "白鋼鏈甲"),
+        (9082, "烏茲盾"),
+        (1917, "橡木長弓"),
+        (3231, "甲匠長外衣"),
+        (34387, "鉍金雷火炮"),
+        (22997, "花花公子帽"),
+        (20739, "紙窗屏風"),
+        (27651, "盜龍革短裙"),
+        (19507, "硬鋼橫頭錘"),
+        (30388, "游牧民的帳篷"),
+        (7021, "黑鐵連接板"),
+        (48529, "基地軟墊"),
+        (14259, "管弦樂琴樂譜：多加與烏內之心"),
+        (13023, "戎甲大師面罩"),
+        (3349, "棉絨垮褲"),
+        (41655, "管弦樂琴樂譜：最後之戰（最終幻想IV）"),
+        (4139, "金綠柱石手鐲"),
+        (36883, "雪峰方窗"),
+        (34716, "蛇牛革精准上裝"),
+        (35411, "伽迦象革手甲"),
+        (5180, "海藍石"),
+        (12916, "翠綠附魔墨水"),
+        (29828, "第二期重建用的工作服"),
+        (27863, "堅果糕"),
+        (5213, "星光石"),
+        (13791, "亞拉戈虎爪（複製品）"),
+        (18504, "玉鋼戰戟"),
+        (35020, "古典風格長劍"),
+        (44246, "圖拉爾商人襯衫"),
+        (3714, "礦工手套"),
+        (29816, "第二期重建用的梯子"),
+        (8123, "抗震元件"),
+        (44982, "皓光斷頭劍"),
+        (41844, "銀狼革魔導典"),
+        (16628, "重合金爪"),
+        (38916, "研究者大地頭燈帽"),
+        (29684, "貓小胖掛鐘"),
+        (41612, "翠光黃道儀"),
+        (46048, "儀仗精准坎肩"),
+        (34542, "錳鋼太陽儀"),
+        (42480, "卡扎納爾制敵戰甲"),
+        (43260, "鈦金廚刀"),
+        (1605, "青銅騎兵劍"),
+        (31028, "收藏用魔銀耳夾"),
+        (13807, "亞拉戈重腿甲（複製品）"),
+        (2580, "紅木釣竿"),
+        (47918, "霓虹街頭鞋"),
+        (28468, "中庸煎鍋"),
+        (48443, "宇宙探索用的腰帶"),
+        (3552, "山羊革連指手套"),
+        (35643, "收藏用弗里金耳環"),
+        (26472, "中庸游擊手套"),
+        (5374, "紅木木材"),
+        (44430, "探求永恆碎石錘"),
+        (2800, "秘銀頭冠（綠松石）"),
+        (44495, "永恆裝甲"),
+        (3469, "內襯呢絨軟甲褲"),
+        (41605, "翠光屠刀"),
+        (2757, "古典頭盔"),
+        (5578, "外套絹布"),
+        (18967, "琅玕治愈指環"),
+        (6605, "分析系統"),
+        (45980, "3級巧力之寶水"),
+        (13838, "亞拉戈高位長槍（複製品）"),
+        (27924, "紅色繡球頭花"),
+        (31039, "收藏用魔象革圍腰"),
+        (25948, "靈青劍"),
+        (2494, "青銅平底鍋"),
+        (8386, "賢王莫古弓"),
+        (7514, "蛟蜥詠咒尖頭靴"),
+        (39781, "面向收藏家的書櫃"),
+        (23173, "絕妙的調配用品"),
+        (38561, "藍色香豌豆花項鍊"),
+        (20680, "木制東洋小屋房頂"),
+        (18047, "白銅花劍"),
+        (13834, "亞拉戈高位鳶盾（複製品）"),
+        (2891, "纖維騎士帽"),
+        (39656, "繼業者制敵短衣"),
+        (26112, "沙柚木長弓"),
+        (16936, "2級畫框"),
+        (39655, "繼業者制敵頭帶"),
+        (8112, "傳聲元件"),
+        (34149, "紫黃晶詠咒手環"),
+        (10800, "奇美纖維精准無檐帽"),
+        (48419, "改良設備用的長桿"),
+        (30756, "南洋海濱軟甲褲"),
+        (26476, "中庸治愈禮帽"),
+        (41839, "混金大刀"),
+        (23894, "浴室瓦片牆"),
+        (6684, "莫古莫古地毯"),
+        (27757, "矮人棉布"),
+        (7085, "毛絨絨冥鬼之眼"),
+        (20810, "紫色光明百合頭花"),
+        (31086, "收藏用秧雞烤肉"),
+        (34696, "球粒隕石魔導輪刃"),
+        (42180, "獰豹革強襲涼鞋"),
+        (41900, "銀狼革精准項鍊"),
+        (36125, "生命騎士武具"),
+        (29813, "第二期重建用的掃把"),
+        (19003, "真獅鷲革精准狩獵帽"),
+        (7575, "菠菜乳蛋餅"),
+        (35579, "餐廳木制展示櫃"),
+        (10996, "硬銀強攻手鐲"),
+        (2830, "加固騎兵頭盔"),
+        (10086, "精密藥"),
+        (40679, "裝飾噴泉"),
+        (41088, "天蓬吊簾"),
+        (2572, "柳木釣竿"),
+        (38757, "面向收藏家的壁燈"),
+        (21736, "真麻游擊外套"),
+        (13820, "亞拉戈精准胸甲（複製品）"),
+        (27721, "硬水鋁石"),
+        (27297, "隨手亂放的眼鏡"),
+        (6406, "林間尖窗"),
+        (40409, "仙人刺寢室襯衫"),
+        (48587, "月面素材的高湯"),
+        (21087, "王室鮭魚排"),
+        (4106, "山羊革手環"),
+        (10071, "自律型魔導浮游炮"),
+        (9086, "烏茲長槍"),
+        (20742, "手持提燈"),
+        (18354, "翠銀長杖"),
+        (44225, "收藏用耐力之寶藥"),
+        (9334, "黑線鱈沙拉"),
+        (9304, "徵糧護腕"),
+        (48193, "聖輝騎士武具"),
+        (23796, "羅剎拳士眼帶"),
+        (19528, "卸鐵鍛鐵錘"),
+        (34438, "弗里金治愈耳夾"),
+        (28799, "紅色風鈴頭花"),
+        (44060, "獰豹革"),
+        (15541, "光銀蒸餾器"),
+        (25982, "野蠻盜龍革強襲高筒靴"),
+        (38764, "面向收藏家的馬林巴琴"),
+        (38763, "面向收藏家的仙子莓茶"),
+        (18983, "鈀金刺劍"),
+        (10891, "奇美纖維治愈聖職衣"),
+        (27713, "鈦銅錠"),
+        (19543, "松木紡車"),
+        (44856, "舒適的軟座靠椅"),
+        (9039, "奇蜥制敵馬褲"),
+        (10699, "聖虹禦敵垮褲"),
+        (42871, "舊日王國拳劍"),
+        (29453, "伊修加德新型治愈上裝"),
+        (36894, "雪峰石制煙囪"),
+        (4865, "番茄汁"),
+        (6359, "木制林間公館房頂"),
+        (25960, "靈青天球儀"),
+        (12026, "飛龍革手環"),
+        (34710, "迷彩絨強襲禮帽"),
+        (14139, "油封牡蠣"),
+        (8099, "加工工具"),
+        (6627, "海濱桌台"),
+        (35345, "鉍金圓革刀"),
+        (9628, "2級持久飼料"),
+        (19886, "剛力之藥湯"),
+        (36694, "條約悌陽象煎鍋"),
+        (19658, "嗶嘰鬥篷"),
+        (17892, "投標用的打刀"),
+        (48313, "宇宙飛線用的長桿"),
+        (41096, "仿晶簇室內燈"),
+        (30975, "收藏用樺木符杖"),
+        (9300, "工匠連指手套"),
+        (43212, "奧闊鉻鐵銼刀"),
+        (2083, "雷華咒角"),
+        (27670, "棉絨手套"),
+        (10935, "星絹布詠咒工作服"),
+        (47981, "女王騎士裝甲"),
+        (15577, "無盡雲神手炮"),
+        (38762, "面向收藏家的化妝箱"),
+        (30407, "複製長椅"),
+        (18350, "卸鐵小刀"),
+        (28872, "空鼠治愈鐵鈎筒靴（複製品）"),
+        (8134, "長風羽飾元件"),
+        (31145, "自動化需要的部件"),
+        (37410, "亞馬烏羅提枝形吊燈"),
+        (13911, "龍神精准脛甲（複製品）"),
+        (28864, "空鼠游擊格鬥服（複製品）"),
+        (36129, "神龍夢幻騎士武具"),
+        (11974, "聖虹布打底褲"),
+        (10886, "皇金治愈王冠"),
+        (18241, "紅玉棉布詠咒寬邊帽"),
+        (10107, "鴨高湯"),
+        (10602, "精金鈎斧"),
+        (44914, "室內遮陽棚"),
+        (1682, "鞣革護拳"),
+        (46973, "宇宙探索用的無結構隕鐵錠"),
+        (15591, "偃武火槍"),
+        (12622, "剛力之龍藥"),
+        (31923, "第四期重建用的鉚釘"),
+        (6391, "石制林間別墅外牆"),
+        (48244, "宇宙月精金塊"),
+        (18992, "鉻鐵制敵戰鎧"),
+        (27872, "馬薩拉奶茶"),
+        (38909, "研究者碎石錘"),
+        (9510, "熔火指環"),
+        (42478, "卡扎納爾禦敵脛甲"),
+        (19632, "葛布巧匠無檐帽"),
+        (39430, "多彩地板"),
+        (6451, "林間圓弧圍欄"),
+        (13033, "精革大師花帽"),
+        (48384, "改良設備用的照明"),
+        (3488, "烹調工作褲"),
+        (25664, "白麻精准兜帽"),
+        (25652, "石金制敵頭飾"),
+        (48445, "改良設備用的綿羊地毯"),
+        (3741, "鞣革大地鴨嘴靴"),
+        (4564, "解毒藥"),
+        (34571, "金毗羅鰐革墮龍皮甲"),
+        (39558, "緋光法杖"),
+        (48380, "基地香爐"),
+        (2420, "新來者裁皮刀"),
+        (45983, "3級意力之寶水"),
+        (12561, "白雲母磨刀石"),
+        (13809, "亞拉戈制敵面罩（複製品）"),
+        (32224, "花邊帷幔"),
+        (42192, "薄絹治愈鬥服"),
+        (34574, "錳鋼墮龍鬥鞋"),
+        (1685, "硬革護拳"),
+        (27890, "檸檬薩赫蛋糕"),
+        (25702, "島狼詠咒手鐲"),
+        (13861, "亞拉戈高位強襲軟甲褲（複製品）"),
+        (4266, "尖晶石耳墜"),
+        (13801, "亞拉戈手槍（複製品）"),
+        (6685, "魔界花地毯"),
+        (4143, "海藍石手鐲"),
+        (2920, "木匠頭巾帽"),
+        (19554, "玉鋼蒸餾器"),
+        (18251, "白銅詠咒耳墜"),
+        (44219, "收藏用落雷絹手套"),
+        (17541, "橙色櫻花頭花"),
+        (5092, "黑鐵鉚釘"),
+        (19749, "櫸木項鍊"),
+        (6369, "混合制綠洲公館房頂"),
+        (34265, "阿爾馬斯提精准狩獵帽"),
+        (43205, "混金縫針"),
+        (29418, "伊修加德新型花劍"),
+        (34575, "絳紅草布白獅鷲兜帽"),
+        (4243, "海藍石耳墜"),
+        (35024, "古典風格騎兵弓"),
+        (3937, "漁人休閒靴"),
+        (48297, "基地床"),
+        (20701, "東洋雕花門"),
+        (39775, "面向收藏家的水族箱"),
+        (25024, "神聖女神之斧"),
+        (4657, "紅燜皮皮拉魚"),
+        (42034, "錘頭鰐革游擊半指護手"),
+        (28839, "空賊詠咒長衣（複製品）"),
+        (37744, "復興雙刃斧"),
+        (42065, "河岸石治愈指環"),
+        (21741, "真麻治愈寬邊帽"),
+        (11079, "雪人利齒詠咒戒指"),
+        (42156, "鈷鎢天星盤"),
+        (15728, "編織籃部件"),
+        (3484, "金匠軟甲褲"),
+        (29817, "第二期重建用的鋸子"),
+        (10783, "聖虹強襲垮褲"),
+        (28730, "重建用的草繩"),
+        (48294, "長期保存用的優質紙"),
+        (48178, "聖輝小刀"),
+        (13656, "空賊強襲長靴"),
+        (26123, "鈦銅刺刀"),
+        (34259, "輝翠銀制敵鎖甲靴"),
+        (37810, "復興治愈手鐲"),
+        (12518, "靈銀塊"),
+        (6645, "杜鵑花盆栽"),
+        (48324, "宇宙愛情花油"),
+        (25809, "綠飄龍革禦敵馬褲"),
+        (34691, "蛇牛革魔導典"),
+        (19990, "紵布"),
+        (44253, "科學家手套"),
+        (10893, "星絹布治愈工作服"),
+        (27691, "白梣木木材"),
+        (43262, "鈦金園藝鐮"),
+        (42042, "高山亞麻詠咒寬邊帽"),
+        (34728, "迷彩絨治愈打底褲"),
+        (18080, "赤麻治愈束胸"),
+        (37748, "復興巨劍"),
+        (34100, "輝翠銀禦敵頭盔"),
+        (10995, "靈銀強攻手環"),
+        (26180, "三重石精准手鐲"),
+        (31153, "減輕磨損的部件"),
+        (27361, "硬鋼刺刀"),
+        (44211, "收藏用獰豹革護臂"),
+        (13851, "亞拉戈高位禦敵軟甲褲（複製品）"),
+        (15727, "基建資材"),
+        (31167, "酒館的必需品"),
+        (44108, "塊狀地毯"),
+        (6549, "海濱衣櫃"),
+        (8130, "長風引擎元件"),
+        (44101, "圖拉爾水果撻"),
+        (3163, "亞麻工作服"),
+        (27843, "黑夜醋"),
+        (35648, "收藏用賽加羚羊革手套"),
+        (17554, "近東風格的收藏品部件"),
+        (2118, "獸齒羚羊角長杖"),
+        (23153, "基拉巴尼亞醫藥品部件"),
+        (19562, "翠銀砂輪機"),
+        (6571, "妖精書櫃"),
+        (6615, "莊園桌子"),
+        (25031, "神聖女神之玉杖"),
+        (42168, "獰豹革禦敵護臂"),
+        (46057, "儀仗治愈兜帽"),
+        (48310, "宇宙探索用的紡車"),
+        (11041, "黃水晶治愈項環"),
+        (31955, "第四期重建用的特供部隊儲物櫃"),
+        (19636, "猛虎革巧匠筒靴"),
+        (36068, "奶汁牛肉"),
+        (34568, "絳紅草布最後的獨角獸長裙"),
+        (29799, "第二期重建用的麻乳"),
+        (10973, "蛋白石禦敵手鐲"),
+        (3943, "玄鋼鎖甲靴"),
+        (2859, "鈷鐵開面盔"),
+        (42473, "卡扎納爾盾"),
+        (33888, "再品爭先逐進之苦"),
+        (41595, "習武無袖背心"),
+        (48413, "統一規格的曲柄"),
+        (25853, "硬水鋁石禦敵項環"),
+        (17030, "猴面雀綠雕"),
+        (48402, "宇宙探索用的研磨機"),
+        (22557, "葡萄酒杯"),
+        (38756, "面向收藏家的座椅"),
+        (38766, "面向收藏家的長椅"),
+        (6637, "林間台燈"),
+        (27689, "白橡木木材"),
+        (31045, "收藏用虹布外套"),
+        (13832, "亞拉戈詠咒軟甲褲（複製品）"),
+        (23799, "羅剎拳士袴"),
+        (27714, "矮人銀錠"),
+        (26456, "中庸制敵鎧靴"),
+        (25843, "虹繭詠咒鬥上裝"),
+        (31212, "第三期重建用的長靴"),
+        (45998, "3級智力之寶藥"),
+        (3065, "新來者長外衣"),
+        (10757, "白鈦強襲頭甲"),
+        (13051, "烹飪大師軟甲褲"),
+        (27213, "魔銀項環"),
+        (27869, "菌蒸巨型水虎魚"),
+        (28750, "重建用的鋸子"),
+        (46050, "儀仗精准寬松直筒褲"),
+        (26460, "中庸強襲手套"),
+        (4580, "失明猛毒藥"),
+        (13858, "亞拉戈高位強襲頭甲（複製品）"),
+        (4688, "陷阱草沙拉"),
+        (48428, "基地總部用的自動售貨機"),
+        (7977, "榻榻米"),
+        (11975, "青麻短裙"),
+        (48349, "高耐久性水箱"),
+        (13271, "高貴皮靴"),
+        (36865, "混合制雪峰小屋房頂"),
+        (10644, "皇金長玉杖"),
+        (34561, "金毗羅鰐革升龍皮甲"),
+        (27282, "水晶都長椅"),
+        (42039, "錘頭鰐革治愈護臂"),
+        (28514, "中庸紡車"),
+        (2930, "漁人休閒帽"),
+        (34233, "棕櫚戰戟"),
+        (27678, "改良型纖維寬邊帽"),
+        (27312, "水晶都傘架"),
+        (48628, "工人的能量棒"),
+        (14843, "黃色古典玫瑰頭花"),
+        (25861, "硬水鋁石治愈手鐲"),
+        (13012, "烹飪大師廚鍋"),
+        (36311, "輝煌工具的第一次加工部件：刻木"),
+        (34714, "蛇牛革強襲長靴"),
+        (29964, "3級巧力之幻水"),
+        (4724, "奶酪蛋奶酥"),
+        (14939, "風絹無檐帽"),
+        (3234, "衣匠燕尾服"),
+        (19615, "赤麻短裙"),
+        (43261, "鈦金碎石錘"),
+        (6629, "綠洲桌台"),
+        (42327, "鈦金制敵釘刺手套"),
+        (11035, "貴橄欖石精准戒指"),
+        (26435, "凶犯"),
+        (8795, "綠洲開架書櫃"),
+        (13758, "鐵刀木角材"),
+        (19524, "硬鋼廚刀"),
+        (29986, "白色風信子頭花"),
+        (3188, "呢絨工作服"),
+        (30851, "青君錫杖"),
+        (12058, "鐵小人"),
+        (26455, "中庸制敵打底褲"),
+        (31951, "第四期重建用的植物成長劑"),
+        (18242, "紅玉棉布詠咒長袍"),
+        (44013, "氧化鎂磨刀石"),
+        (37842, "7級耐力之幻藥"),
+        (33878, "橙色蝴蝶蘭耳飾"),
+        (6641, "綠洲花瓶"),
+        (2359, "新來者銼刀"),
+        (38551, "夢羽妖靈王魔導書"),
+        (35594, "以太增幅部件"),
+        (2834, "鈷鐵護目鏡"),
+        (34241, "山雞革魔導典"),
+        (33228, "紀念醫院的繪畫道具"),
+        (31662, "管弦樂琴樂譜：悠久之風　～暗影～"),
+        (42923, "舊日王國詠咒外套"),
+        (24495, "青龍的雕塑"),
+        (27204, "白橡木手鐲"),
+        (19816, "山芋泥蕎麥面"),
+        (30478, "唯美大地項鍊"),
+        (20747, "虎徹複製品"),
+        (3773, "硬革涼鞋"),
+        (7767, "魚粉"),
+        (11032, "煙晶精准戒指"),
+        (6393, "混合制綠洲小屋外牆"),
+        (31205, "第三期重建用的掃把"),
+        (3397, "亞麻緊身褲"),
+        (5473, "植物成長劑4型"),
+        (34120, "輝翠銀游擊頭盔"),
+        (42873, "舊日王國游擊槍"),
+        (34255, "白鑞制敵頭冠"),
+        (43625, "鉍金雙牙"),
+        (15931, "野豬革幸存者矮靴"),
+        (34534, "鐵木長弓"),
+        (44653, "冬季皮鞋"),
+        (40629, "經典桌面台燈"),
+        (35422, "雪亞麻布大地打底褲"),
+        (41616, "翠光圓月輪"),
+        (13640, "空賊禦敵頭盔"),
+        (6144, "海神湯"),
+        (18244, "紅玉棉布長裙"),
+        (8845, "1級修煉以太轉輪"),
+        (44427, "探求永恆紡車"),
+        (35454, "鐵木巧匠手鐲"),
+        (28865, "空鼠游擊鐵鈎護臂（複製品）"),
+        (41875, "銀狼革精准半指護手"),
+        (38849, "畫材必需品"),
+        (43275, "卡扎納爾銼刀"),
+        (14070, "伊修加德內牆"),
+        (9517, "高質的古布料"),
+        (25981, "仙子棉強襲及膝褲"),
+        (31036, "收藏用迦迦納怪鳥革皮靴"),
+        (11872, "硬銀手鋸"),
+        (13304, "荒神鋼禦敵指環"),
+        (2763, "棉絨圓錐帽"),
+        (42940, "舊日王國治愈手環"),
+        (6588, "蔓德拉落地燈"),
+        (25834, "綠飄龍革游擊高筒靴"),
+        (25686, "斯劍虎革詠咒筒靴"),
+        (21729, "真麻精准無檐帽"),
+        (13029, "鏤金大師外衣"),
+        (35041, "古典風格劍鬥士兜甲"),
+        (41095, "曼德維爾冒險者穀物片"),
+        (35084, "古典風格詠咒項環"),
+        (2262, "鑲銀步兵盾"),
+        (17312, "空鼠制敵格鬥服"),
+        (23324, "管弦樂琴樂譜：黑暗與白雪"),
+        (30074, "神龍夢幻大劍"),
+        (2675, "梣木假面"),
+        (10652, "古鳥革魔導典"),
+        (3922, "民兵戰靴"),
+        (10878, "安菲瑟龍革游擊高筒靴"),
+        (4715, "獵人風味水蜥餅"),
+        (31216, "第三期重建用的睡床"),
+        (38898, "研究者鶴嘴鋤"),
+        (15975, "迦巴勒落地燈"),
+        (2498, "黑鐵煎鍋"),
+        (37809, "復興精准手鐲"),
+        (26480, "中庸治愈長靴"),
+        (11889, "硬銀圓革刀"),
+        (6490, "海濱路燈"),
+        (12008, "長頸駝革流蘇靴"),
+        (36315, "輝煌工具的第一次加工部件：制革"),
+        (35044, "古典風格劍鬥士脛甲"),
+        (43310, "嵌齒象革大地工作坎肩"),
+        (16636, "閃爍長杖"),
+        (3327, "棉布緊身褲"),
+        (41887, "銀狼革治愈涼鞋"),
+        (41613, "翠光武士刀"),
+        (37365, "複製立柱"),
+        (8096, "索具元件"),
+        (27743, "砂獷獸革"),
+        (31157, "鋼板增產的部件"),
+        (3868, "秘銀鎖甲靴"),
+        (18531, "玉鋼強襲手鎧"),
+        (13014, "珍木大師圍裙"),
+        (11973, "古鳥革巧匠馬褲"),
+        (25008, "鐵紋礦石"),
+        (44100, "蘆薈水果凍"),
+        (35836, "黃金核心"),
+        (31112, "改良用白銀錠"),
+        (44103, "釀柿子椒"),
+        (3694, "盜龍革護臂"),
+        (10614, "樺木長弓"),
+        (37745, "復興長槍"),
+        (27938, "玻璃革兜帽坎肩"),
+        (36188, "綠片岩磨刀石"),
+        (35337, "輝翠銀研鉢"),
+        (31881, "總督治愈項環"),
+        (3259, "駿鷹革束腰衣"),
+        (48376, "宇宙探索用的蒸餾器"),
+        (36043, "紅菜湯"),
+        (37807, "復興禦敵手鐲"),
+        (31048, "收藏用青麻包頭巾"),
+        (48179, "聖輝巨劍"),
+        (5573, "方尖槍槍尖"),
+        (31165, "冷卻裝置的部件"),
+        (2184, "古典巫書"),
+        (5196, "翡翠硬玉"),
+        (35461, "完滿木巧匠項鍊"),
+        (21735, "真麻游擊無檐帽"),
+        (40413, "陸行鳥寢室襯衫"),
+        (10623, "靈銀雙手劍"),
+        (15524, "碳纖布巧匠長靴"),
+        (5475, "紅銅附魔墨水"),
+        (26445, "領主盾"),
+        (3352, "黑鐵重足鎧"),
+        (44151, "普魯斯鰐革"),
+        (17938, "手握壽司"),
+        (28472, "中庸巧匠工作帽"),
+        (39651, "繼業者禦敵短衣"),
+        (2939, "駿鷹革狩獵帽"),
+        (27766, "石金附魔墨水"),
+        (3324, "棉布褲襪"),
+        (10685, "飛龍革禦敵手套"),
+        (1981, "火華幻杖"),
+        (21084, "獅子魚油"),
+        (3312, "草布緊身褲"),
+        (44247, "圖拉爾商人及膝褲"),
+        (17333, "空鼠治愈鐵鈎護臂"),
+        (1824, "黃銅長槍"),
+        (27639, "亞麻頭巾"),
+        (18189, "硬鋼長劍"),
+        (13910, "龍神精准馬褲（複製品）"),
+        (13091, "龍堡絨樹"),
+        (19980, "葛布"),
+        (21930, "橙色鬱金香腕帶"),
+        (2852, "纖維寬邊帽"),
+        (41608, "翠光牧杖"),
+        (4618, "御雷藥"),
+        (29502, "高地風味菜肉卷"),
+        (22898, "清銀砂輪機"),
+        (26120, "鈦銅天球儀"),
+        (16910, "羊絨工作褲"),
+        (12020, "聖雪松指環"),
+        (33656, "前沿外套"),
+        (48385, "改良設備用的直立型照明"),
+        (3642, "秘銀連指手鎧"),
+        (6473, "魔界花枝形吊燈"),
+        (6465, "林間豪華吊燈"),
+        (23773, "羅剎短刀"),
+        (31209, "第三期重建用的鋸子"),
+        (19568, "玉鋼鐮刀"),
+        (30837, "朱焰經典"),
+        (38845, "開發機械的必需品"),
+        (18532, "魔象革強襲馬褲"),
+        (39243, "家用獺獺噴泉金屬材料"),
+        (19044, "鈀金精准指環"),
+        (34700, "迷彩絨禦敵軍帽"),
+        (39643, "繼業者武士刀"),
+        (29479, "伊修加德新型禦敵指環"),
+        (44209, "收藏用銀狼革半指護手"),
+        (8832, "石砌地板"),
+        (24250, "千種鋼"),
+        (4173, "民兵手鍊"),
+        (14263, "管弦樂琴樂譜：雷鳴"),
+        (31841, "總督制敵鎧靴"),
+        (34237, "輝翠銀火槍"),
+        (35403, "球粒隕石園藝鐮"),
+        (43347, "卡扎納爾大地指環"),
+        (14182, "2級空白管弦樂琴樂譜"),
+        (4320, "日長石項環"),
+        (7481, "鎢鋼重胸甲"),
+        (34231, "輝翠銀鋼拳"),
+        (16686, "閃爍治愈耳墜"),
+        (27291, "水晶都機械式收銀台"),
+        (32244, "武器展示窗"),
+        (17542, "紫色櫻花頭花"),
+        (3410, "呢絨大地垮褲"),
+        (35412, "黑麻軟褲"),
+        (10825, "聖虹精准垮褲"),
+        (20539, "迷你奇美拉"),
+        (26292, "矮人銀制敵頭盔"),
+        (14940, "風絹短上衣"),
+        (18213, "硬鋼制敵手鎧"),
+        (48278, "研究用的木材"),
+        (21769, "清銀強攻戒指"),
+        (13266, "高貴緊身上衣"),
+        (21720, "真麻制敵馬褲"),
+        (18419, "星尖石強攻指環"),
+        (6415, "林間拱頂門"),
+        (27681, "纖維馬褲"),
+        (36065, "無花果蜜餞"),
+        (24176, "藍色馬蹄蓮頭花"),
+        (42889, "舊日王國雙牙"),
+        (26293, "矮人銀制敵鏈甲"),
+        (10720, "聖虹制敵襯衫"),
+        (40639, "經典雨傘架"),
+        (27160, "矮人銀鐮刀"),
+        (18697, "玉綢游擊包頭巾"),
+        (39323, "黑色牽牛花頭花"),
+        (34687, "球粒隕石魔導手炮"),
+        (7493, "蛟蜥強襲襯衫"),
+        (40678, "鄉村鞦韆雙人長椅"),
+        (38818, "孚布特樣式工藝品"),
+        (6531, "綠洲沙發"),
+        (3230, "鐵匠圍裙裝"),
+        (4456, "巨蟾蜍戒指"),
+        (22432, "章魚燒"),
+        (25955, "靈青手炮"),
+        (37333, "豪勇騎士武具"),
+        (22447, "2級剛力之藥湯"),
+        (5177, "橄欖石"),
+        (42046, "錘頭鰐革詠咒長靴"),
+        (5091, "青銅鉚釘"),
+        (18227, "牛鬼革精准筒靴"),
+        (35394, "球粒隕石羊角錘"),
+        (9619, "2級加速飼料"),
+        (39689, "繼業者詠咒耳墜"),
+        (33258, "東方室內水塘"),
+        (19009, "鈀金游擊頭冠"),
+        (8376, "厚土戰弓"),
+        (6403, "海濱方窗"),
+        (21835, "廚具架"),
+        (42185, "獰豹革精准涼鞋"),
+        (17728, "孤兒院的日用品部件"),
+        (27184, "力山羊巧匠束膝褲"),
+        (2618, "毛球擬餌"),
+        (1625, "白鋼寬刃劍"),
+        (14140, "燉鮟鱇"),
+        (20265, "橙色雛菊頭花"),
+        (6485, "秘銀庭院桌"),
+        (2395, "黑鐵雕金錘"),
+        (13303, "樟木禦敵腕環"),
+        (4482, "野豬革指環"),
+        (2570, "民兵鐮刀"),
+        (5337, "羊毛線"),
+        (1836, "白銀戰叉"),
+        (15580, "無盡雲神魔導書"),
+        (39567, "緋光騎士武具"),
+        (18703, "玉綢治愈兜帽"),
+        (27645, "改良型呢絨弓術手套"),
+        (35392, "球粒隕石手斧"),
+        (10409, "旋風神痛"),
+        (44082, "高級吐司"),
+        (2264, "古典小圓盾"),
+        (42166, "獰豹革禦敵頭帶"),
+        (6650, "天平"),
+        (18669, "鈀金天星盤"),
+        (37361, "彈簧門櫃台"),
+        (10388, "薩維奈短外套"),
+        (27131, "鈦銅銼刀"),
+        (19754, "瞪羚革項環"),
+        (3120, "棉絨巧匠工作坎肩"),
+        (42040, "高山亞麻治愈長裙"),
+        (36074, "薩維奈奶茶"),
+        (26268, "矮人銀鎬"),
+        (10930, "聖虹詠咒襯衫"),
+        (44019, "五加木木材"),
+        (19017, "真獅鷲革治愈手套"),
+        (4313, "黃銅工藝項環"),
+        (9091, "奇蜥魔導書"),
+        (19531, "卸鐵裁皮刀"),
+        (27693, "愈瘡木木材"),
+        (4133, "石榴石手鐲"),
+        (3607, "亞麻半指手套"),
+        (1723, "鈷鐵指虎"),
+        (42186, "薄絹游擊頭飾"),
+        (15546, "光銀羊角錘"),
+        (44194, "收藏用鈦金研鉢"),
+        (14728, "荒神布精准棉甲"),
+        (6535, "莊園長椅"),
+        (18219, "牛鬼革強襲半指手套"),
+        (44105, "牧羊人夾餅"),
+        (10595, "白鈦指虎"),
+        (11940, "白鈦研鉢"),
+        (28168, "蔬菜攤"),
+        (13316, "荒神鋼治愈指環"),
+        (44654, "冬季緊身短裙"),
+        (29458, "伊修加德新型詠咒禮帽"),
+        (9078, "白金詠咒手鐲"),
+        (35810, "撞色木紋地板"),
+        (11916, "白鈦羊角錘"),
+        (26326, "海燕革詠咒短靴"),
+        (5515, "蜂蠟"),
+        (10662, "精金太陽儀"),
+        (19506, "硬鋼手鋸"),
+        (42894, "舊日王國禦敵臂甲"),
+        (3887, "鈷鐵戰靴"),
+        (14841, "紅色古典玫瑰頭花"),
+        (10390, "薩維奈垮褲"),
+        (4641, "鼴鼠肉糕"),
+        (27237, "金屬制的香爐"),
+        (28960, "石堆內牆"),
+        (43273, "克拉洛胡桃木釣竿"),
+        (30482, "辣醬炒全蟹"),
+        (10641, "黑曜石長玉杖"),
+        (6607, "林間方桌"),
+        (4725, "羅蘭莓奶酪蛋糕"),
+        (14229, "管弦樂琴樂譜：再來一杯！"),
+        (26486, "中庸詠咒長靴"),
+        (35350, "鉍金手斧"),
+        (31659, "收藏用檸檬水"),
+        (28869, "空鼠治愈格鬥服（複製品）"),
+        (19602, "阿拉米格橫頭錘"),
+        (42024, "錘頭鰐革強襲半指護手"),
+        (48242, "宇宙飛船用的長桿"),
+        (28461, "中庸手鋸"),
+        (3544, "青銅手鎧"),
+        (44423, "探求永恆銼刀"),
+        (48356, "無人機用的齒輪"),
+        (31070, "收藏用瞪羚革魔導典"),
+        (46011, "儀仗聖徒"),
+        (13668, "空賊游擊長靴"),
+        (4526, "駿鷹革指環"),
+        (37790, "復興治愈護膝褲"),
+        (18516, "玉鋼長盾"),
+        (10708, "白鈦禦敵鎧靴"),
+        (31956, "第四期重建用的特供天文儀"),
+        (35092, "古典風格精准指環"),
+        (31004, "收藏用白鈦臂甲"),
+        (8548, "豹紋男式短褲"),
+        (26173, "三重石禦敵項環"),
+        (31083, "收藏用天婦羅拼盤"),
+        (8793, "海濱開架書櫃"),
+        (14846, "紫色古典玫瑰頭花"),
+        (13670, "空賊治愈航空帽"),
+        (19983, "草原嗶嘰"),
+        (48562, "加工檢驗用的油"),
+        (13038, "錦衣大師禮帽"),
+        (48295, "基地椅子"),
+        (42905, "舊日王國強襲護膝褲"),
+        (1803, "鈷鐵刺柄雙刃斧"),
+        (48482, "宇宙探索用的宇航服"),
+        (34389, "弗里金咒杖"),
+        (8373, "厚土重拳"),
+        (3345, "棉布腿套"),
+        (48487, "急救用的布"),
+        (12908, "高地乾麵"),
+        (18501, "玉鋼長劍"),
+        (14730, "荒神布精准及膝褲"),
+        (7998, "直線踏腳石"),
+        (38674, "萊韋耶勒爾內牆"),
+        (14851, "聖蜥蜴革馬褲"),
+        (14049, "軟糊怪地毯"),
+        (1732, "古典骨拳"),
+        (4367, "鋯石項環"),
+        (35842, "觀星鏡操作杖"),
+        (2550, "黃銅手斧"),
+        (26027, "青金石詠咒指環"),
+        (7071, "煉金工作爐"),
+        (7132, "石制內牆"),
+        (31820, "總督手炮"),
+        (31874, "總督強攻耳墜"),
+        (19513, "硬鋼煎鍋"),
+        (32226, "木條隔離牆"),
+        (19584, "鉬鋼平斬"),
+        (19738, "柿木耳墜"),
+        (21751, "地懶革詠咒高筒靴"),
+        (42004, "釕金刺劍"),
+        (48614, "長期保存用的宇宙口糧"),
+        (44847, "亞歷山德里亞金屬板"),
+        (6454, "綠洲裝飾圍牆"),
+        (38900, "研究者釣竿"),
+        (31225, "第三期重建用的特供鐵匠工作台"),
+        (34581, "絳紅草布黑獅鷲長袍"),
+        (28804, "紫色風鈴頭花"),
+        (10813, "巨龍革精准手套"),
+        (17338, "空鼠詠咒半指護手"),
+        (28800, "藍色風鈴頭花"),
+        (31928, "第四期重建用的芝麻餅乾"),
+        (6580, "冥鬼屏風"),
+        (19984, "精紡毛紗"),
+        (33603, "白帝斬馬刀"),
+        (8360, "獄火神杖"),
+        (27682, "盜龍革尖頭靴"),
+        (42218, "粉綠柱石精准指環"),
+        (16692, "柚木詠咒項環"),
+        (2741, "白銀頭冠（金綠柱石）"),
+        (29450, "伊修加德新型游擊鎖甲靴"),
+        (19744, "魔象革項環"),
+        (21936, "冬意毛衣"),
+        (10653, "飛龍革魔導典"),
+        (35656, "收藏用伽迦象革魔導典"),
+        (2626, "復合亮片"),
+        (41898, "銀狼革禦敵項鍊"),
+        (38758, "面向收藏家的裝飾門"),
+        (11014, "精准青麻緞帶"),
+        (3800, "棉布禮鞋"),
+        (9205, "狂熱小刀"),
+        (41137, "伊爾美格花架"),
+        (10895, "飛龍革治愈手套"),
+        (17910, "亨格長頸駝的毛皮"),
+        (15532, "加隆德巧匠腕環"),
+        (34549, "錳鋼鳶盾"),
+        (21725, "真麻強襲半指手套"),
+        (10672, "白鈦禦敵面具"),
+        (1959, "低語楓木幻杖"),
+        (43292, "雪木棉馬褲"),
+        (10991, "蛋白石強攻項環"),
+        (36665, "留學生用魔器"),
+        (44154, "2級耐力之寶水"),
+        (4863, "明膠"),
+        (3232, "金匠工作服"),
+        (27658, "改良型秘銀重甲"),
+        (19824, "清蒸赤點石斑魚"),
+        (28638, "雷克蘭德餐桌"),
+        (30838, "朱焰文書"),
+        (26346, "透鋰長石治愈指環"),
+        (35031, "古典風格魔導典"),
+        (2231, "青銅小圓盾"),
+        (38768, "面向收藏家的皮革沙發"),
+        (4735, "巧克力球"),
+        (6397, "石制綠洲公館外牆"),
+        (44091, "犎牛牛排"),
+        (18721, "鈀金強攻項環"),
+        (27926, "黃色繡球頭花"),
+        (11943, "靈銀廚刀"),
+        (33032, "橙色康乃馨耳環"),
+        (38606, "原生態木制橫梁"),
+        (37351, "水晶壁掛"),
+        (35030, "古典風格魔導書"),
+        (31944, "第四期重建用的藥湯"),
+        (3541, "硬革護臂"),
+        (36901, "雪峰石制圍牆"),
+        (18547, "嗶嘰治愈寬邊帽"),
+        (42020, "高山亞麻制敵騎兵褲"),
+        (12549, "煙晶"),
+        (26312, "海燕革游擊手套"),
+        (10928, "皇金詠咒頭冠"),
+        (11980, "長頸駝革皮靴"),
+        (30460, "唯美鐮刀"),
+        (13894, "龍神禦敵手鎧（複製品）"),
+        (38567, "黑色香豌豆花項鍊"),
+        (2579, "獸角釣竿"),
+        (8786, "海濱小凳"),
+        (6414, "林間尖頂門"),
+        (18217, "紅玉棉布強襲頭巾"),
+        (23143, "基拉巴尼亞醫藥品"),
+        (31014, "收藏用鈦銅塔盾"),
+        (42059, "河岸石精准手鐲"),
+        (41996, "木棉木咒杖"),
+        (23877, "仿制百葉窗"),
+        (42300, "鈦金戰斧"),
+        (17543, "白色櫻花頭花"),
+        (5324, "草布"),
+        (14726, "荒神革強襲腿甲靴"),
+        (25047, "無限鬼神之咒杖"),
+        (26494, "中庸強攻項環"),
+        (7542, "春意禮鞋"),
+        (3256, "玄鋼重甲"),
+        (48289, "宇宙探索用的樹液"),
+        (47922, "保鏢長褲"),
+        (3885, "盜龍革長靴"),
+        (36064, "無花果沙拉"),
+        (42149, "深紅木牧杖"),
+        (27657, "改良型秘銀鎖甲頭罩"),
+        (12002, "飛龍革及膝褲"),
+        (15976, "精梳毛布地毯"),
+        (42298, "鈦金彎刃刀"),
+        (3162, "亞麻圍裙裝"),
+        (13034, "精革大師圍裙"),
+        (34738, "星點石英治愈耳墜"),
+        (33226, "紀念醫院的康復訓練用品"),
+        (6375, "混合制海濱小屋外牆"),
+        (33598, "白帝鬥手甲"),
+        (26311, "海燕革游擊外套"),
+        (8817, "海濱矮燈"),
+        (20269, "混色雛菊頭花"),
+        (43321, "卡岡圖亞革大地半指護手"),
+        (12589, "聖虹布"),
+        (47220, "宇宙探索用的香爐"),
+        (33292, "盔甲架"),
+        (48526, "研究用的宇宙布"),
+        (31115, "改良用橡膠"),
+        (35075, "古典風格禦敵耳墜"),
+        (35555, "管弦樂琴留聲機"),
+        (12545, "星藍石"),
+        (6489, "低矮花盆"),
+        (18236, "紅玉棉布長袍"),
+        (18417, "星尖石詠咒手鐲"),
+        (6523, "海濱長椅"),
+        (29796, "第二期重建用的鞣革"),
+        (13743, "芝麻餅乾"),
+        (39313, "沙龍服務員禮服坎肩"),
+        (35074, "古典風格旗手戰靴"),
+        (28751, "重建用的廚台"),
+        (12625, "智力之龍藥"),
+        (7984, "雙人羽床"),
+        (21744, "真麻治愈馬褲"),
+        (43246, "鈦金制革刀"),
+        (35342, "鉍金橫頭錘"),
+        (10764, "白鈦強襲鏈甲"),
+        (8570, "拉姆裝甲"),
+        (8564, "金邊墨鏡"),
+        (25820, "綠飄龍革強襲手套"),
+        (2408, "泥岩砂輪機"),
+        (44081, "蛋包飯"),
+        (45979, "3級剛力之寶水"),
+        (35430, "絳紅草布大地坎肩"),
+        (38262, "蜜瓜果汁"),
+        (8389, "賢王莫古書·莫古"),
+        (24177, "黃色馬蹄蓮頭花"),
+        (35333, "輝翠銀手鉗"),
+        (35065, "古典風格醫護兵月桂冠"),
+        (18220, "紅玉棉布強襲束膝褲"),
+        (43334, "相思木手鐲"),
+        (2300, "古典鳶盾"),
+        (32234, "服裝展示櫃"),
+        (12018, "龍牙耳墜"),
+        (6357, "混合制林間小屋房頂"),
+        (5580, "絹布襪"),
+        (21756, "清銀治愈耳墜"),
+        (13922, "龍神遊擊頭冠（複製品）"),
+        (16649, "重合金制敵手鎧"),
+        (7076, "冬貝利餐桌"),
+        (13008, "鏤金大師工藝錘"),
+        (30132, "藍色風信子頭花"),
+        (32245, "畫作展示窗"),
+        (30251, "管弦樂琴樂譜：究極神兵（蠻荒神影）"),
+        (34263, "山雞革強襲騎兵褲"),
+        (19894, "1級智力之靈水"),
+        (44203, "收藏用混金長弓"),
+        (2611, "漂浮誘餌魚"),
+        (9046, "奇蜥強襲涼鞋"),
+        (31896, "4級智力之幻藥"),
+        (34703, "迷彩絨禦敵打底褲"),
+        (16706, "閃爍錠"),
+        (16697, "柚木詠咒手鐲"),
+        (15645, "光流纖維"),
+        (42874, "舊日王國弓"),
+        (44855, "新列車的研磨工具"),
+        (19622, "紅玉棉布無檐帽"),
+        (2318, "新來者手鋸"),
+        (18503, "玉鋼戰斧"),
+        (4734, "菠蘿蛋糕"),
+        (39660, "繼業者強襲頭帶"),
+        (39672, "繼業者游擊手套"),
+        (18067, "迦迦納怪鳥革精准狩獵帽"),
+        (18065, "迦迦納怪鳥革強襲涼鞋"),
+        (2832, "呢絨騎士帽"),
+        (41864, "銀狼革制敵短衣"),
+        (18666, "鈀金法杖"),
+        (16642, "重合金禦敵胸甲"),
+        (19015, "真獅鷲革治愈寬邊帽"),
+        (31886, "總督治愈手鐲"),
+        (48634, "宇宙奶酪"),
+        (27755, "力山羊毛線"),
+        (48253, "改良設備用的耐熱合金"),
+        (27093, "白橡木紡車"),
+        (19926, "落葉松木材"),
+        (25978, "仙子棉強襲狩獵帽"),
+        (26442, "驚異刺劍"),
+        (23002, "風尚花邊短裙"),
+        (31754, "最終改良用瞪羚革"),
+        (9088, "烏茲小刀"),
+        (31151, "車身重要的部件"),
+        (38266, "洛夫坦山羊臀肉排"),
+        (4452, "天青石戒指"),
+        (42008, "木棉木飛翼"),
+        (19993, "迦迦納怪鳥革"),
+        (41422, "魔法樹建造用金屬材料"),
+        (8043, "英騎裝備的改良材料"),
+        (26183, "三重石禦敵指環"),
+        (32942, "4級智力之幻水"),
+        (27136, "鈦銅研鉢"),
+        (34702, "球粒隕石禦敵手鎧"),
+        (42362, "白黃金精准項環"),
+        (36108, "5級意力之幻藥"),
+        (10635, "聖雪松幻杖"),
+        (35576, "方孔隔離牆"),
+        (27130, "鈦銅羊角錘"),
+        (19530, "翠銀雕金錘"),
+        (41094, "仿制地窖門"),
+        (37768, "復興制敵胸甲"),
+        (40622, "監視者之館頂燈"),
+        (22886, "夜鋼圓頭錘"),
+        (26016, "野蠻盜龍革治愈項鍊"),
+        (25647, "深金禦敵胸甲"),
+        (3614, "白鋼重手鎧"),
+        (38605, "葉小妖掛鐘"),
+        (26160, "纏尾蛟革治愈戰靴"),
+        (7115, "賢王信箱"),
+        (26025, "青金石精准指環"),
+        (29474, "伊修加德新型禦敵手環"),
+        (27374, "鉬鋼圓月輪"),
+        (48555, "基地草叢"),
+        (35644, "收藏用錳鋼最後的獨角獸之角"),
+        (3316, "業餘馬褲"),
+        (3709, "金匠手套"),
+        (28481, "中庸大地筒靴"),
+        (18037, "山毛櫸復合弓"),
+        (37839, "7級意力之幻水"),
+        (34597, "藍鋯石精准手環"),
+        (35769, "矮人銀戰鐮"),
+        (10629, "靈銀火槍"),
+        (3174, "秘銀鎖子鎧"),
+        (24261, "3級剛力之藥湯"),
+        (29685, "爆彈怪煉金鍋"),
+        (11887, "靈銀圓革刀"),
+        (39688, "繼業者治愈耳墜"),
+        (2328, "業餘羊角錘"),
+        (31813, "總督長劍"),
+        (19607, "阿拉米格蒸餾器"),
+        (11988, "古鳥革大地外套"),
+        (32235, "武器展示櫃"),
+        (18679, "鉬鋼制敵頭甲"),
+        (4608, "意力之秘藥"),
+        (19535, "卸鐵鶴嘴鋤"),
+        (8291, "藍寶石項環"),
+        (35593, "以太觀測部件"),
+        (4685, "鰐梨色拉"),
+        (15540, "荒神縫針"),
+        (8364, "旋風神凝"),
+        (5738, "衛月紅染劑"),
+        (1648, "古典維京劍"),
+        (22908, "鬼師束褲"),
+        (2356, "青銅銼刀"),
+        (42063, "河岸石強攻指環"),
+        (31085, "收藏用散壽司"),
+        (27218, "白梣木項鍊"),
+        (29423, "伊修加德新型禦敵上裝"),
+        (42198, "粉綠柱石詠咒半指手套"),
+        (15510, "碳纖布大地皮帽"),
+        (11893, "龍牙縫針"),
+        (35767, "鈦銅扎戈斧鐮"),
+        (2656, "青銅鎖甲頭罩"),
+        (26133, "纏尾蛟革制敵外套"),
+        (10774, "獅鷲革強襲手套"),
+        (7112, "莊園大提琴"),
+        (27077, "深金手鋸"),
+        (18095, "鋰輝石詠咒耳墜"),
+        (48422, "高耐久的收納罐"),
+        (31189, "第三期重建用的草繩"),
+        (35556, "鞋架"),
+        (10101, "雜技演員的小刀"),
+        (47719, "工人的儲備糧"),
+        (10535, "賢王莫古儀"),
+        (8355, "獄火神刀"),
+        (34388, "紅松木牧杖"),
+        (10826, "青麻精准軟甲褲"),
+        (14257, "管弦樂琴樂譜：一落千丈"),
+        (48187, "聖輝花劍"),
+        (36075, "扇貝沙拉"),
+        (19018, "紵布治愈長裙"),
+        (36713, "條約大地毛帽"),
+        (13868, "亞拉戈高位治愈頭冠（複製品）"),
+        (18971, "鉻鐵戰斧"),
+        (5276, "硬革"),
+        (6570, "冥鬼書櫃"),
+        (30461, "唯美巧匠皮帽"),
+        (24323, "邪龍怨影長槍"),
+        (13666, "空賊游擊手套"),
+        (27748, "虹繭布"),
+        (41601, "翠光指虎"),
+        (2156, "山羊革魔導書"),
+        (3008, "草布棉甲"),
+        (33661, "前沿禮服手套"),
+        (10816, "獅鷲革精准手套"),
+        (41517, "混色魯冰花頭花"),
+        (34390, "賽加羚羊革魔導書"),
+        (42340, "白黃金游擊半假面"),
+        (9740, "東方木人"),
+        (31210, "第三期重建用的口罩"),
+        (12235, "探險家篝火"),
+        (48423, "耐衝擊內牆"),
+        (36867, "石制雪峰公館房頂"),
+        (11953, "精金鐮刀"),
+        (36331, "輝煌工具的第三次加工部件：制革"),
+        (27090, "深金手鉗"),
+        (14715, "荒神革禦敵馬褲"),
+        (19601, "阿拉米格手鋸"),
+        (20433, "偃武刺劍"),
+        (17538, "藍色櫻花頭花"),
+        (10841, "白鈦游擊頭甲"),
+        (14832, "遠徵狩獵靴"),
+        (5058, "白鋼錠"),
+        (36213, "黃金絲綢"),
+        (43203, "奧闊鉻鐵工藝錘"),
+        (44096, "蔬菜湯"),
+        (29821, "第二期重建用的手套"),
+        (27107, "靈青手斧"),
+        (34582, "金毗羅鰐革黑獅鷲手套"),
+        (11040, "皇金治愈項鍊"),
+        (35364, "錳鋼圓頭錘"),
+        (21762, "黑柳詠咒項鍊"),
+        (6204, "魔導先鋒追蹤型"),
+        (42938, "舊日王國強攻手環"),
+        (25631, "白橡木游擊槍"),
+        (27286, "水晶都茶壺"),
+        (43887, "蘆葦鐮刀"),
+        (38918, "研究者大地護臂"),
+        (18358, "卸鐵打刀"),
+        (44987, "皓光魔導典"),
+        (8704, "無框眼鏡"),
+        (27884, "帝王鮭魚排"),
+        (42148, "深紅木長弓"),
+        (27842, "黃金蜂蜜"),
+        (10866, "虹布游擊垮褲"),
+        (30824, "月讀命幽夜直刀"),
+        (3611, "野豬革半指護手"),
+        (43997, "奧闊鉻鐵錠"),
+        (9489, "奇蜥游擊涼鞋"),
+        (10960, "巨龍革詠咒長靴"),
+        (39690, "繼業者禦敵項環"),
+        (4733, "魚糕"),
+        (31200, "第三期重建用的泥岩砂輪機"),
+        (2219, "楓木方盾"),
+        (38270, "焗帝王海膽佐麵包"),
+        (5780, "獵人綠染劑"),
+        (8001, "弧形踏腳木"),
+        (17961, "吉祥天女模型"),
+        (48399, "宇宙球粒隕石齒輪"),
+        (30820, "月讀命幽夜咒杖"),
+        (38536, "海島幻象稜晶"),
+        (31903, "菠蘿沙拉"),
+        (48342, "宇宙探索用的圓頭錘"),
+        (20262, "藍色雛菊頭花"),
+        (46007, "奶酪莫雷特"),
+        (34576, "絳紅草布白獅鷲長袍"),
+        (13654, "空賊強襲手套"),
+        (37832, "泰坦蚺革"),
+        (3325, "內襯草布軟甲褲"),
+        (23097, "靠椅"),
+        (33031, "綠色康乃馨耳環"),
+        (41904, "銀狼革強攻手鐲"),
+        (17956, "長頸駝標本"),
+        (3700, "民兵手鎧"),
+        (5258, "砂岩磨刀石"),
+        (20702, "東洋鑲金門"),
+        (39646, "繼業者圓月輪"),
+        (39661, "繼業者強襲短衣"),
+        (42933, "舊日王國強攻項環"),
+        (22560, "下裝衣架"),
+        (33282, "水晶都衣櫃"),
+        (25818, "虹繭強襲圓頂帽"),
+        (48522, "宇宙探索用的工匠服"),
+        (10917, "長頸駝革治愈法靴"),
+        (41615, "翠光鋸刃"),
+        (27106, "靈青鶴嘴鋤"),
+        (2058, "青銅咒杖"),
+        (2361, "白鋼銼刀"),
+        (27195, "海燕革短靴"),
+        (39652, "繼業者禦敵半指手套"),
+        (48338, "基地屋頂照明"),
+        (11023, "貴橄欖石精准手鐲"),
+        (18074, "赤麻游擊坎肩"),
+        (35078, "古典風格治愈耳墜"),
+        (19813, "柿子布丁"),
+        (19634, "翠銀手甲"),
+        (28740, "重建用的乾麵"),
+        (12602, "硬銀附魔墨水"),
+        (42174, "薄絹制敵腿套"),
+        (3077, "棉絨巧匠短上衣"),
+        (27115, "靈青研鉢"),
+        (2352, "革柄鳥嘴錘"),
+        (37324, "豪勇短銃"),
+        (7616, "駿鷹革細繩"),
+        (10660, "白鈦天球儀"),
+        (20736, "東洋開架書櫃"),
+        (28747, "重建用的木炭"),
+        (30395, "大河狸漢堡組合"),
+        (35344, "鉍金工藝錘"),
+        (4553, "超級恢復藥"),
+        (13674, "空賊治愈長筒靴"),
+        (12107, "戈耳狄零式座椅"),
+        (2202, "博學詠咒巫書"),
+        (12580, "聖雪松材"),
+        (14155, "荒神革"),
+        (16623, "黑色三色堇頭花"),
+        (48270, "宇宙探索用的藥餌"),
+        (5282, "野豬革"),
+        (41866, "雪木棉制敵打底褲"),
+        (13688, "青麻襯衫"),
+        (10620, "精金匕首"),
+        (9285, "伴郎禮鞋"),
+        (40644, "短柱書架"),
+        (23841, "羅剎豪士指飾"),
+        (25812, "虹繭制敵頭飾"),
+        (13815, "亞拉戈強襲胸甲（複製品）"),
+        (3244, "邪鬼工作服"),
+        (34422, "賽加羚羊革游擊護臂"),
+        (8779, "海濱四柱床"),
+        (13665, "空賊游擊短衣"),
+        (48272, "養殖用的魚餌"),
+        (26342, "透鋰長石詠咒手鐲"),
+        (18562, "石青治愈耳墜"),
+        (43281, "卡扎納爾廚刀"),
+        (19532, "牛角縫針"),
+        (41618, "翠光騎士武具"),
+        (30840, "朱焰直刀"),
+        (34730, "迷彩絨詠咒禮帽"),
+        (18211, "硬鋼制敵開面盔"),
+        (8540, "珍珠葵寬邊草帽"),
+        (22912, "山師手甲"),
+        (37830, "發晶"),
+        (44160, "智力之寶藥"),
+        (19589, "鉬鋼鐮刀"),
+        (26145, "力山羊精准束腰衣"),
+        (11065, "黃水晶詠咒項環"),
+        (32941, "4級耐力之幻水"),
+        (1670, "神風彎刀"),
+        (22737, "太鼓部件"),
+        (46019, "儀仗屠刀"),
+        (3320, "棉布條紋工作褲"),
+        (4121, "獸角腕環"),
+        (23806, "羅剎弓士草履"),
+        (5486, "鯊油"),
+        (17553, "孤兒院的日用品"),
+        (18262, "姆德斯德角禦敵指環"),
+        (9236, "厚土屠刀"),
+        (27180, "野蠻盜龍革大地工作靴"),
+        (1643, "秘銀寬刃劍"),
+        (26125, "鈦銅塔盾"),
+        (3933, "煉金長靴"),
+        (31046, "收藏用虹布緞帶"),
+        (33285, "莫古力書桌"),
+        (42373, "白黃金治愈指環"),
+        (26270, "矮人銀重錘"),
+        (16647, "重合金制敵鎖甲頭罩"),
+        (6389, "木制林間別墅外牆"),
+        (39875, "魚子醬三明治"),
+        (34303, "白鑞治愈指環"),
+        (31939, "第四期重建用的口罩"),
+        (7993, "格子盆栽"),
+        (37409, "亞馬烏羅提地板"),
+        (10885, "皇金治愈頭冠"),
+        (27171, "仙子棉巧匠工作帽"),
+        (6410, "海濱木制門"),
+        (31078, "收藏用皇家蒸蛋"),
+        (47923, "保鏢禮鞋"),
+        (35592, "觀星鏡的支架"),
+        (11022, "皇金精准手鐲"),
+        (26128, "纏尾蛟革禦敵半指護手"),
+        (48621, "耐儲存的煙薰肉"),
+        (17551, "黑渦團軍需品"),
+        (41853, "奧闊鉻鐵戰鐮"),
+        (13907, "龍神精准禮帽（複製品）"),
+        (43977, "高山食鹽"),
+        (46001, "珍珠奶茶"),
+        (33875, "藍色蝴蝶蘭耳飾"),
+        (6980, "高地連指手套"),
+        (13793, "亞拉戈長槍（複製品）"),
+        (30986, "收藏用靈銀輔劍"),
+        (20558, "須佐之男裝甲"),
+        (18375, "猛虎革強襲護手"),
+        (23332, "管弦樂琴樂譜：彼岸花開"),
+        (21759, "黑柳強攻項鍊"),
+        (26140, "纏尾蛟革強襲半指護手"),
+        (6413, "林間古典門"),
+        (31095, "綠色秋英頭花"),
+        (23768, "羅剎劍"),
+        (23989, "朱孔雀"),
+        (26454, "中庸制敵手鎧"),
+        (2662, "青銅角盔"),
+        (11901, "蔓德拉戰隊煎鍋"),
+        (34536, "錳鋼巨劍"),
+        (4667, "生鮮牡蠣"),
+        (36872, "木制雪峰小屋外牆"),
+        (18710, "玉綢詠咒長袍"),
+        (26005, "仙子棉詠咒長褲"),
+        (18103, "鋰輝石精准手鐲"),
+        (10929, "虹布詠咒長袍"),
+        (15964, "索菲婭模型"),
+        (48378, "宇宙探索用的硬質鉚釘"),
+        (26287, "矮人銀禦敵鏈甲"),
+        (33662, "前沿禮鞋"),
+        (39681, "繼業者詠咒長衣"),
+        (43319, "卡岡圖亞革大地工作帽"),
+        (38917, "研究者大地工作坎肩"),
+        (2629, "草布兜帽"),
+        (4137, "橄欖石手鐲"),
+        (43209, "奧闊鉻鐵手斧"),
+        (44898, "格鬥擂台"),
+        (40625, "什錦餅乾"),
+        (29827, "第二期重建用的篝火台"),
+        (34136, "紫黃晶強攻耳墜"),
+        (17914, "高級長頸駝夾克"),
+        (26320, "海燕革治愈短靴"),
+        (48564, "加工檢驗用的合成樹脂"),
+        (15731, "石磚加工用品"),
+        (2331, "黑鐵羊角錘"),
+        (6634, "莊園暖爐"),
+        (31835, "總督禦敵鎧靴"),
+        (42338, "嵌齒象革精准襯褲"),
+        (29658, "改良用的天鵝絨"),
+        (34719, "蛇牛革精准長靴"),
+        (47946, "紫色葉子花耳環"),
+        (7486, "鎢鋼面罩"),
+        (38928, "研究者大地指環"),
+        (25813, "深金制敵戰甲"),
+        (34428, "雪亞麻治愈打底褲"),
+        (5192, "藍寶石"),
+        (12588, "虹布"),
+        (34547, "錳鋼注射管"),
+        (19942, "翠銀塊"),
+        (15974, "壁式書櫃"),
+        (13276, "青藍小手"),
+        (42178, "獰豹革強襲護臂"),
+        (11946, "精金加固廚刀"),
+        (18506, "玉鋼左手劍"),
+        (5083, "白鋼環"),
+        (25670, "深金游擊口罩"),
+        (6610, "海濱餐桌"),
+        (43201, "奧闊鉻鐵橫頭錘"),
+        (18194, "硬鋼波刃"),
+        (10106, "魔化水銀"),
+        (28729, "重建用的鞣革"),
+        (10999, "貴橄欖石強攻手鐲"),
+        (44210, "收藏用錘頭鰐革護腿"),
+        (34087, "輝翠銀短銃"),
+        (2347, "加重鳥嘴錘"),
+        (21864, "轉角樹籬隔離牆"),
+        (20378, "驚雷震霆"),
+        (48627, "工人用的粘合劑"),
+        (5185, "尖晶石"),
+        (48494, "耐用纖維"),
+        (14852, "駿鷹革護胸"),
+        (25650, "深金禦敵鎖甲靴"),
+        (48630, "宇宙探索用的完美食料"),
+        (10846, "聖虹游擊襯衫"),
+        (28862, "空鼠精准黑鐵筒靴（複製品）"),
+        (48552, "工人用的聖水"),
+        (28133, "綠洲壁掛噴泉"),
+        (35401, "球粒隕石廚刀"),
+        (35585, "庭院智蛙擺飾"),
+        (22916, "清銀巧匠耳墜"),
+        (27167, "白麻大地大衣"),
+        (35375, "錳鋼手鉗"),
+        (18191, "硬鋼戰斧"),
+        (48381, "基地掛鐘"),
+        (4117, "螢石手鐲"),
+        (37327, "豪勇經典"),
+        (34397, "弗里金飛翼"),
+        (2620, "旋轉亮片"),
+        (28823, "空賊精准貝雷帽（複製品）"),
+        (17036, "盆栽吊燈"),
+        (28148, "化妝鏡"),
+        (7618, "鹽酸"),
+        (35809, "加雷馬混凝土內牆"),
+        (25041, "無限鬼神之戟"),
+        (19582, "鉬鋼手鉗"),
+        (5334, "棉線"),
+        (15563, "驚雷暴雨"),
+        (10874, "飛龍革游擊長靴"),
+        (12234, "魯冰花叢"),
+        (8118, "駕駛器元件"),
+        (48534, "宇宙復合纖維"),
+        (14903, "毛絨絨蔓德拉王后"),
+        (34402, "賽加羚羊革禦敵護臂"),
+        (19557, "玉鋼手斧"),
+        (26134, "纏尾蛟革制敵半指護手"),
+        (2147, "硬革魔導書"),
+        (26109, "鈦銅拳套"),
+        (15544, "光銀手斧"),
+        (26489, "中庸強攻耳墜"),
+        (4630, "御雷秘藥"),
+        (19729, "落葉松項鍊"),
+        (30446, "唯美蒸餾器"),
+        (8547, "豹紋男式海灘裙"),
+        (44412, "探求永恆橫頭錘"),
+        (48255, "統一規格的軟質金屬板"),
+        (2383, "黑鐵手鉗"),
+        (44230, "收藏用鮭魚乾"),
+        (15547, "光銀銼刀"),
+        (21701, "夜鋼巨劍"),
+        (35595, "以太傳導部件"),
+        (35073, "古典風格旗手裙褲"),
+        (13748, "雲布"),
+        (44422, "探求永恆羊角錘"),
+        (34424, "賽加羚羊革游擊厚底鞋"),
+        (4204, "獸牙耳墜"),
+        (35080, "古典風格禦敵項環"),
+        (19965, "石青"),
+        (6487, "橡木庭院長椅"),
+        (27758, "矮人棉線"),
+        (7966, "毛絨絨哥布林"),
+        (25023, "神聖女神之拳"),
+        (48238, "統一規格的金屬板"),
+        (29415, "伊修加德新型魔導典"),
+        (1897, "榆木長弓"),
+        (42370, "白黃金禦敵指環"),
+        (18416, "星尖石治愈手鐲"),
+        (11024, "靈銀精准耳墜"),
+        (37801, "復興詠咒耳墜"),
+        (42909, "舊日王國精准手套"),
+        (23778, "羅剎經典"),
+        (3339, "內襯棉布軟甲褲"),
+        (21290, "管弦樂琴樂譜：神龍見尾"),
+        (38894, "研究者制革刀"),
+        (4182, "星極手鐲"),
+        (29473, "伊修加德新型詠咒項環"),
+        (26301, "矮人棉強襲束膝褲"),
+        (18192, "硬鋼戰叉"),
+        (27857, "黑色醬汁直面"),
+        (35369, "錳鋼陸行鳥煎鍋"),
+        (3784, "山羊革護腿"),
+        (15522, "碳纖布巧匠袖套"),
+        (4317, "獸角項鍊"),
+        (36897, "雪峰混合制遮蓬"),
+        (30389, "舞台幕簾"),
+        (12032, "飛龍革指環"),
+        (44842, "酸檸檬醃魚"),
+        (10980, "皇金禦敵耳墜"),
+        (19906, "甲膠"),
+        (4694, "草菇甘藍卷"),
+        (24564, "崖畔亭包子必需品"),
+        (18061, "白銅強襲包頭巾"),
+        (15513, "碳纖布大地工作褲"),
+        (42517, "黑星石治愈項環"),
+        (28860, "空鼠精准半指護手（複製品）"),
+        (1847, "白鋼重騎槍"),
+        (13024, "戎甲大師圍裙"),
+        (9362, "織蛛絨布"),
+        (22580, "野餐套裝"),
+        (10951, "聖虹詠咒垮褲"),
+        (48485, "宇宙皮革"),
+        (29653, "改良用的長桿"),
+        (43339, "嵌齒象革指環"),
+        (37751, "復興法杖"),
+        (19576, "鉬鋼煎鍋"),
+        (48484, "宇宙皮革細繩"),
+        (13915, "龍神治愈束腳褲（複製品）"),
+        (9029, "奇蜥禦敵馬褲"),
+        (34711, "迷彩絨強襲上裝"),
+        (19028, "鈀金強攻耳墜"),
+        (17968, "東方午餐組合：蕎麥面"),
+        (11879, "靈銀圓頭錘"),
+        (3708, "甲匠手套"),
+        (12010, "安菲瑟龍革流蘇靴"),
+        (7608, "蛟蜥革"),
+        (7108, "莊園大鍵琴"),
+        (30444, "唯美圓革刀"),
+        (24565, "崖畔亭的備用品"),
+        (12914, "深紅附魔墨水"),
+        (44303, "管弦樂琴樂譜：天災"),
+        (2665, "棉布包頭巾"),
+        (30473, "唯美巧匠項環"),
+        (14734, "荒神布游擊袖套"),
+        (37325, "豪勇錫杖"),
+        (2835, "纖維兜帽"),
+        (18209, "硬鋼禦敵鎖甲靴"),
+        (25803, "深金刺刀"),
+        (34592, "鐵木精准項環"),
+        (3377, "巨蟾蜍革馬褲"),
+        (11903, "靈銀鶴嘴鋤"),
+        (37833, "鈦鐵錠"),
+        (11003, "蛋白石強攻耳墜"),
+        (10880, "虹布治愈小丑帽"),
+        (1758, "業火戰斧"),
+        (2477, "綠金蒸餾器"),
+        (34698, "球粒隕石魔導戰鐮"),
+        (43889, "旅行提燈"),
+        (48174, "聖輝聖徒"),
+        (48320, "運送資材用的纖維板"),
+        (4612, "智力之靈藥"),
+        (27644, "呢絨束腰衣"),
+        (19928, "櫸木木材"),
+        (34754, "星點石英詠咒指環"),
+        (24259, "4級智力之靈水"),
+        (48471, "宇宙探索用的長筒靴"),
+        (41086, "澤羅姆斯的追憶"),
+        (34103, "黑麻禦敵軟甲褲"),
+        (34594, "鐵木詠咒項環"),
+        (7546, "輕鋼肩甲"),
+        (4713, "王冠蛋糕"),
+        (31897, "4級意力之幻藥"),
+        (39773, "面向收藏家的畫框"),
+        (19625, "紅玉棉布打底褲"),
+        (14181, "1級空白管弦樂琴樂譜"),
+        (27771, "1級意力之幻水"),
+        (14265, "管弦樂琴樂譜：死灰復燃"),
+        (36229, "5級耐力之幻水"),
+        (15822, "管弦樂琴樂譜：絕命怒嚎"),
+        (23816, "羅剎學士手甲"),
+        (19746, "魔象革指環"),
+        (26282, "矮人銀刺劍"),
+        (30391, "紙船章魚燒"),
+        (42896, "舊日王國禦敵鎧靴"),
+        (25684, "斯劍虎革詠咒護臂"),
+        (35406, "伽迦象革半手甲"),
+        (18694, "玉綢精准長褲"),
+        (4341, "透綠柱石項環"),
+        (2010, "銅鈴紫杉牧杖"),
+        (28746, "重建用的掃把"),
+        (48181, "聖輝牧杖"),
+        (12612, "1級剛力煉金溶劑"),
+        (1753, "青銅刺柄雙刃斧"),
+        (17035, "裁判所地板"),
+        (38919, "研究者大地打底褲"),
+        (5583, "駿鷹革護肘"),
+        (19508, "硬鋼鑄甲錘"),
+        (31038, "收藏用猛虎革寬邊帽"),
+        (48398, "宇宙球粒隕石板"),
+        (4448, "榍石戒指"),
+        (36890, "雪峰豪華吊燈"),
+        (31862, "總督治愈大衣"),
+        (35561, "東方室內噴泉"),
+        (5067, "玫瑰金錠"),
+        (31885, "總督精准手鐲"),
+        (27085, "深金鶴嘴鋤"),
+        (16689, "柚木強攻項環"),
+        (14731, "荒神革精准高筒靴"),
+        (34712, "球粒隕石強襲手套"),
+        (10598, "靈銀戰斧"),
+        (21697, "夜鋼戰斧"),
+        (46009, "鬃背獸肉排"),
+        (28745, "重建用的地毯"),
+        (19511, "刺球縫針"),
+        (12055, "發條推車"),
+        (26174, "三重石強攻項環"),
+        (14179, "智力之仙藥"),
+        (24523, "綠燈球藻台燈"),
+        (19521, "硬鋼平斬"),
+        (3238, "樵夫工作服"),
+        (6456, "林間內牆"),
+        (17322, "空鼠精准格鬥服"),
+        (2588, "蝲蛄丸子"),
+        (31833, "總督禦敵手鎧"),
+        (36059, "巨人新薯煎餅"),
+        (25826, "綠飄龍革精准手套"),
+        (46067, "儀仗禦敵耳墜"),
+        (36209, "阿爾馬斯提嗶嘰"),
+        (10843, "奇美纖維游擊兜帽"),
+        (35559, "雙軟墊"),
+        (38816, "山嶽防寒用品"),
+        (29431, "伊修加德新型制敵打底褲"),
+        (37775, "復興強襲護膝褲"),
+        (22901, "夜鋼研鉢"),
+        (4684, "煎菠菜"),
+        (2562, "黑鐵鐮刀"),
+        (7067, "甲匠鍛造爐"),
+        (3057, "黑鐵鱗鎧"),
+        (18097, "鋰輝石強攻項環"),
+        (13637, "頂級恢復藥"),
+        (13806, "亞拉戈重手鎧（複製品）"),
+        (26147, "力山羊精准打底褲"),
+        (14138, "洛夫坦山羊排"),
+        (17326, "空鼠游擊面具"),
+        (26135, "力山羊制敵打底褲"),
+        (25699, "島狼強攻手鐲"),
+        (16651, "重合金制敵鎧靴"),
+        (30815, "月讀命幽夜大弓"),
+        (41607, "翠光手槍"),
+        (8131, "長風引擎結合部元件"),
+        (31865, "總督治愈短靴"),
+        (30976, "收藏用山毛櫸復合弓"),
+        (10956, "星絹布詠咒打底褲"),
+        (30055, "達爾馬斯卡垂褶上裝"),
+        (4596, "耐力之藥"),
+        (18969, "鉻鐵長劍"),
+        (31020, "收藏用皇金耳墜"),
+        (21724, "真麻強襲長衣"),
+        (7972, "開放式火爐"),
+        (12860, "俄刻阿尼斯炸肉排"),
+        (18548, "嗶嘰治愈緊身衣"),
+        (35083, "古典風格治愈項環"),
+        (26024, "青金石強攻指環"),
+        (43617, "橙色向日葵頭花"),
+        (3104, "棉絨長外衣"),
+        (32249, "方形磚地"),
+        (19519, "硬鋼手鉗"),
+        (26316, "矮人棉治愈闊邊帽"),
+        (18382, "葛布精准軟甲褲"),
+        (48554, "工人的營養劑"),
+        (14068, "伊修加德枝形吊燈"),
+        (22429, "黑柳木材"),
+        (1953, "薩倫伽光弓"),
+        (41854, "混金振空擺"),
+        (11875, "靈銀鍛鐵錘"),
+        (43268, "卡扎納爾縫針"),
+        (42028, "高山亞麻精准鬥篷"),
+        (44928, "東方東屋"),
+        (42903, "舊日王國強襲上衣"),
+        (18981, "鈀金太陽儀"),
+        (29687, "花園磚牆"),
+        (15147, "衣帽架"),
+        (28877, "空鼠詠咒黑鐵筒靴（複製品）"),
+        (25671, "白麻游擊緊身衣"),
+        (29804, "第二期重建用的皮繩"),
+        (48476, "研究用的防水皮革"),
+        (21761, "黑柳治愈項鍊"),
+        (24799, "青龍裝甲"),
+        (1892, "楓木長弓"),
+        (2260, "鑲銀小圓盾"),
+        (8034, "強化玄鋼絲"),
+        (42317, "鈦金雙軍刀"),
+        (36109, "6級剛力之幻藥"),
+        (36703, "條約紡車"),
+        (9767, "波紋內牆"),
+        (43247, "白黃金縫針"),
+        (48599, "長期保存用的口糧"),
+        (34399, "鉍金盾"),
+        (35632, "收藏用輝翠銀巨劍"),
+        (22541, "獺獺壁燈"),
+        (45999, "3級意力之寶藥"),
+        (46083, "儀仗強攻指環"),
+        (43335, "相思木指環"),
+        (10656, "皇金巫書"),
+        (34444, "弗里金詠咒項環"),
+        (18663, "鉬鋼巨刃"),
+        (6613, "木制餐桌"),
+        (31079, "收藏用風味拌細面"),
+        (23834, "羅剎學士首飾"),
+        (27249, "低刺激性藥餌"),
+        (34304, "白鑞詠咒指環"),
+        (6593, "綠洲浴缸"),
+        (3092, "棉絨大地短上衣"),
+        (33611, "白帝寶劍"),
+        (35338, "輝翠銀廚刀"),
+        (28637, "雷克蘭德座椅"),
+        (41263, "面向收藏家的傳喚鈴"),
+        (48574, "宇宙探索用的秘藥"),
+        (12530, "精金鉚釘"),
+        (19585, "櫸木紡車"),
+        (2439, "秘銀平斬"),
+        (11984, "聖虹布草帽"),
+        (35785, "鈦銅振空擺"),
+        (18988, "真獅鷲革禦敵馬褲"),
+        (11982, "安菲瑟龍革皮靴"),
+        (14175, "3級意力煉金溶劑"),
+        (8453, "邪鬼戒指"),
+        (8454, "改良型鎢鋼重胸甲"),
+        (44008, "混金錠"),
+        (29830, "第二期重建用的煉金藥"),
+        (6597, "石像魔雕像"),
+        (19029, "鈀金精准耳墜"),
+        (6431, "林間遮蓬"),
+        (42884, "舊日王國穿甲劍"),
+        (27725, "黑瑪瑙"),
+        (2472, "黑鐵蒸餾器"),
+        (18257, "白銅禦敵手鐲"),
+        (28164, "珠寶攤"),
+        (11913, "祝聖栗木釣竿"),
+        (18709, "玉綢詠咒兜帽"),
+        (6982, "冰河外套"),
+        (21737, "地懶革游擊手套"),
+        (34409, "賽加羚羊革制敵筒靴"),
+        (42216, "粉綠柱石禦敵指環"),
+        (34744, "星點石英詠咒項環"),
+        (8848, "1級戰績以太轉輪"),
+        (20688, "石制東洋別墅房頂"),
+        (13826, "亞拉戈治愈手套（複製品）"),
+        (7547, "輕鋼三角褲"),
+        (2733, "棉絨巧匠無檐帽"),
+        (2556, "獸角手斧"),
+        (17567, "雲銀錠"),
+        (10975, "貴橄欖石禦敵手鐲"),
+        (34724, "球粒隕石游擊鎖甲靴"),
+        (48570, "宇宙探索用的巨匠藥"),
+        (44205, "收藏用鈷鎢刺劍"),
+        (25644, "深金戰輪"),
+        (29495, "3級智力之幻藥"),
+        (18385, "猛虎革游擊寬邊帽"),
+        (6430, "林間瓦片遮蓬"),
+        (48525, "研究用的宇宙線"),
+        (31871, "總督詠咒短靴"),
+        (40646, "常青藤吊燈"),
+        (40414, "陸行鳥寢室打底褲"),
+        (26332, "透鋰長石詠咒耳墜"),
+        (28870, "空鼠治愈鐵鈎護臂（複製品）"),
+        (39564, "緋光鋸刃"),
+        (6409, "綠洲八角窗"),
+        (21748, "真麻詠咒長袍"),
+        (36322, "輝煌工具的第二次加工部件：雕金"),
+        (3944, "駿鷹革長靴"),
+        (18543, "玉鋼游擊手鎧"),
+        (23100, "黃色大麗花頭花"),
+        (31108, "改良用油脂"),
+        (2449, "蟲牙針"),
+        (6364, "石制林間別墅房頂"),
+        (6479, "十分堅硬的庭院石"),
+        (37374, "福爾唐府邸壁爐"),
+        (2427, "綠金裁皮刀"),
+        (15926, "月火女式夏褲"),
+        (2296, "鈷鐵雙劍機關盾"),
+        (39638, "繼業者牧杖"),
+        (27161, "白麻巧匠包頭巾"),
+        (14850, "聖蜥蜴革背心"),
+        (18230, "紅玉棉布游擊背心"),
+        (28961, "石堆地板"),
+        (15589, "偃武匕首"),
+        (23098, "紅色大麗花頭花"),
+        (12856, "菌蒸皮皮拉魚"),
+        (33606, "白帝咒杖"),
+        (8039, "濃厚番茄醬"),
+        (1776, "白鋼巨斧"),
+        (9056, "奇蜥精准長靴"),
+        (6653, "毛絨絨莫古力"),
+        (27790, "意力之幻藥"),
+        (36105, "5級巧力之幻藥"),
+        (41064, "魅惑貓咪的必需品"),
+        (20477, "闊腿褲"),
+        (26338, "透鋰長石禦敵手鐲"),
+        (27189, "力山羊大地束膝褲"),
+        (14718, "荒神革制敵護甲"),
+        (35405, "黑麻胴著"),
+        (8555, "豹紋女式夏褲"),
+        (2731, "白銀作業眼鏡"),
+        (12021, "祝聖栗木指環"),
+        (27882, "生吃食用蠔"),
+        (42193, "粉綠柱石治愈半指手套"),
+        (48426, "宇宙探索用的保溫金屬"),
+        (3500, "駿鷹革腿套"),
+        (20721, "東洋吊頂行燈"),
+        (28852, "空鼠制敵鐵鈎筒靴（複製品）"),
+        (30970, "收藏用雪松長弓"),
+        (12581, "暗栗木木材"),
+        (19556, "玉鋼鶴嘴鋤"),
+        (37787, "復興治愈貝雷帽"),
+        (43225, "鈷鎢制革刀"),
+        (44153, "2級巧力之寶水"),
+        (10851, "獅鷲革游擊外套"),
+        (48545, "研究員的果凍飲料"),
+        (25856, "硬水鋁石治愈項環"),
+        (7082, "冬貝利壁燈"),
+        (43297, "薄絹巧匠騎兵褲"),
+        (24260, "4級意力之靈水"),
+        (3076, "古典短上衣"),
+        (25805, "深金堅盾"),
+        (38871, "尋找刺激用的工具"),
+        (39319, "綠色牽牛花頭花"),
+        (39774, "面向收藏家的武士刀"),
+        (30834, "朱焰短銃"),
+        (13769, "鷹獵人打底褲"),
+        (19560, "玉鋼銼刀"),
+        (31201, "第三期重建用的鶴嘴鋤"),
+        (10752, "精金制敵腿甲靴"),
+        (31861, "總督治愈頭冠"),
+        (27357, "硬鋼槍刃"),
+        (3443, "盜龍革馬褲"),
+        (13836, "亞拉戈高位虎爪（複製品）"),
+        (39788, "面向收藏家的黑色醬汁直面"),
+        (4565, "眼藥"),
+        (29826, "第二期重建用的街燈"),
+        (3308, "草布熱褲"),
+        (33880, "白色蝴蝶蘭耳飾"),
+        (17465, "盜龍革皮靴"),
+        (27230, "愈瘡木指環"),
+        (6566, "海濱書櫃"),
+        (11949, "精金碎石錘"),
+        (41880, "銀狼革游擊半指護手"),
+        (21770, "清銀精准戒指"),
+        (14251, "管弦樂琴樂譜：原始的審判"),
+        (10889, "青麻治愈長袍"),
+        (34086, "輝翠銀斷頭劍"),
+        (19841, "高原小麥粉"),
+        (44073, "燉煮羊駝肉排"),
+        (42056, "河岸石詠咒項環"),
+        (22720, "法被部件"),
+        (35349, "鉍金鶴嘴鋤"),
+        (30410, "大理石壁床"),
+        (23030, "迷你紗都"),
+        (34588, "藍鋯石治愈耳墜"),
+        (48580, "工人的果凍飲料"),
+        (26345, "透鋰長石精准指環"),
+        (17324, "空鼠精准馬褲"),
+        (35025, "古典風格小刀"),
+        (3696, "纖維禮服手套"),
+        (46025, "儀仗槍刃"),
+        (4569, "金針"),
+        (5069, "黃金錠"),
+        (46010, "儀仗長刀"),
+        (10796, "虹布精准小丑帽"),
+        (31179, "氣派華麗的外裝部件"),
+        (3625, "毒蜥蜴革半指護手"),
+        (28866, "空鼠游擊馬褲（複製品）"),
+        (43620, "黑色向日葵頭花"),
+        (36886, "雪峰古典門"),
+        (48326, "無人機用的金屬桿"),
+        (41990, "釕金彎刀"),
+        (48236, "宇宙加工品"),
+        (31096, "橙色秋英頭花"),
+        (28857, "空鼠強襲黑鐵筒靴（複製品）"),
+        (27990, "改良型亞麻褲襪"),
+        (18404, "星尖石強攻耳墜"),
+        (10647, "飛龍革魔導書"),
+        (25706, "島狼治愈指環"),
+        (24868, "寶石獸吊燈"),
+        (6141, "強心劑"),
+        (9748, "僱員文件櫃"),
+        (10718, "精金制敵開面盔"),
+        (24512, "木制台階"),
+        (14261, "管弦樂琴樂譜：最後的死鬥"),
+        (31051, "收藏用赤麻短裙"),
+        (31947, "第四期重建用的街燈"),
+        (36621, "收藏用球粒隕石蒸餾器"),
+        (48606, "基地小麥粉"),
+        (35036, "古典風格圓月輪"),
+        (34593, "鐵木治愈項環"),
+        (26461, "中庸強襲束膝褲"),
+        (9063, "織蛛長袍"),
+        (22437, "煎綠韭蔥"),
+        (19977, "紅玉棉布"),
+        (2625, "彩虹勺形魚餌"),
+        (13798, "亞拉戈治愈魔導書（複製品）"),
+        (36326, "輝煌工具的第二次加工部件：烹調"),
+        (12850, "伊修加德松餅"),
+        (1996, "楓木牧杖"),
+        (14178, "耐力之仙藥"),
+        (18665, "櫸木牧杖"),
+        (8005, "綠洲裝飾石牆"),
+        (5326, "棉絨"),
+        (2921, "鐵匠護鏡帽"),
+        (13664, "空賊游擊面具"),
+        (35072, "古典風格旗手半指護手"),
+        (27126, "鈦銅莫古力平底鍋"),
+        (12611, "黑火藥"),
+        (13813, "亞拉戈制敵鎖甲靴（複製品）"),
+        (20712, "東洋古典門牌"),
+        (10763, "長頸駝革強襲短衣"),
+        (30449, "唯美手斧"),
+        (10993, "黃水晶強攻項環"),
+        (8366, "旋風神嚎"),
+        (3726, "駿鷹革手套"),
+        (16687, "閃爍詠咒耳墜"),
+        (18693, "瞪羚革精准護臂"),
+        (38563, "綠色香豌豆花項鍊"),
+        (25033, "神聖女神之典"),
+        (26300, "海燕革強襲手套"),
+        (5766, "玉米黃染劑"),
+        (13833, "亞拉戈詠咒法靴（複製品）"),
+        (27927, "綠色繡球頭花"),
+        (15574, "無盡雲神長弓"),
+        (3322, "棉布牧羊褲"),
+        (31851, "總督精准護臂"),
+        (3146, "亞麻外袍"),
+        (12113, "莫古力綠雕"),
+        (18233, "牛鬼革游擊高筒靴"),
+        (16619, "綠色三色堇頭花"),
+        (20806, "藍色光明百合頭花"),
+        (48605, "基地蘑菇沙拉"),
+        (42924, "舊日王國詠咒護臂"),
+        (18698, "瞪羚革游擊護甲"),
+        (30394, "室內水塘"),
+        (44436, "探求永恆巧匠工作靴"),
+        (41614, "翠光刺劍"),
+        (42353, "嵌齒象革詠咒襯褲"),
+        (43263, "卡扎納爾手鋸"),
+        (41814, "艷翼蛇鳥獎杯"),
+        (8850, "1級新兵以太轉輪"),
+        (31163, "提煉實驗的用品"),
+        (36107, "5級智力之幻藥"),
+        (6432, "綠洲木制遮蓬"),
+        (13921, "龍神詠咒法靴（複製品）"),
+        (4723, "奶酪燴飯"),
+        (25986, "野蠻盜龍革精准護臂"),
+        (38556, "夢羽妖靈王魔機刃"),
+        (28753, "重建用的長靴"),
+        (31032, "收藏用飛龍革假面"),
+        (48315, "研究用的防水樹脂"),
+        (20729, "東洋座椅"),
+        (47475, "施工所需的防火布"),
+        (2837, "纖維大地兜帽"),
+        (37358, "碳烤香魚"),
+        (9486, "織蛛游擊襯衫"),
+        (15545, "光流釣竿"),
+        (44077, "鮭魚乾"),
+        (22552, "蠟燭組合"),
+        (23349, "靜魔藥"),
+        (21732, "真麻精准馬褲"),
+        (7104, "雙足飛龍棋子"),
+        (24143, "朱雀裝甲"),
+        (35023, "古典風格長槍"),
+        (31008, "收藏用卸鐵脛甲"),
+        (5512, "玻璃板"),
+        (35391, "球粒隕石鶴嘴鋤"),
+        (43619, "白色向日葵頭花"),
+        (26429, "木偶師"),
+        (14094, "泰坦之形"),
+        (11938, "星樺木紡車"),
+        (35425, "絳紅草布巧匠短上衣"),
+        (8450, "玫瑰金大地耳環"),
+        (18400, "葛布詠咒長裙"),
+        (35429, "絳紅草布大地貝雷帽"),
+        (37323, "豪勇大劍"),
+        (29406, "伊修加德新型雙刃斧"),
+        (41852, "奧闊鉻鐵圓月輪"),
+        (7548, "平紋披肩"),
+        (36231, "5級意力之幻水"),
+        (13859, "亞拉戈高位強襲胸甲（複製品）"),
+        (34383, "紅松木長槍"),
+        (11950, "精金加固碎石錘"),
+        (38247, "法外執行者皮鞋"),
+        (19821, "鬼殼燒"),
+        (16631, "柚木復合弓"),
+        (11951, "靈銀鐮刀"),
+        (37837, "7級耐力之幻水"),
+        (21721, "夜鋼制敵脛甲"),
+        (19019, "真獅鷲革治愈涼鞋"),
+        (30447, "唯美煎鍋"),
+        (2476, "秘銀蒸餾器"),
+        (44877, "甜美褶邊窗簾"),
+        (46859, "宇宙探索用的釣竿"),
+        (13857, "亞拉戈高位制敵鎧靴（複製品）"),
+        (17612, "生命之幻杖"),
+        (40647, "水晶都吊墜壁燈"),
+        (6395, "木制綠洲公館外牆"),
+        (48441, "基地皮鞋"),
+        (44089, "圓扇刺梨蛋糕"),
+        (20681, "混合制東洋小屋房頂"),
+        (39680, "繼業者詠咒貝雷帽"),
+        (36708, "條約巧匠包頭巾"),
+        (3645, "呢絨弓術手套"),
+        (8384, "賢王莫古斧"),
+        (27654, "白鋼頭甲"),
+        (1796, "海盜巨斧"),
+        (19893, "1級耐力之靈水"),
+        (36169, "輝翠銀錠"),
+        (12563, "飛龍革"),
+        (6503, "溶洞藍珊瑚"),
+        (23893, "浴室地板墊"),
+        (3239, "漁人休閒衫"),
+        (12103, "熊皮地毯"),
+        (43259, "鈦金研鉢"),
+        (42025, "錘頭鰐革強襲馬褲"),
+        (22732, "祖先招待用品"),
+        (9749, "僱員櫃台"),
+        (16709, "蒼穹蛋奶酒"),
+        (4284, "玫瑰金耳墜"),
+        (11027, "蛋白石精准耳墜"),
+        (14235, "管弦樂琴樂譜：走向世界"),
+        (25046, "無限鬼神之幻杖"),
+        (6647, "綠金台鐘"),
+        (24328, "邪龍怨影牧杖"),
+        (27673, "改良型亞麻僧袍"),
+        (31921, "第四期重建用的木箱"),
+        (31060, "收藏用古鳥革魔導書"),
+        (3640, "呢絨禮服手套"),
+        (5199, "冰眼石"),
+        (36891, "雪峰枝形吊燈"),
+        (2575, "新來者釣竿"),
+        (36062, "厄爾庇斯佳餚"),
+        (3407, "亞麻束膝褲"),
+        (28808, "空賊禦敵頭盔（複製品）"),
+        (3176, "呢絨長外衣"),
+        (43322, "落雷絹大地寬松七分褲"),
+        (42219, "粉綠柱石治愈指環"),
+        (2700, "棉布大地兜帽"),
+        (9719, "東方浴缸"),
+        (34577, "金毗羅鰐革白獅鷲手套"),
+        (14925, "青麻腿套"),
+        (6536, "迦樓羅沙發"),
+        (42167, "鈷鎢禦敵坎肩"),
+        (6644, "水八角盆栽"),
+        (17959, "東方化妝盒"),
+        (3914, "纖維禮鞋"),
+        (37355, "東方四柱床"),
+        (12616, "1級意力煉金溶劑"),
+        (8014, "大理石地板"),
+        (8844, "1級教練以太轉輪"),
+        (4349, "海藍石項環"),
+        (29408, "伊修加德新型長弓"),
+        (35336, "馬栗木紡車"),
+        (19717, "阿拉米格巧匠包頭巾"),
+        (19831, "煮雜菜"),
+        (47948, "黑色葉子花耳環"),
+        (48589, "工人的能量棒"),
+        (22913, "山師束褲"),
+        (40410, "仙人刺寢室打底褲"),
+        (43346, "克拉洛胡桃木大地手環"),
+        (25796, "仙果木牧杖"),
+        (42369, "白黃金詠咒護身手鐲"),
+        (28853, "空鼠強襲面具（複製品）"),
+        (6486, "黃銅庭院長椅"),
+        (2544, "民兵碎石錘"),
+        (31221, "第三期重建用的遮蓬"),
+        (6560, "海濱櫥櫃"),
+        (37356, "奇妙芭菲"),
+        (16937, "3級畫框"),
+        (48387, "耐衝擊金屬地板"),
+        (37750, "復興牧杖"),
+        (4150, "綠金手鍊"),
+        (3337, "山羊革襯褲"),
+        (29454, "伊修加德新型治愈手套"),
+        (38765, "面向收藏家的虹吸咖啡機"),
+        (30985, "收藏用靈銀鬥劍"),
+        (44428, "探求永恆研鉢"),
+        (30854, "青君文書"),
+        (23800, "羅剎拳士草履"),
+        (10394, "薩維奈臂紗"),
+        (25695, "烏鋼石精准項環"),
+        (34082, "輝翠銀戰斧"),
+        (2630, "草布大地兜帽"),
+        (23835, "羅剎術士首飾"),
+        (14900, "毛絨絨茄子騎士"),
+        (10791, "長頸駝革強襲護腿"),
+        (9235, "旋風神羽"),
+        (8168, "奇妙掃帚"),
+        (23842, "羅剎鬥士指飾"),
+        (6380, "木制海濱別墅外牆"),
+        (14256, "管弦樂琴樂譜：傲慢"),
+        (43206, "奧闊鉻鐵蒸餾器"),
+        (27777, "強泡鹼"),
+        (11987, "安菲瑟龍革翻邊帽"),
+        (6463, "海濱枝形吊燈"),
+        (25039, "無限鬼神之拳"),
+        (31214, "第三期重建用的肥皂"),
+        (4166, "綠松石手鐲"),
+        (3723, "玄鋼重手鎧"),
+        (2384, "新來者手鉗"),
+        (35626, "收藏用馬栗木笠"),
+        (21093, "金碟游樂場的紀念品部件"),
+        (34134, "伽迦象革詠咒高筒靴"),
+        (47351, "防毒面具"),
+        (48336, "基地淋浴"),
+        (17014, "迪亞波羅斯模型"),
+        (2487, "白鋼研鉢"),
+        (28616, "哈迪斯裝甲"),
+        (3699, "民兵手箍"),
+        (1937, "紫杉長弓"),
+        (34722, "球粒隕石游擊手套"),
+        (20436, "圓桌之太刀"),
+        (24327, "邪龍怨影手炮"),
+        (17334, "空鼠治愈馬褲"),
+        (11935, "聖雪松紡車"),
+        (6362, "木制林間別墅房頂"),
+        (15508, "加隆德大地工作褲"),
+        (14146, "光銀錠"),
+        (15650, "蒼穹雞蛋湯"),
+        (35378, "鐵木紡車"),
+        (48595, "基地三明治"),
+        (16708, "半熊革"),
+        (25967, "靈青禦敵胸甲"),
+        (12224, "藍鐵礦"),
+        (38245, "法外執行者手套"),
+        (9068, "白金治愈手鐲"),
+        (18036, "硬鋼三尖槍"),
+        (24258, "4級耐力之靈水"),
+        (38555, "夢羽妖靈王刺劍"),
+        (7975, "龍血樹盆栽"),
+        (3854, "秘銀鎧靴"),
+        (7131, "鄉村內牆"),
+        (17640, "管弦樂琴樂譜：不屈之刃"),
+        (16705, "重合金錠"),
+        (35642, "收藏用白鑞項環"),
+        (38617, "櫃台咖啡套裝"),
+        (10387, "薩維奈包頭巾"),
+        (31756, "最終改良用聖水"),
+        (4697, "胡桃麵包"),
+        (3561, "棉布工作手套"),
+        (35464, "蛇牛革大地耳墜"),
+        (6978, "高地兜帽"),
+        (2767, "骷髏眼罩"),
+        (27145, "矮人銀縫針"),
+        (9042, "織蛛強襲頭巾"),
+        (10643, "硬銀玉杖"),
+        (42035, "錘頭鰐革游擊馬褲"),
+        (10615, "樺木復合弓"),
+        (48440, "室外活動用的工作背包"),
+        (46081, "儀仗詠咒手鐲"),
+        (35659, "收藏用錳鋼附魔墨水"),
+        (31937, "第四期重建用的梯子"),
+        (34699, "球粒隕石魔導盾"),
+        (2470, "黃銅蒸餾器"),
+        (11037, "靈銀治愈項鍊"),
+        (17900, "利姆薩·羅敏薩式腹當"),
+        (38819, "可愛的禮物"),
+        (37349, "大理石室內噴泉"),
+        (9283, "伴郎禮服"),
+        (19628, "牛鬼革短衣"),
+        (28846, "空鼠禦敵馬褲（複製品）"),
+        (39703, "繼業者治愈盔甲戒指"),
+        (23154, "防鯊用品部件"),
+        (19828, "鹽烤石楠嘉魚"),
+        (2522, "白羽鶴嘴鋤"),
+        (48323, "宇宙硅化木寶珠"),
+        (35783, "靈青飛翼"),
+        (23316, "管弦樂琴樂譜：海中"),
+        (20379, "驚雷電掣"),
+        (6476, "黑衣闊葉樹"),
+        (31195, "第三期重建用的鐵環"),
+        (31061, "收藏用古鳥革魔導典"),
+        (27669, "棉絨外袍"),
+        (44166, "2級意力之寶藥"),
+        (4610, "巧力之靈藥"),
+        (48520, "改良設備用的海濱地毯"),
+        (33896, "再陷艱辛勞苦之懼"),
+        (19809, "蕎麥茶"),
+        (41884, "雪木棉治愈束腰獵衣"),
+        (48571, "宇宙探索用的防鏽加工塗料"),
+        (9733, "聚靈棺"),
+        (13871, "亞拉戈高位治愈喇叭褲（複製品）"),
+        (10989, "靈銀強攻項鍊"),
+        (7088, "寶石獸沙發"),
+        (31188, "第三期重建用的鞣革"),
+        (46040, "儀仗制敵騎兵褲"),
+        (26178, "三重石禦敵手鐲"),
+        (5579, "手套絹布"),
+        (13319, "樟木詠咒腕環"),
+        (26002, "靈青詠咒頭甲"),
+        (3668, "盜龍革耐熱手套"),
+        (8849, "1級軍功以太轉輪"),
+        (42915, "舊日王國游擊護膝褲"),
+        (12626, "意力之龍藥"),
+        (28469, "中庸鶴嘴鋤"),
+        (34546, "錳鋼圓月輪"),
+        (18536, "嗶嘰精准緊身衣"),
+        (10700, "長頸駝革禦敵馬褲"),
+        (36626, "收藏用無花果凍糕"),
+        (25009, "鐵紋鋼"),
+        (30455, "唯美平斬"),
+        (34690, "蛇牛革魔導書"),
+        (3572, "山羊革連指輕手套"),
+        (11897, "白鈦蒸餾器"),
+        (18699, "鉬鋼游擊護臂"),
+        (17463, "呢絨背帶襯衫"),
+        (33296, "庭園工具架"),
+        (7988, "綠洲軟墊"),
+        (18958, "琅玕詠咒項環"),
+        (44441, "探求永恆大地工作靴"),
+        (39878, "球形甘藍燉菜"),
+        (37701, "獺獺噴泉建造用合成樹脂"),
+        (4360, "盜龍革項環"),
+        (42299, "鈦金利爪"),
+        (10606, "白鈦騎槍"),
+        (31050, "收藏用奇美纖維鬥服"),
+        (3947, "雲杉木鞋"),
+        (48458, "基地皮革長椅"),
+        (2540, "革柄白鋼碎石錘"),
+        (44184, "汆燙龍蝦"),
+        (32940, "4級巧力之幻水"),
+        (42019, "釕金制敵臂甲"),
+        (11978, "古鳥革巧匠長筒靴"),
+        (43623, "輝翠雙牙"),
+        (1614, "黑鐵短劍"),
+        (6591, "海濱浴缸"),
+        (22736, "養馬必需品"),
+        (31226, "第三期重建用的特供門"),
+        (10981, "貴橄欖石禦敵耳墜"),
+        (2468, "青銅蒸餾器"),
+        (1862, "鈷鐵戰戟"),
+        (7023, "鈷鐵連接板"),
+        (36313, "輝煌工具的第一次加工部件：鑄甲"),
+        (2933, "特務三角帽"),
+        (4614, "御火藥"),
+        (35063, "古典風格雙劍鬥士三角褲"),
+        (10803, "虹布精准長袍"),
+        (39657, "繼業者制敵半指手套"),
+        (48472, "宇宙探索用的採集工作服"),
+        (12644, "皇金圓筒"),
+        (1875, "巨膽三尖槍"),
+        (25794, "深金巨劍"),
+        (24501, "仿制小窗"),
+        (6667, "妖精壁燈"),
+        (12618, "2級巧力煉金溶劑"),
+        (25797, "仙果木長杖"),
+        (17902, "樣品白銀戒指"),
+        (48405, "無人機用的齒輪"),
+        (35441, "蛇牛革大地半指手套"),
+        (41867, "銀狼革制敵戰靴"),
+        (2268, "雙劍機關盾"),
+        (11891, "靈銀縫針"),
+        (8299, "鑽石戒指"),
+        (15505, "加隆德大地皮帽"),
+        (37806, "復興詠咒項鍊"),
+        (10806, "祝聖青麻精准工作服"),
+        (27859, "鹽烤白金鯛魚"),
+        (11937, "樺木紡車"),
+        (41902, "銀狼革詠咒項鍊"),
+        (27865, "煎蘑菇"),
+        (48598, "面向研究員的黑麥粉"),
+        (2922, "甲匠遮面罩"),
+        (27679, "改良型纖維長袍"),
+        (2409, "新來者砂輪機"),
+        (27214, "魔銀手鐲"),
+        (17916, "大迦迦納怪鳥的毛皮"),
+        (48343, "基地灶台"),
+        (28859, "空鼠精准格鬥服（複製品）"),
+        (38550, "夢羽妖靈王法杖"),
+        (11049, "靈銀治愈耳環"),
+        (1602, "青銅手半劍"),
+        (25679, "白麻治愈騎兵褲"),
+        (4162, "琥珀手鐲"),
+        (34246, "輝翠銀輪刃"),
+        (4731, "生薑餅乾"),
+        (8294, "祖母綠手鐲"),
+        (7997, "活梯"),
+        (8780, "林間四柱床"),
+        (10894, "古鳥革治愈手套"),
+        (35021, "古典風格旋棍"),
+        (40399, "橙色菊花頭花"),
+        (6460, "綠洲地板"),
+        (15462, "新世界頭飾"),
+        (27641, "改良型野豬革盔帽"),
+        (16779, "壁掛型間接照明"),
+        (35022, "古典風格戰斧"),
+        (2578, "胡桃釣竿"),
+        (27208, "斯劍虎革項環"),
+        (36839, "校園打底褲"),
+        (5181, "紅電氣石"),
+        (26114, "鈦銅斷頭劍"),
+        (27723, "三重石"),
+        (37380, "藍羽星盤"),
+        (23839, "羅剎學士手飾"),
+        (10972, "硬銀禦敵手鐲"),
+        (44086, "奶油熱巧克力"),
+        (4606, "耐力之秘藥"),
+        (17962, "須佐之男模型"),
+        (15927, "月火女式綁帶涼鞋"),
+        (38618, "露營帳篷"),
+        (17854, "管弦樂琴樂譜：陰謀美計"),
+        (3780, "硬革尖頭靴"),
+        (3823, "棉絨高跟鞋"),
+        (12527, "精金錠"),
+        (37367, "鋼鐵座椅"),
+        (26185, "三重石精准指環"),
+        (3423, "野豬革短裙"),
+        (18043, "白銅魔導書"),
+        (4650, "水煮蛋"),
+        (15549, "荒神砂輪機"),
+        (41109, "高級皮革沙發"),
+        (4174, "特務手鍊"),
+        (37298, "紫色比爾格紫羅蘭項環"),
+        (48101, "花店花園大門"),
+        (26181, "三重石治愈手鐲"),
+        (19756, "瞪羚革指環"),
+        (19605, "阿拉米格圓革刀"),
+        (33110, "管弦樂琴樂譜：漆黑王狼重現世間"),
+        (14184, "光銀塊"),
+        (18259, "白銅精准手鐲"),
+        (2680, "黃銅開面盔"),
+        (11896, "硬銀蒸餾器"),
+        (42349, "嵌齒象革治愈筒靴"),
+        (11016, "皇金精准項鍊"),
+        (18266, "姆德斯德角詠咒指環"),
+        (5179, "紫水晶"),
+        (7022, "白鋼連接板"),
+        (18398, "葛布詠咒長袍"),
+        (20778, "梅氏一族的日用品"),
+        (42507, "落雷絹詠咒軟甲褲"),
+        (35446, "紫黃晶巧匠腕環"),
+        (48365, "宇宙探索用的煎鍋"),
+        (3276, "草布牧羊褲"),
+        (42176, "獰豹革強襲頭帶"),
+        (6561, "林間櫥櫃"),
+        (31753, "最終改良用蒼天石"),
+        (31828, "總督槍刃"),
+        (35393, "完滿木釣竿"),
+        (2233, "梣木方盾"),
+        (19725, "阿拉米格大地打底褲"),
+        (31002, "收藏用靈銀雙劍機關盾"),
+        (13018, "鑌鐵大師護目鏡"),
+        (26019, "野蠻盜龍革強攻手鐲"),
+        (7087, "寶石獸圓桌"),
+        (3427, "呢絨軟甲褲"),
+        (18692, "玉綢精准長袍"),
+        (7105, "城塞棋子"),
+        (29832, "第二期重建用的特供衣櫃"),
+        (42303, "相思木符杖"),
+        (3605, "白鋼鱗指套"),
+        (11905, "白鈦鶴嘴鋤"),
+        (39320, "橙色牽牛花頭花"),
+        (28147, "桌台帽架"),
+        (35062, "古典風格雙劍鬥士袖甲"),
+        (19540, "卸鐵手鉗"),
+        (3343, "棉布束膝褲"),
+        (35332, "輝翠銀銼刀"),
+        (3425, "呢絨褲襪"),
+        (4856, "丁香油"),
+        (2794, "秘銀頭冠（碧璽）"),
+        (26492, "中庸詠咒耳墜"),
+        (15568, "驚雷觀天"),
+        (41847, "奧闊鉻鐵火槍"),
+        (42212, "粉綠柱石強攻手鐲"),
+        (23827, "羅剎鬥士耳飾"),
+        (27225, "矮人銀指環"),
+        (4621, "御冰妙藥"),
+        (5586, "玄鋼手鎧部件"),
+        (11898, "精金蒸餾器"),
+        (37800, "復興治愈耳墜"),
+        (4721, "五海雜燴湯"),
+        (44074, "梅斯卡香煎沼澤鬼魚肉"),
+        (22424, "3級耐力之靈水"),
+        (16679, "星絨詠咒長手套"),
+        (27724, "透鋰長石"),
+        (31958, "第四期重建用的特供坎肩"),
+        (4639, "栗鼠肉排"),
+        (35431, "金毗羅鰐革大地手套"),
+        (48633, "宇宙發酵食品"),
+        (38577, "樹枝盆栽掛架"),
+        (15165, "藍天內牆"),
+        (36873, "石制雪峰小屋外牆"),
+        (10663, "皇金天星盤"),
+        (10669, "精金重盾"),
+        (4170, "龜甲腕環"),
+        (19542, "卸鐵平斬"),
+        (31194, "第三期重建用的鉚釘"),
+        (23770, "羅剎大斧"),
+        (23783, "羅剎大盾"),
+        (15807, "管弦樂琴樂譜：缺失的書頁"),
+        (17727, "別具匠心的禮物部件"),
+        (30841, "朱焰細劍"),
+        (28818, "空賊強襲面具（複製品）"),
+        (18407, "星尖石詠咒耳墜"),
+        (19021, "真獅鷲革詠咒寬邊帽"),
+        (28856, "空鼠強襲馬褲（複製品）"),
+        (18050, "硬鋼禦敵鱗鎧"),
+        (30983, "收藏用白梣木耳墜"),
+        (7093, "寶石獸掛鐘"),
+        (19623, "紅玉棉布短上衣"),
+        (4155, "盜龍革大地手環"),
+        (12528, "靈銀鉚釘"),
+        (5811, "蜂鳥粉染劑"),
+        (14157, "硬化樟木木材"),
+        (10761, "虹布強襲外套"),
+        (34433, "雪亞麻詠咒垮褲"),
+        (11046, "皇金治愈手鐲"),
+        (2063, "風華咒角"),
+        (3257, "玄鋼鎖子鎧"),
+        (8857, "1級耐久以太轉輪"),
+        (6401, "海濱拱窗"),
+        (7074, "密林掛鐘"),
+        (31904, "軟果糕"),
+        (2294, "鳶盾"),
+        (2491, "鈷鐵研鉢"),
+        (3213, "盜龍革皮甲"),
+        (10882, "硬銀單眼鏡"),
+        (27649, "盜龍革襯衫"),
+        (29651, "改良用的合成樹脂"),
+        (47353, "施工所需的防火靴"),
+        (43309, "犎牛嗶嘰大地工作帽"),
+        (11050, "硬銀治愈耳墜"),
+        (35415, "雪亞麻布巧匠工作坎肩"),
+        (48417, "基地作業機械臂"),
+        (34098, "輝翠銀奪命鐮"),
+        (28858, "空鼠精准面具（複製品）"),
+        (48108, "紅白幕布屏風"),
+        (46082, "儀仗禦敵指環"),
+        (31057, "收藏用虹繭鬥上裝"),
+        (14720, "荒神布制敵襯褲"),
+        (16635, "柚木牧杖"),
+        (7537, "水手褲"),
+        (21713, "夜鋼禦敵手鎧"),
+        (46074, "儀仗精准項鍊"),
+        (14186, "刺繡針"),
+        (12233, "亞拉戈高位合成獸革"),
+        (3542, "硬革連指輕手套"),
+        (37348, "木柴架"),
+        (36197, "棕櫚木材"),
+        (4826, "小麥粉"),
+        (28471, "中庸釣竿"),
+        (43274, "卡扎納爾羊角錘"),
+        (10655, "巨龍革魔導典"),
+        (18723, "鈀金治愈項環"),
+        (28994, "紅寶石神兵像"),
+        (30056, "達爾馬斯卡垂褶下裝"),
+        (5335, "露線"),
+        (15151, "外科室幕簾"),
+        (10396, "薩維奈涼鞋"),
+        (27874, "濃縮奶油咖啡"),
+        (3478, "民兵緊身褲"),
+        (35402, "球粒隕石碎石錘"),
+        (34132, "黑麻詠咒半指護手"),
+        (13591, "鈦合金鏡"),
+        (47347, "切削施工所需的手套"),
+        (37357, "游末邦睡榻"),
+        (8011, "條紋內牆"),
+        (9061, "白金精准戒指"),
+        (4605, "巧力之秘藥"),
+        (36869, "木制雪峰別墅房頂"),
+        (8385, "賢王莫古叉"),
+        (41259, "面向收藏家的三角帽"),
+        (12012, "祝聖栗木項鍊"),
+        (35354, "鉍金手鉗"),
+        (34394, "弗里金重刺劍"),
+        (36081, "加雷馬奶酪"),
+        (10896, "青麻治愈半手套"),
+        (19902, "翠銀附魔墨水"),
+        (5795, "靛青藍染劑"),
+        (36036, "賢人麵包"),
+        (43898, "醃刺梨果汁"),
+        (31217, "第三期重建用的火爐"),
+        (36881, "雪峰古典窗"),
+        (6516, "魔界花座椅"),
+        (9287, "伴娘長襪"),
+        (1871, "古典戰戈"),
+        (8010, "古典內牆"),
+        (11881, "白鈦圓頭錘"),
+        (35460, "完滿木巧匠耳墜"),
+        (24865, "白色鈴蘭頭花"),
+        (13037, "精革大師工作靴"),
+        (16690, "柚木精准項環"),
+        (6446, "海濱瓦頂圍牆"),
+        (5522, "泡鹼"),
+        (27240, "油畫布的修復素材"),
+        (31816, "總督長槍"),
+        (25034, "神聖女神之儀"),
+        (17549, "近東風格的收藏品"),
+        (17639, "管弦樂琴樂譜：武神降臨"),
+        (13035, "精革大師手套"),
+        (42367, "白黃金精准護身手鐲"),
+        (43237, "深紅木紡車"),
+        (35321, "輝翠銀橫頭錘"),
+        (29680, "紅木溝渠"),
+        (17958, "東方煙袋盒"),
+        (15548, "光銀手鉗"),
+        (19635, "葛布巧匠及膝褲"),
+        (20428, "無盡雲神刀"),
+        (41870, "銀狼革強襲半指護手"),
+        (39786, "面向收藏家的外套"),
+        (2504, "地獄廚鍋"),
+        (44188, "收藏用相思木法杖"),
+        (18218, "紅玉棉布強襲背心"),
+        (5587, "玄鋼足鎧部件"),
+        (13273, "擊拳手套"),
+        (48354, "大型設備用的耐壓玻璃"),
+        (16597, "青麻鬥篷"),
+        (44050, "重鎢墨水"),
+        (23771, "羅剎長槍"),
+        (21743, "真麻治愈手套"),
+        (11954, "精金加固鐮刀"),
+        (1998, "梣木根杖"),
+        (18705, "瞪羚革治愈手套"),
+        (1716, "龜甲骨拳"),
+        (48344, "基地隔板"),
+        (39560, "緋光魔導典"),
+        (42184, "薄絹精准長褲"),
+        (43219, "奧闊鉻鐵碎石錘"),
+        (41991, "釕金旋棍"),
+        (31845, "總督強襲護臂"),
+        (7099, "冬貝利方形地毯"),
+        (25862, "硬水鋁石詠咒手鐲"),
+        (10717, "安菲瑟龍革制敵頭盔"),
+        (3537, "硬革護腕"),
+        (16671, "星絨治愈兜帽"),
+        (24563, "崖畔亭蒸餾酒必需品"),
+        (27666, "改良型棉布厚袍"),
+        (22423, "3級巧力之靈水"),
+        (20704, "東洋木制煙囪"),
+        (8784, "龍神獎杯"),
+        (4714, "番茄派"),
+        (8133, "長風重要元件"),
+        (2619, "重鐵板鈎"),
+        (20560, "吉祥天女裝甲"),
+        (48489, "研究用的纖維"),
+        (26328, "透鋰長石禦敵耳墜"),
+        (18523, "玉鋼制敵戰盔"),
+        (4611, "耐力之靈藥"),
+        (48469, "改良設備用的橢圓地毯"),
+        (38268, "加雷馬披薩"),
+        (4220, "榍石耳墜"),
+        (10941, "安菲瑟龍革詠咒手套"),
+        (23331, "管弦樂琴樂譜：月下美人"),
+        (4232, "秘銀耳環"),
+        (30381, "光之獎杯"),
+        (26304, "矮人棉精准包頭巾"),
+        (17930, "血液反應藥"),
+        (35628, "收藏用紅松木紡車"),
+        (34300, "白鑞禦敵指環"),
+        (40641, "鄉村原木座椅"),
+        (10812, "長頸駝革精准護臂"),
+        (28813, "空賊制敵頭盔（複製品）"),
+        (7504, "黃金眼鏡"),
+        (10952, "青麻詠咒燈籠褲"),
+        (15465, "新世界喇叭褲"),
+        (43296, "獰豹革巧匠半指手套"),
+        (27643, "改良型呢絨寬邊帽"),
+        (13269, "高貴鐘形帽"),
+        (35467, "蛇牛革大地指環"),
+        (13771, "巨龍革打底褲"),
+        (42458, "卡扎納爾法杖"),
+        (10634, "雪松手杖"),
+        (35846, "炸魚餅必需品"),
+        (13902, "龍神強襲頭冠（複製品）"),
+        (2371, "新來者鑄甲錘"),
+        (34249, "輝翠銀鳶盾"),
+        (4595, "巧力之藥"),
+        (48335, "宇宙探索用的橫頭錘"),
+        (35590, "黃金動力零件"),
+        (10766, "奇美纖維強襲罩衣"),
+        (9373, "白金附魔墨水"),
+        (39686, "繼業者強攻耳墜"),
+        (48332, "改良設備用的大型爐子"),
+        (42496, "卡扎納爾游擊手套"),
+        (2695, "黃銅頭冠（天青石）"),
+        (4335, "秘銀衝鋒項環"),
+        (33602, "白帝忍鐮"),
+        (39565, "緋光圓月輪"),
+        (26150, "鈦銅游擊頭帶"),
+        (30457, "唯美研鉢"),
+        (48317, "基地裝訂封面"),
+        (37766, "復興禦敵鎧靴"),
+        (23843, "羅剎弓士指飾"),
+        (42505, "落雷絹詠咒長衣"),
+        (41993, "木棉木長槍"),
+        (48584, "綠色宇宙煉金藥"),
+        (22530, "紅磚內牆"),
+        (31099, "黑色秋英頭花"),
+        (36210, "雪亞麻布"),
+        (6543, "林間睡床"),
+        (38560, "紅色香豌豆花項鍊"),
+        (2241, "黑鐵機關盾"),
+        (7077, "冬貝利沙發"),
+        (11883, "靈銀工藝錘"),
+        (34301, "白鑞強攻指環"),
+        (9054, "奇蜥弓術手套"),
+        (25051, "無限鬼神之太刀"),
+        (22555, "林間隔板門"),
+        (26288, "海燕革禦敵手套"),
+        (27243, "魯特琴的修復素材"),
+        (2015, "胡桃木牧杖"),
+        (2874, "綠金頭冠（碧璽）"),
+        (15537, "光銀圓頭錘"),
+        (28131, "東方風格室內庭園"),
+        (32243, "服裝展示窗"),
+        (41258, "面向收藏家的皮盾"),
+        (18077, "迦迦納怪鳥革游擊涼鞋"),
+        (2417, "青銅裁皮刀"),
+        (25822, "綠飄龍革強襲高筒靴"),
+        (3563, "黑鐵手鎧"),
+        (33028, "紅色康乃馨耳環"),
+        (14234, "管弦樂琴樂譜：唯一的行路"),
+        (37318, "豪勇鐵甲"),
+        (42927, "舊日王國禦敵耳墜"),
+        (2660, "業餘頭甲"),
+        (18732, "鈀金精准盔甲戒指"),
+        (24509, "大草原式廚房"),
+        (27228, "愈瘡木項鍊"),
+        (22436, "紅蓮特飲"),
+        (8372, "旋風神懷"),
+        (46063, "儀仗詠咒束腰衣"),
+        (22724, "鯰魚精人偶部件"),
+        (25987, "仙子棉精准襯褲"),
+        (7982, "始皇寶座"),
+        (19842, "木棉豆腐"),
+        (27217, "白梣木耳墜"),
+        (44231, "收藏用釀柿子椒"),
+        (20716, "東洋內牆"),
+        (4583, "麻痹猛毒藥"),
+        (14187, "獅鷲革細繩"),
+        (26296, "矮人銀制敵短靴"),
+        (13839, "亞拉戈高位復合弓（複製品）"),
+        (3191, "盜龍革格鬥服"),
+        (46070, "儀仗治愈耳墜"),
+        (21726, "真麻強襲軟甲褲"),
+        (29452, "伊修加德新型治愈軍帽"),
+        (48303, "加工檢驗用的樹脂"),
+        (19827, "燉河鯛"),
+        (13002, "騎士詠咒工作服"),
+        (6466, "林間枝形吊燈"),
+        (43235, "深紅木砂輪機"),
+        (13647, "空賊制敵外套"),
+        (34718, "迷彩絨精准打底褲"),
+        (6398, "木制綠洲別墅外牆"),
+        (29503, "醃鯡魚"),
+        (18395, "猛虎革治愈長筒靴"),
+        (5368, "紫杉木材"),
+        (26343, "透鋰長石禦敵指環"),
+        (13071, "莊園爐"),
+        (44432, "探求永恆巧匠頭飾"),
+        (42305, "嵌齒象革魔導書"),
+        (15963, "威爾特甘斯級飛空艇模型"),
+        (40400, "紫色菊花頭花"),
+        (17890, "小型錘"),
+        (48371, "月球車用的金屬環"),
+        (43239, "鈷鎢廚刀"),
+        (42464, "卡扎納爾太陽儀"),
+        (18235, "白銅頭鏈"),
+        (23810, "羅剎間士手甲"),
+        (31041, "收藏用斯劍虎革軟甲褲"),
+        (43615, "黃色向日葵頭花"),
+        (29963, "3級剛力之幻水"),
+        (30402, "複製扶手"),
+        (36874, "混合制雪峰小屋外牆"),
+        (46972, "宇宙探索用的挖掘機"),
+        (10744, "精金制敵鎖甲騎兵褲"),
+        (48579, "工人的營養果凍"),
+        (26111, "沙柚木刺槍"),
+        (48512, "加工檢驗用的線團"),
+        (47945, "橙色葉子花耳環"),
+        (2501, "純白平底鍋"),
+        (25858, "硬水鋁石禦敵手鐲"),
+        (21934, "混色鬱金香腕帶"),
+        (27175, "野蠻盜龍革巧匠長靴"),
+        (41260, "面向收藏家的月之幕簾"),
+        (23884, "紅木雙層床"),
+        (12604, "聖水"),
+        (19547, "卸鐵鐮刀"),
+        (2374, "白鋼鑄甲錘"),
+        (23845, "羅剎術士指飾"),
+        (43214, "五加木砂輪機"),
+        (12105, "戈耳狄零式寫字檯"),
+        (36702, "條約平斬"),
+        (10530, "巨膽天球儀"),
+        (10678, "聖虹禦敵襯衫"),
+        (30427, "切開的西瓜"),
+        (30497, "印刷必需品"),
+        (25673, "白麻游擊騎兵褲"),
+        (46024, "儀仗小劍"),
+        (5090, "綠金環"),
+        (16677, "星絨詠咒兜帽"),
+        (15143, "博古架"),
+        (31075, "收藏用千層長頸駝焗野菜"),
+        (27284, "水晶都廚房壁爐"),
+        (25674, "斯劍虎革游擊筒靴"),
+        (41113, "巨型鐘錶窗"),
+        (7020, "鈷鐵釘"),
+        (41606, "翠光巨劍"),
+        (23880, "毛線編織物"),
+        (2433, "黑鐵平斬"),
+        (27188, "纏尾蛟革大地手套"),
+        (36841, "系扣校園外套"),
+        (18193, "落葉松復合弓"),
+        (43602, "艷翼蛇鳥裝甲"),
+        (16633, "重合金巨劍"),
+        (18964, "琅玕禦敵指環"),
+        (18718, "鉬鋼治愈耳墜"),
+        (10732, "精金制敵臂甲"),
+        (46060, "儀仗治愈騎兵褲"),
+        (19736, "牛鬼革指環"),
+        (12085, "山嶽圓桌"),
+        (6646, "吊蘭盆栽"),
+        (6619, "莊園書桌"),
+        (25976, "靈青制敵鎧靴"),
+        (5812, "仙子梅染劑"),
+        (18977, "榧木牧杖"),
+        (7025, "棉布帆布"),
+        (13095, "綠砂內牆"),
+        (18970, "鉻鐵拳套"),
+        (10798, "白鈦精准口罩"),
+        (22731, "游牧民風格的商品"),
+        (30848, "青君短刀"),
+        (29461, "伊修加德新型詠咒打底褲"),
+        (48611, "加工檢驗用的小麥粉"),
+        (18527, "玉鋼制敵脛甲"),
+        (35382, "錳鋼園藝鐮"),
+        (6578, "綠洲隔離牆"),
+        (13257, "紅色貝雷帽"),
+        (11076, "皇金詠咒耳墜"),
+        (46051, "儀仗精准尖頭靴"),
+        (1736, "巨膽利爪"),
+        (21823, "阿爾法玩偶"),
+        (20720, "東洋吊頂雪洞燈"),
+        (44178, "圖萊尤拉燉沼澤鬼魚"),
+        (30853, "青君經典"),
+        (13025, "戎甲大師工作手套"),
+        (4601, "耐力之妙藥"),
+        (1893, "白羽楓木短弓"),
+        (2661, "業餘兜帽"),
+        (15097, "亞拉戈法杖（複製品）"),
+        (2482, "青銅研鉢"),
+        (39914, "船景跳跳樂建造用部件"),
+        (16667, "半熊革游擊手套"),
+        (35341, "鉍金手鋸"),
+        (35647, "收藏用山雞革騎兵褲"),
+        (22520, "渡渡鳥登記台"),
+        (31054, "收藏用嗶嘰騎兵褲"),
+        (6443, "海濱標旗"),
+        (4648, "葡萄酒煎豹肉"),
+        (17023, "猴面雀落地燈"),
+        (35058, "古典風格弓鬥士三角褲"),
+        (44030, "高山亞麻布"),
+        (41891, "雪木棉詠咒短裙"),
+        (19522, "落葉松紡車"),
+        (34302, "白鑞精准指環"),
+        (44169, "魔匠藥液"),
+        (9090, "黑檀長杖"),
+        (34279, "山雞革治愈高筒靴"),
+        (37798, "復興強攻耳墜"),
+        (18071, "迦迦納怪鳥革精准筒靴"),
+        (3716, "漁人手套"),
+        (48430, "無人機用的小型鏡頭"),
+        (12862, "洛夫坦山羊湯"),
+        (35639, "收藏用錳鋼貝希摩斯王戰甲"),
+        (30831, "朱焰大弓"),
+        (16720, "意力之強化仙藥"),
+        (29469, "伊修加德新型禦敵項環"),
+        (33609, "白帝天儀"),
+        (34270, "阿爾馬斯提游擊頭帶"),
+        (3557, "黑鐵臂甲"),
+        (33279, "酒品陳列架"),
+        (13908, "龍神精准罩衣（複製品）"),
+        (2548, "黑鐵手斧"),
+        (6425, "風向陸行鳥"),
+        (18962, "琅玕治愈腕環"),
+        (4260, "鋯石耳墜"),
+        (22531, "白色木地板"),
+        (19631, "牛鬼革大地筒靴"),
+        (26169, "三重石強攻耳墜"),
+        (1920, "秘銀騎兵弓"),
+        (17539, "黃色櫻花頭花"),
+        (15585, "偃武利爪"),
+        (31018, "收藏用硬銀單眼鏡"),
+        (47919, "保鏢槍帶背心"),
+        (11983, "靈銀大地護目鏡"),
+        (19569, "鉬鋼圓手鋸"),
+        (10619, "白鈦波刃"),
+        (46044, "儀仗強襲護臂"),
+        (34127, "黑麻治愈半指護手"),
+        (27358, "硬鋼圓月輪"),
+        (7148, "樹林地板"),
+        (19007, "真獅鷲革精准筒靴"),
+        (46038, "儀仗制敵護甲"),
+        (24179, "橙色馬蹄蓮頭花"),
+        (8660, "青銅短刀"),
+        (44970, "靠墊長椅"),
+        (29656, "改良用的寶玉"),
+        (48397, "宇宙馬登時效鋼鉚釘"),
+        (4709, "蘋果撻"),
+        (33895, "再識欣喜若狂之悅"),
+        (14901, "毛絨絨蒜頭明星"),
+        (19719, "阿拉米格巧匠手套"),
+        (48558, "基地培養液"),
+        (2631, "鞣革突騎帽"),
+        (44010, "粉綠柱石"),
+        (8101, "駕駛席內部元件"),
+        (12081, "高地裝甲"),
+        (25630, "深金戰斧"),
+        (33891, "再陷背善向邪之懼"),
+        (34146, "紫黃晶強攻手環"),
+        (48509, "頂級纖維"),
+        (10887, "虹布治愈長袍"),
+        (7606, "古神木材"),
+        (30468, "唯美大地半指護手"),
+        (3660, "野豬革風華手箍"),
+        (3853, "毒蜥蜴革大地尖頭靴"),
+        (24180, "紫色馬蹄蓮頭花"),
+        (31066, "收藏用白銅魔導書"),
+        (22895, "夜鋼羊角錘"),
+        (4113, "孔雀石手鐲"),
+        (11934, "精金加固平斬"),
+        (15514, "碳纖布大地矮靴"),
+        (4315, "白銀衝鋒項環"),
+        (26022, "野蠻盜龍革詠咒手鐲"),
+        (34720, "迷彩絨游擊軍帽"),
+        (18409, "星尖石強攻項環"),
+        (9335, "煙肉湯"),
+        (43208, "奧闊鉻鐵鶴嘴鋤"),
+        (7079, "冬貝利書櫃"),
+        (39312, "沙龍服務員皮鞋"),
+        (26782, "魔法飛床"),
+        (35663, "收藏用開心汁"),
+        (42194, "薄絹治愈長褲"),
+        (35399, "完滿木紡車"),
+        (4181, "駿鷹革手環"),
+        (8095, "動力輪元件"),
+        (19660, "玉綢大地長褲"),
+        (34580, "絳紅草布黑獅鷲兜帽"),
+        (13843, "亞拉戈高位治愈魔導書（複製品）"),
+        (10474, "烏茲左輪槍"),
+        (42161, "鈷鎢戰鐮"),
+        (42357, "白黃金精准耳墜"),
+        (15517, "加隆德巧匠袖套"),
+        (48299, "改良設備用的隔熱材料"),
+        (3348, "棉絨褲襪"),
+        (7976, "天球模型"),
+        (36047, "胡蘿蔔絲"),
+        (24296, "管弦樂琴樂譜：千年旭光"),
+        (9079, "白金詠咒耳墜"),
+        (43276, "卡扎納爾手鉗"),
+        (35381, "錳鋼碎石錘"),
+        (19031, "鈀金詠咒耳墜"),
+        (13841, "亞拉戈高位長杖（複製品）"),
+        (2056, "獸骨咒角"),
+        (6574, "林間立柱"),
+        (46056, "儀仗游擊尖頭靴"),
+        (20362, "旋風神血"),
+        (42011, "釕金鳶盾"),
+        (3825, "白鋼鎧靴"),
+        (41257, "面向收藏家的洗面台"),
+        (2049, "狂人低語"),
+        (35339, "輝翠銀碎石錘"),
+        (4247, "秘銀耳夾"),
+        (42309, "鈦金手槍"),
+        (3559, "山羊革護臂"),
+        (11992, "安菲瑟龍革坎肩"),
+        (8029, "鎢鋼方板"),
+        (48112, "東洋頂櫃"),
+        (13856, "亞拉戈高位制敵軟甲褲（複製品）"),
+        (31864, "總督治愈騎兵褲"),
+        (11033, "蛋白石精准戒指"),
+        (13001, "騎士治愈工作服"),
+        (2547, "業餘手斧"),
+        (13030, "鏤金大師手甲"),
+        (34102, "輝翠銀禦敵護臂"),
+        (17308, "空鼠禦敵鐵鈎護臂"),
+        (13687, "古鳥革皮靴"),
+        (17888, "厚重的打刀"),
+        (44214, "收藏用卡岡圖亞革工作帽"),
+        (25693, "烏鋼石禦敵項環"),
+        (15724, "名牌加工部件"),
+        (10867, "聖虹游擊垮褲"),
+        (9060, "白金精准圍巾"),
+        (31658, "收藏用矮人銀魔導書"),
+        (48631, "宇宙蘑菇油"),
+        (12997, "騎士制敵外套"),
+        (6683, "古菩猩猩地毯"),
+        (48610, "基地早餐組合"),
+        (2785, "古典廚帽"),
+        (20476, "奧黛"),
+        (36171, "錳鋼錠"),
+        (2769, "秘銀作業眼鏡"),
+        (23833, "羅剎弓士首飾"),
+        (25980, "仙子棉強襲袖套"),
+        (26115, "鈦銅火槍"),
+        (24256, "4級剛力之靈水"),
+        (38904, "研究者砂輪機"),
+        (4435, "山羊革戒指"),
+        (26428, "緩罪劍"),
+        (25845, "虹繭詠咒打底褲"),
+        (32202, "鰻魚食盒"),
+        (8809, "瀧太郎標本"),
+        (28867, "空鼠游擊鐵鈎筒靴（複製品）"),
+        (12115, "曬衣桿"),
+        (30972, "收藏用聖雪松紡車"),
+        (28639, "皮革沙發"),
+        (30757, "南洋海濱短裙"),
+        (31190, "第三期重建用的墨水"),
+        (18223, "牛鬼革盔帽"),
+        (8787, "林間小凳"),
+        (36838, "校園外套"),
+        (33655, "前沿帽"),
+        (37376, "大聖堂講壇"),
+        (15527, "加隆德大地腕環"),
+        (28731, "重建用的墨水"),
+        (42344, "鈦金游擊護腿"),
+        (34285, "棕櫚禦敵耳夾"),
+        (4094, "黃銅手鍊"),
+        (21707, "清銀天星盤"),
+        (27662, "改良型鈷鐵手鎧"),
+        (12095, "林間隔離矮牆"),
+        (30475, "唯美巧匠戒指"),
+        (34272, "山雞革游擊手套"),
+        (28809, "空賊禦敵外套（複製品）"),
+        (18391, "葛布治愈寬邊帽"),
+        (48488, "宇宙探索用的毛帽"),
+        (38592, "榻榻米夾層樓板"),
+        (3662, "野豬革土華手箍"),
+        (16629, "重合金戰斧"),
+        (4704, "俾斯麥雞蛋三明治"),
+        (11977, "奇美纖維短裙"),
+        (38547, "夢羽妖靈王斷頭劍"),
+        (35352, "鉍金羊角錘"),
+        (10767, "獅鷲革強襲外套"),
+        (31884, "總督強攻手鐲"),
+        (42308, "鈦金巨劍"),
+        (11964, "奇美纖維巧匠工作坎肩"),
+        (5504, "魚膠"),
+        (10728, "白鈦制敵連指手鎧"),
+        (31064, "收藏用頂級恢復藥"),
+        (41144, "仿制魔導維修站牆"),
+        (31879, "總督強攻項環"),
+        (21764, "黑柳強攻腕環"),
+        (12915, "深紅高位附魔墨水"),
+        (14845, "橙色古典玫瑰頭花"),
+        (42189, "薄絹游擊長褲"),
+        (44033, "落雷絹"),
+        (29836, "第二期重建用的特供外套"),
+        (2220, "部隊紋章盾"),
+        (5585, "玄鋼胸甲部件"),
+        (12222, "合成生物的粗皮"),
+        (46064, "儀仗詠咒手套"),
+        (2389, "鈷鐵手鉗"),
+        (18529, "魔象革強襲兜帽"),
+        (38889, "時尚飾品"),
+        (19978, "紅玉棉線"),
+        (22723, "祭弓部件"),
+        (24864, "紫色鈴蘭頭花"),
+        (1673, "破甲鋒"),
+        (44971, "熱帶風情四柱床"),
+        (35655, "收藏用迷彩絨打底褲"),
+        (12029, "靈銀大地耳環"),
+        (16712, "嫩炒蛋"),
+        (12006, "古鳥革大地長筒靴"),
+        (21095, "解放軍的軍需品部件"),
+        (40397, "黃色菊花頭花"),
+        (34380, "鉍金鬥劍"),
+        (13804, "亞拉戈頭盔（複製品）"),
+        (7081, "冬貝利屏風"),
+        (28820, "空賊強襲手套（複製品）"),
+        (24861, "黃色鈴蘭頭花"),
+        (11906, "精金鶴嘴鎬"),
+        (38906, "研究者紡車"),
+        (20699, "東洋格窗"),
+        (10782, "虹布強襲裙褲"),
+        (26157, "力山羊治愈長衣"),
+        (30462, "唯美巧匠工作服"),
+        (10954, "奇美纖維詠咒馬褲"),
+        (24863, "橙色鈴蘭頭花"),
+        (2654, "硬革盔帽"),
+        (8296, "堇青石手鐲"),
+        (8387, "賢王莫古幻杖"),
+        (15965, "亞歷山大模型"),
+        (6596, "天文望遠鏡"),
+        (10840, "白鈦游擊口罩"),
+        (43300, "薄絹大地短衣"),
+        (27881, "香杏果凍"),
+        (9288, "伴娘涼鞋"),
+        (30470, "唯美大地筒靴"),
+        (10402, "秘銀大劍"),
+        (29814, "第二期重建用的染劑"),
+        (31656, "收藏用海燕革長衣"),
+        (19638, "葛布大地外套"),
+        (2999, "草布短袖罩衣"),
+        (39702, "繼業者精准盔甲戒指"),
+        (27694, "檀香木木材"),
+        (6459, "林間地板"),
+        (27274, "簡易式窗簾"),
+        (14244, "管弦樂琴樂譜：風暴中的燈火"),
+        (28754, "重建用的手套"),
+        (10884, "奇美纖維治愈修道帽"),
+        (44250, "圖拉爾旅行者皮鞋"),
+        (10963, "獅鷲革詠咒長靴"),
+        (39876, "魚子醬佐小餅乾"),
+        (42492, "落雷絹精准軟甲褲"),
+        (27182, "纏尾蛟革巧匠外套"),
+        (23883, "麵包置物架"),
+        (29455, "伊修加德新型治愈打底褲"),
+        (35052, "古典風格拳鬥士袖甲"),
+        (7106, "神殿騎士棋子"),
+        (22537, "莫古力台燈"),
+        (48500, "宇宙飛線用的耐用棉線"),
+        (36324, "輝煌工具的第二次加工部件：裁縫"),
+        (6544, "綠洲睡床"),
+        (19963, "藍晶石"),
+        (48389, "基地備用的椅子"),
+        (13829, "亞拉戈詠咒頭冠（複製品）"),
+        (4757, "羅蘭莓奶酪"),
+        (4581, "沈默猛毒藥"),
+        (27997, "2級耐力之幻藥"),
+        (43204, "奧闊鉻鐵制革刀"),
+        (33600, "白帝長槍"),
+        (2193, "薩維奈魔咒書"),
+        (22545, "月讀人偶"),
+        (48438, "宇宙探索用的手套"),
+        (30998, "收藏用靈青槍刃"),
+        (48348, "改良設備用的火爐"),
+        (12087, "招財寶"),
+        (27788, "耐力之幻藥"),
+        (18578, "石青詠咒指環"),
+        (3575, "棉絨半指手套"),
+        (48615, "慰勞用的甜脆麵包乾"),
+        (35390, "球粒隕石爆彈怪煎鍋"),
+        (21698, "黑柳長槍"),
+        (14719, "荒神革制敵護臂"),
+        (12591, "聖青麻布"),
+        (42906, "舊日王國強襲皮鞋"),
+        (13010, "錦衣大師縫針"),
+        (31105, "改良用迦迦納革細繩"),
+        (37321, "豪勇大弓"),
+        (1813, "暴怒震斧"),
+        (2194, "薩維奈愈咒書"),
+        (34262, "山雞革強襲手套"),
+        (35577, "懸掛壁燈"),
+        (41106, "葉小妖蘚球"),
+        (42881, "舊日王國重炮"),
+        (24507, "圓木塔"),
+        (43244, "鈦金圓頭錘"),
+        (22911, "山師胴著"),
+        (18731, "鈀金強攻盔甲戒指"),
+        (41850, "混金刺劍"),
+        (48480, "運送資材用的宇宙纖維"),
+        (11961, "聖虹布短上衣"),
+        (2452, "仙人針"),
+        (29475, "伊修加德新型強攻手環"),
+        (13638, "頂級以太藥"),
+        (2551, "黑鐵陸行鳥手斧"),
+        (35060, "古典風格雙劍鬥士面具"),
+        (3355, "棉絨緊身褲"),
+        (29815, "第二期重建用的紅茶"),
+        (17032, "福爾唐府邸內牆"),
+        (22998, "花花公子皺邊禮服襯衫"),
+        (15725, "訓練用部件"),
+        (13307, "樟木強攻腕環"),
+        (34411, "賽加羚羊革強襲長衣"),
+        (34750, "星點石英禦敵指環"),
+        (34133, "黑麻詠咒打底褲"),
+        (2827, "秘銀鎖甲頭罩"),
+        (15571, "無盡雲神拳刺"),
+        (48379, "宇宙探索用的平底鍋"),
+        (3538, "草布禮服手套"),
+        (2351, "鈷鐵橫頭錘"),
+        (39554, "緋光小刀"),
+        (42000, "釕金巨劍"),
+        (40406, "樸素寢室打底褲"),
+        (6525, "綠洲長椅"),
+        (19546, "卸鐵碎石錘"),
+        (48427, "大型設備用的齒輪"),
+        (35376, "鐵木泥岩砂輪機"),
+        (29647, "改良用的鋼絲"),
+        (8048, "黑術裝備的改良材料"),
+        (42316, "白黃金注射管"),
+        (34391, "賽加羚羊革魔導典"),
+        (2598, "鯡魚丸子"),
+        (35596, "特制甜味素"),
+        (10834, "巨龍革精准長靴"),
+        (7962, "水果拼盤"),
+        (37398, "常春藤白拱橋"),
+        (35424, "絳紅草布巧匠無檐帽"),
+        (10349, "莫古莫古吊燈"),
+        (48536, "宇宙棉布"),
+        (34278, "山雞革治愈騎兵褲"),
+        (47928, "保鏢槍帶長褲"),
+        (44090, "西蘭花炒菠菜"),
+        (34447, "鉍金精准手環"),
+        (2026, "銅鈴橡木牧杖"),
+        (6382, "石制海濱別墅外牆"),
+        (31946, "第四期重建用的火爐"),
+        (42030, "高山亞麻精准長裙"),
+        (43328, "五加木耳環"),
+        (12592, "奇美纖維"),
+        (3485, "革匠軟甲褲"),
+        (26144, "力山羊精准毛帽"),
+        (39682, "繼業者詠咒護臂"),
+        (2792, "秘銀頭冠（鋯石）"),
+        (18575, "石青強攻指環"),
+        (11880, "白鈦球頂錘"),
+        (37413, "管弦樂琴樂譜：情感所影響的力量"),
+        (27157, "矮人銀研鉢"),
+        (25655, "斯劍虎革制敵軟甲褲"),
+        (12096, "綠洲隔離矮牆"),
+        (48549, "研究員的營養品"),
+        (31001, "收藏用靈銀鎖子鎧"),
+        (26490, "中庸精准耳墜"),
+        (39310, "沙龍服務員手套"),
+        (21758, "黑柳禦敵項鍊"),
+        (31222, "第三期重建用的植物成長劑"),
+        (44992, "皓光圓月輪"),
+        (34731, "蛇牛革詠咒上裝"),
+        (13027, "戎甲大師工作靴"),
+        (13805, "亞拉戈重甲（複製品）"),
+        (42006, "釕金折扇"),
+        (15552, "光銀研鉢"),
+        (11917, "精金羊角錘"),
+        (28827, "空賊精准筒靴（複製品）"),
+        (1899, "高速榆木短弓"),
+        (35396, "球粒隕石手鉗"),
+        (28194, "傳單製作必需品"),
+        (3369, "巨蟾蜍革及膝褲"),
+        (20546, "吉祥天女之形"),
+        (8802, "星芒節烤渡渡鳥"),
+        (48305, "基地托盤"),
+        (9745, "個人天文儀"),
+        (16935, "1級畫框"),
+        (39482, "小火車"),
+        (11081, "蛋白石詠咒戒指"),
+        (18253, "白銅強攻項環"),
+        (36060, "高山茶"),
+        (24183, "混色馬蹄蓮頭花"),
+        (41999, "釕金匕首"),
+        (47103, "月球車的維修金屬板"),
+        (38616, "起床鬧鐘"),
+        (6358, "石制林間小屋房頂"),
+        (2719, "棉布圓錐帽"),
+        (13770, "鷹獵人矮靴"),
+        (42482, "卡岡圖亞革制敵軟甲褲"),
+        (41849, "奧闊鉻鐵武士刀"),
+        (34604, "藍鋯石詠咒指環"),
+        (34292, "白鑞精准項環"),
+        (26280, "矮人銀天星盤"),
+        (48261, "月球車用的貨物包裝用品"),
+        (31888, "總督禦敵戒指"),
+        (2555, "鈷鐵手斧"),
+        (48608, "統一規格的宇宙粘合劑"),
+        (35050, "古典風格拳鬥士面具"),
+        (2044, "銅鈴紅木牧杖"),
+        (34240, "山雞革魔導書"),
+        (44254, "科學家馬褲"),
+        (35328, "輝翠銀鶴嘴鋤"),
+        (12607, "植物成長劑5型"),
+        (12097, "酒桶架"),
+        (3209, "鈷鐵胸甲"),
+        (41837, "奧闊鉻鐵拳套"),
+        (28738, "重建用的布料"),
+        (4326, "榍石項環"),
+        (22738, "紙屑生產工具"),
+        (29472, "伊修加德新型治愈項環"),
+        (34454, "鉍金詠咒指環"),
+        (28841, "空賊詠咒打底褲（複製品）"),
+        (16695, "柚木精准手鐲"),
+        (10373, "魔導機械修理材料"),
+        (39674, "繼業者游擊皮靴"),
+        (19987, "玉綢"),
+        (2574, "榆木釣竿"),
+        (43311, "嵌齒象革大地半指護手"),
+        (25959, "靈青魔導典"),
+        (29422, "伊修加德新型禦敵軍帽"),
+        (11922, "精金加固銼刀"),
+        (27778, "植物成長劑10型"),
+        (34598, "藍鋯石治愈手環"),
+        (1961, "梣木幻杖"),
+        (30996, "收藏用深金雙刃劍"),
+        (32252, "人字形地板"),
+        (43338, "嵌齒象革手環"),
+        (35384, "球粒隕石橫頭錘"),
+        (39873, "宇宙香辣湯"),
+        (4710, "牧羊人派"),
+        (7107, "龍騎士棋子"),
+        (19616, "迦迦納怪鳥革皮靴"),
+        (20366, "厚土打刀"),
+        (48623, "發放用的湯鍋燉菜"),
+        (31169, "耐熱性的部件"),
+        (4198, "紅銅耳夾"),
+        (18367, "翠銀制敵發夾"),
+        (31069, "收藏用植物成長劑"),
+        (14255, "管弦樂琴樂譜：魔神"),
+        (29480, "伊修加德新型強攻指環"),
+        (15160, "東方木橋"),
+        (31819, "總督斷頭劍"),
+        (25645, "深金鳶盾"),
+        (2304, "雲杉圓盾"),
+        (19036, "榧木詠咒項環"),
+        (10627, "精金巨劍"),
+        (11052, "皇金治愈耳墜"),
+        (11029, "貴橄欖石精准耳墜"),
+        (8038, "濃鹽酸"),
+        (4160, "碧璽手鐲"),
+        (42204, "粉綠柱石治愈耳墜"),
+        (18968, "琅玕詠咒指環"),
+        (48111, "東洋博古架"),
+        (34283, "山雞革詠咒騎兵褲"),
+        (30845, "青君戰斧"),
+        (43344, "黑星石大地耳墜"),
+        (14173, "3級耐力煉金溶劑"),
+        (42001, "釕金火槍"),
+        (16698, "閃爍禦敵指環"),
+        (2528, "秘銀鶴嘴鋤"),
+        (5798, "東洲藍染劑"),
+        (21867, "博雷爾內牆"),
+        (42032, "高山亞麻游擊頭巾"),
+        (10771, "白鈦強襲臂甲"),
+        (35367, "錳鋼縫針"),
+        (36877, "混合制雪峰公館外牆"),
+        (25827, "虹繭精准打底褲"),
+        (22558, "吧台"),
+        (30814, "月讀命幽夜長槍"),
+        (44984, "皓光牧杖"),
+        (5470, "植物成長劑1型"),
+        (37301, "混色比爾格紫羅蘭項環"),
+        (25851, "硬水鋁石治愈耳墜"),
+        (29481, "伊修加德新型精准指環"),
+        (13032, "鏤金大師涼鞋"),
+        (10845, "虹布游擊襯衫"),
+        (6371, "木制綠洲別墅房頂"),
+        (43320, "落雷絹大地坎肩"),
+        (2552, "白鋼手斧"),
+        (48239, "宇宙飛船用的齒輪"),
+        (31875, "總督精准耳墜"),
+        (14735, "荒神布游擊及膝褲"),
+        (18560, "石青強攻耳墜"),
+        (37353, "咕哎鐘"),
+        (19925, "山毛櫸木材"),
+        (5262, "蛋殼磨刀石"),
+        (34096, "輝翠銀環刃"),
+        (2623, "雉雞擬餌"),
+        (20478, "木涼鞋"),
+        (3386, "白鋼重足鎧"),
+        (26431, "行空"),
+        (1925, "橡木復合弓"),
+        (25867, "硬水鋁石詠咒指環"),
+        (48542, "改良設備用的混凝土"),
+        (28836, "空賊治愈打底褲（複製品）"),
+        (8013, "素瓷地板"),
+        (18110, "鋰輝石詠咒指環"),
+        (18346, "卸鐵爪"),
+        (15145, "毛絨絨小熊"),
+        (29820, "第二期重建用的長靴"),
+        (2497, "新來者平底鍋"),
+        (2078, "火華咒角"),
+        (42037, "高山亞麻治愈寬邊帽"),
+        (2913, "民兵寬邊帽"),
+        (7568, "魔法掃帚"),
+        (25052, "無限鬼神之刺劍"),
+        (35414, "雪亞麻布巧匠包頭巾"),
+        (6584, "林間落地燈"),
+        (3763, "楓木木鞋"),
+        (33248, "暴風藍內牆"),
+        (29645, "改良用的硅化木寶珠"),
+        (3309, "草布褲襪"),
+        (37771, "復興制敵鎧靴"),
+        (18068, "迦迦納怪鳥革精准短衣"),
+        (6484, "胡桃木庭院桌"),
+        (28127, "游末邦大理石地板"),
+        (12606, "星極油"),
+        (7961, "午茶組合"),
+        (10836, "安菲瑟龍革精准高筒靴"),
+        (5379, "硬化雲杉木材"),
+        (27652, "盜龍革長筒靴"),
+        (2758, "白鋼護目鏡"),
+        (38890, "研究者圓手鋸"),
+        (3102, "白鋼胸甲"),
+        (5073, "白鋼板"),
+        (13653, "空賊強襲短衣"),
+        (11007, "雪人利齒強攻戒指"),
+        (12001, "古鳥革大地馬褲"),
+        (15157, "沙灘椅"),
+        (13643, "空賊禦敵鬥褲"),
+        (40655, "仿制關閉的門"),
+        (3017, "硬革格鬥服"),
+        (44198, "收藏用釕金鎧靴"),
+        (27123, "鈦銅制革刀"),
+        (4603, "意力之妙藥"),
+        (27239, "精巧的戒指"),
+        (36312, "輝煌工具的第一次加工部件：鍛冶"),
+        (27174, "仙子棉巧匠馬褲"),
+        (13847, "亞拉戈高位天球儀（複製品）"),
+        (10473, "厚土重炮"),
+        (30469, "唯美大地軟甲褲"),
+        (15476, "極限幸存者襯衫"),
+        (5471, "植物成長劑2型"),
+        (18976, "鉻鐵火槍"),
+        (4501, "綠金巧匠戒指"),
+        (44109, "紫藤吊燈"),
+        (29825, "第二期重建用的火爐"),
+        (15601, "圓桌之戟"),
+        (48538, "工人的營養劑"),
+        (35027, "古典風格手炮"),
+        (47099, "吸引器的備用水箱"),
+        (27146, "矮人銀蒸餾器"),
+        (48180, "聖輝手槍"),
+        (42914, "舊日王國游擊護臂"),
+        (27116, "靈青廚刀"),
+        (9027, "烏茲胸甲"),
+        (2829, "呢絨貝雷帽"),
+        (36040, "貓魅風味島萃烤串"),
+        (38621, "綻放庭院桌"),
+        (27222, "矮人銀耳夾"),
+        (39424, "東方人力車"),
+        (10842, "奇美纖維游擊無檐帽"),
+        (2349, "白鋼鳥嘴錘"),
+        (34271, "山雞革游擊短衣"),
+        (14172, "3級巧力煉金溶劑"),
+        (8781, "綠洲四柱床"),
+        (21766, "黑柳治愈腕環"),
+        (41842, "混金法杖"),
+        (18674, "瞪羚革禦敵外套"),
+        (10526, "秘銀天球儀"),
+        (1666, "巨膽長劍"),
+        (34452, "鉍金精准指環"),
+        (6408, "綠洲尖窗"),
+        (19039, "榧木精准手鐲"),
+        (39556, "緋光刀鎖"),
+        (38912, "研究者巧匠長衣"),
+        (23147, "雲海珍寶"),
+        (27099, "靈青橫頭錘"),
+        (8114, "排氣元件"),
+        (1786, "秘銀長刃斧"),
+        (3532, "草布工作手套"),
+        (42209, "深紅木治愈項鍊"),
+        (20263, "黃色雛菊頭花"),
+        (2185, "綠金之書"),
+        (21719, "夜鋼制敵手鎧"),
+        (36879, "石制雪峰別墅外牆"),
+        (30452, "唯美銼刀"),
+        (34139, "紫黃晶詠咒耳墜"),
+        (25690, "烏鋼石精准耳墜"),
+        (2411, "粉岩砂輪機"),
+        (10909, "聖虹治愈垮褲"),
+        (13926, "龍神遊擊筒靴（複製品）"),
+        (6611, "林間餐桌"),
+        (39640, "繼業者魔導書"),
+        (35335, "輝翠銀平斬"),
+        (42486, "卡扎納爾強襲護臂"),
+        (27219, "白梣木手鐲"),
+        (3018, "草布長袍"),
+        (18070, "迦迦納怪鳥革精准皮套褲"),
+        (34261, "山雞革強襲短衣"),
+        (5361, "楓木木材"),
+        (3429, "呢絨束膝褲"),
+        (48312, "宇宙飛線用的長桿部件"),
+        (31026, "收藏用石金重刺劍"),
+        (34108, "黑麻制敵軟甲褲"),
+        (3774, "硬革便鞋"),
+        (4602, "智力之妙藥"),
+        (3535, "鞣革手箍"),
+        (25855, "硬水鋁石精准項環"),
+        (10829, "安菲瑟龍革精准襯褲"),
+        (8285, "藍寶石耳墜"),
+        (37772, "復興強襲兜帽"),
+        (11074, "硬銀詠咒耳墜"),
+        (18509, "柿木牧杖"),
+        (30847, "青君大弓"),
+        (34088, "馬栗木牧杖"),
+        (19948, "鈀金錠"),
+        (42005, "釕金槍刃"),
+        (18109, "鋰輝石治愈指環"),
+        (4669, "烤黑鰨"),
+        (34715, "迷彩絨精准軍帽"),
+        (29506, "烏雞布丁"),
+        (10809, "獅鷲革精准作戰服"),
+        (48098, "水鴨色地毯"),
+        (6435, "林間側壁煙囪"),
+        (5507, "翼膠"),
+        (34440, "弗里金禦敵項環"),
+        (35049, "古典風格騎士戰靴"),
+        (10146, "秘制王冠蛋糕"),
+        (6529, "海濱沙發"),
+        (3481, "木匠工作褲"),
+        (20472, "道士手套"),
+        (48533, "宇宙植物性纖維"),
+        (34117, "輝翠銀精准護臂"),
+        (28821, "空賊強襲軟甲褲（複製品）"),
+        (39322, "白色牽牛花頭花"),
+        (19555, "玉鋼煎鍋"),
+        (19753, "瞪羚革耳墜"),
+        (10621, "精金左手劍"),
+        (31868, "總督詠咒大衣"),
+        (3619, "白鋼連指手鎧"),
+        (27209, "斯劍虎革手環"),
+        (28466, "中庸縫針"),
+        (39662, "繼業者強襲手套"),
+        (14853, "駿鷹革短褲"),
+        (31917, "第四期重建用的鞣革"),
+        (28831, "空賊游擊軟甲褲（複製品）"),
+        (44098, "牧豆樹豆湯"),
+        (36187, "銠金錠"),
+        (35056, "古典風格弓鬥士長衣"),
+        (34680, "球粒隕石魔導劍"),
+        (24332, "邪龍怨影天球儀"),
+        (2136, "巨膽長杖"),
+        (26158, "纏尾蛟革治愈半指護手"),
+        (31920, "第四期重建用的麻乳"),
+        (6388, "石制林間公館外牆"),
+        (9070, "白金治愈圍巾"),
+        (48388, "基地備用椅子的部件"),
+        (27192, "矮人棉外套"),
+        (3583, "棉絨短手套"),
+        (19000, "紵布強襲垮褲"),
+        (18510, "翠銀法杖"),
+        (4309, "黃銅衝鋒項環"),
+        (2339, "民兵羊角錘"),
+        (29959, "尊紫珠"),
+        (42495, "落雷絹游擊罩衣"),
+        (38902, "研究者銼刀"),
+        (30982, "收藏用仙果木長杖"),
+        (43326, "混金手鐲"),
+        (10412, "烏茲大彎刀"),
+        (13317, "樟木詠咒項鍊"),
+        (16781, "豪華舞台"),
+        (36061, "高山茶餅乾"),
+        (36888, "雪峰地板"),
+        (46076, "儀仗詠咒項鍊"),
+        (25815, "綠飄龍革制敵馬褲"),
+        (17320, "空鼠強襲黑鐵筒靴"),
+        (2471, "新來者蒸餾器"),
+        (1754, "黑鐵戰斧"),
+        (35325, "輝翠銀縫針"),
+        (29426, "伊修加德新型禦敵鎖甲靴"),
+        (7499, "蛟蜥精准罩衣"),
+        (34125, "伽迦象革治愈頭冠"),
+        (35051, "古典風格拳鬥士兜甲"),
+        (26502, "中庸詠咒手鐲"),
+        (16643, "重合金禦敵手鎧"),
+        (42027, "高山亞麻精准纏頭巾"),
+        (6606, "海濱方桌"),
+        (44046, "耐力之寶水"),
+        (4849, "楓糖漿"),
+        (23157, "雲海珍寶部件"),
+        (6582, "魔界花屏風"),
+        (2363, "秘銀銼刀"),
+        (8797, "酒桶"),
+        (18661, "櫸木長弓"),
+        (13844, "亞拉戈高位輝刀（複製品）"),
+        (39719, "紫鋰輝石"),
+        (13686, "虹布裙撐禮服"),
+        (5094, "鈷鐵鉚釘"),
+        (10470, "獄火火槍"),
+        (46002, "甜馬黛茶"),
+        (42144, "鈷鎢彎刀"),
+        (38859, "署名紀念品"),
+        (3274, "草布馬褲"),
+        (20730, "東洋長椅"),
+        (34532, "錳鋼戰斧"),
+        (29655, "改良用的鏈條板"),
+        (34436, "弗里金強攻耳夾"),
+        (48309, "特制革匠工作台"),
+        (3489, "礦工工作褲"),
+        (3856, "野豬革鴨嘴靴"),
+        (27179, "仙子棉大地工作褲"),
+        (34119, "伽迦象革精准皮靴"),
+        (10992, "皇金強攻項鍊"),
+        (18376, "葛布強襲軟甲褲"),
+        (17613, "生命之咒杖"),
+        (26175, "三重石精准項環"),
+        (39696, "繼業者強攻手環"),
+        (4337, "狼牙項鍊"),
+        (31010, "收藏用鉬鋼頭甲"),
+        (27789, "智力之幻藥"),
+        (48517, "基地沙發軟墊"),
+        (42944, "舊日王國精准指環"),
+        (23785, "羅剎豪士袈裟"),
+        (14267, "管弦樂琴樂譜：Answers　-生死答問-"),
+        (48454, "運送資材用的月蚤革"),
+        (2508, "青銅廚刀"),
+        (23780, "羅剎天儀"),
+        (43234, "鈷鎢手鉗"),
+        (23774, "羅剎大太刀"),
+        (41997, "錘頭鰐革魔導書"),
+        (18408, "星尖石禦敵項環"),
+        (10854, "長頸駝革游擊護臂"),
+        (6480, "布滿苔蘚的庭院石"),
+        (44204, "收藏用河岸石頭冠"),
+        (34153, "紫黃晶治愈指環"),
+        (2870, "綠金頭冠（紅電氣石）"),
+        (16710, "蘋果芙紐多"),
+        (7140, "莫古力內牆"),
+        (14065, "添水"),
+        (44983, "皓光手槍"),
+        (36849, "藍羽陸行鳥裝甲"),
+        (29497, "力山羊肉丸"),
+        (10710, "精金禦敵脛甲"),
+        (2160, "雕花山羊革魔導書"),
+        (31072, "收藏用綠飄龍革魔導書"),
+        (12857, "香烤湖膽"),
+        (2492, "民兵研鉢"),
+        (20685, "石制東洋公館房頂"),
+        (15150, "直立型間接照明"),
+        (8663, "黑鐵輔劍"),
+        (3576, "山羊革半指護手"),
+        (18033, "硬鋼鬥劍"),
+        (16713, "海鮮拌面"),
+        (37765, "復興禦敵護膝褲"),
+        (37784, "復興游擊護臂"),
+        (42942, "舊日王國禦敵指環"),
+        (33263, "鼓桌"),
+        (44989, "皓光武士刀"),
+        (48548, "用於應急食品的防腐劑"),
+        (25658, "白麻強襲兜帽"),
+        (26446, "中庸禦敵頭冠"),
+        (35055, "古典風格弓鬥士頭甲"),
+        (19657, "嗶嘰兜帽"),
+        (2424, "白鋼圓革刀"),
+        (10702, "精金禦敵鎖甲騎兵褲"),
+        (29501, "香腸泡菜"),
+        (12868, "包心卡貝基"),
+        (3243, "苔角鱗鎧"),
+        (45978, "不飛鳥毛布"),
+        (48182, "聖輝咒杖"),
+        (3015, "業餘厚袍"),
+        (1611, "殘戮劍"),
+        (39385, "古舊的咒術道具"),
+        (25029, "神聖女神之火"),
+        (1778, "白鋼長刃斧"),
+        (29407, "伊修加德新型三尖槍"),
+        (18085, "山毛櫸詠咒面具"),
+        (7138, "樹林內牆"),
+        (13828, "亞拉戈治愈法靴（複製品）"),
+        (8098, "引擎外殼元件"),
+        (31945, "第四期重建用的睡床"),
+        (25789, "深金指虎"),
+        (23808, "羅剎間士眼帶"),
+        (10719, "靈銀制敵鎖子鎧"),
+        (25979, "仙子棉強襲棉甲"),
+        (16673, "星絨治愈長手套"),
+        (46023, "儀仗曲刃刀"),
+        (3013, "業餘工作坎肩"),
+        (32251, "植樹噴泉"),
+        (13865, "亞拉戈高位精准手套（複製品）"),
+        (43298, "獰豹革巧匠工作靴"),
+        (25032, "神聖女神之書"),
+        (29500, "蜂蜜牛角麵包"),
+        (21065, "神龍玩偶"),
+        (32225, "鄉村陸行鳥櫃台"),
+        (27930, "白色繡球頭花"),
+        (13853, "亞拉戈高位制敵頭甲（複製品）"),
+        (25954, "靈青斷頭劍"),
+        (18682, "瞪羚革制敵襯褲"),
+        (23885, "提籃"),
+        (29837, "第二期重建用的特供壁紙"),
+        (34150, "紫黃晶禦敵指環"),
+        (48601, "慰勞用的嫩炒蛋"),
+        (40367, "高貝扎裝甲"),
+        (22889, "清銀縫針"),
+        (37753, "復興魔導典"),
+        (10753, "精金制敵鎖甲靴"),
+        (37280, "艾布拉納賽黃晶"),
+        (27958, "巨匠藥水"),
+        (34596, "藍鋯石強攻手環"),
+        (2146, "梣木巫書"),
+        (2927, "烹調高廚帽"),
+        (10721, "白鈦制敵胸甲"),
+        (45975, "八面體隕鐵塊"),
+        (43288, "銀狼革皮靴"),
+        (6377, "木制海濱公館外牆"),
+        (16688, "柚木禦敵項環"),
+        (21712, "夜鋼禦敵戰甲"),
+        (36712, "條約巧匠淺口鞋"),
+        (20371, "賢王莫古刺劍"),
+        (8093, "右舷船體元件"),
+        (44252, "科學家大衣"),
+        (19580, "鉬鋼羊角錘"),
+        (28829, "空賊游擊短衣（複製品）"),
+        (19583, "貴榴石砂輪機"),
+        (31832, "總督禦敵大衣"),
+        (19013, "真獅鷲革游擊戰靴"),
+        (6417, "綠洲冠型門"),
+        (4209, "黃銅耳夾"),
+        (12084, "山嶽座椅"),
+        (3810, "山羊革大地尖頭靴"),
+        (24538, "淡青色地毯"),
+        (18232, "紅玉棉布游擊束膝褲"),
+        (19578, "鉬鋼手斧"),
+        (23037, "月讀裝甲"),
+        (28479, "中庸大地半指護手"),
+        (29984, "橙色風信子頭花"),
+        (8292, "紅寶石手鐲"),
+        (41862, "銀狼革禦敵戰靴"),
+        (41268, "面向收藏家的耐力之幻藥"),
+        (27269, "磚塊地板"),
+        (34573, "絳紅草布墮龍束膝褲"),
+        (39704, "繼業者詠咒盔甲戒指"),
+        (33877, "綠色蝴蝶蘭耳飾"),
+        (10707, "白鈦禦敵鎖甲靴"),
+        (25639, "斯劍虎革魔導典"),
+        (2518, "民兵廚刀"),
+        (22445, "豆腐煎餅"),
+        (28732, "重建用的麻乳"),
+        (35443, "蛇牛革大地矮靴"),
+        (15554, "光銀碎石錘"),
+        (9073, "織蛛短外套"),
+        (37300, "黑色比爾格紫羅蘭項環"),
+        (29987, "黑色風信子頭花"),
+        (18386, "葛布游擊束腰獵衣"),
+        (5204, "星眼石"),
+        (13920, "龍神詠咒燈籠褲（複製品）"),
+        (48386, "高耐久燃料容器"),
+        (11960, "古鳥革巧匠外套"),
+        (2287, "鷹紋圓盾"),
+        (6568, "綠洲書櫃"),
+        (11026, "硬銀精准耳墜"),
+        (30821, "月讀命幽夜經典"),
+        (28976, "疊好的被褥"),
+        (20008, "板岩磨刀石"),
+        (18670, "鉬鋼打刀"),
+        (27940, "牛皮鞋"),
+        (28748, "重建用的紅茶"),
+        (6507, "黃金谷鮮血草"),
+        (2666, "馬倌草帽"),
+        (48596, "工人用的油"),
+        (9083, "烏茲彎刀"),
+        (31092, "紅色秋英頭花"),
+        (31022, "收藏用白銅項鍊"),
+        (10664, "靈銀步兵盾"),
+        (43317, "落雷絹巧匠寬松七分褲"),
+        (23144, "防鯊用品"),
+        (2687, "黃銅頭冠（賽黃晶）"),
+        (18557, "魔象革詠咒法靴"),
+        (9044, "織蛛強襲纏手帶"),
+        (25035, "神聖女神之刀"),
+        (37755, "復興武士刀"),
+        (23787, "羅剎豪士筒袴"),
+        (31053, "收藏用葛布寬邊帽"),
+        (27858, "蠶豆沙拉"),
+        (7142, "石制地板"),
+        (11018, "靈銀精准手鐲"),
+        (8368, "旋風神刺"),
+        (24254, "彗星棉布"),
+        (28162, "防具攤"),
+        (2880, "綠金頭冠（綠松石）"),
+        (4728, "葡萄乾"),
+        (22728, "團扇部件"),
+        (27878, "咖啡曲奇"),
+        (18513, "翠銀天星盤"),
+        (22536, "莫古力睡床"),
+        (48331, "無人機用的硬質金屬桿"),
+        (7540, "春意禮服"),
+        (48551, "長期保存用的實驗藥品"),
+        (34419, "賽加羚羊革精准厚底鞋"),
+        (26283, "矮人銀魔機刃"),
+        (17555, "寒冷地帶的特產部件"),
+        (8362, "獄火神書"),
+        (35447, "紫黃晶巧匠指環"),
+        (48321, "宇宙探索用的生物燃料"),
+        (11904, "硬銀鶴嘴鎬"),
+        (34112, "伽迦象革強襲半指護手"),
+        (34412, "賽加羚羊革強襲手套"),
+        (3627, "毒蜥蜴革大地半指護手"),
+        (19043, "鈀金強攻指環"),
+        (1688, "硬革鋼拳"),
+        (19829, "味噌田樂"),
+        (7541, "春意長裙"),
+        (4241, "紫水晶耳墜"),
+        (43255, "鈦金手鉗"),
+        (31089, "收藏用濃縮奶油咖啡"),
+        (23106, "混色大麗花頭花"),
+        (13309, "樟木精准項鍊"),
+        (7059, "精煉藥"),
+        (27839, "野園甜菜糖"),
+        (13827, "亞拉戈治愈軟甲褲（複製品）"),
+        (18044, "白銅魔導典"),
+        (37769, "復興制敵臂甲"),
+        (27197, "海燕革長衣"),
+        (5472, "植物成長劑3型"),
+        (42465, "卡扎納爾武士刀"),
+        (18258, "白銅強攻手鐲"),
+        (3833, "白鋼長筒靴"),
+        (34135, "紫黃晶禦敵耳墜"),
+        (19938, "白銅塊"),
+        (35035, "古典風格槍刃"),
+        (31084, "收藏用柿子布丁"),
+        (6573, "海濱立柱"),
+        (8847, "1級特訓以太轉輪"),
+        (3710, "革匠手套"),
+        (2481, "業餘研鉢"),
+        (47592, "宇宙探索用的蘑菇燈"),
+        (4748, "菠蘿汁"),
+        (44824, "藍色卡特蘭頭花"),
+        (7146, "鋪瓦地板"),
+        (3473, "纖維軟甲褲"),
+        (39324, "混色牽牛花頭花"),
+        (15152, "尼德霍格模型"),
+        (18730, "鈀金禦敵盔甲戒指"),
+        (24326, "邪龍怨影大劍"),
+        (31015, "收藏用靈銀護目鏡"),
+        (33264, "小提琴展示櫃"),
+        (2473, "特制蒸餾器"),
+        (26310, "矮人棉游擊包頭巾"),
+        (34746, "星點石英強攻腕帶"),
+        (23188, "辣椒焗奶酪"),
+        (33881, "黑色蝴蝶蘭耳飾"),
+        (38545, "夢羽妖靈王長弓"),
+        (9053, "奇蜥外套"),
+        (18243, "紅玉棉布詠咒半指護手"),
+        (10727, "飛龍革制敵手套"),
+        (4613, "意力之靈藥"),
+        (35434, "迷彩絨巧匠工作帽"),
+        (10673, "白鈦禦敵頭盔"),
+        (13676, "空賊詠咒航空帽"),
+        (37794, "復興詠咒護臂"),
+        (44873, "柴火烤爐"),
+        (24324, "邪龍怨影弓"),
+        (6416, "綠洲拱頂門"),
+        (4689, "煎雞油菌"),
+        (3881, "呢絨禮鞋"),
+        (14732, "荒神布游擊狩獵帽"),
+        (3904, "狩獵靴"),
+        (5329, "纖維布"),
+        (39318, "黃色牽牛花頭花"),
+        (9301, "工匠及膝褲"),
+        (4708, "兔形派"),
+        (13096, "莊園地板"),
+        (8379, "厚土巨鑰"),
+        (14744, "荒神革詠咒護臂"),
+        (23775, "羅剎短銃"),
+        (3408, "呢絨垮褲"),
+        (35456, "金毗羅鰐革大地耳墜"),
+        (34569, "金毗羅鰐革最後的獨角獸甲靴"),
+        (5202, "雷眼石"),
+        (19897, "2級巧力之靈水"),
+        (25984, "野蠻盜龍革精準頭甲"),
+        (3236, "烹調圍裙裝"),
+        (39694, "繼業者詠咒項環"),
+        (8290, "堇青石項環"),
+        (11939, "靈銀研鉢"),
+        (21710, "夜鋼盾"),
+        (11874, "精金手鋸"),
+        (19518, "硬鋼銼刀"),
+        (4450, "螢石戒指"),
+        (42182, "薄絹精准鬥服"),
+        (42160, "鈷鎢輪刃"),
+        (42220, "粉綠柱石詠咒指環"),
+        (1650, "白羽橡木石刃棒"),
+        (18051, "硬鋼禦敵護臂"),
+        (2553, "白鋼陸行鳥手斧"),
+        (42314, "鈦金戰輪"),
+        (14924, "長頸駝革外套"),
+        (48433, "宇宙銠金錠"),
+        (6515, "綠洲座椅"),
+        (20754, "神龍之翼"),
+        (34247, "白鑞振空擺"),
+        (43896, "發酵蘆薈汁"),
+        (1750, "青銅戰斧"),
+        (28764, "重建用的燉菜"),
+        (19517, "硬鋼羊角錘"),
+        (2430, "業餘平斬"),
+        (8557, "弦月長睡帽"),
+        (33271, "木制螺旋樓梯"),
+        (44047, "智力之寶水"),
+        (17013, "祖爾宛模型"),
+        (48560, "工人的宇宙營養劑"),
+        (15968, "布莉娜娃娃"),
+        (14093, "迦樓羅之形"),
+        (10847, "長頸駝革游擊短衣"),
+        (27207, "斯劍虎革耳墜"),
+        (48328, "宇宙探索用的錘子"),
+        (8808, "巨齒鯊的牙骨"),
+        (3062, "棉布長袍"),
+        (35627, "收藏用棕櫚木手鐲"),
+        (48593, "罐裝應急食品"),
+        (42003, "釕金武士刀"),
+        (36124, "偃武騎士武具"),
+        (39633, "繼業者長槍"),
+        (8796, "貨運木箱"),
+        (3790, "內襯鞣革大地鴨嘴靴"),
+        (25824, "虹繭精准寬邊帽"),
+        (26333, "透鋰長石禦敵項環"),
+        (37743, "復興指虎"),
+        (31889, "總督強攻戒指"),
+        (26507, "中庸詠咒指環"),
+        (18673, "鉬鋼禦敵頭甲"),
+        (21203, "薩維奈秋意禮服"),
+        (37290, "圓桌之戰輪"),
+        (31943, "第四期重建用的肥皂"),
+        (43226, "鈷鎢縫針"),
+        (7086, "冥鬼大口花瓶"),
+        (35574, "木制垃圾桶"),
+        (31102, "改良用黑鐵釘"),
+        (11929, "龍鱗砂輪機"),
+        (27370, "玉鋼戰輪"),
+        (12033, "巨龍革指環"),
+        (14246, "管弦樂琴樂譜：上下易位"),
+        (48603, "慰勞用的烤蘑菇串"),
+        (38243, "法外執行者帽"),
+        (3379, "亞麻褲襪"),
+        (41851, "奧闊鉻鐵刺刀"),
+        (4527, "星極指環"),
+        (10725, "精金制敵外套"),
+        (28814, "空賊制敵外套（複製品）"),
+        (14188, "刺繡線"),
+        (2825, "呢絨狩獵帽"),
+        (17610, "生命之刃"),
+        (19603, "阿拉米格圓頭錘"),
+        (27802, "硬泥岩磨刀石"),
+        (18953, "琅玕詠咒耳墜"),
+        (17469, "翼手龍革皮靴"),
+        (48540, "工人的健康飲料"),
+        (31098, "白色秋英頭花"),
+        (23101, "綠色大麗花頭花"),
+        (42323, "嵌齒象革禦敵軟甲褲"),
+        (11890, "皇金制革刀"),
+        (19512, "硬鋼蒸餾器"),
+        (5057, "黑鐵錠"),
+        (9615, "1級速度飼料"),
+        (44110, "淡藍內牆"),
+        (33227, "紀念醫院的茶會用品"),
+        (36866, "木制雪峰公館房頂"),
+        (18039, "硬鋼斷頭劍"),
+        (3533, "草布短手套"),
+        (34396, "鉍金圓月輪"),
+        (6665, "伊弗利特壁燈"),
+        (41594, "習武禮鞋"),
+        (8298, "紅寶石戒指"),
+        (15582, "無盡雲神量天儀"),
+        (8300, "祖母綠戒指"),
+        (31755, "最終改良用紵布"),
+        (7121, "高山木人"),
+        (44159, "耐力之寶藥"),
+        (41087, "水晶都壁掛雨棚"),
+        (8094, "左舷船體元件"),
+        (27672, "巨蟾蜍革長靴"),
+        (20779, "解放軍的軍需品"),
+        (44651, "冬季寬松夾克"),
+        (13649, "空賊制敵鬥褲"),
+        (4272, "綠金耳夾"),
+        (42363, "白黃金治愈項環"),
+        (5198, "火眼石"),
+        (16632, "重合金小刀"),
+        (6672, "貝希摩斯壁掛"),
+        (22745, "魅惑的魔物人偶"),
+        (4673, "清燉羊肉"),
+        (19731, "落葉松戒指"),
+        (44048, "意力之寶水"),
+        (7065, "木匠工作台"),
+        (44180, "咖啡可可甜脆"),
+        (29812, "第二期重建用的地毯"),
+        (10391, "薩維奈尖頭鞋"),
+        (34393, "鉍金打刀"),
+        (4126, "秘銀巧匠手鍊"),
+        (12881, "高地小麥粉"),
+        (3883, "鈷鐵長筒靴"),
+        (2693, "黃銅頭冠（螢石）"),
+        (39410, "火之天王的鬥篷"),
+        (13869, "亞拉戈高位治愈長衣（複製品）"),
+        (11997, "安菲瑟龍革手套"),
+        (19724, "阿拉米格大地手套"),
+        (1607, "黃銅手半劍"),
+        (3539, "草布弓術手套"),
+        (14136, "風味拌細面"),
+        (35087, "古典風格精准手環"),
+        (42468, "卡扎納爾圓月輪"),
+        (15473, "幸存者襯衫"),
+        (17855, "管弦樂琴樂譜：天王"),
+        (8051, "學士裝備的改良材料"),
+        (18048, "硬鋼重盾"),
+        (19927, "柿木木材"),
+        (39666, "繼業者精准短衣"),
+        (29793, "第二期重建用的合金"),
+        (34717, "球粒隕石精准手套"),
+        (4255, "綠金耳環"),
+        (1882, "方尖槍"),
+        (48346, "改良設備用的熔爐"),
+        (46006, "奶酪沙拉"),
+        (41846, "奧闊鉻鐵斷頭劍"),
+        (43973, "烏托姆辣醬"),
+        (18505, "柿木長弓"),
+        (42897, "舊日王國制敵頭盔"),
+        (42173, "獰豹革制敵護臂"),
+        (23879, "木制展示櫃"),
+        (48279, "室外活動用的木炭"),
+        (31042, "收藏用綠飄龍革高筒靴"),
+        (4726, "薄荷酸奶昔"),
+        (42191, "獰豹革治愈頭帶"),
+        (3240, "民兵長袍"),
+        (5330, "天蠶絹布"),
+        (48377, "統一規格的研鉢"),
+        (35059, "古典風格弓鬥士戰靴"),
+        (43271, "卡扎納爾鶴嘴鋤"),
+        (32253, "天窗吊燈"),
+        (10531, "昏星盤"),
+        (3147, "呢絨襯衫"),
+        (3658, "野豬革冰華手箍"),
+        (19004, "紵布精准罩衣"),
+        (37778, "復興精准上衣"),
+        (18511, "魔象革魔導書"),
+        (6632, "綠洲櫃台"),
+        (36701, "條約砂輪機"),
+        (34254, "輝翠銀禦敵鎖甲靴"),
+        (10971, "靈銀禦敵手環"),
+        (26004, "野蠻盜龍革詠咒手套"),
+        (17468, "法蘭絨七分褲"),
+        (37797, "復興禦敵耳墜"),
+        (12220, "紅莫柯草"),
+        (13313, "樟木治愈項鍊"),
+        (14250, "管弦樂琴樂譜：諸神已死"),
+        (46022, "儀仗黃道儀"),
+        (35582, "伊爾美格花園噴泉"),
+        (19733, "牛鬼革耳墜"),
+        (31149, "保養鬍子的工具"),
+        (2337, "秘銀羊角錘"),
+        (20714, "東洋鑲金圍牆"),
+        (35665, "收藏用巨人新薯煎餅"),
+        (11927, "星極砂輪機"),
+        (34418, "雪亞麻精准打底褲"),
+        (26319, "矮人棉治愈束膝褲"),
+        (18978, "鈀金長杖"),
+        (20752, "仿制圓窗"),
+        (44175, "烤雞"),
+        (4745, "橙汁"),
+        (8006, "海濱鐵圍欄"),
+        (4663, "艾蒿烤鯉魚"),
+        (18982, "鉻鐵打刀"),
+        (3499, "駿鷹革軟甲褲"),
+        (27659, "改良型鈷鐵騎兵頭盔"),
+        (23814, "羅剎學士冠"),
+        (20713, "東洋紋飾圍牆"),
+        (18689, "瞪羚革強襲露趾長靴"),
+        (5476, "黑鐵附魔墨水"),
+        (38929, "近東蝦香飯"),
+        (6418, "綠洲古典門"),
+        (10743, "巨龍革制敵馬褲"),
+        (42036, "錘頭鰐革游擊護腿"),
+        (46849, "工作用的腳手架"),
+        (48293, "運送資材用的樹脂"),
+        (11044, "硬銀治愈手鐲"),
+        (28807, "混色風鈴頭花"),
+        (19822, "烤海螺"),
+        (35437, "迷彩絨巧匠騎兵褲"),
+        (40658, "古典書桌"),
+        (42481, "卡扎納爾制敵手鎧"),
+        (15592, "偃武牧杖"),
+        (4512, "綠松石戒指"),
+        (34280, "阿爾馬斯提詠咒禮帽"),
+        (34094, "輝翠銀刺劍"),
+        (36624, "收藏用迷彩絨工作帽"),
+        (15511, "碳纖布大地坎肩"),
+        (36115, "巨匠藥酒"),
+        (10632, "精金左輪槍"),
+        (18563, "石青詠咒耳墜"),
+        (48287, "耐用紙漿"),
+        (2703, "梣木假面（天青石）"),
+        (20475, "鬥笠"),
+        (14834, "遠徵手套"),
+        (27754, "力山羊毛布"),
+        (36122, "驚雷騎士武具"),
+        (35379, "錳鋼研鉢"),
+        (10985, "蛋白石禦敵戒指"),
+        (30454, "唯美砂輪機"),
+        (37281, "薩維奈纖維"),
+        (5474, "植物成長劑4型濃縮液"),
+        (4109, "日長石手鐲"),
+        (20745, "衣架"),
+        (5187, "紅寶石"),
+        (27082, "石金縫針"),
+        (1708, "野豬護拳"),
+        (30459, "唯美碎石錘"),
+        (8302, "堇青石戒指"),
+        (1699, "白鋼爪"),
+        (44020, "木棉木材"),
+        (17313, "空鼠制敵鐵鈎護臂"),
+        (36709, "條約巧匠坎肩"),
+        (15159, "大型庭院水塘"),
+        (10967, "蛋白石禦敵項環"),
+        (8834, "黑鐵地板"),
+        (2315, "青銅手鋸"),
+        (42932, "舊日王國禦敵項環"),
+        (44885, "圓形草毯"),
+        (31942, "第四期重建用的手套"),
+        (11006, "瑪瑙強攻戒指"),
+        (31196, "第三期重建用的皮繩"),
+        (3178, "呢絨外袍"),
+        (33876, "黃色蝴蝶蘭耳飾"),
+        (20812, "黑色光明百合頭花"),
+        (44012, "黑星石"),
+        (42525, "黑星石強攻指環"),
+        (44161, "意力之寶藥"),
+        (23102, "橙色大麗花頭花"),
+        (29440, "伊修加德新型精准軍帽"),
+        (48518, "改良設備用的綠洲地毯"),
+        (25821, "虹繭強襲打底褲"),
+        (28812, "空賊禦敵長靴（複製品）"),
+        (23805, "羅剎弓士袴"),
+        (25836, "虹繭治愈帽"),
+        (17321, "空鼠精准面具"),
+        (2373, "革柄圓頭錘"),
+        (48557, "基地植物成長劑"),
+        (18538, "嗶嘰精准騎兵褲"),
+        (29811, "第二期重建用的裁縫工具"),
+        (35583, "鞦韆椅"),
+        (23777, "羅剎咒杖"),
+        (19661, "瞪羚革毛邊靴"),
+        (19943, "卸鐵錠"),
+        (15531, "加隆德巧匠耳墜"),
+        (28992, "草原床"),
+        (42512, "黑星石治愈耳夾"),
+        (46015, "儀仗幻杖"),
+        (7495, "羊絨強襲短裙"),
+        (19581, "鉬鋼銼刀"),
+        (28462, "中庸橫頭錘"),
+        (3622, "獸角鱗指套"),
+        (39650, "繼業者禦敵頭帶"),
+        (22559, "吧椅"),
+        (48496, "宇宙飛線用的以太纖維"),
+        (16685, "閃爍精准耳墜"),
+        (5376, "紫檀木材"),
+        (37761, "復興鳶盾"),
+        (48436, "宇宙球粒隕石齒輪"),
+        (29822, "第二期重建用的肥皂"),
+        (31012, "收藏用深金翼冠"),
+        (6626, "冥鬼矮櫃"),
+        (37836, "7級巧力之幻水"),
+        (24491, "莫古力書架"),
+        (24528, "貓小胖沙發"),
+        (35597, "特制酵母"),
+        (6583, "海濱落地燈"),
+        (22727, "篝火台部件"),
+        (20690, "混合制東洋小屋外牆"),
+        (25665, "白麻精准長衫"),
+        (10395, "薩維奈綁腿"),
+        (34401, "賽加羚羊革禦敵長衣"),
+        (8819, "綠洲矮燈"),
+        (11059, "貴橄欖石治愈戒指"),
+        (44424, "探求永恆手鉗"),
+        (18676, "玉綢禦敵長褲"),
+        (34257, "輝翠銀制敵手鎧"),
+        (20394, "硬銀太刀"),
+        (16909, "青麻工作褲"),
+        (42372, "白黃金精准指環"),
+        (4574, "損血毒藥"),
+        (17960, "多瑪屏風"),
+        (6513, "海濱座椅"),
+        (7508, "蛟蜥治愈長靴"),
+        (29459, "伊修加德新型詠咒上裝"),
+        (31093, "藍色秋英頭花"),
+        (10934, "奇美纖維詠咒魔術服"),
+        (2655, "草布寬邊帽"),
+        (42181, "獰豹革精準頭帶"),
+        (6592, "林間浴缸"),
+        (44417, "探求永恆蒸餾器"),
+        (33030, "黃色康乃馨耳環"),
+        (26531, "純鈦礦"),
+        (23831, "羅剎豪士首飾"),
+        (10833, "長頸駝革精准護腿"),
+        (12526, "精金塊"),
+        (48269, "月面調料"),
+        (27751, "仙子棉布"),
+        (46033, "儀仗禦敵護甲"),
+        (48271, "養殖用的提取液"),
+        (10795, "獅鷲革強襲長靴"),
+        (32228, "花店櫃台"),
+        (29686, "隆卡搖椅"),
+        (4164, "尖晶石手鐲"),
+        (28137, "餐具套裝"),
+        (39317, "藍色牽牛花頭花"),
+        (7501, "羊絨精准短裙"),
+        (13872, "亞拉戈高位治愈長靴（複製品）"),
+        (19509, "白銅雕金錘"),
+        (42493, "卡扎納爾精准脛甲"),
+        (48547, "基地蜜蠟"),
+        (39421, "家用獺獺噴泉"),
+        (42190, "獰豹革游擊涼鞋"),
+        (27674, "改良型秘銀作業眼鏡"),
+        (18559, "石青禦敵耳墜"),
+        (26438, "魔咒手稿"),
+        (27749, "虹繭線"),
+        (37774, "復興強襲護臂"),
+        (25957, "靈青法杖"),
+        (11047, "貴橄欖石治愈手鐲"),
+        (4258, "紅電氣石耳墜"),
+        (36837, "校園平頂帽"),
+        (42187, "薄絹游擊鬥服"),
+        (6648, "冥鬼台鐘"),
+        (19561, "玉鋼手鉗"),
+        (3137, "古典圍裙裝"),
+        (18713, "瞪羚革詠咒法靴"),
+        (18719, "鉬鋼詠咒耳墜"),
+        (17467, "法蘭絨背帶襯衫"),
+        (12090, "蛋糕托盤"),
+        (42165, "鈷鎢鳶盾"),
+        (34382, "鉍金戰斧"),
+        (29652, "改良用的種子提取液"),
+        (44901, "黃金燒"),
+        (38885, "超強力洗滌劑"),
+        (17616, "生命之儀"),
+        (18704, "瞪羚革治愈長衣"),
+        (2230, "青銅步兵盾"),
+        (18542, "魔象革游擊外套"),
+        (16711, "煙肉蛋醬拌面"),
+        (13259, "白色貝雷帽"),
+        (12584, "星樺木木材"),
+        (48414, "宇宙探索用的硬質彈簧"),
+        (3806, "山羊革鴨嘴靴"),
+        (29476, "伊修加德新型精准手環"),
+        (10690, "精金禦敵臂甲"),
+        (34692, "球粒隕石魔導天星盤"),
+        (34591, "鐵木強攻項環"),
+        (42508, "卡岡圖亞革詠咒皮靴"),
+        (26277, "愈瘡木矮人玉杖"),
+        (35640, "收藏用球粒隕石鎖甲靴"),
+        (26124, "鈦銅圓月輪"),
+        (13671, "空賊治愈長衣"),
+        (13892, "龍神禦敵戰冠（複製品）"),
+        (2652, "草布頭巾"),
+        (19627, "牛鬼革寬邊帽"),
+        (28735, "重建用的鉚釘"),
+        (4628, "御風秘藥"),
+        (11039, "蛋白石治愈項環"),
+        (18550, "嗶嘰治愈騎兵褲"),
+        (13267, "高貴馬褲"),
+        (2670, "棉布圍巾"),
+        (33659, "前沿緞帶"),
+        (11038, "治愈青麻緞帶"),
+        (35383, "球粒隕石手鋸"),
+        (48455, "長期保存用的羊皮紙"),
+        (2123, "獸齒山羊角長杖"),
+        (16654, "星絨強襲罩衣"),
+        (2322, "橫切手鋸"),
+        (18413, "星尖石禦敵手鐲"),
+        (35389, "球粒隕石蒸餾器"),
+        (30974, "收藏用祝聖栗木指環"),
+        (16699, "閃爍強攻指環"),
+        (37282, "鑲烤墨魚"),
+        (11048, "靈銀治愈耳墜"),
+        (2929, "樵夫尖角帽"),
+        (4497, "綠金戒指"),
+        (48260, "統一規格的棉線"),
+        (18999, "真獅鷲革強襲手套"),
+        (18955, "琅玕強攻項環"),
+        (35043, "古典風格劍鬥士三角褲"),
+        (30816, "月讀命幽夜苦無"),
+        (48493, "宇宙探索用的毛巾"),
+        (31177, "驅散睡意的秘密道具"),
+        (31905, "蘋果新薯沙拉"),
+        (7534, "盛花草帽"),
+        (48241, "統一規格的鉚釘"),
+        (1987, "綠葉紫檀幻杖"),
+        (35068, "古典風格醫護兵三角褲"),
+        (11910, "精金手斧"),
+        (11979, "聖虹布禮鞋"),
+        (28478, "中庸大地外套"),
+        (16637, "半熊革魔導書"),
+        (3347, "山羊革馬褲"),
+        (36714, "條約大地外套"),
+        (4172, "民兵腕環"),
+        (34290, "白鑞禦敵項環"),
+        (12583, "樺木木材"),
+        (19516, "姆德斯德角釣竿"),
+        (8107, "右翼可動元件"),
+        (13918, "龍神詠咒長袍（複製品）"),
+        (6670, "貝拉哈迪亞水晶燈"),
+        (9081, "白金詠咒戒指"),
+        (19571, "鉬鋼球頂錘"),
+        (27094, "深金研鉢"),
+        (8452, "邪鬼項環"),
+        (19612, "赤麻巧匠包頭巾"),
+        (19723, "阿拉米格大地外套"),
+        (19652, "嗶嘰針織帽"),
+        (41656, "管弦樂琴樂譜：赤翼　～曉月～"),
+        (17908, "王冠寶石工房的特制禮物"),
+        (15506, "加隆德大地坎肩"),
+        (12519, "靈銀錠"),
+        (39412, "軟糊怪布丁"),
+        (35994, "綠色紫燈花耳環"),
+        (42452, "卡扎納爾鬥劍"),
+        (10608, "精金長槍"),
+        (17015, "斯卡哈模型"),
+        (48359, "宇宙探索用的耐衝擊板"),
+        (39665, "繼業者精準頭帶"),
+        (25810, "綠飄龍革禦敵高筒靴"),
+        (25027, "神聖女神之刃"),
+        (48333, "統一規格的合金"),
+        (29499, "檸檬華夫餅"),
+        (44163, "2級巧力之寶藥"),
+        (3206, "盜龍革皮衣"),
+        (16683, "閃爍禦敵耳墜"),
+        (16599, "羊絨毛邊帽"),
+        (20707, "東洋木制遮蓬"),
+        (40624, "仿制簾窗"),
+        (16641, "重合金禦敵鎖甲頭罩"),
+        (9314, "工匠廚刀"),
+        (1945, "巨膽長弓"),
+        (23830, "羅剎術士耳飾"),
+        (7110, "莊園定音鼓"),
+        (48561, "基地餐具套裝"),
+        (8777, "南洋沙發"),
+        (48175, "聖輝雙刃斧"),
+        (42483, "卡扎納爾制敵脛甲"),
+        (26148, "纏尾蛟革精准涼鞋"),
+        (36247, "伽迦象革"),
+        (34101, "伽迦象革禦敵長衣"),
+        (35363, "錳鋼橫頭錘"),
+        (32801, "小牛皮騎手打底褲"),
+        (18521, "玉鋼禦敵脛甲"),
+        (34232, "輝翠銀長刃斧"),
+        (11900, "白鈦煎鍋"),
+        (22924, "地懶革大地指環"),
+        (39777, "面向收藏家的校園皮鞋"),
+        (35587, "白銀風車"),
+        (47223, "照明修理用具"),
+        (27676, "改良型呢絨褲襪"),
+        (44179, "泡沫奶油肉桂咖啡"),
+        (44255, "科學家短靴"),
+        (39717, "暗櫻木材"),
+        (47717, "宇宙探索用的鰻魚口糧"),
+        (12088, "東方早餐組合"),
+        (27664, "改良型鈷鐵鎧靴"),
+        (41423, "魔法樹建造用合成樹脂"),
+        (41903, "銀狼革禦敵手鐲"),
+        (48617, "宇宙探索用的紅滴糖"),
+        (31062, "收藏用煉金溶劑"),
+        (26298, "矮人銀強襲頭盔"),
+        (46066, "儀仗詠咒長靴"),
+        (37838, "7級智力之幻水"),
+        (42154, "鈷鎢斷頭劍"),
+        (10815, "安菲瑟龍革精准手套"),
+        (20753, "仿制彩繪玻璃窗"),
+        (38562, "黃色香豌豆花項鍊"),
+        (41890, "銀狼革詠咒半指護手"),
+        (13662, "空賊精准筒靴"),
+        (16719, "智力之強化仙藥"),
+        (21753, "清銀禦敵耳墜"),
+        (8380, "厚土聖鑰"),
+        (10899, "安菲瑟龍革治愈手套"),
+        (3844, "巨蟾蜍革鴨嘴靴"),
+        (48531, "宇宙探索用的厚布"),
+        (10856, "安菲瑟龍革游擊護臂"),
+        (30466, "唯美大地工作帽"),
+        (16618, "黃色三色堇頭花"),
+        (17544, "黑色櫻花頭花"),
+        (6433, "綠洲遮蓬"),
+        (48474, "據點用的帳篷"),
+        (2817, "秘銀開面盔"),
+        (9359, "白金塊"),
+        (13912, "龍神治愈兜帽（複製品）"),
+        (34425, "雪亞麻治愈無檐帽"),
+        (36717, "條約大地矮靴"),
+        (24334, "邪龍怨影刺劍"),
+        (20809, "橙色光明百合頭花"),
+        (13305, "樟木強攻項鍊"),
+        (11920, "白鈦銼刀"),
+        (26176, "三重石治愈項環"),
+        (28737, "重建用的皮繩"),
+        (6671, "綠金掛鐘"),
+        (24257, "4級巧力之靈水"),
+        (1752, "陰雲戰斧"),
+        (28516, "中庸廚刀"),
+        (2390, "民兵手鉗"),
+        (34129, "伽迦象革治愈高筒靴"),
+        (4732, "小圓餅"),
+        (25689, "烏鋼石強攻耳墜"),
+        (1969, "綠葉紫杉幻杖"),
+        (35450, "馬栗木大地手鐲"),
+        (5194, "黑珍珠"),
+        (11911, "雪松釣竿"),
+        (13673, "空賊治愈打底褲"),
+        (19939, "硬鋼塊"),
+        (26130, "纏尾蛟革禦敵戰靴"),
+        (31199, "第三期重建用的芝麻餅乾"),
+        (19722, "阿拉米格大地包頭巾"),
+        (44168, "巨匠藥液"),
+        (39726, "8級意力之幻水"),
+        (19527, "卸鐵圓手鋸"),
+        (28144, "哈迪斯獎杯"),
+        (12546, "蛋白石"),
+        (27132, "鈦銅手鉗"),
+        (34533, "鐵木長槍"),
+        (39636, "繼業者巨劍"),
+        (38629, "管弦樂琴樂譜：高貝扎四天王之戰　～禁斷的記憶～"),
+        (20363, "旋風神傲"),
+        (13831, "亞拉戈詠咒手套（複製品）"),
+        (4730, "梅乾"),
+        (30071, "神龍夢幻長槍"),
+        (35340, "輝翠銀園藝鐮"),
+        (25028, "神聖女神之巨劍"),
+        (27717, "虹結晶石材"),
+        (41111, "希望之地地毯"),
+        (30080, "神龍夢幻天儀"),
+        (27886, "巫師萵苣沙拉"),
+        (27147, "矮人銀平底鍋"),
+        (6381, "混合制海濱別墅外牆"),
+        (46072, "儀仗禦敵項鍊"),
+        (3761, "鞣革皮靴"),
+        (9084, "烏茲指虎"),
+        (15604, "圓桌之大劍"),
+        (12608, "植物成長劑6型"),
+        (18998, "真獅鷲革強襲胸衣"),
+        (25866, "硬水鋁石治愈指環"),
+        (36705, "條約廚刀"),
+        (27151, "矮人銀羊角錘"),
+        (36664, "留學生用文具"),
+        (35438, "蛇牛革巧匠工作靴"),
+        (24005, "私語呢絨打底褲"),
+        (10918, "巨龍革治愈長靴"),
+        (28476, "中庸巧匠工作鞋"),
+        (26478, "中庸治愈禮服手套"),
+        (40643, "老式邊桌"),
+        (11058, "獅鷲利爪治愈戒指"),
+        (30458, "唯美廚刀"),
+        (34695, "球粒隕石魔導鋸刃"),
+        (4747, "蘋果汁"),
+        (36248, "山雞革"),
+        (1706, "蟹甲骨拳"),
+        (18725, "鈀金禦敵手環"),
+        (5785, "天上藍染劑"),
+        (39693, "繼業者治愈項環"),
+        (19808, "枇杷果飲"),
+        (21747, "真麻詠咒寬邊帽"),
+        (2798, "秘銀頭冠（尖晶石）"),
+        (6614, "妖精餐桌"),
+        (11957, "青麻巧匠包頭巾"),
+        (30823, "月讀命幽夜天儀"),
+        (6625, "莊園花台"),
+        (38907, "研究者研鉢"),
+        (10978, "硬銀禦敵耳墜"),
+        (3562, "新來者手套"),
+        (12851, "伊修加德三明治"),
+        (27120, "鈦銅橫頭錘"),
+        (5260, "粉砂岩磨刀石"),
+        (6445, "綠洲標旗"),
+        (27955, "紅色火藥"),
+        (1733, "鈷鐵爪"),
+        (20711, "東洋鑲金門牌"),
+        (5371, "胡桃木材"),
+        (2857, "綠金單眼鏡"),
+        (42318, "相思木圓筆"),
+        (36619, "收藏用完滿木釣竿"),
+        (27879, "力山羊肉藍帶肉排"),
+        (27707, "石金塊"),
+        (48185, "聖輝黃道儀"),
+        (6666, "火神壁燈"),
+        (27193, "海燕革半指手套"),
+        (6563, "莊園櫥櫃"),
+        (13026, "戎甲大師工作褲"),
+        (1728, "毒蜥蜴革鋼拳"),
+        (18526, "魔象革制敵馬褲"),
+        (11902, "精金煎鍋"),
+        (12024, "巨龍革項環"),
+        (2808, "呢絨大地兜帽"),
+        (42457, "克拉洛胡桃木牧杖"),
+        (9076, "奇蜥詠咒法靴"),
+        (29482, "伊修加德新型治愈指環"),
+        (29648, "改良用的磨刀石"),
+        (17319, "空鼠強襲馬褲"),
+        (34404, "賽加羚羊革禦敵筒靴"),
+        (4156, "紅電氣石手鐲"),
+        (9224, "吸血匕首"),
+        (34145, "紫黃晶禦敵手環"),
+        (6654, "毛絨絨陸行鳥"),
+        (41112, "酒樽"),
+        (27250, "恢復期的藥餌"),
+        (36625, "收藏用蛇牛革魔導典"),
+        (18263, "姆德斯德角強攻指環"),
+        (3920, "民兵鴨嘴靴"),
+        (28845, "空鼠禦敵鐵鈎護臂（複製品）"),
+        (4485, "紅珊瑚戒指"),
+        (18388, "葛布游擊軟甲褲"),
+        (6392, "木制綠洲小屋外牆"),
+        (38913, "研究者巧匠手套"),
+        (41145, "迷津葡萄路燈"),
+        (48292, "基地隔熱材料"),
+        (18204, "硬鋼步兵盾"),
+        (26168, "三重石禦敵耳墜"),
+        (34590, "鐵木禦敵項環"),
+        (2469, "業餘蒸餾器"),
+        (20388, "靈銀打刀"),
+        (4568, "律動藥"),
+        (42026, "錘頭鰐革強襲護腿"),
+        (39776, "面向收藏家的輪刃"),
+        (31003, "收藏用白鈦煎鍋"),
+        (10642, "硬銀長杖"),
+        (19637, "猛虎革大地無檐帽"),
+        (20261, "紅色雛菊頭花"),
+        (10680, "白鈦禦敵胸甲"),
+        (18255, "白銅治愈項環"),
+        (34437, "弗里金精准耳夾"),
+        (21827, "懸掛櫥櫃"),
+        (3906, "鈷鐵鎖甲靴"),
+        (3258, "駿鷹革皮衣"),
+        (3334, "棉布馬褲"),
+        (36051, "小扁豆咖喱"),
+        (2149, "雕花硬革魔導書"),
+        (41509, "紅色魯冰花頭花"),
+        (14941, "恐龍革手套"),
+        (4095, "黃銅巧匠手鍊"),
+        (35560, "馬林巴琴"),
+        (9289, "樸素長裙"),
+        (2940, "玄鋼鎖甲頭罩"),
+        (42351, "嵌齒象革詠咒釘刺坎肩"),
+        (15559, "驚雷霹靂"),
+        (13754, "血椒"),
+        (31019, "收藏用硬銀玉杖"),
+        (4609, "剛力之靈藥"),
+        (4277, "民兵耳環"),
+        (33275, "冰盒"),
+        (19579, "櫸木釣竿"),
+        (2422, "黑鐵裁皮刀"),
+        (35993, "黃色紫燈花耳環"),
+        (1894, "革裝楓木長弓"),
+        (13088, "薩菲洛特之根"),
+        (44416, "探求永恆縫針"),
+        (34557, "錳鋼貝希摩斯女王手鎧"),
+        (42311, "鈦金武士刀"),
+        (2850, "鈷鐵騎兵頭盔"),
+        (13306, "荒神鋼強攻耳墜"),
+        (6439, "綠洲門牌"),
+        (28855, "空鼠強襲半指護手（複製品）"),
+        (10988, "強攻虹布緞帶"),
+        (2102, "染血咒杖"),
+        (6494, "木柴"),
+        (48435, "宇宙球粒隕石板"),
+        (2614, "烏鴉擬餌"),
+        (6585, "綠洲落地燈"),
+        (6492, "樹樁凳"),
+        (28464, "中庸工藝錘"),
+        (27711, "靈青石材"),
+        (6586, "莊園燭台"),
+        (3586, "白鋼臂甲"),
+        (3931, "革匠工作靴"),
+        (30817, "月讀命幽夜大太刀"),
+        (6669, "無限城壁燈"),
+        (14176, "剛力之仙藥"),
+        (15821, "管弦樂琴樂譜：賢王莫古爾·莫古十二世"),
+        (8831, "石砌內牆"),
+        (4347, "紫水晶項環"),
+        (29404, "伊修加德新型長劍"),
+        (18517, "玉鋼禦敵戰盔"),
+        (10802, "獅鷲革精准眼罩"),
+        (36689, "條約圓頭錘"),
+        (41998, "錘頭鰐革魔導典"),
+        (2023, "橡木根杖"),
+        (12613, "1級巧力煉金溶劑"),
+        (4152, "綠金巧匠手鍊"),
+        (13070, "東方午茶組合"),
+        (10679, "白鈦禦敵戰鎧"),
+        (35093, "古典風格治愈指環"),
+        (7147, "金屬地板"),
+        (28736, "重建用的鐵環"),
+        (9308, "工匠銼刀"),
+        (26127, "纏尾蛟革禦敵外套"),
+        (13046, "煉真大師長裙"),
+        (12091, "東方晚餐組合"),
+        (10534, "厚土天球儀"),
+        (2166, "白銀之書"),
+        (27706, "深金塊"),
+        (38924, "研究者巧匠戒指"),
+        (11876, "白鈦橫頭錘"),
+        (11969, "安菲瑟龍革半指手套"),
+        (29962, "古巨蜥革"),
+        (35088, "古典風格治愈手環"),
+        (30818, "月讀命幽夜短銃"),
+        (7075, "冬貝利圓桌"),
+        (26503, "中庸禦敵指環"),
+        (36842, "校園長裙"),
+        (42523, "黑星石詠咒手鐲"),
+        (6664, "惡魔牆雕像"),
+        (34595, "藍鋯石禦敵手環"),
+        (43908, "金煌採火器"),
+        (6500, "水神噴泉"),
+        (25832, "綠飄龍革游擊手套"),
+        (19510, "硬鋼裁皮刀"),
+        (27149, "矮人銀手斧"),
+        (19564, "柿木紡車"),
+        (35070, "古典風格旗手角冠"),
+        (11882, "精金球頂錘"),
+        (42916, "舊日王國游擊鎧靴"),
+        (48461, "室外活動用的皮革"),
+        (14844, "綠色古典玫瑰頭花"),
+        (9768, "榻榻米地板"),
+        (22906, "鬼師胴著"),
+        (10881, "聖虹治愈騎士帽"),
+        (3001, "草布束腰牧衣"),
+        (18535, "嗶嘰精准狩獵帽"),
+        (14833, "遠徵罩衣"),
+        (10405, "鈷鐵雙手劍"),
+        (2616, "鐵板鈎"),
+        (44191, "收藏用奧闊鉻鐵拳套"),
+        (43650, "球粒隕石魔導畫筆"),
+        (19904, "植物成長劑7型"),
+        (5749, "鈷鐵棕染劑"),
+        (13069, "旅行筆錄"),
+        (30385, "木制階梯書櫃"),
+        (25993, "仙子棉游擊襯褲"),
+        (17926, "甜味智力之秘藥"),
+        (15923, "月火男式短褲"),
+        (4237, "橄欖石耳墜"),
+        (1649, "哥布林長劍"),
+        (5171, "孔雀石"),
+        (8546, "豹紋護身符"),
+        (34552, "錳鋼貝希摩斯王手鎧"),
+        (43211, "奧闊鉻鐵羊角錘"),
+        (36066, "無花果凍糕"),
+        (22487, "管弦樂琴樂譜：天風"),
+        (10872, "獅鷲革游擊襯褲"),
+        (34115, "輝翠銀精准面罩"),
+        (27271, "治療百合落地燈"),
+        (42147, "深紅木長槍"),
+        (31013, "收藏用靈青手鎧"),
+        (31878, "總督禦敵項環"),
+        (17537, "紅色櫻花頭花"),
+        (9026, "烏茲頭盔"),
+        (3138, "野豬革格鬥服"),
+        (1659, "鈷鐵賽施爾長刀"),
+        (10769, "飛龍革強襲手套"),
+        (38612, "巴爾巴莉希婭之劍展示台"),
+        (38896, "研究者蒸餾器"),
+        (24274, "路易波士紅茶"),
+        (48311, "高空作業用的腳手架"),
+        (2145, "雕花鞣革魔導書"),
+        (1635, "古典手半劍"),
+        (9333, "魚翅湯"),
+        (44011, "白黃金錠"),
+        (27720, "烏鋼石"),
+        (28467, "中庸蒸餾器"),
+        (1756, "黑鐵雙刃斧"),
+        (6680, "綠洲菱形地毯"),
+        (22900, "黑柳紡車"),
+        (4153, "盜龍革手環"),
+        (42466, "卡扎納爾重刺劍"),
+        (12023, "飛龍革項環"),
+        (10786, "奇美纖維強襲騎兵褲"),
+        (30079, "神龍夢幻文書"),
+        (37805, "復興治愈項鍊"),
+        (11924, "白鈦手鉗"),
+        (13792, "亞拉戈戰斧（複製品）"),
+        (38921, "研究者巧匠耳墜"),
+        (39630, "繼業者長劍"),
+        (9361, "奇蜥革"),
+        (40630, "仿制月光窗"),
+        (38930, "近東蝦咖喱"),
+        (6400, "石制綠洲別墅外牆"),
+        (48479, "基地沙發"),
+        (42494, "黑星石游擊開面罩盔"),
+        (10472, "賢王莫古火槍"),
+        (27144, "矮人銀圓革刀"),
+        (48247, "宇宙飛船用的硬質合金"),
+        (8558, "弦月花睡帽"),
+        (27270, "水晶都吊燈"),
+        (28145, "麥酒龍頭"),
+        (44158, "巧力之寶藥"),
+        (43250, "鈦金鶴嘴鋤"),
+        (26015, "野蠻盜龍革精准項鍊"),
+        (2710, "山羊革眼罩"),
+        (37840, "7級剛力之幻藥"),
+        (1825, "黑鐵長槍"),
+        (10529, "鈷鐵天星盤"),
+        (13263, "閱讀眼鏡"),
+        (37763, "復興禦敵胸甲"),
+        (19538, "卸鐵羊角錘"),
+        (10603, "精金頭人斧"),
+        (31137, "工作區的必需品"),
+        (18069, "迦迦納怪鳥革精准手套"),
+        (8662, "黑鐵小刀"),
+        (28763, "重建用的煉金藥"),
+        (41603, "翠光游擊槍"),
+        (38927, "研究者大地手環"),
+        (6550, "林間衣櫃"),
+        (29483, "伊修加德新型詠咒指環"),
+        (16657, "半熊革強襲護腿"),
+        (3935, "礦工工作靴"),
+        (25641, "深金武士刀"),
+        (4124, "秘銀手鍊"),
+        (42935, "舊日王國治愈項環"),
+        (42021, "釕金制敵鎧靴"),
+        (47915, "霓虹街頭鴨舌帽"),
+        (48510, "改良設備用的窗簾"),
+        (31109, "改良用雪松板材"),
+        (34111, "伽迦象革強襲坎肩"),
+        (4666, "鹽漬鱈魚"),
+        (38895, "研究者縫針"),
+        (4444, "賽黃晶戒指"),
+        (4617, "御土藥"),
+        (8697, "無瑕戒指"),
+        (23186, "納夏煎餅"),
+        (6394, "石制綠洲小屋外牆"),
+        (26302, "海燕革強襲短靴"),
+        (19741, "柿木戒指"),
+        (7983, "海濱門墊"),
+        (34745, "星點石英禦敵腕帶"),
+        (26436, "淨路之杖"),
+        (31016, "收藏用靈銀手鐲"),
+        (28642, "木制平台"),
+        (12031, "皇金大地耳環"),
+        (20735, "東洋櫥櫃"),
+        (19642, "嗶嘰巧匠包頭巾"),
+        (39550, "緋光鋼拳"),
+        (42339, "嵌齒象革精准筒靴"),
+        (11936, "暗栗木紡車"),
+        (35386, "球粒隕石工藝錘"),
+        (39729, "8級耐力之幻藥"),
+        (18237, "牛鬼革禮服手套"),
+        (31181, "修理自走人偶的部件"),
+        (28756, "重建用的藥湯"),
+        (5056, "青銅錠"),
+        (36692, "條約縫針"),
+        (4825, "黑麥粉"),
+        (3180, "秘銀重甲"),
+        (39395, "青藍之手掛鐘"),
+        (12864, "鮮貝羹"),
+        (37326, "豪勇咒杖"),
+        (18231, "牛鬼革游擊半指手套"),
+        (38267, "懸掛番茄沙拉"),
+        (36041, "炸帝王蟹餅"),
+        (25638, "斯劍虎革魔導書"),
+        (3793, "黑鐵鎧靴"),
+        (42183, "獰豹革精准護臂"),
+        (29419, "伊修加德新型魔機刃"),
+        (33657, "前沿軟甲褲"),
+        (42895, "舊日王國禦敵護膝褲"),
+        (3536, "業餘耐熱手套"),
+        (10389, "薩維奈手套"),
+        (7507, "羊絨治愈短裙"),
+        (16616, "紅色三色堇頭花"),
+        (41878, "雪木棉游擊毛帽"),
+        (48576, "面向兔兔族的植物成長劑"),
+        (18712, "玉綢詠咒長褲"),
+        (13685, "古鳥革鐘形帽"),
+        (27092, "深金平斬"),
+        (30421, "東方木制平台"),
+        (42491, "卡扎納爾精准手套"),
+        (7480, "鎢鋼重頭盔"),
+        (48296, "宇宙探索用的釣竿"),
+        (20266, "紫色雛菊頭花"),
+        (22538, "獺獺餐桌"),
+        (27210, "斯劍虎革指環"),
+        (10788, "獅鷲革強襲襯褲"),
+        (4577, "睡眠毒藥"),
+        (48519, "改良設備用的林間地毯"),
+        (41602, "翠光戰斧"),
+        (6437, "海濱門牌"),
+        (38261, "蜜瓜派"),
+        (10661, "白鈦天星盤"),
+        (18076, "赤麻游擊襯褲"),
+        (19941, "硬鋼錠"),
+        (2627, "羽毛擬餌"),
+        (8120, "緊急逃生工具"),
+        (13272, "鷹獵人襯衫"),
+        (14262, "管弦樂琴樂譜：悠久之風"),
+        (48465, "加工檢驗用的腰帶"),
+        (13667, "空賊游擊軟甲褲"),
+        (5333, "草線"),
+        (42501, "落雷絹治愈手套"),
+        (13089, "魔神之樹"),
+        (3311, "草布垮褲"),
+        (33599, "白帝大斧"),
+        (3628, "秘銀手鎧"),
+        (10103, "螢石透鏡"),
+        (26153, "力山羊游擊打底褲"),
+        (3579, "黑鐵重手鎧"),
+        (19552, "玉鋼圓革刀"),
+        (12034, "獅鷲革指環"),
+        (12098, "展示台"),
+        (19604, "阿拉米格工藝錘"),
+        (11955, "靈銀巧匠護目鏡"),
+        (34685, "球粒隕石魔導屠刀"),
+        (10804, "聖虹精准襯衫"),
+        (36882, "雪峰尖窗"),
+        (4476, "紫水晶戒指"),
+        (43289, "雪木棉頭巾"),
+        (13821, "亞拉戈精准手鎧（複製品）"),
+        (10408, "獄火大彎刀"),
+        (44176, "雞肉奶油面"),
+        (21696, "夜鋼爪"),
+        (6628, "林間桌台"),
+        (27166, "白麻大地包頭巾"),
+        (35327, "輝翠銀鯰魚精煎鍋"),
+        (14868, "聖蜥蜴革短裙"),
+        (3928, "鐵匠工作靴"),
+        (48514, "加工檢驗用的纖維"),
+        (19572, "鈀金雕金錘"),
+        (18092, "鋰輝石強攻耳墜"),
+        (31843, "總督強襲兜帽"),
+        (8110, "散熱元件"),
+        (8361, "獄火神錘"),
+        (13270, "高貴裙撐禮服"),
+        (4626, "御火秘藥"),
+        (19574, "鈀金縫針"),
+        (31818, "總督小刀"),
+        (42516, "黑星石精准項環"),
+        (3712, "煉金手套"),
+        (35588, "黃金外殼零件"),
+        (15562, "驚雷驚天"),
+        (28811, "空賊禦敵鬥褲（複製品）"),
+        (36073, "無花果餅乾"),
+        (11019, "靈銀精准手環"),
+        (17637, "管弦樂琴樂譜：無盡蒼空"),
+        (28837, "空賊治愈長筒靴（複製品）"),
+        (48556, "基地觀賞植物"),
+        (3321, "棉布垮褲"),
+        (34299, "棕櫚詠咒手環"),
+        (15599, "圓桌之拳"),
+        (18405, "星尖石精准耳墜"),
+        (39778, "面向收藏家的亞麻陽傘"),
+        (36716, "條約大地馬褲"),
+        (26164, "纏尾蛟革詠咒半指護手"),
+        (27183, "纏尾蛟革巧匠手套"),
+        (27234, "金屬制的三叉槍"),
+        (7617, "銀絲織錦"),
+        (4604, "剛力之秘藥"),
+        (35090, "古典風格禦敵指環"),
+        (35079, "古典風格詠咒耳墜"),
+        (9739, "枯井"),
+        (36199, "鐵木木材"),
+        (47944, "綠色葉子花耳環"),
+        (33250, "雲海橡果吊燈"),
+        (29438, "伊修加德新型強襲長靴"),
+        (44177, "圖萊尤拉烤犎牛肉"),
+        (4696, "煎餅"),
+        (18057, "硬鋼制敵護臂"),
+        (9728, "綠洲早餐組合"),
+        (5484, "蟹油"),
+        (31826, "總督武士刀"),
+        (1895, "梣木短弓"),
+        (46030, "儀仗平筆"),
+        (22722, "神轎部件"),
+        (15581, "無盡雲神魔導典"),
+        (28832, "空賊游擊長靴（複製品）"),
+        (10346, "水晶畫內牆"),
+        (8703, "現代鼻眼鏡"),
+        (34686, "球粒隕石魔導斷頭劍"),
+        (1821, "青銅長槍"),
+        (12617, "2級剛力煉金溶劑"),
+        (14231, "管弦樂琴樂譜：雲中匿影"),
+        (29839, "第二期重建用的特供菜餚"),
+        (39676, "繼業者治愈長衣"),
+        (3850, "獸角鱗脛甲"),
+        (37783, "復興游擊上衣"),
+        (10922, "虹布詠咒小丑帽"),
+        (9316, "徵糧鐮刀"),
+        (34585, "藍鋯石禦敵耳墜"),
+        (26166, "纏尾蛟革詠咒涼鞋"),
+        (13308, "荒神鋼強攻指環"),
+        (42477, "卡岡圖亞革禦敵軟甲褲"),
+        (25692, "烏鋼石詠咒耳墜"),
+        (31953, "第四期重建用的特供冰盒"),
+        (39659, "繼業者制敵矮靴"),
+        (15775, "1級水族箱"),
+        (27103, "魔銀縫針"),
+        (38865, "清掃機改良部件"),
+        (27792, "2級巧力之幻水"),
+        (9724, "理符櫃台"),
+        (3413, "呢絨緊身褲"),
+        (3318, "草布軟甲褲"),
+        (44857, "導覽地圖的印刷工具"),
+        (24276, "拉條子"),
+        (48602, "慰勞用的燉魚頭"),
+        (31204, "第三期重建用的皮袋"),
+        (4847, "食鹽"),
+        (42329, "鈦金制敵護腿"),
+        (10957, "古鳥革詠咒尖頭靴"),
+        (25840, "綠飄龍革治愈筒靴"),
+        (15928, "野豬革幸存者牛仔帽"),
+        (28513, "中庸平斬"),
+        (34742, "星點石英精准項環"),
+        (26177, "三重石詠咒項環"),
+        (27245, "工房的招牌"),
+        (33879, "紫色蝴蝶蘭耳飾"),
+        (8284, "堇青石耳墜"),
+        (48277, "宇宙探索用的手鐲"),
+        (42007, "釕金戰鐮"),
+        (36069, "南瓜香燴時蔬"),
+        (31213, "第三期重建用的手套"),
+        (7958, "海濱牆架"),
+        (44985, "皓光咒杖"),
+        (31147, "武器修理的部件"),
+        (10964, "禦敵虹布緞帶"),
+        (9040, "烏茲鎖甲靴"),
+        (44825, "黃色卡特蘭頭花"),
+        (28588, "都市外套"),
+        (10712, "靈銀制敵頭盔"),
+        (31847, "總督強襲長靴"),
+        (2526, "白鋼鶴嘴鋤"),
+        (9028, "烏茲連指手鎧"),
+        (48539, "統一規格的黏膠"),
+        (5182, "鋯石"),
+        (42887, "舊日王國奪命鐮"),
+        (15595, "偃武魔導典"),
+        (22570, "東洋清潔工具"),
+        (36690, "條約工藝錘"),
+        (16662, "半熊革精准軟甲褲"),
+        (9313, "工匠研鉢"),
+        (9298, "經典眼鏡"),
+        (19895, "1級意力之靈水"),
+        (42332, "鈦金強襲釘刺手套"),
+        (44094, "鹽烤奧闊鱒"),
+        (3765, "鞣革護腿"),
+        (15732, "絕靈容器"),
+        (7512, "蛟蜥詠咒手套"),
+        (39642, "繼業者天星盤"),
+        (14161, "樟腦"),
+        (13320, "荒神鋼詠咒指環"),
+        (27642, "改良型呢絨襯衫"),
+        (36325, "輝煌工具的第二次加工部件：煉金"),
+        (3871, "野豬革工作靴"),
+        (27251, "貝魯布風味的食餌"),
+        (8047, "詠詩裝備的改良材料"),
+        (36691, "條約制革刀"),
+        (12623, "巧力之龍藥"),
+        (18034, "硬鋼指虎"),
+        (7073, "碧海台鐘"),
+        (21711, "夜鋼禦敵頭盔"),
+        (31855, "總督游擊兜帽"),
+        (10723, "精金制敵重甲"),
+        (44023, "克拉洛胡桃木木材"),
+        (40637, "熱帶風情獨立式吊床"),
+        (12028, "獅鷲革手環"),
+        (12890, "蛋黃醬"),
+        (28875, "空鼠詠咒半指護手（複製品）"),
+        (38771, "面向收藏家的仙子莓撻"),
+        (19010, "真獅鷲革游擊胸衣"),
+        (5734, "煤煙黑染劑"),
+        (35066, "古典風格醫護兵長衣"),
+        (27804, "凝灰岩磨刀石"),
+        (27235, "耐魔法用首飾"),
+        (28840, "空賊詠咒手套（複製品）"),
+        (6482, "亞麻陽傘"),
+        (38855, "管樂器部件"),
+        (8851, "1級達識以太轉輪"),
+        (16630, "重合金騎槍"),
+        (27636, "硬革腿套"),
+        (46039, "儀仗制敵臂甲"),
+        (5800, "虛空藍染劑"),
+        (6457, "綠洲內牆"),
+        (19534, "卸鐵煎鍋"),
+        (19041, "榧木詠咒手鐲"),
+        (5367, "榆木木材"),
+        (27772, "魔銀附魔墨水"),
+        (4233, "石榴石耳墜"),
+        (3215, "顯貴短上衣"),
+        (23821, "羅剎術士道衣"),
+        (6565, "妖精櫥櫃"),
+        (48360, "宇宙鈦鐵錠"),
+        (10953, "祝聖青麻詠咒束膝褲"),
+        (1800, "古典鈎斧"),
+        (36201, "藤春木木材"),
+        (1657, "鈷鐵鬥劍"),
+        (13854, "亞拉戈高位制敵胸甲（複製品）"),
+        (20697, "石制東洋別墅外牆"),
+        (10998, "皇金強攻手鐲"),
+        (27866, "香腸"),
+        (12543, "瑪瑙"),
+        (46016, "儀仗玉杖"),
+        (10754, "靈銀強襲頭冠"),
+        (18373, "猛虎革強襲寬邊帽"),
+        (34446, "鉍金強攻手環"),
+        (4615, "御冰藥"),
+        (27296, "木制扶手"),
+        (17918, "高級裙撐禮服"),
+        (29803, "第二期重建用的鐵環"),
+        (10401, "白鋼雙手劍"),
+        (7482, "鎢鋼重手鎧"),
+        (2189, "雕花綠金之書"),
+        (19563, "玉鋼平斬"),
+        (35630, "收藏用完滿木腕環"),
+        (34137, "紫黃晶精准耳墜"),
+        (31080, "收藏用栗子冰糕"),
+        (42936, "舊日王國詠咒項環"),
+        (12918, "碧藍附魔墨水"),
+        (27278, "狩獵裝備櫃"),
+        (16645, "重合金禦敵鎧靴"),
+        (4222, "螢石耳墜"),
+        (43301, "獰豹革大地半指護手"),
+        (42877, "舊日王國魔導書"),
+        (28721, "烏賊墨汁炒飯"),
+        (2723, "黑鐵騎兵頭盔"),
+        (16644, "半熊革禦敵襯褲"),
+        (2581, "紫檀釣竿"),
+        (3121, "棉絨大地工作坎肩"),
+        (46005, "彩椒犎牛柳"),
+        (30852, "青君咒杖"),
+        (9184, "青銅小刀"),
+        (48248, "宇宙飛船用的硬質裝甲板"),
+        (2298, "牛血皮盾"),
+        (19025, "真獅鷲革詠咒涼鞋"),
+        (46049, "儀仗精准護臂"),
+        (16821, "管弦樂琴樂譜：電閃雷鳴"),
+        (3261, "絹布治愈長袍"),
+        (15587, "偃武長槍"),
+        (5364, "梣木木材"),
+        (34243, "輝翠銀打刀"),
+        (48411, "基地化妝鏡"),
+        (46037, "儀仗制敵護耳帽"),
+        (33224, "紀念醫院的日用品"),
+        (8852, "1級博識以太轉輪"),
+        (38853, "音箱調音設備"),
+        (10983, "雪人利齒禦敵戒指"),
+        (30991, "收藏用硬鋼斷頭劍"),
+        (2130, "黑角長杖"),
+        (25634, "深金大劍"),
+        (38891, "研究者橫頭錘"),
+        (39870, "火龍果果昔"),
+        (21833, "寶石獸浴缸"),
+        (26466, "中庸精准半指手套"),
+        (35645, "收藏用星點石英項環"),
+        (19633, "葛布巧匠長袍"),
+        (6481, "篝火爐"),
+        (34584, "錳鋼黑獅鷲筒靴"),
+        (34266, "阿爾馬斯提精准外套"),
+        (20740, "翠竹盆栽"),
+        (17021, "早餐蛋包飯"),
+        (25831, "虹繭游擊棉甲"),
+        (31870, "總督詠咒騎兵褲"),
+        (7150, "莫古力地板"),
+        (4676, "清燉鬼鮟鱇"),
+        (10659, "靈銀天星盤"),
+        (36330, "輝煌工具的第三次加工部件：雕金"),
+        (34238, "棕櫚手杖"),
+        (2451, "狼牙縫針"),
+        (31941, "第四期重建用的長靴"),
+        (19900, "2級意力之靈水"),
+        (8121, "預備水箱元件"),
+        (5075, "玄鋼板"),
+        (14724, "荒神革強襲護臂"),
+        (42200, "獰豹革詠咒涼鞋"),
+        (24493, "4級金屬水族箱"),
+        (34684, "完滿木魔導弓"),
+        (37408, "亞馬烏羅提內牆"),
+        (7019, "白鋼釘"),
+        (44078, "醬炒飯"),
+        (30401, "複製台階"),
+        (26433, "絲音"),
+        (23782, "羅剎細劍"),
+        (36707, "條約園藝鐮"),
+        (34274, "山雞革游擊高筒靴"),
+        (27861, "血番茄果汁"),
+        (28977, "俾斯麥早餐組合"),
+        (37816, "復興詠咒戒指"),
+        (34269, "山雞革精准高筒靴"),
+        (35077, "古典風格精准耳墜"),
+        (13850, "亞拉戈高位禦敵手鎧（複製品）"),
+        (27862, "仙子蘋果派"),
+        (7101, "滅菌系統"),
+        (34540, "金毗羅鰐革魔導書"),
+        (35871, "開心筒靴"),
+        (31907, "檸檬松餅"),
+        (21813, "獺獺提燈"),
+        (44058, "銀狼革"),
+        (7014, "燒結磚"),
+        (6478, "沙漠鐵木樹"),
+        (38548, "夢羽妖靈王手炮"),
+        (30997, "收藏用深金廚刀"),
+        (35061, "古典風格雙劍鬥士兜甲"),
+        (31887, "總督詠咒手鐲"),
+        (9485, "織蛛游擊頭巾"),
+        (24265, "3級意力之藥湯"),
+        (19537, "松木釣竿"),
+        (12706, "哥布林釣鈎"),
+        (25991, "野蠻盜龍革游擊護甲"),
+        (2334, "白鋼羊角錘"),
+        (31906, "豆子咖喱"),
+        (18734, "鈀金詠咒盔甲戒指"),
+        (42489, "黑星石精準頭甲"),
+        (12016, "星樺木腕環"),
+        (8049, "白愈裝備的改良材料"),
+        (43231, "深紅木釣竿"),
+        (25659, "白麻強襲長衫"),
+        (13268, "高貴筒靴"),
+        (24866, "黑色鈴蘭頭花"),
+        (13022, "鑌鐵大師涼鞋"),
+        (41905, "銀狼革精准手鐲"),
+        (27650, "盜龍革手套"),
+        (40657, "自助酒桶"),
+        (20811, "白色光明百合頭花"),
+        (11956, "聖虹布無檐帽"),
+        (42002, "釕金黃道儀"),
+        (34110, "輝翠銀強襲面罩"),
+        (2539, "白鋼碎石錘"),
+        (14141, "糖沼鯥"),
+        (48288, "改良設備用的隔熱木材"),
+        (31657, "收藏用矮人棉貝雷帽"),
+        (5784, "深林綠染劑"),
+        (35598, "特制油脂"),
+        (14243, "管弦樂琴樂譜：黑暗的擁抱"),
+        (16822, "管弦樂琴樂譜：平衡"),
+        (2364, "鈷鐵銼刀"),
+        (30839, "朱焰天儀"),
+        (42013, "釕金禦敵兜甲"),
+        (4749, "甘菊茶"),
+        (27280, "仿制小彩繪玻璃窗"),
+        (10807, "奇美纖維精准鬥服"),
+        (17607, "生命之戟"),
+        (4665, "熔岩蟾蜍腿"),
+        (9461, "仁慈短刺劍身"),
+        (42060, "河岸石治愈手鐲"),
+        (3351, "棉絨大地垮褲"),
+        (20694, "石制東洋公館外牆"),
+        (32935, "硬鋁錠"),
+        (29493, "3級巧力之幻藥"),
+        (6599, "利姆萊茵的祝福"),
+        (18520, "魔象革禦敵馬褲"),
+        (1872, "雄鹿戰戈"),
+        (31931, "第四期重建用的吊鍋"),
+        (43313, "嵌齒象革大地工作靴"),
+        (37812, "復興禦敵戒指"),
+        (17329, "空鼠游擊馬褲"),
+        (2593, "螃蟹丸子"),
+        (38565, "紫色香豌豆花項鍊"),
+        (7489, "蛟蜥軟甲褲"),
+        (46052, "儀仗游擊護耳帽"),
+        (27646, "改良型內襯呢絨軟甲褲"),
+        (3477, "民兵軟甲褲"),
+        (39653, "繼業者禦敵護膝褲"),
+        (2397, "黑鐵裝飾錘"),
+        (10838, "虹布游擊兜帽"),
+        (21800, "幻象稜晶"),
+        (3656, "野豬革火華手箍"),
+        (26484, "中庸詠咒半手套"),
+        (45997, "3級耐力之寶藥"),
+        (25649, "斯劍虎革禦敵軟甲褲"),
+        (48318, "基地書架"),
+        (27152, "矮人銀銼刀"),
+        (40632, "蒸籠燒麥"),
+        (1867, "三尖槍"),
+        (2573, "業餘釣竿"),
+        (4345, "金綠柱石項環"),
+        (36039, "庵摩羅果酸奶昔"),
+        (12999, "騎士精准作戰服"),
+        (3568, "棉布禮服手套"),
+        (18555, "魔象革詠咒手套"),
+        (15578, "無盡雲神牧杖"),
+        (42524, "黑星石禦敵指環"),
+        (12917, "翠綠高位附魔墨水"),
+        (10681, "精金禦敵重甲"),
+        (46041, "儀仗制敵脛甲"),
+        (6534, "綠洲拐沙發"),
+        (2320, "黑鐵陸行鳥手鋸"),
+        (6354, "混合制海濱別墅房頂"),
+        (27883, "鮭魚奶油面"),
+        (10969, "黃水晶禦敵項環"),
+        (15573, "無盡雲神遊擊槍"),
+        (39309, "沙龍服務員坎肩"),
+        (15553, "光銀廚刀"),
+        (34116, "伽迦象革精准短衣"),
+        (3775, "青銅鎧靴"),
+        (29417, "伊修加德新型武士刀"),
+        (13796, "亞拉戈咒杖（複製品）"),
+        (35351, "紅松木釣竿"),
+        (12523, "皇金錠"),
+        (10837, "獅鷲革精准高筒靴"),
+        (1609, "黃銅維京劍"),
+        (34293, "白鑞治愈項環"),
+        (42454, "卡扎納爾雙刃斧"),
+        (38269, "帝王海膽奶油面"),
+        (30978, "收藏用松木牧杖"),
+        (34729, "蛇牛革治愈長靴"),
+        (36711, "條約巧匠垮褲"),
+        (35658, "收藏用月凝膠"),
+        (44182, "菠蘿臍橙果凍"),
+        (42170, "獰豹革禦敵高筒靴"),
+        (11057, "蛋白石治愈戒指"),
+        (2332, "新來者羊角錘"),
+        (48257, "宇宙探索用的鐵板"),
+        (9360, "白金錠"),
+        (24526, "壁掛式花盆架"),
+        (7996, "吊鍋"),
+        (11928, "白雲母砂輪機"),
+        (34395, "鉍金槍刃"),
+        (27247, "魯特琴的調整部件"),
+        (7505, "羊絨治愈長袍"),
+        (30451, "唯美羊角錘"),
+        (44000, "鈦金塊"),
+        (17922, "高級薩維奈舞裙"),
+        (37796, "復興詠咒皮鞋"),
+        (37296, "綠色比爾格紫羅蘭項環"),
+        (4183, "玫瑰金手鍊"),
+        (3698, "顯貴手套"),
+        (26165, "力山羊詠咒打底褲"),
+        (14252, "管弦樂琴樂譜：墮天使"),
+        (8455, "改良型鎢鋼胸甲"),
+        (18108, "鋰輝石精准指環"),
+        (26295, "矮人棉制敵束膝褲"),
+        (29416, "伊修加德新型天星盤"),
+        (3415, "亞麻工作褲"),
+        (13312, "荒神鋼精准指環"),
+        (14152, "荒神布"),
+        (48362, "宇宙球粒隕石板"),
+        (48490, "宇宙探索用的細繩"),
+        (6373, "石制綠洲別墅房頂"),
+        (7016, "灰泥"),
+        (16013, "水晶吊燈"),
+        (36620, "收藏用球粒隕石工藝錘"),
+        (29802, "第二期重建用的鉚釘"),
+        (9284, "伴郎禮褲"),
+        (28983, "野餐籃"),
+        (7986, "海濱軟墊"),
+        (37360, "底燈木制夾層樓板"),
+        (39727, "8級剛力之幻藥"),
+        (48581, "宇宙探索用的多色煉金藥"),
+        (5074, "鈷鐵板"),
+        (30832, "朱焰苦無"),
+        (13846, "亞拉戈高位手槍（複製品）"),
+        (10773, "安菲瑟龍革強襲手套"),
+        (19645, "嗶嘰巧匠垮褲"),
+        (8281, "鑽石耳墜"),
+        (7018, "黑鐵釘"),
+        (24539, "邊境內牆"),
+        (34705, "迷彩絨制敵軍帽"),
+        (26336, "透鋰長石治愈項環"),
+        (27119, "鈦銅手鋸"),
+        (22899, "夜鋼平斬"),
+        (4702, "騎士麵包"),
+        (13906, "龍神強襲筒靴（複製品）"),
+        (40631, "金屬管置物架"),
+        (35407, "黑麻束褲"),
+        (27100, "靈青圓頭錘"),
+        (36057, "椰香魚羹"),
+        (4305, "紅銅衝鋒項環"),
+        (29468, "伊修加德新型詠咒耳墜"),
+        (3336, "棉布巧匠馬褲"),
+        (29405, "伊修加德新型利爪"),
+        (48357, "無人機用的反光板"),
+        (9727, "海濱晚餐組合"),
+        (8383, "賢王莫古拳"),
+        (31876, "總督治愈耳墜"),
+        (9330, "濃縮康寶藍"),
+        (15555, "光銀鐮刀"),
+        (27362, "硬鋼戰輪"),
+        (2810, "呢絨包頭巾"),
+        (4631, "御水秘藥"),
+        (17882, "高級屏風"),
+        (31825, "總督天球儀"),
+        (21042, "神龍裝甲"),
+        (21767, "黑柳詠咒腕環"),
+        (19826, "天婦羅拼盤"),
+        (9487, "織蛛游擊纏手帶"),
+        (31224, "第三期重建用的特供金匠工作台"),
+        (42459, "卡扎納爾之書"),
+        (22579, "獺獺長椅"),
+        (27716, "重鎢鋼錠"),
+        (22553, "林間小型儲物櫃"),
+        (25806, "深金禦敵翼冠"),
+        (2396, "新來者雕金錘"),
+        (27635, "改良型棉頭帶"),
+        (33293, "古代獸的頭骨"),
+        (2524, "新來者鶴嘴鋤"),
+        (15777, "3級水族箱"),
+        (44828, "紫色卡特蘭頭花"),
+        (19587, "鉬鋼廚刀"),
+        (18239, "牛鬼革治愈筒靴"),
+        (36666, "魔法餐盒"),
+        (31228, "第三期重建用的特供皮革椅子"),
+        (27663, "呢絨禦敵軟甲褲"),
+        (38877, "修理採集工具的部件"),
+        (8788, "綠洲小凳"),
+        (18508, "玉鋼火槍"),
+        (31935, "第四期重建用的聖水"),
+        (13302, "荒神鋼禦敵耳墜"),
+        (43614, "藍色向日葵頭花"),
+        (10536, "白金天星盤"),
+        (43290, "銀狼革外套"),
+        (3498, "玄鋼重足鎧"),
+        (4422, "紅銅戒指"),
+        (28163, "武器攤"),
+        (2000, "榆木牧杖"),
+        (39552, "緋光游擊槍"),
+        (28128, "游末邦枝形吊燈"),
+        (2495, "業餘平底鍋"),
+        (20432, "偃武太刀"),
+        (2560, "青銅鐮刀"),
+        (5060, "玄鋼錠"),
+        (26269, "矮人銀指虎"),
+        (13682, "虹布緊身上衣"),
+        (39725, "8級智力之幻水"),
+        (2672, "硬革小帽"),
+        (48632, "宇宙蘑菇醬"),
+        (37785, "復興游擊護膝褲"),
+        (43279, "克拉洛胡桃木紡車"),
+        (14717, "荒神革制敵頭甲"),
+        (14743, "荒神革詠咒護甲"),
+        (37283, "炸墨魚圈"),
+        (39244, "家用獺獺噴泉合成樹脂"),
+        (18377, "猛虎革強襲高筒靴"),
+        (4218, "孔雀石耳墜"),
+        (16655, "半熊革強襲手套"),
+        (25788, "深金短劍"),
+        (21825, "哈利卡納蘇斯午茶組合"),
+        (18201, "白銅雙板天星盤"),
+        (30484, "工匠的煉金溶劑"),
+        (30406, "複製照明"),
+        (2448, "白銀縫針"),
+        (18720, "鈀金禦敵項環"),
+        (9355, "希瓦裝甲"),
+        (2569, "鈷鐵鐮刀"),
+        (42892, "舊日王國禦敵頭盔"),
+        (13084, "陸行鳥綠雕"),
+        (2888, "古典兜帽"),
+        (10828, "奇美纖維精准騎兵褲"),
+        (38263, "落日胡蘿蔔絲"),
+        (26443, "守護之刃"),
+        (13590, "翼手龍革細繩"),
+        (48393, "無人機用的耐高溫金屬"),
+        (38767, "面向收藏家的天文望遠鏡"),
+        (26141, "力山羊強襲短裙"),
+        (36249, "賽加羚羊革"),
+        (18954, "琅玕禦敵項環"),
+        (11067, "靈銀詠咒手環"),
+        (39915, "船景跳跳樂建造用金屬材料"),
+        (13019, "鑌鐵大師圍裙"),
+        (35466, "蛇牛革大地手環"),
+        (13848, "亞拉戈高位禦敵頭冠（複製品）"),
+        (38956, "殊級恢復藥"),
+        (8833, "方磚地板"),
+        (4652, "芥末蛋"),
+        (2779, "亞麻寬邊帽"),
+        (13007, "戎甲大師圓頭錘"),
+        (18994, "真獅鷲革制敵馬褲"),
+        (35589, "黃金精密齒輪"),
+        (48275, "耐儲存食品用的料酒"),
+        (44897, "仿制衣櫃門"),
+        (4168, "黑珍珠手鐲"),
+        (36863, "木制雪峰小屋房頂"),
+        (18401, "猛虎革詠咒長筒靴"),
+        (19515, "硬鋼手斧"),
+        (27887, "仙子莓茶"),
+        (14902, "毛絨絨番茄國王"),
+        (19656, "瞪羚革皮靴"),
+        (19909, "柿漆"),
+        (35385, "球粒隕石圓頭錘"),
+        (2886, "纖維貝雷帽"),
+        (29833, "第二期重建用的特供吊燈"),
+        (12062, "7號蒸汽小戰車"),
+        (47941, "紅色葉子花耳環"),
+        (12112, "雲神靈泉"),
+        (8127, "裝飾元件"),
+        (2612, "黃銅勺形魚餌"),
+        (7013, "磚塊"),
+        (18965, "琅玕強攻指環"),
+        (28511, "中庸手鉗"),
+        (2071, "土華咒角"),
+        (5576, "星盤杖量表"),
+        (13919, "龍神詠咒手套（複製品）"),
+        (5740, "果酒紅染劑"),
+        (38817, "孚布特風土美食"),
+        (28727, "重建用的金屬板"),
+        (4377, "黑珍珠項環"),
+        (8554, "豹紋女式海灘裙"),
+        (41859, "銀狼革禦敵外套"),
+        (34727, "球粒隕石治愈手套"),
+        (3399, "亞麻軟甲褲"),
+        (5186, "綠松石"),
+        (2806, "呢絨兜帽"),
+        (48304, "加工檢驗用的釣竿"),
+        (13661, "空賊精准五分褲"),
+        (34398, "鉍金戰鐮"),
+        (33898, "再品形槁心灰之苦"),
+        (35026, "古典風格巨劍"),
+        (18537, "魔象革精准手套"),
+        (4332, "白銀工藝項環"),
+        (15651, "田園番茄面"),
+        (29410, "伊修加德新型巨劍"),
+        (25854, "硬水鋁石強攻項環"),
+        (4467, "秘銀巧匠戒指"),
+        (6522, "就坐系統"),
+        (35444, "紫黃晶巧匠耳夾"),
+        (22726, "淨化用祭具"),
+        (2398, "白鋼雕金錘"),
+        (7500, "蛟蜥精准手套"),
+        (31926, "第四期重建用的布料"),
+        (9512, "高質的研鉢"),
+        (12842, "伊修加德奶茶"),
+        (29428, "伊修加德新型制敵軍帽"),
+        (18059, "迦迦納怪鳥革制敵腿甲靴"),
+        (24575, "崖畔亭備用品的部件"),
+        (27752, "仙子棉線"),
+        (41873, "雪木棉精准毛帽"),
+        (23815, "羅剎學士道衣"),
+        (10674, "精金禦敵頭盔"),
+        (41592, "習武背心"),
+        (11082, "獅鷲利爪詠咒戒指"),
+        (44418, "探求永恆煎鍋"),
+        (27715, "矮人銀塊"),
+        (26151, "力山羊游擊長衣"),
+        (27177, "野蠻盜龍革大地坎肩"),
+        (30493, "龍族用採集工具部件"),
+        (11942, "精金加固研鉢"),
+        (15472, "幸存者牛仔帽"),
+        (2786, "白鋼開面盔"),
+        (25859, "硬水鋁石強攻手鐲"),
+        (35569, "松軟煎餅套餐"),
+        (10835, "安菲瑟龍革精准長靴"),
+        (48516, "基地裁縫套裝"),
+        (27888, "仙子莓奶酪蛋糕"),
+        (30825, "月讀命幽夜細劍"),
+        (40633, "煎餃"),
+        (29657, "改良用的羊皮紙"),
+        (38591, "側系帶窗簾"),
+        (10604, "靈銀三尖槍"),
+        (31914, "第四期重建用的合金"),
+        (26437, "催化之杖"),
+        (3762, "鞣革尖頭靴"),
+        (10974, "皇金禦敵手鐲"),
+        (3651, "盜龍革半指護手"),
+        (18686, "瞪羚革強襲護甲"),
+        (2440, "鈷鐵平斬"),
+        (25965, "靈青塔盾"),
+        (15726, "再利用建築資材"),
+        (21763, "黑柳禦敵腕環"),
+        (5175, "石榴石"),
+        (48559, "統一規格的宇宙泥漿"),
+        (35636, "收藏用輝翠銀拳套"),
+        (29436, "伊修加德新型強襲手套"),
+        (2653, "鞣革眼罩"),
+        (4470, "透綠柱石戒指"),
+        (27121, "鈦銅球頂錘"),
+        (47096, "宇宙探索用的貨箱"),
+        (36354, "管弦樂琴樂譜：呼喚終結之神"),
+        (7141, "鄉村地板"),
+        (34753, "星點石英治愈指環"),
+        (3187, "呢絨圍裙裝"),
+        (42941, "舊日王國詠咒手環"),
+        (42880, "舊日王國大劍"),
+        (42526, "黑星石精准指環"),
+        (48566, "基地化妝箱"),
+        (48327, "統一規格的釘子"),
+        (44087, "羊駝奶油面"),
+        (28761, "重建用的工作服"),
+        (39782, "面向收藏家的吊燈"),
+        (35449, "馬栗木大地項鍊"),
+        (31107, "改良用黏膠"),
+        (34291, "白鑞強攻項環"),
+        (30404, "複製自動門"),
+        (29409, "伊修加德新型小刀"),
+        (41832, "觀星平台"),
+        (3406, "古典騎兵褲"),
+        (43282, "卡扎納爾碎石錘"),
+        (29465, "伊修加德新型強攻耳墜"),
+        (42528, "黑星石詠咒指環"),
+        (8044, "昭武裝備的改良材料"),
+        (10801, "奇美纖維精准兜帽"),
+        (34281, "阿爾馬斯提詠咒外套"),
+        (2380, "業餘手鉗"),
+        (6378, "混合制海濱公館外牆"),
+        (48612, "加工檢驗用的鹽"),
+        (28480, "中庸大地打底褲"),
+        (42164, "深紅木圓筆"),
+        (35032, "古典風格黃道儀"),
+        (34572, "錳鋼墮龍手套"),
+        (19514, "硬鋼鶴嘴鎬"),
+        (4115, "榍石手鐲"),
+        (48184, "聖輝魔導典"),
+        (18671, "鈀金穿甲劍"),
+        (29471, "伊修加德新型精准項環"),
+        (10827, "祝聖青麻精准束膝褲"),
+        (27295, "三明治籃子"),
+        (12089, "命名日蛋糕"),
+        (12013, "星樺木項鍊"),
+        (27640, "改良型野豬革長靴"),
+        (13075, "東方圓桌"),
+        (44426, "探求永恆平斬"),
+        (33882, "混色蝴蝶蘭耳飾"),
+        (5787, "魔花綠染劑"),
+        (35634, "收藏用錳鋼橫頭錘"),
+        (36211, "絳紅草布"),
+        (15149, "間接照明"),
+        (35373, "錳鋼羊角錘"),
+        (18264, "姆德斯德角精准指環"),
+        (31021, "收藏用白銅天星盤"),
+        (44193, "收藏用鈷鎢彎刀"),
+        (44830, "黑色卡特蘭頭花"),
+        (44162, "2級剛力之寶藥"),
+        (37795, "復興詠咒護膝褲"),
+        (18553, "嗶嘰詠咒寬邊帽"),
+        (3313, "草布腿套"),
+        (35366, "錳鋼圓革刀"),
+        (31202, "第三期重建用的吊鍋"),
+        (34253, "山雞革禦敵騎兵褲"),
+        (6422, "林間圓形煙囪"),
+        (15967, "卡爾克娃娃"),
+        (6360, "混合制林間公館房頂"),
+        (47601, "火焰噴射器的燃料"),
+        (42504, "黑星石詠咒口罩"),
+        (2151, "紫杉巫書"),
+        (3909, "盜龍革工作靴"),
+        (44218, "收藏用犎牛嗶嘰工作帽"),
+        (27787, "巧力之幻藥"),
+        (35047, "古典風格騎士袖甲"),
+        (29985, "紫色風信子頭花"),
+        (3011, "青銅鎖子鎧"),
+        (17924, "天女的羽衣"),
+        (18421, "星尖石治愈指環"),
+        (31898, "披薩"),
+        (10849, "奇美纖維游擊鬥服"),
+        (36885, "雪峰裝飾門"),
+        (33903, "再品妒火中燒之苦"),
+        (41858, "混金禦敵頭甲"),
+        (42177, "薄絹強襲鬥服"),
+        (4586, "沈默劇毒藥"),
+        (2737, "白銀頭冠（透綠柱石）"),
+        (6190, "無頭騎士模型"),
+        (16666, "星絨游擊罩衣"),
+        (44187, "收藏用深紅木項鍊"),
+        (35811, "濃翠吊燈"),
+        (44045, "巧力之寶水"),
+        (27091, "白橡木砂輪機"),
+        (13877, "亞拉戈高位詠咒筒靴（複製品）"),
+        (2502, "白鋼煎鍋"),
+        (36049, "茄子鑲桓娑鳥肉"),
+        (20705, "東洋朱漆煙囪"),
+        (11064, "皇金詠咒項鍊"),
+        (32798, "小牛皮騎手運動帽"),
+        (10657, "貴族之典"),
+        (34260, "阿爾馬斯提強襲頭帶"),
+        (25654, "斯劍虎革制敵手套"),
+        (4353, "秘銀工藝項環"),
+        (48274, "宇宙探索用的煉金藥"),
+        (20682, "石制東洋小屋房頂"),
+        (6639, "海濱花瓶"),
+        (3019, "草布巧匠工作坎肩"),
+        (5071, "青銅板"),
+        (48330, "宇宙探索用的鋸子"),
+        (14052, "伊修加德晚餐組合"),
+        (19523, "硬鋼研鉢"),
+        (35564, "森林舊椅子"),
+        (41083, "米灰色地毯"),
+        (42878, "舊日王國魔導典"),
+        (18952, "琅玕治愈耳墜"),
+        (10709, "精金禦敵鎧靴"),
+        (25857, "硬水鋁石詠咒項環"),
+        (31155, "矮人族繪圖的工具"),
+        (43999, "鈷鎢錠"),
+        (2238, "古典方盾"),
+        (30987, "收藏用靈銀圓手鋸"),
+        (23794, "羅剎槍士護脛"),
+        (48284, "改良設備用的隔熱材料"),
+        (26347, "透鋰長石詠咒指環"),
+        (28126, "游末邦內牆"),
+        (31006, "收藏用硬鋼蒸餾器"),
+        (31655, "收藏用矮人銀指環"),
+        (4642, "貓魅風味山珍烤串"),
+        (27870, "胡椒炒新薯"),
+        (1642, "哥布林彎刀"),
+        (25667, "白麻精准打底褲"),
+        (9730, "東方祭壇"),
+        (14894, "邪鬼長靴"),
+        (18380, "葛布精准束腰獵衣"),
+        (20698, "東洋方窗"),
+        (2685, "黃銅頭冠（日長石）"),
+        (43891, "全新的裝幀部件"),
+        (18662, "鉬鋼小刀"),
+        (46017, "儀仗魔導書"),
+        (34726, "蛇牛革治愈上裝"),
+        (2507, "業餘廚刀"),
+        (1838, "黑鐵戰戟"),
+        (10932, "祝聖青麻詠咒工作服"),
+        (42169, "薄絹禦敵腿套"),
+        (37831, "仁面木木材"),
+        (1941, "古典戰弓"),
+        (7100, "投射系統"),
+        (15805, "管弦樂琴樂譜：迎風"),
+        (4598, "意力之藥"),
+        (42886, "舊日王國圓月輪"),
+        (1633, "白鋼彎刃刀"),
+        (13592, "鈦合金方板"),
+        (18728, "鈀金治愈手環"),
+        (46858, "宇宙探索用的水產貨箱"),
+        (1967, "紫杉幻杖"),
+        (26498, "中庸禦敵手鐲"),
+        (6575, "綠洲立柱"),
+        (43251, "鈦金手斧"),
+        (2088, "綠金咒杖"),
+        (19812, "糖煮水果果凍"),
+        (13301, "樟木禦敵項鍊"),
+        (41897, "五加木詠咒耳墜"),
+        (42899, "舊日王國制敵臂甲"),
+        (23374, "風尚禮鞋"),
+        (48262, "試驗加工用的木材"),
+        (18360, "卸鐵鳶盾"),
+        (10879, "獅鷲革游擊長靴"),
+        (5086, "紅銅環"),
+        (10049, "橢圓讀書眼鏡"),
+        (27299, "無瑕獎杯"),
+        (4700, "玉米麵包"),
+        (21704, "清銀法杖"),
+        (3549, "山羊革護腕"),
+        (2478, "熱能蒸餾器"),
+        (27138, "鈦銅碎石錘"),
+        (19035, "榧木治愈項環"),
+        (42334, "嵌齒象革強襲筒靴"),
+        (42474, "卡扎納爾禦敵頭盔"),
+        (2566, "革柄白鋼鐮刀"),
+        (13262, "典雅無框眼鏡"),
+        (20684, "混合制東洋公館房頂"),
+        (18345, "卸鐵長劍"),
+        (11931, "靈銀平斬"),
+        (38623, "野營套裝"),
+        (3860, "野豬革長靴"),
+        (12109, "星紅石八音盒"),
+        (3770, "鞣革長筒靴"),
+        (9488, "織蛛游擊束膝褲"),
+        (34538, "鐵木牧杖"),
+        (35038, "古典風格戰鐮"),
+        (19042, "鈀金禦敵指環"),
+        (9072, "白金詠咒頭飾"),
+        (42016, "釕金禦敵鎧靴"),
+        (27365, "卸鐵槍刃"),
+        (2250, "山羊革皮盾"),
+        (33899, "再燃渴念離人之欲"),
+        (2814, "呢絨寬邊帽"),
+        (42343, "嵌齒象革游擊軟甲褲"),
+        (42049, "河岸石精准耳墜"),
+        (6427, "風向仙人掌"),
+        (41265, "面向收藏家的大理石噴泉"),
+        (7017, "清漆"),
+        (21702, "夜鋼雷火炮"),
+        (17884, "茶屋長椅"),
+        (19890, "意力之藥湯"),
+        (21204, "拉札罕秋意襯衫"),
+        (5289, "駿鷹軟革"),
+        (19810, "草原奶茶"),
+        (3647, "呢絨半指手套"),
+        (9307, "工匠羊角錘"),
+        (3534, "鞣革連指手套"),
+        (28197, "飛空艇改裝必需品"),
+        (39701, "繼業者強攻盔甲戒指"),
+        (13061, "美容師傳喚鈴"),
+        (7109, "莊園演奏椅"),
+        (20695, "木制東洋別墅外牆"),
+        (13659, "空賊精准坎肩"),
+        (36893, "雪峰圓形煙囪"),
+        (19739, "柿木項鍊"),
+        (34251, "輝翠銀禦敵戰甲"),
+        (23000, "花花公子便鞋"),
+        (31044, "收藏用纏尾蛟革半指護手"),
+        (48511, "急救用的繃帶"),
+        (18353, "松木牧杖"),
+        (34381, "鉍金旋棍"),
+        (9744, "野香蕉樹"),
+        (9622, "2級適應飼料"),
+        (38892, "研究者圓頭錘"),
+        (41092, "星空屏風"),
+        (2776, "亞麻包頭巾"),
+        (6461, "海濱吊燈"),
+        (34430, "賽加羚羊革詠咒儀仗帽"),
+        (19030, "鈀金治愈耳墜"),
+        (25666, "白麻精准長手套"),
+        (43343, "卡扎納爾巧匠戒指"),
+        (19814, "散壽司"),
+        (4343, "橄欖石項環"),
+        (10622, "靈銀大劍"),
+        (13899, "龍神制敵臂甲（複製品）"),
+        (29429, "伊修加德新型制敵上裝"),
+        (25050, "無限鬼神之儀"),
+        (36011, "永暗裝甲"),
+        (38893, "研究者工藝錘"),
+        (4620, "御火妙藥"),
+        (5373, "橡木木材"),
+        (34288, "棕櫚治愈耳夾"),
+        (33278, "濾掛式咖啡"),
+        (34275, "阿爾馬斯提治愈無檐帽"),
+        (1958, "楓木幻杖"),
+        (2346, "黑鐵鳥嘴錘"),
+        (39673, "繼業者游擊打底褲"),
+        (18397, "葛布詠咒寬邊帽"),
+        (34118, "黑麻精准軟甲褲"),
+        (29806, "第二期重建用的植物油"),
+        (29444, "伊修加德新型精准長靴"),
+        (34152, "紫黃晶精准指環"),
+        (2712, "黑鐵頭盔"),
+        (18519, "玉鋼禦敵手鎧"),
+        (6541, "登記台"),
+        (4567, "蘇醒藥"),
+        (34423, "雪亞麻游擊垮褲"),
+        (29412, "伊修加德新型牧杖"),
+        (31023, "收藏用翠銀手甲"),
+        (39562, "緋光武士刀"),
+        (10052, "工匠圍裙裝"),
+        (27098, "靈青圓手鋸"),
+        (19947, "鉬鋼錠"),
+        (14258, "管弦樂琴樂譜：絢爛"),
+        (25030, "神聖女神之幻杖"),
+        (35347, "鉍金蒸餾器"),
+        (31063, "收藏用長頸駝革魔導典"),
+        (13860, "亞拉戈高位強襲手鎧（複製品）"),
+        (25040, "無限鬼神之斧"),
+        (41084, "海底吊燈"),
+        (27781, "暗銀附魔墨水"),
+        (42352, "犎牛嗶嘰詠咒半指手套"),
+        (35787, "矮人銀蛇石針"),
+        (12590, "青麻布"),
+        (35069, "古典風格醫護兵戰靴"),
+        (8836, "冰神枝形吊燈"),
+        (10747, "靈銀制敵鎧靴"),
+        (4698, "蜂蜜松餅"),
+        (10104, "蛟蜥漆皮"),
+        (27122, "鈦銅雕金錘"),
+        (3319, "硬革三角褲"),
+        (12610, "水晶板"),
+        (33893, "再品惻隱悲憫之苦"),
+        (30440, "唯美手鋸"),
+        (36871, "混合制雪峰別墅房頂"),
+        (21083, "阿拉米格纖維"),
+        (43307, "犎牛嗶嘰巧匠軟甲褲"),
+        (13680, "空賊詠咒長筒靴"),
+        (40415, "陸行鳥寢室拖鞋"),
+        (31925, "第四期重建用的皮繩"),
+        (14714, "光銀禦敵手鎧"),
+        (9625, "2級體力飼料"),
+        (17614, "生命之書"),
+        (25798, "綠飄龍革魔導書"),
+        (7487, "鎢鋼胸甲"),
+        (43230, "鈷鎢手斧"),
+        (2319, "黑鐵雕紋手鋸"),
+        (14095, "利維亞桑之形"),
+        (4655, "辛辣蛋"),
+        (2109, "獸齒牡羊角長杖"),
+        (10890, "祝聖青麻治愈工作服"),
+        (13595, "國王餅"),
+        (25990, "野蠻盜龍革游擊頭甲"),
+        (4690, "金錢蘑炒雞肉"),
+        (48176, "聖輝游擊槍"),
+        (1976, "土華幻杖"),
+        (48300, "室外活動用的木炭"),
+        (6562, "綠洲櫥櫃"),
+        (25793, "深金小刀"),
+        (34708, "迷彩絨制敵打底褲"),
+        (27709, "石金錠"),
+        (13593, "破魔箭"),
+        (41067, "研究員的休息必需品"),
+        (6363, "混合制林間別墅房頂"),
+        (31915, "第四期重建用的金屬板"),
+        (19040, "榧木治愈手鐲"),
+        (17880, "高級煙袋盒"),
+        (38585, "奶酪火鍋"),
+        (34392, "弗里金太陽儀"),
+        (19996, "魔象革"),
+        (40620, "監視者之館內牆"),
+        (27638, "改良型棉絨頭巾"),
+        (35653, "收藏用雪亞麻布巧匠工作坎肩"),
+        (3016, "鞣革皮甲"),
+        (41886, "雪木棉治愈打底褲"),
+        (17024, "商店置物櫃"),
+        (3475, "顯貴打底褲"),
+        (13797, "亞拉戈詠咒魔導書（複製品）"),
+        (9769, "黑白方格地板"),
+        (30441, "唯美橫頭錘"),
+        (39687, "繼業者精准耳墜"),
+        (19743, "魔象革耳墜"),
+        (6426, "風向碧企鵝"),
+        (44083, "香蕉星磅蛋糕"),
+        (42361, "白黃金強攻項環"),
+        (26474, "中庸游擊高筒靴"),
+        (15520, "碳纖布巧匠無檐帽"),
+        (31220, "第三期重建用的工作服"),
+        (22451, "2級意力之藥湯"),
+        (36055, "賢人三明治"),
+        (40682, "常青藤遮陽棚木制平台"),
+        (35040, "古典風格劍鬥士頭甲"),
+        (1766, "雷暴戰斧"),
+        (3613, "野豬革大地半指護手"),
+        (12595, "青麻線"),
+        (3934, "烹調高跟鞋"),
+        (27373, "鉬鋼槍刃"),
+        (2434, "新來者平斬"),
+        (40623, "園藝工作桌"),
+        (10698, "古鳥革禦敵馬褲"),
+        (35869, "開心手鍊"),
+        (22903, "夜鋼碎石錘"),
+        (3724, "玄鋼連指手鎧"),
+        (8858, "1級疾風以太轉輪"),
+        (42918, "舊日王國治愈外套"),
+        (3680, "纖維半指手套"),
+        (8459, "改良型羊絨詠咒長袍"),
+        (15164, "薩雷安地板"),
+        (42038, "高山亞麻治愈鬥篷"),
+        (43895, "冷園果汁"),
+        (19644, "魔象革巧匠手套"),
+        (22897, "夜鋼手鉗"),
+        (2475, "白銀蒸餾器"),
+        (7536, "盛花腕帶"),
+        (20545, "須佐之男之形"),
+        (34121, "伽迦象革游擊坎肩"),
+        (39561, "緋光天儀"),
+        (35368, "錳鋼蒸餾器"),
+        (31919, "第四期重建用的墨水"),
+        (34445, "鉍金禦敵手環"),
+        (2932, "玫瑰金頭冠"),
+        (13031, "鏤金大師軟甲褲"),
+        (2345, "新來者橫頭錘"),
+        (18200, "牛鬼革魔導典"),
+        (5577, "帽子絹布"),
+        (31653, "收藏用矮人銀手斧"),
+        (35428, "金毗羅鰐革巧匠皮鞋"),
+        (31219, "第三期重建用的篝火台"),
+        (22493, "真麻布"),
+        (22433, "什錦壽司卷"),
+        (14943, "恐龍革禮鞋"),
+        (21866, "東洋溫泉"),
+        (21929, "綠色鬱金香腕帶"),
+        (23829, "羅剎學士耳飾"),
+        (22999, "花花公子皺邊褲"),
+        (7483, "鎢鋼重腿甲"),
+        (2057, "紅銅咒杖"),
+        (39684, "繼業者詠咒矮靴"),
+        (10955, "安菲瑟龍革詠咒緊身褲"),
+        (17912, "灰熊手套"),
+        (33897, "再識幸災樂禍之悅"),
+        (32254, "混合木制內牆"),
+        (15958, "無敵級飛空艇模型"),
+        (30380, "複製屋頂照明"),
+        (30856, "青君鱗紋刀"),
+        (6524, "林間長椅"),
+        (8359, "獄火神弓"),
+        (20547, "俾斯麥之形"),
+        (11045, "蛋白石治愈手鐲"),
+        (24515, "燉點組合"),
+        (36183, "白鑞錠"),
+        (35432, "絳紅草布大地五分褲"),
+        (34567, "金毗羅鰐革最後的獨角獸護臂"),
+        (34092, "輝翠銀天星盤"),
+        (12912, "蒼天石"),
+        (2802, "秘銀騎兵頭盔"),
+        (34420, "雪亞麻游擊無檐帽"),
+        (42313, "鈦金槍刃"),
+        (10609, "精金三尖槍"),
+        (11967, "長頸駝革半指手套"),
+        (10590, "精金寬刃劍"),
+        (36082, "椰奶"),
+        (36234, "6級耐力之幻水"),
+        (34563, "絳紅草布升龍束膝褲"),
+        (12859, "皇家蒸蛋"),
+        (30057, "達爾馬斯卡皮鞋"),
+        (8092, "氣囊強化元件"),
+        (6424, "綠洲圓形煙囪"),
+        (31916, "第四期重建用的金屬錠"),
+        (27272, "治療百合壁燈"),
+        (22539, "獺獺屏風"),
+        (29430, "伊修加德新型制敵手套"),
+        (18106, "鋰輝石禦敵指環"),
+        (5286, "邪鬼革"),
+        (42518, "黑星石詠咒項環"),
+        (42195, "獰豹革治愈涼鞋"),
+        (26453, "中庸制敵戰鎧"),
+        (7615, "玫瑰金齒輪"),
+        (3053, "棉布大地工作坎肩"),
+        (48503, "基地窗簾"),
+        (34296, "棕櫚強攻手環"),
+        (48585, "藍色宇宙煉金藥"),
+        (23844, "羅剎學士指飾"),
+        (13311, "樟木精准腕環"),
+        (48616, "慰勞用的碳烤魚"),
+        (42047, "河岸石禦敵耳墜"),
+        (36072, "白桃撻"),
+        (28465, "中庸圓革刀"),
+        (36622, "收藏用球粒隕石縫針"),
+        (15744, "卷揚機部件"),
+        (39679, "繼業者治愈矮靴"),
+        (2753, "白鋼角盔"),
+        (17323, "空鼠精准半指護手"),
+        (4711, "鰻魚派"),
+        (7072, "廚匠烹飪台"),
+        (43232, "鈷鎢羊角錘"),
+        (3769, "業餘鴨嘴靴"),
+        (11030, "瑪瑙精准戒指"),
+        (37401, "花園大門"),
+        (13036, "精革大師軟甲褲"),
+        (10811, "飛龍革精准護袖"),
+        (31007, "收藏用硬鋼護腰帶"),
+        (18063, "赤麻強襲護臂"),
+        (34151, "紫黃晶強攻指環"),
+        (42945, "舊日王國治愈指環"),
+        (42509, "黑星石禦敵耳夾"),
+        (34554, "錳鋼貝希摩斯王鎧靴"),
+        (2525, "黑鐵鶴嘴鎬"),
+        (29441, "伊修加德新型精准上裝"),
+        (15969, "魔法人偶台燈"),
+        (31814, "總督虎爪"),
+        (10959, "長頸駝革詠咒法靴"),
+        (44433, "探求永恆巧匠上裝"),
+        (31948, "第四期重建用的篝火台"),
+        (48240, "月面礦物合金"),
+        (8666, "秘銀匕首"),
+        (8563, "橢圓眼鏡"),
+        (21057, "羅波那之形"),
+        (3827, "巨蟾蜍革腿甲靴"),
+        (42057, "河岸石禦敵手鐲"),
+        (8008, "林間葉紋內牆"),
+        (8798, "香爐"),
+        (10616, "靈銀輔劍"),
+        (9332, "煌黑魚子醬"),
+        (26467, "中庸精准襯褲"),
+        (7114, "莊園樂譜架"),
+        (48369, "改良設備用的燃料容器"),
+        (46014, "儀仗短弓"),
+        (28734, "重建用的鐵釘"),
+        (34570, "錳鋼墮龍戰盔"),
+        (15515, "加隆德巧匠無檐帽"),
+        (27998, "2級智力之幻藥"),
+        (34427, "賽加羚羊革治愈手套"),
+        (9226, "仁慈短刺"),
+        (20691, "石制東洋小屋外牆"),
+        (35633, "收藏用鉍金錘"),
+        (6448, "海濱石制圍牆"),
+        (27690, "仙果木木材"),
+        (4141, "紫水晶手鐲"),
+        (19545, "卸鐵廚刀"),
+        (44994, "皓光騎士武具"),
+        (42882, "舊日王國天星盤"),
+        (20777, "金碟游樂場的紀念品"),
+        (16691, "柚木治愈項環"),
+        (5510, "橡膠"),
+        (28850, "空鼠制敵鐵鈎護臂（複製品）"),
+        (19730, "落葉松手鍊"),
+        (28842, "空賊詠咒長筒靴（複製品）"),
+        (3275, "草布巧匠馬褲"),
+        (15550, "光銀平斬"),
+        (40396, "藍色菊花頭花"),
+        (42875, "舊日王國燭杖"),
+        (44104, "烤牛肉夾餅"),
+        (25963, "靈青槍刃"),
+        (36212, "迷彩絨"),
+        (43909, "尤卡巨人族樣式的封面"),
+        (44149, "重蟻木木材"),
+        (36079, "紫蘇油"),
+        (4718, "翡翠豆湯"),
+        (42461, "卡扎納爾小刀"),
+        (30678, "真·光之鳥甲"),
+        (15916, "厚土長杖"),
+        (2377, "鈷鐵圓頭錘"),
+        (23788, "羅剎豪士護脛"),
+        (36104, "5級剛力之幻藥"),
+        (12621, "2級意力煉金溶劑"),
+        (29807, "第二期重建用的麵包"),
+        (3912, "鈷鐵鎧靴"),
+        (34277, "阿爾馬斯提治愈手套"),
+        (23769, "羅剎鐵甲"),
+        (9038, "烏茲手鎧"),
+        (24329, "邪龍怨影魔杖"),
+        (3578, "山羊革大地半指護手"),
+        (26482, "中庸詠咒寬邊帽"),
+        (31187, "第三期重建用的金屬錠"),
+        (27873, "墨汁燉飯"),
+        (29829, "第二期重建用的帳篷"),
+        (27860, "長棍麵包"),
+        (10631, "白鈦燧發槍"),
+        (3482, "鐵匠工作褲"),
+        (1822, "牡羊角漁槍"),
+        (17318, "空鼠強襲半指護手"),
+        (37369, "白鋼儲物櫃"),
+        (10965, "靈銀禦敵項鍊"),
+        (26187, "三重石詠咒指環"),
+        (24860, "藍色鈴蘭頭花"),
+        (34297, "棕櫚精准手環"),
+        (7506, "蛟蜥治愈手套"),
+        (40380, "迷你巴爾巴莉希婭"),
+        (6612, "綠洲餐桌"),
+        (18058, "迦迦納怪鳥革制敵騎兵褲"),
+        (26322, "矮人棉詠咒闊邊帽"),
+        (13896, "龍神禦敵鎧靴（複製品）"),
+        (26499, "中庸強攻手鐲"),
+        (48577, "面向兔兔族的翠綠隔離牆"),
+        (28984, "最佳演奏者的小號"),
+        (10586, "靈銀鬥劍"),
+        (15535, "光銀手鋸"),
+        (42151, "獰豹革魔導書"),
+        (3026, "青銅胸甲"),
+        (15721, "超級喇叭部件"),
+        (34264, "山雞革強襲短靴"),
+        (27232, "金屬制的手甲"),
+        (4643, "羚羊肉排"),
+        (2582, "鍍金紫檀釣竿"),
+        (43222, "鈷鎢橫頭錘"),
+        (19565, "玉鋼研鉢"),
+        (12223, "蒼天石原石"),
+        (18357, "翠銀量天儀"),
+        (10982, "瑪瑙禦敵戒指"),
+        (8369, "旋風神詐"),
+        (9302, "工匠涼鞋"),
+        (27140, "矮人銀手鋸"),
+        (30070, "神龍夢幻戰斧"),
+        (36706, "條約碎石錘"),
+        (5506, "黑膠"),
+        (4478, "海藍石戒指"),
+        (3597, "巨蟾蜍革護臂"),
+        (34080, "輝翠銀劍"),
+        (48273, "統一規格的潤滑油"),
+        (31859, "總督游擊長靴"),
+        (17316, "空鼠強襲面具"),
+        (5169, "日長石"),
+        (4693, "蒜炒草菇"),
+        (13855, "亞拉戈高位制敵手鎧（複製品）"),
+        (34550, "錳鋼貝希摩斯王戰盔"),
+        (19548, "玉鋼手鋸"),
+        (17953, "寶石獸睡床"),
+        (34555, "錳鋼貝希摩斯女王戰盔"),
+        (26014, "野蠻盜龍革強攻項鍊"),
+        (21869, "古典枝形吊燈"),
+        (14729, "荒神布精准袖套"),
+        (22919, "黑柳巧匠戒指"),
+        (4670, "貓魅風味海產烤串"),
+        (28755, "重建用的肥皂"),
+        (31850, "總督精准上裝"),
+        (13925, "龍神遊擊長褲（複製品）"),
+        (9032, "白金禦敵手鐲"),
+        (40621, "監視者之館地板"),
+        (27163, "斯劍虎革巧匠手套"),
+        (9059, "白金精准耳墜"),
+        (10468, "巨膽刀鎖"),
+        (4587, "睡眠劇毒藥"),
+        (13875, "亞拉戈高位詠咒手套（複製品）"),
+        (2226, "青銅重盾"),
+        (24264, "3級智力之藥湯"),
+        (28834, "空賊治愈長衣（複製品）"),
+        (17464, "呢絨七分褲"),
+        (30813, "月讀命幽夜大斧"),
+        (19553, "瞪羚角縫針"),
+        (23838, "羅剎弓士手飾"),
+        (1810, "野蠻巨斧"),
+        (10350, "羅波那風扇燈"),
+        (25952, "靈青長弓"),
+        (4211, "獸角耳墜"),
+        (44059, "錘頭鰐革"),
+        (3208, "古典長袍"),
+        (6477, "海島棕櫚樹"),
+        (5478, "秘銀附魔墨水"),
+        (24573, "崖畔亭蒸餾酒必需品的部件"),
+        (5059, "鈷鐵錠"),
+        (24508, "園藝工的脫水香草"),
+        (31757, "最終改良用鯊油"),
+        (27083, "深金蒸餾器"),
+        (20689, "木制東洋小屋外牆"),
+        (16600, "羊絨鬥篷"),
+        (2076, "熏銀咒杖"),
+        (12596, "毛蟲絲"),
+        (18979, "真獅鷲革魔導書"),
+        (31940, "第四期重建用的石材"),
+        (25792, "仙果木長弓"),
+        (2437, "革柄白鋼平斬"),
+        (28876, "空鼠詠咒馬褲（複製品）"),
+        (11009, "蛋白石強攻戒指"),
+        (29460, "伊修加德新型詠咒手套"),
+        (43897, "深綠果汁"),
+        (7571, "新薯煎餅"),
+        (14174, "3級智力煉金溶劑"),
+        (22582, "獺獺行燈"),
+        (2796, "秘銀頭冠（琥珀）"),
+        (4653, "煎跳蜥蛋"),
+        (4558, "特級以太藥"),
+        (41829, "雙台面桌子"),
+        (28848, "空鼠制敵面具（複製品）"),
+        (38847, "出品菜餚的必需品"),
+        (41855, "奧闊鉻鐵雙牙"),
+        (13045, "煉真大師手套"),
+        (46069, "儀仗精准耳墜"),
+        (42925, "舊日王國詠咒護膝褲"),
+        (17615, "生命之典"),
+        (30835, "朱焰錫杖"),
+        (10677, "靈銀禦敵鎖子鎧"),
+        (42913, "舊日王國游擊上衣"),
+        (19648, "魔象革大地短外套"),
+        (15464, "新世界臂環"),
+        (2735, "白銀頭冠（石榴石）"),
+        (18415, "星尖石精准手鐲"),
+        (5065, "秘銀錠"),
+        (39877, "淺醃球形甘藍"),
+        (14727, "荒神布精准狩獵帽"),
+        (18729, "鈀金詠咒手環"),
+        (8097, "甲板元件"),
+        (35085, "古典風格禦敵手環"),
+        (44251, "科學家單眼鏡"),
+        (4645, "盜龍熏肉"),
+        (36053, "巨人魚子泥沙拉"),
+        (30133, "黃色風信子頭花"),
+        (4439, "白銀戒指"),
+        (36687, "條約圓手鋸"),
+        (18570, "石青強攻手鐲"),
+        (36715, "條約大地手套"),
+        (10793, "安菲瑟龍革強襲長靴"),
+        (10857, "安菲瑟龍革游擊手套"),
+        (31817, "總督長弓"),
+        (48568, "宇宙探索用的魔匠藥"),
+        (25969, "野蠻盜龍革禦敵馬褲"),
+        (9064, "奇蜥治愈手套"),
+        (19995, "猛虎革"),
+        (15566, "驚雷咆哮"),
+        (29818, "第二期重建用的口罩"),
+        (34089, "馬栗木咒杖"),
+        (36892, "雪峰混合制煙囪"),
+        (30844, "青君手甲劍"),
+        (31863, "總督治愈手套"),
+        (43256, "相思木砂輪機"),
+        (46046, "儀仗強襲尖頭靴"),
+        (43629, "球粒隕石魔導雙牙"),
+        (5366, "雪松木材"),
+        (17317, "空鼠強襲格鬥服"),
+        (36895, "雪峰木制遮蓬"),
+        (16626, "獅鷲革護袖"),
+        (6673, "海濱地毯"),
+        (18368, "猛虎革制敵外套"),
+        (2488, "重鋼研鉢"),
+        (18658, "鉬鋼指虎"),
+        (13816, "亞拉戈強襲手鎧（複製品）"),
+        (30981, "收藏用白橡木游擊槍"),
+        (5062, "紅銅錠"),
+        (21843, "艾歐澤亞燈籠"),
+        (2805, "亞麻大地無檐帽"),
+        (25656, "斯劍虎革制敵護腿"),
+        (36900, "雪峰標旗"),
+        (36615, "圓桌之騎士武具"),
+        (48550, "長期保存用的調料"),
+        (20471, "道士袍服"),
+        (6642, "妖精花瓶"),
+        (3241, "民兵胸甲"),
+        (4686, "酸泡菜"),
+        (28749, "重建用的桌台"),
+        (48434, "宇宙銠金長桿"),
+        (43613, "紅色向日葵頭花"),
+        (2549, "新來者手斧"),
+        (35637, "收藏用輝翠銀鳶盾"),
+        (3310, "鞣革及膝褲"),
+        (39414, "簡易水槽"),
+        (9723, "一箱蔬菜"),
+        (18572, "石青治愈手鐲"),
+        (21915, "迷你瑪格奈"),
+        (27656, "改良型秘銀騎兵頭盔"),
+        (48363, "宇宙球粒隕石齒輪"),
+        (5284, "毒蜥蜴革"),
+        (35641, "收藏用輝翠銀蛇石針"),
+        (38911, "研究者巧匠護目鏡"),
+        (7149, "陸行鳥地板"),
+        (27738, "斯劍虎革"),
+        (16696, "柚木治愈手鐲"),
+        (31949, "第四期重建用的工作服"),
+        (19539, "卸鐵銼刀"),
+        (35652, "收藏用阿爾馬斯提手套"),
+        (5089, "秘銀環"),
+        (31837, "總督制敵頭冠"),
+        (14048, "軟糊怪落地燈"),
+        (48190, "聖輝飛翼"),
+        (40757, "青龍雕像"),
+        (43224, "鈷鎢工藝錘"),
+        (17331, "空鼠治愈面具"),
+        (37754, "復興天星盤"),
+        (35999, "混色紫燈花耳環"),
+        (35400, "球粒隕石研鉢"),
+        (10404, "鈷鐵大劍"),
+        (23776, "羅剎錫杖"),
+        (44206, "收藏用白黃金項環"),
+        (19653, "玉綢背帶襯衫"),
+        (18355, "猛虎革魔導書"),
+        (28758, "重建用的火爐"),
+        (4607, "智力之秘藥"),
+        (4660, "鹽烤公主鱒"),
+        (22734, "蒸包子用品"),
+        (28198, "蜂箱夜總會特產"),
+        (37742, "復興長劍"),
+        (22426, "3級意力之靈水"),
+        (24322, "邪龍怨影大斧"),
+        (31173, "試射用的靶子"),
+        (2677, "狩獵帽"),
+        (44181, "臍橙餅乾"),
+        (6676, "林間地毯"),
+        (14183, "3級空白管弦樂琴樂譜"),
+        (18950, "琅玕強攻耳墜"),
+        (37752, "復興魔導書"),
+        (48467, "基地沙發"),
+        (39785, "面向收藏家的喝水碧企鵝"),
+        (34405, "鉍金制敵頭甲"),
+        (46080, "儀仗治愈手鐲"),
+        (22739, "供品"),
+        (31029, "收藏用鈦銅頭甲"),
+        (31193, "第三期重建用的鐵釘"),
+        (4668, "水煮鯛魚"),
+        (3740, "鞣革鴨嘴靴"),
+        (6595, "盆栽龍舌蘭"),
+        (10731, "安菲瑟龍革制敵護臂"),
+        (36318, "輝煌工具的第一次加工部件：烹調"),
+        (7985, "陸行鳥立柱"),
+        (34113, "黑麻強襲短裙"),
+        (3858, "秘銀長筒靴"),
+        (48466, "基地工作背包"),
+        (27134, "鈦銅平斬"),
+        (42031, "錘頭鰐革精准長靴"),
+        (3623, "秘銀臂甲"),
+        (20700, "東洋拱窗"),
+        (34747, "星點石英精准腕帶"),
+        (48337, "改良設備用的火爐"),
+        (27677, "改良型呢絨僧袍"),
+        (43911, "可可果肉果汁"),
+        (42321, "鈦金禦敵釘刺戰甲"),
+        (6636, "海濱台燈"),
+        (18094, "鋰輝石治愈耳墜"),
+        (3805, "榆木木鞋"),
+        (12855, "鹽烤香魚"),
+        (1769, "白鋼刺柄雙刃斧"),
+        (12051, "小小阿托莫斯"),
+        (41815, "奇跡之劍展示台"),
+        (6624, "林間抽屜桌"),
+        (48565, "長期保存用的飲料"),
+        (3847, "白鋼戰靴"),
+        (41057, "澤羅姆斯裝甲"),
+        (18198, "白銅玉杖"),
+        (24593, "雨靴"),
+        (35992, "藍色紫燈花耳環"),
+        (9286, "伴娘禮裙"),
+        (6589, "仙人掌摯友"),
+        (19929, "榧木木材"),
+        (42315, "鈦金奪命鐮"),
+        (1711, "秘銀爪"),
+        (44880, "拼綴皮革沙發"),
+        (9193, "白鋼小刀"),
+        (3861, "秘銀戰靴"),
+        (27111, "靈青手鉗"),
+        (18533, "玉鋼強襲脛甲"),
+        (38595, "北洋清洗台"),
+        (20683, "木制東洋公館房頂"),
+        (15474, "幸存者七分褲"),
+        (10703, "奇美纖維禦敵馬褲"),
+        (17462, "呢絨針織帽"),
+        (14242, "管弦樂琴樂譜：漆黑的誓言"),
+        (13825, "亞拉戈治愈束腰衣（複製品）"),
+        (10714, "白鈦制敵頭盔"),
+        (36321, "輝煌工具的第二次加工部件：鑄甲"),
+        (15596, "偃武天星盤"),
+        (10675, "精金禦敵頭冠"),
+        (44652, "冬季寬松直筒褲"),
+        (35844, "黃金香料"),
+        (33658, "前沿短靴"),
+        (27143, "矮人銀工藝錘"),
+        (10407, "征服大劍"),
+        (14051, "軟糊怪圓桌"),
+        (18221, "牛鬼革強襲高筒靴"),
+        (13830, "亞拉戈詠咒束腰衣（複製品）"),
+        (46058, "儀仗治愈束腰衣"),
+        (26504, "中庸強攻指環"),
+        (13909, "龍神精准臂甲（複製品）"),
+        (10986, "獅鷲利爪禦敵戒指"),
+        (2673, "青銅頭盔"),
+        (35442, "迷彩絨大地及膝褲"),
+        (34273, "山雞革游擊騎兵褲"),
+        (26021, "野蠻盜龍革治愈手鐲"),
+        (35998, "黑色紫燈花耳環"),
+        (19045, "鈀金治愈指環"),
+        (29402, "紅寶石神兵裝甲"),
+        (10997, "蛋白石強攻手鐲"),
+        (16680, "星絨詠咒打底褲"),
+        (8815, "大型門墊"),
+        (44095, "鯰魚派"),
+        (19889, "智力之藥湯"),
+        (10758, "奇美纖維強襲無檐帽"),
+        (16624, "混色三色堇頭花"),
+        (16634, "重合金重炮"),
+        (35433, "金毗羅鰐革大地筒靴"),
+        (6455, "海濱內牆"),
+        (2048, "巨膽牧杖"),
+        (1823, "犬牙漁槍"),
+        (11036, "治愈虹布緞帶"),
+        (12614, "1級耐力煉金溶劑"),
+        (44222, "收藏用巧力之寶水"),
+        (42347, "犎牛嗶嘰治愈半指手套"),
+        (8036, "強化駿鷹革細繩"),
+        (6352, "石制海濱公館房頂"),
+        (48622, "發放用的湯鍋燉菜的素材"),
+        (34407, "賽加羚羊革制敵護臂"),
+        (44434, "探求永恆巧匠護臂"),
+        (48406, "改良設備用的散熱裝置"),
+        (6449, "林間石制圍牆"),
+        (6682, "綿羊地毯"),
+        (42371, "白黃金強攻指環"),
+        (46026, "儀仗圓月輪"),
+        (35426, "金毗羅鰐革巧匠手套"),
+        (8816, "大型花壇"),
+        (42326, "鈦金制敵釘刺戰甲"),
+        (39647, "繼業者飛翼"),
+        (6635, "黃銅燭台"),
+        (36237, "輝翠銀附魔墨水"),
+        (10760, "獅鷲革強襲三角帽"),
+        (44196, "收藏用卡扎納爾圓革刀"),
+        (39724, "8級耐力之幻水"),
+        (26462, "中庸強襲涼鞋"),
+        (8122, "輕量化元件"),
+        (6458, "海濱地板"),
+        (42485, "卡岡圖亞革強襲短衣"),
+        (2854, "呢絨獵鹿帽"),
+        (2425, "秘銀裁皮刀"),
+        (31856, "總督游擊上裝"),
+        (18957, "琅玕治愈項環"),
+        (34385, "鉍金小刀"),
+        (26126, "鈦銅禦敵頭甲"),
+        (18075, "赤麻游擊護臂"),
+        (10637, "祝聖栗木幻杖"),
+        (2004, "紫杉根杖"),
+        (2876, "綠金頭冠（琥珀）"),
+        (9080, "白金詠咒圍巾"),
+        (19023, "真獅鷲革詠咒手套"),
+        (2144, "楓木巫書"),
+        (13917, "龍神詠咒闊邊帽（複製品）"),
+        (34114, "伽迦象革強襲皮靴"),
+        (35586, "小型古董時鐘塔"),
+        (30980, "收藏用櫸木紡車"),
+        (19005, "真獅鷲革精准手套"),
+        (31834, "總督禦敵騎兵褲"),
+        (28851, "空鼠制敵馬褲（複製品）"),
+        (48444, "耐用羊皮紙"),
+        (33608, "白帝文書"),
+        (38552, "夢羽妖靈王魔導典"),
+        (42055, "河岸石治愈項環"),
+        (10933, "奇美纖維詠咒長袍"),
+        (31894, "4級巧力之幻藥"),
+        (2928, "礦工頭燈帽"),
+        (33610, "白帝刀"),
+        (25828, "綠飄龍革精准筒靴"),
+        (14738, "荒神布治愈棉甲"),
+        (37322, "豪勇苦無"),
+        (3142, "獸骨鱗鎧"),
+        (6594, "素陶壺"),
+        (2106, "獸骨長杖"),
+        (10768, "古鳥革強襲手套"),
+        (31751, "最終改良用銀制齒輪"),
+        (14149, "荒神鋼錠"),
+        (13913, "龍神治愈長袍（複製品）"),
+        (20746, "紅珊瑚擺件"),
+        (20692, "木制東洋公館外牆"),
+        (27137, "鈦銅廚刀"),
+        (10746, "獅鷲革制敵長裙"),
+        (18507, "玉鋼巨劍"),
+        (43907, "替換用的中軸"),
+        (48421, "改良設備用的機械臂"),
+        (19835, "魚汁"),
+        (41856, "五加木斜角筆"),
+        (34242, "白鑞量天儀"),
+        (30483, "炮銅板"),
+        (31934, "第四期重建用的掃把"),
+        (28962, "木頭吊燈"),
+        (2617, "下沈誘餌魚"),
+        (19891, "1級剛力之靈水"),
+        (11886, "皇金雕金錘"),
+        (25998, "野蠻盜龍革治愈護臂"),
+        (38568, "混色香豌豆花項鍊"),
+        (3486, "衣匠軟甲褲"),
+        (33259, "雜貨店推車櫃台"),
+        (38622, "竹制籬笆牆"),
+        (48468, "改良設備用的流蘇地毯"),
+        (37320, "豪勇長矛"),
+        (2997, "緊身小背心"),
+        (44156, "2級意力之寶水"),
+        (3764, "鞣革涼鞋"),
+        (38564, "橙色香豌豆花項鍊"),
+        (8106, "左翼元件"),
+        (10670, "靈銀禦敵角盔"),
+        (10668, "精金步兵盾"),
+        (44079, "醬汁蘸蝦"),
+        (47473, "整備所需的布匹"),
+        (31901, "煙薰雞肉"),
+        (17466, "法蘭絨針織帽"),
+        (8367, "旋風神喙"),
+        (34736, "星點石英強攻耳墜"),
+        (26532, "純鈦板"),
+        (5517, "羊毛脂"),
+        (27667, "改良型山羊革狩獵靴"),
+        (15930, "亞麻幸存者七分褲"),
+        (38875, "修理冷凍器的部件"),
+        (12086, "山嶽午茶組合"),
+        (31938, "第四期重建用的鋸子"),
+        (8103, "儀表元件"),
+        (25997, "仙子棉治愈長袍"),
+        (23790, "羅剎槍士面飾"),
+        (8129, "長風船體元件"),
+        (5188, "鑽石"),
+        (2211, "神言集"),
+        (2996, "貼身背心"),
+        (15607, "圓桌之咒杖"),
+        (20687, "混合制東洋別墅房頂"),
+        (17904, "高精度眼鏡"),
+        (30379, "複製地板"),
+        (26449, "中庸禦敵鎖甲騎兵褲"),
+        (1845, "白鋼長槍"),
+        (25635, "深金左輪槍"),
+        (3185, "纖維長袍"),
+        (39699, "繼業者詠咒手環"),
+        (3014, "業餘無領襯衫"),
+        (3363, "棉絨打底褲"),
+        (9616, "2級速度飼料"),
+        (31230, "第三期重建用的特供鋪瓦地板"),
+        (27117, "靈青碎石錘"),
+        (4213, "白銀耳環"),
+        (48586, "統一規格的粘合劑"),
+        (33281, "哲學者圓桌"),
+        (23168, "上級以太藥"),
+        (10931, "青麻詠咒長袍"),
+        (41110, "牆面收藏櫃"),
+        (35322, "輝翠銀圓頭錘"),
+        (2895, "顯貴無檐帽"),
+        (27181, "力山羊巧匠包頭巾"),
+        (32936, "白夜結晶板"),
+        (31068, "收藏用猛虎革魔導書"),
+        (3467, "纖維束膝褲"),
+        (2514, "革柄白鋼廚刀"),
+        (12092, "海濱午餐組合"),
+        (9085, "烏茲長刃斧"),
+        (24524, "蜂蜜罐"),
+        (13011, "煉真大師蒸餾器"),
+        (43249, "鈦金爆彈怪煎鍋"),
+        (43293, "銀狼革長靴"),
+        (10925, "祝聖栗木詠咒假面"),
+        (8705, "粗框眼鏡"),
+        (18081, "白銅治愈臂環"),
+        (2289, "古典圓盾"),
+        (4454, "獸角戒指"),
+        (2341, "青銅橫頭錘"),
+        (40652, "熱帶風情隔離牆"),
+        (4579, "損血猛毒藥"),
+        (21088, "蒸蛋羹"),
+        (6517, "冥鬼座椅"),
+        (2228, "圓盾"),
+        (4389, "星極項環"),
+        (44164, "2級耐力之寶藥"),
+        (3365, "棉絨軟甲褲"),
+        (26444, "深淵輪刃"),
+        (18972, "鉻鐵長槍"),
+        (15739, "雕像樣本"),
+        (35646, "收藏用伽迦象革皮鞋"),
+        (27770, "1級智力之幻水"),
+        (43972, "梅斯卡料酒"),
+        (23373, "風尚禮帽"),
+        (33892, "再品嫉賢妒能之苦"),
+        (26170, "三重石精准耳墜"),
+        (42470, "卡扎納爾飛翼"),
+        (41901, "銀狼革治愈項鍊"),
+        (14723, "荒神革強襲護甲"),
+        (10749, "白鈦制敵鎧靴"),
+        (6559, "部隊儲物櫃"),
+        (18959, "琅玕禦敵腕環"),
+        (18098, "鋰輝石精准項環"),
+        (39381, "熱帶風情沙發"),
+        (8820, "林間路燈"),
+        (19022, "紵布詠咒長袍"),
+        (11894, "獅鷲縫針"),
+        (42883, "舊日王國武士刀"),
+        (11941, "精金研鉢"),
+        (30082, "神龍夢幻細劍"),
+        (28591, "叛逆筒靴"),
+        (38566, "白色香豌豆花項鍊"),
+        (18202, "硬鋼打刀"),
+        (15523, "碳纖布巧匠馬褲"),
+        (8561, "弦月拖鞋"),
+        (29797, "第二期重建用的草繩"),
+        (27708, "深金錠"),
+        (3540, "青銅連指手鎧"),
+        (35380, "錳鋼廚刀"),
+        (2413, "寶石砂輪機"),
+        (34725, "迷彩絨治愈軍帽"),
+        (13863, "亞拉戈高位精准口罩（複製品）"),
+        (26118, "纏尾蛟革魔導書"),
+        (44986, "皓光魔導書"),
+        (19901, "白銅附魔墨水"),
+        (10689, "重精金禦敵手鎧"),
+        (18045, "白銅天星盤"),
+        (2610, "陸行鳥擬餌"),
+        (10676, "精金禦敵開面盔"),
+        (27158, "矮人銀廚刀"),
+        (9303, "徵糧工作服"),
+        (30755, "南洋海濱襯衫"),
+        (27661, "改良型鈷鐵胸甲"),
+        (11919, "靈銀銼刀"),
+        (3715, "樵夫手套"),
+        (41511, "黃色魯冰花頭花"),
+        (27081, "深金裁皮刀"),
+        (3317, "草布打底褲"),
+        (3666, "野豬革水華手箍"),
+        (18683, "瞪羚革制敵露趾長靴"),
+        (25801, "深金打刀"),
+        (38901, "研究者羊角錘"),
+        (33900, "再陷身敗名裂之懼"),
+        (44650, "冬季護耳帽"),
+        (18502, "玉鋼拳劍"),
+        (36037, "裸麥酸湯"),
+        (42929, "舊日王國精准耳墜"),
+        (10600, "白鈦大斧"),
+        (2510, "黑鐵廚刀"),
+        (7027, "纖維襯料"),
+        (41843, "銀狼革魔導書"),
+        (19533, "卸鐵蒸餾器"),
+        (48442, "宇宙探索用的皮包"),
+        (41816, "廚房櫥櫃"),
+        (20737, "東洋烹飪台"),
+        (43299, "薄絹大地狩獵帽"),
+        (3010, "草布短袍"),
+        (48473, "據點用的帳篷部件"),
+        (13866, "亞拉戈高位精准馬褲（複製品）"),
+        (9511, "高質的木柴"),
+        (18722, "鈀金精准項環"),
+        (10787, "安菲瑟龍革強襲襯褲"),
+        (2376, "鈷鐵鑄甲錘"),
+        (17568, "群雲布"),
+        (40626, "園藝蠟燭擺件"),
+        (12114, "看板"),
+        (48282, "宇宙探索用的紡車"),
+        (14842, "藍色古典玫瑰頭花"),
+        (18099, "鋰輝石治愈項環"),
+        (17325, "空鼠精准黑鐵筒靴"),
+        (4465, "秘銀戒指"),
+        (15475, "幸存者矮靴"),
+        (18207, "硬鋼禦敵手鎧"),
+        (1960, "綠葉楓木幻杖"),
+        (22435, "犏牛肉排"),
+        (26318, "海燕革治愈手套"),
+        (3118, "白鋼鱗鎧"),
+        (8790, "石砌壁爐"),
+        (9066, "奇蜥治愈法靴"),
+        (35357, "紅松木紡車"),
+        (12524, "白鈦塊"),
+        (20708, "東洋朱漆遮蓬"),
+        (17971, "畫架"),
+        (30833, "朱焰大太刀"),
+        (10976, "靈銀禦敵耳墜"),
+        (18566, "石青精准項環"),
+        (25838, "綠飄龍革治愈手套"),
+        (8108, "左翼可動元件"),
+        (6618, "林間書桌"),
+        (42050, "河岸石治愈耳墜"),
+        (29504, "鯡魚派"),
+        (27648, "呢絨頭巾"),
+        (10883, "祝聖栗木治愈假面"),
+        (9036, "烏茲面罩"),
+        (29496, "3級意力之幻藥"),
+        (4528, "玫瑰金戒指"),
+        (18561, "石青精准耳墜"),
+        (30830, "朱焰長槍"),
+        (3431, "秘銀重足鎧"),
+        (19818, "燉豬肉"),
+        (30083, "神龍夢幻槍刃"),
+        (41514, "紫色魯冰花頭花"),
+        (25968, "靈青禦敵手鎧"),
+        (34707, "球粒隕石制敵手套"),
+        (39683, "繼業者詠咒打底褲"),
+        (2658, "草帽"),
+        (34107, "輝翠銀制敵護臂"),
+        (6183, "砂礫小像兵"),
+        (18987, "鉻鐵禦敵手鎧"),
+        (27125, "鈦銅蒸餾器"),
+        (2819, "秘銀口罩"),
+        (35435, "迷彩絨巧匠工作衫"),
+        (48254, "月面礦物的合金塊"),
+        (34721, "蛇牛革游擊上裝"),
+        (19825, "澆汁菜蛙"),
+        (12547, "黃水晶"),
+        (21695, "夜鋼長劍"),
+        (10870, "奇美纖維游擊騎兵褲"),
+        (22735, "茶會必需品"),
+        (39645, "繼業者槍刃"),
+        (9071, "白金治愈戒指"),
+        (18668, "瞪羚革魔導典"),
+        (18101, "鋰輝石禦敵手鐲"),
+        (19945, "玉鋼錠"),
+        (31823, "總督魔導書"),
+        (5079, "秘銀板"),
+        (6384, "混合制林間小屋外牆"),
+        (36076, "扇貝咖喱"),
+        (33253, "鑽石神兵像"),
+        (9089, "黑檀牧杖"),
+        (2107, "牡羊角長杖"),
+        (42017, "河岸石制敵頭冠"),
+        (15533, "加隆德巧匠指環"),
+        (12913, "加隆德鋼"),
+        (8375, "厚土長槍"),
+        (23145, "庫爾札斯防寒用品"),
+        (10684, "靈銀禦敵手鎧"),
+        (32212, "鐵鍋番茄燉蛋"),
+        (21841, "1級金屬水族箱"),
+        (18389, "猛虎革游擊高筒靴"),
+        (7995, "庭院水塘"),
+        (2743, "白銀頭冠（紫水晶）"),
+        (36697, "條約釣竿"),
+        (9256, "亂波裝備的改良材料"),
+        (2523, "黑鐵鶴嘴鋤"),
+        (47469, "宇宙探索用的蘑菇棉布"),
+        (27212, "魔銀耳夾"),
+        (8806, "莊園沙發"),
+        (42469, "卡扎納爾戰鐮"),
+        (30994, "收藏用玉鋼拳劍"),
+        (6509, "千獄看守草"),
+        (19726, "阿拉米格大地皮鞋"),
+        (2912, "民兵戰盔"),
+        (48409, "基地望遠鏡"),
+        (48527, "宇宙飛船用的大塊布"),
+        (35086, "古典風格強攻手環"),
+        (35353, "鉍金銼刀"),
+        (34441, "弗里金強攻項環"),
+        (2246, "蛇紋圓盾"),
+        (46020, "儀仗大劍"),
+        (48382, "基地備用的室外照明"),
+        (27791, "2級剛力之幻水"),
+        (24004, "私語呢絨大衣"),
+        (5088, "白銀環"),
+        (18966, "琅玕精准指環"),
+        (10649, "巨龍革魔導書"),
+        (43243, "鈦金橫頭錘"),
+        (2350, "革柄白鋼鳥嘴錘"),
+        (35419, "雪亞麻布大地包頭巾"),
+        (42196, "獰豹革詠咒頭帶"),
+        (19994, "牛鬼革"),
+        (13900, "龍神制敵馬褲（複製品）"),
+        (4322, "賽黃晶項環"),
+        (6542, "海濱睡床"),
+        (36868, "混合制雪峰公館房頂"),
+        (8785, "水晶塔獎杯"),
+        (26010, "白梣木精准耳墜"),
+        (6519, "莊園凳"),
+        (27875, "黃油煎迷霧菠菜"),
+        (10651, "貴族之書"),
+        (3483, "甲匠工作褲"),
+        (21289, "管弦樂琴樂譜：神龍見首"),
+        (4750, "香草茶"),
+        (1684, "青銅指虎"),
+        (14725, "荒神布強襲襯褲"),
+        (27150, "愈瘡木釣竿"),
+        (10588, "白鈦手半劍"),
+        (15743, "晚餐所需品"),
+        (13078, "紅木隔板門"),
+        (42902, "舊日王國強襲兜帽"),
+        (13823, "亞拉戈精准鎖甲靴（複製品）"),
+        (34252, "輝翠銀禦敵手鎧"),
+        (7607, "鎢鋼錠"),
+        (28822, "空賊強襲長靴（複製品）"),
+        (4867, "雞高湯"),
+        (36668, "輔助學習用品"),
+        (6679, "綠洲地毯"),
+        (17898, "殿前比試用總面"),
+        (18576, "石青精准指環"),
+        (3426, "野豬革及膝褲"),
+        (48508, "基地軟墊"),
+        (26339, "透鋰長石強攻手鐲"),
+        (19575, "鉬鋼蒸餾器"),
+        (8382, "賢王莫古劍"),
+        (10984, "煙晶禦敵戒指"),
+        (34131, "黑麻詠咒長衣"),
+        (20390, "靈銀太刀"),
+        (27128, "鈦銅手斧"),
+        (18347, "卸鐵戰斧"),
+        (19621, "迦迦納怪鳥革流蘇靴"),
+        (27154, "愈瘡木砂輪機"),
+        (48353, "防鏽金屬"),
+        (6396, "混合制綠洲公館外牆"),
+        (13873, "亞拉戈高位詠咒口罩（複製品）"),
+        (4850, "蜂蜜"),
+        (34602, "藍鋯石精准指環"),
+        (34090, "伽迦象革魔導書"),
+        (35781, "石金注射管"),
+        (41896, "五加木治愈耳墜"),
+        (48499, "改良設備用的布"),
+        (26477, "中庸治愈長衣"),
+        (46053, "儀仗游擊坎肩"),
+        (19817, "牛肉串"),
+        (25661, "白麻強襲打底褲"),
+        (33266, "立式招牌：酒館"),
+        (35057, "古典風格弓鬥士腕環"),
+        (12566, "安菲瑟龍革"),
+        (20359, "獄火刺劍"),
+        (10877, "安菲瑟龍革游擊長靴"),
+        (17928, "特制姆恩·圖伊豆汁"),
+        (42320, "鈦金禦敵頭盔"),
+        (21937, "冒險者毛衣"),
+        (31231, "第三期重建用的特供套餐"),
+        (10748, "飛龍革制敵長靴"),
+        (37293, "紅色比爾格紫羅蘭項環"),
+        (27991, "冒險者兜帽坎肩"),
+        (46032, "儀仗禦敵護耳帽"),
+        (14745, "荒神布詠咒長裙"),
+        (32937, "梔子染布"),
+        (3771, "硬革皮靴"),
+        (2589, "蝦虎丸子"),
+        (21730, "真麻精准外套"),
+        (17894, "騎士板金鎧"),
+        (30849, "青君蛇腹劍"),
+        (13924, "龍神遊擊手套（複製品）"),
+        (26121, "鈦銅打刀"),
+        (3701, "民兵連指手鎧"),
+        (30393, "木桶桌"),
+        (42188, "獰豹革游擊護臂"),
+        (22556, "綠洲隔板門"),
+        (13050, "烹飪大師手套"),
+        (48403, "統一規格的鎖鏈"),
+        (10724, "精金制敵鏈甲"),
+        (13047, "煉真大師長筒靴"),
+        (25795, "深金明火槍"),
+        (42018, "釕金制敵兜甲"),
+        (10888, "聖虹治愈襯衫"),
+        (35371, "錳鋼手斧"),
+        (42497, "落雷絹游擊軟甲褲"),
+        (15146, "伊修加德早餐組合"),
+        (10715, "白鈦制敵面具"),
+        (13314, "荒神鋼治愈耳墜"),
+        (10593, "靈銀拳鋒"),
+        (16806, "管弦樂琴樂譜：天上世界"),
+        (12854, "蘑菇沙拉"),
+        (22543, "裁衣匠布料筐"),
+        (35346, "弗里金縫針"),
+        (39426, "焦化葉小妖石窯"),
+        (6616, "海濱書桌"),
+        (2750, "棉絨貝雷帽"),
+        (27795, "2級意力之幻水"),
+        (11945, "精金廚刀"),
+        (6374, "木制海濱小屋外牆"),
+        (18349, "松木復合弓"),
+        (6608, "綠洲方桌"),
+        (42014, "釕金禦敵臂甲"),
+        (13650, "空賊制敵長靴"),
+        (2697, "山羊革盔帽"),
+        (42053, "河岸石強攻項環"),
+        (17327, "空鼠游擊格鬥服"),
+        (48609, "超級開心汁"),
+        (11054, "瑪瑙治愈戒指"),
+        (22574, "水母台燈"),
+        (27853, "秧雞烤肉"),
+        (18364, "葛布禦敵長裙"),
+        (36196, "馬栗木木材"),
+        (6441, "林間懸掛門牌"),
+        (39723, "8級巧力之幻水"),
+        (35028, "古典風格牧杖"),
+        (13898, "龍神制敵戰鎧（複製品）"),
+        (31840, "總督制敵騎兵褲"),
+        (34682, "球粒隕石魔導戰斧"),
+        (27746, "白麻線"),
+        (48478, "基地皮革"),
+        (27159, "矮人銀碎石錘"),
+        (2513, "白鋼廚刀"),
+        (42319, "鈦金盾"),
+        (29660, "改良用的種子粉"),
+        (36186, "星點石英"),
+        (16782, "圓形舞台"),
+        (11078, "瑪瑙詠咒戒指"),
+        (19819, "長頸駱肉乾"),
+        (48477, "宇宙飛船用的大塊皮革"),
+        (17638, "管弦樂琴樂譜：災厄狂起"),
+        (4504, "鋯石戒指"),
+        (35458, "金毗羅鰐革大地手環"),
+        (16675, "半熊革露趾長靴"),
+        (4363, "紅珊瑚項鍊"),
+        (48325, "宇宙蒸餾油"),
+        (3574, "黑鐵鱗指套"),
+        (25999, "仙子棉治愈長褲"),
+        (2453, "綠金縫針"),
+        (42503, "卡岡圖亞革治愈皮靴"),
+        (18226, "紅玉棉布垮褲"),
+        (26505, "中庸精准指環"),
+        (5572, "暴怒震斧斧頭"),
+        (10650, "皇金之書"),
+        (30829, "朱焰大斧"),
+        (4656, "碧企鵝蛋捲"),
+        (6521, "對龍城塞指揮椅"),
+        (8132, "長風舵盤元件"),
+        (36878, "木制雪峰別墅外牆"),
+        (21928, "黃色鬱金香腕帶"),
+        (4756, "奶油奶酪"),
+        (10713, "飛龍革制敵盔帽"),
+        (1827, "黑鐵騎槍"),
+        (15567, "驚雷電繞"),
+        (2960, "草布束腰衣"),
+        (2278, "古典步兵盾"),
+        (13318, "荒神鋼詠咒耳墜"),
+        (28825, "空賊精准手套（複製品）"),
+        (30855, "青君天儀"),
+        (29456, "伊修加德新型治愈長靴"),
+        (31067, "收藏用靈水"),
+        (48345, "基地備用的棒狀照明"),
+        (42928, "舊日王國強攻耳墜"),
+        (27710, "魔銀塊"),
+        (25791, "仙果木長槍"),
+        (28170, "道具攤"),
+        (43893, "莫布林族樣式的裝幀部件"),
+        (16659, "閃爍精準頭冠"),
+        (2143, "鞣革魔導書"),
+        (43324, "混金耳夾"),
+        (31830, "總督塔盾"),
+        (3932, "衣匠長靴"),
+        (48523, "宇宙飛線用的纖維的部件"),
+        (48590, "建材用的染劑"),
+        (48370, "統一規格的硬質金屬環"),
+        (42157, "鈷鎢武士刀"),
+        (2443, "紅銅縫針"),
+        (1997, "楓木手杖"),
+        (15609, "圓桌之典"),
+        (42910, "舊日王國精准護膝褲"),
+        (13028, "鏤金大師頭冠"),
+        (42506, "落雷絹詠咒手套"),
+        (39316, "紅色牽牛花頭花"),
+        (2664, "紅銅開面盔"),
+        (4664, "包心甘藍"),
+        (10601, "白鈦戰斧"),
+        (4687, "包心球薊"),
+        (34244, "白鑞刺劍"),
+        (10532, "獄火天球儀"),
+        (25814, "深金制敵手鎧"),
+        (2302, "玄鋼步兵盾"),
+        (4575, "失明毒藥"),
+        (44213, "收藏用卡岡圖亞革軟甲褲"),
+        (44186, "收藏用木棉木長槍"),
+        (8665, "白鋼短刀"),
+        (3457, "盜龍革及膝褲"),
+        (8283, "黃玉耳墜"),
+        (36132, "朱焰騎士武具"),
+        (4324, "孔雀石項環"),
+        (10658, "靈銀天球儀"),
+        (6506, "對龍城塞烽火籠"),
+        (10814, "安菲瑟龍革精准護臂"),
+        (8814, "綠洲門墊"),
+        (15530, "加隆德巧匠項鍊"),
+        (42210, "深紅木詠咒項鍊"),
+        (34442, "弗里金精准項環"),
+        (3791, "山羊革腿甲靴"),
+        (4132, "野豬革大地手環"),
+        (3354, "棉絨條紋工作褲"),
+        (48314, "研究用的防水木板"),
+        (25956, "靈青牧杖"),
+        (42306, "嵌齒象革魔導典"),
+        (19651, "魔象革大地尖頭鞋"),
+        (15512, "碳纖布大地手套"),
+        (27876, "血色五海雜燴湯"),
+        (5099, "秘銀鉚釘"),
+        (23146, "翠水鄉特產"),
+        (28728, "重建用的金屬錠"),
+        (37359, "常春藤幕簾"),
+        (44440, "探求永恆大地腿套"),
+        (26441, "行蘊"),
+        (42904, "舊日王國強襲護臂"),
+        (20474, "道士雲履"),
+        (48460, "改良設備用的樹林地板"),
+        (19820, "芙蓉蟹"),
+        (28196, "舞台服裝必需品"),
+        (2862, "盜龍革盔帽"),
+        (42341, "鈦金游擊釘刺護甲"),
+        (23001, "風尚褶邊連衣裙"),
+        (3203, "鈷鐵鎖子鎧"),
+        (2030, "萌芽紫檀根杖"),
+        (2002, "榆木手杖"),
+        (48276, "室外活動用的木柴"),
+        (48546, "改良設備用的泥漿"),
+        (38244, "法外執行者上衣"),
+        (41892, "銀狼革詠咒戰靴"),
+        (6419, "海濱圓形煙囪"),
+        (41883, "雪木棉治愈皮毛帽"),
+        (43974, "高級楓糖漿"),
+        (37749, "復興重炮"),
+        (26337, "透鋰長石詠咒項環"),
+        (31183, "隱秘型外裝的部件"),
+        (27987, "無瑕靈君裝甲"),
+        (35362, "錳鋼手鋸"),
+        (25833, "虹繭游擊打底褲"),
+        (35465, "蛇牛革大地項鍊"),
+        (45016, "管弦樂琴樂譜：孤獨之路"),
+        (32242, "玩偶展示窗"),
+        (13646, "空賊制敵頭盔"),
+        (36236, "6級意力之幻水"),
+        (26000, "野蠻盜龍革治愈筒靴"),
+        (19745, "魔象革手環"),
+        (15437, "希瓦之形"),
+        (45976, "玫瑰紅紋石"),
+        (12619, "2級耐力煉金溶劑"),
+        (15575, "無盡雲神匕首"),
+        (3411, "內襯亞麻軟甲褲"),
+        (47943, "黃色葉子花耳環"),
+        (31893, "4級剛力之幻藥"),
+        (24333, "邪龍怨影太刀"),
+        (10848, "白鈦游擊鏈甲"),
+        (31198, "第三期重建用的植物油"),
+        (11071, "貴橄欖石詠咒手鐲"),
+        (10852, "虹布游擊半指手套"),
+        (31844, "總督強襲上裝"),
+        (18212, "硬鋼制敵鏈甲"),
+        (48506, "基地毛線團"),
+        (44829, "白色卡特蘭頭花"),
+        (8451, "玫瑰金項環"),
+        (28802, "綠色風鈴頭花"),
+        (13837, "亞拉戈高位戰斧（複製品）"),
+        (15960, "無敵改級飛空艇模型"),
+        (8105, "右翼元件"),
+        (35421, "賽加羚羊革大地手套"),
+        (18960, "琅玕強攻腕環"),
+        (36185, "藍鋯石"),
+        (24511, "木制夾層樓板"),
+        (11080, "煙晶詠咒戒指"),
+        (19609, "阿拉米格鶴嘴鋤"),
+        (14059, "古代野獸的骨骼標本"),
+        (28139, "仿制長方窗"),
+        (9516, "秘制鰻魚派"),
+        (35649, "收藏用金毗羅鰐革皮鞋"),
+        (2097, "翡翠咒杖"),
+        (20734, "東洋書櫃"),
+        (22892, "夜鋼鶴嘴鋤"),
+        (29646, "改良用的鉚釘"),
+        (10892, "奇美纖維治愈長袍"),
+        (12844, "熱巧克力"),
+        (11055, "雪人利齒治愈戒指"),
+        (19846, "棉籽油"),
+        (42179, "薄絹強襲長褲"),
+        (4440, "白銀巧匠戒指"),
+        (3068, "黑鐵重甲"),
+        (27959, "魔匠藥水"),
+        (34284, "山雞革詠咒高筒靴"),
+        (13811, "亞拉戈制敵手鎧（複製品）"),
+        (24506, "貓小胖存錢罐"),
+        (30989, "收藏用白鈦研鉢"),
+        (2093, "古典咒錘"),
+        (13772, "巨龍革矮靴"),
+        (27169, "白麻大地打底褲"),
+        (29414, "伊修加德新型魔導書"),
+        (12022, "星樺木指環"),
+        (43294, "薄絹巧匠工作帽"),
+        (23781, "羅剎刀"),
+        (26281, "矮人銀打刀"),
+        (4308, "硬革項環"),
+        (43305, "犎牛嗶嘰巧匠工作坎肩"),
+        (39692, "繼業者精准項環"),
+        (15528, "加隆德大地指環"),
+        (2926, "煉金單眼鏡"),
+        (21815, "莫古力掛鐘"),
+        (25048, "無限鬼神之書"),
+        (2394, "青銅裝飾錘"),
+        (4158, "鋯石手鐲"),
+        (39871, "茄泥醬"),
+        (3043, "山羊革皮甲"),
+        (26483, "中庸詠咒長衣"),
+        (27942, "夏季靛藍七分褲"),
+        (42301, "相思木戰叉"),
+        (14230, "管弦樂琴樂譜：華彩的山麓"),
+        (3581, "山羊革手箍"),
+        (19830, "長頸駱焗菜"),
+        (43337, "嵌齒象革項鍊"),
+        (8009, "綠洲葉紋內牆"),
+        (37759, "復興振空擺"),
+        (19630, "牛鬼革皮套褲"),
+        (23784, "羅剎豪士面飾"),
+        (44185, "收藏用五加木耳墜"),
+        (44199, "收藏用鈷鎢陸行鳥煎鍋"),
+        (19940, "白銅錠"),
+        (10968, "皇金禦敵項鍊"),
+        (42917, "舊日王國治愈貝雷帽"),
+        (14836, "遠徵長靴"),
+        (34583, "絳紅草布黑獅鷲褲襪"),
+        (9065, "織蛛治愈短褲"),
+        (13800, "亞拉戈斷頭劍（複製品）"),
+        (33272, "伊修加德式午餐組合"),
+        (27105, "靈青仙子豬平底鍋"),
+        (37762, "復興禦敵頭盔"),
+        (47603, "瓦斯中毒解毒劑"),
+        (20267, "白色雛菊頭花"),
+        (26488, "中庸禦敵耳墜"),
+        (25962, "靈青刺劍"),
+        (38863, "攜帶式螢光棒部件"),
+        (11925, "精金手鉗"),
+        (22893, "夜鋼手斧"),
+        (3927, "木匠工作靴"),
+        (4625, "御水妙藥"),
+        (28871, "空鼠治愈馬褲（複製品）"),
+        (8050, "喚靈裝備的改良材料"),
+        (44147, "馬登時效鋼錠"),
+        (7535, "水手服"),
+        (14739, "荒神布治愈袖套"),
+        (43269, "卡扎納爾蒸餾器"),
+        (27108, "白梣木釣竿"),
+        (13870, "亞拉戈高位治愈手套（複製品）"),
+        (23791, "羅剎槍士袈裟"),
+        (35654, "收藏用絳紅草布無檐帽"),
+        (2344, "黑鐵橫頭錘"),
+        (13849, "亞拉戈高位重長衣（複製品）"),
+        (10900, "星絹布治愈禮服手套"),
+        (2729, "棉絨包頭巾"),
+        (6677, "林間流蘇地毯"),
+        (43345, "克拉洛胡桃木大地項鍊"),
+        (7098, "冬貝利頭屏風"),
+        (27745, "白麻布"),
+        (24566, "釀造奶酒的必需品"),
+        (44009, "河岸石"),
+        (3516, "鞣革護腕"),
+        (11908, "白鈦手斧"),
+        (48286, "統一規格的耳環"),
+        (22905, "鬼師帽"),
+        (27692, "沙柚木木材"),
+        (3639, "野豬革耐熱手套"),
+        (6423, "綠洲石制煙囪"),
+        (6139, "以太指環"),
+        (30481, "炸蟹餅"),
+        (17954, "台式管弦樂琴"),
+        (21091, "基拉巴尼亞的特產部件"),
+        (4369, "碧璽項環"),
+        (17932, "特制溶解液"),
+        (16601, "蛟蜥革毛邊靴"),
+        (6386, "木制林間公館外牆"),
+        (2923, "金匠包頭巾"),
+        (28195, "新菜餚必需品"),
+        (18701, "瞪羚革游擊露趾長靴"),
+        (31207, "第三期重建用的紅茶"),
+        (48298, "頂級特殊紙"),
+        (18727, "鈀金精准手環"),
+        (7978, "轉角櫃台"),
+        (39663, "繼業者強襲打底褲"),
+        (6581, "妖精屏風"),
+        (14053, "高貴晚餐組合"),
+        (35416, "賽加羚羊革巧匠手套"),
+        (4722, "新薯泥"),
+        (37330, "豪勇太刀"),
+        (26452, "中庸制敵頭冠"),
+        (12100, "王家會議桌"),
+        (6640, "林間花瓶"),
+        (3050, "棉布短袍"),
+        (17522, "祖爾宛裝甲"),
+        (33839, "管弦樂琴樂譜：孤兒們的戰鬥"),
+        (27668, "白銀單眼鏡"),
+        (27932, "混色繡球頭花"),
+        (37412, "管弦樂琴樂譜：終結之戰"),
+        (18224, "紅玉棉布緊身衣"),
+        (13904, "龍神強襲手套（複製品）"),
+        (25996, "仙子棉治愈兜帽"),
+        (13039, "錦衣大師外套"),
+        (31930, "第四期重建用的手斧"),
+        (10683, "精金禦敵外套"),
+        (10469, "玄鋼雷火炮"),
+        (18383, "猛虎革精准高筒靴"),
+        (41817, "雜亂的椅子"),
+        (38915, "研究者巧匠筒靴"),
+        (9315, "徵糧碎石錘"),
+        (39675, "繼業者治愈貝雷帽"),
+        (5338, "天蠶絲"),
+        (10756, "白鈦強襲面具"),
+        (34141, "紫黃晶強攻項環"),
+        (31203, "第三期重建用的裁縫工具"),
+        (15436, "拉姆之形"),
+        (11909, "硬銀手斧"),
+        (27931, "黑色繡球頭花"),
+        (11871, "靈銀圓手鋸"),
+        (42488, "卡扎納爾強襲脛甲"),
+        (18949, "琅玕禦敵耳墜"),
+        (2734, "棉絨大地無檐帽"),
+        (3711, "衣匠手套"),
+        (42324, "鈦金禦敵護腿"),
+        (10897, "巨龍革治愈手套"),
+        (3332, "棉布軟甲褲"),
+        (2650, "部隊帽"),
+        (34384, "鉍金弓"),
+        (10832, "飛龍革精准長靴"),
+        (36208, "黑麻布"),
+        (26006, "靈青詠咒脛甲"),
+        (4699, "心形卷餅"),
+        (8456, "改良型蛟蜥強襲襯衫"),
+        (2691, "黃銅頭冠（榍石）"),
+        (35094, "古典風格詠咒指環"),
+        (34566, "絳紅草布最後的獨角獸長袍"),
+        (26156, "力山羊治愈毛帽"),
+        (26479, "中庸治愈軟甲褲"),
+        (48431, "宇宙探索用的焊機"),
+        (5085, "玄鋼環"),
+        (5570, "破甲鋒劍身"),
+        (18215, "硬鋼制敵鎖甲靴"),
+        (42153, "鈷鎢屠刀"),
+        (30078, "神龍夢幻經典"),
+        (4373, "尖晶石項環"),
+        (6569, "莊園書櫃"),
+        (42527, "黑星石治愈指環"),
+        (7494, "蛟蜥強襲手套"),
+        (35343, "鉍金圓頭錘"),
+        (2338, "鈷鐵羊角錘"),
+        (22540, "獺獺椅子"),
+        (10987, "貴橄欖石禦敵戒指"),
+        (21706, "清銀魔導典"),
+        (12894, "氂牛奶油"),
+        (18249, "白銅精准耳墜"),
+        (6142, "鹽烤暖水鱒"),
+        (16701, "閃爍治愈指環"),
+        (41885, "銀狼革治愈半指護手"),
+        (33894, "再陷膽戰心驚之懼"),
+        (2343, "青銅鳥嘴錘"),
+        (2426, "秘銀圓革刀"),
+        (43270, "卡扎納爾魔石精平底鍋"),
+        (35448, "馬栗木大地耳墜"),
+        (48372, "宇宙探索用的煎鍋"),
+        (14180, "意力之仙藥"),
+        (6537, "風神沙發"),
+        (31206, "第三期重建用的聖水"),
+        (33291, "古菩掛鐘"),
+        (26307, "矮人棉精准束膝褲"),
+        (39559, "緋光魔導書"),
+        (10645, "皇金長杖"),
+        (34295, "棕櫚禦敵手環"),
+        (48497, "統一規格的軟甲褲"),
+        (25646, "深金禦敵頭盔"),
+        (19016, "紵布治愈長袍"),
+        (34081, "輝翠銀拳套"),
+        (10613, "祝聖栗木長弓"),
+        (1743, "復仇烈拳"),
+        (36080, "棕櫚糖"),
+        (5480, "黃金附魔墨水"),
+        (41992, "釕金戰斧"),
+        (43227, "鈷鎢蒸餾器"),
+        (42922, "舊日王國詠咒兜帽"),
+        (8288, "祖母綠項環"),
+        (6501, "鬥神討伐紀念碑"),
+        (27929, "紫色繡球頭花"),
+        (17970, "東方午餐組合：飯團"),
+        (42348, "嵌齒象革治愈襯褲"),
+        (19899, "2級智力之靈水"),
+        (42333, "嵌齒象革強襲襯褲"),
+        (12615, "1級智力煉金溶劑"),
+        (19898, "2級耐力之靈水"),
+        (18565, "石青強攻項環"),
+        (11914, "樺木釣竿"),
+        (2419, "黃銅裁皮刀"),
+        (14847, "白色古典玫瑰頭花"),
+        (27765, "植物成長劑9型"),
+        (43306, "嵌齒象革巧匠半指手套"),
+        (4588, "麻痹劇毒藥"),
+        (25970, "靈青禦敵鎧靴"),
+        (2790, "秘銀頭冠（紅電氣石）"),
+        (2577, "羚羊角釣竿"),
+        (12707, "葉片釣鈎"),
+        (3930, "金匠工作靴"),
+        (36106, "5級耐力之幻藥"),
+        (10592, "靈銀拳劍"),
+        (28725, "重建用的合板"),
+        (8457, "改良型蛟蜥精准罩衣"),
+        (44882, "新薯片"),
+        (2855, "古典小丑帽"),
+        (48355, "研究用的養殖水槽"),
+        (39632, "繼業者戰斧"),
+        (12521, "硬銀錠"),
+        (26138, "力山羊強襲毛帽"),
+        (2400, "秘銀裝飾錘"),
+        (19046, "鈀金詠咒指環"),
+        (16911, "輕型強心劑"),
+        (18700, "瞪羚革游擊襯褲"),
+        (46977, "火焰噴射器部件"),
+        (18706, "玉綢治愈長褲"),
+        (15820, "管弦樂琴樂譜：混沌的漩渦"),
+        (27637, "硬革長靴"),
+        (2460, "榆木紡車"),
+        (43265, "卡扎納爾圓頭錘"),
+        (19629, "牛鬼革大地手套"),
+        (42476, "卡扎納爾禦敵手鎧"),
+        (34123, "黑麻游擊短裙"),
+        (15593, "偃武長玉杖"),
+        (12865, "燉番茄"),
+        (20808, "綠色光明百合頭花"),
+        (41833, "木制庭院門"),
+        (44879, "園藝夾層樓板"),
+        (23793, "羅剎槍士筒袴"),
+        (4857, "橄欖油"),
+        (13679, "空賊詠咒打底褲"),
+        (9234, "獄火神刺"),
+        (48252, "無人機用的金屬板"),
+        (42453, "卡扎納爾旋棍"),
+        (6567, "林間書櫃"),
+        (10704, "獅鷲革禦敵長裙"),
+        (35329, "輝翠銀手斧"),
+        (46054, "儀仗游擊護臂"),
+        (17336, "空鼠詠咒面具"),
+        (42213, "粉綠柱石精准手鐲"),
+        (40656, "壁掛式鍋架"),
+        (26329, "透鋰長石強攻耳墜"),
+        (10527, "秘銀天星盤"),
+        (41869, "雪木棉強襲短衣"),
+        (8363, "獄火神典"),
+        (14929, "青麻熱褲"),
+        (35355, "紅松木泥岩砂輪機"),
+        (26458, "中庸強襲頭冠"),
+        (5377, "黑檀木材"),
+        (2893, "鈷鐵角盔"),
+        (10686, "白鈦禦敵手鎧"),
+        (10970, "靈銀禦敵手鐲"),
+        (31927, "第四期重建用的植物油"),
+        (43236, "鈷鎢平斬"),
+        (35410, "黑麻旅衣"),
+        (43254, "鈦金銼刀"),
+        (37294, "藍色比爾格紫羅蘭項環"),
+        (9185, "青銅匕首"),
+        (39641, "繼業者魔導典"),
+        (35054, "古典風格拳鬥士戰靴"),
+        (44200, "收藏用鈦金釘刺護甲"),
+        (46077, "儀仗禦敵手鐲"),
+        (11888, "白鈦制革刀"),
+        (2537, "黑鐵碎石錘"),
+        (48383, "改良設備用的壁燈"),
+        (5093, "白鋼鉚釘"),
+        (10799, "祝聖栗木精准假面"),
+        (29442, "伊修加德新型精准手套"),
+        (2011, "紫杉手杖"),
+        (15463, "新世界外套"),
+        (3546, "硬革半指護手"),
+        (3768, "業餘長靴"),
+        (41838, "奧闊鉻鐵戰斧"),
+        (34559, "錳鋼貝希摩斯女王鎧靴"),
+        (43998, "釕金錠"),
+        (31960, "第四期重建用的特供冰糕"),
+        (48264, "試驗加工用的皮革"),
+        (9033, "白金禦敵耳墜"),
+        (48110, "東洋地柱"),
+        (36896, "雪峰石制遮蓬"),
+        (25988, "野蠻盜龍革精准腿甲靴"),
+        (4270, "黑珍珠耳墜"),
+        (40411, "仙人刺寢室拖鞋"),
+        (20710, "東洋木制門牌"),
+        (23803, "羅剎弓士羽織"),
+        (35323, "輝翠銀工藝錘"),
+        (11885, "硬銀工藝錘"),
+        (1751, "青銅雙刃斧"),
+        (10597, "精金指虎"),
+        (10687, "白鈦禦敵臂甲"),
+        (42033, "高山亞麻游擊上裝"),
+        (34408, "賽加羚羊革制敵軟甲褲"),
+        (2388, "秘銀手鉗"),
+        (38903, "研究者手鉗"),
+        (10701, "巨龍革禦敵馬褲"),
+        (14741, "荒神革治愈長靴"),
+        (38925, "研究者大地耳墜"),
+        (25653, "白麻制敵罩衣"),
+        (34749, "星點石英詠咒腕帶"),
+        (9186, "黃銅小刀"),
+        (48452, "急救用的皮袋"),
+        (4623, "御土妙藥"),
+        (3242, "民兵格鬥服"),
+        (40408, "仙人刺寢室眼罩"),
+        (6361, "石制林間公館房頂"),
+        (31059, "收藏用力山羊束腰衣"),
+        (39678, "繼業者治愈打底褲"),
+        (3550, "強化連指手鎧"),
+        (12869, "千層長頸駝燉野菜"),
+        (48619, "宇宙探索用的黃滴糖"),
+        (22910, "山師笠"),
+        (34400, "鉍金禦敵護目鏡"),
+        (4480, "白珍珠戒指"),
+        (34245, "輝翠銀刺刀"),
+        (30131, "紅色風信子頭花"),
+        (1949, "長臂復合弓"),
+        (31076, "收藏用索姆阿爾栗子蛋糕"),
+        (26325, "矮人棉詠咒束膝褲"),
+        (31025, "收藏用鈀金縫針"),
+        (18088, "赤麻詠咒短褲"),
+        (15913, "旋風神心"),
+        (39718, "軟銀錠"),
+        (23218, "白澤的五行眼"),
+        (10332, "炸魚套餐"),
+        (25951, "靈青長槍"),
+        (39669, "繼業者精准矮靴"),
+        (10682, "精金禦敵兜甲"),
+        (10913, "奇美纖維治愈馬褲"),
+        (19838, "蕎麥糊"),
+        (44088, "巧克力奶油蛋糕"),
+        (36320, "輝煌工具的第二次加工部件：鍛冶"),
+        (3936, "樵夫工作靴"),
+        (2728, "白銀眼鏡"),
+        (12529, "白鈦鉚釘"),
+        (36667, "特訓用魔法人偶"),
+        (18056, "硬鋼制敵鱗鎧"),
+        (46047, "儀仗精准護耳帽"),
+        (27999, "2級意力之幻藥"),
+        (13021, "鑌鐵大師工作褲"),
+        (25694, "烏鋼石強攻項環"),
+        (4111, "賽黃晶手鐲"),
+        (41082, "蕨類內牆"),
+        (25668, "斯劍虎革精准露趾長靴"),
+        (26163, "力山羊詠咒束腰衣"),
+        (21757, "清銀詠咒耳墜"),
+        (10898, "奇美纖維治愈手套"),
+        (2924, "革匠工作帽"),
+        (5280, "巨蟾蜍革"),
+        (35423, "賽加羚羊革大地皮鞋"),
+        (21705, "清銀魔導書"),
+        (47916, "霓虹街頭寬松夾克"),
+        (41841, "混金牧杖"),
+        (8280, "紅寶石耳墜"),
+        (19626, "牛鬼革皮靴"),
+        (43308, "嵌齒象革巧匠長靴"),
+        (16665, "閃爍游擊頭冠"),
+        (30450, "唯美釣竿"),
+        (34693, "球粒隕石魔導武士刀"),
+        (22923, "地懶革大地手環"),
+        (35796, "新世界午餐組合：卷餅"),
+        (34147, "紫黃晶精准手環"),
+        (42197, "薄絹詠咒鬥服"),
+        (23103, "紫色大麗花頭花"),
+        (25045, "無限鬼神之火"),
+        (35397, "完滿木泥岩砂輪機"),
+        (2051, "七公六杖"),
+        (9069, "白金治愈耳墜"),
+        (42872, "舊日王國雙刃斧"),
+        (5170, "賽黃晶"),
+        (34085, "輝翠銀屠刀"),
+        (10625, "白鈦巨劍"),
+        (23896, "坐墊套裝"),
+        (25802, "石金刺劍"),
+        (26142, "纏尾蛟革強襲戰靴"),
+        (3945, "駿鷹革狩獵靴"),
+        (26020, "野蠻盜龍革精准手鐲"),
+        (29435, "伊修加德新型強襲上裝"),
+        (26289, "矮人棉禦敵束膝褲"),
+        (9618, "1級加速飼料"),
+        (34083, "馬栗木長槍"),
+        (18252, "白銅禦敵項環"),
+        (2595, "鱸魚丸子"),
+        (31037, "收藏用牛鬼革短衣"),
+        (8295, "黃玉手鐲"),
+        (7513, "羊絨詠咒馬褲"),
+        (34748, "星點石英治愈腕帶"),
+        (48250, "統一規格的金屬環"),
+        (25848, "硬水鋁石禦敵耳墜"),
+        (3921, "民兵鎧靴"),
+        (17328, "空鼠游擊鐵鈎護臂"),
+        (44420, "探求永恆手斧"),
+        (10938, "青麻詠咒半手套"),
+        (39671, "繼業者游擊短衣"),
+        (37779, "復興精准手套"),
+        (34235, "輝翠銀匕首"),
+        (34565, "錳鋼最後的獨角獸之角"),
+        (3653, "盜龍革大地半指護手"),
+        (41089, "隆卡壁爐"),
+        (48390, "超硬外部材料"),
+        (15722, "莫古力用工具部件"),
+        (38440, "煞風裝甲"),
+        (26274, "矮人銀斷頭劍"),
+        (1639, "白鋼長劍"),
+        (10990, "強攻青麻緞帶"),
+        (42045, "高山亞麻詠咒長裙"),
+        (2765, "白鋼騎兵頭盔"),
+        (10824, "虹布精准長褲"),
+        (4474, "金綠柱石戒指"),
+        (43278, "卡扎納爾平斬"),
+        (15572, "無盡雲神戰斧"),
+        (4554, "特級恢復藥"),
+        (31839, "總督制敵手鎧"),
+        (37764, "復興禦敵臂甲"),
+        (39648, "繼業者扎戈斧鐮"),
+        (12003, "青麻大地軟甲褲"),
+        (32939, "4級剛力之幻水"),
+        (28760, "重建用的篝火台"),
+        (25680, "斯劍虎革治愈筒靴"),
+        (25949, "靈青虎爪"),
+        (3370, "內襯棉絨軟甲褲"),
+        (25819, "虹繭強襲棉甲"),
+        (19618, "迦迦納怪鳥革坎肩"),
+        (40412, "陸行鳥寢室眼罩"),
+        (4727, "羅蘭莓酸奶昔"),
+        (27889, "可可麵包乾"),
+        (9051, "白金強攻戒指"),
+        (6668, "尼姆壁燈"),
+        (28512, "中庸砂輪機"),
+        (3870, "橡木木鞋"),
+        (27867, "烤力山羊"),
+        (23099, "藍色大麗花頭花"),
+        (38843, "舞台重要部件"),
+        (4585, "失明劇毒藥"),
+        (42062, "河岸石禦敵指環"),
+        (37770, "復興制敵護膝褲"),
+        (10875, "長頸駝革游擊護腿"),
+        (27928, "橙色繡球頭花"),
+        (44859, "修復鐵軌用的資材"),
+        (18238, "紅玉棉布短褲"),
+        (8541, "寬邊草帽"),
+        (47942, "藍色葉子花耳環"),
+        (8091, "氣囊元件"),
+        (10759, "奇美纖維強襲兜帽"),
+        (41609, "翠光咒杖"),
+        (35361, "鉍金園藝鐮"),
+        (12548, "貴橄欖石"),
+        (3421, "呢絨腿套"),
+        (26439, "實用主義"),
+        (29805, "第二期重建用的布料"),
+        (42355, "白黃金禦敵耳墜"),
+        (44029, "雪木棉布"),
+        (7116, "寶石獸庭院桌"),
+        (2543, "鈷鐵碎石錘"),
+        (26129, "力山羊禦敵打底褲"),
+        (34104, "伽迦象革禦敵長靴"),
+        (38672, "萊韋耶勒爾地板"),
+        (38857, "貨幣製造工具"),
+        (42475, "卡扎納爾禦敵戰甲"),
+        (31077, "收藏用煎牛肝菌"),
+        (11015, "蛋白石精准項環"),
+        (48572, "宇宙探索用的防腐劑"),
+        (9312, "工匠紡車"),
+        (22896, "夜鋼銼刀"),
+        (47468, "宇宙探索用的塊狀地毯"),
+        (26471, "中庸游擊外套"),
+        (10729, "白鈦制敵臂甲"),
+        (12867, "千層長頸駝焗野菜"),
+        (11944, "白鈦廚刀"),
+        (31005, "收藏用精金步兵盾"),
+        (3193, "呢絨僧袍"),
+        (18053, "迦迦納怪鳥革禦敵腿甲靴"),
+        (27671, "改良型棉絨馬褲"),
+        (34286, "棕櫚強攻耳夾"),
+        (25637, "白橡木法杖"),
+        (44419, "探求永恆鶴嘴鋤"),
+        (18365, "卸鐵禦敵脛甲"),
+        (18089, "迦迦納怪鳥革詠咒涼鞋"),
+        (5193, "白珍珠"),
+        (18403, "星尖石禦敵耳墜"),
+        (38881, "健身用品"),
+        (22907, "鬼師手甲"),
+        (46071, "儀仗詠咒耳墜"),
+        (26491, "中庸治愈耳墜"),
+        (20396, "精金打刀"),
+        (13017, "珍木大師工作靴"),
+        (10839, "聖虹游擊圍巾"),
+        (10646, "古鳥革魔導書"),
+        (36111, "6級耐力之幻藥"),
+        (6602, "綠洲圓桌"),
+        (21749, "真麻詠咒手套"),
+        (5372, "松木木材"),
+        (48535, "宇宙線團"),
+        (42022, "高山亞麻強襲頭巾"),
+        (2368, "業餘鑄甲錘"),
+        (12522, "皇金塊"),
+        (27279, "戰鬥精英裝備櫃"),
+        (6674, "海濱流蘇地毯"),
+        (43627, "錳鋼雙牙"),
+        (41868, "雪木棉強襲頭帶"),
+        (15425, "羅蘭莓刨冰"),
+        (18659, "鉬鋼戰斧"),
+        (15507, "加隆德大地手套"),
+        (3454, "纖維腿套"),
+        (7066, "鐵匠工作台"),
+        (22422, "3級剛力之靈水"),
+        (36200, "完滿木木材"),
+        (48186, "聖輝武士刀"),
+        (2878, "綠金頭冠（尖晶石）"),
+        (13020, "鑌鐵大師手套"),
+        (31024, "收藏用翠銀法杖"),
+        (25860, "硬水鋁石精准手鐲"),
+        (42934, "舊日王國精准項環"),
+        (19643, "魔象革巧匠短外套"),
+        (18956, "琅玕精准項環"),
+        (42331, "嵌齒象革強襲釘刺格鬥服"),
+        (18356, "猛虎革魔導典"),
+        (13864, "亞拉戈高位精准長衣（複製品）"),
+        (46003, "高級馬黛茶餅乾"),
+        (33280, "仙子蘋果籃子"),
+        (27742, "海燕革"),
+        (46975, "採掘隕石用的鑽頭"),
+        (2423, "白銀裁皮刀"),
+        (27854, "紫胡蘿蔔果汁"),
+        (32229, "猴面雀搖椅"),
+        (4333, "野豬革項環"),
+        (19823, "燉黃尾鰤魚頭"),
+        (42064, "河岸石精准指環"),
+        (43252, "相思木釣竿"),
+        (6681, "綠洲四葉地毯"),
+        (25026, "神聖女神之弓"),
+        (40703, "管弦樂琴樂譜：虛無界的救贖者"),
+        (22888, "夜鋼圓革刀"),
+        (27088, "深金羊角錘"),
+        (30387, "藍鳥旋轉掛飾"),
+        (36693, "條約蒸餾器"),
+        (4720, "白魚湯"),
+        (3041, "棉布無領襯衫"),
+        (35053, "古典風格拳鬥士三角褲"),
+        (11932, "白鈦平斬"),
+        (2317, "陸行鳥手鋸"),
+        (22921, "清銀大地耳墜"),
+        (3829, "巨蟾蜍革工作靴"),
+        (26323, "海燕革詠咒長袍"),
+        (48265, "宇宙探索用的皮革"),
+        (29505, "農場早餐"),
+        (29810, "第二期重建用的蒸餾器"),
+        (12845, "無花果奶凍"),
+        (31175, "試做裝甲的部件"),
+        (34732, "球粒隕石詠咒手套"),
+        (37793, "復興詠咒外套"),
+        (13072, "莊園案板"),
+        (10671, "飛龍革禦敵鬃盔"),
+        (4375, "綠松石項環"),
+        (20437, "圓桌之刺劍"),
+        (31913, "第四期重建用的合板"),
+        (48588, "研究記錄用的墨水"),
+        (34681, "球粒隕石魔導虎爪"),
+        (43213, "奧闊鉻鐵手鉗"),
+        (2464, "紅木紡車"),
+        (25950, "靈青重斧"),
+        (15729, "牆壁修補部件"),
+        (43302, "薄絹大地及膝褲"),
+        (38897, "研究者煎鍋"),
+        (4556, "高級以太藥"),
+        (43325, "混金項環"),
+        (4852, "奶油"),
+        (19038, "榧木強攻手鐲"),
+        (42901, "舊日王國制敵鎧靴"),
+        (44991, "皓光槍刃"),
+        (18348, "卸鐵騎槍"),
+        (34713, "迷彩絨強襲打底褲"),
+        (18199, "牛鬼革魔導書"),
+        (12870, "鮮紅羅蘭莓派"),
+        (27248, "解毒的藥餌"),
+        (34701, "球粒隕石禦敵上裝"),
+        (14736, "荒神革游擊高筒靴"),
+        (22428, "清銀錠"),
+        (48420, "改良設備用的彈簧"),
+        (7549, "平紋腰布裙"),
+        (35358, "鉍金研鉢"),
+        (38905, "研究者平斬"),
+        (19526, "硬鋼鐮刀"),
+        (16621, "紫色三色堇頭花"),
+        (38926, "研究者大地項鍊"),
+        (30390, "落地沙發"),
+        (1971, "綠葉橡木幻杖"),
+        (15929, "亞麻幸存者襯衫"),
+        (8854, "1級巨匠以太轉輪"),
+        (26432, "海島之弓"),
+        (34743, "星點石英治愈項環"),
+        (44977, "皓光指虎"),
+        (17641, "管弦樂琴樂譜：無限"),
+        (48463, "加工檢驗用的皮革"),
+        (18414, "星尖石強攻手鐲"),
+        (9737, "納爾札爾教團講台"),
+        (8378, "厚土咒錘"),
+        (13862, "亞拉戈高位強襲鎧靴（複製品）"),
+        (48462, "加工檢驗用的皮革細繩"),
+        (33034, "白色康乃馨耳環"),
+        (12601, "靈銀附魔墨水"),
+        (42146, "鈷鎢戰斧"),
+        (42890, "舊日王國圓筆"),
+        (11912, "暗栗木釣竿"),
+        (6502, "次元刀凱旋門"),
+        (13824, "亞拉戈治愈頭冠（複製品）"),
+        (43202, "奧闊鉻鐵圓頭錘"),
+        (34530, "錳鋼劍"),
+        (3456, "纖維褲襪"),
+        (48607, "基地調味汁"),
+        (37799, "復興精准耳墜"),
+        (36228, "5級巧力之幻水"),
+        (12019, "皇金巧匠耳環"),
+        (48457, "宇宙探索用的工作手套"),
+        (7960, "晚餐組合"),
+        (3788, "內襯鞣革鴨嘴靴"),
+        (48597, "即食烤牛排"),
+        (18393, "猛虎革治愈護臂"),
+        (10911, "祝聖青麻治愈束膝褲"),
+        (4858, "薰衣草油"),
+        (43248, "鈦金蒸餾器"),
+        (12593, "星絹布"),
+        (6452, "綠洲木制圍牆"),
+        (41610, "翠光魔導書"),
+        (18577, "石青治愈指環"),
+        (2536, "新來者碎石錘"),
+        (13842, "亞拉戈高位詠咒魔導書（複製品）"),
+        (44220, "收藏用落雷絹寬松七分褲"),
+        (7111, "莊園大號"),
+        (23820, "羅剎術士冠"),
+        (39314, "沙龍服務員禮服手套"),
+        (20718, "東洋地板"),
+        (18675, "瞪羚革禦敵手套"),
+        (10406, "巨膽斷頭劍"),
+        (34733, "迷彩絨詠咒打底褲"),
+        (3742, "楓木木拖鞋"),
+        (33660, "前沿禮服"),
+        (6638, "綠洲台燈"),
+        (10979, "蛋白石禦敵耳墜"),
+        (2530, "小鷹鶴嘴鎬"),
+        (23900, "絨毯"),
+        (36903, "雪峰園藝圍牆"),
+        (48563, "加工檢驗用的煉金藥"),
+        (23836, "羅剎豪士手飾"),
+        (18961, "琅玕精准腕環"),
+        (18984, "鉻鐵盾"),
+        (21765, "黑柳精准腕環"),
+        (4245, "白珍珠耳墜"),
+        (29466, "伊修加德新型精准耳墜"),
+        (9358, "烏茲錠"),
+        (7496, "蛟蜥強襲長靴"),
+        (11948, "白鈦碎石錘"),
+        (48501, "統一規格的帆布"),
+        (48459, "頂級羊皮紙"),
+        (18087, "白銅詠咒臂環"),
+        (21927, "藍色鬱金香腕帶"),
+        (48418, "基地備用的室內照明"),
+        (2739, "白銀頭冠（橄欖石）"),
+        (24263, "3級耐力之藥湯"),
+        (8553, "豹紋女式夏衣"),
+        (13076, "瑪哈之棺"),
+        (18104, "鋰輝石治愈手鐲"),
+        (4147, "紅珊瑚腕環"),
+        (33601, "白帝琵琶弓"),
+        (8289, "黃玉項環"),
+        (33890, "再燃蓄怨積憤之欲"),
+        (9770, "綠洲石路地板"),
+        (3402, "野豬革三角褲"),
+        (34298, "棕櫚治愈手環"),
+        (44437, "探求永恆大地護目鏡"),
+        (11031, "雪人利齒精准戒指"),
+        (18079, "山毛櫸治愈面具"),
+        (46036, "儀仗禦敵脛甲"),
+        (38760, "面向收藏家的地毯"),
+        (8046, "騰龍裝備的改良材料"),
+        (37804, "復興精准項鍊"),
+        (34258, "山雞革制敵騎兵褲"),
+        (5290, "長須豹裘皮"),
+        (44912, "檻框隔離牆"),
+        (17611, "生命之火"),
+        (25808, "深金禦敵手套"),
+        (26009, "白梣木強攻耳墜"),
+        (10772, "安菲瑟龍革強襲護臂"),
+        (13589, "精金投斧"),
+        (48504, "運送資材用的布"),
+        (8661, "黃銅匕首"),
+        (37773, "復興強襲上衣"),
+        (3066, "棉絨襯衫"),
+        (28965, "化妝箱"),
+        (2169, "雕花白銀之書"),
+        (2069, "白銀咒杖"),
+        (44084, "照燒楓糖雞肉"),
+        (7084, "拉拉菲爾腳台"),
+        (23822, "羅剎術士手甲"),
+        (9075, "織蛛詠咒短褲"),
+        (3307, "草布內褲"),
+        (34410, "雪亞麻強襲無檐帽"),
+        (10105, "羽紗"),
+        (15608, "圓桌之書"),
+        (36329, "輝煌工具的第三次加工部件：鑄甲"),
+        (8846, "1級鍛鍊以太轉輪"),
+        (44044, "剛力之寶水"),
+        (17605, "生命之拳"),
+        (31017, "收藏用靈銀縫針"),
+        (29794, "第二期重建用的金屬板"),
+        (23187, "抹茶"),
+        (48592, "工人的能量棒"),
+        (20441, "生命之刺劍"),
+        (9735, "戀人節蛋糕"),
+        (44226, "收藏用克拉洛胡桃木平筆"),
+        (2511, "新來者廚刀"),
+        (16596, "青麻毛邊帽"),
+        (26162, "力山羊詠咒毛帽"),
+        (3442, "呢絨工作褲"),
+        (31954, "第四期重建用的特供風向陸行鳥"),
+        (41894, "五加木強攻耳墜"),
+        (46851, "助燃材料"),
+        (3767, "青銅鎖甲靴"),
+        (5729, "素雪白染劑"),
+        (4328, "螢石項環"),
+        (27172, "仙子棉巧匠圍裙"),
+        (43267, "卡扎納爾圓革刀"),
+        (35089, "古典風格詠咒手環"),
+        (2212, "神恩集"),
+        (10962, "安菲瑟龍革詠咒高跟鞋"),
+        (15526, "加隆德大地耳墜"),
+        (9045, "織蛛強襲束膝褲"),
+        (9736, "王室隔板"),
+        (23812, "羅剎間士草履"),
+        (27675, "改良型呢絨長外衣"),
+        (20440, "生命之太刀"),
+        (48316, "分析用的養殖水槽"),
+        (48481, "宇宙探索用的結實皮革"),
+        (46061, "儀仗治愈長靴"),
+        (2279, "鈷鐵步兵盾"),
+        (13802, "亞拉戈天球儀（複製品）"),
+        (48281, "刻木匠用的資材"),
+        (35404, "黑麻帽"),
+        (23818, "羅剎學士草履"),
+        (2436, "白鋼平斬"),
+        (3691, "古典耐熱手套"),
+        (4692, "清燉盜龍肉"),
+        (4468, "石榴石戒指"),
+        (44843, "香煎面拖旗魚"),
+        (38873, "印刷菜譜的工具"),
+        (18362, "猛虎革禦敵外套"),
+        (18083, "迦迦納怪鳥革治愈涼鞋"),
+        (4629, "御土秘藥"),
+        (15466, "新世界狩獵靴"),
+        (23786, "羅剎豪士籠手"),
+        (42490, "落雷絹精准罩衣"),
+        (43303, "獰豹革大地工作靴"),
+        (24867, "混色鈴蘭頭花"),
+        (3725, "駿鷹革弓術手套"),
+        (2324, "秘銀陸行鳥手鋸"),
+        (20693, "混合制東洋公館外牆"),
+        (14935, "超硬加隆德鋼"),
+        (3670, "鈷鐵連指手鎧"),
+        (7089, "寶石獸寫字桌"),
+        (11907, "靈銀手斧"),
+        (10920, "皇金治愈鎖甲靴"),
+        (32938, "袋獅革"),
+        (37802, "復興禦敵項鍊"),
+        (28473, "中庸巧匠外套"),
+        (18093, "鋰輝石精准耳墜"),
+        (19613, "赤麻巧匠工作服"),
+        (1801, "半月長刃斧"),
+        (21708, "夜鋼刀"),
+        (13258, "綠色貝雷帽"),
+        (11873, "白鈦圓手鋸"),
+        (13817, "亞拉戈強襲軟甲褲（複製品）"),
+        (34741, "星點石英強攻項環"),
+        (8125, "高傳導率元件"),
+        (47107, "備用氣罐"),
+        (4197, "紅銅耳環"),
+        (41910, "混金精准指環"),
+        (7068, "金匠工作台"),
+        (40395, "紅色菊花頭花"),
+        (26434, "暴風舌大劍"),
+        (35048, "古典風格騎士三角褲"),
+        (37289, "圓桌之槍刃"),
+        (27191, "矮人棉貝雷帽"),
+        (14064, "綠蔭拱橋"),
+        (24574, "崖畔亭包子必需品的部件"),
+        (46004, "羊駝濃湯"),
+        (6348, "混合制海濱小屋房頂"),
+        (14849, "混色古典玫瑰頭花"),
+        (9297, "藝術眼鏡"),
+        (6444, "林間標旗"),
+        (33273, "餐桌套裝（花格桌布）"),
+        (4736, "巧克力珠"),
+        (9048, "白金強攻手鐲"),
+        (47471, "超輕菌絲布料"),
+        (48280, "基地床"),
+        (18046, "硬鋼太刀"),
+        (25672, "斯劍虎革游擊手套"),
+        (30069, "神龍夢幻鬥器"),
+        (33029, "藍色康乃馨耳環"),
+        (27268, "胡桃木內牆"),
+        (11025, "靈銀精准耳環"),
+        (2615, "白銀勺形魚餌"),
+        (13810, "亞拉戈制敵胸甲（複製品）"),
+        (2446, "黃銅縫針"),
+        (4231, "狼牙耳墜"),
+        (23185, "金平糖"),
+        (12847, "栗子冰糕"),
+        (3713, "烹調連指手套"),
+        (16714, "藍帶肉排"),
+        (41611, "翠光魔導典"),
+        (26341, "透鋰長石治愈手鐲"),
+        (48243, "硬質月精金錠"),
+        (36332, "輝煌工具的第三次加工部件：裁縫"),
+        (39411, "妖靈圓桌"),
+        (31883, "總督禦敵手鐲"),
+        (4307, "紅銅工藝項環"),
+        (31952, "第四期重建用的燉菜"),
+        (35567, "貓小胖地毯"),
+        (28817, "空賊制敵長靴（複製品）"),
+        (5178, "金綠柱石"),
+        (8804, "星芒節蛋糕"),
+        (10958, "飛龍革詠咒長靴"),
+        (6576, "海濱隔離牆"),
+        (36128, "無限鬼神騎士武具"),
+        (27793, "2級耐力之幻水"),
+        (42487, "卡岡圖亞革強襲軟甲褲"),
+        (19551, "翠銀工藝錘"),
+        (2039, "翡翠手杖"),
+        (27089, "深金銼刀"),
+        (2459, "新來者紡車"),
+        (3543, "棉布半指手套"),
+        (6598, "石像鬼雕像"),
+        (9050, "白金強攻圍巾"),
+        (42463, "卡扎納爾雷火炮"),
+        (48626, "面向兔兔族的觸控板"),
+        (4339, "石榴石項環"),
+        (28887, "管弦樂琴樂譜：不滅孤念"),
+        (35459, "金毗羅鰐革大地指環"),
+        (4103, "白銀手鍊"),
+        (23155, "庫爾札斯防寒用品部件"),
+        (23156, "翠水鄉特產部件"),
+        (15594, "偃武魔導書"),
+        (29425, "伊修加德新型禦敵打底褲"),
+        (27096, "深金碎石錘"),
+        (48415, "宇宙探索用的小刀"),
+        (41848, "混金天球儀"),
+        (5477, "白銀附魔墨水"),
+        (21717, "夜鋼制敵頭盔"),
+        (15741, "龍鱗銼刀"),
+        (14240, "管弦樂琴樂譜：餓狼群集"),
+        (13644, "空賊禦敵長靴"),
+        (6497, "泰坦燈籠"),
+        (18422, "星尖石詠咒指環"),
+        (8032, "強化雲杉合板"),
+        (29988, "混色風信子頭花"),
+        (31088, "收藏用焗黃新薯"),
+        (17545, "粉色櫻花頭花"),
+        (20722, "東洋吊頂燈籠"),
+        (14253, "管弦樂琴樂譜：重壓滅頂"),
+        (29801, "第二期重建用的鐵釘"),
+        (27864, "血番茄沙拉"),
+        (12620, "2級智力煉金溶劑"),
+        (19608, "阿拉米格煎鍋"),
+        (42519, "黑星石禦敵手鐲"),
+        (48569, "宇宙探索用的名匠藥"),
+        (41512, "綠色魯冰花頭花"),
+        (19903, "鈀金附魔墨水"),
+        (34432, "賽加羚羊革詠咒護臂"),
+        (3707, "鐵匠手套"),
+        (28826, "空賊精准五分褲（複製品）"),
+        (13835, "亞拉戈高位長刀（複製品）"),
+        (18052, "迦迦納怪鳥革禦敵騎兵褲"),
+        (37777, "復興精准貝雷帽"),
+        (44021, "深紅木木材"),
+        (43621, "混色向日葵頭花"),
+        (46035, "儀仗禦敵騎兵褲"),
+        (6370, "石制綠洲公館房頂"),
+        (13009, "精革大師裁皮刀"),
+        (39730, "8級智力之幻藥"),
+        (34426, "雪亞麻治愈短衣"),
+        (27170, "斯劍虎革大地工作靴"),
+        (30971, "收藏用雪松釣竿"),
+        (43242, "鈦金手鋸"),
+        (23826, "羅剎豪士耳飾"),
+        (12550, "紅玉髓"),
+        (2335, "革柄白鋼羊角錘"),
+        (2441, "民兵平斬"),
+        (23792, "羅剎槍士籠手"),
+        (44097, "牧豆樹豆汁"),
+        (43603, "雷轉質裝甲"),
+        (18530, "魔象革強襲外套"),
+        (37295, "黃色比爾格紫羅蘭項環"),
+        (27176, "野蠻盜龍革大地工作帽"),
+        (23863, "朱雀的壁掛雕塑"),
+        (8390, "賢王莫古書·庫啵"),
+        (2325, "鈷鐵雕紋手鋸"),
+        (1908, "梣木復合弓"),
+        (11042, "靈銀治愈手鐲"),
+        (29683, "虹吸咖啡機"),
+        (18973, "榧木長弓"),
+        (10961, "安菲瑟龍革詠咒高筒靴"),
+        (3821, "山羊革筒靴"),
+        (25682, "白麻詠咒帽"),
+        (4235, "透綠柱石耳墜"),
+        (10745, "安菲瑟龍革制敵騎兵褲"),
+        (14189, "角粉"),
+        (39242, "家用獺獺噴泉部件"),
+        (2622, "秘銀勺形魚餌"),
+        (12111, "俾斯麥靈泉"),
+        (14137, "山珍番茄面"),
+        (38264, "胡蘿蔔布丁"),
+        (48334, "月球車用的長桿"),
+        (10528, "綠金天球儀"),
+        (19034, "榧木精准項環"),
+        (11008, "煙晶強攻戒指"),
+        (22741, "投擲用特產"),
+        (41255, "面向收藏家的鬧鐘"),
+        (4566, "山彥草藥"),
+        (26117, "沙柚木法杖"),
+        (8126, "輔助空氣動力元件"),
+        (12998, "騎士強襲外套"),
+        (31890, "總督精准戒指"),
+        (7973, "海濱隔離矮牆"),
+        (36314, "輝煌工具的第一次加工部件：雕金"),
+        (42330, "鈦金強襲頭盔"),
+        (41888, "雪木棉詠咒皮毛帽"),
+        (39409, "水幕隔離牆"),
+        (8004, "林間樹籬牆"),
+        (27203, "白橡木項鍊"),
+        (30973, "收藏用暗栗木法杖"),
+        (48302, "加工檢驗用的木板"),
+        (29808, "第二期重建用的研磨工具"),
+        (2257, "公牛重盾"),
+        (8200, "娜娜珂小熊"),
+        (8835, "希瓦枝形吊燈"),
+        (7026, "亞麻帆布"),
+        (28975, "牆上玫瑰"),
+        (5584, "駿鷹革護膝"),
+        (16820, "管弦樂琴樂譜：忘卻的彼岸"),
+        (48249, "宇宙探索用的無人機"),
+        (2485, "新來者研鉢"),
+        (35033, "古典風格武士刀"),
+        (18399, "猛虎革詠咒護臂"),
+        (44190, "收藏用克拉洛胡桃木釣竿"),
+        (44165, "2級智力之寶藥"),
+        (6365, "木制綠洲小屋房頂"),
+        (8102, "壓載物元件"),
+        (5287, "聖蜥蜴革"),
+        (10844, "獅鷲革游擊三角帽"),
+        (39670, "繼業者游擊頭帶"),
+        (18410, "星尖石精准項環"),
+        (14869, "聖蜥蜴革外套"),
+        (2708, "黑鐵角盔"),
+        (37786, "復興游擊鎧靴"),
+        (35398, "球粒隕石平斬"),
+        (17957, "迦迦納怪鳥標本"),
+        (48446, "宇宙飛線用的皮革細繩"),
+        (4716, "羊奶麥粥"),
+        (39691, "繼業者強攻項環"),
+        (31211, "第三期重建用的石材"),
+        (36696, "條約手斧"),
+        (33035, "黑色康乃馨耳環"),
+        (7979, "植物隔離矮牆"),
+        (48541, "統一規格的染劑"),
+        (28518, "中庸鐮刀"),
+        (27113, "靈青平斬"),
+        (46075, "儀仗治愈項鍊"),
+        (11020, "硬銀精准手鐲"),
+        (2041, "古典牧杖"),
+        (13077, "魔菇座椅"),
+        (44887, "永恆紀念碑"),
+        (2725, "加固角盔"),
+        (43285, "雪木棉外套"),
+        (48339, "宇宙探索用的文件"),
+        (27097, "深金鐮刀"),
+        (22740, "烤鯰魚用品"),
+        (2778, "鮟鱇草帽"),
+        (44858, "技師用的必要工具"),
+        (26501, "中庸治愈手鐲"),
+        (5571, "復仇烈拳握柄"),
+        (9049, "白金強攻耳墜"),
+        (43215, "奧闊鉻鐵平斬"),
+        (13799, "亞拉戈小刀（複製品）"),
+        (42462, "卡扎納爾巨劍"),
+        (7959, "早餐組合"),
+        (31895, "4級耐力之幻藥"),
+        (11877, "白鈦鍛鐵錘"),
+        (43295, "薄絹巧匠工作衫"),
+        (15516, "加隆德巧匠圍裙"),
+        (10471, "旋風神棄"),
+        (2882, "纖維狩獵帽"),
+        (29413, "伊修加德新型法杖"),
+        (13852, "亞拉戈高位禦敵鎧靴（複製品）"),
+        (38546, "夢羽妖靈王屠刀"),
+        (2236, "黑鐵重盾"),
+        (11001, "靈銀強攻耳環"),
+        (6520, "莊園座椅"),
+        (43315, "落雷絹巧匠襯衫"),
+        (8458, "改良型羊絨治愈長袍"),
+        (27838, "油橄欖油"),
+        (25698, "島狼禦敵手鐲"),
+        (38931, "黝簾石"),
+        (19639, "猛虎革大地半指護手"),
+        (11966, "聖虹布禮服手套"),
+        (26136, "纏尾蛟革制敵戰靴"),
+        (3105, "古典工作坎肩"),
+        (41254, "面向收藏家的竹制籬笆牆"),
+        (35413, "伽迦象革草履"),
+        (42499, "黑星石治愈口罩"),
+        (25973, "靈青制敵胸甲"),
+        (21202, "薩維奈秋意襯衫"),
+        (37362, "加隆德工具套裝"),
+        (34435, "弗里金禦敵耳夾"),
+        (24514, "純白隔離牆"),
+        (3100, "巨蟾蜍革皮甲"),
+        (4108, "山羊革大地手環"),
+        (2445, "業餘縫針"),
+        (24859, "紅色鈴蘭頭花"),
+        (39308, "沙龍服務員帽"),
+        (18392, "葛布治愈長袍"),
+        (16700, "閃爍精准指環"),
+        (3908, "紅木木鞋"),
+        (27129, "沙柚木釣竿"),
+        (21094, "梅氏一族的日用品部件"),
+        (10921, "獅鷲革治愈長靴"),
+        (17967, "東方午餐組合：壽司"),
+        (34752, "星點石英精准指環"),
+        (26108, "鈦銅曲劍"),
+        (2201, "博學治愈巫書"),
+        (36116, "魔匠藥酒"),
+        (17552, "別具匠心的禮物"),
+        (13672, "空賊治愈手套"),
+        (15590, "偃武大劍"),
+        (43280, "卡扎納爾研鉢"),
+        (6383, "木制林間小屋外牆"),
+        (39787, "面向收藏家的植物隔離矮牆"),
+        (29478, "伊修加德新型詠咒手環"),
+        (30076, "神龍夢幻錫杖"),
+        (5482, "魚油"),
+        (2121, "山羊角長杖"),
+        (1985, "雷華幻杖"),
+        (8045, "勇戰裝備的改良材料"),
+        (21824, "博雷爾午茶組合"),
+        (37780, "復興精准護膝褲"),
+        (12541, "水淙石"),
+        (19641, "猛虎革大地筒靴"),
+        (15588, "偃武長弓"),
+        (13660, "空賊精准手套"),
+        (28726, "重建用的合金"),
+        (15605, "圓桌之火"),
+        (20738, "東洋地爐"),
+        (29800, "第二期重建用的木箱"),
+        (36182, "紫黃晶"),
+        (27841, "山地小麥粉"),
+        (18545, "玉鋼游擊脛甲"),
+        (42322, "鈦金禦敵釘刺手套"),
+        (14247, "管弦樂琴樂譜：魔大戰的傷痕"),
+        (27139, "鈦銅鐮刀"),
+        (3692, "鈷鐵手鎧"),
+        (2689, "黃銅頭冠（孔雀石）"),
+        (6405, "林間圓窗"),
+        (20470, "道士冠巾"),
+        (44197, "收藏用奧闊鉻鐵蒸餾器"),
+        (12017, "雪人利齒耳墜"),
+        (16640, "重合金鳶盾"),
+        (26279, "矮人銀魔導典"),
+        (37291, "圓桌之蛇石針"),
+        (6491, "農用貨車"),
+        (14740, "荒神布治愈長裙"),
+        (33268, "圓窗隔離牆"),
+        (4205, "黃銅耳環"),
+        (8778, "南洋拐沙發"),
+        (6387, "混合制林間公館外牆"),
+        (26324, "海燕革詠咒手套"),
+        (3681, "纖維工作手套"),
+        (6504, "四管對龍加農炮"),
+        (28861, "空鼠精准馬褲（複製品）"),
+        (8370, "旋風神導"),
+        (9624, "1級體力飼料"),
+        (42342, "鈦金游擊釘刺手套"),
+        (26139, "力山羊強襲大衣"),
+        (27199, "矮人棉馬褲"),
+        (36898, "雪峰門牌"),
+        (19525, "硬鋼碎石錘"),
+        (43888, "玉米薄餅壓制機"),
+        (3678, "纖維弓術手套"),
+        (2668, "黃銅眼鏡"),
+        (11043, "靈銀治愈手環"),
+        (4678, "清燉牛肉"),
+        (18667, "瞪羚革魔導書"),
+        (15477, "極限幸存者長褲"),
+        (8782, "滴水海芋"),
+        (31110, "改良用黑鐵錠"),
+        (10393, "薩維奈舞裙"),
+        (18387, "猛虎革游擊護手"),
+        (31877, "總督詠咒耳墜"),
+        (43892, "佩魯佩魯族樣式的裝幀部件"),
+        (9746, "海濱庭院桌組合"),
+        (34560, "錳鋼升龍戰盔"),
+        (43331, "五加木指環"),
+        (4371, "琥珀項環"),
+        (6412, "海濱裝飾門"),
+        (13310, "荒神鋼精准耳墜"),
+        (36698, "條約羊角錘"),
+        (13594, "受祝箭羽"),
+        (34122, "伽迦象革游擊半指護手"),
+        (29961, "紫草布"),
+        (6649, "文具一組"),
+        (31957, "第四期重建用的特供工具腰帶"),
+        (37328, "豪勇文書"),
+        (31191, "第三期重建用的麻乳"),
+        (30758, "南洋海濱皮鞋"),
+        (28715, "強化玻璃纖維"),
+        (10351, "武神風扇燈"),
+        (10915, "古鳥革治愈尖頭靴"),
+        (30993, "收藏用卸鐵打刀"),
+        (20358, "獄火打刀"),
+        (7091, "寶石獸衣櫃"),
+        (25058, "管弦樂琴樂譜：雲蒸龍變"),
+        (6434, "海濱側壁煙囪"),
+        (27995, "2級剛力之幻藥"),
+        (48486, "宇宙皮革細繩"),
+        (27164, "白麻巧匠打底褲"),
+        (20807, "黃色光明百合頭花"),
+        (34084, "馬栗木長弓"),
+        (3837, "胡桃木鞋"),
+        (31882, "總督詠咒項環"),
+        (16656, "半熊革強襲軟甲褲"),
+        (4216, "賽黃晶耳墜"),
+        (12106, "戈耳狄座椅"),
+        (3918, "顯貴高跟鞋"),
+        (42358, "白黃金治愈耳墜"),
+        (7981, "雷神等離子燈"),
+        (18573, "石青詠咒手鐲"),
+        (18549, "魔象革治愈手套"),
+        (43340, "黑星石巧匠耳墜"),
+        (10765, "奇美纖維強襲鬥服"),
+        (44031, "薄絹織物"),
+        (42012, "河岸石禦敵頭冠"),
+        (8303, "藍寶石戒指"),
+        (42455, "克拉洛胡桃木長槍"),
+        (14254, "管弦樂琴樂譜：英傑"),
+        (27168, "斯劍虎革大地手套"),
+        (13658, "空賊精准貝雷帽"),
+        (46018, "儀仗魔導典"),
+        (25648, "深金禦敵手鎧"),
+        (40634, "懸掛廚房櫃"),
+        (13901, "龍神制敵脛甲（複製品）"),
+        (18995, "鉻鐵制敵脛甲"),
+        (28589, "都市筒靴"),
+        (4658, "綠樹蟾蜍腿"),
+        (40398, "綠色菊花頭花"),
+        (36704, "條約研鉢"),
+        (5483, "水母油"),
+        (11062, "詠咒青麻緞帶"),
+        (24279, "鮭魚松餅"),
+        (3036, "棉布厚袍"),
+        (38543, "夢羽妖靈王重斧"),
+        (4555, "以太藥"),
+        (34739, "星點石英詠咒耳墜"),
+        (25961, "靈青打刀"),
+        (6499, "利維亞桑噴泉"),
+        (48624, "面向兔兔族的果汁"),
+        (44001, "卡扎納爾錠"),
+        (39731, "8級意力之幻藥"),
+        (15606, "圓桌之幻杖"),
+        (10927, "奇美纖維詠咒魔術帽"),
+        (16650, "半熊革制敵襯褲"),
+        (3260, "絹布詠咒長袍"),
+        (21723, "清銀強襲假面"),
+        (35045, "古典風格騎士頭甲"),
+        (35408, "伽迦象革足袋"),
+        (43207, "奧闊鉻鐵平底鍋"),
+        (17337, "空鼠詠咒格鬥服"),
+        (46013, "儀仗長柄刀"),
+        (41889, "雪木棉詠咒外套"),
+        (43318, "克拉洛胡桃木巧匠涼鞋"),
+        (26317, "海燕革治愈長袍"),
+        (6464, "林間吊燈"),
+        (25629, "深金三尖拳劍"),
+        (6505, "對龍炮彈箱"),
+        (36134, "哈迪斯騎士武具"),
+        (31058, "收藏用仙子棉兜帽"),
+        (48191, "聖輝戰鐮"),
+        (30453, "唯美手鉗"),
+        (23840, "羅剎術士手飾"),
+        (22430, "地懶革"),
+        (18394, "葛布治愈長裙"),
+        (21742, "真麻治愈長袍"),
+        (48259, "統一規格的皮革細繩"),
+        (43333, "相思木項鍊"),
+        (10636, "暗栗木法杖"),
+        (9087, "黑檀長弓"),
+        (48401, "統一規格的彈簧"),
+        (31654, "收藏用矮人銀蒸餾器"),
+        (1627, "榆木石刃棒"),
+        (3020, "草布大地工作坎肩"),
+        (37402, "植物套裝"),
+        (27095, "深金廚刀"),
+        (42510, "黑星石強攻耳夾"),
+        (23817, "羅剎學士筒袴"),
+        (8115, "廢品緊急處理元件"),
+        (7614, "玄鋼絲"),
+        (7009, "切石"),
+        (19966, "琅玕"),
+        (19755, "瞪羚革手環"),
+        (14061, "仙人刺綠雕"),
+        (8357, "獄火神斧"),
+        (30464, "唯美巧匠軟甲褲"),
+        (17306, "空鼠禦敵面具"),
+        (7552, "利維亞桑裝甲"),
+        (48578, "工人的營養劑"),
+        (19975, "赤麻線"),
+        (12843, "凍霧雞尾酒"),
+        (11995, "長頸駝革手套"),
+        (2624, "漂浮誘餌蛙"),
+        (7570, "酒香蒸鯰魚"),
+        (44093, "雙油風味拌細面"),
+        (16693, "柚木禦敵手鐲"),
+        (17994, "福爾唐枝形吊燈"),
+        (11892, "硬銀縫針"),
+        (9311, "工匠平斬"),
+        (29834, "第二期重建用的特供暖爐"),
+        (44208, "收藏用黑星石耳墜"),
+        (17025, "常春藤石柱"),
+        (14066, "浮士德木人"),
+        (26459, "中庸強襲鬥服"),
+        (6623, "林間矮櫃"),
+        (36840, "校園皮鞋"),
+        (23105, "黑色大麗花頭花"),
+        (40640, "鄉村原木桌"),
+        (32799, "小牛皮騎手外套"),
+        (39654, "繼業者禦敵矮靴"),
+        (4659, "水煮蝲蛄"),
+        (28171, "精肉攤"),
+        (11073, "靈銀詠咒耳環"),
+        (44150, "海島棉布"),
+        (28978, "辛辣蛋沙拉碗"),
+        (5095, "玄鋼鉚釘"),
+        (44216, "收藏用高山亞麻上裝"),
+        (26468, "中庸精准筒靴"),
+        (35662, "收藏用炸帝王蟹餅"),
+        (43291, "銀狼革手套"),
+        (2669, "硬革眼罩"),
+        (18042, "白銅長杖"),
+        (1622, "黑鐵騎兵劍"),
+        (21760, "黑柳精准項鍊"),
+        (27285, "水晶都廚房壁掛架"),
+        (10666, "白鈦鳶盾"),
+        (22902, "夜鋼廚刀"),
+        (26530, "柳杉木材"),
+        (44228, "收藏用香蕉星磅蛋糕"),
+        (35039, "古典風格長盾"),
+        (2465, "紫檀紡車"),
+        (16715, "祭司蛋包飯"),
+        (25850, "硬水鋁石精准耳墜"),
+        (31218, "第三期重建用的街燈"),
+        (2490, "秘銀研鉢"),
+        (18993, "鉻鐵制敵手鎧"),
+        (42888, "舊日王國蛇石針"),
+        (10605, "靈銀角力棒"),
+        (21772, "清銀詠咒戒指"),
+        (41906, "銀狼革治愈手鐲"),
+        (28149, "項鍊展示台"),
+        (7543, "花草帽"),
+        (42163, "鈷鎢鑽紋蛇刀"),
+        (21836, "般若面具"),
+        (13043, "煉真大師眼鏡"),
+        (8100, "駕駛席外部元件"),
+        (24275, "生薑沙拉"),
+        (5259, "泥岩磨刀石"),
+        (46027, "儀仗戰鐮"),
+        (35455, "鐵木巧匠指環"),
+        (38879, "缺少的烹飪用品"),
+        (44304, "管弦樂琴樂譜：存在的理由"),
+        (29649, "改良用的鞣革"),
+        (7078, "冬貝利長沙發"),
+        (29838, "第二期重建用的特供常備藥"),
+        (33033, "紫色康乃馨耳環"),
+        (19012, "紵布游擊垮褲"),
+        (41266, "面向收藏家的皮衣"),
+        (48116, "菱格櫃櫥"),
+        (25643, "深金槍刃"),
+        (25636, "白橡木牧杖"),
+        (36319, "輝煌工具的第二次加工部件：刻木"),
+        (19964, "星尖石"),
+        (25677, "白麻治愈鬥服"),
+        (26116, "沙柚木牧杖"),
+        (48203, "管弦樂琴樂譜：五月的玫瑰　～金曦～"),
+        (42479, "卡扎納爾制敵頭盔"),
+        (6420, "海濱石制煙囪"),
+        (2316, "業餘手鋸"),
+        (19520, "硅岩砂輪機"),
+        (25704, "島狼強攻指環"),
+        (12849, "凱撒麵包"),
+        (11072, "靈銀詠咒耳墜"),
+        (24178, "綠色馬蹄蓮頭花"),
+        (31094, "黃色秋英頭花"),
+        (6488, "半高花盆"),
+        (13049, "烹飪大師圍裙"),
+        (18974, "鉻鐵小刀"),
+        (37364, "簡易拱窗"),
+        (21703, "黑柳牧杖"),
+        (34564, "錳鋼升龍鬥鞋"),
+        (12846, "索姆阿爾栗子蛋糕"),
+        (36054, "亞考牛慕沙卡"),
+        (41899, "銀狼革強攻項鍊"),
+        (48107, "東洋衣櫥"),
+        (34544, "錳鋼刺劍"),
+        (29424, "伊修加德新型禦敵手鎧"),
+        (18975, "鉻鐵巨劍"),
+        (3831, "棉絨禮鞋"),
+        (41604, "翠光長弓"),
+        (12624, "耐力之龍藥"),
+        (2503, "鈷鐵平底鍋"),
+        (35451, "馬栗木大地指環"),
+        (27868, "蘑菇烤串"),
+        (29965, "3級耐力之幻水"),
+        (2527, "白鋼鶴嘴鎬"),
+        (27086, "深金手斧"),
+        (4619, "御水藥"),
+        (19570, "鉬鋼鍛鐵錘"),
+        (2993, "部隊罩衣"),
+        (19721, "阿拉米格巧匠皮鞋"),
+        (31034, "收藏用巨龍革指環"),
+        (10869, "巨龍革游擊馬褲"),
+        (27739, "綠飄龍革"),
+        (2431, "青銅平斬"),
+        (27941, "夏季靛藍襯衫"),
+        (30445, "唯美縫針"),
+        (25683, "白麻詠咒鬥服"),
+        (1694, "鐵甲指虎"),
+        (26495, "中庸精准項環"),
+        (30400, "複製平台"),
+        (10596, "精金拳鋒"),
+        (34267, "阿爾馬斯提精准手套"),
+        (29494, "3級耐力之幻藥"),
+        (35578, "高架床"),
+        (35984, "冒險者野餐籃"),
+        (31892, "總督詠咒戒指"),
+        (26465, "中庸精准罩衣"),
+        (7490, "鎢鋼鎧靴"),
+        (44207, "收藏用卡扎納爾太陽儀"),
+        (6438, "林間門牌"),
+        (8297, "藍寶石手鐲"),
+        (29792, "第二期重建用的合板"),
+        (46042, "儀仗強襲護耳帽"),
+        (18412, "星尖石詠咒項環"),
+        (14241, "管弦樂琴樂譜：激潮"),
+        (5503, "黏膠"),
+        (6428, "海濱木制遮蓬"),
+        (18197, "落葉松幻杖"),
+        (11895, "靈銀蒸餾器"),
+        (39783, "面向收藏家的紋章盾"),
+        (11923, "靈銀手鉗"),
+        (23772, "羅剎大弓"),
+        (30405, "複製鐵絲網"),
+        (12603, "皇金附魔墨水"),
+        (20805, "紅色光明百合頭花"),
+        (18359, "翠銀小劍"),
+        (8789, "收藏櫃"),
+        (15519, "加隆德巧匠長靴"),
+        (44183, "龍蝦濃湯"),
+        (46065, "儀仗詠咒騎兵褲"),
+        (20709, "東洋石制遮蓬"),
+        (27118, "靈青鐮刀"),
+        (23902, "白色木牆"),
+        (36884, "雪峰木制門"),
+        (15579, "無盡雲神長玉杖"),
+        (10994, "靈銀強攻手鐲"),
+        (6622, "林間文件櫃"),
+        (4640, "渡渡鳥烤肉"),
+        (42513, "黑星石詠咒耳夾"),
+        (36623, "收藏用蛇牛革半手套"),
+        (8035, "高精度玫瑰金齒輪"),
+        (10966, "禦敵青麻緞帶"),
+        (40401, "白色菊花頭花"),
+        (41063, "緩解腰痛的必需品"),
+        (34451, "鉍金強攻指環"),
+        (35387, "球粒隕石圓革刀"),
+        (26506, "中庸治愈指環"),
+        (26330, "透鋰長石精准耳墜"),
+        (6350, "木制海濱公館房頂"),
+        (47949, "混色葉子花耳環"),
+        (18681, "鉬鋼制敵護臂"),
+        (47344, "宇宙探索用的鞣革"),
+        (2372, "黑鐵圓頭錘"),
+        (26122, "鈦銅刺劍"),
+        (6510, "千獄火葬花"),
+        (8287, "鑽石項環"),
+        (1978, "水華幻杖"),
+        (4624, "御雷妙藥"),
+        (16661, "半熊革精准手套"),
+        (17936, "多瑪烏冬面湯底"),
+        (48492, "宇宙探索用的衣物"),
+        (4514, "黑珍珠戒指"),
+        (5191, "堇青石"),
+        (25852, "硬水鋁石詠咒耳墜"),
+        (36044, "奶油紫湯"),
+        (2021, "黑羽手杖"),
+        (43245, "鈦金工藝錘"),
+        (27769, "1級耐力之幻水"),
+        (6385, "石制林間小屋外牆"),
+        (42203, "粉綠柱石精准耳墜"),
+        (18196, "硬鋼燧發槍"),
+        (43284, "雪木棉貝雷帽"),
+        (5325, "棉布"),
+        (6652, "魔導死神模型"),
+        (29434, "伊修加德新型強襲禮帽"),
+        (18541, "魔象革游擊兜帽"),
+        (14737, "荒神布治愈狩獵帽"),
+        (4239, "金綠柱石耳墜"),
+        (2401, "秘銀工藝錘"),
+        (43336, "嵌齒象革耳墜"),
+        (3841, "野豬革尖頭靴"),
+        (3404, "亞麻打底褲"),
+        (4145, "白珍珠手鐲"),
+        (7083, "冬貝利橢圓地毯"),
+        (4135, "透綠柱石手鐲"),
+        (39720, "石榴紅棉布"),
+        (43316, "卡岡圖亞革巧匠半指手套"),
+        (19550, "玉鋼圓頭錘"),
+        (6603, "林間圓桌"),
+        (19024, "紵布詠咒長裙"),
+        (7139, "陸行鳥內牆"),
+        (25974, "靈青制敵手鎧"),
+        (3616, "野豬革手箍"),
+        (15735, "龍族報酬"),
+        (44092, "烤犎牛肉"),
+        (22449, "2級耐力之藥湯"),
+        (28973, "莊園梳妝台"),
+        (10630, "白鈦明火槍"),
+        (36112, "6級智力之幻藥"),
+        (33301, "室外武具桌"),
+        (42926, "舊日王國詠咒皮鞋"),
+        (19946, "鈀金塊"),
+        (42498, "卡扎納爾游擊脛甲"),
+        (41871, "雪木棉強襲打底褲"),
+        (31936, "第四期重建用的紅茶"),
+        (21842, "2級金屬水族箱"),
+        (11965, "古鳥革巧匠手套"),
+        (14045, "管弦樂琴"),
+        (8033, "高精度玄鋼鈎"),
+        (12525, "白鈦錠"),
+        (3160, "呢絨長袍"),
+        (30474, "唯美巧匠腕環"),
+        (35377, "錳鋼平斬"),
+        (13652, "空賊強襲面具"),
+        (13642, "空賊禦敵手鎧"),
+        (27194, "矮人棉軟甲褲"),
+        (10589, "白鈦長劍"),
+        (37297, "橙色比爾格紫羅蘭項環"),
+        (6447, "海濱柵欄圍牆"),
+        (14158, "樟木木材"),
+        (48374, "改良設備用的火爐"),
+        (25790, "深金大斧"),
+        (7080, "冬貝利睡床"),
+        (4093, "獸骨腕環"),
+        (10467, "鈷鐵火槍"),
+        (20776, "東方風格的收藏品"),
+        (18539, "魔象革精准筒靴"),
+        (7024, "白鋼折葉"),
+        (11990, "長頸駝革坎肩"),
+        (10633, "精金重炮"),
+        (2133, "白牙長杖"),
+        (19949, "鉻鐵錠"),
+        (29467, "伊修加德新型治愈耳墜"),
+        (42009, "釕金雙牙"),
+        (25800, "石金太陽儀"),
+        (36110, "6級巧力之幻藥"),
+        (3727, "絹布手套"),
+        (44229, "收藏用圖拉爾菠蘿蛋糕"),
+        (41882, "銀狼革游擊涼鞋"),
+        (17307, "空鼠禦敵格鬥服"),
+        (44192, "收藏用釕金戰斧"),
+        (11004, "皇金強攻耳墜"),
+        (3929, "甲匠工作靴"),
+        (19619, "迦迦納怪鳥革手套"),
+        (27880, "檸檬水"),
+        (42885, "舊日王國槍刃"),
+        (35568, "胡桃木立架花瓶"),
+        (15144, "洗面台"),
+        (5479, "綠金附魔墨水"),
+        (35635, "收藏用球粒隕石廚刀"),
+        (4510, "尖晶石戒指"),
+        (48620, "耐儲存的煙薰魚"),
+        (39390, "烹飪玩具套裝"),
+        (12863, "綠寶石湯"),
+        (4597, "智力之藥"),
+        (48319, "運送資材用的宇宙木材"),
+        (31821, "總督牧杖"),
+        (18571, "石青精准手鐲"),
+        (30992, "收藏用硬鋼羊角錘"),
+        (37363, "書櫃暗門隔離牆"),
+        (6498, "土神燈籠"),
+        (37760, "復興扎戈斧鐮"),
+        (4578, "麻痹毒藥"),
+        (27775, "植物膠"),
+        (11994, "聖虹布工作手套"),
+        (42368, "白黃金治愈護身手鐲"),
+        (2405, "業餘砂輪機"),
+        (3314, "草布束膝褲"),
+        (26448, "中庸禦敵手鎧"),
+        (18214, "牛鬼革制敵軟甲褲"),
+        (10830, "星絹布精准長褲"),
+        (15538, "荒神工藝錘"),
+        (31103, "改良用秘銀環"),
+        (19649, "魔象革大地手套"),
+        (21700, "夜鋼短刀"),
+        (8301, "黃玉戒指"),
+        (26026, "青金石治愈指環"),
+        (36127, "神聖女神騎士武具"),
+        (31071, "收藏用幻水"),
+        (18657, "鉬鋼長劍"),
+        (5799, "風暴藍染劑"),
+        (36232, "6級剛力之幻水"),
+        (2563, "新來者鐮刀"),
+        (31899, "迷霧菠菜乳蛋餅"),
+        (15922, "月火男式坎肩"),
+        (4557, "超級以太藥"),
+        (15925, "月火女式夏衣"),
+        (27925, "藍色繡球頭花"),
+        (3599, "白鋼手鎧"),
+        (2375, "白鋼圓頭錘"),
+        (11918, "精金加固羊角錘"),
+        (37767, "復興制敵頭盔"),
+        (10465, "秘銀火槍"),
+        (42522, "黑星石治愈手鐲"),
+        (3648, "呢絨工作手套"),
+        (34099, "輝翠銀盾"),
+        (44913, "茶罐櫃"),
+        (4848, "楓糖"),
+        (11053, "貴橄欖石治愈耳墜"),
+        (37329, "豪勇干支"),
+        (5189, "祖母綠"),
+        (18247, "白銅禦敵耳墜"),
+        (10808, "奇美纖維精准短袖罩衣"),
+        (35331, "輝翠銀羊角錘"),
+        (44439, "探求永恆大地工作手套"),
+        (10853, "飛龍革游擊護袖"),
+        (32823, "綠寶石神兵裝甲"),
+        (38265, "旋轉烤肉卷餅"),
+        (15525, "加隆德大地項鍊"),
+        (46029, "儀仗雙軍刀"),
+        (38910, "研究者園藝鐮"),
+        (37319, "豪勇戰斧"),
+        (21727, "地懶革強襲高筒靴"),
+        (18980, "真獅鷲革魔導典"),
+        (43323, "克拉洛胡桃木大地涼鞋"),
+        (3060, "棉布束腰獵衣"),
+        (42205, "粉綠柱石詠咒耳墜"),
+        (28806, "黑色風鈴頭花"),
+        (35575, "仿制木屋閣樓天窗"),
+        (31030, "收藏用古鳥革腰帶"),
+        (3000, "草布僧袍"),
+        (30403, "複製橫梁"),
+        (6483, "蓮葉陽傘"),
+        (31750, "最終改良用松木木材"),
+        (28744, "重建用的燭台"),
+        (19844, "甜菜糖"),
+        (25994, "野蠻盜龍革游擊腿甲靴"),
+        (10464, "秘銀步槍"),
+        (19536, "卸鐵手斧"),
+        (41909, "混金強攻指環"),
+        (48268, "統一規格的磚塊"),
+        (19974, "赤麻布"),
+        (44155, "2級智力之寶水"),
+        (1932, "鈷鐵騎兵弓"),
+        (9209, "秘銀短刀"),
+        (3233, "革匠工作衫"),
+        (42366, "白黃金強攻護身手鐲"),
+        (15742, "雲海釣餌"),
+        (15565, "驚雷怒震"),
+        (7970, "牆上盆栽"),
+        (42199, "薄絹詠咒長褲"),
+        (25830, "虹繭游擊圓頂帽"),
+        (27680, "纖維手套"),
+        (31106, "改良用面料"),
+        (31087, "收藏用黑色醬汁直面"),
+        (36880, "混合制雪峰別墅外牆"),
+        (48507, "宇宙探索用的帽子"),
+        (17636, "管弦樂琴樂譜：斬"),
+        (46008, "鬃背獸肉餅"),
+        (48594, "研究員的賢人麵包"),
+        (41995, "木棉木手杖"),
+        (31752, "最終改良用鎢鋼方板"),
+        (3515, "草布半指手套"),
+        (20370, "賢王莫古打刀"),
+        (11993, "古鳥革大地手套"),
+        (27760, "泡絹布"),
+        (3024, "棉布僧袍"),
+        (27740, "野蠻盜龍革"),
+        (2938, "駿鷹革頭巾"),
+        (39470, "煬火裝甲"),
+        (30857, "青君細劍"),
+        (43218, "奧闊鉻鐵廚刀"),
+        (44062, "卡岡圖亞革"),
+        (2521, "業餘鶴嘴鋤"),
+        (41908, "混金禦敵指環"),
+        (39677, "繼業者治愈護臂"),
+        (19559, "玉鋼羊角錘"),
+        (29462, "伊修加德新型詠咒皮靴"),
+        (26119, "纏尾蛟革魔導典"),
+        (7572, "香烤填鴨"),
+        (2421, "黑鐵圓革刀"),
+        (19981, "葛線"),
+        (37758, "復興戰輪"),
+        (14712, "光銀禦敵頭盔"),
+        (23183, "鷹眼砂"),
+        (40758, "朱雀雕像"),
+        (48322, "宇宙琥珀"),
+        (34148, "紫黃晶治愈手環"),
+        (2066, "冰華咒角"),
+        (29447, "伊修加德新型游擊上裝"),
+        (3183, "龜甲鱗鎧"),
+        (35638, "收藏用鉍金貓小胖煎鍋"),
+        (31846, "總督強襲打底褲"),
+        (41516, "黑色魯冰花頭花"),
+        (36056, "鹽烤淺灘鱈魚"),
+        (41845, "奧闊鉻鐵小刀"),
+        (44022, "相思木木材"),
+        (36168, "輝翠銀塊"),
+        (27767, "1級剛力之幻水"),
+        (4365, "紅電氣石項環"),
+        (42061, "河岸石詠咒手鐲"),
+        (13818, "亞拉戈強襲鎖甲靴（複製品）"),
+        (42328, "嵌齒象革制敵軟甲褲"),
+        (35995, "橙色紫燈花耳環"),
+        (4855, "可可粉"),
+        (36327, "輝煌工具的第三次加工部件：刻木"),
+        (48183, "聖輝魔導書"),
+        (45982, "3級智力之寶水"),
+        (16672, "星絨治愈長衫"),
+        (3039, "棉布棉甲"),
+        (7992, "球形灌木"),
+        (48613, "加工檢驗用的油"),
+        (13648, "空賊制敵手鎧"),
+        (39685, "繼業者禦敵耳墜"),
+        (10850, "奇美纖維游擊罩衣"),
+        (35417, "雪亞麻布巧匠打底褲"),
+        (31111, "改良用秘銀板"),
+        (9722, "被爐"),
+        (43890, "冥想用香爐"),
+        (4379, "綠金工藝項環"),
+        (18685, "玉綢強襲兜帽"),
+        (42337, "犎牛嗶嘰精准半指手套"),
+        (4551, "恢復藥"),
+        (38573, "夢羽妖靈王騎士武具"),
+        (14171, "3級剛力煉金溶劑"),
+        (13814, "亞拉戈強襲面罩（複製品）"),
+        (2542, "秘銀碎石錘"),
+        (10466, "鈷鐵步槍"),
+        (7064, "傳喚鈴"),
+        (42907, "舊日王國精准貝雷帽"),
+        (25816, "深金制敵鎖甲靴"),
+        (31011, "收藏用深金胸甲"),
+        (6366, "混合制綠洲小屋房頂"),
+        (24006, "私語呢絨短靴"),
+        (25864, "硬水鋁石強攻指環"),
+        (10638, "樺木符杖"),
+        (6372, "混合制綠洲別墅房頂"),
+        (5582, "駿鷹革護脛"),
+        (17608, "生命之弓"),
+        (24492, "3級金屬水族箱"),
+        (27196, "矮人棉頭帶"),
+        (32246, "烘焙展示窗"),
+        (35870, "開心騎兵褲"),
+        (6651, "顯微鏡"),
+        (42471, "卡扎納爾雙牙"),
+        (2816, "亞麻獵鹿帽"),
+        (42214, "粉綠柱石治愈手鐲"),
+        (2355, "業餘銼刀"),
+        (10784, "青麻強襲軟甲褲"),
+        (13052, "烹飪大師矮靴"),
+        (12030, "硬銀大地耳環"),
+        (10706, "飛龍革禦敵長靴"),
+        (41912, "混金詠咒指環"),
+        (28752, "重建用的石材"),
+        (19944, "翠銀錠"),
+        (5327, "亞麻布"),
+        (48301, "加工檢驗用的木材"),
+        (19577, "鉬鋼鶴嘴鋤"),
+        (48396, "宇宙馬登時效鋼錠"),
+        (42876, "舊日王國咒杖"),
+        (18256, "白銅詠咒項環"),
+        (3149, "秘銀胸甲"),
+        (48366, "統一規格的鏈條板"),
+        (44223, "收藏用獰豹革魔導典"),
+        (26285, "矮人銀盾"),
+        (2500, "白鋼平底鍋"),
+        (42354, "嵌齒象革詠咒筒靴"),
+        (19647, "嗶嘰大地包頭巾"),
+        (44414, "探求永恆工藝錘"),
+        (19624, "牛鬼革巧匠手套"),
+        (25697, "烏鋼石詠咒項環"),
+        (31197, "第三期重建用的布料"),
+        (2370, "黑鐵鑄甲錘"),
+        (6675, "海濱橢圓地毯"),
+        (16681, "半熊革詠咒露趾長靴"),
+        (3911, "古典長靴"),
+        (25696, "烏鋼石治愈項環"),
+        (7502, "蛟蜥精准筒靴"),
+        (43616, "綠色向日葵頭花"),
+        (4097, "硬革手環"),
+        (31853, "總督精准長靴"),
+        (19734, "牛鬼革項環"),
+        (39728, "8級巧力之幻藥"),
+        (35334, "馬栗木泥岩砂輪機"),
+        (15603, "圓桌之刀"),
+        (13795, "亞拉戈牧杖（複製品）"),
+        (23837, "羅剎鬥士手飾"),
+        (18687, "鉬鋼強襲護臂"),
+        (27190, "纏尾蛟革大地工作靴"),
+        (2450, "秘銀縫針"),
+        (28974, "芝士櫃"),
+        (34097, "輝翠銀蛇石針"),
+        (16716, "剛力之強化仙藥"),
+        (17335, "空鼠治愈鐵鈎筒靴"),
+        (14830, "遠徵外套"),
+        (17020, "毛線籃"),
+        (35064, "古典風格雙劍鬥士戰靴"),
+        (39563, "緋光重刺劍"),
+        (4646, "牛肉乾"),
+        (38770, "面向收藏家的聖靈藥瓶"),
+        (24576, "釀造奶酒必需品的部件"),
+        (40649, "東方室內竹景噴泉"),
+        (27142, "矮人銀圓頭錘"),
+        (3502, "絹布馬褲"),
+        (25700, "島狼精准手鐲"),
+        (42041, "錘頭鰐革治愈長靴"),
+        (7113, "莊園竪琴"),
+        (25025, "神聖女神之戟"),
+        (27084, "深金秧雞平底鍋"),
+        (17606, "生命之斧"),
+        (10770, "長頸駝革強襲護臂"),
+        (9734, "橡木低矮花盆"),
+        (4516, "盜龍革指環"),
+        (33295, "林間涼亭"),
+        (21822, "麵包籃"),
+        (31869, "總督詠咒手套"),
+        (31100, "混色秋英頭花"),
+        (47595, "以太抗性高的特殊保護劑"),
+        (42514, "黑星石禦敵項環"),
+        (6552, "莊園衣櫃"),
+        (11012, "精准虹布緞帶"),
+        (28762, "重建用的防水布"),
+        (27986, "緹坦妮雅裝甲"),
+        (26284, "矮人銀輪刃"),
+        (42900, "舊日王國制敵護膝褲"),
+        (22742, "陸地蹦極用品"),
+        (2323, "秘銀手鋸"),
+        (18265, "姆德斯德角治愈指環"),
+        (35067, "古典風格醫護兵腕環"),
+        (46983, "吸引器的吸嘴"),
+        (35037, "古典風格蛇石針"),
+        (27141, "矮人銀橫頭錘"),
+        (4674, "小扁豆煮山栗"),
+        (48358, "宇宙探索用的挖掘鑽頭"),
+        (22440, "樹籬隔離牆"),
+        (10726, "靈銀制敵手鎧"),
+        (43257, "鈦金平斬"),
+        (32802, "小牛皮騎手運動鞋"),
+        (12996, "騎士禦敵外套"),
+        (36876, "石制雪峰公館外牆"),
+        (40403, "混色菊花頭花"),
+        (4599, "剛力之妙藥"),
+        (36233, "6級巧力之幻水"),
+        (3501, "絹布軟甲褲"),
+        (48583, "黃色宇宙煉金藥"),
+        (20706, "東洋石制煙囪"),
+        (22721, "供台部件"),
+        (35029, "古典風格長玉杖"),
+        (7539, "春意草帽"),
+        (3372, "棉絨束膝褲"),
+        (9309, "工匠手鉗"),
+        (39553, "緋光戰弓"),
+        (6356, "木制林間小屋房頂"),
+        (19988, "玉絲"),
+        (14722, "荒神革強襲頭甲"),
+        (1764, "黑鐵鈎斧"),
+        (10914, "星絹布治愈打底褲"),
+        (9514, "高質的羊皮紙"),
+        (44425, "探求永恆砂輪機"),
+        (37792, "復興詠咒兜帽"),
+        (5087, "黃銅環"),
+        (15776, "2級水族箱"),
+        (34289, "棕櫚詠咒耳夾"),
+        (36700, "條約手鉗"),
+        (1681, "獸骨骨拳"),
+        (26182, "三重石詠咒手鐲"),
+        (11899, "仙人刺煎鍋"),
+        (20731, "東洋睡床"),
+        (10716, "精金制敵頭盔"),
+        (31186, "第三期重建用的金屬板"),
+        (10591, "精金翼劍"),
+        (39874, "窯烤盜龍腿"),
+        (47947, "白色葉子花耳環"),
+        (2381, "青銅手鉗"),
+        (10705, "靈銀禦敵鎧靴"),
+        (26011, "白梣木治愈耳墜"),
+        (19573, "鉬鋼制革刀"),
+        (31114, "改良用呢絨"),
+        (4100, "白珊瑚腕環"),
+        (21714, "真麻禦敵長裙"),
+        (22917, "黑柳巧匠項鍊"),
+        (3654, "鈷鐵臂甲"),
+        (44195, "收藏用卡扎納爾戰鐮"),
+        (31052, "收藏用紅玉棉布背心"),
+        (2125, "獸角長杖"),
+        (19882, "名匠藥茶"),
+        (48130, "風乾火腿"),
+        (15921, "月火帽"),
+        (14264, "管弦樂琴樂譜：白銀凶鳥展翅翱翔"),
+        (27198, "海燕革手套"),
+        (36699, "條約銼刀"),
+        (18096, "鋰輝石禦敵項環"),
+        (5813, "帝王紫染劑"),
+        (33249, "奶油黃地毯"),
+        (19611, "阿拉米格釣竿"),
+        (48432, "宇宙探索用的收納罐"),
+        (34403, "賽加羚羊革禦敵軟甲褲"),
+        (6981, "高地長靴"),
+        (6518, "妖精座椅"),
+        (27369, "玉鋼槍刃"),
+        (1781, "秘銀刺柄雙刃斧"),
+        (36131, "月讀命幽夜騎士武具"),
+        (20367, "厚土花劍"),
+        (44032, "犎牛嗶嘰"),
+        (16663, "半熊革精准護腿"),
+        (23798, "羅剎拳士手甲"),
+        (13808, "亞拉戈重鎖甲靴（複製品）"),
+        (3487, "煉金軟褲"),
+        (43894, "輝鱗族樣式的裝幀部件"),
+        (23802, "羅剎弓士眼帶"),
+        (18040, "硬鋼重炮"),
+        (19883, "巨匠藥茶"),
+        (43341, "黑星石巧匠圍巾"),
+        (26171, "三重石治愈耳墜"),
+        (48425, "基地備用的照明"),
+        (6579, "木制屏風"),
+        (25985, "野蠻盜龍革精准護甲"),
+        (31074, "收藏用藥水"),
+        (12858, "魔蛇鳥肉丸"),
+        (15778, "4級水族箱"),
+        (10762, "聖虹強襲襯衫"),
+        (22725, "交易品部件"),
+        (35996, "紫色紫燈花耳環"),
+        (48283, "耐用優質紙"),
+        (31227, "第三期重建用的特供時鐘"),
+        (12919, "碧藍高位附魔墨水"),
+        (5173, "螢石"),
+        (37782, "復興游擊兜帽"),
+        (42335, "白黃金精准面具"),
+        (30081, "神龍夢幻刀"),
+        (10977, "靈銀禦敵耳環"),
+        (35629, "收藏用鐵木泥岩砂輪機"),
+        (37747, "復興小刀"),
+        (26334, "透鋰長石強攻項環"),
+        (9030, "烏茲鎧靴"),
+        (3341, "新來者工作褲"),
+        (33902, "再品身負重擔之苦"),
+        (15551, "樟木紡車"),
+        (5328, "呢絨"),
+        (36046, "開心汁"),
+        (24182, "黑色馬蹄蓮頭花"),
+        (36173, "月精金錠"),
+        (2546, "青銅手斧"),
+        (7613, "玄鋼鈎"),
+        (19811, "果仁蜜餅"),
+        (12015, "祝聖栗木腕環"),
+        (41147, "薩雷安反射水池"),
+        (3090, "棉絨僧袍"),
+        (48351, "收納箱部件"),
+        (8293, "鑽石手鐲"),
+        (36130, "白帝騎士武具"),
+        (2348, "白鋼橫頭錘"),
+        (14050, "軟糊怪沙發"),
+        (2872, "綠金頭冠（鋯石）"),
+        (36050, "辣味番茄燉蛋"),
+        (21844, "爆彈怪火爐"),
+        (32237, "烘焙展示櫃"),
+        (18707, "瞪羚革治愈法靴"),
+        (6367, "石制綠洲小屋房頂"),
+        (37841, "7級巧力之幻藥"),
+        (48347, "改良設備用的工作台"),
+        (27187, "纏尾蛟革大地長袍"),
+        (48513, "加工檢驗用的布"),
+        (21932, "白色鬱金香腕帶"),
+        (25628, "深金雙刃劍"),
+        (31867, "總督詠咒寬邊帽"),
+        (1603, "青銅維京劍"),
+        (2934, "特務小丑帽"),
+        (48391, "大型設備用的耐高溫內部材料"),
+        (37814, "復興精准戒指"),
+        (36238, "錳鋼附魔墨水"),
+        (41593, "習武工作褲"),
+        (10392, "薩維奈頭紗"),
+        (20397, "皇金刺劍"),
+        (9037, "烏茲戰鎧"),
+        (46034, "儀仗禦敵臂甲"),
+        (14835, "遠徵熱褲"),
+        (17969, "東方午餐組合：烏冬面"),
+        (20389, "靈銀刺劍"),
+        (48291, "宇宙探索用的木箱"),
+        (27165, "斯劍虎革巧匠工作靴"),
+        (28475, "中庸巧匠軟甲褲"),
+        (39721, "恐鰐鞣革"),
+        (34294, "白鑞詠咒項環"),
+        (48350, "耐衝擊隔離牆"),
+        (22733, "牛毛剪刀部件"),
+        (28849, "空鼠制敵格鬥服（複製品）"),
+        (1879, "冠軍騎槍"),
+        (12520, "硬銀塊"),
+        (8811, "毛巾掛"),
+        (12231, "致遠帆布"),
+        (12005, "奇美纖維軟甲褲"),
+        (48424, "基地備用照明的部件"),
+        (3038, "棉布大地厚袍"),
+        (36058, "厄爾庇斯之面"),
+        (13819, "亞拉戈精准面罩（複製品）"),
+        (34709, "蛇牛革制敵皮靴"),
+        (33036, "混色康乃馨耳環"),
+        (5508, "鱗膠"),
+        (18195, "硬鋼巨劍"),
+        (8000, "直線踏腳木"),
+        (2415, "民兵砂輪機"),
+        (3385, "亞麻大地垮褲"),
+        (36198, "紅松木材"),
+        (32934, "銀山毛櫸木材"),
+        (18100, "鋰輝石詠咒項環"),
+        (41429, "仿制魔導維修站地台"),
+        (42051, "河岸石詠咒耳墜"),
+        (28847, "空鼠禦敵鐵鈎筒靴（複製品）"),
+        (34740, "星點石英禦敵項環"),
+        (15536, "光銀橫頭錘"),
+        (2457, "梣木紡車"),
+        (18524, "玉鋼制敵罩衣"),
+        (25804, "深金圓月輪"),
+        (6411, "海濱鑲釘門"),
+        (26485, "中庸詠咒打底褲"),
+        (8374, "厚土大斧"),
+        (42171, "獰豹革制敵頭帶"),
+        (6407, "綠洲方窗"),
+        (29824, "第二期重建用的睡床"),
+        (41267, "面向收藏家的貓小胖沙發"),
+        (23891, "壁掛餐盤置物架"),
+        (46086, "儀仗詠咒指環"),
+        (38887, "對菌類兵器"),
+        (2576, "紫杉釣竿"),
+        (19833, "油炸蟹肉餅"),
+        (44201, "收藏用卡扎納爾脛甲"),
+        (21933, "黑色鬱金香腕帶"),
+        (31043, "收藏用野蠻盜龍革工作帽"),
+        (12542, "虎眼石"),
+        (42043, "高山亞麻詠咒鬥篷"),
+        (12094, "玩具箱"),
+        (39555, "緋光巨劍"),
+        (13005, "珍木大師手鋸"),
+        (34539, "鐵木法杖"),
+        (8856, "1級美食以太轉輪"),
+        (31143, "用於擴張據點的部件"),
+        (17934, "多瑪飯團"),
+        (25865, "硬水鋁石精准指環"),
+        (7092, "寶石獸地毯"),
+        (48329, "統一規格的合金鉚釘"),
+        (4285, "玫瑰金耳夾"),
+        (2414, "石蜥蜴砂輪機"),
+        (31049, "收藏用祝聖青麻工作服"),
+        (48532, "宇宙探索用的飛船制服"),
+        (48266, "試驗加工用的布料"),
+        (9726, "林間午茶組合"),
+        (27241, "內襯的修復素材"),
+        (28515, "中庸研鉢"),
+        (47723, "變異菌床的提取液"),
+        (36323, "輝煌工具的第二次加工部件：制革"),
+        (4430, "黃銅戒指"),
+        (19544, "卸鐵研鉢"),
+        (25660, "白麻強襲長手套"),
+        (6467, "綠洲吊燈"),
+        (19885, "強精煉藥"),
+        (18062, "赤麻強襲坎肩"),
+        (17896, "白鈦手甲"),
+        (10789, "古鳥革強襲長靴"),
+        (48498, "耐用棉線"),
+        (27080, "深金工藝錘"),
+        (36889, "雪峰吊燈"),
+        (41877, "銀狼革精准戰靴"),
+        (9034, "白金禦敵圍巾"),
+        (12848, "焦糖牛奶沙司"),
+        (1965, "低語梣木幻杖"),
+        (25825, "虹繭精准棉甲"),
+        (29835, "第二期重建用的特供浴缸"),
+        (19566, "玉鋼廚刀"),
+        (43228, "鈷鎢陸行鳥煎鍋"),
+        (11021, "蛋白石精准手鐲"),
+        (18724, "鈀金詠咒項環"),
+        (7980, "拉姆等離子燈"),
+        (30378, "複製內牆"),
+        (48306, "基地雙層床"),
+        (42937, "舊日王國禦敵手環"),
+        (35326, "輝翠銀蒸餾器"),
+        (2365, "民兵銼刀"),
+        (3052, "棉布巧匠工作坎肩"),
+        (1850, "蟹甲漁槍"),
+        (31081, "收藏用果仁蜜餅"),
+        (16620, "橙色三色堇頭花"),
+        (30072, "神龍夢幻大弓"),
+        (48573, "宇宙探索用的秘藥材料"),
+        (36184, "弗里金錠"),
+        (38851, "搭建活動台必需品"),
+        (7963, "冰鎮果汁"),
+        (2925, "衣匠高禮帽"),
+        (5190, "黃玉"),
+        (28828, "空賊游擊面具（複製品）"),
+        (5761, "沃土棕染劑"),
+        (3447, "盜龍革三角褲"),
+        (13794, "亞拉戈復合弓（複製品）"),
+        (27112, "白梣木砂輪機"),
+        (37700, "獺獺噴泉建造用金屬材料"),
+        (42893, "舊日王國禦敵胸甲"),
+        (41836, "奧闊鉻鐵曲劍"),
+        (10411, "賢王莫古巨劍"),
+        (4695, "黑松露燴飯"),
+        (25633, "深金五指劍"),
+        (36695, "條約鶴嘴鋤"),
+        (42155, "鈷鎢手炮"),
+        (13923, "龍神遊擊鬥服（複製品）"),
+        (42052, "河岸石禦敵項環"),
+        (1915, "梣木騎兵弓"),
+        (5066, "綠金錠"),
+        (3866, "野豬革狩獵靴"),
+        (47721, "輔食果凍"),
+        (48448, "耐用皮革"),
+        (4264, "琥珀耳墜"),
+        (34586, "藍鋯石強攻耳墜"),
+        (48447, "統一規格的皮衣"),
+        (4703, "拉諾西亞吐司"),
+        (29421, "伊修加德新型騎士盾"),
+        (2047, "樹靈神"),
+        (34587, "藍鋯石精准耳墜"),
+        (10617, "靈銀刺刀"),
+        (45995, "3級剛力之寶藥"),
+        (26314, "矮人銀游擊短靴"),
+        (21092, "東方風格的收藏品部件"),
+        (38583, "奇妙門窗"),
+        (2529, "鈷鐵鶴嘴鎬"),
+        (21931, "紫色鬱金香腕帶"),
+        (19962, "鋰輝石"),
+        (26493, "中庸禦敵項環"),
+        (41840, "混金長弓"),
+        (34105, "輝翠銀制敵面罩"),
+        (10790, "飛龍革強襲長靴"),
+        (15543, "光銀鶴嘴鋤"),
+        (3886, "龜甲鱗脛甲"),
+        (31852, "總督精准打底褲"),
+        (27162, "白麻巧匠工作服"),
+        (6548, "木制櫃櫥"),
+        (5581, "駿鷹革護肩"),
+        (2342, "業餘橫頭錘"),
+        (31929, "第四期重建用的紡車"),
+        (36333, "輝煌工具的第三次加工部件：煉金"),
+        (13903, "龍神強襲鬥服（複製品）"),
+        (15959, "企業級飛空艇模型"),
+        (2959, "草布大地厚袍"),
+        (8813, "林間門墊"),
+        (41513, "橙色魯冰花頭花"),
+        (41879, "雪木棉游擊束腰獵衣"),
+        (16617, "藍色三色堇頭花"),
+        (30496, "龍族用招待菜餚必需品"),
+        (28741, "重建用的木桶"),
+        (35409, "馬栗木笠"),
+        (39667, "繼業者精准半指手套"),
+        (15745, "特制獎勵"),
+        (3127, "白鋼重甲"),
+        (27186, "力山羊大地包頭巾"),
+        (41994, "木棉木長弓"),
+        (17339, "空鼠詠咒馬褲"),
+        (48625, "面向兔兔族的白滴糖"),
+        (5068, "玫瑰金塊"),
+        (19748, "櫸木耳墜"),
+        (34600, "藍鋯石禦敵指環"),
+        (42058, "河岸石強攻手鐲"),
+        (29654, "改良用的連接板"),
+        (25842, "虹繭詠咒帽"),
+        (11011, "貴橄欖石強攻戒指"),
+        (7999, "弧形踏腳石"),
+        (48395, "宇宙探索用的以太吸收機"),
+        (10916, "飛龍革治愈長靴"),
+        (34282, "阿爾馬斯提詠咒手套"),
+        (31223, "第三期重建用的燉菜"),
+        (30465, "唯美巧匠筒靴"),
+        (27135, "沙柚木紡車"),
+        (8388, "賢王莫古長杖"),
+        (26305, "海燕革精准長袍"),
+        (42921, "舊日王國治愈皮鞋"),
+        (19659, "嗶嘰工作手套"),
+        (34106, "伽迦象革制敵坎肩"),
+        (7492, "蛟蜥強襲頭巾"),
+        (11926, "精金加固手鉗"),
+        (34144, "紫黃晶詠咒項環"),
+        (6390, "混合制林間別墅外牆"),
+        (26276, "愈瘡木矮人牧杖"),
+        (22569, "南海廚房"),
+        (3383, "亞麻垮褲"),
+        (8012, "花紋內牆"),
+        (11959, "奇美纖維巧匠包頭巾"),
+        (41860, "銀狼革禦敵半指護手"),
+        (3471, "盜龍革襯褲"),
+        (21709, "清銀刺劍"),
+        (38869, "氣密性防護服部件"),
+        (35661, "收藏用賢人麵包"),
+        (2399, "白鋼裝飾錘"),
+        (26146, "纏尾蛟革精准半指護手"),
+        (34248, "輝翠銀戰鐮"),
+        (2788, "野豬革盔帽"),
+        (36870, "石制雪峰別墅房頂"),
+        (48528, "基地加工布"),
+        (4576, "沈默毒藥"),
+        (34268, "山雞革精准騎兵褲"),
+        (4651, "渡渡鳥蛋煎蛋捲"),
+        (4712, "血紅奇異果撻"),
+        (42374, "白黃金詠咒指環"),
+        (2958, "草布厚袍"),
+        (48524, "宇宙飛線用的纖維"),
+        (44102, "野生香蕉果昔"),
+        (27856, "蠶豆湯"),
+        (21916, "迷你莫艾石像"),
+        (1637, "胡桃石刃棒"),
+        (44421, "探求永恆釣竿"),
+        (10626, "精金雙手劍"),
+        (34589, "藍鋯石詠咒耳墜"),
+        (14177, "巧力之仙藥"),
+        (15924, "月火男式綁帶涼鞋"),
+        (32206, "綠寶石神兵像"),
+        (45977, "克羅諾獸革"),
+        (4854, "可可油"),
+        (25972, "靈青制敵頭盔"),
+        (48367, "宇宙探索用的面罩"),
+        (30442, "唯美圓頭錘"),
+        (3802, "黑鐵長筒靴"),
+        (3808, "山羊革尖頭靴"),
+        (48097, "人字形內牆"),
+        (46853, "超輕菌類建材"),
+        (38932, "工匠的幻水"),
+        (43644, "棕櫚圓筆"),
+        (25975, "野蠻盜龍革制敵馬褲"),
+        (7090, "寶石獸座椅"),
+        (10525, "白銀天球儀"),
+        (30995, "收藏用鉬鋼手鉗"),
+        (3022, "棉布襯衫"),
+        (33605, "白帝錫杖"),
+        (17315, "空鼠制敵鐵鈎筒靴"),
+        (18091, "鋰輝石禦敵耳墜"),
+        (48340, "統一規格的連接板"),
+        (17983, "東方茶屋長椅"),
+        (18352, "卸鐵重炮"),
+        (27153, "矮人銀手鉗"),
+        (36071, "白桃汁"),
+        (29659, "改良用的玻璃板"),
+        (36239, "月凝膠"),
+        (4224, "天青石耳墜"),
+        (5505, "鬼膠"),
+        (9092, "奇蜥魔典"),
+        (46045, "儀仗強襲寬松直筒褲"),
+        (34095, "輝翠銀魔機刃"),
+        (42010, "木棉木圓筆"),
+        (19720, "阿拉米格巧匠打底褲"),
+        (2663, "馬僕草帽"),
+        (6143, "鹽烤求雨魚"),
+        (27087, "白橡木釣竿"),
+        (5277, "山羊革"),
+        (39557, "緋光牧杖"),
+        (35760, "深金戰鐮"),
+        (2402, "綠金工藝錘"),
+        (43342, "黑星石巧匠手鐲"),
+        (26440, "北河三天儀"),
+        (6214, "盧恩人玩偶"),
+        (36113, "6級意力之幻藥"),
+        (36012, "聖光裝甲"),
+        (18556, "嗶嘰詠咒騎兵褲"),
+        (7573, "薩赫蛋糕"),
+        (42208, "深紅木精准項鍊"),
+        (37844, "7級意力之幻藥"),
+        (42898, "舊日王國制敵胸甲"),
+        (11947, "靈銀碎石錘"),
+        (32800, "小牛皮騎手手套"),
+        (27223, "矮人銀項環"),
+        (15518, "加隆德巧匠馬褲"),
+        (22434, "燉點"),
+        (30494, "龍族用縫紉工具"),
+        (37299, "白色比爾格紫羅蘭項環"),
+        (34093, "輝翠銀武士刀"),
+        (10050, "豹目眼鏡"),
+        (25846, "綠飄龍革詠咒筒靴"),
+        (39639, "繼業者法杖"),
+        (42912, "舊日王國游擊兜帽"),
+        (41090, "閱讀凳子"),
+        (44152, "2級剛力之寶水"),
+        (31950, "第四期重建用的遮蓬"),
+        (2884, "鈷鐵鎖甲頭罩"),
+        (44990, "皓光重刺劍"),
+        (38557, "夢羽妖靈王環刃"),
+        (9766, "圓點內牆"),
+        (48256, "宇宙探索用的耐壓金屬板"),
+        (25662, "斯劍虎革強襲露趾長靴"),
+        (13747, "鈦合金錠"),
+        (5378, "雲杉木材"),
+        (41105, "舷窗隔離牆"),
+        (2444, "蝠牙縫針"),
+        (41066, "應對眼疲勞的必需品"),
+        (37347, "古典梳妝台"),
+        (4506, "碧璽戒指"),
+        (42472, "克拉洛胡桃木平筆"),
+        (43277, "克拉洛胡桃木砂輪機"),
+        (10792, "白鈦強襲鎧靴"),
+        (19718, "阿拉米格巧匠工作坎肩"),
+        (19740, "柿木手鍊"),
+        (15915, "獄火法杖"),
+        (41264, "面向收藏家的號角"),
+        (34443, "弗里金治愈項環"),
+        (11989, "飛龍革皮衣"),
+        (16648, "重合金制敵胸甲"),
+        (31141, "主炮的重要部件"),
+        (5275, "鞣革"),
+        (21754, "清銀強攻耳墜"),
+        (3211, "纖維外袍"),
+        (30846, "青君長槍"),
+        (45996, "3級巧力之寶藥"),
+        (4861, "餡餅坯"),
+        (26500, "中庸精准手鐲"),
+        (21718, "夜鋼制敵戰鎧"),
+        (25849, "硬水鋁石強攻耳墜"),
+        (6601, "海濱圓桌"),
+        (35794, "聖光獎杯"),
+        (10924, "硬銀作業眼鏡"),
+        (46078, "儀仗強攻手鐲"),
+        (42919, "舊日王國治愈手套"),
+        (29960, "印茄木材"),
+        (4662, "鮭魚排"),
+        (2386, "白鋼手鉗"),
+        (29819, "第二期重建用的石材"),
+        (28142, "莫古力室內鞋"),
+        (43646, "紅松木平筆"),
+        (17955, "壁掛式花瓶"),
+        (34431, "雪亞麻詠咒長袍"),
+        (44827, "橙色卡特蘭頭花"),
+        (19037, "榧木禦敵手鐲"),
+        (2804, "亞麻巧匠無檐帽"),
+        (35660, "收藏用藥酒"),
+        (36123, "無盡雲神騎士武具"),
+        (39431, "多彩頂燈"),
+        (8449, "苔角耳墜"),
+        (35462, "完滿木巧匠手鐲"),
+        (10751, "精金制敵鎧靴"),
+        (16660, "星絨精准罩衣"),
+        (31880, "總督精准項環"),
+        (30812, "月讀命幽夜三日月"),
+        (38923, "研究者巧匠手鐲"),
+        (26179, "三重石強攻手鐲"),
+        (13916, "龍神治愈皮靴（複製品）"),
+        (15521, "碳纖布巧匠圍裙"),
+        (26313, "矮人棉游擊束膝褲"),
+        (36227, "5級剛力之幻水"),
+        (26013, "野蠻盜龍革禦敵項鍊"),
+        (2861, "鈷鐵口罩"),
+        (4552, "高級恢復藥"),
+        (8007, "海濱葉紋內牆"),
+        (39551, "緋光戰斧"),
+        (3601, "棉絨工作手套"),
+        (28757, "重建用的睡床"),
+        (15576, "無盡雲神巨劍"),
+        (27178, "野蠻盜龍革大地手套"),
+        (46055, "儀仗游擊寬松直筒褲"),
+        (10648, "長頸駝革魔導書"),
+        (31900, "奶油雞肉寬面"),
+        (2094, "水華咒角"),
+        (36170, "鉍金錠"),
+        (14239, "管弦樂琴樂譜：以血洗血"),
+        (30819, "月讀命幽夜錫杖"),
+        (2568, "秘銀鐮刀"),
+        (30988, "收藏用白鈦制革刀"),
+        (43229, "鈷鎢鶴嘴鋤"),
+        (18406, "星尖石治愈耳墜"),
+        (8853, "1級名匠以太轉輪"),
+        (2270, "盜龍革皮盾"),
+        (14069, "伊修加德地板"),
+        (4746, "葡萄汁"),
+        (48373, "基地咖啡機"),
+        (38815, "牧羊工具"),
+        (26496, "中庸治愈項環"),
+        (15962, "塔塔諾拉級飛空艇模型"),
+        (17609, "生命之刀"),
+        (39872, "燒烤暗色茄子"),
+        (34751, "星點石英強攻指環"),
+        (18205, "硬鋼禦敵開面盔"),
+        (18951, "琅玕精准耳墜"),
+        (28510, "中庸銼刀"),
+        (12991, "正教裝甲"),
+        (1820, "獸骨漁槍"),
+        (35591, "黃金髮聲零件"),
+        (42891, "舊日王國步兵盾"),
+        (10750, "白鈦制敵鎖甲靴"),
+        (25043, "無限鬼神之刀"),
+        (15961, "奧德賽級飛空艇模型"),
+        (24562, "伊修加德菜餚必需品"),
+        (12895, "發酵黃油"),
+        (48591, "基地野餐籃"),
+        (6379, "石制海濱公館外牆"),
+        (27220, "白梣木指環"),
+        (4644, "山羊肉排"),
+        (48543, "基地蠟燭"),
+        (43221, "鈷鎢手鋸"),
+        (2517, "鈷鐵廚刀"),
+        (48307, "特制木匠工作台"),
+        (25676, "白麻治愈帽"),
+        (44232, "收藏用烤牛肉夾餅"),
+        (31902, "黃金菠蘿汁"),
+        (5765, "蜂蜜黃染劑"),
+        (34140, "紫黃晶禦敵項環"),
+        (40407, "樸素寢室拖鞋"),
+        (8286, "紅寶石項環"),
+        (5081, "青銅環"),
+        (38914, "研究者巧匠軟甲褲"),
+        (2484, "黑鐵研鉢"),
+        (34276, "阿爾馬斯提治愈外套"),
+        (44431, "探求永恆園藝鐮"),
+        (27233, "金屬制的食材容器"),
+        (1905, "革裝榆木長弓"),
+        (21920, "白小虎"),
+        (37834, "楊梅染布"),
+        (34256, "輝翠銀制敵戰甲"),
+        (11002, "硬銀強攻耳墜"),
+        (1806, "巨膽戰斧"),
+        (31101, "改良用雪松木材"),
+        (25839, "虹繭治愈打底褲"),
+        (11068, "硬銀詠咒手鐲"),
+        (42484, "黑星石強襲頭甲"),
+        (22730, "鯰魚釣具"),
+        (18726, "鈀金強攻手環"),
+        (1663, "鈷鐵翼劍"),
+        (25691, "烏鋼石治愈耳墜"),
+        (6514, "林間座椅"),
+        (27891, "高級烤牛肉"),
+        (3028, "棉布短袖罩衣"),
+        (29470, "伊修加德新型強攻項環"),
+        (3140, "亞麻僧袍"),
+        (47920, "保鏢背心"),
+        (36052, "北洋酸奶"),
+        (48189, "聖輝環刃"),
+        (38553, "夢羽妖靈王天球儀"),
+        (4691, "貓魅風味森鮮烤串"),
+        (34130, "伽迦象革詠咒頭冠"),
+        (11034, "獅鷲利爪精准戒指"),
+        (15564, "驚雷轟鳴"),
+        (21816, "白虎邊桌"),
+        (10463, "白鋼火槍"),
+        (3603, "棉絨禮服手套"),
+        (36252, "兩棲犀革"),
+        (13874, "亞拉戈高位詠咒長衣（複製品）"),
+        (43330, "五加木手鐲"),
+        (48368, "無人機用的金屬絲"),
+        (18064, "赤麻強襲襯褲"),
+        (4679, "澤梅爾家風味焗菜"),
+        (27960, "極精煉藥"),
+        (43241, "鈷鎢園藝鐮"),
+        (18369, "卸鐵制敵手鎧"),
+        (28463, "中庸圓頭錘"),
+        (37808, "復興強攻手鐲"),
+        (33225, "紀念醫院的醫療用品"),
+        (2061, "黃銅咒杖"),
+        (19549, "玉鋼橫頭錘"),
+        (44215, "收藏用雪木棉貝雷帽"),
+        (24251, "常輝鋼錠"),
+        (20392, "硬銀打刀"),
+        (41421, "魔法樹建造用部件"),
+        (11083, "貴橄欖石詠咒戒指"),
+        (23811, "羅剎間士袴"),
+        (34250, "白鑞禦敵頭冠"),
+        (42356, "白黃金強攻耳墜"),
+        (30977, "收藏用落葉松項鍊"),
+        (28833, "空賊治愈航空帽（複製品）"),
+        (18361, "翠銀禦敵發夾"),
+        (6604, "冥鬼圓桌"),
+        (20813, "混色光明百合頭花"),
+        (27124, "鈦銅縫針"),
+        (40663, "黃昏之劍展示台"),
+        (18525, "玉鋼制敵手鎧"),
+        (10639, "樺木法杖"),
+        (13678, "空賊詠咒手套"),
+        (26273, "矮人銀切割刃"),
+        (5481, "玫瑰金附魔墨水"),
+        (42943, "舊日王國強攻指環"),
+        (33267, "窗戶隔離牆"),
+        (13097, "小仙女吊燈"),
+        (20686, "木制東洋別墅房頂"),
+        (44224, "收藏用魔匠藥液"),
+        (6347, "木制海濱小屋房頂"),
+        (4616, "御風藥"),
+        (6349, "石制海濱小屋房頂"),
+        (16622, "白色三色堇頭花"),
+        (4446, "孔雀石戒指"),
+        (43618, "紫色向日葵頭花"),
+        (28474, "中庸巧匠手套"),
+        (42500, "落雷絹治愈長衣"),
+        (7070, "衣匠織布機"),
+        (13845, "亞拉戈高位斷頭劍（複製品）"),
+        (21052, "飛行座椅"),
+        (39700, "繼業者禦敵盔甲戒指"),
+        (41262, "面向收藏家的添水"),
+        (30396, "戶外紅磚廚房"),
+        (42217, "粉綠柱石強攻指環"),
+        (48408, "宇宙探索用的縫針"),
+        (27101, "靈青工藝錘"),
+        (12007, "飛龍革工作靴"),
+        (3045, "黑鐵胸甲"),
+        (24253, "大袋鼠革"),
+        (34551, "錳鋼貝希摩斯王戰甲"),
+        (2248, "黑鐵鳶盾"),
+        (41269, "面向收藏家的辣醬炒全蟹"),
+        (35599, "特制添加劑"),
+        (26340, "透鋰長石精准手鐲"),
+        (42511, "黑星石精准耳夾"),
+        (3799, "山羊革便鞋"),
+        (12108, "星藍石八音盒"),
+        (10628, "靈銀明火槍"),
+        (42044, "錘頭鰐革詠咒護臂"),
+        (25642, "石金重刺劍"),
+        (6421, "林間草頂煙囪"),
+        (46062, "儀仗詠咒兜帽"),
+        (5176, "透綠柱石"),
+        (35991, "紅色紫燈花耳環"),
+        (3023, "棉布束腰牧衣"),
+        (26450, "中庸禦敵鎧靴"),
+        (4701, "橡果餅乾"),
+        (30467, "唯美大地工作服"),
+        (32250, "海濱葡萄藤架"),
+        (2392, "青銅雕金錘"),
+        (13596, "迷你莫古力"),
+        (3728, "絹布治愈手套"),
+        (44823, "紅色卡特蘭頭花"),
+        (28517, "中庸碎石錘"),
+        (26464, "中庸精准包頭巾"),
+        (22885, "夜鋼橫頭錘"),
+        (16625, "星絹布長袍"),
+        (29681, "水晶珊瑚"),
+        (14928, "暗長頸駝革手套"),
+        (14260, "管弦樂琴樂譜：暗淡"),
+        (7777, "黃麻線"),
+        (3145, "亞麻大地短上衣"),
+        (37757, "復興魔機刃"),
+        (19586, "鉬鋼研鉢"),
+        (14930, "長頸駝革長靴"),
+        (31040, "收藏用瞪羚革護臂"),
+        (40402, "黑色菊花頭花"),
+        (10831, "古鳥革精准尖頭靴"),
+        (44167, "名匠藥液"),
+        (9225, "巨膽屠刀"),
+        (27634, "改良型筒靴"),
+        (41893, "五加木禦敵耳墜"),
+        (27665, "改良型牛皮長靴"),
+        (23824, "羅剎術士草履"),
+        (26272, "愈瘡木矮人復合弓"),
+        (48109, "東洋書院窗"),
+        (22884, "夜鋼鋸"),
+        (31918, "第四期重建用的草繩"),
+        (19032, "榧木禦敵項環"),
+        (18544, "魔象革游擊馬褲"),
+        (41874, "雪木棉精准長衣"),
+        (24280, "番茄燉蛋"),
+        (19620, "赤麻大地軟甲褲"),
+        (21750, "真麻詠咒馬褲"),
+        (3570, "棉布弓術手套"),
+        (35360, "鉍金碎石錘"),
+        (14080, "薩菲洛特裝甲"),
+        (10410, "厚土斷頭劍"),
+        (34138, "紫黃晶治愈耳墜"),
+        (24262, "3級巧力之藥湯"),
+        (4421, "獸骨戒指"),
+        (24513, "木制橫梁"),
+        (4099, "硬革大地手環"),
+        (13822, "亞拉戈精准軟甲褲（複製品）"),
+        (39379, "花藝鳥籠"),
+        (33604, "白帝短銃"),
+        (6633, "妖精櫃台"),
+        (16639, "閃爍天星盤"),
+        (6469, "綠洲枝形吊燈"),
+        (38867, "錄音協助機開發部件"),
+        (24536, "園藝工的菜園"),
+        (18374, "葛布強襲束腰獵衣"),
+        (34603, "藍鋯石治愈指環"),
+        (37397, "伊修加德浴室"),
+        (8559, "弦月睡袍"),
+        (36328, "輝煌工具的第三次加工部件：鍛冶"),
+        (24525, "紅地毯"),
+        (7510, "羊絨詠咒寬邊帽"),
+        (16668, "半熊革游擊軟甲褲"),
+        (33901, "再陷心孤意怯之懼"),
+        (15737, "泉水修補部件"),
+        (6368, "木制綠洲公館房頂"),
+        (27242, "阿馬羅裝備的修復素材"),
+        (18568, "石青詠咒項環"),
+        (34723, "迷彩絨游擊打底褲"),
+        (18038, "硬鋼左手劍"),
+        (4600, "巧力之妙藥"),
+        (5172, "榍石"),
+        (35370, "錳鋼鶴嘴鋤"),
+        (25688, "烏鋼石禦敵耳墜"),
+        (2745, "白銀頭冠（海藍石）"),
+        (27957, "名匠藥水"),
+        (17886, "輕量短劍"),
+        (4681, "傑克南瓜燈"),
+        (10740, "古鳥革制敵馬褲"),
+        (19646, "魔象革巧匠尖頭鞋"),
+        (28838, "空賊詠咒航空帽（複製品）"),
+        (6643, "冥鬼花瓶"),
+        (34415, "賽加羚羊革精准儀仗帽"),
+        (3165, "毒蜥蜴革格鬥服"),
+        (31824, "總督魔導典"),
+        (37292, "圓桌之奪命鐮"),
+        (6402, "海濱凸窗"),
+        (16694, "柚木強攻手鐲"),
+        (2915, "白銀三角帽"),
+        (5200, "風眼石"),
+        (48245, "宇宙球粒隕石錠"),
+        (46073, "儀仗強攻項鍊"),
+        (21731, "地懶革精准手套"),
+        (46059, "儀仗治愈手套"),
+        (21817, "白虎寶座"),
+        (29831, "第二期重建用的燉菜"),
+        (26184, "三重石強攻指環"),
+        (2714, "新來者頭甲"),
+        (19610, "阿拉米格手斧"),
+        (35650, "收藏用蛇牛革上裝"),
+        (31185, "第三期重建用的合金"),
+        (35763, "深金奪命鐮"),
+        (23809, "羅剎間士羽織"),
+        (12564, "長頸駝革"),
+        (47345, "宇宙探索用的皮革細繩"),
+        (24592, "雨衣"),
+        (15539, "光銀圓革刀"),
+        (12866, "菜包肉"),
+        (6376, "石制海濱小屋外牆"),
+        (41872, "銀狼革強襲戰靴"),
+        (35439, "迷彩絨大地狩獵帽"),
+        (3416, "毒蜥蜴革三角褲"),
+        (9043, "織蛛強襲襯衫"),
+        (18363, "卸鐵禦敵手鎧"),
+        (48352, "基地備用的收納箱"),
+        (35359, "鉍金廚刀"),
+        (19750, "櫸木手鍊"),
+        (30472, "唯美巧匠耳夾"),
+        (36172, "球粒隕石錠"),
+        (43200, "奧闊鉻鐵手鋸"),
+        (36114, "名匠藥酒"),
+        (42159, "鈷鎢鋸刃"),
+        (19588, "鉬鋼碎石錘"),
+        (10610, "雪松長弓"),
+        (9718, "大型落地鐘"),
+        (46021, "儀仗重炮"),
+        (30448, "唯美鶴嘴鋤"),
+        (18574, "石青禦敵指環"),
+        (27253, "給妖靈的禮物"),
+        (17550, "寒冷地帶的特產"),
+        (18985, "鉻鐵禦敵頭盔"),
+        (30984, "收藏用沙柚木刺槍"),
+        (30836, "朱焰咒杖"),
+        (48246, "宇宙鈦鐵板"),
+        (27892, "高級清燉牛肉"),
+        (48392, "基地總部用的保險箱"),
+        (14942, "風絹打底褲"),
+        (41857, "奧闊鉻鐵塔盾"),
+        (30073, "神龍夢幻雙劍"),
+        (43329, "五加木項鍊"),
+        (39429, "多彩內牆"),
+        (10403, "秘銀雙手劍"),
+        (35420, "雪亞麻布大地外套"),
+        (36899, "雪峰懸掛門牌"),
+        (39916, "船景跳跳樂建造用合成樹脂"),
+        (6631, "林間櫃台"),
+        (43217, "奧闊鉻鐵研鉢"),
+        (12027, "巨龍革手環"),
+        (15723, "石像修復部件"),
+        (42365, "白黃金禦敵護身手鐲"),
+        (47229, "吸引器的精密零件"),
+        (6620, "對龍城塞指揮桌"),
+        (4827, "玉米粉"),
+        (13914, "龍神治愈手套（複製品）"),
+        (19606, "阿拉米格縫針"),
+        (42460, "卡扎納爾之典"),
+        (20310, "仿制方窗"),
+        (10805, "長頸駝革精准短衣"),
+        (29498, "莫爾斯"),
+        (36235, "6級智力之幻水"),
+        (10940, "安菲瑟龍革詠咒禮服手套"),
+        (17340, "空鼠詠咒黑鐵筒靴"),
+        (41065, "魅力十足的立體模型"),
+        (35418, "賽加羚羊革巧匠皮鞋"),
+        (34553, "金毗羅鰐革貝希摩斯王腿甲"),
+        (1740, "野性鋼拳"),
+        (5575, "七公六杖延齡花"),
+        (42920, "舊日王國治愈護膝褲"),
+        (11075, "蛋白石詠咒耳墜"),
+        (21768, "清銀禦敵戒指"),
+        (14848, "黑色古典玫瑰頭花"),
+        (22425, "3級智力之靈水"),
+        (38554, "夢羽妖靈王刀"),
+        (48188, "聖輝槍刃"),
+        (28835, "空賊治愈手套（複製品）"),
+        (12232, "鐵刀木木材"),
+        (6663, "大型戰船的舵盤"),
+        (10711, "精金禦敵鎖甲靴"),
+        (22448, "2級巧力之藥湯"),
+        (34126, "黑麻治愈長衣"),
+        (35042, "古典風格劍鬥士袖甲"),
+        (14060, "古代野獸的尾骨標本"),
+        (18082, "赤麻治愈短褲"),
+        (41907, "銀狼革詠咒手鐲"),
+        (7488, "鎢鋼手鎧"),
+        (17878, "高級化妝盒"),
+        (31056, "收藏用白麻長衫"),
+        (37788, "復興治愈外套"),
+        (18206, "硬鋼禦敵鏈甲"),
+        (4859, "蘋果醋"),
+        (26470, "中庸游擊包頭巾"),
+        (15740, "飛空艇模型"),
+        (28816, "空賊制敵鬥褲（複製品）"),
+        (26529, "柳杉原木"),
+        (4683, "烤仙人掌葉"),
+        (36251, "蛇牛革"),
+        (13044, "煉真大師長袍"),
+        (21771, "清銀治愈戒指"),
+        (31082, "收藏用牛肉串"),
+        (6450, "林間草頂圍牆"),
+        (6983, "冰河長靴"),
+        (12861, "芝士焗洋蔥湯"),
+        (4502, "紅電氣石戒指"),
+        (28132, "東方午餐組合：蒸蛋羹"),
+        (37400, "高架木制平台"),
+        (5810, "蓮花粉染劑"),
+        (17920, "高級春意禮服"),
+        (31139, "釀造設備的必需品"),
+        (18567, "石青治愈項環"),
+        (44831, "混色卡特蘭頭花"),
+        (28759, "重建用的街燈"),
+        (12565, "巨龍革"),
+        (2462, "胡桃紡車"),
+        (18225, "牛鬼革精准手套"),
+        (29449, "伊修加德新型游擊打底褲"),
+        (14713, "光銀禦敵胸甲"),
+        (4330, "天青石項環"),
+        (30979, "收藏用柿木手鍊"),
+        (21926, "紅色鬱金香腕帶"),
+        (25844, "綠飄龍革詠咒手套"),
+        (39311, "沙龍服務員打底褲"),
+        (10612, "暗栗木長弓"),
+        (26430, "復仇魔斧"),
+        (19614, "迦迦納怪鳥革半指手套"),
+        (5574, "薩倫伽光弓弓背"),
+        (18073, "白銅游擊包頭巾"),
+        (34450, "鉍金禦敵指環"),
+        (34434, "賽加羚羊革詠咒厚底鞋"),
+        (3772, "硬革戰靴"),
+        (4677, "香燴時蔬"),
+        (3315, "業餘工作褲"),
+        (37781, "復興精准鎧靴"),
+        (9515, "高質的安定粉"),
+        (5061, "玄鋼塊"),
+        (42302, "相思木長弓"),
+        (8113, "照明元件"),
+        (13684, "古鳥革筒靴"),
+        (35453, "鐵木巧匠項鍊"),
+        (36070, "南瓜濃湯"),
+        (36240, "黃色火藥"),
+        (2418, "業餘裁皮刀"),
+        (18691, "玉綢精准包頭巾"),
+        (36038, "庵摩羅果沙拉"),
+        (48285, "宇宙飛線用的樹脂"),
+        (31815, "總督大斧"),
+        (13905, "龍神強襲長褲（複製品）"),
+        (33300, "庭院鐵砧"),
+        (24522, "魔法稜晶袋"),
+        (3916, "盜龍革腿甲靴"),
+        (35388, "球粒隕石縫針"),
+        (6462, "海濱豪華吊燈"),
+        (34142, "紫黃晶精准項環"),
+        (34429, "賽加羚羊革治愈厚底鞋"),
+        (4682, "高山蘿蔔沙拉"),
+        (34735, "星點石英禦敵耳墜"),
+        (19815, "柿葉壽司"),
+        (28739, "重建用的植物油"),
+        (31932, "第四期重建用的裁縫工具"),
+        (3779, "梣木木鞋"),
+        (31663, "管弦樂琴樂譜：末路盡頭"),
+        (38761, "面向收藏家的裝飾帷幔"),
+        (13006, "鑌鐵大師鳥嘴錘"),
+        (31035, "收藏用安菲瑟龍革寬邊帽"),
+        (26447, "中庸禦敵戰鎧"),
+        (19843, "米醋"),
+        (9513, "高質的鉢杵"),
+    ],
+}
@@ -1,802 +1,802 @@
 &[
-RecipeLevel { job_level: 0, max_progress: 0, max_quality: 0, max_durability: 0, progress_div: 0, quality_div: 0, progress_mod: 0, quality_mod: 0, },
-RecipeLevel { job_level: 1, max_progress: 19, max_quality: 100, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 2, max_progress: 20, max_quality: 110, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 3, max_progress: 20, max_quality: 120, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 4, max_progress: 21, max_quality: 130, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 5, max_progress: 33, max_quality: 150, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 6, max_progress: 36, max_quality: 170, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 7, max_progress: 37, max_quality: 190, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 8, max_progress: 41, max_quality: 210, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 9, max_progress: 42, max_quality: 230, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 10, max_progress: 45, max_quality: 250, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 11, max_progress: 48, max_quality: 270, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 12, max_progress: 53, max_quality: 290, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 13, max_progress: 54, max_quality: 310, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 14, max_progress: 54, max_quality: 330, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 15, max_progress: 55, max_quality: 360, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 16, max_progress: 63, max_quality: 390, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 17, max_progress: 66, max_quality: 420, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 18, max_progress: 67, max_quality: 450, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 19, max_progress: 68, max_quality: 480, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 20, max_progress: 74, max_quality: 510, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 21, max_progress: 75, max_quality: 540, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 22, max_progress: 75, max_quality: 570, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 23, max_progress: 79, max_quality: 600, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 24, max_progress: 85, max_quality: 630, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 25, max_progress: 89, max_quality: 660, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 26, max_progress: 90, max_quality: 690, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 27, max_progress: 91, max_quality: 720, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 28, max_progress: 100, max_quality: 760, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 29, max_progress: 101, max_quality: 800, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 30, max_progress: 102, max_quality: 850, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 31, max_progress: 106, max_quality: 900, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 32, max_progress: 110, max_quality: 950, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 33, max_progress: 111, max_quality: 1000, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 34, max_progress: 115, max_quality: 1050, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 35, max_progress: 123, max_quality: 1110, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 36, max_progress: 124, max_quality: 1160, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 37, max_progress: 128, max_quality: 1220, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 38, max_progress: 129, max_quality: 1280, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 39, max_progress: 137, max_quality: 1340, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 40, max_progress: 138, max_quality: 1400, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 41, max_progress: 143, max_quality: 1460, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 42, max_progress: 144, max_quality: 1520, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 43, max_progress: 155, max_quality: 1580, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 44, max_progress: 156, max_quality: 1640, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 45, max_progress: 158, max_quality: 1700, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 46, max_progress: 159, max_quality: 1760, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 47, max_progress: 167, max_quality: 1820, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 48, max_progress: 172, max_quality: 1880, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 49, max_progress: 174, max_quality: 1940, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 50, max_progress: 180, max_quality: 2000, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 50, max_progress: 185, max_quality: 2040, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 50, max_progress: 190, max_quality: 2080, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 50, max_progress: 195, max_quality: 2120, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 50, max_progress: 200, max_quality: 2160, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 50, max_progress: 210, max_quality: 2200, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 50, max_progress: 212, max_quality: 2220, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 50, max_progress: 214, max_quality: 2240, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 50, max_progress: 216, max_quality: 2260, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 50, max_progress: 218, max_quality: 2280, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 50, max_progress: 220, max_quality: 2300, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 50, max_progress: 223, max_quality: 2320, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 50, max_progress: 226, max_quality: 2340, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 50, max_progress: 229, max_quality: 2360, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 50, max_progress: 232, max_quality: 2380, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 50, max_progress: 235, max_quality: 2400, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 50, max_progress: 238, max_quality: 2420, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 50, max_progress: 241, max_quality: 2440, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 50, max_progress: 244, max_quality: 2460, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 50, max_progress: 247, max_quality: 2480, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 50, max_progress: 250, max_quality: 2500, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 258, max_quality: 2512, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 266, max_quality: 2524, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 274, max_quality: 2536, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 282, max_quality: 2548, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 290, max_quality: 2560, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 298, max_quality: 2572, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 306, max_quality: 2584, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 314, max_quality: 2596, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 322, max_quality: 2608, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 330, max_quality: 2620, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 339, max_quality: 2633, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 348, max_quality: 2646, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 357, max_quality: 2659, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 366, max_quality: 2672, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 375, max_quality: 2685, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 384, max_quality: 2698, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 393, max_quality: 2711, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 402, max_quality: 2724, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 411, max_quality: 2737, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 420, max_quality: 2750, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 423, max_quality: 2757, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 426, max_quality: 2764, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 429, max_quality: 2771, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 432, max_quality: 2778, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 435, max_quality: 2785, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 438, max_quality: 2792, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 441, max_quality: 2799, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 444, max_quality: 2806, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 447, max_quality: 2813, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 450, max_quality: 2820, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 453, max_quality: 2828, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 456, max_quality: 2836, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 459, max_quality: 2844, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 462, max_quality: 2852, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 465, max_quality: 2860, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 468, max_quality: 2868, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 471, max_quality: 2876, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 474, max_quality: 2884, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 477, max_quality: 2892, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 480, max_quality: 2900, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 480, max_quality: 2900, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 480, max_quality: 2900, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 480, max_quality: 2900, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 50, max_progress: 480, max_quality: 2900, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 51, max_progress: 220, max_quality: 2070, max_durability: 80, progress_div: 61, quality_div: 39, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 51, max_progress: 224, max_quality: 2077, max_durability: 80, progress_div: 61, quality_div: 39, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 51, max_progress: 228, max_quality: 2084, max_durability: 80, progress_div: 61, quality_div: 39, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 51, max_progress: 232, max_quality: 2091, max_durability: 80, progress_div: 61, quality_div: 39, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 51, max_progress: 236, max_quality: 2098, max_durability: 80, progress_div: 61, quality_div: 39, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 51, max_progress: 240, max_quality: 2105, max_durability: 80, progress_div: 61, quality_div: 39, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 51, max_progress: 244, max_quality: 2112, max_durability: 80, progress_div: 61, quality_div: 39, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 51, max_progress: 248, max_quality: 2119, max_durability: 80, progress_div: 61, quality_div: 39, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 51, max_progress: 252, max_quality: 2126, max_durability: 80, progress_div: 61, quality_div: 39, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 51, max_progress: 256, max_quality: 2133, max_durability: 80, progress_div: 61, quality_div: 39, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 52, max_progress: 260, max_quality: 2140, max_durability: 80, progress_div: 62, quality_div: 43, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 52, max_progress: 268, max_quality: 2154, max_durability: 80, progress_div: 62, quality_div: 43, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 52, max_progress: 276, max_quality: 2168, max_durability: 80, progress_div: 62, quality_div: 43, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 52, max_progress: 284, max_quality: 2182, max_durability: 80, progress_div: 62, quality_div: 43, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 52, max_progress: 292, max_quality: 2196, max_durability: 80, progress_div: 62, quality_div: 43, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 53, max_progress: 300, max_quality: 2210, max_durability: 80, progress_div: 63, quality_div: 43, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 53, max_progress: 313, max_quality: 2233, max_durability: 80, progress_div: 63, quality_div: 43, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 53, max_progress: 326, max_quality: 2256, max_durability: 80, progress_div: 63, quality_div: 43, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 54, max_progress: 340, max_quality: 2280, max_durability: 80, progress_div: 64, quality_div: 44, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 54, max_progress: 353, max_quality: 2303, max_durability: 80, progress_div: 64, quality_div: 44, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 54, max_progress: 366, max_quality: 2326, max_durability: 80, progress_div: 64, quality_div: 44, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 55, max_progress: 380, max_quality: 2350, max_durability: 70, progress_div: 65, quality_div: 45, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 55, max_progress: 393, max_quality: 2373, max_durability: 70, progress_div: 65, quality_div: 45, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 55, max_progress: 406, max_quality: 2396, max_durability: 70, progress_div: 65, quality_div: 45, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 56, max_progress: 420, max_quality: 2420, max_durability: 70, progress_div: 66, quality_div: 46, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 56, max_progress: 433, max_quality: 2443, max_durability: 70, progress_div: 66, quality_div: 46, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 56, max_progress: 446, max_quality: 2466, max_durability: 70, progress_div: 66, quality_div: 46, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 57, max_progress: 460, max_quality: 2490, max_durability: 70, progress_div: 67, quality_div: 47, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 57, max_progress: 473, max_quality: 2513, max_durability: 70, progress_div: 67, quality_div: 47, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 57, max_progress: 486, max_quality: 2536, max_durability: 70, progress_div: 67, quality_div: 47, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 58, max_progress: 500, max_quality: 2560, max_durability: 70, progress_div: 68, quality_div: 48, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 58, max_progress: 513, max_quality: 2583, max_durability: 70, progress_div: 68, quality_div: 48, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 58, max_progress: 526, max_quality: 2606, max_durability: 70, progress_div: 68, quality_div: 48, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 59, max_progress: 540, max_quality: 2630, max_durability: 70, progress_div: 69, quality_div: 49, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 59, max_progress: 560, max_quality: 2665, max_durability: 70, progress_div: 69, quality_div: 49, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 580, max_quality: 2700, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 588, max_quality: 2710, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 596, max_quality: 2720, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 604, max_quality: 2730, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 612, max_quality: 2740, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 620, max_quality: 2750, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 628, max_quality: 2760, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 636, max_quality: 2770, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 644, max_quality: 2780, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 652, max_quality: 2790, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 660, max_quality: 2800, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 664, max_quality: 2805, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 668, max_quality: 2810, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 672, max_quality: 2815, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 676, max_quality: 2820, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 680, max_quality: 2825, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 684, max_quality: 2830, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 688, max_quality: 2835, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 692, max_quality: 2840, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 696, max_quality: 2845, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 700, max_quality: 2850, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 704, max_quality: 2855, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 708, max_quality: 2860, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 712, max_quality: 2865, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 716, max_quality: 2870, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 720, max_quality: 2875, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 724, max_quality: 2880, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 728, max_quality: 2885, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 732, max_quality: 2890, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 736, max_quality: 2895, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 60, max_progress: 740, max_quality: 2900, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 744, max_quality: 2913, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 748, max_quality: 2926, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 752, max_quality: 2939, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 756, max_quality: 2952, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 760, max_quality: 2965, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 764, max_quality: 2978, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 768, max_quality: 2991, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 772, max_quality: 3004, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 776, max_quality: 3017, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 780, max_quality: 3030, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 785, max_quality: 3043, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 790, max_quality: 3056, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 795, max_quality: 3069, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 800, max_quality: 3082, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 805, max_quality: 3095, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 810, max_quality: 3108, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 815, max_quality: 3121, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 820, max_quality: 3134, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 825, max_quality: 3147, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 830, max_quality: 3160, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 835, max_quality: 3174, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 840, max_quality: 3188, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 845, max_quality: 3202, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 850, max_quality: 3216, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 855, max_quality: 3230, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 860, max_quality: 3244, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 865, max_quality: 3258, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 870, max_quality: 3272, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 875, max_quality: 3286, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 880, max_quality: 3300, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 882, max_quality: 3309, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 884, max_quality: 3318, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 886, max_quality: 3327, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 888, max_quality: 3336, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 890, max_quality: 3345, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 892, max_quality: 3354, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 894, max_quality: 3363, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 896, max_quality: 3372, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 898, max_quality: 3381, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 900, max_quality: 3390, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 903, max_quality: 3403, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 906, max_quality: 3416, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 909, max_quality: 3429, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 912, max_quality: 3442, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 915, max_quality: 3455, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 918, max_quality: 3468, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 921, max_quality: 3481, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 924, max_quality: 3494, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 927, max_quality: 3507, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 930, max_quality: 3520, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 933, max_quality: 3534, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 936, max_quality: 3548, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 939, max_quality: 3562, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 942, max_quality: 3576, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 945, max_quality: 3590, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 948, max_quality: 3604, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 951, max_quality: 3618, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 954, max_quality: 3632, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 957, max_quality: 3646, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 960, max_quality: 3660, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 964, max_quality: 3674, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 968, max_quality: 3688, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 972, max_quality: 3702, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 976, max_quality: 3716, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 980, max_quality: 3730, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 984, max_quality: 3744, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 988, max_quality: 3758, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 992, max_quality: 3772, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 996, max_quality: 3786, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 1000, max_quality: 3800, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 1000, max_quality: 3800, max_durability: 80, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 1000, max_quality: 3800, max_durability: 80, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 1000, max_quality: 3800, max_durability: 80, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 60, max_progress: 1000, max_quality: 3800, max_durability: 80, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 61, max_progress: 630, max_quality: 2790, max_durability: 80, progress_div: 81, quality_div: 58, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 61, max_progress: 635, max_quality: 2799, max_durability: 80, progress_div: 81, quality_div: 58, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 61, max_progress: 640, max_quality: 2808, max_durability: 80, progress_div: 81, quality_div: 58, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 61, max_progress: 645, max_quality: 2817, max_durability: 80, progress_div: 81, quality_div: 58, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 61, max_progress: 650, max_quality: 2826, max_durability: 80, progress_div: 81, quality_div: 58, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 61, max_progress: 655, max_quality: 2835, max_durability: 80, progress_div: 81, quality_div: 58, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 61, max_progress: 660, max_quality: 2844, max_durability: 80, progress_div: 81, quality_div: 58, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 61, max_progress: 665, max_quality: 2853, max_durability: 80, progress_div: 81, quality_div: 58, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 61, max_progress: 670, max_quality: 2862, max_durability: 80, progress_div: 81, quality_div: 58, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 61, max_progress: 675, max_quality: 2871, max_durability: 80, progress_div: 81, quality_div: 58, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 62, max_progress: 680, max_quality: 2880, max_durability: 80, progress_div: 82, quality_div: 61, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 62, max_progress: 690, max_quality: 2898, max_durability: 80, progress_div: 82, quality_div: 61, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 62, max_progress: 700, max_quality: 2916, max_durability: 80, progress_div: 82, quality_div: 61, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 62, max_progress: 710, max_quality: 2934, max_durability: 80, progress_div: 82, quality_div: 61, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 62, max_progress: 720, max_quality: 2952, max_durability: 80, progress_div: 82, quality_div: 61, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 63, max_progress: 730, max_quality: 2970, max_durability: 80, progress_div: 83, quality_div: 64, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 63, max_progress: 746, max_quality: 3000, max_durability: 80, progress_div: 83, quality_div: 64, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 63, max_progress: 763, max_quality: 3030, max_durability: 80, progress_div: 83, quality_div: 64, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 64, max_progress: 780, max_quality: 3060, max_durability: 80, progress_div: 84, quality_div: 65, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 64, max_progress: 796, max_quality: 3090, max_durability: 80, progress_div: 84, quality_div: 65, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 64, max_progress: 813, max_quality: 3120, max_durability: 80, progress_div: 84, quality_div: 65, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 65, max_progress: 830, max_quality: 3150, max_durability: 80, progress_div: 85, quality_div: 66, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 65, max_progress: 846, max_quality: 3180, max_durability: 80, progress_div: 85, quality_div: 66, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 65, max_progress: 863, max_quality: 3210, max_durability: 80, progress_div: 85, quality_div: 66, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 66, max_progress: 880, max_quality: 3240, max_durability: 80, progress_div: 86, quality_div: 66, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 66, max_progress: 896, max_quality: 3270, max_durability: 80, progress_div: 86, quality_div: 66, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 66, max_progress: 913, max_quality: 3300, max_durability: 80, progress_div: 86, quality_div: 66, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 67, max_progress: 930, max_quality: 3330, max_durability: 80, progress_div: 87, quality_div: 68, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 67, max_progress: 946, max_quality: 3360, max_durability: 80, progress_div: 87, quality_div: 68, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 67, max_progress: 963, max_quality: 3390, max_durability: 80, progress_div: 87, quality_div: 68, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 68, max_progress: 980, max_quality: 3420, max_durability: 80, progress_div: 88, quality_div: 68, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 68, max_progress: 996, max_quality: 3450, max_durability: 80, progress_div: 88, quality_div: 68, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 68, max_progress: 1013, max_quality: 3480, max_durability: 80, progress_div: 88, quality_div: 68, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 69, max_progress: 1030, max_quality: 3510, max_durability: 80, progress_div: 89, quality_div: 68, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 69, max_progress: 1055, max_quality: 3555, max_durability: 80, progress_div: 89, quality_div: 68, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1080, max_quality: 3600, max_durability: 80, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1086, max_quality: 3660, max_durability: 80, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1092, max_quality: 3720, max_durability: 80, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1098, max_quality: 3780, max_durability: 80, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1104, max_quality: 3840, max_durability: 80, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1110, max_quality: 3900, max_durability: 80, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1116, max_quality: 3960, max_durability: 80, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1122, max_quality: 4020, max_durability: 80, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1128, max_quality: 4080, max_durability: 80, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1134, max_quality: 4140, max_durability: 80, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1140, max_quality: 4200, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1143, max_quality: 4230, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1146, max_quality: 4260, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1149, max_quality: 4290, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1152, max_quality: 4320, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1155, max_quality: 4350, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1158, max_quality: 4380, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1161, max_quality: 4410, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1164, max_quality: 4440, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1167, max_quality: 4470, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1170, max_quality: 4500, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1173, max_quality: 4530, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1176, max_quality: 4560, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1179, max_quality: 4590, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1182, max_quality: 4620, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1185, max_quality: 4650, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1188, max_quality: 4680, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1191, max_quality: 4710, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1194, max_quality: 4740, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1197, max_quality: 4770, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 70, max_progress: 1200, max_quality: 4800, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1205, max_quality: 4813, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1210, max_quality: 4826, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1215, max_quality: 4839, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1220, max_quality: 4852, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1225, max_quality: 4865, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1230, max_quality: 4878, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1235, max_quality: 4891, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1240, max_quality: 4904, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1245, max_quality: 4917, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1250, max_quality: 4930, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1255, max_quality: 4943, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1260, max_quality: 4956, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1265, max_quality: 4969, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1270, max_quality: 4982, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1275, max_quality: 4995, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1280, max_quality: 5008, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1285, max_quality: 5021, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1290, max_quality: 5034, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1295, max_quality: 5047, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1300, max_quality: 5060, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1305, max_quality: 5074, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1310, max_quality: 5088, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1315, max_quality: 5102, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1320, max_quality: 5116, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1325, max_quality: 5130, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1330, max_quality: 5144, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1335, max_quality: 5158, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1340, max_quality: 5172, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1345, max_quality: 5186, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1350, max_quality: 5200, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1355, max_quality: 5230, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1360, max_quality: 5260, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1365, max_quality: 5290, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1370, max_quality: 5320, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1375, max_quality: 5350, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1380, max_quality: 5380, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1385, max_quality: 5410, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1390, max_quality: 5440, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1395, max_quality: 5470, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1400, max_quality: 5500, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1405, max_quality: 5530, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1410, max_quality: 5560, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1415, max_quality: 5590, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1420, max_quality: 5620, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1425, max_quality: 5650, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1430, max_quality: 5680, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1435, max_quality: 5710, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1440, max_quality: 5740, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1445, max_quality: 5770, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1450, max_quality: 5800, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1455, max_quality: 5830, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1460, max_quality: 5860, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1465, max_quality: 5890, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1470, max_quality: 5920, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1475, max_quality: 5950, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1480, max_quality: 5980, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1485, max_quality: 6010, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1490, max_quality: 6040, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1495, max_quality: 6070, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 70, max_progress: 1500, max_quality: 6100, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 71, max_progress: 1150, max_quality: 3700, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 71, max_progress: 1155, max_quality: 3707, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 71, max_progress: 1160, max_quality: 3714, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 71, max_progress: 1165, max_quality: 3721, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 71, max_progress: 1170, max_quality: 3728, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 71, max_progress: 1175, max_quality: 3735, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 71, max_progress: 1180, max_quality: 3742, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 71, max_progress: 1185, max_quality: 3749, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 71, max_progress: 1190, max_quality: 3756, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 71, max_progress: 1195, max_quality: 3763, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 71, max_progress: 1200, max_quality: 3770, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 71, max_progress: 1205, max_quality: 3777, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 71, max_progress: 1210, max_quality: 3784, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 71, max_progress: 1215, max_quality: 3792, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 72, max_progress: 1220, max_quality: 3800, max_durability: 80, progress_div: 102, quality_div: 82, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 72, max_progress: 1234, max_quality: 3820, max_durability: 80, progress_div: 102, quality_div: 82, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 72, max_progress: 1248, max_quality: 3840, max_durability: 80, progress_div: 102, quality_div: 82, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 72, max_progress: 1262, max_quality: 3860, max_durability: 80, progress_div: 102, quality_div: 82, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 72, max_progress: 1276, max_quality: 3880, max_durability: 80, progress_div: 102, quality_div: 82, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 73, max_progress: 1290, max_quality: 3900, max_durability: 80, progress_div: 103, quality_div: 83, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 73, max_progress: 1313, max_quality: 3933, max_durability: 80, progress_div: 103, quality_div: 83, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 73, max_progress: 1336, max_quality: 3966, max_durability: 80, progress_div: 103, quality_div: 83, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 74, max_progress: 1360, max_quality: 4000, max_durability: 80, progress_div: 104, quality_div: 84, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 74, max_progress: 1383, max_quality: 4033, max_durability: 80, progress_div: 104, quality_div: 84, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 74, max_progress: 1406, max_quality: 4066, max_durability: 80, progress_div: 104, quality_div: 84, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 75, max_progress: 1430, max_quality: 4100, max_durability: 80, progress_div: 105, quality_div: 85, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 75, max_progress: 1453, max_quality: 4133, max_durability: 80, progress_div: 105, quality_div: 85, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 75, max_progress: 1476, max_quality: 4166, max_durability: 80, progress_div: 105, quality_div: 85, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 76, max_progress: 1500, max_quality: 4200, max_durability: 80, progress_div: 106, quality_div: 86, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 76, max_progress: 1523, max_quality: 4233, max_durability: 80, progress_div: 106, quality_div: 86, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 76, max_progress: 1546, max_quality: 4266, max_durability: 80, progress_div: 106, quality_div: 86, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 77, max_progress: 1570, max_quality: 4300, max_durability: 80, progress_div: 107, quality_div: 87, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 77, max_progress: 1593, max_quality: 4333, max_durability: 80, progress_div: 107, quality_div: 87, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 77, max_progress: 1616, max_quality: 4366, max_durability: 80, progress_div: 107, quality_div: 87, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 78, max_progress: 1640, max_quality: 4400, max_durability: 80, progress_div: 108, quality_div: 88, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 78, max_progress: 1663, max_quality: 4433, max_durability: 80, progress_div: 108, quality_div: 88, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 78, max_progress: 1686, max_quality: 4466, max_durability: 80, progress_div: 108, quality_div: 88, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 79, max_progress: 1710, max_quality: 4500, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 79, max_progress: 1715, max_quality: 4508, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 79, max_progress: 1720, max_quality: 4516, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 79, max_progress: 1726, max_quality: 4524, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 79, max_progress: 1732, max_quality: 4532, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 79, max_progress: 1738, max_quality: 4540, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 79, max_progress: 1744, max_quality: 4548, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 79, max_progress: 1750, max_quality: 4556, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 79, max_progress: 1756, max_quality: 4564, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 79, max_progress: 1762, max_quality: 4573, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 79, max_progress: 1768, max_quality: 4582, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 79, max_progress: 1774, max_quality: 4591, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 1780, max_quality: 4600, max_durability: 80, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 1792, max_quality: 4614, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 1804, max_quality: 4628, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 1816, max_quality: 4642, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 1828, max_quality: 4656, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 1840, max_quality: 4670, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 1852, max_quality: 4684, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 1864, max_quality: 4698, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 1876, max_quality: 4712, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 1888, max_quality: 4726, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 1900, max_quality: 6000, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 1915, max_quality: 6190, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 1930, max_quality: 6380, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 1945, max_quality: 6570, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 1960, max_quality: 6760, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 1975, max_quality: 6950, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 1990, max_quality: 7140, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 2005, max_quality: 7330, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 2020, max_quality: 7520, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 2035, max_quality: 7710, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 2050, max_quality: 7900, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2075, max_quality: 7920, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2100, max_quality: 7940, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2125, max_quality: 7960, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2150, max_quality: 7980, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2175, max_quality: 8000, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2200, max_quality: 8020, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2225, max_quality: 8040, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2250, max_quality: 8060, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2275, max_quality: 8080, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2300, max_quality: 8100, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2325, max_quality: 8120, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2350, max_quality: 8140, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2375, max_quality: 8160, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2400, max_quality: 8180, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2425, max_quality: 8200, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2450, max_quality: 8220, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2475, max_quality: 8240, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2500, max_quality: 8260, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2525, max_quality: 8280, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2550, max_quality: 8300, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2575, max_quality: 8320, max_durability: 70, progress_div: 140, quality_div: 90, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 2600, max_quality: 8340, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2625, max_quality: 8360, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2650, max_quality: 8380, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2675, max_quality: 8400, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2700, max_quality: 8420, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2725, max_quality: 8440, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2750, max_quality: 8460, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2775, max_quality: 8480, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2800, max_quality: 8500, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3856, max_quality: 12323, max_durability: 70, progress_div: 140, quality_div: 130, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 2852, max_quality: 8566, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2878, max_quality: 8599, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2904, max_quality: 8632, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2930, max_quality: 8665, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2956, max_quality: 8698, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 2982, max_quality: 8731, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3008, max_quality: 8764, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3034, max_quality: 8797, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3060, max_quality: 8830, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3087, max_quality: 8863, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3114, max_quality: 8896, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3141, max_quality: 8929, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3168, max_quality: 8962, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3195, max_quality: 8995, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3222, max_quality: 9028, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3249, max_quality: 9061, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3276, max_quality: 9094, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3303, max_quality: 9127, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3330, max_quality: 9160, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3357, max_quality: 9194, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3384, max_quality: 9228, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3411, max_quality: 9262, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3438, max_quality: 9296, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3465, max_quality: 9330, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3492, max_quality: 9364, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3519, max_quality: 9398, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3546, max_quality: 9432, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3573, max_quality: 9466, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 3600, max_quality: 9500, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 80, max_progress: 4672, max_quality: 15656, max_durability: 70, progress_div: 140, quality_div: 130, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 4220, max_quality: 14618, max_durability: 70, progress_div: 140, quality_div: 130, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 5059, max_quality: 15474, max_durability: 70, progress_div: 140, quality_div: 130, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 5077, max_quality: 14321, max_durability: 70, progress_div: 140, quality_div: 130, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 5095, max_quality: 14854, max_durability: 70, progress_div: 140, quality_div: 130, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 80, max_progress: 5470, max_quality: 16156, max_durability: 70, progress_div: 140, quality_div: 130, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 81, max_progress: 2000, max_quality: 5200, max_durability: 80, progress_div: 121, quality_div: 105, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 81, max_progress: 2100, max_quality: 5300, max_durability: 80, progress_div: 121, quality_div: 105, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 81, max_progress: 2200, max_quality: 5500, max_durability: 80, progress_div: 121, quality_div: 105, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 82, max_progress: 2300, max_quality: 5700, max_durability: 80, progress_div: 122, quality_div: 106, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 82, max_progress: 2350, max_quality: 5800, max_durability: 80, progress_div: 122, quality_div: 106, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 82, max_progress: 2400, max_quality: 5900, max_durability: 80, progress_div: 122, quality_div: 106, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 82, max_progress: 2450, max_quality: 6000, max_durability: 80, progress_div: 122, quality_div: 106, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 82, max_progress: 2500, max_quality: 6100, max_durability: 80, progress_div: 122, quality_div: 106, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 83, max_progress: 2600, max_quality: 6200, max_durability: 80, progress_div: 123, quality_div: 107, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 83, max_progress: 2660, max_quality: 6280, max_durability: 80, progress_div: 123, quality_div: 107, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 83, max_progress: 2720, max_quality: 6360, max_durability: 80, progress_div: 123, quality_div: 107, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 83, max_progress: 2780, max_quality: 6440, max_durability: 80, progress_div: 123, quality_div: 107, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 83, max_progress: 2840, max_quality: 6520, max_durability: 80, progress_div: 123, quality_div: 107, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 84, max_progress: 2900, max_quality: 6600, max_durability: 80, progress_div: 124, quality_div: 108, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 84, max_progress: 2920, max_quality: 6620, max_durability: 80, progress_div: 124, quality_div: 108, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 84, max_progress: 2940, max_quality: 6640, max_durability: 80, progress_div: 124, quality_div: 108, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 84, max_progress: 2960, max_quality: 6660, max_durability: 80, progress_div: 124, quality_div: 108, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 84, max_progress: 2980, max_quality: 6680, max_durability: 80, progress_div: 124, quality_div: 108, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 85, max_progress: 3000, max_quality: 6700, max_durability: 80, progress_div: 125, quality_div: 109, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 85, max_progress: 3020, max_quality: 6720, max_durability: 80, progress_div: 125, quality_div: 109, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 85, max_progress: 3040, max_quality: 6740, max_durability: 80, progress_div: 125, quality_div: 109, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 85, max_progress: 3060, max_quality: 6760, max_durability: 80, progress_div: 125, quality_div: 109, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 85, max_progress: 3080, max_quality: 6780, max_durability: 80, progress_div: 125, quality_div: 109, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 86, max_progress: 3100, max_quality: 6800, max_durability: 80, progress_div: 126, quality_div: 110, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 86, max_progress: 3120, max_quality: 6820, max_durability: 80, progress_div: 126, quality_div: 110, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 86, max_progress: 3140, max_quality: 6840, max_durability: 80, progress_div: 126, quality_div: 110, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 86, max_progress: 3160, max_quality: 6860, max_durability: 80, progress_div: 126, quality_div: 110, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 86, max_progress: 3180, max_quality: 6880, max_durability: 80, progress_div: 126, quality_div: 110, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 87, max_progress: 3200, max_quality: 6900, max_durability: 80, progress_div: 127, quality_div: 111, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 87, max_progress: 3220, max_quality: 6920, max_durability: 80, progress_div: 127, quality_div: 111, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 87, max_progress: 3240, max_quality: 6940, max_durability: 80, progress_div: 127, quality_div: 111, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 87, max_progress: 3260, max_quality: 6960, max_durability: 80, progress_div: 127, quality_div: 111, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 87, max_progress: 3280, max_quality: 6980, max_durability: 80, progress_div: 127, quality_div: 111, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 88, max_progress: 3300, max_quality: 7000, max_durability: 80, progress_div: 128, quality_div: 112, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 88, max_progress: 3320, max_quality: 7020, max_durability: 80, progress_div: 128, quality_div: 112, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 88, max_progress: 3340, max_quality: 7040, max_durability: 80, progress_div: 128, quality_div: 112, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 88, max_progress: 3360, max_quality: 7060, max_durability: 80, progress_div: 128, quality_div: 112, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 88, max_progress: 3380, max_quality: 7080, max_durability: 80, progress_div: 128, quality_div: 112, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 89, max_progress: 3400, max_quality: 7100, max_durability: 80, progress_div: 129, quality_div: 113, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 89, max_progress: 3420, max_quality: 7120, max_durability: 80, progress_div: 129, quality_div: 113, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 89, max_progress: 3440, max_quality: 7140, max_durability: 80, progress_div: 129, quality_div: 113, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 89, max_progress: 3460, max_quality: 7160, max_durability: 80, progress_div: 129, quality_div: 113, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 89, max_progress: 3480, max_quality: 7180, max_durability: 80, progress_div: 129, quality_div: 113, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 90, max_progress: 3500, max_quality: 7200, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, },
-RecipeLevel { job_level: 90, max_progress: 3520, max_quality: 7220, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, },
-RecipeLevel { job_level: 90, max_progress: 3540, max_quality: 7240, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, },
-RecipeLevel { job_level: 90, max_progress: 3560, max_quality: 7260, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, },
-RecipeLevel { job_level: 90, max_progress: 3580, max_quality: 7280, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, },
-RecipeLevel { job_level: 90, max_progress: 3600, max_quality: 7300, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, },
-RecipeLevel { job_level: 90, max_progress: 3620, max_quality: 7320, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, },
-RecipeLevel { job_level: 90, max_progress: 3640, max_quality: 7340, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, },
-RecipeLevel { job_level: 90, max_progress: 3660, max_quality: 7360, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, },
-RecipeLevel { job_level: 90, max_progress: 3680, max_quality: 7380, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, },
-RecipeLevel { job_level: 90, max_progress: 3700, max_quality: 7400, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, },
-RecipeLevel { job_level: 90, max_progress: 3720, max_quality: 7440, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, },
-RecipeLevel { job_level: 90, max_progress: 3740, max_quality: 7480, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, },
-RecipeLevel { job_level: 90, max_progress: 3760, max_quality: 7520, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, },
-RecipeLevel { job_level: 90, max_progress: 3780, max_quality: 7560, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, },
-RecipeLevel { job_level: 90, max_progress: 3800, max_quality: 7600, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, },
-RecipeLevel { job_level: 90, max_progress: 3820, max_quality: 7640, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, },
-RecipeLevel { job_level: 90, max_progress: 3840, max_quality: 7680, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, },
-RecipeLevel { job_level: 90, max_progress: 3860, max_quality: 7720, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, },
-RecipeLevel { job_level: 90, max_progress: 3880, max_quality: 7760, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, },
-RecipeLevel { job_level: 90, max_progress: 3900, max_quality: 7800, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 3940, max_quality: 7820, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 3980, max_quality: 7840, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4020, max_quality: 7860, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4060, max_quality: 7880, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4100, max_quality: 7900, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4140, max_quality: 7920, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4180, max_quality: 7940, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4220, max_quality: 7960, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4260, max_quality: 7980, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4300, max_quality: 8000, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8010, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8020, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8030, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8040, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8050, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8060, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8070, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8080, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8090, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8100, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8110, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8120, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8130, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8140, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8150, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8160, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8170, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8180, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8190, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8200, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 7480, max_quality: 13620, max_durability: 70, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8440, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8460, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8480, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8500, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8520, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8540, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8560, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8580, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8600, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8620, max_durability: 60, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8640, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8660, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8680, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8700, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8720, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8740, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8760, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8780, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8800, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8820, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8840, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8860, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8880, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8900, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8920, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8940, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8960, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8980, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 9000, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 9020, max_durability: 60, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 9040, max_durability: 70, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 9060, max_durability: 70, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 9080, max_durability: 70, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 9100, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 9120, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 9140, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 9160, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 9180, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 91, max_progress: 4000, max_quality: 8200, max_durability: 80, progress_div: 143, quality_div: 123, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 91, max_progress: 4083, max_quality: 8333, max_durability: 80, progress_div: 143, quality_div: 123, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 91, max_progress: 4166, max_quality: 8466, max_durability: 80, progress_div: 143, quality_div: 123, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 92, max_progress: 4250, max_quality: 8600, max_durability: 80, progress_div: 146, quality_div: 126, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 92, max_progress: 4333, max_quality: 8733, max_durability: 80, progress_div: 146, quality_div: 126, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 92, max_progress: 4416, max_quality: 8866, max_durability: 80, progress_div: 146, quality_div: 126, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 93, max_progress: 4500, max_quality: 9000, max_durability: 80, progress_div: 149, quality_div: 129, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 93, max_progress: 4575, max_quality: 9100, max_durability: 80, progress_div: 149, quality_div: 129, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 93, max_progress: 4650, max_quality: 9200, max_durability: 80, progress_div: 149, quality_div: 129, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 93, max_progress: 4725, max_quality: 9300, max_durability: 80, progress_div: 149, quality_div: 129, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 94, max_progress: 4800, max_quality: 9400, max_durability: 80, progress_div: 152, quality_div: 132, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 94, max_progress: 4860, max_quality: 9480, max_durability: 80, progress_div: 152, quality_div: 132, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 94, max_progress: 4920, max_quality: 9560, max_durability: 80, progress_div: 152, quality_div: 132, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 94, max_progress: 4980, max_quality: 9640, max_durability: 80, progress_div: 152, quality_div: 132, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 94, max_progress: 5040, max_quality: 9720, max_durability: 80, progress_div: 152, quality_div: 132, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 95, max_progress: 5100, max_quality: 9800, max_durability: 80, progress_div: 155, quality_div: 135, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 95, max_progress: 5160, max_quality: 9880, max_durability: 80, progress_div: 155, quality_div: 135, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 95, max_progress: 5220, max_quality: 9960, max_durability: 80, progress_div: 155, quality_div: 135, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 95, max_progress: 5280, max_quality: 10040, max_durability: 80, progress_div: 155, quality_div: 135, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 95, max_progress: 5340, max_quality: 10120, max_durability: 80, progress_div: 155, quality_div: 135, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 96, max_progress: 5400, max_quality: 10200, max_durability: 80, progress_div: 158, quality_div: 138, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 96, max_progress: 5460, max_quality: 10280, max_durability: 80, progress_div: 158, quality_div: 138, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 96, max_progress: 5520, max_quality: 10360, max_durability: 80, progress_div: 158, quality_div: 138, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 96, max_progress: 5580, max_quality: 10440, max_durability: 80, progress_div: 158, quality_div: 138, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 96, max_progress: 5640, max_quality: 10520, max_durability: 80, progress_div: 158, quality_div: 138, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 97, max_progress: 5700, max_quality: 10600, max_durability: 80, progress_div: 161, quality_div: 141, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 97, max_progress: 5760, max_quality: 10680, max_durability: 80, progress_div: 161, quality_div: 141, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 97, max_progress: 5820, max_quality: 10760, max_durability: 80, progress_div: 161, quality_div: 141, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 97, max_progress: 5880, max_quality: 10840, max_durability: 80, progress_div: 161, quality_div: 141, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 97, max_progress: 5940, max_quality: 10920, max_durability: 80, progress_div: 161, quality_div: 141, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 98, max_progress: 6000, max_quality: 11000, max_durability: 80, progress_div: 164, quality_div: 144, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 98, max_progress: 6060, max_quality: 11080, max_durability: 80, progress_div: 164, quality_div: 144, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 98, max_progress: 6120, max_quality: 11160, max_durability: 80, progress_div: 164, quality_div: 144, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 98, max_progress: 6180, max_quality: 11240, max_durability: 80, progress_div: 164, quality_div: 144, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 98, max_progress: 6240, max_quality: 11320, max_durability: 80, progress_div: 164, quality_div: 144, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 99, max_progress: 6300, max_quality: 11400, max_durability: 80, progress_div: 167, quality_div: 147, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 99, max_progress: 6360, max_quality: 11520, max_durability: 80, progress_div: 167, quality_div: 147, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 99, max_progress: 6420, max_quality: 11640, max_durability: 80, progress_div: 167, quality_div: 147, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 99, max_progress: 6480, max_quality: 11760, max_durability: 80, progress_div: 167, quality_div: 147, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 99, max_progress: 6540, max_quality: 11880, max_durability: 80, progress_div: 167, quality_div: 147, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 7500, max_quality: 15000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 8050, max_quality: 17600, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 9500, max_quality: 20000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 9000, max_quality: 18700, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 25000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
-RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, },
+RecipeLevel { job_level: 0, max_progress: 0, max_quality: 0, max_durability: 0, progress_div: 0, quality_div: 0, progress_mod: 0, quality_mod: 0, conditions_flag: 0, },
+RecipeLevel { job_level: 1, max_progress: 19, max_quality: 100, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 2, max_progress: 20, max_quality: 110, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 3, max_progress: 20, max_quality: 120, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 4, max_progress: 21, max_quality: 130, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 5, max_progress: 33, max_quality: 150, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 6, max_progress: 36, max_quality: 170, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 7, max_progress: 37, max_quality: 190, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 8, max_progress: 41, max_quality: 210, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 9, max_progress: 42, max_quality: 230, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 10, max_progress: 45, max_quality: 250, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 11, max_progress: 48, max_quality: 270, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 12, max_progress: 53, max_quality: 290, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 13, max_progress: 54, max_quality: 310, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 14, max_progress: 54, max_quality: 330, max_durability: 60, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 15, max_progress: 55, max_quality: 360, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 16, max_progress: 63, max_quality: 390, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 17, max_progress: 66, max_quality: 420, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 18, max_progress: 67, max_quality: 450, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 19, max_progress: 68, max_quality: 480, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 20, max_progress: 74, max_quality: 510, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 21, max_progress: 75, max_quality: 540, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 22, max_progress: 75, max_quality: 570, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 23, max_progress: 79, max_quality: 600, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 24, max_progress: 85, max_quality: 630, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 25, max_progress: 89, max_quality: 660, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 26, max_progress: 90, max_quality: 690, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 27, max_progress: 91, max_quality: 720, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 28, max_progress: 100, max_quality: 760, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 29, max_progress: 101, max_quality: 800, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 30, max_progress: 102, max_quality: 850, max_durability: 70, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 31, max_progress: 106, max_quality: 900, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 32, max_progress: 110, max_quality: 950, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 33, max_progress: 111, max_quality: 1000, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 34, max_progress: 115, max_quality: 1050, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 35, max_progress: 123, max_quality: 1110, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 36, max_progress: 124, max_quality: 1160, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 37, max_progress: 128, max_quality: 1220, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 38, max_progress: 129, max_quality: 1280, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 39, max_progress: 137, max_quality: 1340, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 40, max_progress: 138, max_quality: 1400, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 41, max_progress: 143, max_quality: 1460, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 42, max_progress: 144, max_quality: 1520, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 43, max_progress: 155, max_quality: 1580, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 44, max_progress: 156, max_quality: 1640, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 45, max_progress: 158, max_quality: 1700, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 46, max_progress: 159, max_quality: 1760, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 47, max_progress: 167, max_quality: 1820, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 48, max_progress: 172, max_quality: 1880, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 49, max_progress: 174, max_quality: 1940, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 180, max_quality: 2000, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 185, max_quality: 2040, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 190, max_quality: 2080, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 195, max_quality: 2120, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 200, max_quality: 2160, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 210, max_quality: 2200, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 212, max_quality: 2220, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 214, max_quality: 2240, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 216, max_quality: 2260, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 218, max_quality: 2280, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 220, max_quality: 2300, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 223, max_quality: 2320, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 226, max_quality: 2340, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 229, max_quality: 2360, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 232, max_quality: 2380, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 235, max_quality: 2400, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 238, max_quality: 2420, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 241, max_quality: 2440, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 244, max_quality: 2460, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 247, max_quality: 2480, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 250, max_quality: 2500, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 258, max_quality: 2512, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 266, max_quality: 2524, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 274, max_quality: 2536, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 282, max_quality: 2548, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 290, max_quality: 2560, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 298, max_quality: 2572, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 306, max_quality: 2584, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 314, max_quality: 2596, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 322, max_quality: 2608, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 330, max_quality: 2620, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 339, max_quality: 2633, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 348, max_quality: 2646, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 357, max_quality: 2659, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 366, max_quality: 2672, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 375, max_quality: 2685, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 384, max_quality: 2698, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 393, max_quality: 2711, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 402, max_quality: 2724, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 411, max_quality: 2737, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 420, max_quality: 2750, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 423, max_quality: 2757, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 426, max_quality: 2764, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 429, max_quality: 2771, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 432, max_quality: 2778, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 435, max_quality: 2785, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 438, max_quality: 2792, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 441, max_quality: 2799, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 444, max_quality: 2806, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 447, max_quality: 2813, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 450, max_quality: 2820, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 453, max_quality: 2828, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 456, max_quality: 2836, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 459, max_quality: 2844, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 462, max_quality: 2852, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 465, max_quality: 2860, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 468, max_quality: 2868, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 471, max_quality: 2876, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 474, max_quality: 2884, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 477, max_quality: 2892, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 480, max_quality: 2900, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 480, max_quality: 2900, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 480, max_quality: 2900, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 480, max_quality: 2900, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 50, max_progress: 480, max_quality: 2900, max_durability: 80, progress_div: 50, quality_div: 30, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 51, max_progress: 220, max_quality: 2070, max_durability: 80, progress_div: 61, quality_div: 39, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 51, max_progress: 224, max_quality: 2077, max_durability: 80, progress_div: 61, quality_div: 39, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 51, max_progress: 228, max_quality: 2084, max_durability: 80, progress_div: 61, quality_div: 39, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 51, max_progress: 232, max_quality: 2091, max_durability: 80, progress_div: 61, quality_div: 39, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 51, max_progress: 236, max_quality: 2098, max_durability: 80, progress_div: 61, quality_div: 39, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 51, max_progress: 240, max_quality: 2105, max_durability: 80, progress_div: 61, quality_div: 39, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 51, max_progress: 244, max_quality: 2112, max_durability: 80, progress_div: 61, quality_div: 39, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 51, max_progress: 248, max_quality: 2119, max_durability: 80, progress_div: 61, quality_div: 39, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 51, max_progress: 252, max_quality: 2126, max_durability: 80, progress_div: 61, quality_div: 39, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 51, max_progress: 256, max_quality: 2133, max_durability: 80, progress_div: 61, quality_div: 39, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 52, max_progress: 260, max_quality: 2140, max_durability: 80, progress_div: 62, quality_div: 43, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 52, max_progress: 268, max_quality: 2154, max_durability: 80, progress_div: 62, quality_div: 43, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 52, max_progress: 276, max_quality: 2168, max_durability: 80, progress_div: 62, quality_div: 43, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 52, max_progress: 284, max_quality: 2182, max_durability: 80, progress_div: 62, quality_div: 43, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 52, max_progress: 292, max_quality: 2196, max_durability: 80, progress_div: 62, quality_div: 43, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 53, max_progress: 300, max_quality: 2210, max_durability: 80, progress_div: 63, quality_div: 43, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 53, max_progress: 313, max_quality: 2233, max_durability: 80, progress_div: 63, quality_div: 43, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 53, max_progress: 326, max_quality: 2256, max_durability: 80, progress_div: 63, quality_div: 43, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 54, max_progress: 340, max_quality: 2280, max_durability: 80, progress_div: 64, quality_div: 44, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 54, max_progress: 353, max_quality: 2303, max_durability: 80, progress_div: 64, quality_div: 44, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 54, max_progress: 366, max_quality: 2326, max_durability: 80, progress_div: 64, quality_div: 44, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 55, max_progress: 380, max_quality: 2350, max_durability: 70, progress_div: 65, quality_div: 45, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 55, max_progress: 393, max_quality: 2373, max_durability: 70, progress_div: 65, quality_div: 45, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 55, max_progress: 406, max_quality: 2396, max_durability: 70, progress_div: 65, quality_div: 45, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 56, max_progress: 420, max_quality: 2420, max_durability: 70, progress_div: 66, quality_div: 46, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 56, max_progress: 433, max_quality: 2443, max_durability: 70, progress_div: 66, quality_div: 46, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 56, max_progress: 446, max_quality: 2466, max_durability: 70, progress_div: 66, quality_div: 46, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 57, max_progress: 460, max_quality: 2490, max_durability: 70, progress_div: 67, quality_div: 47, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 57, max_progress: 473, max_quality: 2513, max_durability: 70, progress_div: 67, quality_div: 47, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 57, max_progress: 486, max_quality: 2536, max_durability: 70, progress_div: 67, quality_div: 47, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 58, max_progress: 500, max_quality: 2560, max_durability: 70, progress_div: 68, quality_div: 48, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 58, max_progress: 513, max_quality: 2583, max_durability: 70, progress_div: 68, quality_div: 48, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 58, max_progress: 526, max_quality: 2606, max_durability: 70, progress_div: 68, quality_div: 48, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 59, max_progress: 540, max_quality: 2630, max_durability: 70, progress_div: 69, quality_div: 49, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 59, max_progress: 560, max_quality: 2665, max_durability: 70, progress_div: 69, quality_div: 49, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 580, max_quality: 2700, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 588, max_quality: 2710, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 596, max_quality: 2720, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 604, max_quality: 2730, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 612, max_quality: 2740, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 620, max_quality: 2750, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 628, max_quality: 2760, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 636, max_quality: 2770, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 644, max_quality: 2780, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 652, max_quality: 2790, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 660, max_quality: 2800, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 664, max_quality: 2805, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 668, max_quality: 2810, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 672, max_quality: 2815, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 676, max_quality: 2820, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 680, max_quality: 2825, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 684, max_quality: 2830, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 688, max_quality: 2835, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 692, max_quality: 2840, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 696, max_quality: 2845, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 700, max_quality: 2850, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 704, max_quality: 2855, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 708, max_quality: 2860, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 712, max_quality: 2865, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 716, max_quality: 2870, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 720, max_quality: 2875, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 724, max_quality: 2880, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 728, max_quality: 2885, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 732, max_quality: 2890, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 736, max_quality: 2895, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 740, max_quality: 2900, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 744, max_quality: 2913, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 748, max_quality: 2926, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 752, max_quality: 2939, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 756, max_quality: 2952, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 760, max_quality: 2965, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 764, max_quality: 2978, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 768, max_quality: 2991, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 772, max_quality: 3004, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 776, max_quality: 3017, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 780, max_quality: 3030, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 785, max_quality: 3043, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 790, max_quality: 3056, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 795, max_quality: 3069, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 800, max_quality: 3082, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 805, max_quality: 3095, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 810, max_quality: 3108, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 815, max_quality: 3121, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 820, max_quality: 3134, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 825, max_quality: 3147, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 830, max_quality: 3160, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 835, max_quality: 3174, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 840, max_quality: 3188, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 845, max_quality: 3202, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 850, max_quality: 3216, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 855, max_quality: 3230, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 860, max_quality: 3244, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 865, max_quality: 3258, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 870, max_quality: 3272, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 875, max_quality: 3286, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 880, max_quality: 3300, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 882, max_quality: 3309, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 884, max_quality: 3318, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 886, max_quality: 3327, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 888, max_quality: 3336, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 890, max_quality: 3345, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 892, max_quality: 3354, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 894, max_quality: 3363, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 896, max_quality: 3372, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 898, max_quality: 3381, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 900, max_quality: 3390, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 903, max_quality: 3403, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 906, max_quality: 3416, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 909, max_quality: 3429, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 912, max_quality: 3442, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 915, max_quality: 3455, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 918, max_quality: 3468, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 921, max_quality: 3481, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 924, max_quality: 3494, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 927, max_quality: 3507, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 930, max_quality: 3520, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 933, max_quality: 3534, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 936, max_quality: 3548, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 939, max_quality: 3562, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 942, max_quality: 3576, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 945, max_quality: 3590, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 948, max_quality: 3604, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 951, max_quality: 3618, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 954, max_quality: 3632, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 957, max_quality: 3646, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 960, max_quality: 3660, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 964, max_quality: 3674, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 968, max_quality: 3688, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 972, max_quality: 3702, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 976, max_quality: 3716, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 980, max_quality: 3730, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 984, max_quality: 3744, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 988, max_quality: 3758, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 992, max_quality: 3772, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 996, max_quality: 3786, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 1000, max_quality: 3800, max_durability: 70, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 1000, max_quality: 3800, max_durability: 80, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 1000, max_quality: 3800, max_durability: 80, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 1000, max_quality: 3800, max_durability: 80, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 60, max_progress: 1000, max_quality: 3800, max_durability: 80, progress_div: 70, quality_div: 50, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 61, max_progress: 630, max_quality: 2790, max_durability: 80, progress_div: 81, quality_div: 58, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 61, max_progress: 635, max_quality: 2799, max_durability: 80, progress_div: 81, quality_div: 58, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 61, max_progress: 640, max_quality: 2808, max_durability: 80, progress_div: 81, quality_div: 58, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 61, max_progress: 645, max_quality: 2817, max_durability: 80, progress_div: 81, quality_div: 58, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 61, max_progress: 650, max_quality: 2826, max_durability: 80, progress_div: 81, quality_div: 58, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 61, max_progress: 655, max_quality: 2835, max_durability: 80, progress_div: 81, quality_div: 58, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 61, max_progress: 660, max_quality: 2844, max_durability: 80, progress_div: 81, quality_div: 58, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 61, max_progress: 665, max_quality: 2853, max_durability: 80, progress_div: 81, quality_div: 58, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 61, max_progress: 670, max_quality: 2862, max_durability: 80, progress_div: 81, quality_div: 58, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 61, max_progress: 675, max_quality: 2871, max_durability: 80, progress_div: 81, quality_div: 58, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 62, max_progress: 680, max_quality: 2880, max_durability: 80, progress_div: 82, quality_div: 61, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 62, max_progress: 690, max_quality: 2898, max_durability: 80, progress_div: 82, quality_div: 61, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 62, max_progress: 700, max_quality: 2916, max_durability: 80, progress_div: 82, quality_div: 61, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 62, max_progress: 710, max_quality: 2934, max_durability: 80, progress_div: 82, quality_div: 61, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 62, max_progress: 720, max_quality: 2952, max_durability: 80, progress_div: 82, quality_div: 61, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 63, max_progress: 730, max_quality: 2970, max_durability: 80, progress_div: 83, quality_div: 64, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 63, max_progress: 746, max_quality: 3000, max_durability: 80, progress_div: 83, quality_div: 64, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 63, max_progress: 763, max_quality: 3030, max_durability: 80, progress_div: 83, quality_div: 64, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 64, max_progress: 780, max_quality: 3060, max_durability: 80, progress_div: 84, quality_div: 65, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 64, max_progress: 796, max_quality: 3090, max_durability: 80, progress_div: 84, quality_div: 65, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 64, max_progress: 813, max_quality: 3120, max_durability: 80, progress_div: 84, quality_div: 65, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 65, max_progress: 830, max_quality: 3150, max_durability: 80, progress_div: 85, quality_div: 66, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 65, max_progress: 846, max_quality: 3180, max_durability: 80, progress_div: 85, quality_div: 66, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 65, max_progress: 863, max_quality: 3210, max_durability: 80, progress_div: 85, quality_div: 66, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 66, max_progress: 880, max_quality: 3240, max_durability: 80, progress_div: 86, quality_div: 66, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 66, max_progress: 896, max_quality: 3270, max_durability: 80, progress_div: 86, quality_div: 66, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 66, max_progress: 913, max_quality: 3300, max_durability: 80, progress_div: 86, quality_div: 66, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 67, max_progress: 930, max_quality: 3330, max_durability: 80, progress_div: 87, quality_div: 68, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 67, max_progress: 946, max_quality: 3360, max_durability: 80, progress_div: 87, quality_div: 68, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 67, max_progress: 963, max_quality: 3390, max_durability: 80, progress_div: 87, quality_div: 68, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 68, max_progress: 980, max_quality: 3420, max_durability: 80, progress_div: 88, quality_div: 68, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 68, max_progress: 996, max_quality: 3450, max_durability: 80, progress_div: 88, quality_div: 68, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 68, max_progress: 1013, max_quality: 3480, max_durability: 80, progress_div: 88, quality_div: 68, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 69, max_progress: 1030, max_quality: 3510, max_durability: 80, progress_div: 89, quality_div: 68, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 69, max_progress: 1055, max_quality: 3555, max_durability: 80, progress_div: 89, quality_div: 68, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1080, max_quality: 3600, max_durability: 80, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1086, max_quality: 3660, max_durability: 80, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1092, max_quality: 3720, max_durability: 80, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1098, max_quality: 3780, max_durability: 80, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1104, max_quality: 3840, max_durability: 80, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1110, max_quality: 3900, max_durability: 80, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1116, max_quality: 3960, max_durability: 80, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1122, max_quality: 4020, max_durability: 80, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1128, max_quality: 4080, max_durability: 80, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1134, max_quality: 4140, max_durability: 80, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1140, max_quality: 4200, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1143, max_quality: 4230, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1146, max_quality: 4260, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1149, max_quality: 4290, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1152, max_quality: 4320, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1155, max_quality: 4350, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1158, max_quality: 4380, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1161, max_quality: 4410, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1164, max_quality: 4440, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1167, max_quality: 4470, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1170, max_quality: 4500, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1173, max_quality: 4530, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1176, max_quality: 4560, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1179, max_quality: 4590, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1182, max_quality: 4620, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1185, max_quality: 4650, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1188, max_quality: 4680, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1191, max_quality: 4710, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1194, max_quality: 4740, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1197, max_quality: 4770, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1200, max_quality: 4800, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1205, max_quality: 4813, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1210, max_quality: 4826, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1215, max_quality: 4839, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1220, max_quality: 4852, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1225, max_quality: 4865, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1230, max_quality: 4878, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1235, max_quality: 4891, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1240, max_quality: 4904, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1245, max_quality: 4917, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1250, max_quality: 4930, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1255, max_quality: 4943, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1260, max_quality: 4956, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1265, max_quality: 4969, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1270, max_quality: 4982, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1275, max_quality: 4995, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1280, max_quality: 5008, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1285, max_quality: 5021, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1290, max_quality: 5034, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1295, max_quality: 5047, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1300, max_quality: 5060, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1305, max_quality: 5074, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1310, max_quality: 5088, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1315, max_quality: 5102, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1320, max_quality: 5116, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1325, max_quality: 5130, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1330, max_quality: 5144, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1335, max_quality: 5158, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1340, max_quality: 5172, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1345, max_quality: 5186, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1350, max_quality: 5200, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1355, max_quality: 5230, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1360, max_quality: 5260, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1365, max_quality: 5290, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1370, max_quality: 5320, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1375, max_quality: 5350, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1380, max_quality: 5380, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1385, max_quality: 5410, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1390, max_quality: 5440, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1395, max_quality: 5470, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1400, max_quality: 5500, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1405, max_quality: 5530, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1410, max_quality: 5560, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1415, max_quality: 5590, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1420, max_quality: 5620, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1425, max_quality: 5650, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1430, max_quality: 5680, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1435, max_quality: 5710, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1440, max_quality: 5740, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1445, max_quality: 5770, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1450, max_quality: 5800, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1455, max_quality: 5830, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1460, max_quality: 5860, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1465, max_quality: 5890, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1470, max_quality: 5920, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1475, max_quality: 5950, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1480, max_quality: 5980, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1485, max_quality: 6010, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1490, max_quality: 6040, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1495, max_quality: 6070, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 70, max_progress: 1500, max_quality: 6100, max_durability: 70, progress_div: 90, quality_div: 70, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 71, max_progress: 1150, max_quality: 3700, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 71, max_progress: 1155, max_quality: 3707, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 71, max_progress: 1160, max_quality: 3714, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 71, max_progress: 1165, max_quality: 3721, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 71, max_progress: 1170, max_quality: 3728, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 71, max_progress: 1175, max_quality: 3735, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 71, max_progress: 1180, max_quality: 3742, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 71, max_progress: 1185, max_quality: 3749, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 71, max_progress: 1190, max_quality: 3756, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 71, max_progress: 1195, max_quality: 3763, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 71, max_progress: 1200, max_quality: 3770, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 71, max_progress: 1205, max_quality: 3777, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 71, max_progress: 1210, max_quality: 3784, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 71, max_progress: 1215, max_quality: 3792, max_durability: 80, progress_div: 101, quality_div: 81, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 72, max_progress: 1220, max_quality: 3800, max_durability: 80, progress_div: 102, quality_div: 82, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 72, max_progress: 1234, max_quality: 3820, max_durability: 80, progress_div: 102, quality_div: 82, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 72, max_progress: 1248, max_quality: 3840, max_durability: 80, progress_div: 102, quality_div: 82, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 72, max_progress: 1262, max_quality: 3860, max_durability: 80, progress_div: 102, quality_div: 82, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 72, max_progress: 1276, max_quality: 3880, max_durability: 80, progress_div: 102, quality_div: 82, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 73, max_progress: 1290, max_quality: 3900, max_durability: 80, progress_div: 103, quality_div: 83, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 73, max_progress: 1313, max_quality: 3933, max_durability: 80, progress_div: 103, quality_div: 83, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 73, max_progress: 1336, max_quality: 3966, max_durability: 80, progress_div: 103, quality_div: 83, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 74, max_progress: 1360, max_quality: 4000, max_durability: 80, progress_div: 104, quality_div: 84, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 74, max_progress: 1383, max_quality: 4033, max_durability: 80, progress_div: 104, quality_div: 84, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 74, max_progress: 1406, max_quality: 4066, max_durability: 80, progress_div: 104, quality_div: 84, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 75, max_progress: 1430, max_quality: 4100, max_durability: 80, progress_div: 105, quality_div: 85, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 75, max_progress: 1453, max_quality: 4133, max_durability: 80, progress_div: 105, quality_div: 85, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 75, max_progress: 1476, max_quality: 4166, max_durability: 80, progress_div: 105, quality_div: 85, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 76, max_progress: 1500, max_quality: 4200, max_durability: 80, progress_div: 106, quality_div: 86, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 76, max_progress: 1523, max_quality: 4233, max_durability: 80, progress_div: 106, quality_div: 86, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 76, max_progress: 1546, max_quality: 4266, max_durability: 80, progress_div: 106, quality_div: 86, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 77, max_progress: 1570, max_quality: 4300, max_durability: 80, progress_div: 107, quality_div: 87, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 77, max_progress: 1593, max_quality: 4333, max_durability: 80, progress_div: 107, quality_div: 87, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 77, max_progress: 1616, max_quality: 4366, max_durability: 80, progress_div: 107, quality_div: 87, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 78, max_progress: 1640, max_quality: 4400, max_durability: 80, progress_div: 108, quality_div: 88, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 78, max_progress: 1663, max_quality: 4433, max_durability: 80, progress_div: 108, quality_div: 88, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 78, max_progress: 1686, max_quality: 4466, max_durability: 80, progress_div: 108, quality_div: 88, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 79, max_progress: 1710, max_quality: 4500, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 79, max_progress: 1715, max_quality: 4508, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 79, max_progress: 1720, max_quality: 4516, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 79, max_progress: 1726, max_quality: 4524, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 79, max_progress: 1732, max_quality: 4532, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 79, max_progress: 1738, max_quality: 4540, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 79, max_progress: 1744, max_quality: 4548, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 79, max_progress: 1750, max_quality: 4556, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 79, max_progress: 1756, max_quality: 4564, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 79, max_progress: 1762, max_quality: 4573, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 79, max_progress: 1768, max_quality: 4582, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 79, max_progress: 1774, max_quality: 4591, max_durability: 80, progress_div: 109, quality_div: 89, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 1780, max_quality: 4600, max_durability: 80, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 1792, max_quality: 4614, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 1804, max_quality: 4628, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 1816, max_quality: 4642, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 1828, max_quality: 4656, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 1840, max_quality: 4670, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 1852, max_quality: 4684, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 1864, max_quality: 4698, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 1876, max_quality: 4712, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 1888, max_quality: 4726, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 1900, max_quality: 6000, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 1915, max_quality: 6190, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 1930, max_quality: 6380, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 1945, max_quality: 6570, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 1960, max_quality: 6760, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 1975, max_quality: 6950, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 1990, max_quality: 7140, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2005, max_quality: 7330, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2020, max_quality: 7520, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2035, max_quality: 7710, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2050, max_quality: 7900, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2075, max_quality: 7920, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2100, max_quality: 7940, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2125, max_quality: 7960, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2150, max_quality: 7980, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2175, max_quality: 8000, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2200, max_quality: 8020, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2225, max_quality: 8040, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2250, max_quality: 8060, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2275, max_quality: 8080, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2300, max_quality: 8100, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2325, max_quality: 8120, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2350, max_quality: 8140, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2375, max_quality: 8160, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2400, max_quality: 8180, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2425, max_quality: 8200, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2450, max_quality: 8220, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2475, max_quality: 8240, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2500, max_quality: 8260, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2525, max_quality: 8280, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2550, max_quality: 8300, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2575, max_quality: 8320, max_durability: 70, progress_div: 140, quality_div: 90, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2600, max_quality: 8340, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2625, max_quality: 8360, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2650, max_quality: 8380, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2675, max_quality: 8400, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2700, max_quality: 8420, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2725, max_quality: 8440, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2750, max_quality: 8460, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2775, max_quality: 8480, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2800, max_quality: 8500, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3856, max_quality: 12323, max_durability: 70, progress_div: 140, quality_div: 130, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2852, max_quality: 8566, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2878, max_quality: 8599, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2904, max_quality: 8632, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2930, max_quality: 8665, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2956, max_quality: 8698, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 2982, max_quality: 8731, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3008, max_quality: 8764, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3034, max_quality: 8797, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3060, max_quality: 8830, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3087, max_quality: 8863, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3114, max_quality: 8896, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3141, max_quality: 8929, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3168, max_quality: 8962, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3195, max_quality: 8995, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3222, max_quality: 9028, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3249, max_quality: 9061, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3276, max_quality: 9094, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3303, max_quality: 9127, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3330, max_quality: 9160, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3357, max_quality: 9194, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3384, max_quality: 9228, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3411, max_quality: 9262, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3438, max_quality: 9296, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3465, max_quality: 9330, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3492, max_quality: 9364, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3519, max_quality: 9398, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3546, max_quality: 9432, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3573, max_quality: 9466, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 3600, max_quality: 9500, max_durability: 70, progress_div: 110, quality_div: 90, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 4672, max_quality: 15656, max_durability: 70, progress_div: 140, quality_div: 130, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 4220, max_quality: 14618, max_durability: 70, progress_div: 140, quality_div: 130, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 5059, max_quality: 15474, max_durability: 70, progress_div: 140, quality_div: 130, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 5077, max_quality: 14321, max_durability: 70, progress_div: 140, quality_div: 130, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 5095, max_quality: 14854, max_durability: 70, progress_div: 140, quality_div: 130, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 80, max_progress: 5470, max_quality: 16156, max_durability: 70, progress_div: 140, quality_div: 130, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 81, max_progress: 2000, max_quality: 5200, max_durability: 80, progress_div: 121, quality_div: 105, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 81, max_progress: 2100, max_quality: 5300, max_durability: 80, progress_div: 121, quality_div: 105, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 81, max_progress: 2200, max_quality: 5500, max_durability: 80, progress_div: 121, quality_div: 105, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 82, max_progress: 2300, max_quality: 5700, max_durability: 80, progress_div: 122, quality_div: 106, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 82, max_progress: 2350, max_quality: 5800, max_durability: 80, progress_div: 122, quality_div: 106, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 82, max_progress: 2400, max_quality: 5900, max_durability: 80, progress_div: 122, quality_div: 106, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 82, max_progress: 2450, max_quality: 6000, max_durability: 80, progress_div: 122, quality_div: 106, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 82, max_progress: 2500, max_quality: 6100, max_durability: 80, progress_div: 122, quality_div: 106, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 83, max_progress: 2600, max_quality: 6200, max_durability: 80, progress_div: 123, quality_div: 107, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 83, max_progress: 2660, max_quality: 6280, max_durability: 80, progress_div: 123, quality_div: 107, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 83, max_progress: 2720, max_quality: 6360, max_durability: 80, progress_div: 123, quality_div: 107, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 83, max_progress: 2780, max_quality: 6440, max_durability: 80, progress_div: 123, quality_div: 107, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 83, max_progress: 2840, max_quality: 6520, max_durability: 80, progress_div: 123, quality_div: 107, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 84, max_progress: 2900, max_quality: 6600, max_durability: 80, progress_div: 124, quality_div: 108, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 84, max_progress: 2920, max_quality: 6620, max_durability: 80, progress_div: 124, quality_div: 108, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 84, max_progress: 2940, max_quality: 6640, max_durability: 80, progress_div: 124, quality_div: 108, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 84, max_progress: 2960, max_quality: 6660, max_durability: 80, progress_div: 124, quality_div: 108, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 84, max_progress: 2980, max_quality: 6680, max_durability: 80, progress_div: 124, quality_div: 108, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 85, max_progress: 3000, max_quality: 6700, max_durability: 80, progress_div: 125, quality_div: 109, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 85, max_progress: 3020, max_quality: 6720, max_durability: 80, progress_div: 125, quality_div: 109, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 85, max_progress: 3040, max_quality: 6740, max_durability: 80, progress_div: 125, quality_div: 109, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 85, max_progress: 3060, max_quality: 6760, max_durability: 80, progress_div: 125, quality_div: 109, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 85, max_progress: 3080, max_quality: 6780, max_durability: 80, progress_div: 125, quality_div: 109, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 86, max_progress: 3100, max_quality: 6800, max_durability: 80, progress_div: 126, quality_div: 110, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 86, max_progress: 3120, max_quality: 6820, max_durability: 80, progress_div: 126, quality_div: 110, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 86, max_progress: 3140, max_quality: 6840, max_durability: 80, progress_div: 126, quality_div: 110, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 86, max_progress: 3160, max_quality: 6860, max_durability: 80, progress_div: 126, quality_div: 110, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 86, max_progress: 3180, max_quality: 6880, max_durability: 80, progress_div: 126, quality_div: 110, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 87, max_progress: 3200, max_quality: 6900, max_durability: 80, progress_div: 127, quality_div: 111, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 87, max_progress: 3220, max_quality: 6920, max_durability: 80, progress_div: 127, quality_div: 111, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 87, max_progress: 3240, max_quality: 6940, max_durability: 80, progress_div: 127, quality_div: 111, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 87, max_progress: 3260, max_quality: 6960, max_durability: 80, progress_div: 127, quality_div: 111, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 87, max_progress: 3280, max_quality: 6980, max_durability: 80, progress_div: 127, quality_div: 111, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 88, max_progress: 3300, max_quality: 7000, max_durability: 80, progress_div: 128, quality_div: 112, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 88, max_progress: 3320, max_quality: 7020, max_durability: 80, progress_div: 128, quality_div: 112, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 88, max_progress: 3340, max_quality: 7040, max_durability: 80, progress_div: 128, quality_div: 112, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 88, max_progress: 3360, max_quality: 7060, max_durability: 80, progress_div: 128, quality_div: 112, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 88, max_progress: 3380, max_quality: 7080, max_durability: 80, progress_div: 128, quality_div: 112, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 89, max_progress: 3400, max_quality: 7100, max_durability: 80, progress_div: 129, quality_div: 113, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 89, max_progress: 3420, max_quality: 7120, max_durability: 80, progress_div: 129, quality_div: 113, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 89, max_progress: 3440, max_quality: 7140, max_durability: 80, progress_div: 129, quality_div: 113, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 89, max_progress: 3460, max_quality: 7160, max_durability: 80, progress_div: 129, quality_div: 113, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 89, max_progress: 3480, max_quality: 7180, max_durability: 80, progress_div: 129, quality_div: 113, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3500, max_quality: 7200, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3520, max_quality: 7220, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3540, max_quality: 7240, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3560, max_quality: 7260, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3580, max_quality: 7280, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3600, max_quality: 7300, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3620, max_quality: 7320, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3640, max_quality: 7340, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3660, max_quality: 7360, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3680, max_quality: 7380, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3700, max_quality: 7400, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3720, max_quality: 7440, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3740, max_quality: 7480, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3760, max_quality: 7520, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3780, max_quality: 7560, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3800, max_quality: 7600, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3820, max_quality: 7640, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3840, max_quality: 7680, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3860, max_quality: 7720, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3880, max_quality: 7760, max_durability: 80, progress_div: 130, quality_div: 115, progress_mod: 90, quality_mod: 80, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3900, max_quality: 7800, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3940, max_quality: 7820, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 3980, max_quality: 7840, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4020, max_quality: 7860, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4060, max_quality: 7880, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4100, max_quality: 7900, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4140, max_quality: 7920, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4180, max_quality: 7940, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4220, max_quality: 7960, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4260, max_quality: 7980, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4300, max_quality: 8000, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8010, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8020, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8030, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8040, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8050, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8060, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8070, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8080, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8090, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8100, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8110, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8120, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8130, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8140, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8150, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8160, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8170, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8180, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8190, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8200, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 7480, max_quality: 13620, max_durability: 70, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8440, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8460, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8480, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8500, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8520, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8540, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8560, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8580, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8600, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8620, max_durability: 60, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8640, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8660, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8680, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8700, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8720, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8740, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8760, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8780, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8800, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8820, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8840, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8860, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8880, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8900, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8920, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8940, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8960, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 8980, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 9000, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 80, quality_mod: 70, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 9020, max_durability: 60, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 9040, max_durability: 70, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 9060, max_durability: 70, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 9080, max_durability: 70, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 9100, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 9120, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 9140, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 9160, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 90, max_progress: 4400, max_quality: 9180, max_durability: 70, progress_div: 130, quality_div: 115, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 91, max_progress: 4000, max_quality: 8200, max_durability: 80, progress_div: 143, quality_div: 123, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 91, max_progress: 4083, max_quality: 8333, max_durability: 80, progress_div: 143, quality_div: 123, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 91, max_progress: 4166, max_quality: 8466, max_durability: 80, progress_div: 143, quality_div: 123, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 92, max_progress: 4250, max_quality: 8600, max_durability: 80, progress_div: 146, quality_div: 126, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 92, max_progress: 4333, max_quality: 8733, max_durability: 80, progress_div: 146, quality_div: 126, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 92, max_progress: 4416, max_quality: 8866, max_durability: 80, progress_div: 146, quality_div: 126, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 93, max_progress: 4500, max_quality: 9000, max_durability: 80, progress_div: 149, quality_div: 129, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 93, max_progress: 4575, max_quality: 9100, max_durability: 80, progress_div: 149, quality_div: 129, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 93, max_progress: 4650, max_quality: 9200, max_durability: 80, progress_div: 149, quality_div: 129, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 93, max_progress: 4725, max_quality: 9300, max_durability: 80, progress_div: 149, quality_div: 129, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 94, max_progress: 4800, max_quality: 9400, max_durability: 80, progress_div: 152, quality_div: 132, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 94, max_progress: 4860, max_quality: 9480, max_durability: 80, progress_div: 152, quality_div: 132, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 94, max_progress: 4920, max_quality: 9560, max_durability: 80, progress_div: 152, quality_div: 132, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 94, max_progress: 4980, max_quality: 9640, max_durability: 80, progress_div: 152, quality_div: 132, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 94, max_progress: 5040, max_quality: 9720, max_durability: 80, progress_div: 152, quality_div: 132, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 95, max_progress: 5100, max_quality: 9800, max_durability: 80, progress_div: 155, quality_div: 135, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 95, max_progress: 5160, max_quality: 9880, max_durability: 80, progress_div: 155, quality_div: 135, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 95, max_progress: 5220, max_quality: 9960, max_durability: 80, progress_div: 155, quality_div: 135, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 95, max_progress: 5280, max_quality: 10040, max_durability: 80, progress_div: 155, quality_div: 135, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 95, max_progress: 5340, max_quality: 10120, max_durability: 80, progress_div: 155, quality_div: 135, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 96, max_progress: 5400, max_quality: 10200, max_durability: 80, progress_div: 158, quality_div: 138, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 96, max_progress: 5460, max_quality: 10280, max_durability: 80, progress_div: 158, quality_div: 138, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 96, max_progress: 5520, max_quality: 10360, max_durability: 80, progress_div: 158, quality_div: 138, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 96, max_progress: 5580, max_quality: 10440, max_durability: 80, progress_div: 158, quality_div: 138, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 96, max_progress: 5640, max_quality: 10520, max_durability: 80, progress_div: 158, quality_div: 138, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 97, max_progress: 5700, max_quality: 10600, max_durability: 80, progress_div: 161, quality_div: 141, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 97, max_progress: 5760, max_quality: 10680, max_durability: 80, progress_div: 161, quality_div: 141, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 97, max_progress: 5820, max_quality: 10760, max_durability: 80, progress_div: 161, quality_div: 141, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 97, max_progress: 5880, max_quality: 10840, max_durability: 80, progress_div: 161, quality_div: 141, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 97, max_progress: 5940, max_quality: 10920, max_durability: 80, progress_div: 161, quality_div: 141, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 98, max_progress: 6000, max_quality: 11000, max_durability: 80, progress_div: 164, quality_div: 144, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 98, max_progress: 6060, max_quality: 11080, max_durability: 80, progress_div: 164, quality_div: 144, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 98, max_progress: 6120, max_quality: 11160, max_durability: 80, progress_div: 164, quality_div: 144, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 98, max_progress: 6180, max_quality: 11240, max_durability: 80, progress_div: 164, quality_div: 144, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 98, max_progress: 6240, max_quality: 11320, max_durability: 80, progress_div: 164, quality_div: 144, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 99, max_progress: 6300, max_quality: 11400, max_durability: 80, progress_div: 167, quality_div: 147, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 99, max_progress: 6360, max_quality: 11520, max_durability: 80, progress_div: 167, quality_div: 147, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 99, max_progress: 6420, max_quality: 11640, max_durability: 80, progress_div: 167, quality_div: 147, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 99, max_progress: 6480, max_quality: 11760, max_durability: 80, progress_div: 167, quality_div: 147, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 99, max_progress: 6540, max_quality: 11880, max_durability: 80, progress_div: 167, quality_div: 147, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 7500, max_quality: 15000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 8050, max_quality: 17600, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 9500, max_quality: 20000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 9000, max_quality: 18700, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 25000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 10000, max_quality: 10000, max_durability: 80, progress_div: 180, quality_div: 180, progress_mod: 100, quality_mod: 100, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 80, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
+RecipeLevel { job_level: 100, max_progress: 6600, max_quality: 12000, max_durability: 70, progress_div: 170, quality_div: 150, progress_mod: 90, quality_mod: 75, conditions_flag: 0, },
 ]
@@ -0,0 +1,30 @@
+use raphael_sim::Action;
+
+/// Action icons packed into one single-row image per crafting job, `assets/action-icons/atlas-{job_id}.webp`.
+/// All jobs share the same layout, so that only the atlas of the selected job has to be loaded.
+/// Both the images and the positions of the icons are generated by `raphael-data-updater`.
+#[derive(Debug, Clone, Copy)]
+pub struct ActionIconAtlas {
+    pub width: u32,
+    pub height: u32,
+    pub icon_size: u32,
+    pub icons: &'static [ActionIcon],
+}
+
+/// Position of the left edge of an icon in the atlas, in pixels
+#[derive(Debug, Clone, Copy)]
+pub struct ActionIcon {
+    pub action: Action,
+    pub x: u32,
+}
+
+pub const ACTION_ICON_ATLAS: ActionIconAtlas = include!("../data/action_icons.rs");
+
+/// Number of action icon atlases, one per crafting job
+pub const ACTION_ICON_ATLAS_COUNT: u8 = 8;
+
+impl ActionIconAtlas {
+    pub fn find(&self, action: Action) -> Option<&ActionIcon> {
+        self.icons.iter().find(|icon| icon.action == action)
+    }
+}
@@ -3,7 +3,7 @@ use std::fmt::Write;
 pub const MEALS: &[Consumable] = include!("../data/meals.rs");
 pub const POTIONS: &[Consumable] = include!("../data/potions.rs");
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Consumable {
     pub item_id: u32,
@@ -83,6 +83,21 @@ pub fn cp_bonus(base: u16, consumables: &[Option<Consumable>]) -> u16 {
         .sum()
 }
 
+/// Returns the amount of Craftsmanship and Control that is missing to meet the stat requirements of the recipe.
+pub fn missing_stats(
+    recipe: &crate::Recipe,
+    crafter_stats: crate::CrafterStats,
+    consumables: &[Option<Consumable>],
+) -> (u16, u16) {
+    let craftsmanship =
+        crafter_stats.craftsmanship + craftsmanship_bonus(crafter_stats.craftsmanship, consumables);
+    let control = crafter_stats.control + control_bonus(crafter_stats.control, consumables);
+    (
+        recipe.req_craftsmanship.saturating_sub(craftsmanship),
+        recipe.req_control.saturating_sub(control),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{Locale, get_item_name};
@@ -93,12 +108,10 @@ mod tests {
         MEALS
             .iter()
             .chain(POTIONS.iter())
-            .find(
-                |consumable| match get_item_name(consumable.item_id, consumable.hq, Locale::EN) {
-                    None => false,
-                    Some(name) => name == item_name,
-                },
-            )
+            .find(|consumable| {
+                get_item_name(consumable.item_id, consumable.hq, Locale::EN)
+                    .is_some_and(|name| name == item_name)
+            })
             .copied()
     }
 
@@ -113,6 +126,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_missing_stats() {
+        let recipe = crate::Recipe {
+            req_craftsmanship: 4100,
+            req_control: 4050,
+            ..*crate::RECIPES.values().next().unwrap()
+        };
+        let crafter_stats = crate::CrafterStats {
+            craftsmanship: 4021,
+            control: 4023,
+            ..Default::default()
+        };
+        assert_eq!(missing_stats(&recipe, crafter_stats, &[]), (79, 27));
+        let consumable = find_consumable("Rroneek Steak \u{e03c}");
+        assert_eq!(
+            missing_stats(&recipe, crafter_stats, &[consumable]),
+            (79, 0)
+        );
+    }
+
     #[test]
     fn test_rroneek_steak_hq() {
         let consumable = find_consumable("Rroneek Steak \u{e03c}").unwrap();
@@ -10,7 +10,16 @@ pub use locales::*;
 mod search;
 pub use search::*;
 
-use raphael_sim::{Action, ActionMask, Settings};
+mod action_icons;
+pub use action_icons::*;
+
+mod verification;
+pub use verification::*;
+
+use raphael_sim::{
+    Action, ActionMask, Condition, ConditionProbabilities, MacroEstimate, Settings,
+    SimulationState, TraitOverrides, estimate_macro,
+};
 
 pub const HQ_ICON_CHAR: char = '\u{e03c}';
 pub const CL_ICON_CHAR: char = '\u{e03d}';
@@ -39,6 +48,9 @@ pub struct RecipeLevel {
     pub quality_div: u32,
     pub progress_mod: u32,
     pub quality_mod: u32,
+    /// Conditions that recipes of this level can roll, see [`CONDITION_FLAGS`].
+    /// `0` for levels whose flags haven't been imported yet.
+    pub conditions_flag: u16,
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
@@ -49,6 +61,9 @@ pub struct CustomRecipeOverrides {
     pub max_durability_override: u16,
     pub base_progress_override: Option<u16>,
     pub base_quality_override: Option<u16>,
+    /// Chances of the conditions that replace the approximate chances of [`condition_probabilities`]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub condition_probabilities_override: Option<ConditionProbabilities>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -73,6 +88,17 @@ pub const LEVEL_ADJUST_TABLE: &[u16] = include!("../data/level_adjust_table.rs")
 pub static RECIPES: phf::OrderedMap<u32, Recipe> = include!("../data/recipes.rs");
 pub const ITEMS: phf::OrderedMap<u32, Item> = include!("../data/items.rs");
 
+/// Recipe level that the stats of a crafter with `job_level` are compared against.
+/// Level-scaling recipes are synced to the recipe level of the job level, capped at their max level.
+pub fn effective_recipe_level(recipe: &Recipe, job_level: u8) -> u16 {
+    if recipe.max_level_scaling != 0 {
+        let job_level = std::cmp::min(recipe.max_level_scaling, job_level);
+        LEVEL_ADJUST_TABLE[job_level as usize]
+    } else {
+        recipe.recipe_level
+    }
+}
+
 pub fn get_game_settings(
     recipe: Recipe,
     custom_recipe_overrides: Option<CustomRecipeOverrides>,
@@ -80,14 +106,7 @@ pub fn get_game_settings(
     food: Option<Consumable>,
     potion: Option<Consumable>,
 ) -> Settings {
-    let rlvl = if recipe.max_level_scaling != 0 {
-        let job_level = std::cmp::min(recipe.max_level_scaling, crafter_stats.level);
-        LEVEL_ADJUST_TABLE[job_level as usize] as usize
-    } else {
-        recipe.recipe_level as usize
-    };
-
-    let mut rlvl_record = RLVLS[rlvl];
+    let mut rlvl_record = RLVLS[effective_recipe_level(&recipe, crafter_stats.level) as usize];
     if recipe.max_level_scaling != 0 {
         // https://github.com/KonaeAkira/raphael-rs/pull/126#issuecomment-2832041490
         rlvl_record.max_durability = 80;
@@ -118,6 +137,10 @@ pub fn get_game_settings(
     if !crafter_stats.quick_innovation {
         allowed_actions = allowed_actions.remove(Action::QuickInnovation);
     }
+    // only specialists, who are the only ones to have either of the actions above, can use delineations
+    if !crafter_stats.heart_and_soul && !crafter_stats.quick_innovation {
+        allowed_actions = allowed_actions.remove(Action::CarefulObservation);
+    }
 
     match custom_recipe_overrides {
         Some(overrides) => Settings {
@@ -137,6 +160,7 @@ pub fn get_game_settings(
             allowed_actions,
             adversarial: false,
             backload_progress: false,
+            traits: TraitOverrides::none(),
         },
         None => Settings {
             max_cp: cp as _,
@@ -149,6 +173,7 @@ pub fn get_game_settings(
             allowed_actions,
             adversarial: false,
             backload_progress: false,
+            traits: TraitOverrides::none(),
         },
     }
 }
@@ -194,6 +219,158 @@ pub fn get_initial_quality(
     }
 }
 
+/// Finds the fewest HQ ingredients whose initial Quality is at least `target_initial_quality`.
+/// Among combinations with the same number of HQ ingredients, the one that overshoots the target the least is returned.
+/// Returns `None` if the target can't be reached even with all ingredients HQ.
+pub fn get_minimal_hq_ingredients(
+    crafter_stats: CrafterStats,
+    recipe: Recipe,
+    target_initial_quality: u16,
+) -> Option<[u8; 6]> {
+    let max_amounts = recipe
+        .ingredients
+        .map(|ingredient| match ITEMS.get(&ingredient.item_id) {
+            Some(item) if item.can_be_hq => ingredient.amount as u8,
+            _ => 0,
+        });
+    let mut best: Option<(u32, u16, [u8; 6])> = None;
+    let mut hq_ingredients = [0; 6];
+    loop {
+        let count: u32 = hq_ingredients.iter().map(|&amount| u32::from(amount)).sum();
+        if best.is_none_or(|(best_count, _, _)| count <= best_count) {
+            let quality = get_initial_quality(crafter_stats, recipe, hq_ingredients);
+            if quality >= target_initial_quality
+                && best.is_none_or(|(best_count, best_quality, _)| {
+                    (count, quality) < (best_count, best_quality)
+                })
+            {
+                best = Some((count, quality, hq_ingredients));
+            }
+        }
+        // advance to the next combination, or stop once all of them have been visited
+        let Some(index) = (0..6).find(|&index| hq_ingredients[index] < max_amounts[index]) else {
+            break;
+        };
+        hq_ingredients[index] += 1;
+        hq_ingredients[..index].fill(0);
+    }
+    best.map(|(_, _, hq_ingredients)| hq_ingredients)
+}
+
+/// For every number of HQ ingredients from zero up to all of them, the combination with the most initial Quality, together with that Quality.
+/// The `n`-th entry is for `n` HQ ingredients.
+pub fn get_best_hq_ingredients_per_count(
+    crafter_stats: CrafterStats,
+    recipe: Recipe,
+) -> Vec<([u8; 6], u16)> {
+    let max_amounts = recipe
+        .ingredients
+        .map(|ingredient| match ITEMS.get(&ingredient.item_id) {
+            Some(item) if item.can_be_hq => ingredient.amount as u8,
+            _ => 0,
+        });
+    let max_count: usize = max_amounts.iter().map(|&amount| usize::from(amount)).sum();
+    let mut best: Vec<Option<([u8; 6], u16)>> = vec![None; max_count + 1];
+    let mut hq_ingredients = [0; 6];
+    loop {
+        let count: usize = hq_ingredients
+            .iter()
+            .map(|&amount| usize::from(amount))
+            .sum();
+        let quality = get_initial_quality(crafter_stats, recipe, hq_ingredients);
+        if best[count].is_none_or(|(_, best_quality)| quality > best_quality) {
+            best[count] = Some((hq_ingredients, quality));
+        }
+        // advance to the next combination, or stop once all of them have been visited
+        let Some(index) = (0..6).find(|&index| hq_ingredients[index] < max_amounts[index]) else {
+            break;
+        };
+        hq_ingredients[index] += 1;
+        hq_ingredients[..index].fill(0);
+    }
+    // every count in between is reachable, so all entries have been filled
+    best.into_iter().map(Option::unwrap).collect()
+}
+
+/// Approximate time it takes to Quick Synthesize a single item, in seconds, whether the synthesis succeeds or not.
+pub const QUICK_SYNTHESIS_SECONDS_PER_ATTEMPT: u32 = 3;
+
+/// Outcome of Quick Synthesis over `crafts` simulated crafts in which the conditions are rolled at random, see [`estimate_macro`].
+/// Quick Synthesis is modeled as using Basic Synthesis until the item is complete or the durability runs out,
+/// so it never produces HQ items and conditions like Sturdy or Malleable decide whether it succeeds.
+/// Returns `None` for expert recipes, which can't be quick synthesized.
+pub fn estimate_quick_synthesis(
+    recipe: Recipe,
+    crafter_stats: CrafterStats,
+    food: Option<Consumable>,
+    potion: Option<Consumable>,
+    crafts: u32,
+) -> Option<MacroEstimate> {
+    if recipe.is_expert {
+        return None;
+    }
+    let settings = get_game_settings(recipe, None, crafter_stats, food, potion);
+    // Basic Synthesis uses at least 5 durability, so this is enough steps in any conditions
+    let actions = vec![Action::BasicSynthesis; usize::from(settings.max_durability.div_ceil(5))];
+    Some(estimate_macro(
+        &settings,
+        SimulationState::new(&settings),
+        &actions,
+        0,
+        condition_probabilities(recipe, None),
+        crafts,
+    ))
+}
+
+/// Bits of [`RecipeLevel::conditions_flag`] of the conditions that are modeled by the simulator.
+/// The game's flags also have a bit for Good Omen, which is not simulated.
+pub const CONDITION_FLAGS: [(u16, Condition); 9] = [
+    (1 << 0, Condition::Normal),
+    (1 << 1, Condition::Good),
+    (1 << 2, Condition::Excellent),
+    (1 << 3, Condition::Poor),
+    (1 << 4, Condition::Centered),
+    (1 << 5, Condition::Sturdy),
+    (1 << 6, Condition::Pliant),
+    (1 << 7, Condition::Malleable),
+    (1 << 8, Condition::Primed),
+];
+
+/// Conditions the recipe can roll that are modeled by the simulator, taken from the condition flags of its recipe level.
+/// If the flags of the level haven't been imported, regular recipes get Normal, Good, Excellent and Poor, and expert recipes only Normal and Good,
+/// since which of their own conditions an expert recipe rolls isn't known.
+pub fn modeled_conditions(recipe: Recipe) -> Vec<Condition> {
+    let conditions_flag = match RLVLS.get(usize::from(recipe.recipe_level)) {
+        Some(rlvl) if rlvl.conditions_flag != 0 => rlvl.conditions_flag,
+        _ if recipe.is_expert => 0b11,
+        _ => 0b1111,
+    };
+    CONDITION_FLAGS
+        .iter()
+        .filter(|(flag, _)| conditions_flag & flag != 0)
+        .map(|(_, condition)| *condition)
+        .collect()
+}
+
+/// Approximate chances of the conditions the recipe rolls, used when estimating how a macro fares in random conditions.
+/// Every regular recipe gets the same chances and so does every expert recipe, out of the conditions of [`modeled_conditions`],
+/// unless the custom recipe overrides its chances.
+pub fn condition_probabilities(
+    recipe: Recipe,
+    custom_recipe_overrides: Option<CustomRecipeOverrides>,
+) -> ConditionProbabilities {
+    if let Some(probabilities) =
+        custom_recipe_overrides.and_then(|overrides| overrides.condition_probabilities_override)
+    {
+        return probabilities;
+    }
+    let probabilities = match recipe.is_expert {
+        true => ConditionProbabilities::EXPERT_RECIPE,
+        false => ConditionProbabilities::REGULAR_RECIPE,
+    };
+    probabilities.restricted_to(&modeled_conditions(recipe))
+}
+
 const HQ_LOOKUP: [u8; 101] = [
     1, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 6, 6, 6, 6, 7, 7, 7, 7, 8, 8, 8,
     9, 9, 9, 10, 10, 10, 11, 11, 11, 12, 12, 12, 13, 13, 13, 14, 14, 14, 15, 15, 15, 16, 16, 17,
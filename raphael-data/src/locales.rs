@@ -9,6 +9,8 @@ pub enum Locale {
     FR,
     JP,
     CN,
+    /// Traditional Chinese
+    TW,
     KR,
 }
 
@@ -20,6 +22,7 @@ impl std::fmt::Display for Locale {
             Self::FR => write!(f, "FR"),
             Self::JP => write!(f, "JP"),
             Self::CN => write!(f, "CN"),
+            Self::TW => write!(f, "TW"),
             Self::KR => write!(f, "KR"),
         }
     }
@@ -29,6 +32,7 @@ const JOB_NAMES_EN: [&str; 8] = ["CRP", "BSM", "ARM", "GSM", "LTW", "WVR", "ALC"
 const JOB_NAMES_DE: [&str; 8] = ["ZMR", "GRS", "PLA", "GLD", "GER", "WEB", "ALC", "GRM"];
 const JOB_NAMES_FR: [&str; 8] = ["MEN", "FRG", "ARM", "ORF", "TAN", "COU", "ALC", "CUI"];
 const JOB_NAMES_CN: [&str; 8] = ["刻木", "锻铁", "铸甲", "雕金", "制革", "裁衣", "炼金", "烹调"];
+const JOB_NAMES_TW: [&str; 8] = ["刻木", "鍛鐵", "鑄甲", "雕金", "製革", "裁衣", "煉金", "烹調"];
 const JOB_NAMES_KR: [&str; 8] = ["목수", "대장", "갑주", "보석", "가죽", "재봉", "연금", "요리"];
 
 pub fn get_job_name(job_id: u8, locale: Locale) -> &'static str {
@@ -38,6 +42,7 @@ pub fn get_job_name(job_id: u8, locale: Locale) -> &'static str {
         Locale::FR => JOB_NAMES_FR[job_id as usize],
         Locale::JP => JOB_NAMES_EN[job_id as usize], // JP job abbreviations are the same as EN
         Locale::CN => JOB_NAMES_CN[job_id as usize],
+        Locale::TW => JOB_NAMES_TW[job_id as usize],
         Locale::KR => JOB_NAMES_KR[job_id as usize],
     }
 }
@@ -47,6 +52,7 @@ pub static ITEM_NAMES_DE: phf::Map<u32, &str> = include!("../data/item_names_de.
 pub static ITEM_NAMES_FR: phf::Map<u32, &str> = include!("../data/item_names_fr.rs");
 pub static ITEM_NAMES_JP: phf::Map<u32, &str> = include!("../data/item_names_jp.rs");
 pub static ITEM_NAMES_CN: phf::Map<u32, &str> = include!("../data/item_names_cn.rs");
+pub static ITEM_NAMES_TW: phf::Map<u32, &str> = include!("../data/item_names_tw.rs");
 pub static ITEM_NAMES_KR: phf::Map<u32, &str> = include!("../data/item_names_kr.rs");
 
 pub fn get_item_name_raw(item_id: u32, locale: Locale) -> Option<&'static str> {
@@ -56,6 +62,11 @@ pub fn get_item_name_raw(item_id: u32, locale: Locale) -> Option<&'static str> {
         Locale::FR => ITEM_NAMES_FR.get(&item_id).copied(),
         Locale::JP => ITEM_NAMES_JP.get(&item_id).copied(),
         Locale::CN => ITEM_NAMES_CN.get(&item_id).copied(),
+        // items that don't have a Traditional Chinese name yet are shown with their Simplified Chinese name
+        Locale::TW => ITEM_NAMES_TW
+            .get(&item_id)
+            .or_else(|| ITEM_NAMES_CN.get(&item_id))
+            .copied(),
         Locale::KR => ITEM_NAMES_KR.get(&item_id).copied(),
     }
 }
@@ -99,6 +110,7 @@ pub const fn action_name(action: Action, locale: Locale) -> &'static str {
         Locale::FR => action_name_fr(action),
         Locale::JP => action_name_jp(action),
         Locale::CN => action_name_cn(action),
+        Locale::TW => action_name_tw(action),
         Locale::KR => action_name_kr(action),
     }
 }
@@ -134,6 +146,7 @@ const fn action_name_en(action: Action) -> &'static str {
         Action::RefinedTouch => "Refined Touch",
         Action::ImmaculateMend => "Immaculate Mend",
         Action::TrainedPerfection => "Trained Perfection",
+        Action::CarefulObservation => "Careful Observation",
         Action::TrainedEye => "Trained Eye",
         Action::QuickInnovation => "Quick Innovation",
     }
@@ -170,6 +183,7 @@ const fn action_name_de(action: Action) -> &'static str {
         Action::RefinedTouch => "Raffinierte Veredelung",
         Action::ImmaculateMend => "Winkelzug",
         Action::TrainedPerfection => "Meisters Beitrag",
+        Action::CarefulObservation => "Sorgfältige Beobachtung",
         Action::TrainedEye => "Flinke Hand",
         Action::QuickInnovation => "Spontane Innovation",
     }
@@ -206,6 +220,7 @@ const fn action_name_fr(action: Action) -> &'static str {
         Action::RefinedTouch => "Ouvrage raffiné",
         Action::ImmaculateMend => "Réparation totale",
         Action::TrainedPerfection => "Main suprême",
+        Action::CarefulObservation => "Observation méticuleuse",
         Action::TrainedEye => "Main preste",
         Action::QuickInnovation => "Innovation instantanée",
     }
@@ -242,6 +257,7 @@ const fn action_name_jp(action: Action) -> &'static str {
         Action::RefinedTouch => "洗練加工",
         Action::ImmaculateMend => "パーフェクトメンド",
         Action::TrainedPerfection => "匠の絶技",
+        Action::CarefulObservation => "設計変更",
         Action::TrainedEye => "匠の早業",
         Action::QuickInnovation => "クイックイノベーション",
     }
@@ -278,6 +294,44 @@ const fn action_name_cn(action: Action) -> &'static str {
         Action::RefinedTouch => "精炼加工",
         Action::ImmaculateMend => "巧夺天工",
         Action::TrainedPerfection => "工匠的绝技",
+        Action::CarefulObservation => "设计变动",
+        Action::TrainedEye => "工匠的神速技巧",
+        Action::QuickInnovation => "快速改革",
+    }
+}
+
+const fn action_name_tw(action: Action) -> &'static str {
+    match action {
+        Action::BasicSynthesis => "製作",
+        Action::BasicTouch => "加工",
+        Action::MasterMend => "精修",
+        Action::Observe => "觀察",
+        Action::TricksOfTheTrade => "秘訣",
+        Action::WasteNot => "儉約",
+        Action::Veneration => "崇敬",
+        Action::StandardTouch => "中級加工",
+        Action::GreatStrides => "闊步",
+        Action::Innovation => "改革",
+        Action::WasteNot2 => "長期儉約",
+        Action::ByregotsBlessing => "比爾格的祝福",
+        Action::PreciseTouch => "集中加工",
+        Action::MuscleMemory => "堅信",
+        Action::CarefulSynthesis => "模範製作",
+        Action::Manipulation => "掌握",
+        Action::PrudentTouch => "儉約加工",
+        Action::AdvancedTouch => "上級加工",
+        Action::Reflect => "閒靜",
+        Action::PreparatoryTouch => "坯料加工",
+        Action::Groundwork => "坯料製作",
+        Action::DelicateSynthesis => "精密製作",
+        Action::IntensiveSynthesis => "集中製作",
+        Action::HeartAndSoul => "專心致志",
+        Action::PrudentSynthesis => "儉約製作",
+        Action::TrainedFinesse => "工匠的神技",
+        Action::RefinedTouch => "精煉加工",
+        Action::ImmaculateMend => "巧奪天工",
+        Action::TrainedPerfection => "工匠的絕技",
+        Action::CarefulObservation => "設計變動",
         Action::TrainedEye => "工匠的神速技巧",
         Action::QuickInnovation => "快速改革",
     }
@@ -314,6 +368,7 @@ const fn action_name_kr(action: Action) -> &'static str {
         Action::RefinedTouch => "세련 가공",
         Action::ImmaculateMend => "완벽한 땜질",
         Action::TrainedPerfection => "장인의 초절 기술",
+        Action::CarefulObservation => "설계 변경",
         Action::TrainedEye => "장인의 날랜손",
         Action::QuickInnovation => "신속한 혁신",
     }
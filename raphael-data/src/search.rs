@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
 use crate::{
     CL_ICON_CHAR, Consumable, HQ_ICON_CHAR, Locale, MEALS, POTIONS, RECIPES, get_item_name,
 };
@@ -23,18 +26,176 @@ fn preprocess_pattern(pattern: &str) -> String {
         .replace([HQ_ICON_CHAR, CL_ICON_CHAR], "")
 }
 
-pub fn find_recipes(search_string: &str, locale: Locale) -> Vec<u32> {
-    let pattern = preprocess_pattern(search_string);
-    RECIPES
-        .entries()
-        .filter_map(|(recipe_id, recipe)| {
-            let item_name = crate::get_recipe_name(&recipe, false, locale)?;
-            match contains_noncontiguous(&item_name.to_lowercase(), &pattern) {
-                true => Some(*recipe_id),
-                false => None,
+/// How well a recipe name matches the search pattern, better matches are listed first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum MatchKind {
+    /// Every word of the pattern is within a few typos of a part of a word of the name
+    Fuzzy,
+    /// The pattern consists of the initials of words of the name
+    Initials,
+    /// Every word of the pattern is contained in a word of the name
+    Substring,
+}
+
+/// Recipe names of one locale, preprocessed once so that searching doesn't need to look up and normalize every name again
+struct RecipeSearchIndex {
+    /// Recipe ID and normalized name of every recipe, in the order of `RECIPES`
+    entries: Vec<(u32, String)>,
+    /// Indices into `entries` of the names that contain the trigram within a single word, in ascending order
+    trigrams: HashMap<[char; 3], Vec<u32>>,
+}
+
+impl RecipeSearchIndex {
+    fn new(locale: Locale) -> Self {
+        let entries: Vec<(u32, String)> = RECIPES
+            .entries()
+            .filter_map(|(recipe_id, recipe)| {
+                let item_name = crate::get_recipe_name(recipe, false, locale)?;
+                Some((*recipe_id, preprocess_pattern(&item_name)))
+            })
+            .collect();
+        let mut trigrams: HashMap<[char; 3], Vec<u32>> = HashMap::new();
+        for (index, (_, name)) in (0..).zip(&entries) {
+            for word in name.split_whitespace() {
+                let chars: Vec<char> = word.chars().collect();
+                for trigram in chars.windows(3) {
+                    let postings = trigrams
+                        .entry([trigram[0], trigram[1], trigram[2]])
+                        .or_default();
+                    if postings.last() != Some(&index) {
+                        postings.push(index);
+                    }
+                }
+            }
+        }
+        Self { entries, trigrams }
+    }
+
+    fn get(locale: Locale) -> &'static Self {
+        static INDICES: [OnceLock<RecipeSearchIndex>; 7] = [const { OnceLock::new() }; 7];
+        INDICES[locale as usize].get_or_init(|| Self::new(locale))
+    }
+
+    /// Entries that can possibly be a fuzzy match, or `None` if the pattern is too short to rule out any entry.
+    /// Each typo changes at most three trigrams of a word, so a match shares all other trigrams of the pattern word.
+    fn fuzzy_candidates(&self, pattern_words: &[Vec<char>]) -> Option<Vec<bool>> {
+        let mut candidates: Option<Vec<bool>> = None;
+        for word in pattern_words {
+            let trigram_count = word.len().saturating_sub(2);
+            let required_count = trigram_count.saturating_sub(3 * max_typos(word.len()));
+            if required_count == 0 {
+                continue;
             }
+            let mut shared_counts = vec![0; self.entries.len()];
+            let mut seen = Vec::new();
+            for trigram in word.windows(3) {
+                let trigram = [trigram[0], trigram[1], trigram[2]];
+                if seen.contains(&trigram) {
+                    continue;
+                }
+                seen.push(trigram);
+                for &index in self.trigrams.get(&trigram).into_iter().flatten() {
+                    shared_counts[index as usize] += 1;
+                }
+            }
+            let candidates = candidates.get_or_insert_with(|| vec![true; self.entries.len()]);
+            for (candidate, shared_count) in candidates.iter_mut().zip(shared_counts) {
+                *candidate &= shared_count >= required_count;
+            }
+        }
+        candidates
+    }
+
+    fn find(&self, pattern: &str) -> Vec<u32> {
+        let pattern_words: Vec<Vec<char>> = pattern
+            .split_whitespace()
+            .map(|word| word.chars().collect())
+            .collect();
+        let fuzzy_candidates = self.fuzzy_candidates(&pattern_words);
+        let mut matches: Vec<(MatchKind, u32)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, (recipe_id, name))| {
+                let match_kind = if contains_noncontiguous(name, pattern) {
+                    MatchKind::Substring
+                } else if pattern_words.len() == 1 && matches_initials(name, &pattern_words[0]) {
+                    MatchKind::Initials
+                } else if fuzzy_candidates
+                    .as_ref()
+                    .is_none_or(|candidates| candidates[index])
+                    && matches_fuzzy(name, &pattern_words)
+                {
+                    MatchKind::Fuzzy
+                } else {
+                    return None;
+                };
+                Some((match_kind, *recipe_id))
+            })
+            .collect();
+        // stable sort keeps the order of `RECIPES` among equally good matches
+        matches.sort_by_key(|m| std::cmp::Reverse(m.0));
+        matches
+            .into_iter()
+            .map(|(_, recipe_id)| recipe_id)
+            .collect()
+    }
+}
+
+/// Number of typos that are tolerated in a pattern word of the given length
+fn max_typos(word_length: usize) -> usize {
+    match word_length {
+        0..4 => 0,
+        4..8 => 1,
+        _ => 2,
+    }
+}
+
+fn matches_initials(string: &str, pattern: &[char]) -> bool {
+    if pattern.len() < 2 {
+        return false;
+    }
+    let mut initials = string
+        .split_whitespace()
+        .filter_map(|word| word.chars().next());
+    pattern
+        .iter()
+        .all(|c| initials.any(|initial| initial == *c))
+}
+
+/// Same as `contains_noncontiguous`, but every pattern word may have a few typos
+fn matches_fuzzy(string: &str, pattern_words: &[Vec<char>]) -> bool {
+    let mut words = string.split_whitespace();
+    pattern_words.iter().all(|pattern_word| {
+        words.any(|word| {
+            let word: Vec<char> = word.chars().collect();
+            substring_edit_distance(pattern_word, &word) <= max_typos(pattern_word.len())
         })
-        .collect()
+    })
+}
+
+/// Smallest edit distance between `pattern` and any substring of `text`
+fn substring_edit_distance(pattern: &[char], text: &[char]) -> usize {
+    // distances[j] is the distance between the current prefix of the pattern and the best substring of text ending at j
+    let mut distances = vec![0; text.len() + 1];
+    for (i, &p) in pattern.iter().enumerate() {
+        let mut diagonal = distances[0];
+        distances[0] = i + 1;
+        for (j, &t) in text.iter().enumerate() {
+            let distance = (diagonal + usize::from(p != t))
+                .min(distances[j] + 1)
+                .min(distances[j + 1] + 1);
+            diagonal = distances[j + 1];
+            distances[j + 1] = distance;
+        }
+    }
+    distances.into_iter().min().unwrap_or(0)
+}
+
+/// Finds recipes whose name matches the search string, best matches first.
+/// Besides names containing every word of the search string, this also finds names by their initials and names with typos in the search string.
+pub fn find_recipes(search_string: &str, locale: Locale) -> Vec<u32> {
+    RecipeSearchIndex::get(locale).find(&preprocess_pattern(search_string))
 }
 
 fn find_consumables(search_string: &str, locale: Locale, consumables: &[Consumable]) -> Vec<usize> {
@@ -0,0 +1,351 @@
+use raphael_sim::{Action, ActionMask, Condition, Settings, SimulationState};
+
+use crate::{Consumable, CrafterStats, CustomRecipeOverrides, LEVEL_ADJUST_TABLE, RLVLS, Recipe};
+
+/// A value that the game settings or the simulator computed differently from the reference implementation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalculationMismatch {
+    pub description: String,
+    /// Value according to the reference implementation
+    pub expected: u32,
+    /// Value according to `get_game_settings` or the simulator
+    pub actual: u32,
+}
+
+impl std::fmt::Display for CalculationMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: expected {}, got {}",
+            self.description, self.expected, self.actual
+        )
+    }
+}
+
+/// Stat including the bonuses of food and potion, each of which is floored and capped on its own.
+fn stat_with_bonus(
+    base: u16,
+    consumables: &[Option<Consumable>],
+    bonus: fn(&Consumable) -> (u16, u16),
+) -> u32 {
+    consumables
+        .iter()
+        .flatten()
+        .map(|consumable| {
+            let (rel, max) = bonus(consumable);
+            std::cmp::min(u32::from(base) * u32::from(rel) / 100, u32::from(max))
+        })
+        .fold(u32::from(base), |stat, bonus| stat + bonus)
+}
+
+/// Reference values of the game settings, computed in integer arithmetic so that no step depends on floating point rounding.
+struct ReferenceSettings {
+    max_cp: u32,
+    max_durability: u32,
+    max_progress: u32,
+    max_quality: u32,
+    base_progress: u32,
+    base_quality: u32,
+}
+
+impl ReferenceSettings {
+    fn new(
+        recipe: Recipe,
+        crafter_stats: CrafterStats,
+        food: Option<Consumable>,
+        potion: Option<Consumable>,
+    ) -> Self {
+        let consumables = [food, potion];
+        let craftsmanship = stat_with_bonus(crafter_stats.craftsmanship, &consumables, |c| {
+            (c.craft_rel, c.craft_max)
+        });
+        let control = stat_with_bonus(crafter_stats.control, &consumables, |c| {
+            (c.control_rel, c.control_max)
+        });
+        let cp = stat_with_bonus(crafter_stats.cp, &consumables, |c| (c.cp_rel, c.cp_max));
+
+        // level-synced recipes use the recipe level of the synced job level
+        let level_synced = recipe.max_level_scaling != 0;
+        let rlvl_record = match level_synced {
+            true => {
+                let job_level = std::cmp::min(recipe.max_level_scaling, crafter_stats.level);
+                RLVLS[usize::from(LEVEL_ADJUST_TABLE[usize::from(job_level)])]
+            }
+            false => RLVLS[usize::from(recipe.recipe_level)],
+        };
+
+        // base = floor((stat * 10 / div + offset) * mod / 100), with the division by `div` kept exact until the end
+        let base_value = |stat: u32, div: u32, offset: u32, modifier: u32| -> u32 {
+            let numerator = u64::from(stat) * 10 + u64::from(offset) * u64::from(div);
+            match crafter_stats.level <= rlvl_record.job_level {
+                true => (numerator * u64::from(modifier) / (u64::from(div) * 100)) as u32,
+                false => (numerator / u64::from(div)) as u32,
+            }
+        };
+        Self {
+            max_cp: cp,
+            max_durability: match level_synced {
+                true => 80 * u32::from(recipe.durability_factor) / 100,
+                false => {
+                    u32::from(rlvl_record.max_durability) * u32::from(recipe.durability_factor)
+                        / 100
+                }
+            },
+            max_progress: rlvl_record.max_progress * recipe.progress_factor / 100,
+            max_quality: rlvl_record.max_quality * recipe.quality_factor / 100,
+            base_progress: base_value(
+                craftsmanship,
+                rlvl_record.progress_div,
+                2,
+                rlvl_record.progress_mod,
+            ),
+            base_quality: base_value(
+                control,
+                rlvl_record.quality_div,
+                35,
+                rlvl_record.quality_mod,
+            ),
+        }
+    }
+}
+
+/// Action whose Progress and Quality increase is checked, with the actions used before it.
+struct ActionScenario {
+    /// Actions used before the checked action, whose own increases aren't checked
+    setup: &'static [Action],
+    condition: Condition,
+    action: Action,
+    /// Inner Quiet stacks that the setup grants once the trait is learned
+    inner_quiet: u32,
+}
+
+const ACTION_SCENARIOS: &[ActionScenario] = &[
+    ActionScenario {
+        setup: &[],
+        condition: Condition::Normal,
+        action: Action::BasicSynthesis,
+        inner_quiet: 0,
+    },
+    ActionScenario {
+        setup: &[],
+        condition: Condition::Normal,
+        action: Action::CarefulSynthesis,
+        inner_quiet: 0,
+    },
+    ActionScenario {
+        setup: &[Action::Veneration],
+        condition: Condition::Normal,
+        action: Action::Groundwork,
+        inner_quiet: 0,
+    },
+    ActionScenario {
+        setup: &[],
+        condition: Condition::Malleable,
+        action: Action::BasicSynthesis,
+        inner_quiet: 0,
+    },
+    ActionScenario {
+        setup: &[],
+        condition: Condition::Normal,
+        action: Action::BasicTouch,
+        inner_quiet: 0,
+    },
+    ActionScenario {
+        setup: &[Action::Innovation],
+        condition: Condition::Normal,
+        action: Action::BasicTouch,
+        inner_quiet: 0,
+    },
+    ActionScenario {
+        setup: &[Action::Innovation, Action::GreatStrides],
+        condition: Condition::Normal,
+        action: Action::PrudentTouch,
+        inner_quiet: 0,
+    },
+    ActionScenario {
+        setup: &[],
+        condition: Condition::Good,
+        action: Action::BasicTouch,
+        inner_quiet: 0,
+    },
+    ActionScenario {
+        setup: &[],
+        condition: Condition::Excellent,
+        action: Action::PreciseTouch,
+        inner_quiet: 0,
+    },
+    ActionScenario {
+        setup: &[],
+        condition: Condition::Poor,
+        action: Action::BasicTouch,
+        inner_quiet: 0,
+    },
+    ActionScenario {
+        setup: &[Action::BasicTouch, Action::BasicTouch],
+        condition: Condition::Normal,
+        action: Action::BasicTouch,
+        inner_quiet: 2,
+    },
+    ActionScenario {
+        setup: &[
+            Action::Innovation,
+            Action::BasicTouch,
+            Action::BasicTouch,
+            Action::GreatStrides,
+        ],
+        condition: Condition::Good,
+        action: Action::DelicateSynthesis,
+        inner_quiet: 2,
+    },
+];
+
+/// Progress and Quality potency of `action` at `job_level` as listed in the game's action descriptions, including the traits that enhance it.
+/// Kept apart from the action data of the simulator, so that a wrong potency there is caught.
+fn reference_potency(action: Action, job_level: u8) -> (u64, u64) {
+    match action {
+        Action::BasicSynthesis if job_level >= 31 => (120, 0),
+        Action::BasicSynthesis => (100, 0),
+        Action::CarefulSynthesis if job_level >= 82 => (180, 0),
+        Action::CarefulSynthesis => (150, 0),
+        Action::Groundwork if job_level >= 86 => (360, 0),
+        Action::Groundwork => (300, 0),
+        Action::DelicateSynthesis if job_level >= 94 => (150, 100),
+        Action::DelicateSynthesis => (100, 100),
+        Action::BasicTouch | Action::PrudentTouch => (0, 100),
+        Action::PreciseTouch => (0, 150),
+        _ => unreachable!("no reference potency for {action:?}"),
+    }
+}
+
+/// Job level from which touch actions grant Inner Quiet stacks
+const INNER_QUIET_LEVEL: u8 = 11;
+
+/// Progress and Quality of the checked action of `scenario`, computed as in the game: floor(base * potency% * buff multiplier * condition multiplier), where Inner Quiet adds 10% to the Quality multiplier per stack.
+fn reference_increase(settings: &Settings, scenario: &ActionScenario) -> (u32, u32) {
+    let buffed = |buff: Action| u64::from(scenario.setup.contains(&buff));
+    let (progress_potency, quality_potency) =
+        reference_potency(scenario.action, settings.job_level);
+    let inner_quiet = match settings.job_level >= INNER_QUIET_LEVEL {
+        true => u64::from(scenario.inner_quiet),
+        false => 0,
+    };
+    let progress_multiplier = 100 + 50 * buffed(Action::Veneration);
+    let quality_multiplier = (100 + 10 * inner_quiet)
+        * (100 + 50 * buffed(Action::Innovation) + 100 * buffed(Action::GreatStrides));
+    let (progress_condition, quality_condition) = match scenario.condition {
+        Condition::Good => (100, 150),
+        Condition::Excellent => (100, 400),
+        Condition::Poor => (100, 50),
+        Condition::Malleable => (150, 100),
+        _ => (100, 100),
+    };
+    let progress = u64::from(settings.base_progress)
+        * progress_potency
+        * progress_multiplier
+        * progress_condition
+        / 1_000_000;
+    let quality =
+        u64::from(settings.base_quality) * quality_potency * quality_multiplier * quality_condition
+            / 100_000_000;
+    (progress as u32, quality as u32)
+}
+
+/// Cross-checks the game settings and the Progress and Quality increases of the simulator against an independent implementation of the game's formulas and rounding rules.
+/// Meant to catch formula regressions, e.g. when the recipe level table changes. An empty result means that everything agrees.
+///
+/// Custom recipes are only checked as far as they don't override the checked values.
+pub fn verify_game_settings(
+    recipe: Recipe,
+    custom_recipe_overrides: Option<CustomRecipeOverrides>,
+    crafter_stats: CrafterStats,
+    food: Option<Consumable>,
+    potion: Option<Consumable>,
+) -> Vec<CalculationMismatch> {
+    let settings =
+        crate::get_game_settings(recipe, custom_recipe_overrides, crafter_stats, food, potion);
+    let reference = ReferenceSettings::new(recipe, crafter_stats, food, potion);
+    let mut mismatches = Vec::new();
+    let mut check = |description: String, expected: u32, actual: u32| {
+        if expected != actual {
+            mismatches.push(CalculationMismatch {
+                description,
+                expected,
+                actual,
+            });
+        }
+    };
+
+    check(
+        "Max CP".to_owned(),
+        reference.max_cp,
+        u32::from(settings.max_cp),
+    );
+    let overrides = custom_recipe_overrides.unwrap_or_default();
+    if custom_recipe_overrides.is_none() {
+        check(
+            "Max Durability".to_owned(),
+            reference.max_durability,
+            u32::from(settings.max_durability),
+        );
+        check(
+            "Max Progress".to_owned(),
+            reference.max_progress,
+            u32::from(settings.max_progress),
+        );
+        check(
+            "Max Quality".to_owned(),
+            reference.max_quality,
+            u32::from(settings.max_quality),
+        );
+    }
+    if overrides.base_progress_override.is_none() {
+        check(
+            "Base Progress".to_owned(),
+            reference.base_progress,
+            u32::from(settings.base_progress),
+        );
+    }
+    if overrides.base_quality_override.is_none() {
+        check(
+            "Base Quality".to_owned(),
+            reference.base_quality,
+            u32::from(settings.base_quality),
+        );
+    }
+
+    // the simulator is checked with its own settings, so that a mismatch above doesn't show up twice
+    let settings = Settings {
+        max_progress: u16::MAX,
+        max_quality: u16::MAX,
+        max_durability: u16::MAX,
+        max_cp: u16::MAX,
+        allowed_actions: ActionMask::all(),
+        ..settings
+    };
+    for scenario in ACTION_SCENARIOS {
+        let Ok(state) = SimulationState::from_macro(&settings, scenario.setup) else {
+            // the setup actions aren't unlocked at this job level
+            continue;
+        };
+        let Ok(next_state) = state.use_action(scenario.action, scenario.condition, &settings)
+        else {
+            continue;
+        };
+        let (progress, quality) = reference_increase(&settings, scenario);
+        let description = format!(
+            "{:?} in a {:?} step after {:?}",
+            scenario.action, scenario.condition, scenario.setup
+        );
+        check(
+            format!("{description} Progress"),
+            progress,
+            next_state.progress - state.progress,
+        );
+        check(
+            format!("{description} Quality"),
+            quality,
+            next_state.quality - state.quality,
+        );
+    }
+    mismatches
+}
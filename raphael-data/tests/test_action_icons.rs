@@ -0,0 +1,22 @@
+use raphael_data::*;
+use raphael_sim::ACTION_DATA;
+
+#[test]
+fn test_every_action_has_an_icon() {
+    for action_data in ACTION_DATA {
+        let icon = ACTION_ICON_ATLAS
+            .find(action_data.action)
+            .unwrap_or_else(|| panic!("{:?} has no icon", action_data.action));
+        assert!(icon.x + ACTION_ICON_ATLAS.icon_size <= ACTION_ICON_ATLAS.width);
+        assert_eq!(ACTION_ICON_ATLAS.icon_size, ACTION_ICON_ATLAS.height);
+    }
+}
+
+#[test]
+fn test_every_job_has_an_atlas() {
+    let atlas_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../assets/action-icons");
+    for job_id in 0..ACTION_ICON_ATLAS_COUNT {
+        let atlas = atlas_dir.join(format!("atlas-{job_id}.webp"));
+        assert!(atlas.is_file(), "{} is missing", atlas.display());
+    }
+}
@@ -1,5 +1,5 @@
 use raphael_data::*;
-use raphael_sim::{Action, ActionMask, Settings};
+use raphael_sim::{Action, ActionMask, Settings, TraitOverrides};
 
 fn find_recipe(item_name: &'static str) -> Option<Recipe> {
     for recipe in RECIPES.values() {
@@ -48,9 +48,11 @@ fn test_roast_chicken() {
             allowed_actions: ActionMask::all()
                 .remove(Action::TrainedEye)
                 .remove(Action::HeartAndSoul)
-                .remove(Action::QuickInnovation),
+                .remove(Action::QuickInnovation)
+                .remove(Action::CarefulObservation),
             adversarial: false,
             backload_progress: false,
+            traits: TraitOverrides::none(),
         }
     );
 }
@@ -87,6 +89,7 @@ fn test_turali_pineapple_ponzecake() {
                 .remove(Action::QuickInnovation),
             adversarial: false,
             backload_progress: false,
+            traits: TraitOverrides::none(),
         }
     );
     let initial_quality = get_initial_quality(crafter_stats, recipe, [0, 1, 0, 0, 0, 0]);
@@ -121,9 +124,11 @@ fn test_smaller_water_otter_hardware() {
             allowed_actions: ActionMask::all()
                 .remove(Action::TrainedEye)
                 .remove(Action::HeartAndSoul)
-                .remove(Action::QuickInnovation),
+                .remove(Action::QuickInnovation)
+                .remove(Action::CarefulObservation),
             adversarial: false,
             backload_progress: false,
+            traits: TraitOverrides::none(),
         }
     );
 }
@@ -156,6 +161,7 @@ fn test_grade_8_tincture() {
             allowed_actions: ActionMask::all().remove(Action::QuickInnovation),
             adversarial: false,
             backload_progress: false,
+            traits: TraitOverrides::none(),
         }
     );
 }
@@ -192,6 +198,7 @@ fn test_claro_walnut_spinning_wheel() {
                 .remove(Action::HeartAndSoul),
             adversarial: false,
             backload_progress: false,
+            traits: TraitOverrides::none(),
         }
     );
 }
@@ -222,9 +229,11 @@ fn test_habitat_chair_lv100() {
             allowed_actions: ActionMask::all()
                 .remove(Action::TrainedEye)
                 .remove(Action::HeartAndSoul)
-                .remove(Action::QuickInnovation),
+                .remove(Action::QuickInnovation)
+                .remove(Action::CarefulObservation),
             adversarial: false,
             backload_progress: false,
+            traits: TraitOverrides::none(),
         }
     );
 }
@@ -256,9 +265,11 @@ fn test_habitat_chair_lv97() {
             allowed_actions: ActionMask::all()
                 .remove(Action::TrainedEye)
                 .remove(Action::HeartAndSoul)
-                .remove(Action::QuickInnovation),
+                .remove(Action::QuickInnovation)
+                .remove(Action::CarefulObservation),
             adversarial: false,
             backload_progress: false,
+            traits: TraitOverrides::none(),
         }
     );
 }
@@ -290,9 +301,11 @@ fn test_habitat_chair_lv98() {
             allowed_actions: ActionMask::all()
                 .remove(Action::TrainedEye)
                 .remove(Action::HeartAndSoul)
-                .remove(Action::QuickInnovation),
+                .remove(Action::QuickInnovation)
+                .remove(Action::CarefulObservation),
             adversarial: false,
             backload_progress: false,
+            traits: TraitOverrides::none(),
         }
     );
 }
@@ -324,9 +337,11 @@ fn test_standard_indurate_rings_lv93() {
             allowed_actions: ActionMask::all()
                 .remove(Action::TrainedEye)
                 .remove(Action::HeartAndSoul)
-                .remove(Action::QuickInnovation),
+                .remove(Action::QuickInnovation)
+                .remove(Action::CarefulObservation),
             adversarial: false,
             backload_progress: false,
+            traits: TraitOverrides::none(),
         }
     );
 }
@@ -358,9 +373,11 @@ fn test_lunar_alloy_ingots_lv90() {
             allowed_actions: ActionMask::all()
                 .remove(Action::TrainedEye)
                 .remove(Action::HeartAndSoul)
-                .remove(Action::QuickInnovation),
+                .remove(Action::QuickInnovation)
+                .remove(Action::CarefulObservation),
             adversarial: false,
             backload_progress: false,
+            traits: TraitOverrides::none(),
         }
     );
 }
@@ -392,9 +409,11 @@ fn test_standard_high_density_fiberboard_lv91() {
             allowed_actions: ActionMask::all()
                 .remove(Action::TrainedEye)
                 .remove(Action::HeartAndSoul)
-                .remove(Action::QuickInnovation),
+                .remove(Action::QuickInnovation)
+                .remove(Action::CarefulObservation),
             adversarial: false,
             backload_progress: false,
+            traits: TraitOverrides::none(),
         }
     );
 }
@@ -425,9 +444,183 @@ fn test_lunar_alloy_ingots_lv10() {
             allowed_actions: ActionMask::all()
                 .remove(Action::TrainedEye)
                 .remove(Action::HeartAndSoul)
-                .remove(Action::QuickInnovation),
+                .remove(Action::QuickInnovation)
+                .remove(Action::CarefulObservation),
             adversarial: false,
             backload_progress: false,
+            traits: TraitOverrides::none(),
         }
     );
 }
+
+#[test]
+/// Custom recipes that are level-synced use the same recipe level as the game data
+fn test_custom_level_synced_recipe() {
+    let recipe = find_recipe("Lunar Alloy Ingot").unwrap();
+    let custom_recipe = Recipe {
+        item_id: 0,
+        recipe_level: 1,
+        ..recipe
+    };
+    for level in [10, 50, 90, 100] {
+        let crafter_stats = CrafterStats {
+            craftsmanship: 3796,
+            control: 3447,
+            cp: 504,
+            level,
+            manipulation: true,
+            heart_and_soul: false,
+            quick_innovation: false,
+        };
+        let settings = get_game_settings(recipe, None, crafter_stats, None, None);
+        let custom_recipe_overrides = CustomRecipeOverrides {
+            max_progress_override: settings.max_progress,
+            max_quality_override: settings.max_quality,
+            max_durability_override: settings.max_durability,
+            ..Default::default()
+        };
+        assert_eq!(
+            effective_recipe_level(&custom_recipe, level),
+            LEVEL_ADJUST_TABLE[usize::from(level)]
+        );
+        assert_eq!(
+            get_game_settings(
+                custom_recipe,
+                Some(custom_recipe_overrides),
+                crafter_stats,
+                None,
+                None
+            ),
+            settings
+        );
+    }
+}
+
+#[test]
+/// A custom recipe synced to a level is at the recipe level of the job level, capped at the sync level
+fn test_custom_recipe_level_sync() {
+    let recipe = *RECIPES
+        .values()
+        .find(|recipe| recipe.max_level_scaling == 0)
+        .unwrap();
+    let synced_recipe = Recipe {
+        item_id: 0,
+        max_level_scaling: 50,
+        ..recipe
+    };
+    // level sync and base increase overrides are mutually exclusive
+    let custom_recipe_overrides = CustomRecipeOverrides {
+        max_progress_override: 2000,
+        max_quality_override: 4000,
+        max_durability_override: 60,
+        ..Default::default()
+    };
+    for (level, synced_level) in [(10, 10u8), (40, 40), (50, 50), (90, 50), (100, 50)] {
+        let crafter_stats = CrafterStats {
+            craftsmanship: 3796,
+            control: 3447,
+            cp: 504,
+            level,
+            manipulation: true,
+            heart_and_soul: false,
+            quick_innovation: false,
+        };
+        let recipe_level = LEVEL_ADJUST_TABLE[usize::from(synced_level)];
+        assert_eq!(effective_recipe_level(&synced_recipe, level), recipe_level);
+        let unsynced_recipe = Recipe {
+            recipe_level,
+            ..recipe
+        };
+        assert_eq!(
+            get_game_settings(
+                synced_recipe,
+                Some(custom_recipe_overrides),
+                crafter_stats,
+                None,
+                None
+            ),
+            get_game_settings(
+                unsynced_recipe,
+                Some(custom_recipe_overrides),
+                crafter_stats,
+                None,
+                None
+            )
+        );
+    }
+}
+
+#[test]
+fn test_minimal_hq_ingredients() {
+    let recipe = find_recipe("Turali Pineapple Ponzecake").unwrap();
+    let crafter_stats = CrafterStats {
+        craftsmanship: 4321,
+        control: 4321,
+        cp: 600,
+        level: 94,
+        manipulation: true,
+        heart_and_soul: true,
+        quick_innovation: false,
+    };
+    let hq_ingredients = get_minimal_hq_ingredients(crafter_stats, recipe, 2000).unwrap();
+    assert_eq!(hq_ingredients, [0, 1, 0, 0, 0, 0]);
+    assert_eq!(
+        get_minimal_hq_ingredients(crafter_stats, recipe, 0),
+        Some([0; 6])
+    );
+    assert_eq!(
+        get_minimal_hq_ingredients(crafter_stats, recipe, u16::MAX),
+        None
+    );
+}
+
+#[test]
+fn test_best_hq_ingredients_per_count() {
+    let recipe = find_recipe("Turali Pineapple Ponzecake").unwrap();
+    let crafter_stats = CrafterStats {
+        craftsmanship: 4321,
+        control: 4321,
+        cp: 600,
+        level: 94,
+        manipulation: true,
+        heart_and_soul: true,
+        quick_innovation: false,
+    };
+    let best = get_best_hq_ingredients_per_count(crafter_stats, recipe);
+    assert_eq!(best[0], ([0; 6], 0));
+    for (count, (hq_ingredients, quality)) in best.iter().enumerate() {
+        let total: usize = hq_ingredients
+            .iter()
+            .map(|&amount| usize::from(amount))
+            .sum();
+        assert_eq!(total, count);
+        assert_eq!(
+            get_initial_quality(crafter_stats, recipe, *hq_ingredients),
+            *quality
+        );
+    }
+    assert!(best.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+}
+
+#[test]
+fn test_estimate_quick_synthesis() {
+    let recipe = find_recipe("Lunar Alloy Ingot").unwrap();
+    let crafter_stats = CrafterStats {
+        craftsmanship: 3796,
+        control: 3447,
+        cp: 504,
+        level: 10,
+        manipulation: true,
+        heart_and_soul: false,
+        quick_innovation: false,
+    };
+    let estimate = estimate_quick_synthesis(recipe, crafter_stats, None, None, 1000).unwrap();
+    assert_eq!(estimate.completed, 1000);
+    let crafter_stats = CrafterStats {
+        craftsmanship: 100,
+        level: 90,
+        ..crafter_stats
+    };
+    let estimate = estimate_quick_synthesis(recipe, crafter_stats, None, None, 1000).unwrap();
+    assert_eq!(estimate.completed, 0);
+}
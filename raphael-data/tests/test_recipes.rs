@@ -1,4 +1,5 @@
 use raphael_data::*;
+use raphael_sim::{Condition, ConditionProbabilities};
 
 #[track_caller]
 fn assert_item_exists(item_id: u32) {
@@ -120,3 +121,65 @@ fn test_habitat_chair() {
         }
     );
 }
+
+#[test]
+fn test_modeled_conditions() {
+    let expert_recipe = find_recipe("Uncharted Course Resin").unwrap();
+    let conditions = modeled_conditions(expert_recipe);
+    assert!(conditions.starts_with(&[Condition::Normal, Condition::Good]));
+    assert!(!conditions.contains(&Condition::Excellent));
+    assert!(!conditions.contains(&Condition::Poor));
+    let recipe = find_recipe("Habitat Chair \u{e03d}").unwrap();
+    assert_eq!(
+        modeled_conditions(recipe),
+        [
+            Condition::Normal,
+            Condition::Good,
+            Condition::Excellent,
+            Condition::Poor
+        ]
+    );
+    for recipe in RECIPES.values() {
+        let conditions = modeled_conditions(*recipe);
+        assert!(conditions.contains(&Condition::Normal), "{recipe:?}");
+        if recipe.is_expert {
+            assert!(!conditions.contains(&Condition::Excellent), "{recipe:?}");
+        }
+    }
+}
+
+#[test]
+fn test_condition_probabilities() {
+    let expert_recipe = find_recipe("Uncharted Course Resin").unwrap();
+    assert_eq!(
+        condition_probabilities(expert_recipe, None),
+        ConditionProbabilities::EXPERT_RECIPE.restricted_to(&modeled_conditions(expert_recipe))
+    );
+    assert_eq!(condition_probabilities(expert_recipe, None).excellent, 0);
+    assert_eq!(
+        condition_probabilities(expert_recipe, None).good,
+        ConditionProbabilities::EXPERT_RECIPE.good
+    );
+    let recipe = find_recipe("Habitat Chair \u{e03d}").unwrap();
+    assert_eq!(
+        condition_probabilities(recipe, None),
+        ConditionProbabilities::REGULAR_RECIPE
+    );
+    let probabilities = ConditionProbabilities {
+        good: 40,
+        excellent: 10,
+        ..Default::default()
+    };
+    let custom_recipe_overrides = CustomRecipeOverrides {
+        condition_probabilities_override: Some(probabilities),
+        ..Default::default()
+    };
+    assert_eq!(
+        condition_probabilities(recipe, Some(custom_recipe_overrides)),
+        probabilities
+    );
+    assert_eq!(
+        condition_probabilities(recipe, Some(CustomRecipeOverrides::default())),
+        ConditionProbabilities::REGULAR_RECIPE
+    );
+}
@@ -0,0 +1,45 @@
+use raphael_data::*;
+
+fn find_recipe_names(search_string: &str) -> Vec<String> {
+    find_recipes(search_string, Locale::EN)
+        .into_iter()
+        .map(|recipe_id| get_recipe_name(&RECIPES[&recipe_id], false, Locale::EN).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_substring_match() {
+    let names = find_recipe_names("ipe lumb");
+    assert_eq!(names.first().map(String::as_str), Some("Ipe Lumber"));
+}
+
+#[test]
+fn test_initials_match() {
+    let names = find_recipe_names("bch");
+    assert!(names.iter().any(|name| name == "Bronze Cross-pein Hammer"));
+}
+
+#[test]
+fn test_typo_match() {
+    let names = find_recipe_names("bronze crosspein hamer");
+    assert!(names.iter().any(|name| name == "Bronze Cross-pein Hammer"));
+}
+
+#[test]
+fn test_exact_matches_first() {
+    // names with a typo, such as "Number", come after all names that contain the pattern
+    let names = find_recipe_names("lumber");
+    let first_fuzzy = names
+        .iter()
+        .position(|name| !name.to_lowercase().contains("lumber"));
+    let last_exact = names
+        .iter()
+        .rposition(|name| name.to_lowercase().contains("lumber"));
+    assert!(last_exact.is_some());
+    assert!(first_fuzzy.is_none_or(|first_fuzzy| last_exact.unwrap() < first_fuzzy));
+}
+
+#[test]
+fn test_empty_pattern() {
+    assert_eq!(find_recipes("", Locale::EN).len(), RECIPES.len());
+}
@@ -0,0 +1,77 @@
+use raphael_data::*;
+
+fn crafter_stats(level: u8, craftsmanship: u16, control: u16, cp: u16) -> CrafterStats {
+    CrafterStats {
+        craftsmanship,
+        control,
+        cp,
+        level,
+        manipulation: true,
+        heart_and_soul: true,
+        quick_innovation: true,
+    }
+}
+
+#[test]
+fn test_all_recipes_match_reference() {
+    let stats = [
+        crafter_stats(100, 5400, 5300, 700),
+        crafter_stats(100, 4000, 3900, 600),
+        crafter_stats(90, 3500, 3400, 550),
+        crafter_stats(50, 1200, 1100, 400),
+        crafter_stats(1, 100, 100, 180),
+    ];
+    let food = MEALS.iter().copied().find(|meal| meal.hq);
+    let potion = POTIONS.iter().copied().find(|potion| potion.hq);
+    for recipe in RECIPES.values() {
+        for crafter_stats in stats {
+            let mismatches = verify_game_settings(*recipe, None, crafter_stats, food, potion);
+            assert!(
+                mismatches.is_empty(),
+                "recipe {} with {crafter_stats:?}: {mismatches:?}",
+                recipe.item_id
+            );
+        }
+    }
+}
+
+#[test]
+fn test_level_synced_recipes_match_reference() {
+    let level_synced_recipes: Vec<Recipe> = RECIPES
+        .values()
+        .copied()
+        .filter(|recipe| recipe.max_level_scaling != 0)
+        .collect();
+    assert!(!level_synced_recipes.is_empty());
+    for recipe in level_synced_recipes {
+        // below, at and above the level the recipe is synced to
+        let max_level = recipe.max_level_scaling;
+        for level in [max_level.saturating_sub(10).max(1), max_level, 100] {
+            let crafter_stats = crafter_stats(level, 3000, 2900, 500);
+            let mismatches = verify_game_settings(recipe, None, crafter_stats, None, None);
+            assert!(
+                mismatches.is_empty(),
+                "recipe {} at level {level}: {mismatches:?}",
+                recipe.item_id
+            );
+        }
+    }
+}
+
+#[test]
+fn test_custom_recipe_overrides_are_not_checked() {
+    let recipe = *RECIPES.values().next().unwrap();
+    let overrides = CustomRecipeOverrides {
+        max_progress_override: 1234,
+        max_quality_override: 5678,
+        max_durability_override: 35,
+        base_progress_override: Some(1),
+        base_quality_override: Some(1),
+        condition_probabilities_override: None,
+    };
+    let crafter_stats = crafter_stats(100, 5400, 5300, 700);
+    assert_eq!(
+        verify_game_settings(recipe, Some(overrides), crafter_stats, None, None),
+        []
+    );
+}
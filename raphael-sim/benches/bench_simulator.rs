@@ -14,6 +14,7 @@ fn bench_use_action(c: &mut Criterion) {
         allowed_actions: ActionMask::all(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
     let state = SimulationState::new(&settings);
 
@@ -0,0 +1,34 @@
+&[
+ActionData { action: Action::BasicSynthesis, level_requirement: 1, cp_cost: 0, combo_cp_cost: None, durability_cost: 10, progress_potency: 100, enhanced_progress_potency: Some((31, 120)), quality_potency: 0, },
+ActionData { action: Action::BasicTouch, level_requirement: 5, cp_cost: 18, combo_cp_cost: None, durability_cost: 10, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 100, },
+ActionData { action: Action::MasterMend, level_requirement: 7, cp_cost: 88, combo_cp_cost: None, durability_cost: 0, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 0, },
+ActionData { action: Action::Observe, level_requirement: 13, cp_cost: 7, combo_cp_cost: None, durability_cost: 0, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 0, },
+ActionData { action: Action::TricksOfTheTrade, level_requirement: 13, cp_cost: 0, combo_cp_cost: None, durability_cost: 0, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 0, },
+ActionData { action: Action::WasteNot, level_requirement: 15, cp_cost: 56, combo_cp_cost: None, durability_cost: 0, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 0, },
+ActionData { action: Action::Veneration, level_requirement: 15, cp_cost: 18, combo_cp_cost: None, durability_cost: 0, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 0, },
+ActionData { action: Action::StandardTouch, level_requirement: 18, cp_cost: 32, combo_cp_cost: Some(18), durability_cost: 10, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 125, },
+ActionData { action: Action::GreatStrides, level_requirement: 21, cp_cost: 32, combo_cp_cost: None, durability_cost: 0, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 0, },
+ActionData { action: Action::Innovation, level_requirement: 26, cp_cost: 18, combo_cp_cost: None, durability_cost: 0, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 0, },
+ActionData { action: Action::WasteNot2, level_requirement: 47, cp_cost: 98, combo_cp_cost: None, durability_cost: 0, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 0, },
+ActionData { action: Action::ByregotsBlessing, level_requirement: 50, cp_cost: 24, combo_cp_cost: None, durability_cost: 10, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 100, },
+ActionData { action: Action::PreciseTouch, level_requirement: 53, cp_cost: 18, combo_cp_cost: None, durability_cost: 10, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 150, },
+ActionData { action: Action::MuscleMemory, level_requirement: 54, cp_cost: 6, combo_cp_cost: None, durability_cost: 10, progress_potency: 300, enhanced_progress_potency: None, quality_potency: 0, },
+ActionData { action: Action::CarefulSynthesis, level_requirement: 62, cp_cost: 7, combo_cp_cost: None, durability_cost: 10, progress_potency: 150, enhanced_progress_potency: Some((82, 180)), quality_potency: 0, },
+ActionData { action: Action::Manipulation, level_requirement: 65, cp_cost: 96, combo_cp_cost: None, durability_cost: 0, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 0, },
+ActionData { action: Action::PrudentTouch, level_requirement: 66, cp_cost: 25, combo_cp_cost: None, durability_cost: 5, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 100, },
+ActionData { action: Action::AdvancedTouch, level_requirement: 68, cp_cost: 46, combo_cp_cost: Some(18), durability_cost: 10, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 150, },
+ActionData { action: Action::Reflect, level_requirement: 69, cp_cost: 6, combo_cp_cost: None, durability_cost: 10, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 300, },
+ActionData { action: Action::PreparatoryTouch, level_requirement: 71, cp_cost: 40, combo_cp_cost: None, durability_cost: 20, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 200, },
+ActionData { action: Action::Groundwork, level_requirement: 72, cp_cost: 18, combo_cp_cost: None, durability_cost: 20, progress_potency: 300, enhanced_progress_potency: Some((86, 360)), quality_potency: 0, },
+ActionData { action: Action::DelicateSynthesis, level_requirement: 76, cp_cost: 32, combo_cp_cost: None, durability_cost: 10, progress_potency: 100, enhanced_progress_potency: Some((94, 150)), quality_potency: 100, },
+ActionData { action: Action::IntensiveSynthesis, level_requirement: 78, cp_cost: 6, combo_cp_cost: None, durability_cost: 10, progress_potency: 400, enhanced_progress_potency: None, quality_potency: 0, },
+ActionData { action: Action::TrainedEye, level_requirement: 80, cp_cost: 250, combo_cp_cost: None, durability_cost: 10, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 0, },
+ActionData { action: Action::HeartAndSoul, level_requirement: 86, cp_cost: 0, combo_cp_cost: None, durability_cost: 0, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 0, },
+ActionData { action: Action::PrudentSynthesis, level_requirement: 88, cp_cost: 18, combo_cp_cost: None, durability_cost: 5, progress_potency: 180, enhanced_progress_potency: None, quality_potency: 0, },
+ActionData { action: Action::TrainedFinesse, level_requirement: 90, cp_cost: 32, combo_cp_cost: None, durability_cost: 0, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 100, },
+ActionData { action: Action::RefinedTouch, level_requirement: 92, cp_cost: 24, combo_cp_cost: None, durability_cost: 10, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 100, },
+ActionData { action: Action::QuickInnovation, level_requirement: 96, cp_cost: 0, combo_cp_cost: None, durability_cost: 0, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 0, },
+ActionData { action: Action::ImmaculateMend, level_requirement: 98, cp_cost: 112, combo_cp_cost: None, durability_cost: 0, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 0, },
+ActionData { action: Action::TrainedPerfection, level_requirement: 100, cp_cost: 0, combo_cp_cost: None, durability_cost: 0, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 0, },
+ActionData { action: Action::CarefulObservation, level_requirement: 55, cp_cost: 0, combo_cp_cost: None, durability_cost: 0, progress_potency: 0, enhanced_progress_potency: None, quality_potency: 0, },
+]
@@ -1,9 +1,53 @@
 use crate::{ActionMask, Condition, Settings, SimulationState};
 
+/// Base values of an action.
+/// The values are generated by `raphael-data-updater`, so that balance changes don't require changing the action implementations.
+#[derive(Debug, Clone, Copy)]
+pub struct ActionData {
+    pub action: Action,
+    pub level_requirement: u8,
+    pub cp_cost: u16,
+    /// CP cost when the action continues its combo, if it has one.
+    pub combo_cp_cost: Option<u16>,
+    pub durability_cost: u16,
+    pub progress_potency: u32,
+    /// Job level and Progress potency after learning the trait that enhances this action, if any.
+    pub enhanced_progress_potency: Option<(u8, u32)>,
+    pub quality_potency: u32,
+}
+
+impl ActionData {
+    pub const fn progress_potency(&self, settings: &Settings) -> u32 {
+        match self.enhanced_progress_potency {
+            Some((trait_level, potency))
+                if settings
+                    .traits
+                    .is_enhanced(self.action, settings.job_level >= trait_level) =>
+            {
+                potency
+            }
+            _ => self.progress_potency,
+        }
+    }
+}
+
+/// Data of every action, in the order of the [`Action`] variants so that it can be indexed by `Action as usize`.
+pub const ACTION_DATA: &[ActionData] = include!("../data/actions.rs");
+
+const _: () = {
+    assert!(ACTION_DATA.len() == Action::CarefulObservation as usize + 1);
+    let mut index = 0;
+    while index < ACTION_DATA.len() {
+        assert!(ACTION_DATA[index].action as usize == index);
+        index += 1;
+    }
+};
+
 pub trait ActionImpl {
-    const LEVEL_REQUIREMENT: u8;
+    const DATA: ActionData;
+    const LEVEL_REQUIREMENT: u8 = Self::DATA.level_requirement;
     /// All bits of this mask must be present in the settings' action mask for the action to be enabled.
-    const ACTION_MASK: ActionMask;
+    const ACTION_MASK: ActionMask = ActionMask::none().add(Self::DATA.action);
     /// Does this action trigger ticking effects (e.g. Manipulation)?
     const TICK_EFFECTS: bool = true;
 
@@ -16,50 +60,61 @@ pub trait ActionImpl {
     }
 
     #[inline]
-    fn progress_increase(state: &SimulationState, settings: &Settings) -> u32 {
-        let action_mod = u32::from(Self::progress_modifier(state, settings));
+    fn progress_increase(
+        state: &SimulationState,
+        settings: &Settings,
+        condition: Condition,
+    ) -> u32 {
+        let action_mod = u32::from(Self::progress_modifier(state, settings, condition));
         let effect_mod = u32::from(state.effects.progress_modifier());
-        u32::from(settings.base_progress) * action_mod * effect_mod / 10000
+        let condition_mod = condition.progress_modifier();
+        u32::from(settings.base_progress) * action_mod * effect_mod * condition_mod / 20000
     }
 
     #[inline]
     fn quality_increase(state: &SimulationState, settings: &Settings, condition: Condition) -> u32 {
         let action_mod = u32::from(Self::quality_modifier(state, settings));
         let effect_mod = u32::from(state.effects.quality_modifier());
-        let condition_mod = match condition {
-            Condition::Normal => 2,
-            Condition::Good => 3,
-            Condition::Excellent => 8,
-            Condition::Poor => 1,
-        };
+        let condition_mod = condition.quality_modifier();
         u32::from(settings.base_quality) * action_mod * effect_mod * condition_mod / 20000
     }
 
-    fn durability_cost(state: &SimulationState, settings: &Settings, _condition: Condition) -> u16 {
+    fn durability_cost(state: &SimulationState, settings: &Settings, condition: Condition) -> u16 {
         if state.effects.trained_perfection_active() {
             return 0;
         }
-        match state.effects.waste_not() {
-            0 => Self::base_durability_cost(state, settings),
-            _ => Self::base_durability_cost(state, settings).div_ceil(2),
-        }
+        // Waste Not and Sturdy each halve the cost, which is rounded up once
+        let divisor = match (state.effects.waste_not(), condition) {
+            (0, Condition::Sturdy) => 2,
+            (0, _) => 1,
+            (_, Condition::Sturdy) => 4,
+            (_, _) => 2,
+        };
+        Self::base_durability_cost(state, settings).div_ceil(divisor)
     }
 
-    fn cp_cost(state: &SimulationState, settings: &Settings, _condition: Condition) -> u16 {
-        Self::base_cp_cost(state, settings)
+    fn cp_cost(state: &SimulationState, settings: &Settings, condition: Condition) -> u16 {
+        match condition {
+            Condition::Pliant => Self::base_cp_cost(state, settings).div_ceil(2),
+            _ => Self::base_cp_cost(state, settings),
+        }
     }
 
-    fn progress_modifier(_state: &SimulationState, _settings: &Settings) -> u32 {
-        0
+    fn progress_modifier(
+        _state: &SimulationState,
+        settings: &Settings,
+        _condition: Condition,
+    ) -> u32 {
+        Self::DATA.progress_potency(settings)
     }
     fn quality_modifier(_state: &SimulationState, _settings: &Settings) -> u32 {
-        0
+        Self::DATA.quality_potency
     }
     fn base_durability_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        0
+        Self::DATA.durability_cost
     }
     fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        0
+        Self::DATA.cp_cost
     }
 
     fn transform_pre(_state: &mut SimulationState, _settings: &Settings, _condition: Condition) {}
@@ -72,32 +127,15 @@ pub trait ActionImpl {
 
 pub struct BasicSynthesis {}
 impl ActionImpl for BasicSynthesis {
-    const LEVEL_REQUIREMENT: u8 = 1;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::BasicSynthesis);
-    fn progress_modifier(_state: &SimulationState, settings: &Settings) -> u32 {
-        if settings.job_level < 31 { 100 } else { 120 }
-    }
-    fn base_durability_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        10
-    }
+    const DATA: ActionData = Action::BasicSynthesis.data();
 }
 
 pub struct BasicTouch {}
 impl BasicTouch {
-    pub const CP_COST: u16 = 18;
+    pub const CP_COST: u16 = Action::BasicTouch.data().cp_cost;
 }
 impl ActionImpl for BasicTouch {
-    const LEVEL_REQUIREMENT: u8 = 5;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::BasicTouch);
-    fn quality_modifier(_state: &SimulationState, _settings: &Settings) -> u32 {
-        100
-    }
-    fn base_durability_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        10
-    }
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        Self::CP_COST
-    }
+    const DATA: ActionData = Action::BasicTouch.data();
     fn combo(_state: &SimulationState, _settings: &Settings, _condition: Condition) -> Combo {
         Combo::BasicTouch
     }
@@ -105,14 +143,10 @@ impl ActionImpl for BasicTouch {
 
 pub struct MasterMend {}
 impl MasterMend {
-    pub const CP_COST: u16 = 88;
+    pub const CP_COST: u16 = Action::MasterMend.data().cp_cost;
 }
 impl ActionImpl for MasterMend {
-    const LEVEL_REQUIREMENT: u8 = 7;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::MasterMend);
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        Self::CP_COST
-    }
+    const DATA: ActionData = Action::MasterMend.data();
     fn transform_post(state: &mut SimulationState, settings: &Settings, _condition: Condition) {
         state.durability = std::cmp::min(settings.max_durability, state.durability + 30);
     }
@@ -120,14 +154,10 @@ impl ActionImpl for MasterMend {
 
 pub struct Observe {}
 impl Observe {
-    pub const CP_COST: u16 = 7;
+    pub const CP_COST: u16 = Action::Observe.data().cp_cost;
 }
 impl ActionImpl for Observe {
-    const LEVEL_REQUIREMENT: u8 = 13;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::Observe);
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        Self::CP_COST
-    }
+    const DATA: ActionData = Action::Observe.data();
     fn combo(_state: &SimulationState, _settings: &Settings, _condition: Condition) -> Combo {
         Combo::StandardTouch
     }
@@ -135,8 +165,7 @@ impl ActionImpl for Observe {
 
 pub struct TricksOfTheTrade {}
 impl ActionImpl for TricksOfTheTrade {
-    const LEVEL_REQUIREMENT: u8 = 13;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::TricksOfTheTrade);
+    const DATA: ActionData = Action::TricksOfTheTrade.data();
     fn precondition(
         state: &SimulationState,
         _settings: &Settings,
@@ -162,48 +191,37 @@ impl ActionImpl for TricksOfTheTrade {
 
 pub struct WasteNot {}
 impl WasteNot {
-    pub const CP_COST: u16 = 56;
+    pub const CP_COST: u16 = Action::WasteNot.data().cp_cost;
 }
 impl ActionImpl for WasteNot {
-    const LEVEL_REQUIREMENT: u8 = 15;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::WasteNot);
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        Self::CP_COST
-    }
-    fn transform_post(state: &mut SimulationState, _settings: &Settings, _condition: Condition) {
-        state.effects.set_waste_not(4);
+    const DATA: ActionData = Action::WasteNot.data();
+    fn transform_post(state: &mut SimulationState, _settings: &Settings, condition: Condition) {
+        state
+            .effects
+            .set_waste_not(4 + condition.effect_extension());
     }
 }
 
 pub struct Veneration {}
 impl Veneration {
-    pub const CP_COST: u16 = 18;
+    pub const CP_COST: u16 = Action::Veneration.data().cp_cost;
 }
 impl ActionImpl for Veneration {
-    const LEVEL_REQUIREMENT: u8 = 15;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::Veneration);
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        Self::CP_COST
-    }
-    fn transform_post(state: &mut SimulationState, _settings: &Settings, _condition: Condition) {
-        state.effects.set_veneration(4);
+    const DATA: ActionData = Action::Veneration.data();
+    fn transform_post(state: &mut SimulationState, _settings: &Settings, condition: Condition) {
+        state
+            .effects
+            .set_veneration(4 + condition.effect_extension());
     }
 }
 
 pub struct StandardTouch {}
 impl ActionImpl for StandardTouch {
-    const LEVEL_REQUIREMENT: u8 = 18;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::StandardTouch);
-    fn quality_modifier(_state: &SimulationState, _settings: &Settings) -> u32 {
-        125
-    }
-    fn base_durability_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        10
-    }
+    const DATA: ActionData = Action::StandardTouch.data();
     fn base_cp_cost(state: &SimulationState, _settings: &Settings) -> u16 {
         match state.effects.combo() {
-            Combo::BasicTouch => 18,
-            _ => 32,
+            Combo::BasicTouch => Self::DATA.combo_cp_cost.unwrap_or(Self::DATA.cp_cost),
+            _ => Self::DATA.cp_cost,
         }
     }
     fn combo(state: &SimulationState, _settings: &Settings, _condition: Condition) -> Combo {
@@ -216,11 +234,10 @@ impl ActionImpl for StandardTouch {
 
 pub struct GreatStrides {}
 impl GreatStrides {
-    pub const CP_COST: u16 = 32;
+    pub const CP_COST: u16 = Action::GreatStrides.data().cp_cost;
 }
 impl ActionImpl for GreatStrides {
-    const LEVEL_REQUIREMENT: u8 = 21;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::GreatStrides);
+    const DATA: ActionData = Action::GreatStrides.data();
     fn precondition(
         state: &SimulationState,
         _settings: &Settings,
@@ -231,21 +248,19 @@ impl ActionImpl for GreatStrides {
             true => Ok(()),
         }
     }
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        Self::CP_COST
-    }
-    fn transform_post(state: &mut SimulationState, _settings: &Settings, _condition: Condition) {
-        state.effects.set_great_strides(3);
+    fn transform_post(state: &mut SimulationState, _settings: &Settings, condition: Condition) {
+        state
+            .effects
+            .set_great_strides(3 + condition.effect_extension());
     }
 }
 
 pub struct Innovation {}
 impl Innovation {
-    pub const CP_COST: u16 = 18;
+    pub const CP_COST: u16 = Action::Innovation.data().cp_cost;
 }
 impl ActionImpl for Innovation {
-    const LEVEL_REQUIREMENT: u8 = 26;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::Innovation);
+    const DATA: ActionData = Action::Innovation.data();
     fn precondition(
         state: &SimulationState,
         _settings: &Settings,
@@ -256,33 +271,29 @@ impl ActionImpl for Innovation {
             true => Ok(()),
         }
     }
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        Self::CP_COST
-    }
-    fn transform_post(state: &mut SimulationState, _settings: &Settings, _condition: Condition) {
-        state.effects.set_innovation(4);
+    fn transform_post(state: &mut SimulationState, _settings: &Settings, condition: Condition) {
+        state
+            .effects
+            .set_innovation(4 + condition.effect_extension());
     }
 }
 
 pub struct WasteNot2 {}
 impl WasteNot2 {
-    pub const CP_COST: u16 = 98;
+    pub const CP_COST: u16 = Action::WasteNot2.data().cp_cost;
 }
 impl ActionImpl for WasteNot2 {
-    const LEVEL_REQUIREMENT: u8 = 47;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::WasteNot2);
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        Self::CP_COST
-    }
-    fn transform_post(state: &mut SimulationState, _settings: &Settings, _condition: Condition) {
-        state.effects.set_waste_not(8);
+    const DATA: ActionData = Action::WasteNot2.data();
+    fn transform_post(state: &mut SimulationState, _settings: &Settings, condition: Condition) {
+        state
+            .effects
+            .set_waste_not(8 + condition.effect_extension());
     }
 }
 
 pub struct ByregotsBlessing {}
 impl ActionImpl for ByregotsBlessing {
-    const LEVEL_REQUIREMENT: u8 = 50;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::ByregotsBlessing);
+    const DATA: ActionData = Action::ByregotsBlessing.data();
     fn precondition(
         state: &SimulationState,
         _settings: &Settings,
@@ -294,13 +305,7 @@ impl ActionImpl for ByregotsBlessing {
         }
     }
     fn quality_modifier(state: &SimulationState, _settings: &Settings) -> u32 {
-        100 + 20 * state.effects.inner_quiet() as u32
-    }
-    fn base_durability_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        10
-    }
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        24
+        Self::DATA.quality_potency + 20 * state.effects.inner_quiet() as u32
     }
     fn transform_post(state: &mut SimulationState, _settings: &Settings, _condition: Condition) {
         state.effects.set_inner_quiet(0);
@@ -309,8 +314,7 @@ impl ActionImpl for ByregotsBlessing {
 
 pub struct PreciseTouch {}
 impl ActionImpl for PreciseTouch {
-    const LEVEL_REQUIREMENT: u8 = 53;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::PreciseTouch);
+    const DATA: ActionData = Action::PreciseTouch.data();
     fn precondition(
         state: &SimulationState,
         _settings: &Settings,
@@ -324,15 +328,6 @@ impl ActionImpl for PreciseTouch {
         }
         Ok(())
     }
-    fn quality_modifier(_state: &SimulationState, _settings: &Settings) -> u32 {
-        150
-    }
-    fn base_durability_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        10
-    }
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        18
-    }
     fn transform_post(state: &mut SimulationState, _settings: &Settings, condition: Condition) {
         let iq = state.effects.inner_quiet();
         state.effects.set_inner_quiet(std::cmp::min(10, iq + 1));
@@ -344,8 +339,7 @@ impl ActionImpl for PreciseTouch {
 
 pub struct MuscleMemory {}
 impl ActionImpl for MuscleMemory {
-    const LEVEL_REQUIREMENT: u8 = 54;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::MuscleMemory);
+    const DATA: ActionData = Action::MuscleMemory.data();
     fn precondition(
         state: &SimulationState,
         _settings: &Settings,
@@ -356,60 +350,37 @@ impl ActionImpl for MuscleMemory {
         }
         Ok(())
     }
-    fn progress_modifier(_state: &SimulationState, _settings: &Settings) -> u32 {
-        300
-    }
-    fn base_durability_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        10
-    }
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        6
-    }
-    fn transform_post(state: &mut SimulationState, _settings: &Settings, _condition: Condition) {
-        state.effects.set_muscle_memory(5);
+    fn transform_post(state: &mut SimulationState, _settings: &Settings, condition: Condition) {
+        state
+            .effects
+            .set_muscle_memory(5 + condition.effect_extension());
     }
 }
 
 pub struct CarefulSynthesis {}
 impl ActionImpl for CarefulSynthesis {
-    const LEVEL_REQUIREMENT: u8 = 62;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::CarefulSynthesis);
-    fn progress_modifier(_state: &SimulationState, settings: &Settings) -> u32 {
-        match settings.job_level {
-            0..82 => 150,
-            82.. => 180,
-        }
-    }
-    fn base_durability_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        10
-    }
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        7
-    }
+    const DATA: ActionData = Action::CarefulSynthesis.data();
 }
 
 pub struct Manipulation {}
 impl Manipulation {
-    pub const CP_COST: u16 = 96;
+    pub const CP_COST: u16 = Action::Manipulation.data().cp_cost;
 }
 impl ActionImpl for Manipulation {
-    const LEVEL_REQUIREMENT: u8 = 65;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::Manipulation);
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        Self::CP_COST
-    }
+    const DATA: ActionData = Action::Manipulation.data();
     fn transform_pre(state: &mut SimulationState, _settings: &Settings, _condition: Condition) {
         state.effects.set_manipulation(0);
     }
-    fn transform_post(state: &mut SimulationState, _settings: &Settings, _condition: Condition) {
-        state.effects.set_manipulation(8);
+    fn transform_post(state: &mut SimulationState, _settings: &Settings, condition: Condition) {
+        state
+            .effects
+            .set_manipulation(8 + condition.effect_extension());
     }
 }
 
 pub struct PrudentTouch {}
 impl ActionImpl for PrudentTouch {
-    const LEVEL_REQUIREMENT: u8 = 66;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::PrudentTouch);
+    const DATA: ActionData = Action::PrudentTouch.data();
     fn precondition(
         state: &SimulationState,
         _settings: &Settings,
@@ -420,39 +391,22 @@ impl ActionImpl for PrudentTouch {
         }
         Ok(())
     }
-    fn quality_modifier(_state: &SimulationState, _settings: &Settings) -> u32 {
-        100
-    }
-    fn base_durability_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        5
-    }
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        25
-    }
 }
 
 pub struct AdvancedTouch {}
 impl ActionImpl for AdvancedTouch {
-    const LEVEL_REQUIREMENT: u8 = 68;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::AdvancedTouch);
-    fn quality_modifier(_state: &SimulationState, _settings: &Settings) -> u32 {
-        150
-    }
-    fn base_durability_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        10
-    }
+    const DATA: ActionData = Action::AdvancedTouch.data();
     fn base_cp_cost(state: &SimulationState, _settings: &Settings) -> u16 {
         match state.effects.combo() {
-            Combo::StandardTouch => 18,
-            _ => 46,
+            Combo::StandardTouch => Self::DATA.combo_cp_cost.unwrap_or(Self::DATA.cp_cost),
+            _ => Self::DATA.cp_cost,
         }
     }
 }
 
 pub struct Reflect {}
 impl ActionImpl for Reflect {
-    const LEVEL_REQUIREMENT: u8 = 69;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::Reflect);
+    const DATA: ActionData = Action::Reflect.data();
     fn precondition(
         state: &SimulationState,
         _settings: &Settings,
@@ -463,15 +417,6 @@ impl ActionImpl for Reflect {
         }
         Ok(())
     }
-    fn quality_modifier(_state: &SimulationState, _settings: &Settings) -> u32 {
-        300
-    }
-    fn base_durability_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        10
-    }
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        6
-    }
     fn transform_post(state: &mut SimulationState, _settings: &Settings, _condition: Condition) {
         let iq = state.effects.inner_quiet();
         state.effects.set_inner_quiet(std::cmp::min(10, iq + 1));
@@ -480,20 +425,10 @@ impl ActionImpl for Reflect {
 
 pub struct PreparatoryTouch {}
 impl PreparatoryTouch {
-    pub const CP_COST: u16 = 40;
+    pub const CP_COST: u16 = Action::PreparatoryTouch.data().cp_cost;
 }
 impl ActionImpl for PreparatoryTouch {
-    const LEVEL_REQUIREMENT: u8 = 71;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::PreparatoryTouch);
-    fn quality_modifier(_state: &SimulationState, _settings: &Settings) -> u32 {
-        200
-    }
-    fn base_durability_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        20
-    }
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        Self::CP_COST
-    }
+    const DATA: ActionData = Action::PreparatoryTouch.data();
     fn transform_post(state: &mut SimulationState, _settings: &Settings, _condition: Condition) {
         let iq = state.effects.inner_quiet();
         state.effects.set_inner_quiet(std::cmp::min(10, iq + 1));
@@ -502,51 +437,28 @@ impl ActionImpl for PreparatoryTouch {
 
 pub struct Groundwork {}
 impl ActionImpl for Groundwork {
-    const LEVEL_REQUIREMENT: u8 = 72;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::Groundwork);
-    fn progress_modifier(state: &SimulationState, settings: &Settings) -> u32 {
-        let base = match settings.job_level {
-            0..86 => 300,
-            86.. => 360,
-        };
-        if Self::durability_cost(state, settings, Condition::Normal) > state.durability {
+    const DATA: ActionData = Action::Groundwork.data();
+    fn progress_modifier(
+        state: &SimulationState,
+        settings: &Settings,
+        condition: Condition,
+    ) -> u32 {
+        let base = Self::DATA.progress_potency(settings);
+        if Self::durability_cost(state, settings, condition) > state.durability {
             return base / 2;
         }
         base
     }
-    fn base_durability_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        20
-    }
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        18
-    }
 }
 
 pub struct DelicateSynthesis {}
 impl ActionImpl for DelicateSynthesis {
-    const LEVEL_REQUIREMENT: u8 = 76;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::DelicateSynthesis);
-    fn progress_modifier(_state: &SimulationState, settings: &Settings) -> u32 {
-        match settings.job_level {
-            0..94 => 100,
-            94.. => 150,
-        }
-    }
-    fn quality_modifier(_state: &SimulationState, _settings: &Settings) -> u32 {
-        100
-    }
-    fn base_durability_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        10
-    }
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        32
-    }
+    const DATA: ActionData = Action::DelicateSynthesis.data();
 }
 
 pub struct IntensiveSynthesis {}
 impl ActionImpl for IntensiveSynthesis {
-    const LEVEL_REQUIREMENT: u8 = 78;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::IntensiveSynthesis);
+    const DATA: ActionData = Action::IntensiveSynthesis.data();
     fn precondition(
         state: &SimulationState,
         _settings: &Settings,
@@ -562,15 +474,6 @@ impl ActionImpl for IntensiveSynthesis {
         }
         Ok(())
     }
-    fn progress_modifier(_state: &SimulationState, _settings: &Settings) -> u32 {
-        400
-    }
-    fn base_durability_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        10
-    }
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        6
-    }
     fn transform_post(state: &mut SimulationState, _settings: &Settings, condition: Condition) {
         if condition != Condition::Good && condition != Condition::Excellent {
             state.effects.set_heart_and_soul_active(false);
@@ -580,8 +483,7 @@ impl ActionImpl for IntensiveSynthesis {
 
 pub struct TrainedEye {}
 impl ActionImpl for TrainedEye {
-    const LEVEL_REQUIREMENT: u8 = 80;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::TrainedEye);
+    const DATA: ActionData = Action::TrainedEye.data();
     fn precondition(
         state: &SimulationState,
         _settings: &Settings,
@@ -602,18 +504,11 @@ impl ActionImpl for TrainedEye {
     fn quality_modifier(_state: &SimulationState, settings: &Settings) -> u32 {
         u32::from(settings.max_quality)
     }
-    fn base_durability_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        10
-    }
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        250
-    }
 }
 
 pub struct HeartAndSoul {}
 impl ActionImpl for HeartAndSoul {
-    const LEVEL_REQUIREMENT: u8 = 86;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::HeartAndSoul);
+    const DATA: ActionData = Action::HeartAndSoul.data();
     const TICK_EFFECTS: bool = false;
     fn precondition(
         state: &SimulationState,
@@ -633,8 +528,7 @@ impl ActionImpl for HeartAndSoul {
 
 pub struct PrudentSynthesis {}
 impl ActionImpl for PrudentSynthesis {
-    const LEVEL_REQUIREMENT: u8 = 88;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::PrudentSynthesis);
+    const DATA: ActionData = Action::PrudentSynthesis.data();
     fn precondition(
         state: &SimulationState,
         _settings: &Settings,
@@ -645,21 +539,11 @@ impl ActionImpl for PrudentSynthesis {
         }
         Ok(())
     }
-    fn progress_modifier(_state: &SimulationState, _settings: &Settings) -> u32 {
-        180
-    }
-    fn base_durability_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        5
-    }
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        18
-    }
 }
 
 pub struct TrainedFinesse {}
 impl ActionImpl for TrainedFinesse {
-    const LEVEL_REQUIREMENT: u8 = 90;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::TrainedFinesse);
+    const DATA: ActionData = Action::TrainedFinesse.data();
     fn precondition(
         state: &SimulationState,
         _settings: &Settings,
@@ -670,21 +554,14 @@ impl ActionImpl for TrainedFinesse {
         }
         Ok(())
     }
-    fn quality_modifier(_state: &SimulationState, _settings: &Settings) -> u32 {
-        100
-    }
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        32
-    }
 }
 
 pub struct RefinedTouch {}
 impl RefinedTouch {
-    pub const CP_COST: u16 = 24;
+    pub const CP_COST: u16 = Action::RefinedTouch.data().cp_cost;
 }
 impl ActionImpl for RefinedTouch {
-    const LEVEL_REQUIREMENT: u8 = 92;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::RefinedTouch);
+    const DATA: ActionData = Action::RefinedTouch.data();
     fn precondition(
         state: &SimulationState,
         _settings: &Settings,
@@ -695,15 +572,6 @@ impl ActionImpl for RefinedTouch {
         }
         Ok(())
     }
-    fn quality_modifier(_state: &SimulationState, _settings: &Settings) -> u32 {
-        100
-    }
-    fn base_durability_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        10
-    }
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        Self::CP_COST
-    }
     fn transform_post(state: &mut SimulationState, _settings: &Settings, _condition: Condition) {
         let iq = state.effects.inner_quiet();
         state.effects.set_inner_quiet(std::cmp::min(10, iq + 1));
@@ -712,8 +580,7 @@ impl ActionImpl for RefinedTouch {
 
 pub struct QuickInnovation {}
 impl ActionImpl for QuickInnovation {
-    const LEVEL_REQUIREMENT: u8 = 96;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::QuickInnovation);
+    const DATA: ActionData = Action::QuickInnovation.data();
     const TICK_EFFECTS: bool = false;
     fn precondition(
         state: &SimulationState,
@@ -736,14 +603,10 @@ impl ActionImpl for QuickInnovation {
 
 pub struct ImmaculateMend {}
 impl ImmaculateMend {
-    pub const CP_COST: u16 = 112;
+    pub const CP_COST: u16 = Action::ImmaculateMend.data().cp_cost;
 }
 impl ActionImpl for ImmaculateMend {
-    const LEVEL_REQUIREMENT: u8 = 98;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::ImmaculateMend);
-    fn base_cp_cost(_state: &SimulationState, _settings: &Settings) -> u16 {
-        Self::CP_COST
-    }
+    const DATA: ActionData = Action::ImmaculateMend.data();
     fn transform_post(state: &mut SimulationState, settings: &Settings, _condition: Condition) {
         state.durability = settings.max_durability;
     }
@@ -751,8 +614,7 @@ impl ActionImpl for ImmaculateMend {
 
 pub struct TrainedPerfection {}
 impl ActionImpl for TrainedPerfection {
-    const LEVEL_REQUIREMENT: u8 = 100;
-    const ACTION_MASK: ActionMask = ActionMask::none().add(Action::TrainedPerfection);
+    const DATA: ActionData = Action::TrainedPerfection.data();
     fn precondition(
         state: &SimulationState,
         _settings: &Settings,
@@ -769,6 +631,21 @@ impl ActionImpl for TrainedPerfection {
     }
 }
 
+pub struct CarefulObservation {}
+impl CarefulObservation {
+    /// Every use consumes a Specialist's delineation, of which at most this many can be used per synthesis.
+    /// The uses aren't part of the state, see [`crate::Synthesis`].
+    pub const MAX_USES: u8 = 3;
+}
+impl ActionImpl for CarefulObservation {
+    const DATA: ActionData = Action::CarefulObservation.data();
+    const TICK_EFFECTS: bool = false;
+    fn combo(state: &SimulationState, _settings: &Settings, _condition: Condition) -> Combo {
+        // the step isn't used up, so the combo of the previous action is kept
+        state.effects.combo()
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Action {
@@ -803,7 +680,148 @@ pub enum Action {
     QuickInnovation,
     ImmaculateMend,
     TrainedPerfection,
+    /// Rerolls the condition without using up the step, so it leaves the state unchanged.
+    /// Only specialists can use it and it can't be used in macros.
+    CarefulObservation,
+}
+
+/// Matches `$action` and evaluates `$body` with `$A` naming the [`ActionImpl`] of the action,
+/// so that everything dispatched on an [`Action`] covers the same actions.
+macro_rules! dispatch_action {
+    ($action:expr, $A:ident => $body:expr) => {
+        match $action {
+            $crate::Action::BasicSynthesis => {
+                type $A = $crate::BasicSynthesis;
+                $body
+            }
+            $crate::Action::BasicTouch => {
+                type $A = $crate::BasicTouch;
+                $body
+            }
+            $crate::Action::MasterMend => {
+                type $A = $crate::MasterMend;
+                $body
+            }
+            $crate::Action::Observe => {
+                type $A = $crate::Observe;
+                $body
+            }
+            $crate::Action::TricksOfTheTrade => {
+                type $A = $crate::TricksOfTheTrade;
+                $body
+            }
+            $crate::Action::WasteNot => {
+                type $A = $crate::WasteNot;
+                $body
+            }
+            $crate::Action::Veneration => {
+                type $A = $crate::Veneration;
+                $body
+            }
+            $crate::Action::StandardTouch => {
+                type $A = $crate::StandardTouch;
+                $body
+            }
+            $crate::Action::GreatStrides => {
+                type $A = $crate::GreatStrides;
+                $body
+            }
+            $crate::Action::Innovation => {
+                type $A = $crate::Innovation;
+                $body
+            }
+            $crate::Action::WasteNot2 => {
+                type $A = $crate::WasteNot2;
+                $body
+            }
+            $crate::Action::ByregotsBlessing => {
+                type $A = $crate::ByregotsBlessing;
+                $body
+            }
+            $crate::Action::PreciseTouch => {
+                type $A = $crate::PreciseTouch;
+                $body
+            }
+            $crate::Action::MuscleMemory => {
+                type $A = $crate::MuscleMemory;
+                $body
+            }
+            $crate::Action::CarefulSynthesis => {
+                type $A = $crate::CarefulSynthesis;
+                $body
+            }
+            $crate::Action::Manipulation => {
+                type $A = $crate::Manipulation;
+                $body
+            }
+            $crate::Action::PrudentTouch => {
+                type $A = $crate::PrudentTouch;
+                $body
+            }
+            $crate::Action::AdvancedTouch => {
+                type $A = $crate::AdvancedTouch;
+                $body
+            }
+            $crate::Action::Reflect => {
+                type $A = $crate::Reflect;
+                $body
+            }
+            $crate::Action::PreparatoryTouch => {
+                type $A = $crate::PreparatoryTouch;
+                $body
+            }
+            $crate::Action::Groundwork => {
+                type $A = $crate::Groundwork;
+                $body
+            }
+            $crate::Action::DelicateSynthesis => {
+                type $A = $crate::DelicateSynthesis;
+                $body
+            }
+            $crate::Action::IntensiveSynthesis => {
+                type $A = $crate::IntensiveSynthesis;
+                $body
+            }
+            $crate::Action::TrainedEye => {
+                type $A = $crate::TrainedEye;
+                $body
+            }
+            $crate::Action::HeartAndSoul => {
+                type $A = $crate::HeartAndSoul;
+                $body
+            }
+            $crate::Action::PrudentSynthesis => {
+                type $A = $crate::PrudentSynthesis;
+                $body
+            }
+            $crate::Action::TrainedFinesse => {
+                type $A = $crate::TrainedFinesse;
+                $body
+            }
+            $crate::Action::RefinedTouch => {
+                type $A = $crate::RefinedTouch;
+                $body
+            }
+            $crate::Action::QuickInnovation => {
+                type $A = $crate::QuickInnovation;
+                $body
+            }
+            $crate::Action::ImmaculateMend => {
+                type $A = $crate::ImmaculateMend;
+                $body
+            }
+            $crate::Action::TrainedPerfection => {
+                type $A = $crate::TrainedPerfection;
+                $body
+            }
+            $crate::Action::CarefulObservation => {
+                type $A = $crate::CarefulObservation;
+                $body
+            }
+        }
+    };
 }
+pub(crate) use dispatch_action;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum Combo {
@@ -834,6 +852,10 @@ impl Combo {
 }
 
 impl Action {
+    pub const fn data(self) -> ActionData {
+        ACTION_DATA[self as usize]
+    }
+
     pub const fn time_cost(self) -> u8 {
         match self {
             Self::BasicSynthesis => 3,
@@ -867,6 +889,8 @@ impl Action {
             Self::TrainedPerfection => 3,
             Self::TrainedEye => 3,
             Self::QuickInnovation => 3,
+            // not part of macros, so it adds no wait to them
+            Self::CarefulObservation => 0,
         }
     }
 }
@@ -1,7 +1,174 @@
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Condition {
+    #[default]
     Normal,
     Good,
     Excellent,
     Poor,
+    /// Only rolled by expert recipes. Raises the success rate of actions, which has no effect on the modeled actions since they always succeed.
+    Centered,
+    /// Only rolled by expert recipes. Halves the durability cost.
+    Sturdy,
+    /// Only rolled by expert recipes. Halves the CP cost.
+    Pliant,
+    /// Only rolled by expert recipes. Increases Progress by 50%.
+    Malleable,
+    /// Only rolled by expert recipes. Effects applied in this step last 2 steps longer.
+    Primed,
+}
+
+impl Condition {
+    /// Conditions of consecutive steps, where the first steps have the given conditions and all steps after them are Normal.
+    pub fn sequence(first_conditions: &[Self]) -> impl Iterator<Item = Self> + '_ {
+        first_conditions
+            .iter()
+            .copied()
+            .chain(std::iter::repeat(Self::Normal))
+    }
+
+    /// Whether a step with this condition can directly follow a step with `previous`.
+    /// Excellent is always followed by Poor and Poor only ever follows Excellent.
+    pub const fn can_follow(self, previous: Self) -> bool {
+        matches!(previous, Self::Excellent) == matches!(self, Self::Poor)
+    }
+
+    /// Whether the condition is only rolled by expert recipes.
+    pub const fn is_expert_only(self) -> bool {
+        matches!(
+            self,
+            Self::Centered | Self::Sturdy | Self::Pliant | Self::Malleable | Self::Primed
+        )
+    }
+
+    /// Quality multiplier of the condition in halves, e.g. 3 for the 1.5x of Good.
+    pub const fn quality_modifier(self) -> u32 {
+        match self {
+            Self::Good => 3,
+            Self::Excellent => 8,
+            Self::Poor => 1,
+            _ => 2,
+        }
+    }
+
+    /// Progress multiplier of the condition in halves, e.g. 3 for the 1.5x of Malleable.
+    pub const fn progress_modifier(self) -> u32 {
+        match self {
+            Self::Malleable => 3,
+            _ => 2,
+        }
+    }
+
+    /// Steps that an effect applied in a step with this condition lasts longer.
+    pub const fn effect_extension(self) -> u8 {
+        match self {
+            Self::Primed => 2,
+            _ => 0,
+        }
+    }
+}
+
+/// Chances of the conditions a step can roll, in percent, the remaining chance is Normal.
+/// The step after Excellent is always Poor, which is always followed by Normal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConditionProbabilities {
+    pub good: u8,
+    pub excellent: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub centered: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sturdy: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pliant: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub malleable: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub primed: u8,
+}
+
+impl ConditionProbabilities {
+    /// Approximate rates of regular recipes
+    pub const REGULAR_RECIPE: Self = Self {
+        good: 25,
+        excellent: 4,
+        centered: 0,
+        sturdy: 0,
+        pliant: 0,
+        malleable: 0,
+        primed: 0,
+    };
+    /// Approximate rates of expert recipes, which never roll Excellent.
+    /// No expert recipe rolls all of these conditions, see [`Self::restricted_to`].
+    pub const EXPERT_RECIPE: Self = Self {
+        good: 12,
+        excellent: 0,
+        centered: 15,
+        sturdy: 15,
+        pliant: 12,
+        malleable: 12,
+        primed: 12,
+    };
+
+    /// Chance of each condition other than Normal, in the order in which they are rolled
+    pub const fn chances(self) -> [(Condition, u8); 7] {
+        [
+            (Condition::Good, self.good),
+            (Condition::Excellent, self.excellent),
+            (Condition::Centered, self.centered),
+            (Condition::Sturdy, self.sturdy),
+            (Condition::Pliant, self.pliant),
+            (Condition::Malleable, self.malleable),
+            (Condition::Primed, self.primed),
+        ]
+    }
+
+    /// Same chances, except that the conditions that aren't among `conditions` never roll
+    #[must_use]
+    pub fn restricted_to(self, conditions: &[Condition]) -> Self {
+        let chance = |condition: Condition, chance: u8| match conditions.contains(&condition) {
+            true => chance,
+            false => 0,
+        };
+        Self {
+            good: chance(Condition::Good, self.good),
+            excellent: chance(Condition::Excellent, self.excellent),
+            centered: chance(Condition::Centered, self.centered),
+            sturdy: chance(Condition::Sturdy, self.sturdy),
+            pliant: chance(Condition::Pliant, self.pliant),
+            malleable: chance(Condition::Malleable, self.malleable),
+            primed: chance(Condition::Primed, self.primed),
+        }
+    }
+
+    /// Sum of the chances of all conditions other than Normal
+    pub fn total(self) -> u16 {
+        self.chances()
+            .iter()
+            .map(|&(_, chance)| u16::from(chance))
+            .sum()
+    }
+
+    /// Condition of the step after a step with `previous`, where `roll` is uniformly distributed in `0..100`
+    pub fn next(self, previous: Condition, roll: u8) -> Condition {
+        match previous {
+            Condition::Excellent => return Condition::Poor,
+            Condition::Poor => return Condition::Normal,
+            _ => (),
+        }
+        let mut threshold = 0;
+        for (condition, chance) in self.chances() {
+            threshold += u16::from(chance);
+            if u16::from(roll) < threshold {
+                return condition;
+            }
+        }
+        Condition::Normal
+    }
+}
+
+impl Default for ConditionProbabilities {
+    fn default() -> Self {
+        Self::REGULAR_RECIPE
+    }
 }
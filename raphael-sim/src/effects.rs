@@ -3,15 +3,17 @@ use crate::{Combo, Settings};
 #[bitfield_struct::bitfield(u32, default = false)]
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Effects {
+    /// Inner Quiet stacks, or `QUALITY_ACTIONS_FORBIDDEN` once Quality actions are forbidden by the `backload_progress` setting.
+    /// Inner Quiet is always 0 in that case, so the flag doesn't need a bit of its own.
     #[bits(4)]
-    pub inner_quiet: u8,
+    inner_quiet_bits: u8,
     #[bits(4)]
     pub waste_not: u8,
     #[bits(3)]
     pub innovation: u8,
     #[bits(3)]
     pub veneration: u8,
-    #[bits(2)]
+    #[bits(3)]
     pub great_strides: u8,
     #[bits(3)]
     pub muscle_memory: u8,
@@ -20,17 +22,18 @@ pub struct Effects {
 
     pub trained_perfection_available: bool,
     pub heart_and_soul_available: bool,
-    pub quick_innovation_available: bool,
     pub trained_perfection_active: bool,
     pub heart_and_soul_active: bool,
 
     pub adversarial_guard: bool,
-    pub allow_quality_actions: bool,
+    pub quick_innovation_available: bool,
 
     #[bits(2)]
     pub combo: Combo,
 }
 
+const QUALITY_ACTIONS_FORBIDDEN: u8 = 0b1111;
+
 impl Effects {
     /// Effects at synthesis begin
     pub fn initial(settings: &Settings) -> Self {
@@ -49,6 +52,52 @@ impl Effects {
             .with_combo(Combo::SynthesisBegin)
     }
 
+    #[inline]
+    pub const fn inner_quiet(self) -> u8 {
+        if self.allow_quality_actions() {
+            self.inner_quiet_bits()
+        } else {
+            0
+        }
+    }
+
+    /// Inner Quiet stays at 0 while Quality actions are forbidden.
+    #[inline]
+    pub const fn with_inner_quiet(self, value: u8) -> Self {
+        if self.allow_quality_actions() {
+            self.with_inner_quiet_bits(value)
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    pub const fn set_inner_quiet(&mut self, value: u8) {
+        *self = self.with_inner_quiet(value);
+    }
+
+    #[inline]
+    pub const fn allow_quality_actions(self) -> bool {
+        self.inner_quiet_bits() != QUALITY_ACTIONS_FORBIDDEN
+    }
+
+    /// Forbidding Quality actions also removes all Inner Quiet stacks.
+    #[inline]
+    pub const fn with_allow_quality_actions(self, value: bool) -> Self {
+        if !value {
+            self.with_inner_quiet_bits(QUALITY_ACTIONS_FORBIDDEN)
+        } else if !self.allow_quality_actions() {
+            self.with_inner_quiet_bits(0)
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    pub const fn set_allow_quality_actions(&mut self, value: bool) {
+        *self = self.with_allow_quality_actions(value);
+    }
+
     #[inline]
     pub const fn progress_modifier(self) -> u32 {
         let mm_mod = 2 * (self.muscle_memory() != 0) as u32;
@@ -93,12 +142,11 @@ impl Effects {
     /// Removes all effects that are only relevant for Quality.
     #[inline]
     pub const fn strip_quality_effects(self) -> Self {
-        self.with_allow_quality_actions(false)
-            .with_inner_quiet(0)
-            .with_innovation(0)
+        self.with_innovation(0)
             .with_great_strides(0)
             .with_adversarial_guard(false)
             .with_quick_innovation_available(false)
+            .with_allow_quality_actions(false)
     }
 }
 
@@ -124,6 +172,7 @@ const EFFECTS_BIT_2: u32 = Effects::new()
     .with_waste_not(4)
     .with_innovation(4)
     .with_veneration(4)
+    .with_great_strides(4)
     .with_muscle_memory(4)
     .with_manipulation(4)
     .into_bits();
@@ -0,0 +1,76 @@
+use crate::{Action, Condition, ConditionProbabilities, Settings, SimulationState, Synthesis};
+
+/// Outcome of a macro over many simulated crafts with random conditions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MacroEstimate {
+    pub crafts: u32,
+    /// Crafts in which the macro reached the max Progress
+    pub completed: u32,
+    /// Completed crafts in which the Quality reached the target
+    pub reached_target: u32,
+}
+
+/// SplitMix64, so that estimates are reproducible without depending on a random number crate
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniformly distributed in `0..100`
+    fn roll(&mut self) -> u8 {
+        (((self.next_u64() >> 32) * 100) >> 32) as u8
+    }
+}
+
+/// Simulates `crafts` crafts of the macro from `initial_state`, with the conditions of the steps drawn from `probabilities`.
+/// The first step is always Normal, as in-game. Actions that can't be used, e.g. because the condition they require didn't roll, are skipped like in an in-game macro and don't use up the step.
+///
+/// The conditions are drawn from a fixed seed, so the same inputs always give the same estimate.
+/// Quality is simulated as rolled, even if `settings` are adversarial.
+pub fn estimate_macro(
+    settings: &Settings,
+    initial_state: SimulationState,
+    actions: &[Action],
+    target_quality: u32,
+    probabilities: ConditionProbabilities,
+    crafts: u32,
+) -> MacroEstimate {
+    let settings = Settings {
+        adversarial: false,
+        ..*settings
+    };
+    let mut rng = Rng(0);
+    let mut estimate = MacroEstimate {
+        crafts,
+        completed: 0,
+        reached_target: 0,
+    };
+    for _ in 0..crafts {
+        let mut synthesis = Synthesis::new(initial_state);
+        let mut condition = Condition::Normal;
+        for action in actions {
+            if synthesis.state.is_final(&settings) {
+                break;
+            }
+            // a skipped action doesn't use up the step, so the condition only changes after a used one
+            if let Ok(new_synthesis) = synthesis.use_action(*action, condition, &settings) {
+                synthesis = new_synthesis;
+                condition = probabilities.next(condition, rng.roll());
+            }
+        }
+        let state = synthesis.state;
+        if state.progress >= u32::from(settings.max_progress) {
+            estimate.completed += 1;
+            if state.quality >= target_quality {
+                estimate.reached_target += 1;
+            }
+        }
+    }
+    estimate
+}
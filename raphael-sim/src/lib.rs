@@ -2,13 +2,18 @@ mod actions;
 pub use actions::*;
 
 mod conditions;
-pub use conditions::Condition;
+pub use conditions::{Condition, ConditionProbabilities};
+
+mod estimate;
+pub use estimate::{MacroEstimate, estimate_macro};
 
 mod effects;
 pub use effects::Effects;
 
 pub mod state;
-pub use state::SimulationState;
+pub use state::{IncreaseFactors, SimulationState, Synthesis};
 
 mod settings;
-pub use settings::{ActionMask, Settings};
+pub use settings::{ActionMask, Settings, TraitOverrides};
+
+mod share;
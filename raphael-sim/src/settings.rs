@@ -14,16 +14,56 @@ pub struct Settings {
     pub adversarial: bool,
     /// If `backload_progress` is set, after using any action that increases Progress, the simulator will forbid the use of actions that directly increase Quality.
     pub backload_progress: bool,
+    /// Traits and actions that are toggled regardless of `job_level`, e.g. to test datamined changes.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub traits: TraitOverrides,
 }
 
 impl Settings {
     pub fn is_action_allowed<ACTION: ActionImpl>(&self) -> bool {
+        self.is_action_unlocked::<ACTION>() && self.allowed_actions.has_mask(ACTION::ACTION_MASK)
+    }
+
+    pub fn is_action_unlocked<ACTION: ActionImpl>(&self) -> bool {
         self.job_level >= ACTION::LEVEL_REQUIREMENT
-            && self.allowed_actions.has_mask(ACTION::ACTION_MASK)
+            || self.traits.unlocked_actions.has_mask(ACTION::ACTION_MASK)
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TraitOverrides {
+    /// Actions that get the Progress increase of their trait even if the job level is too low
+    pub enhanced_actions: ActionMask,
+    /// Actions that don't get the Progress increase of their trait even if the job level is high enough
+    pub unenhanced_actions: ActionMask,
+    /// Actions that can be used even if the job level is too low
+    pub unlocked_actions: ActionMask,
+}
+
+impl TraitOverrides {
+    /// No overrides, traits and actions are unlocked based on `job_level` only.
+    pub const fn none() -> Self {
+        Self {
+            enhanced_actions: ActionMask::none(),
+            unenhanced_actions: ActionMask::none(),
+            unlocked_actions: ActionMask::none(),
+        }
+    }
+
+    /// Returns whether the trait that enhances `action` is active, given whether it would be active based on the job level alone.
+    pub const fn is_enhanced(&self, action: Action, unlocked_by_level: bool) -> bool {
+        if self.enhanced_actions.has(action) {
+            true
+        } else if self.unenhanced_actions.has(action) {
+            false
+        } else {
+            unlocked_by_level
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ActionMask {
     mask: u64,
@@ -52,6 +92,7 @@ impl ActionMask {
             .remove(Action::TrainedEye)
             .remove(Action::HeartAndSoul)
             .remove(Action::QuickInnovation)
+            .remove(Action::CarefulObservation)
     }
 
     pub const fn has(self, action: Action) -> bool {
@@ -0,0 +1,90 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+
+use crate::{ActionMask, Effects, Settings, SimulationState};
+
+/// Incremented whenever the layout of the encoded state changes, so that old strings are rejected instead of being misread.
+const SHARE_STRING_VERSION: u8 = 2;
+
+/// version (1) + settings fingerprint (4) + cp (2) + durability (2) + progress (4) + quality (4) + unreliable quality (4) + effects (4)
+const SHARE_STRING_BYTES: usize = 25;
+
+impl Settings {
+    /// Hash of the settings that stays the same across platforms and builds, unlike `std::hash::Hash`.
+    /// Used to detect that a shared state was created for a different recipe or crafter.
+    pub fn fingerprint(&self) -> u32 {
+        // 32-bit FNV-1a
+        let mut hash: u32 = 0x811c_9dc5;
+        let mut write = |bytes: &[u8]| {
+            for byte in bytes {
+                hash ^= u32::from(*byte);
+                hash = hash.wrapping_mul(0x0100_0193);
+            }
+        };
+        write(&self.max_cp.to_le_bytes());
+        write(&self.max_durability.to_le_bytes());
+        write(&self.max_progress.to_le_bytes());
+        write(&self.max_quality.to_le_bytes());
+        write(&self.base_progress.to_le_bytes());
+        write(&self.base_quality.to_le_bytes());
+        write(&[self.job_level]);
+        write(&self.allowed_actions.to_bits().to_le_bytes());
+        write(&[u8::from(self.adversarial), u8::from(self.backload_progress)]);
+        let mask_bytes = |mask: ActionMask| mask.to_bits().to_le_bytes();
+        write(&mask_bytes(self.traits.enhanced_actions));
+        write(&mask_bytes(self.traits.unenhanced_actions));
+        write(&mask_bytes(self.traits.unlocked_actions));
+        hash
+    }
+}
+
+impl SimulationState {
+    /// Encodes the state as a short URL-safe base64 string that can be pasted somewhere else, e.g. to ask for a continuation of a mid-craft state.
+    pub fn to_share_string(&self, settings: &Settings) -> String {
+        let mut bytes = Vec::with_capacity(SHARE_STRING_BYTES);
+        bytes.push(SHARE_STRING_VERSION);
+        bytes.extend_from_slice(&settings.fingerprint().to_le_bytes());
+        bytes.extend_from_slice(&self.cp.to_le_bytes());
+        bytes.extend_from_slice(&self.durability.to_le_bytes());
+        bytes.extend_from_slice(&self.progress.to_le_bytes());
+        bytes.extend_from_slice(&self.quality.to_le_bytes());
+        bytes.extend_from_slice(&self.unreliable_quality.to_le_bytes());
+        bytes.extend_from_slice(&self.effects.into_bits().to_le_bytes());
+        URL_SAFE_NO_PAD.encode(bytes)
+    }
+
+    /// Decodes a string created by [`SimulationState::to_share_string`].
+    /// Fails if the string is malformed or if it was created with different `settings`.
+    pub fn from_share_string(string: &str, settings: &Settings) -> Result<Self, &'static str> {
+        let bytes = URL_SAFE_NO_PAD
+            .decode(string.trim())
+            .map_err(|_| "Invalid state string")?;
+        if bytes.len() != SHARE_STRING_BYTES || bytes[0] != SHARE_STRING_VERSION {
+            return Err("Invalid state string");
+        }
+        let u16_at = |offset: usize| u16::from_le_bytes([bytes[offset], bytes[offset + 1]]);
+        let u32_at = |offset: usize| {
+            u32::from_le_bytes([
+                bytes[offset],
+                bytes[offset + 1],
+                bytes[offset + 2],
+                bytes[offset + 3],
+            ])
+        };
+        if u32_at(1) != settings.fingerprint() {
+            return Err("State string was created with different settings");
+        }
+        let state = Self {
+            cp: u16_at(5),
+            durability: u16_at(7),
+            progress: u32_at(9),
+            quality: u32_at(13),
+            unreliable_quality: u32_at(17),
+            effects: Effects::from_bits(u32_at(21)),
+        };
+        if state.cp > settings.max_cp || state.durability > settings.max_durability {
+            return Err("Invalid state string");
+        }
+        Ok(state)
+    }
+}
@@ -2,6 +2,27 @@ use crate::actions::*;
 use crate::effects::*;
 use crate::{Condition, Settings};
 
+/// Factors of a Progress and Quality increase, which are multiplied and floored once:
+/// - Progress: base Progress * potency / 100 * effect modifier / 100 * condition modifier / 2
+/// - Quality: base Quality * potency / 100 * effect modifier / 100 * condition modifier / 2
+///
+/// The increases are the ones the simulator uses, which differ from the formula for actions like Trained Eye.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IncreaseFactors {
+    pub progress_potency: u32,
+    /// Percentage from Veneration and Muscle Memory
+    pub progress_effect_modifier: u32,
+    /// Multiplier of the condition in halves, see [`Condition::progress_modifier`]
+    pub progress_condition_modifier: u32,
+    pub progress_increase: u32,
+    pub quality_potency: u32,
+    /// Percentage from Inner Quiet, Innovation and Great Strides
+    pub quality_effect_modifier: u32,
+    /// Multiplier of the condition in halves, see [`Condition::quality_modifier`]
+    pub quality_condition_modifier: u32,
+    pub quality_increase: u32,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct SimulationState {
     pub cp: u16,
@@ -25,32 +46,55 @@ impl SimulationState {
     }
 
     pub fn from_macro(settings: &Settings, actions: &[Action]) -> Result<Self, &'static str> {
-        let mut state = Self::new(settings);
+        let mut synthesis = Synthesis::new(Self::new(settings));
         for action in actions {
-            state = state.use_action(*action, Condition::Normal, settings)?;
+            synthesis = synthesis.use_action(*action, Condition::Normal, settings)?;
         }
-        Ok(state)
+        Ok(synthesis.state)
     }
 
     pub fn from_macro_continue_on_error(
         settings: &Settings,
         actions: &[Action],
     ) -> (Self, Vec<Result<(), &'static str>>) {
-        let mut state = Self::new(settings);
+        Self::from_macro_in_conditions(settings, actions, &[])
+    }
+
+    /// Same as [`Self::from_macro_continue_on_error`], except that the first steps have the given conditions instead of Normal.
+    pub fn from_macro_in_conditions(
+        settings: &Settings,
+        actions: &[Action],
+        first_conditions: &[Condition],
+    ) -> (Self, Vec<Result<(), &'static str>>) {
+        Self::new(settings).continue_macro_in_conditions(settings, actions, first_conditions)
+    }
+
+    /// Same as [`Self::from_macro_in_conditions`], except that the actions are executed starting from `self`, e.g. a state with less than the max CP.
+    /// An action that fails doesn't use up the step, so its condition carries over to the next action.
+    pub fn continue_macro_in_conditions(
+        self,
+        settings: &Settings,
+        actions: &[Action],
+        first_conditions: &[Condition],
+    ) -> (Self, Vec<Result<(), &'static str>>) {
+        let mut synthesis = Synthesis::new(self);
         let mut errors = Vec::new();
+        let mut conditions = Condition::sequence(first_conditions).peekable();
         for action in actions {
-            state = match state.use_action(*action, Condition::Normal, settings) {
-                Ok(new_state) => {
+            let condition = *conditions.peek().unwrap();
+            synthesis = match synthesis.use_action(*action, condition, settings) {
+                Ok(new_synthesis) => {
+                    conditions.next();
                     errors.push(Ok(()));
-                    new_state
+                    new_synthesis
                 }
                 Err(err) => {
                     errors.push(Err(err));
-                    state
+                    synthesis
                 }
             };
         }
-        (state, errors)
+        (synthesis.state, errors)
     }
 
     pub fn is_final(&self, settings: &Settings) -> bool {
@@ -62,7 +106,7 @@ impl SimulationState {
         settings: &Settings,
         condition: Condition,
     ) -> Result<(), &'static str> {
-        if settings.job_level < A::LEVEL_REQUIREMENT {
+        if !settings.is_action_unlocked::<A>() {
             Err("Level not high enough")
         } else if !settings.allowed_actions.has_mask(A::ACTION_MASK) {
             Err("Action disabled by action mask")
@@ -127,7 +171,7 @@ impl SimulationState {
                 .set_inner_quiet(std::cmp::min(10, state.effects.inner_quiet() + 1));
         }
 
-        let progress_increase = A::progress_increase(self, settings);
+        let progress_increase = A::progress_increase(self, settings, condition);
         state.progress += progress_increase;
         if progress_increase != 0 && state.effects.muscle_memory() != 0 {
             state.effects.set_muscle_memory(0);
@@ -172,54 +216,82 @@ impl SimulationState {
         condition: Condition,
         settings: &Settings,
     ) -> Result<Self, &'static str> {
-        match action {
-            Action::BasicSynthesis => self.use_action_impl::<BasicSynthesis>(settings, condition),
-            Action::BasicTouch => self.use_action_impl::<BasicTouch>(settings, condition),
-            Action::MasterMend => self.use_action_impl::<MasterMend>(settings, condition),
-            Action::Observe => self.use_action_impl::<Observe>(settings, condition),
-            Action::TricksOfTheTrade => {
-                self.use_action_impl::<TricksOfTheTrade>(settings, condition)
-            }
-            Action::WasteNot => self.use_action_impl::<WasteNot>(settings, condition),
-            Action::Veneration => self.use_action_impl::<Veneration>(settings, condition),
-            Action::StandardTouch => self.use_action_impl::<StandardTouch>(settings, condition),
-            Action::GreatStrides => self.use_action_impl::<GreatStrides>(settings, condition),
-            Action::Innovation => self.use_action_impl::<Innovation>(settings, condition),
-            Action::WasteNot2 => self.use_action_impl::<WasteNot2>(settings, condition),
-            Action::ByregotsBlessing => {
-                self.use_action_impl::<ByregotsBlessing>(settings, condition)
-            }
-            Action::PreciseTouch => self.use_action_impl::<PreciseTouch>(settings, condition),
-            Action::MuscleMemory => self.use_action_impl::<MuscleMemory>(settings, condition),
-            Action::CarefulSynthesis => {
-                self.use_action_impl::<CarefulSynthesis>(settings, condition)
-            }
-            Action::Manipulation => self.use_action_impl::<Manipulation>(settings, condition),
-            Action::PrudentTouch => self.use_action_impl::<PrudentTouch>(settings, condition),
-            Action::AdvancedTouch => self.use_action_impl::<AdvancedTouch>(settings, condition),
-            Action::Reflect => self.use_action_impl::<Reflect>(settings, condition),
-            Action::PreparatoryTouch => {
-                self.use_action_impl::<PreparatoryTouch>(settings, condition)
-            }
-            Action::Groundwork => self.use_action_impl::<Groundwork>(settings, condition),
-            Action::DelicateSynthesis => {
-                self.use_action_impl::<DelicateSynthesis>(settings, condition)
-            }
-            Action::IntensiveSynthesis => {
-                self.use_action_impl::<IntensiveSynthesis>(settings, condition)
-            }
-            Action::TrainedEye => self.use_action_impl::<TrainedEye>(settings, condition),
-            Action::HeartAndSoul => self.use_action_impl::<HeartAndSoul>(settings, condition),
-            Action::PrudentSynthesis => {
-                self.use_action_impl::<PrudentSynthesis>(settings, condition)
-            }
-            Action::TrainedFinesse => self.use_action_impl::<TrainedFinesse>(settings, condition),
-            Action::RefinedTouch => self.use_action_impl::<RefinedTouch>(settings, condition),
-            Action::QuickInnovation => self.use_action_impl::<QuickInnovation>(settings, condition),
-            Action::ImmaculateMend => self.use_action_impl::<ImmaculateMend>(settings, condition),
-            Action::TrainedPerfection => {
-                self.use_action_impl::<TrainedPerfection>(settings, condition)
+        if action == Action::CarefulObservation {
+            // the step isn't used up, so not even the adversarial Quality or the combo change.
+            // The uses are limited per synthesis, see `Synthesis::use_action`.
+            self.check_common_preconditions::<CarefulObservation>(settings, condition)?;
+            return Ok(*self);
+        }
+        dispatch_action!(action, A => self.use_action_impl::<A>(settings, condition))
+    }
+
+    /// Factors of the Progress and Quality increase of `action`, for showing how the increases are computed.
+    /// Doesn't check whether the action can be used.
+    pub fn increase_factors(
+        &self,
+        action: Action,
+        condition: Condition,
+        settings: &Settings,
+    ) -> IncreaseFactors {
+        dispatch_action!(action, A => Self::increase_factors_impl::<A>(self, settings, condition))
+    }
+
+    fn increase_factors_impl<A: ActionImpl>(
+        state: &Self,
+        settings: &Settings,
+        condition: Condition,
+    ) -> IncreaseFactors {
+        IncreaseFactors {
+            progress_potency: A::progress_modifier(state, settings, condition),
+            progress_effect_modifier: state.effects.progress_modifier(),
+            progress_condition_modifier: condition.progress_modifier(),
+            progress_increase: A::progress_increase(state, settings, condition),
+            quality_potency: A::quality_modifier(state, settings),
+            quality_effect_modifier: state.effects.quality_modifier(),
+            quality_condition_modifier: condition.quality_modifier(),
+            quality_increase: A::quality_increase(state, settings, condition),
+        }
+    }
+}
+
+/// A synthesis in progress, which also tracks what the solvers don't need in their states.
+/// The solvers never use Careful Observation, so its uses would only grow their states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Synthesis {
+    pub state: SimulationState,
+    careful_observations: u8,
+}
+
+impl Synthesis {
+    pub fn new(state: SimulationState) -> Self {
+        Self {
+            state,
+            careful_observations: 0,
+        }
+    }
+
+    /// Uses of Careful Observation so far in the synthesis
+    pub fn careful_observations(&self) -> u8 {
+        self.careful_observations
+    }
+
+    /// Same as [`SimulationState::use_action`], except that Careful Observation fails after [`CarefulObservation::MAX_USES`] uses.
+    pub fn use_action(
+        &self,
+        action: Action,
+        condition: Condition,
+        settings: &Settings,
+    ) -> Result<Self, &'static str> {
+        let mut careful_observations = self.careful_observations;
+        if action == Action::CarefulObservation {
+            if careful_observations >= CarefulObservation::MAX_USES {
+                return Err("Careful Observation can only be used 3 times per synthesis.");
             }
+            careful_observations += 1;
         }
+        Ok(Self {
+            state: self.state.use_action(action, condition, settings)?,
+            careful_observations,
+        })
     }
 }
@@ -11,6 +11,7 @@ const SETTINGS: Settings = Settings {
     allowed_actions: ActionMask::all(),
     adversarial: false,
     backload_progress: false,
+    traits: TraitOverrides::none(),
 };
 
 /// Returns the 4 primary stats of a state:
@@ -719,3 +720,72 @@ fn test_quick_innovation() {
         Err("Quick Innovation cannot be used while Innovation is active.")
     );
 }
+
+#[test]
+fn test_careful_observation() {
+    let initial_state = SimulationState::from_macro(
+        &SETTINGS,
+        &[Action::Manipulation, Action::Veneration, Action::BasicTouch],
+    )
+    .unwrap();
+    let state = initial_state
+        .use_action(Action::CarefulObservation, Condition::Good, &SETTINGS)
+        .unwrap();
+    assert_eq!(state, initial_state); // the step isn't used up, so nothing changes
+    let state = SimulationState::from_macro(
+        &SETTINGS,
+        &[
+            Action::BasicTouch,
+            Action::CarefulObservation,
+            Action::StandardTouch,
+        ],
+    )
+    .unwrap();
+    // the combo is kept, so Standard Touch costs less
+    assert_eq!(primary_stats(&state, &SETTINGS).3, 18 + 18);
+    let settings = Settings {
+        allowed_actions: ActionMask::regular(),
+        ..SETTINGS
+    };
+    let state = SimulationState::new(&settings).use_action(
+        Action::CarefulObservation,
+        Condition::Normal,
+        &settings,
+    );
+    assert_eq!(state, Err("Action disabled by action mask"));
+}
+
+#[test]
+fn test_careful_observation_use_limit() {
+    let mut synthesis = Synthesis::new(SimulationState::new(&SETTINGS));
+    for _ in 0..3 {
+        synthesis = synthesis
+            .use_action(Action::CarefulObservation, Condition::Normal, &SETTINGS)
+            .unwrap();
+    }
+    assert_eq!(synthesis.careful_observations(), 3);
+    assert_eq!(
+        synthesis.use_action(Action::CarefulObservation, Condition::Normal, &SETTINGS),
+        Err("Careful Observation can only be used 3 times per synthesis.")
+    );
+    // the uses are counted across the whole synthesis, not just consecutive ones
+    let (_, errors) = SimulationState::from_macro_continue_on_error(
+        &SETTINGS,
+        &[
+            Action::CarefulObservation,
+            Action::BasicTouch,
+            Action::CarefulObservation,
+            Action::BasicTouch,
+            Action::CarefulObservation,
+            Action::BasicTouch,
+            Action::CarefulObservation,
+        ],
+    );
+    assert_eq!(
+        errors.last(),
+        Some(&Err(
+            "Careful Observation can only be used 3 times per synthesis."
+        ))
+    );
+    assert!(errors[..6].iter().all(Result::is_ok));
+}
@@ -1,4 +1,4 @@
-use raphael_sim::{Action, ActionMask, Condition, Settings, SimulationState};
+use raphael_sim::{Action, ActionMask, Condition, Settings, SimulationState, TraitOverrides};
 const SETTINGS: Settings = Settings {
     max_cp: 1000,
     max_durability: 80,
@@ -10,6 +10,7 @@ const SETTINGS: Settings = Settings {
     allowed_actions: ActionMask::all(),
     adversarial: true,
     backload_progress: false,
+    traits: TraitOverrides::none(),
 };
 
 /// Calculate the minimum achievable Quality across all possible Condition rolls
@@ -71,6 +72,27 @@ fn test_simple() {
     }
 }
 
+#[test]
+fn test_careful_observation() {
+    let state = SimulationState::from_macro(
+        &SETTINGS,
+        &[
+            Action::BasicTouch,
+            Action::BasicSynthesis,
+            Action::BasicTouch,
+            Action::BasicSynthesis,
+        ],
+    )
+    .unwrap();
+    // an unguarded step without Quality would drop the unreliable Quality
+    assert_ne!(state.unreliable_quality, 0);
+    assert!(!state.effects.adversarial_guard());
+    let new_state = state
+        .use_action(Action::CarefulObservation, Condition::Normal, &SETTINGS)
+        .unwrap();
+    assert_eq!(new_state, state);
+}
+
 #[test]
 fn test_short_quality_opener() {
     let actions = [Action::Reflect];
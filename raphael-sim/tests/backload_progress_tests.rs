@@ -1,4 +1,4 @@
-use raphael_sim::{Action, ActionMask, Condition, Settings, SimulationState};
+use raphael_sim::{Action, ActionMask, Condition, Settings, SimulationState, TraitOverrides};
 
 const SETTINGS: Settings = Settings {
     max_cp: 500,
@@ -11,6 +11,7 @@ const SETTINGS: Settings = Settings {
     allowed_actions: ActionMask::all(),
     adversarial: true,
     backload_progress: true,
+    traits: TraitOverrides::none(),
 };
 
 #[test]
@@ -0,0 +1,199 @@
+use raphael_sim::{
+    Action, ActionMask, Condition, ConditionProbabilities, Settings, SimulationState,
+    TraitOverrides, estimate_macro,
+};
+
+const SETTINGS: Settings = Settings {
+    max_cp: 400,
+    max_durability: 100,
+    max_progress: 1500,
+    max_quality: 40000,
+    base_progress: 100,
+    base_quality: 100,
+    job_level: 100,
+    allowed_actions: ActionMask::all(),
+    adversarial: false,
+    backload_progress: false,
+    traits: TraitOverrides::none(),
+};
+
+const ACTIONS: [Action; 6] = [
+    Action::MuscleMemory,
+    Action::Veneration,
+    Action::Groundwork,
+    Action::PreparatoryTouch,
+    Action::PreparatoryTouch,
+    Action::Groundwork,
+];
+
+/// Quality of the macro when the first steps have `conditions` and all steps after them are Normal
+fn quality_in_conditions(conditions: &[Condition]) -> u32 {
+    let mut state = SimulationState::new(&SETTINGS);
+    for (step, action) in ACTIONS.iter().enumerate() {
+        let condition = conditions.get(step).copied().unwrap_or(Condition::Normal);
+        state = state.use_action(*action, condition, &SETTINGS).unwrap();
+    }
+    state.quality
+}
+
+#[test]
+/// Without Good or Excellent, every craft rolls only Normal
+fn test_normal_conditions_only() {
+    let probabilities = ConditionProbabilities {
+        good: 0,
+        excellent: 0,
+        ..Default::default()
+    };
+    let quality = quality_in_conditions(&[]);
+    let estimate = estimate_macro(
+        &SETTINGS,
+        SimulationState::new(&SETTINGS),
+        &ACTIONS,
+        quality,
+        probabilities,
+        100,
+    );
+    assert_eq!(estimate.completed, 100);
+    assert_eq!(estimate.reached_target, 100);
+    let estimate = estimate_macro(
+        &SETTINGS,
+        SimulationState::new(&SETTINGS),
+        &ACTIONS,
+        quality + 1,
+        probabilities,
+        100,
+    );
+    assert_eq!(estimate.reached_target, 0);
+}
+
+#[test]
+/// With a guaranteed Good, every step after the first one is Good
+fn test_good_conditions_only() {
+    let probabilities = ConditionProbabilities {
+        good: 100,
+        excellent: 0,
+        ..Default::default()
+    };
+    let mut conditions = [Condition::Good; ACTIONS.len()];
+    conditions[0] = Condition::Normal;
+    let quality = quality_in_conditions(&conditions);
+    assert!(quality > quality_in_conditions(&[]));
+    let estimate = estimate_macro(
+        &SETTINGS,
+        SimulationState::new(&SETTINGS),
+        &ACTIONS,
+        quality,
+        probabilities,
+        100,
+    );
+    assert_eq!(estimate.reached_target, 100);
+}
+
+#[test]
+/// Excellent is followed by Poor and then Normal, regardless of the rolls
+fn test_excellent_chain() {
+    let probabilities = ConditionProbabilities {
+        good: 0,
+        excellent: 100,
+        ..Default::default()
+    };
+    assert_eq!(
+        probabilities.next(Condition::Normal, 0),
+        Condition::Excellent
+    );
+    assert_eq!(probabilities.next(Condition::Excellent, 0), Condition::Poor);
+    assert_eq!(probabilities.next(Condition::Poor, 0), Condition::Normal);
+    let quality = quality_in_conditions(&[
+        Condition::Normal,
+        Condition::Excellent,
+        Condition::Poor,
+        Condition::Normal,
+        Condition::Excellent,
+        Condition::Poor,
+    ]);
+    let estimate = estimate_macro(
+        &SETTINGS,
+        SimulationState::new(&SETTINGS),
+        &ACTIONS,
+        quality,
+        probabilities,
+        10,
+    );
+    assert_eq!(estimate.reached_target, 10);
+}
+
+#[test]
+/// A macro that runs out of durability before reaching the max Progress never completes
+fn test_incomplete_macro() {
+    let estimate = estimate_macro(
+        &SETTINGS,
+        SimulationState::new(&SETTINGS),
+        &ACTIONS[..2],
+        0,
+        ConditionProbabilities::REGULAR_RECIPE,
+        100,
+    );
+    assert_eq!(estimate.completed, 0);
+    assert_eq!(estimate.reached_target, 0);
+}
+
+#[test]
+/// An action that can't be used is skipped without using up the step, so the next action gets its condition
+fn test_skipped_action_keeps_condition() {
+    let probabilities = ConditionProbabilities {
+        good: 0,
+        excellent: 100,
+        ..Default::default()
+    };
+    // Muscle Memory can only be used in the first step, so the Excellent step goes to Preparatory Touch
+    let actions = [
+        Action::Veneration,
+        Action::MuscleMemory,
+        Action::PreparatoryTouch,
+        Action::Groundwork,
+        Action::Groundwork,
+        Action::Groundwork,
+    ];
+    let mut state = SimulationState::new(&SETTINGS);
+    for (action, condition) in [
+        (Action::Veneration, Condition::Normal),
+        (Action::PreparatoryTouch, Condition::Excellent),
+        (Action::Groundwork, Condition::Poor),
+        (Action::Groundwork, Condition::Normal),
+        (Action::Groundwork, Condition::Excellent),
+    ] {
+        state = state.use_action(action, condition, &SETTINGS).unwrap();
+    }
+    assert!(state.progress >= u32::from(SETTINGS.max_progress));
+    let estimate = estimate_macro(
+        &SETTINGS,
+        SimulationState::new(&SETTINGS),
+        &actions,
+        state.quality,
+        probabilities,
+        10,
+    );
+    assert_eq!(estimate.completed, 10);
+    assert_eq!(estimate.reached_target, 10);
+}
+
+#[test]
+/// The chances of expert recipes are rolled in order, and a condition that isn't in the pool never rolls
+fn test_expert_conditions() {
+    let probabilities = ConditionProbabilities::EXPERT_RECIPE;
+    assert_eq!(probabilities.next(Condition::Normal, 0), Condition::Good);
+    assert_eq!(probabilities.next(Condition::Good, 12), Condition::Centered);
+    assert_eq!(probabilities.next(Condition::Sturdy, 27), Condition::Sturdy);
+    assert_eq!(probabilities.next(Condition::Normal, 42), Condition::Pliant);
+    assert_eq!(
+        probabilities.next(Condition::Normal, 54),
+        Condition::Malleable
+    );
+    assert_eq!(probabilities.next(Condition::Normal, 66), Condition::Primed);
+    assert_eq!(probabilities.next(Condition::Normal, 78), Condition::Normal);
+    let probabilities =
+        probabilities.restricted_to(&[Condition::Normal, Condition::Good, Condition::Sturdy]);
+    assert_eq!(probabilities.total(), 12 + 15);
+    assert_eq!(probabilities.next(Condition::Normal, 12), Condition::Sturdy);
+    assert_eq!(probabilities.next(Condition::Normal, 27), Condition::Normal);
+}
@@ -0,0 +1,88 @@
+use raphael_sim::{Action, ActionMask, Settings, SimulationState, TraitOverrides};
+
+const SETTINGS: Settings = Settings {
+    max_cp: 600,
+    max_durability: 70,
+    max_progress: 6600,
+    max_quality: 12000,
+    base_progress: 250,
+    base_quality: 300,
+    job_level: 100,
+    allowed_actions: ActionMask::all(),
+    adversarial: true,
+    backload_progress: false,
+    traits: TraitOverrides::none(),
+};
+
+#[test]
+/// A mid-craft state (including effects) survives the round trip through a share string
+fn test_round_trip() {
+    let state = SimulationState::from_macro(
+        &SETTINGS,
+        &[
+            Action::MuscleMemory,
+            Action::Manipulation,
+            Action::Veneration,
+            Action::WasteNot,
+            Action::Groundwork,
+            Action::Innovation,
+            Action::BasicTouch,
+        ],
+    )
+    .unwrap();
+    let string = state.to_share_string(&SETTINGS);
+    assert!(
+        string
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    );
+    assert_eq!(
+        SimulationState::from_share_string(&string, &SETTINGS),
+        Ok(state)
+    );
+    // whitespace from copy-pasting is ignored
+    assert_eq!(
+        SimulationState::from_share_string(&format!(" {string}\n"), &SETTINGS),
+        Ok(state)
+    );
+}
+
+#[test]
+/// States created with different settings are rejected
+fn test_settings_mismatch() {
+    let state = SimulationState::new(&SETTINGS);
+    let string = state.to_share_string(&SETTINGS);
+    let other_settings = Settings {
+        base_quality: 301,
+        ..SETTINGS
+    };
+    assert_eq!(
+        SimulationState::from_share_string(&string, &other_settings),
+        Err("State string was created with different settings")
+    );
+}
+
+#[test]
+fn test_invalid_strings() {
+    let string = SimulationState::new(&SETTINGS).to_share_string(&SETTINGS);
+    for invalid_string in ["", "not a state", &string[1..], &format!("{string}AA")] {
+        assert_eq!(
+            SimulationState::from_share_string(invalid_string, &SETTINGS),
+            Err("Invalid state string")
+        );
+    }
+}
+
+#[test]
+/// The fingerprint must not change between builds, otherwise shared strings stop working after an update
+fn test_fingerprint_is_stable() {
+    assert_eq!(SETTINGS.fingerprint(), 426358458);
+    assert_ne!(
+        SETTINGS.fingerprint(),
+        Settings {
+            adversarial: false,
+            ..SETTINGS
+        }
+        .fingerprint()
+    );
+}
@@ -1,4 +1,4 @@
-use raphael_sim::{Action, ActionMask, Condition, Settings, SimulationState};
+use raphael_sim::{Action, ActionMask, Condition, Settings, SimulationState, TraitOverrides};
 
 fn simulate(
     settings: &Settings,
@@ -36,6 +36,7 @@ fn test_level_requirement() {
         allowed_actions: ActionMask::all(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
     let error = SimulationState::new(&settings)
         .use_action(Action::ImmaculateMend, Condition::Normal, &settings)
@@ -43,6 +44,72 @@ fn test_level_requirement() {
     assert_eq!(error, "Level not high enough");
 }
 
+#[test]
+/// Actions in `unlocked_actions` can be used regardless of the job level
+fn test_unlocked_actions_override() {
+    let settings = Settings {
+        max_cp: 150,
+        max_durability: 60,
+        max_progress: 1000,
+        max_quality: 1000,
+        base_progress: 100,
+        base_quality: 100,
+        job_level: 50,
+        allowed_actions: ActionMask::all(),
+        adversarial: false,
+        backload_progress: false,
+        traits: TraitOverrides {
+            unlocked_actions: ActionMask::none().add(Action::ImmaculateMend),
+            ..TraitOverrides::none()
+        },
+    };
+    let state = SimulationState::new(&settings)
+        .use_action(Action::ImmaculateMend, Condition::Normal, &settings)
+        .unwrap();
+    assert_eq!(state.cp, 38);
+}
+
+#[test]
+/// Progress traits can be toggled regardless of the job level
+fn test_enhanced_actions_override() {
+    let settings = Settings {
+        max_cp: 150,
+        max_durability: 60,
+        max_progress: 1000,
+        max_quality: 1000,
+        base_progress: 100,
+        base_quality: 100,
+        job_level: 90,
+        allowed_actions: ActionMask::all(),
+        adversarial: false,
+        backload_progress: false,
+        traits: TraitOverrides::none(),
+    };
+    let states = simulate_normal(&settings, [Action::DelicateSynthesis].into_iter());
+    assert_eq!(states[0].progress, 100);
+
+    let settings = Settings {
+        traits: TraitOverrides {
+            enhanced_actions: ActionMask::none().add(Action::DelicateSynthesis),
+            ..TraitOverrides::none()
+        },
+        ..settings
+    };
+    let states = simulate_normal(&settings, [Action::DelicateSynthesis].into_iter());
+    assert_eq!(states[0].progress, 150);
+
+    let settings = Settings {
+        job_level: 100,
+        traits: TraitOverrides {
+            unenhanced_actions: ActionMask::none().add(Action::CarefulSynthesis),
+            ..TraitOverrides::none()
+        },
+        ..settings
+    };
+    let states = simulate_normal(&settings, [Action::CarefulSynthesis].into_iter());
+    assert_eq!(states[0].progress, 150);
+}
+
 #[test]
 fn test_random_926ae85b() {
     // Copper Gorget
@@ -58,6 +125,7 @@ fn test_random_926ae85b() {
         allowed_actions: ActionMask::all(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
     let actions = [
         Action::BasicSynthesis,
@@ -87,6 +155,7 @@ fn test_random_3c721e47() {
         allowed_actions: ActionMask::all(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
     let actions = [
         Action::MuscleMemory,
@@ -120,6 +189,7 @@ fn test_random_3ba90d3a() {
         allowed_actions: ActionMask::all(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
     let actions = [
         Action::Veneration,
@@ -156,6 +226,7 @@ fn test_random_bce2650c() {
         allowed_actions: ActionMask::all(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
     let actions = [
         Action::MuscleMemory,
@@ -207,6 +278,7 @@ fn test_ingame_be9fc5c2() {
         allowed_actions: ActionMask::all(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
     let states = simulate(
         &settings,
@@ -279,6 +351,7 @@ fn test_ingame_d11d9c68() {
         allowed_actions: ActionMask::all(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
     let actions = [
         Action::Reflect,
@@ -335,6 +408,7 @@ fn test_ingame_f9f0dac7() {
         allowed_actions: ActionMask::all(),
         adversarial: true,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
     let actions = [
         Action::Reflect,
@@ -422,6 +496,7 @@ fn test_ingame_4866545e() {
         allowed_actions: ActionMask::all(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
     let actions = [
         Action::Reflect,
@@ -472,3 +547,227 @@ fn test_ingame_4866545e() {
         ]
     );
 }
+
+#[test]
+/// The given conditions only apply to the first steps, all steps after them are Normal
+fn test_from_macro_in_conditions() {
+    let settings = Settings {
+        max_cp: 200,
+        max_durability: 60,
+        max_progress: 1000,
+        max_quality: 1000,
+        base_progress: 100,
+        base_quality: 100,
+        job_level: 100,
+        allowed_actions: ActionMask::all(),
+        adversarial: false,
+        backload_progress: false,
+        traits: TraitOverrides::none(),
+    };
+    let actions = [
+        Action::TricksOfTheTrade,
+        Action::BasicTouch,
+        Action::BasicTouch,
+    ];
+    let (state, errors) = SimulationState::from_macro_in_conditions(
+        &settings,
+        &actions,
+        &[Condition::Good, Condition::Good],
+    );
+    assert!(errors.iter().all(Result::is_ok));
+    let expected = simulate(
+        &settings,
+        actions
+            .into_iter()
+            .zip([Condition::Good, Condition::Good, Condition::Normal]),
+    );
+    assert_eq!(state, *expected.last().unwrap());
+    // Tricks of the Trade can't be used in Normal condition
+    let (_, errors) = SimulationState::from_macro_in_conditions(&settings, &actions, &[]);
+    assert!(errors[0].is_err());
+}
+
+#[test]
+/// An action that fails doesn't use up the step, so the condition of the step carries over to the next action
+fn test_from_macro_in_conditions_failed_action() {
+    let settings = Settings {
+        max_cp: 200,
+        max_durability: 60,
+        max_progress: 1000,
+        max_quality: 1000,
+        base_progress: 100,
+        base_quality: 100,
+        job_level: 100,
+        allowed_actions: ActionMask::all(),
+        adversarial: false,
+        backload_progress: false,
+        traits: TraitOverrides::none(),
+    };
+    let actions = [
+        Action::ByregotsBlessing,
+        Action::TricksOfTheTrade,
+        Action::BasicTouch,
+    ];
+    let (state, errors) =
+        SimulationState::from_macro_in_conditions(&settings, &actions, &[Condition::Good]);
+    // Byregot's Blessing can't be used without Inner Quiet
+    assert!(errors[0].is_err());
+    assert!(errors[1..].iter().all(Result::is_ok));
+    let expected = simulate(
+        &settings,
+        [
+            (Action::TricksOfTheTrade, Condition::Good),
+            (Action::BasicTouch, Condition::Normal),
+        ]
+        .into_iter(),
+    );
+    assert_eq!(state, *expected.last().unwrap());
+}
+
+#[test]
+/// Continuing from a state with missing CP and Durability keeps the max CP and Durability of the settings
+fn test_continue_macro_in_conditions() {
+    let settings = Settings {
+        max_cp: 200,
+        max_durability: 60,
+        max_progress: 1000,
+        max_quality: 1000,
+        base_progress: 100,
+        base_quality: 100,
+        job_level: 100,
+        allowed_actions: ActionMask::all(),
+        adversarial: false,
+        backload_progress: false,
+        traits: TraitOverrides::none(),
+    };
+    let mut initial_state = SimulationState::new(&settings);
+    initial_state.cp = 100;
+    initial_state.durability = 20;
+    let (state, errors) = initial_state.continue_macro_in_conditions(
+        &settings,
+        &[Action::TricksOfTheTrade, Action::MasterMend],
+        &[Condition::Good],
+    );
+    assert!(errors.iter().all(Result::is_ok));
+    assert_eq!(state.cp, 100 + 20 - 88);
+    assert_eq!(state.durability, 50);
+    // not enough CP for Master's Mend without Tricks of the Trade
+    let mut initial_state = SimulationState::new(&settings);
+    initial_state.cp = 50;
+    let (_, errors) =
+        initial_state.continue_macro_in_conditions(&settings, &[Action::MasterMend], &[]);
+    assert!(errors[0].is_err());
+}
+
+#[test]
+/// The increase factors multiply to the increases of the simulator
+fn test_increase_factors() {
+    let settings = Settings {
+        max_cp: 500,
+        max_durability: 80,
+        max_progress: 10000,
+        max_quality: 20000,
+        base_progress: 237,
+        base_quality: 261,
+        job_level: 100,
+        allowed_actions: ActionMask::all(),
+        adversarial: false,
+        backload_progress: false,
+        traits: TraitOverrides::none(),
+    };
+    let state = SimulationState::from_macro(
+        &settings,
+        &[
+            Action::Reflect,
+            Action::Veneration,
+            Action::Innovation,
+            Action::GreatStrides,
+        ],
+    )
+    .unwrap();
+    for action in [
+        Action::Groundwork,
+        Action::PreparatoryTouch,
+        Action::ByregotsBlessing,
+    ] {
+        let factors = state.increase_factors(action, Condition::Good, &settings);
+        let next_state = state
+            .use_action(action, Condition::Good, &settings)
+            .unwrap();
+        assert_eq!(
+            factors.progress_increase,
+            next_state.progress - state.progress
+        );
+        assert_eq!(factors.quality_increase, next_state.quality - state.quality);
+        assert_eq!(
+            factors.progress_increase,
+            u32::from(settings.base_progress)
+                * factors.progress_potency
+                * factors.progress_effect_modifier
+                * factors.progress_condition_modifier
+                / 20000
+        );
+        assert_eq!(
+            factors.quality_increase,
+            u32::from(settings.base_quality)
+                * factors.quality_potency
+                * factors.quality_effect_modifier
+                * factors.quality_condition_modifier
+                / 20000
+        );
+    }
+}
+
+#[test]
+/// The conditions of expert recipes change the costs, the Progress and the duration of effects
+fn test_expert_conditions() {
+    let settings = Settings {
+        max_cp: 500,
+        max_durability: 80,
+        max_progress: 10000,
+        max_quality: 20000,
+        base_progress: 100,
+        base_quality: 100,
+        job_level: 100,
+        allowed_actions: ActionMask::all(),
+        adversarial: false,
+        backload_progress: false,
+        traits: TraitOverrides::none(),
+    };
+    let initial_state = SimulationState::new(&settings);
+    let use_action = |state: SimulationState, action, condition| {
+        state.use_action(action, condition, &settings).unwrap()
+    };
+    let normal = use_action(initial_state, Action::BasicSynthesis, Condition::Normal);
+    // Centered only raises success rates, which the modeled actions don't have
+    assert_eq!(
+        use_action(initial_state, Action::BasicSynthesis, Condition::Centered),
+        normal
+    );
+
+    let sturdy = use_action(initial_state, Action::BasicSynthesis, Condition::Sturdy);
+    assert_eq!(80 - sturdy.durability, (80 - normal.durability) / 2);
+    // Waste Not and Sturdy together take a quarter of the cost, rounded up
+    let waste_not = use_action(initial_state, Action::WasteNot, Condition::Normal);
+    let state = use_action(waste_not, Action::BasicSynthesis, Condition::Sturdy);
+    assert_eq!(waste_not.durability - state.durability, 3);
+
+    let pliant = use_action(initial_state, Action::Innovation, Condition::Pliant);
+    let state = use_action(initial_state, Action::Innovation, Condition::Normal);
+    assert_eq!(500 - pliant.cp, (500 - state.cp).div_ceil(2));
+
+    let malleable = use_action(initial_state, Action::BasicSynthesis, Condition::Malleable);
+    assert_eq!(malleable.progress, normal.progress * 3 / 2);
+
+    let primed = use_action(initial_state, Action::Innovation, Condition::Primed);
+    assert_eq!(primed.effects.innovation(), state.effects.innovation() + 2);
+    let primed = use_action(initial_state, Action::Manipulation, Condition::Primed);
+    assert_eq!(primed.effects.manipulation(), 10);
+    let primed = use_action(initial_state, Action::GreatStrides, Condition::Primed);
+    assert_eq!(primed.effects.great_strides(), 5);
+    // the extended Great Strides ticks down like the other effects and is still active on the 5th step
+    let state = (0..4).fold(primed, |state, _| {
+        use_action(state, Action::BasicSynthesis, Condition::Normal)
+    });
+    assert_eq!(state.effects.great_strides(), 1);
+}
@@ -0,0 +1,71 @@
+use std::hash::{BuildHasher, BuildHasherDefault, RandomState};
+
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use raphael_sim::Effects;
+use raphael_solver::bench_utils::WordHasher;
+use rustc_hash::FxHasher;
+
+/// Stand-in for the 8-byte `ReducedState` keys of the bound solvers, hashed as a single word in the same way.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Key(u64);
+
+impl std::hash::Hash for Key {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_u64(self.0);
+    }
+}
+
+/// Keys made up of the CP, compressed unreliable Quality and effects that occur in the bound solvers.
+fn generate_keys(count: usize) -> Vec<Key> {
+    let mut rng = StdRng::seed_from_u64(0);
+    (0..count)
+        .map(|_| {
+            let effects = Effects::new()
+                .with_inner_quiet(rng.random_range(0..=10))
+                .with_innovation(rng.random_range(0..=4))
+                .with_veneration(rng.random_range(0..=4))
+                .with_manipulation(rng.random_range(0..=8))
+                .with_waste_not(rng.random_range(0..=8))
+                .with_great_strides(rng.random_range(0..=3))
+                .with_allow_quality_actions(true);
+            let cp: u16 = rng.random_range(0..=800);
+            let compressed_unreliable_quality: u8 = rng.random_range(0..=3);
+            Key(u64::from(cp)
+                | (u64::from(compressed_unreliable_quality) << 16)
+                | (u64::from(effects.into_bits()) << 32))
+        })
+        .collect()
+}
+
+fn bench_hasher<S: BuildHasher + Default>(c: &mut Criterion, name: &str, keys: &[Key]) {
+    let mut group = c.benchmark_group("hashers");
+    group.sample_size(10);
+    group.bench_function(BenchmarkId::new("insert", name), |b| {
+        b.iter(|| {
+            let mut set = std::collections::HashSet::with_hasher(S::default());
+            set.extend(keys.iter().copied());
+            set
+        });
+    });
+    let set: std::collections::HashSet<Key, S> = keys.iter().copied().collect();
+    group.bench_function(BenchmarkId::new("contains", name), |b| {
+        b.iter(|| {
+            keys.iter()
+                .filter(|key| set.contains(black_box(*key)))
+                .count()
+        });
+    });
+    group.finish();
+}
+
+/// Compares the hashers for the `ReducedState` keys of the bound solvers.
+fn bench_hashers(c: &mut Criterion) {
+    let keys = generate_keys(1 << 20);
+    bench_hasher::<RandomState>(c, "SipHash", &keys);
+    bench_hasher::<BuildHasherDefault<FxHasher>>(c, "FxHasher", &keys);
+    bench_hasher::<BuildHasherDefault<WordHasher>>(c, "WordHasher", &keys);
+}
+
+criterion_group!(benches, bench_hashers);
+criterion_main!(benches);
@@ -0,0 +1,120 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use raphael_sim::Effects;
+use raphael_solver::bench_utils::SolvedStates;
+use rustc_hash::FxHashMap;
+
+/// Stand-in for the 8-byte `ReducedState` keys of the bound solvers, hashed as a single word in the same way.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Key(u64);
+
+impl std::hash::Hash for Key {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_u64(self.0);
+    }
+}
+
+/// Stand-in for the `ParetoValue<u32, u32>` values of the bound solvers.
+#[derive(Clone, Copy)]
+struct Value {
+    _first: u32,
+    _second: u32,
+}
+
+/// Distinct keys made up of the CP, compressed unreliable Quality and effects that occur in the bound solvers.
+fn generate_keys(count: usize) -> Vec<Key> {
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut keys = rustc_hash::FxHashSet::default();
+    while keys.len() < count {
+        let effects = Effects::new()
+            .with_inner_quiet(rng.random_range(0..=10))
+            .with_innovation(rng.random_range(0..=4))
+            .with_veneration(rng.random_range(0..=4))
+            .with_manipulation(rng.random_range(0..=8))
+            .with_waste_not(rng.random_range(0..=8))
+            .with_great_strides(rng.random_range(0..=3))
+            .with_allow_quality_actions(true);
+        let cp: u16 = rng.random_range(0..=800);
+        let compressed_unreliable_quality: u8 = rng.random_range(0..=3);
+        keys.insert(
+            u64::from(cp)
+                | (u64::from(compressed_unreliable_quality) << 16)
+                | (u64::from(effects.into_bits()) << 32),
+        );
+    }
+    let mut keys: Vec<Key> = keys.into_iter().map(Key).collect();
+    keys.sort_unstable_by_key(|key| key.0);
+    // shuffle to not favour either layout with an insertion order that matches the memory order
+    for i in (1..keys.len()).rev() {
+        keys.swap(i, rng.random_range(0..=i));
+    }
+    keys
+}
+
+fn generate_fronts(count: usize) -> Vec<Vec<Value>> {
+    let mut rng = StdRng::seed_from_u64(1);
+    (0..count)
+        .map(|_| {
+            (0..rng.random_range(1..=8))
+                .map(|_| Value {
+                    _first: rng.random(),
+                    _second: rng.random(),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Compares `SolvedStates` with a map of boxed fronts, which is how the bound solvers used to store their fronts.
+fn bench_solved_states(c: &mut Criterion) {
+    const NUM_STATES: usize = 1 << 20;
+    let keys = generate_keys(NUM_STATES);
+    let fronts = generate_fronts(NUM_STATES);
+
+    let mut group = c.benchmark_group("solved_states");
+    group.sample_size(10);
+
+    group.bench_function(BenchmarkId::new("insert", "SolvedStates"), |b| {
+        b.iter(|| {
+            let mut solved_states = SolvedStates::default();
+            for (key, front) in keys.iter().zip(fronts.iter()) {
+                solved_states.insert(*key, front);
+            }
+            solved_states
+        });
+    });
+    group.bench_function(BenchmarkId::new("insert", "FxHashMap<_, Box<[_]>>"), |b| {
+        b.iter(|| {
+            let mut solved_states = FxHashMap::default();
+            for (key, front) in keys.iter().zip(fronts.iter()) {
+                solved_states.insert(*key, Box::<[Value]>::from(front.as_slice()));
+            }
+            solved_states
+        });
+    });
+
+    let mut solved_states = SolvedStates::default();
+    let mut boxed_fronts = FxHashMap::default();
+    for (key, front) in keys.iter().zip(fronts.iter()) {
+        solved_states.insert(*key, front);
+        boxed_fronts.insert(*key, Box::<[Value]>::from(front.as_slice()));
+    }
+    group.bench_function(BenchmarkId::new("get", "SolvedStates"), |b| {
+        b.iter(|| {
+            keys.iter()
+                .map(|key| solved_states.get(black_box(key)).unwrap().len())
+                .sum::<usize>()
+        });
+    });
+    group.bench_function(BenchmarkId::new("get", "FxHashMap<_, Box<[_]>>"), |b| {
+        b.iter(|| {
+            keys.iter()
+                .map(|key| boxed_fronts.get(black_box(key)).unwrap().len())
+                .sum::<usize>()
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_solved_states);
+criterion_main!(benches);
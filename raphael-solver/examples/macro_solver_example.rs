@@ -1,4 +1,4 @@
-use raphael_sim::{ActionMask, Settings, SimulationState};
+use raphael_sim::{ActionMask, Settings, SimulationState, TraitOverrides};
 use raphael_solver::{AtomicFlag, MacroSolver, SolverSettings};
 
 fn main() {
@@ -18,9 +18,10 @@ fn main() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
 
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
 
     let mut solver = MacroSolver::new(
         solver_settings,
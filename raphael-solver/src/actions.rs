@@ -60,6 +60,7 @@ impl ActionCombo {
                 Action::QuickInnovation => &[Action::QuickInnovation],
                 Action::ImmaculateMend => &[Action::ImmaculateMend],
                 Action::TrainedPerfection => &[Action::TrainedPerfection],
+                Action::CarefulObservation => &[Action::CarefulObservation],
             },
         }
     }
@@ -131,13 +132,34 @@ pub const PROGRESS_ONLY_SEARCH_ACTIONS: [ActionCombo; 14] = [
     ActionCombo::Single(Action::TrainedPerfection),
 ];
 
+/// Actions that can only be used without Heart and Soul when the condition is Good or Excellent
+pub const CONDITION_SEARCH_ACTIONS: [ActionCombo; 3] = [
+    ActionCombo::Single(Action::TricksOfTheTrade),
+    ActionCombo::Single(Action::IntensiveSynthesis),
+    ActionCombo::Single(Action::PreciseTouch),
+];
+
 pub fn use_action_combo(
+    settings: &SolverSettings,
+    state: SimulationState,
+    action_combo: ActionCombo,
+) -> Result<SimulationState, &'static str> {
+    use_action_combo_in_conditions(settings, state, action_combo, &[])
+}
+
+/// Same as [`use_action_combo`], except that the first steps of the combo have the given conditions instead of Normal.
+pub fn use_action_combo_in_conditions(
     settings: &SolverSettings,
     mut state: SimulationState,
     action_combo: ActionCombo,
+    first_conditions: &[Condition],
 ) -> Result<SimulationState, &'static str> {
-    for action in action_combo.actions() {
-        state = state.use_action(*action, Condition::Normal, &settings.simulator_settings)?;
+    for (action, condition) in action_combo
+        .actions()
+        .iter()
+        .zip(Condition::sequence(first_conditions))
+    {
+        state = state.use_action(*action, condition, &settings.simulator_settings)?;
     }
     state.effects.set_combo(Combo::None);
     Ok(state)
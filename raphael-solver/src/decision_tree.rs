@@ -0,0 +1,199 @@
+use raphael_sim::{Action, Condition, SimulationState};
+
+use crate::{SolverException, SolverSettings};
+
+/// Actions to use depending on the condition of each step, for crafts in which a single macro can't react to the conditions.
+/// The first step of a craft is always Normal. The conditions of the steps after it are branched on up to a depth,
+/// and the steps after those are assumed to be Normal, since conditions further ahead are not modeled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecisionTree {
+    pub first_step: FirstStepBranch,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FirstStepBranch {
+    pub condition: Condition,
+    pub action: Action,
+    /// Branches of the second step, the default branch comes last
+    pub next_steps: Vec<StepBranch>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepBranch {
+    /// Conditions of the step in which this branch is taken
+    pub conditions: Vec<Condition>,
+    /// Whether the branch is also taken in the conditions of the step that aren't covered by any other branch
+    pub is_default: bool,
+    /// The rest of the rotation, starting with the action of this step
+    pub actions: Vec<Action>,
+    /// Quality reached by the rotation from the initial state, with the steps after this one in their default conditions
+    pub quality: u32,
+    /// Branches of the next step, the default branch comes last.
+    /// Empty if the condition of the next step isn't branched on or the craft is finished after this step.
+    pub next_steps: Vec<Self>,
+}
+
+impl StepBranch {
+    /// Whether both branches use the same actions in the same conditions, regardless of the Quality they reach
+    fn same_decisions(&self, other: &Self) -> bool {
+        self.actions == other.actions
+            && self.next_steps.len() == other.next_steps.len()
+            && std::iter::zip(&self.next_steps, &other.next_steps).all(|(branch, other)| {
+                branch.conditions == other.conditions
+                    && branch.is_default == other.is_default
+                    && branch.same_decisions(other)
+            })
+    }
+}
+
+/// Conditions among `conditions` that the step after a step with `previous` can have, starting with the one assumed when the next condition is unknown
+fn conditions_after(previous: Condition, conditions: &[Condition]) -> Vec<Condition> {
+    conditions
+        .iter()
+        .copied()
+        .filter(|condition| condition.can_follow(previous))
+        .collect()
+}
+
+/// A step whose condition is known, solved from the state before it
+struct Node {
+    state: SimulationState,
+    condition: Condition,
+    /// Condition assumed for the step after this one
+    next_condition: Condition,
+    /// The rest of the rotation, starting with the action of this step
+    actions: Vec<Action>,
+    /// Nodes of the next step, starting with the one in the default condition
+    children: Vec<usize>,
+}
+
+impl DecisionTree {
+    /// Solves the steps after the first one once per condition they can have among `conditions`, for `depth` steps after the first one,
+    /// using `solve` to solve from the state before the step, in the opener conditions of the settings it is given.
+    /// The solves of a step are ordered by their conditions, so that `solve` can reuse the precomputed tables of consecutive solves with the same settings.
+    /// Fails with [`SolverException::NoSolution`] if a step can't have any of `conditions`, or if `solve` returns an empty rotation or one that can't be used.
+    /// Branches that end up with the same actions as the default branch of their step are folded into it.
+    pub fn build(
+        solver_settings: SolverSettings,
+        initial_state: SimulationState,
+        conditions: &[Condition],
+        depth: usize,
+        mut solve: impl FnMut(SolverSettings, SimulationState) -> Result<Vec<Action>, SolverException>,
+    ) -> Result<Self, SolverException> {
+        let simulator_settings = &solver_settings.simulator_settings;
+        let next_condition = |condition: Condition| {
+            conditions_after(condition, conditions)
+                .first()
+                .copied()
+                .ok_or(SolverException::NoSolution)
+        };
+        let mut solve_node = |node: &mut Node| {
+            node.actions = solve(
+                SolverSettings {
+                    opener_conditions: [node.condition, node.next_condition],
+                    ..solver_settings
+                },
+                node.state,
+            )?;
+            if node.actions.is_empty() {
+                return Err(SolverException::NoSolution);
+            }
+            Ok(())
+        };
+        // the first step of a craft is always Normal
+        let mut root = Node {
+            state: initial_state,
+            condition: Condition::Normal,
+            next_condition: next_condition(Condition::Normal)?,
+            actions: Vec::new(),
+            children: Vec::new(),
+        };
+        solve_node(&mut root)?;
+        let mut nodes = vec![root];
+        let mut step_nodes = 0..1;
+        for _ in 0..depth {
+            let mut next_nodes = Vec::new();
+            for index in step_nodes.clone() {
+                let node = &nodes[index];
+                let state = node
+                    .state
+                    .use_action(node.actions[0], node.condition, simulator_settings)
+                    .map_err(|_| SolverException::NoSolution)?;
+                if state.is_final(simulator_settings) {
+                    continue;
+                }
+                let children_start = nodes.len() + next_nodes.len();
+                for condition in conditions_after(node.condition, conditions) {
+                    // the rotation of the node was solved assuming the default condition for this step
+                    let actions = if condition == node.next_condition {
+                        node.actions[1..].to_vec()
+                    } else {
+                        Vec::new()
+                    };
+                    next_nodes.push(Node {
+                        state,
+                        condition,
+                        next_condition: next_condition(condition)?,
+                        actions,
+                        children: Vec::new(),
+                    });
+                }
+                nodes[index].children = (children_start..nodes.len() + next_nodes.len()).collect();
+            }
+            let mut unsolved: Vec<&mut Node> = next_nodes
+                .iter_mut()
+                .filter(|node| node.actions.is_empty())
+                .collect();
+            unsolved.sort_by_key(|node| (node.condition as u8, node.next_condition as u8));
+            for node in unsolved {
+                solve_node(node)?;
+            }
+            step_nodes = nodes.len()..nodes.len() + next_nodes.len();
+            nodes.extend(next_nodes);
+        }
+        Ok(Self {
+            first_step: FirstStepBranch {
+                condition: nodes[0].condition,
+                action: nodes[0].actions[0],
+                next_steps: Self::branches(&nodes, &nodes[0].children, simulator_settings),
+            },
+        })
+    }
+
+    /// Branches of the step of `children`, with the branches that make the same decisions as the default branch folded into it
+    fn branches(
+        nodes: &[Node],
+        children: &[usize],
+        simulator_settings: &raphael_sim::Settings,
+    ) -> Vec<StepBranch> {
+        let mut branches = children.iter().map(|&index| {
+            let node = &nodes[index];
+            let (final_state, _) = node.state.continue_macro_in_conditions(
+                simulator_settings,
+                &node.actions,
+                &[node.condition, node.next_condition],
+            );
+            StepBranch {
+                conditions: vec![node.condition],
+                is_default: false,
+                actions: node.actions.clone(),
+                quality: final_state.quality,
+                next_steps: Self::branches(nodes, &node.children, simulator_settings),
+            }
+        });
+        let Some(mut default_branch) = branches.next() else {
+            return Vec::new();
+        };
+        default_branch.is_default = true;
+        let mut other_branches = Vec::new();
+        for branch in branches {
+            if branch.same_decisions(&default_branch) {
+                default_branch.conditions.extend(branch.conditions);
+            } else {
+                other_branches.push(branch);
+            }
+        }
+        other_branches.push(default_branch);
+        other_branches
+    }
+}
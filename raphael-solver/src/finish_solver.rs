@@ -63,6 +63,11 @@ impl FinishSolver {
                     if let Ok(new_state) =
                         use_action_combo(&self.settings, state.to_state(), action)
                     {
+                        if new_state.progress != 0
+                            && state.durability < self.settings.min_synthesis_durability
+                        {
+                            continue;
+                        }
                         if new_state.is_final(&self.settings.simulator_settings) {
                             max_progress = std::cmp::max(max_progress, new_state.progress);
                         } else {
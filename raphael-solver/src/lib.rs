@@ -1,3 +1,5 @@
+use raphael_sim::{Action, Condition};
+
 mod actions;
 
 mod finish_solver;
@@ -7,14 +9,25 @@ mod quality_upper_bound_solver;
 use quality_upper_bound_solver::QualityUbSolver;
 
 mod step_lower_bound_solver;
+pub use step_lower_bound_solver::PrecomputeProgress;
 use step_lower_bound_solver::StepLbSolver;
 
 mod macro_solver;
-pub use macro_solver::MacroSolver;
+pub use macro_solver::{MacroSolver, MacroSolverTimings, SolutionMetrics, SolverProgress};
+
+mod decision_tree;
+pub use decision_tree::{DecisionTree, FirstStepBranch, StepBranch};
 
 mod utils;
 pub use utils::AtomicFlag;
 
+/// Internals used by the benchmarks in `benches/`, not part of the public API.
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub mod bench_utils {
+    pub use crate::utils::{SolvedStates, WordHasher};
+}
+
 #[cfg(test)]
 pub mod test_utils;
 
@@ -23,17 +36,192 @@ pub mod test_utils;
 pub enum SolverException {
     NoSolution,
     Interrupted,
-    InternalError(String),
+    /// A precomputed table was queried for a state it doesn't contain. `state` is the debug representation of the queried state.
+    UnknownStateQueried {
+        state: String,
+    },
+    /// A value outgrew the range of the precomputed tables, e.g. a step lower bound above 255 steps.
+    TableOverflow,
+    /// The solver panicked. The panic is caught so that it can be reported instead of taking down the caller.
+    PanicCaught {
+        message: String,
+    },
+    /// The search exceeded the time limit set with [`MacroSolver::set_time_limit`].
+    Timeout,
     #[cfg(target_arch = "wasm32")]
     AllocError,
 }
 
-#[derive(Clone, Copy, Debug)]
+/// Opener conditions that no recipe can roll in a row, see [`SolverSettings::with_opener_conditions`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OpenerConditionsError {
+    /// The condition of the step can't follow the condition of the step before it,
+    /// e.g. an Excellent second step, since the steps after the opener are Normal.
+    ImpossibleSequence { step: usize },
+    /// The recipe never rolls the condition, e.g. Pliant in a regular recipe or Excellent in an expert recipe.
+    UnavailableCondition(Condition),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SolverSettings {
     pub simulator_settings: raphael_sim::Settings,
+    /// Among the rotations that reach the highest Quality, prefer the ones that backload Progress.
+    /// Unlike `simulator_settings.backload_progress`, this never gives up any Quality for backloading.
+    pub prefer_backload_progress: bool,
+    /// Constraint on the first action when solving from the start of the craft.
+    pub opener: OpenerPreference,
+    /// Progress-increasing actions are only used while Durability is at least this high, leaving some slack for mistakes.
+    /// Zero means no restriction.
+    pub min_synthesis_durability: u16,
+    /// Caps on how many times each action may be used.
+    pub action_limits: ActionLimits,
+    /// Assumed condition of the first two steps when solving from the start of the craft, all later steps are Normal.
+    pub opener_conditions: [Condition; 2],
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OpenerPreference {
+    #[default]
+    Any,
+    /// The rotation must start with this action.
+    Force(Action),
+    /// The rotation must not start with this action.
+    Forbid(Action),
+}
+
+impl OpenerPreference {
+    pub fn allows(self, first_action: Action) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Force(action) => first_action == action,
+            Self::Forbid(action) => first_action != action,
+        }
+    }
+}
+
+/// Per-action caps on how many times an action may be used in a rotation.
+///
+/// Only the actions chosen by the solver are counted, i.e. the actions leading up to a mid-craft state passed to [`MacroSolver::solve_from`] are not.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ActionLimits {
+    limits: [u8; 64],
+}
+
+impl ActionLimits {
+    const UNLIMITED: u8 = u8::MAX;
+
+    pub const fn unlimited() -> Self {
+        Self {
+            limits: [Self::UNLIMITED; 64],
+        }
+    }
+
+    #[must_use]
+    pub const fn with_limit(mut self, action: Action, limit: u8) -> Self {
+        self.limits[action as usize] = limit;
+        self
+    }
+
+    pub const fn limit(self, action: Action) -> Option<u8> {
+        self.limit_at(action as usize)
+    }
+
+    /// Limit of the action with index `Action as usize`
+    pub(crate) const fn limit_at(self, index: usize) -> Option<u8> {
+        match self.limits[index] {
+            Self::UNLIMITED => None,
+            limit => Some(limit),
+        }
+    }
+
+    pub fn is_unlimited(self) -> bool {
+        self.limits.iter().all(|&limit| limit == Self::UNLIMITED)
+    }
+}
+
+impl Default for ActionLimits {
+    fn default() -> Self {
+        Self::unlimited()
+    }
+}
+
+// serde doesn't implement its traits for arrays longer than 32 elements
+#[cfg(feature = "serde")]
+impl serde::Serialize for ActionLimits {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.limits.as_slice().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ActionLimits {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let limits = Vec::<u8>::deserialize(deserializer)?;
+        let limits = limits.try_into().map_err(|limits: Vec<u8>| {
+            serde::de::Error::invalid_length(limits.len(), &"64 limits")
+        })?;
+        Ok(Self { limits })
+    }
+}
+
+impl std::fmt::Debug for ActionLimits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let limited = self
+            .limits
+            .iter()
+            .enumerate()
+            .filter(|&(_, &limit)| limit != Self::UNLIMITED);
+        f.debug_map().entries(limited).finish()
+    }
 }
 
 impl SolverSettings {
+    /// Settings without any of the optional constraints and preferences, i.e. the solver only maximizes Quality.
+    pub const fn new(simulator_settings: raphael_sim::Settings) -> Self {
+        Self {
+            simulator_settings,
+            prefer_backload_progress: false,
+            opener: OpenerPreference::Any,
+            min_synthesis_durability: 0,
+            action_limits: ActionLimits::unlimited(),
+            opener_conditions: [Condition::Normal; 2],
+        }
+    }
+
+    /// Same settings with the given opener conditions, if a recipe can roll them in a row.
+    /// The conditions are checked together with the Normal steps that follow them, see [`Condition::sequence`].
+    pub fn with_opener_conditions(
+        self,
+        opener_conditions: [Condition; 2],
+        expert_recipe: bool,
+    ) -> Result<Self, OpenerConditionsError> {
+        let following_conditions = Condition::sequence(&opener_conditions).skip(1);
+        for (step, (previous, condition)) in Condition::sequence(&opener_conditions)
+            .zip(following_conditions)
+            .take(opener_conditions.len())
+            .enumerate()
+        {
+            if !condition.can_follow(previous) {
+                return Err(OpenerConditionsError::ImpossibleSequence { step: step + 1 });
+            }
+        }
+        for condition in opener_conditions {
+            let available = match expert_recipe {
+                true => !matches!(condition, Condition::Excellent | Condition::Poor),
+                false => !condition.is_expert_only(),
+            };
+            if !available {
+                return Err(OpenerConditionsError::UnavailableCondition(condition));
+            }
+        }
+        Ok(Self {
+            opener_conditions,
+            ..self
+        })
+    }
+
     pub fn max_durability(&self) -> u16 {
         self.simulator_settings.max_durability
     }
@@ -2,4 +2,4 @@ mod pareto_front;
 mod search_queue;
 mod solver;
 
-pub use solver::MacroSolver;
+pub use solver::{MacroSolver, MacroSolverTimings, SolutionMetrics, SolverProgress};
@@ -20,15 +20,18 @@ struct Key {
     cp: u16,
     durability: u16,
     effects: u32,
+    /// Packed use counts of the limited actions, a node that has fewer uses left mustn't prune one that has more
+    action_uses: u64,
 }
 
-impl From<&SimulationState> for Key {
-    fn from(state: &SimulationState) -> Self {
+impl Key {
+    fn new(state: &SimulationState, action_uses: u64) -> Self {
         Self {
             progress: state.progress,
             cp: state.cp.next_multiple_of(64),
             durability: state.durability.next_multiple_of(15),
             effects: state.effects.into_bits() & EFFECTS_KEY_MASK,
+            action_uses,
         }
     }
 }
@@ -77,8 +80,12 @@ pub struct ParetoFront {
 }
 
 impl ParetoFront {
-    pub fn insert(&mut self, state: SimulationState) -> bool {
-        let bucket = self.buckets.entry(Key::from(&state)).or_default();
+    /// Inserts the state unless it is dominated by a state of the front that has the same `action_uses`.
+    pub fn insert(&mut self, state: SimulationState, action_uses: u64) -> bool {
+        let bucket = self
+            .buckets
+            .entry(Key::new(&state, action_uses))
+            .or_default();
         let new_value = Value::from(&state);
         let is_dominated = bucket.iter().any(|value| value.dominates(&new_value));
         if !is_dominated {
@@ -2,13 +2,15 @@ use std::collections::BTreeMap;
 
 use raphael_sim::{Action, SimulationState};
 
-use crate::{actions::ActionCombo, utils::Backtracking};
+use crate::{ActionLimits, actions::ActionCombo, utils::Backtracking};
 
 use super::pareto_front::ParetoFront;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SearchScore {
     pub quality_upper_bound: u32,
+    /// Whether Progress is backloaded so far. Only taken into account if backloaded Progress is preferred, otherwise always `true`.
+    pub backloaded: bool,
     pub steps_lower_bound: u8,
     pub duration_lower_bound: u8,
     pub current_steps: u8,
@@ -18,6 +20,7 @@ pub struct SearchScore {
 impl SearchScore {
     pub const MIN: Self = Self {
         quality_upper_bound: 0,
+        backloaded: false,
         steps_lower_bound: u8::MAX,
         duration_lower_bound: u8::MAX,
         current_steps: u8::MAX,
@@ -26,6 +29,7 @@ impl SearchScore {
 
     pub const MAX: Self = Self {
         quality_upper_bound: u32::MAX,
+        backloaded: true,
         steps_lower_bound: 0,
         duration_lower_bound: 0,
         current_steps: 0,
@@ -43,6 +47,7 @@ impl std::cmp::Ord for SearchScore {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         self.quality_upper_bound
             .cmp(&other.quality_upper_bound)
+            .then(self.backloaded.cmp(&other.backloaded))
             .then(other.steps_lower_bound.cmp(&self.steps_lower_bound))
             .then(other.duration_lower_bound.cmp(&self.duration_lower_bound))
             .then(other.current_steps.cmp(&self.current_steps))
@@ -55,6 +60,8 @@ struct SearchNode {
     state: SimulationState,
     action: ActionCombo,
     parent_id: usize,
+    /// Packed use counts of the limited actions on the path to this node, see [`ActionUsesPacking`]
+    action_uses: u64,
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -64,25 +71,104 @@ pub struct SearchQueueStats {
     pub pareto_buckets_squared_size_sum: usize,
 }
 
+/// How the use counts of the limited actions are packed into the key of the Pareto front
+enum ActionUsesPacking {
+    /// No action is limited
+    Unlimited,
+    /// Place value of each action, indexed by `Action as usize` and zero for the actions that aren't limited.
+    /// The use count of a limited action is a digit that ranges from 0 to its limit.
+    Packed {
+        place_values: Box<[u64; 64]>,
+        action_limits: ActionLimits,
+    },
+    /// The use counts don't fit into the key, so nodes aren't pruned by the Pareto front and the use counts are counted by backtracking
+    Unpruned,
+}
+
+impl ActionUsesPacking {
+    fn new(action_limits: ActionLimits) -> Self {
+        if action_limits.is_unlimited() {
+            return Self::Unlimited;
+        }
+        let mut place_values = Box::new([0; 64]);
+        let mut place_value: u64 = 1;
+        for (action, action_place_value) in place_values.iter_mut().enumerate() {
+            let Some(limit) = action_limits.limit_at(action) else {
+                continue;
+            };
+            *action_place_value = place_value;
+            match place_value.checked_mul(u64::from(limit) + 1) {
+                Some(next_place_value) => place_value = next_place_value,
+                None => {
+                    log::info!(
+                        "Action limits too large to pack the use counts, nodes aren't pruned by the Pareto front"
+                    );
+                    return Self::Unpruned;
+                }
+            }
+        }
+        Self::Packed {
+            place_values,
+            action_limits,
+        }
+    }
+
+    /// Packed use counts after using `action` in a node with the packed use counts `action_uses`
+    fn add(&self, action_uses: u64, action: ActionCombo) -> u64 {
+        match self {
+            Self::Packed { place_values, .. } => {
+                // the use counts never exceed the limits, so the digits don't carry over
+                let added_uses: u64 = action
+                    .actions()
+                    .iter()
+                    .map(|&action| place_values[action as usize])
+                    .sum();
+                action_uses + added_uses
+            }
+            Self::Unlimited | Self::Unpruned => 0,
+        }
+    }
+
+    /// Packed use counts of `node` as the key of the Pareto front, or `None` if the node isn't subject to Pareto pruning
+    fn pareto_key(&self, node: &SearchNode) -> Option<u64> {
+        match self {
+            Self::Unpruned => None,
+            Self::Unlimited | Self::Packed { .. } => Some(node.action_uses),
+        }
+    }
+}
+
 pub struct SearchQueue {
-    pareto_front: ParetoFront,
+    /// Separate fronts for nodes that are not backloaded and nodes that are,
+    /// because a node that is not backloaded mustn't prune a node that is
+    pareto_fronts: [ParetoFront; 2],
+    /// Nodes before this step still have an assumed non-Normal condition ahead of them, which a node at another step doesn't,
+    /// so they mustn't be pruned by nor prune a node that is otherwise the same
+    unpruned_steps: u8,
+    action_uses_packing: ActionUsesPacking,
     buckets: BTreeMap<SearchScore, Vec<SearchNode>>,
     backtracking: Backtracking<ActionCombo>,
     current_score: SearchScore,
-    current_nodes: Vec<(SimulationState, usize)>,
+    current_nodes: Vec<(SimulationState, usize, u64)>,
     minimum_score: SearchScore,
     processed_nodes: usize,
     dropped_nodes: usize,
 }
 
 impl SearchQueue {
-    pub fn new(initial_state: SimulationState) -> Self {
+    pub fn new(
+        initial_state: SimulationState,
+        action_limits: ActionLimits,
+        unpruned_steps: u8,
+    ) -> Self {
         Self {
-            pareto_front: ParetoFront::default(),
+            pareto_fronts: Default::default(),
+            unpruned_steps,
+            action_uses_packing: ActionUsesPacking::new(action_limits),
             backtracking: Backtracking::new(),
             buckets: BTreeMap::default(),
             current_score: SearchScore::MAX,
-            current_nodes: vec![(initial_state, Backtracking::<Action>::SENTINEL)],
+            current_nodes: vec![(initial_state, Backtracking::<Action>::SENTINEL, 0)],
             minimum_score: SearchScore::MIN,
             processed_nodes: 0,
             dropped_nodes: 0,
@@ -111,12 +197,14 @@ impl SearchQueue {
         );
     }
 
+    /// Queues the node reached with `action` from the node with `parent_id`, whose packed use counts `parent_action_uses` are returned by [`Self::pop`]
     pub fn push(
         &mut self,
         state: SimulationState,
         score: SearchScore,
         action: ActionCombo,
         parent_id: usize,
+        parent_action_uses: u64,
     ) {
         #[cfg(test)]
         assert!(self.current_score > score);
@@ -125,11 +213,13 @@ impl SearchQueue {
                 state,
                 action,
                 parent_id,
+                action_uses: self.action_uses_packing.add(parent_action_uses, action),
             });
         }
     }
 
-    pub fn pop(&mut self) -> Option<(SimulationState, SearchScore, usize)> {
+    /// Next node to expand, with its backtrack id and the packed use counts of the limited actions on the path to it
+    pub fn pop(&mut self) -> Option<(SimulationState, SearchScore, usize, u64)> {
         while self.current_nodes.is_empty() {
             if let Some((score, mut bucket)) = self.buckets.pop_last() {
                 // sort the bucket to prevent inserting a node to the pareto front that is later dominated by another node in the same bucket
@@ -137,12 +227,18 @@ impl SearchQueue {
                     pareto_weight(&rhs.state).cmp(&pareto_weight(&lhs.state))
                 });
                 self.current_score = score;
+                if score.current_steps >= self.unpruned_steps {
+                    bucket.retain(|node| match self.action_uses_packing.pareto_key(node) {
+                        Some(action_uses) => self.pareto_fronts[usize::from(score.backloaded)]
+                            .insert(node.state, action_uses),
+                        None => true,
+                    });
+                }
                 self.current_nodes = bucket
                     .into_iter()
-                    .filter(|node| self.pareto_front.insert(node.state))
                     .map(|node| {
                         let backtrack_id = self.backtracking.push(node.action, node.parent_id);
-                        (node.state, backtrack_id)
+                        (node.state, backtrack_id, node.action_uses)
                     })
                     .collect();
                 self.processed_nodes += self.current_nodes.len();
@@ -150,8 +246,24 @@ impl SearchQueue {
                 return None;
             }
         }
-        let (state, backtrack_id) = self.current_nodes.pop().unwrap();
-        Some((state, self.current_score, backtrack_id))
+        let (state, backtrack_id, action_uses) = self.current_nodes.pop().unwrap();
+        Some((state, self.current_score, backtrack_id, action_uses))
+    }
+
+    /// Number of times each limited action is used on the path to the node with `backtrack_id` and the packed use counts `action_uses`
+    pub fn action_uses(&self, backtrack_id: usize, action_uses: u64) -> [u8; 64] {
+        match &self.action_uses_packing {
+            ActionUsesPacking::Unlimited => [0; 64],
+            ActionUsesPacking::Packed {
+                place_values,
+                action_limits,
+            } => std::array::from_fn(|action| {
+                action_limits.limit_at(action).map_or(0, |limit| {
+                    (action_uses / place_values[action] % (u64::from(limit) + 1)) as u8
+                })
+            }),
+            ActionUsesPacking::Unpruned => count_action_uses(self.backtrack(backtrack_id)),
+        }
     }
 
     pub fn backtrack(&self, backtrack_id: usize) -> impl Iterator<Item = ActionCombo> {
@@ -162,11 +274,24 @@ impl SearchQueue {
         SearchQueueStats {
             processed_nodes: self.processed_nodes,
             dropped_nodes: self.dropped_nodes,
-            pareto_buckets_squared_size_sum: self.pareto_front.buckets_squared_size_sum(),
+            pareto_buckets_squared_size_sum: self
+                .pareto_fronts
+                .iter()
+                .map(ParetoFront::buckets_squared_size_sum)
+                .sum(),
         }
     }
 }
 
+/// Number of times each action is used, indexed by `Action as usize`
+fn count_action_uses(solver_actions: impl Iterator<Item = ActionCombo>) -> [u8; 64] {
+    let mut action_uses = [0; 64];
+    for action in solver_actions.flat_map(ActionCombo::actions) {
+        action_uses[*action as usize] += 1;
+    }
+    action_uses
+}
+
 fn pareto_weight(state: &SimulationState) -> u32 {
     state.cp as u32
         + state.durability as u32
@@ -1,34 +1,58 @@
 use raphael_sim::*;
 
 use super::search_queue::{SearchQueueStats, SearchScore};
-use crate::actions::{ActionCombo, FULL_SEARCH_ACTIONS, use_action_combo};
+use crate::actions::{
+    ActionCombo, CONDITION_SEARCH_ACTIONS, FULL_SEARCH_ACTIONS, PROGRESS_ONLY_SEARCH_ACTIONS,
+    use_action_combo_in_conditions,
+};
 use crate::macro_solver::search_queue::SearchQueue;
 use crate::quality_upper_bound_solver::QualityUbSolverStats;
 use crate::step_lower_bound_solver::StepLbSolverStats;
 use crate::utils::AtomicFlag;
 use crate::utils::ScopedTimer;
-use crate::{FinishSolver, QualityUbSolver, SolverException, SolverSettings, StepLbSolver};
+use crate::{
+    ActionLimits, FinishSolver, PrecomputeProgress, QualityUbSolver, SolverException,
+    SolverSettings, StepLbSolver,
+};
 
+use std::time::Duration;
 use std::vec::Vec;
 
 #[derive(Clone)]
 struct Solution {
     score: (SearchScore, u32),
-    solver_actions: Vec<ActionCombo>,
+    actions: Vec<Action>,
 }
 
 impl Solution {
-    fn actions(&self) -> Vec<Action> {
-        let mut actions = Vec::new();
-        for solver_action in &self.solver_actions {
-            actions.extend_from_slice(solver_action.actions());
+    fn metrics(&self) -> SolutionMetrics {
+        SolutionMetrics {
+            quality: self.score.0.quality_upper_bound,
+            steps: self.score.0.current_steps,
+            duration: self.score.0.current_duration,
         }
-        actions
     }
 }
 
-type SolutionCallback<'a> = dyn Fn(&[Action]) + 'a;
-type ProgressCallback<'a> = dyn Fn(usize) + 'a;
+/// Summary of a rotation found by the solver.
+/// The Quality is capped at the target and doesn't include the Quality of the state the search started from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SolutionMetrics {
+    pub quality: u32,
+    pub steps: u8,
+    pub duration: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SolverProgress {
+    pub nodes_visited: usize,
+    /// The best rotation found so far, if any
+    pub best_solution: Option<SolutionMetrics>,
+}
+
+type SolutionCallback<'a> = dyn Fn(&[Action]) + Send + Sync + 'a;
+type ProgressCallback<'a> = dyn Fn(SolverProgress) + Send + Sync + 'a;
+type PrecomputeCallback<'a> = dyn Fn(PrecomputeProgress) + Send + Sync + 'a;
 
 #[derive(Debug, Clone, Copy)]
 pub struct MacroSolverStats {
@@ -38,15 +62,28 @@ pub struct MacroSolverStats {
     pub step_lb_stats: StepLbSolverStats,
 }
 
+/// Time spent in each phase of the last solve.
+/// The precompute phases take no time if the tables were already computed by an earlier solve.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MacroSolverTimings {
+    pub quality_ub_precompute: Duration,
+    pub step_lb_precompute: Duration,
+    pub search: Duration,
+}
+
 pub struct MacroSolver<'a> {
     settings: SolverSettings,
     solution_callback: Box<SolutionCallback<'a>>,
     progress_callback: Box<ProgressCallback<'a>>,
+    precompute_callback: Box<PrecomputeCallback<'a>>,
     finish_solver: FinishSolver,
     quality_ub_solver: QualityUbSolver,
     step_lb_solver: StepLbSolver,
     search_queue_stats: SearchQueueStats, // stats of last solve
+    timings: MacroSolverTimings,
     interrupt_signal: AtomicFlag,
+    warm_start: Vec<Action>,
+    time_limit: Option<Duration>,
 }
 
 impl<'a> MacroSolver<'a> {
@@ -60,78 +97,280 @@ impl<'a> MacroSolver<'a> {
             settings,
             solution_callback,
             progress_callback,
+            precompute_callback: Box::new(|_| {}),
             finish_solver: FinishSolver::new(settings),
             quality_ub_solver: QualityUbSolver::new(settings, interrupt_signal.clone()),
             step_lb_solver: StepLbSolver::new(settings, interrupt_signal.clone()),
             search_queue_stats: SearchQueueStats::default(),
+            timings: MacroSolverTimings::default(),
             interrupt_signal,
+            warm_start: Vec::new(),
+            time_limit: None,
         }
     }
 
+    pub fn settings(&self) -> &SolverSettings {
+        &self.settings
+    }
+
+    /// Replaces the callbacks given in [`MacroSolver::new`], e.g. when a solver is kept around and reused for a different task.
+    pub fn set_callbacks(
+        &mut self,
+        solution_callback: Box<SolutionCallback<'a>>,
+        progress_callback: Box<ProgressCallback<'a>>,
+    ) {
+        self.solution_callback = solution_callback;
+        self.progress_callback = progress_callback;
+    }
+
+    /// Sets a known rotation, e.g. a saved rotation for the same recipe, that later solves start from as the best solution found so far.
+    /// Nodes that cannot beat it are never queued, which saves memory on large searches. The rotation is ignored if it doesn't finish the craft from the state the search starts from or breaks any of the settings' constraints.
+    pub fn set_warm_start(&mut self, actions: Vec<Action>) {
+        self.warm_start = actions;
+    }
+
+    /// Sets a callback for the progress of the precompute phase, which is reported separately from the progress of the search.
+    /// Nothing is reported if the tables are already precomputed.
+    pub fn set_precompute_callback(&mut self, precompute_callback: Box<PrecomputeCallback<'a>>) {
+        self.precompute_callback = precompute_callback;
+    }
+
+    /// Limits how long each later solve may take, including the precompute. The limit is only checked during the search, which returns [`SolverException::Timeout`] once it is exceeded.
+    pub fn set_time_limit(&mut self, time_limit: Option<Duration>) {
+        self.time_limit = time_limit;
+    }
+
     pub fn solve(&mut self) -> Result<Vec<Action>, SolverException> {
+        let initial_state = SimulationState::new(&self.settings.simulator_settings);
+        self.solve_from(initial_state)
+    }
+
+    /// Finds the best continuation starting from a mid-craft `state`.
+    /// The precomputed tables are kept between calls, so solving from other states with the same settings skips the precompute step.
+    pub fn solve_from(&mut self, state: SimulationState) -> Result<Vec<Action>, SolverException> {
+        let quality_target = self.settings.max_quality();
+        let opener_conditions_apply = state.effects.combo() == Combo::SynthesisBegin;
+        self.solve_with_quality_target(state, quality_target, opener_conditions_apply)
+    }
+
+    /// Same as [`Self::solve_from`], except that the opener conditions of the settings are the conditions of the steps from `state` on even if it is a mid-craft state,
+    /// e.g. once the condition of the current step is known.
+    pub fn solve_from_in_conditions(
+        &mut self,
+        state: SimulationState,
+    ) -> Result<Vec<Action>, SolverException> {
+        let quality_target = self.settings.max_quality();
+        self.solve_with_quality_target(state, quality_target, true)
+    }
+
+    /// Finds the best rotation that reaches `quality_target` instead of the Quality in the settings.
+    /// The precomputed tables are valid for any target that doesn't exceed the Quality in the settings, so solving for several targets (e.g. all collectable tiers) only precomputes once.
+    pub fn solve_for_quality(
+        &mut self,
+        quality_target: u32,
+    ) -> Result<Vec<Action>, SolverException> {
+        let initial_state = SimulationState::new(&self.settings.simulator_settings);
+        self.solve_from_for_quality(initial_state, quality_target)
+    }
+
+    /// Same as [`Self::solve_for_quality`], except that the search starts from `state`, e.g. a state with less than the max CP.
+    pub fn solve_from_for_quality(
+        &mut self,
+        state: SimulationState,
+        quality_target: u32,
+    ) -> Result<Vec<Action>, SolverException> {
+        let quality_target = std::cmp::min(quality_target, self.settings.max_quality());
+        let opener_conditions_apply = state.effects.combo() == Combo::SynthesisBegin;
+        self.solve_with_quality_target(state, quality_target, opener_conditions_apply)
+    }
+
+    /// Panics, including those of the parallel precompute, are returned as [`SolverException::PanicCaught`] so that the caller can report them.
+    fn solve_with_quality_target(
+        &mut self,
+        state: SimulationState,
+        quality_target: u32,
+        opener_conditions_apply: bool,
+    ) -> Result<Vec<Action>, SolverException> {
+        let solve = || {
+            self.solve_with_quality_target_unguarded(state, quality_target, opener_conditions_apply)
+        };
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(solve)).unwrap_or_else(|payload| {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| (*message).to_owned())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            Err(SolverException::PanicCaught { message })
+        })
+    }
+
+    fn solve_with_quality_target_unguarded(
+        &mut self,
+        state: SimulationState,
+        quality_target: u32,
+        opener_conditions_apply: bool,
+    ) -> Result<Vec<Action>, SolverException> {
+        let deadline = self
+            .time_limit
+            .map(|time_limit| web_time::Instant::now() + time_limit);
         log::debug!(
             "rayon::current_num_threads() = {}",
             rayon::current_num_threads()
         );
 
         let _total_time = ScopedTimer::new("Total Time");
-
-        let initial_state = SimulationState::new(&self.settings.simulator_settings);
+        self.timings = MacroSolverTimings::default();
 
         let timer = ScopedTimer::new("Finish Solver");
-        if !self.finish_solver.can_finish(&initial_state) {
+        if !self.finish_solver.can_finish(&state) {
             return Err(SolverException::NoSolution);
         }
         drop(timer);
 
-        let timer = ScopedTimer::new("Quality UB Solver");
-        self.quality_ub_solver.precompute();
-        drop(timer);
+        if !self.quality_ub_solver.precompute_done() {
+            let timer = ScopedTimer::new("Quality UB Solver");
+            self.quality_ub_solver.precompute();
+            self.timings.quality_ub_precompute = timer.elapsed();
+            drop(timer);
+        }
 
-        let timer = ScopedTimer::new("Step Lb Solver");
-        self.step_lb_solver.precompute();
-        drop(timer);
+        if !self.step_lb_solver.precompute_done() {
+            let timer = ScopedTimer::new("Step Lb Solver");
+            self.step_lb_solver.precompute(&self.precompute_callback);
+            self.timings.step_lb_precompute = timer.elapsed();
+            drop(timer);
+        }
 
-        Ok(self.do_solve(initial_state)?.actions())
+        if !self.precompute_done() {
+            // The precompute was interrupted. Solving again with this solver resumes it, see `precompute_done`.
+            return Err(SolverException::Interrupted);
+        }
+
+        let timer = ScopedTimer::new("Search");
+        let solution = self.do_solve(state, quality_target, opener_conditions_apply, deadline);
+        self.timings.search = timer.elapsed();
+        drop(timer);
+        Ok(solution?.actions)
     }
 
-    fn do_solve(&mut self, state: SimulationState) -> Result<Solution, SolverException> {
-        let _timer = ScopedTimer::new("Search");
-        let mut search_queue = SearchQueue::new(state);
-        let mut solution: Option<Solution> = None;
+    fn do_solve(
+        &mut self,
+        state: SimulationState,
+        quality_target: u32,
+        opener_conditions_apply: bool,
+        deadline: Option<web_time::Instant>,
+    ) -> Result<Solution, SolverException> {
+        let has_action_limits = !self.settings.action_limits.is_unlimited();
+        // The FinishSolver ignores the action limits, so limits on the actions it uses may not leave enough uses to actually finish
+        let finish_actions_unlimited = PROGRESS_ONLY_SEARCH_ACTIONS
+            .into_iter()
+            .flat_map(ActionCombo::actions)
+            .all(|&action| self.settings.action_limits.limit(action).is_none());
+
+        // The conditions of the first steps are usually only known when the search starts from the beginning of the craft
+        let opener_conditions = match opener_conditions_apply {
+            true => self.settings.opener_conditions.as_slice(),
+            false => &[],
+        };
+
+        // The precomputed bounds assume that all remaining steps are Normal, so they only hold from this step on
+        let bounds_valid_from_step = opener_conditions
+            .iter()
+            .rposition(|condition| *condition != Condition::Normal)
+            .map_or(0, |index| index + 1);
+        let mut search_queue = SearchQueue::new(
+            state,
+            self.settings.action_limits,
+            bounds_valid_from_step as u8,
+        );
+
+        let mut solution = self.warm_start_solution(state, quality_target, opener_conditions);
+        if let Some(solution) = &solution {
+            log::debug!("Warm start: {:?}", solution.metrics());
+            search_queue.update_min_score(solution.score.0);
+            (self.solution_callback)(&solution.actions);
+        }
+
+        // The deadline is otherwise only checked every few thousand nodes, which a small search may never reach
+        if deadline.is_some_and(|deadline| web_time::Instant::now() >= deadline) {
+            return Err(SolverException::Timeout);
+        }
 
         let mut popped = 0;
-        while let Some((state, score, backtrack_id)) = search_queue.pop() {
+        while let Some((state, score, backtrack_id, packed_action_uses)) = search_queue.pop() {
             if self.interrupt_signal.is_set() {
                 return Err(SolverException::Interrupted);
             }
 
             popped += 1;
             if popped % (1 << 12) == 0 {
-                (self.progress_callback)(popped);
+                if deadline.is_some_and(|deadline| web_time::Instant::now() >= deadline) {
+                    return Err(SolverException::Timeout);
+                }
+                (self.progress_callback)(SolverProgress {
+                    nodes_visited: popped,
+                    best_solution: solution.as_ref().map(Solution::metrics),
+                });
             }
 
-            for action in FULL_SEARCH_ACTIONS {
-                if let Ok(state) = use_action_combo(&self.settings, state, action) {
+            // Progress stops being backloaded when Quality increases after Progress has been increased
+            let quality_locked = self.settings.prefer_backload_progress && state.progress != 0;
+            let quality = state.quality;
+            let progress = state.progress;
+            let durability = state.durability;
+            // Only the root of a search that starts from the beginning of the craft is subject to the opener preference
+            let is_opener = state.effects.combo() == Combo::SynthesisBegin;
+            let action_uses = match has_action_limits {
+                true => search_queue.action_uses(backtrack_id, packed_action_uses),
+                false => [0; 64],
+            };
+            let step_conditions = opener_conditions
+                .get(usize::from(score.current_steps)..)
+                .unwrap_or_default();
+            let condition_actions = match step_conditions.first() {
+                Some(Condition::Good | Condition::Excellent) => CONDITION_SEARCH_ACTIONS.as_slice(),
+                _ => &[],
+            };
+            for &action in FULL_SEARCH_ACTIONS.iter().chain(condition_actions) {
+                if is_opener && !self.settings.opener.allows(action.actions()[0]) {
+                    continue;
+                }
+                if !within_action_limits(&self.settings.action_limits, &action_uses, action) {
+                    continue;
+                }
+                if let Ok(state) =
+                    use_action_combo_in_conditions(&self.settings, state, action, step_conditions)
+                {
+                    if state.progress > progress
+                        && durability < self.settings.min_synthesis_durability
+                    {
+                        continue;
+                    }
+                    let backloaded =
+                        score.backloaded && !(quality_locked && state.quality > quality);
                     if !state.is_final(&self.settings.simulator_settings) {
                         if !self.finish_solver.can_finish(&state) {
                             // skip this state if it is impossible to max out Progress
                             continue;
                         }
 
-                        search_queue.update_min_score(SearchScore {
-                            quality_upper_bound: std::cmp::min(
-                                state.quality,
-                                self.settings.max_quality(),
-                            ),
-                            ..SearchScore::MIN
-                        });
+                        if finish_actions_unlimited {
+                            search_queue.update_min_score(SearchScore {
+                                quality_upper_bound: std::cmp::min(state.quality, quality_target),
+                                ..SearchScore::MIN
+                            });
+                        }
 
-                        let quality_upper_bound = if state.quality >= self.settings.max_quality() {
-                            self.settings.max_quality()
+                        let bounds_valid = usize::from(score.current_steps + action.steps())
+                            >= bounds_valid_from_step;
+                        let quality_upper_bound = if state.quality >= quality_target {
+                            quality_target
+                        } else if !bounds_valid {
+                            std::cmp::min(score.quality_upper_bound, quality_target)
                         } else {
+                            // The tables are computed for the Quality in the settings, which may exceed the target.
                             std::cmp::min(
-                                score.quality_upper_bound,
+                                std::cmp::min(score.quality_upper_bound, quality_target),
                                 self.quality_ub_solver.quality_upper_bound(state)?,
                             )
                         };
@@ -140,10 +379,10 @@ impl<'a> MacroSolver<'a> {
                             .steps_lower_bound
                             .saturating_sub(score.current_steps + action.steps());
                         let steps_lower_bound =
-                            match quality_upper_bound >= self.settings.max_quality() {
+                            match bounds_valid && quality_upper_bound >= quality_target {
                                 true => self
                                     .step_lb_solver
-                                    .step_lower_bound(state, step_lb_hint)?
+                                    .step_lower_bound(state, step_lb_hint, quality_target)?
                                     .saturating_add(score.current_steps + action.steps()),
                                 false => score.current_steps + action.steps(),
                             };
@@ -152,6 +391,7 @@ impl<'a> MacroSolver<'a> {
                             state,
                             SearchScore {
                                 quality_upper_bound,
+                                backloaded,
                                 steps_lower_bound,
                                 duration_lower_bound: score.current_duration
                                     + action.duration()
@@ -161,13 +401,12 @@ impl<'a> MacroSolver<'a> {
                             },
                             action,
                             backtrack_id,
+                            packed_action_uses,
                         );
                     } else if state.progress >= self.settings.max_progress() {
                         let solution_score = SearchScore {
-                            quality_upper_bound: std::cmp::min(
-                                state.quality,
-                                self.settings.max_quality(),
-                            ),
+                            quality_upper_bound: std::cmp::min(state.quality, quality_target),
+                            backloaded,
                             steps_lower_bound: score.current_steps + action.steps(),
                             duration_lower_bound: score.current_duration + action.duration(),
                             current_steps: score.current_steps + action.steps(),
@@ -179,12 +418,14 @@ impl<'a> MacroSolver<'a> {
                         {
                             solution = Some(Solution {
                                 score: (solution_score, state.quality),
-                                solver_actions: search_queue
+                                actions: search_queue
                                     .backtrack(backtrack_id)
                                     .chain(std::iter::once(action))
+                                    .flat_map(ActionCombo::actions)
+                                    .copied()
                                     .collect(),
                             });
-                            (self.solution_callback)(&solution.as_ref().unwrap().actions());
+                            (self.solution_callback)(&solution.as_ref().unwrap().actions);
                         }
                     }
                 }
@@ -195,6 +436,77 @@ impl<'a> MacroSolver<'a> {
         solution.ok_or(SolverException::NoSolution)
     }
 
+    /// Simulates the warm start rotation from `state` and scores it the same way the search scores its solutions.
+    fn warm_start_solution(
+        &self,
+        mut state: SimulationState,
+        quality_target: u32,
+        opener_conditions: &[Condition],
+    ) -> Option<Solution> {
+        let first_action = *self.warm_start.first()?;
+        if state.effects.combo() == Combo::SynthesisBegin
+            && !self.settings.opener.allows(first_action)
+        {
+            return None;
+        }
+        let mut action_uses = [0; 64];
+        let mut backloaded = true;
+        let mut duration: u8 = 0;
+        let conditions = Condition::sequence(opener_conditions);
+        for (&action, condition) in self.warm_start.iter().zip(conditions) {
+            if state.is_final(&self.settings.simulator_settings) {
+                return None;
+            }
+            action_uses[action as usize] += 1;
+            if self
+                .settings
+                .action_limits
+                .limit(action)
+                .is_some_and(|limit| action_uses[action as usize] > limit)
+            {
+                return None;
+            }
+            let quality_locked = self.settings.prefer_backload_progress && state.progress != 0;
+            let next_state = state
+                .use_action(action, condition, &self.settings.simulator_settings)
+                .ok()?;
+            if next_state.progress > state.progress
+                && state.durability < self.settings.min_synthesis_durability
+            {
+                return None;
+            }
+            backloaded = backloaded && !(quality_locked && next_state.quality > state.quality);
+            duration = duration.checked_add(action.time_cost())?;
+            state = next_state;
+        }
+        if state.progress < self.settings.max_progress() {
+            return None;
+        }
+        let steps = u8::try_from(self.warm_start.len()).ok()?;
+        let score = SearchScore {
+            quality_upper_bound: std::cmp::min(state.quality, quality_target),
+            backloaded,
+            steps_lower_bound: steps,
+            duration_lower_bound: duration,
+            current_steps: steps,
+            current_duration: duration,
+        };
+        Some(Solution {
+            score: (score, state.quality),
+            actions: self.warm_start.clone(),
+        })
+    }
+
+    /// Whether the tables used by the search are fully precomputed.
+    /// An interrupted precompute keeps the finished CP values of the quality upper bound tables and the finished step budgets of the step lower bound tables, so solving again with the same solver resumes the precompute instead of starting over.
+    pub fn precompute_done(&self) -> bool {
+        self.quality_ub_solver.precompute_done() && self.step_lb_solver.precompute_done()
+    }
+
+    pub fn timings(&self) -> MacroSolverTimings {
+        self.timings
+    }
+
     pub fn runtime_stats(&self) -> MacroSolverStats {
         MacroSolverStats {
             finish_states: self.finish_solver.num_states(),
@@ -204,3 +516,15 @@ impl<'a> MacroSolver<'a> {
         }
     }
 }
+
+fn within_action_limits(
+    action_limits: &ActionLimits,
+    action_uses: &[u8; 64],
+    action_combo: ActionCombo,
+) -> bool {
+    action_combo.actions().iter().all(|&action| {
+        action_limits
+            .limit(action)
+            .is_none_or(|limit| action_uses[action as usize] < limit)
+    })
+}
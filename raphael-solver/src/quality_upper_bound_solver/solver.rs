@@ -11,6 +11,8 @@ use super::state::ReducedState;
 
 type ParetoValue = utils::ParetoValue<u32, u32>;
 type ParetoFrontBuilder = utils::ParetoFrontBuilder<u32, u32>;
+type SolvedStates = utils::SolvedStates<ReducedState, ParetoValue>;
+type SolvedStatesChunk = utils::SolvedStatesChunk<ReducedState, ParetoValue>;
 
 #[derive(Debug, Clone, Copy)]
 pub struct QualityUbSolverStats {
@@ -22,13 +24,17 @@ pub struct QualityUbSolverStats {
 pub struct QualityUbSolver {
     settings: SolverSettings,
     interrupt_signal: utils::AtomicFlag,
-    solved_states: FxHashMap<ReducedState, Box<[ParetoValue]>>,
+    solved_states: SolvedStates,
     iq_quality_lut: [u32; 11],
     maximal_templates: FxHashMap<TemplateData, u16>,
     pareto_front_builder: ParetoFrontBuilder,
     durability_cost: u16,
     largest_progress_increase: u32,
+    /// Templates of the precompute phases that are not finished yet, with the phase currently being solved last.
+    precompute_phases: Vec<Vec<Template>>,
+    next_precompute_cp: u16,
     precomputed_states: usize,
+    precompute_done: bool,
 }
 
 impl QualityUbSolver {
@@ -41,7 +47,7 @@ impl QualityUbSolver {
         Self {
             settings,
             interrupt_signal,
-            solved_states: FxHashMap::default(),
+            solved_states: SolvedStates::default(),
             iq_quality_lut: utils::compute_iq_quality_lut(&settings),
             maximal_templates: FxHashMap::default(),
             pareto_front_builder: ParetoFrontBuilder::new(
@@ -50,7 +56,10 @@ impl QualityUbSolver {
             ),
             durability_cost,
             largest_progress_increase: utils::largest_single_action_progress_increase(&settings),
+            precompute_phases: Vec::new(),
+            next_precompute_cp: 0,
             precomputed_states: 0,
+            precompute_done: false,
         }
     }
 
@@ -101,88 +110,126 @@ impl QualityUbSolver {
             .collect()
     }
 
-    pub fn precompute(&mut self) {
-        assert!(self.solved_states.is_empty());
+    fn generate_precompute_phases(&self) -> Vec<Vec<Template>> {
         let all_templates = self.generate_precompute_templates();
+        log::debug!("QualityUbSolver - templates: {}", all_templates.len());
         // States are computed in order of less CP to more CP.
         // States currently being computed assume that child states have already been computed.
         // This is the reason why states with HeartAndSoul and QuickInnovation available must be computed separately.
         // HeartAndSoul enables the use of TricksOfTrade, which restores CP.
         // QuickInnovation requires no CP (and no durability, so durability cost in terms of CP is 0).
-        for (heart_and_soul, quick_innovation) in
-            [(false, false), (false, true), (true, false), (true, true)]
-        {
-            let mut templates: Vec<_> = all_templates
-                .iter()
-                .filter(|template| {
-                    template.data.effects.heart_and_soul_available() == heart_and_soul
-                        && template.data.effects.quick_innovation_available() == quick_innovation
-                })
-                .copied()
-                .collect();
-            // 2 * durability_cost is the minimum CP a state must have to not be considered "final".
-            // See `ReducedState::is_final` for details.
-            for cp in (2 * self.durability_cost..=self.settings.max_cp()).step_by(2) {
-                if self.interrupt_signal.is_set() {
-                    return;
-                }
-                let solved_states = templates
-                    .par_iter_mut()
-                    .filter_map(|template| match template.instantiate(cp) {
-                        Some(state) => Some((template, state)),
-                        None => None,
+        [(false, false), (false, true), (true, false), (true, true)]
+            .into_iter()
+            .rev()
+            .map(|(heart_and_soul, quick_innovation)| {
+                all_templates
+                    .iter()
+                    .filter(|template| {
+                        template.data.effects.heart_and_soul_available() == heart_and_soul
+                            && template.data.effects.quick_innovation_available()
+                                == quick_innovation
                     })
-                    .map_init(
-                        || {
+                    .copied()
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Solves the precomputed states one CP value at a time until all of them are solved.
+    /// When interrupted, the CP values that were fully solved are kept and calling this again resumes from the next CP value.
+    pub fn precompute(&mut self) {
+        while !self.precompute_done && !self.interrupt_signal.is_set() {
+            self.precompute_next_cp();
+        }
+    }
+
+    /// Solves the states of the current precompute phase that have the next CP value.
+    pub(super) fn precompute_next_cp(&mut self) {
+        // 2 * durability_cost is the minimum CP a state must have to not be considered "final".
+        // See `ReducedState::is_final` for details.
+        let min_cp = 2 * self.durability_cost;
+        if self.precompute_phases.is_empty() {
+            assert!(self.solved_states.is_empty());
+            self.precompute_phases = self.generate_precompute_phases();
+            self.next_precompute_cp = min_cp;
+        }
+        let mut templates = self.precompute_phases.pop().unwrap();
+        let cp = self.next_precompute_cp;
+        if cp <= self.settings.max_cp() {
+            let solved_states = templates
+                .par_iter_mut()
+                .filter_map(|template| match template.instantiate(cp) {
+                    Some(state) => Some((template, state)),
+                    None => None,
+                })
+                .fold(
+                    || {
+                        (
                             ParetoFrontBuilder::new(
                                 self.settings.max_progress(),
                                 self.settings.max_quality(),
-                            )
-                        },
-                        |pf_builder, (template, state)| {
-                            let pareto_front = self.solve_precompute_state(pf_builder, state);
-                            let template_is_maximal = {
-                                // A template is "maximal" if there is no benefit of solving it with higher CP
-                                let required_progress = self.settings.max_progress();
-                                let required_quality = self.settings.max_quality().saturating_sub(
-                                    self.iq_quality_lut[usize::from(state.effects.inner_quiet())],
-                                );
-                                #[cfg(test)]
-                                assert!(!pareto_front.is_empty());
-                                pareto_front.last().is_some_and(|value| {
-                                    value.first >= required_progress
-                                        && value.second >= required_quality
-                                })
-                            };
-                            if template_is_maximal {
-                                template.max_cp = cp;
-                            }
-                            (state, pareto_front)
-                        },
-                    )
-                    .collect_vec_list();
-                self.solved_states
-                    .extend(solved_states.into_iter().flatten());
-            }
-            self.maximal_templates.extend(
-                templates
-                    .into_iter()
-                    .map(|template| (template.data, template.max_cp)),
-            );
+                            ),
+                            SolvedStatesChunk::default(),
+                        )
+                    },
+                    |(mut pf_builder, mut chunk), (template, state)| {
+                        self.solve_precompute_state(&mut pf_builder, state);
+                        let pareto_front = pf_builder.peek().unwrap();
+                        let template_is_maximal = {
+                            // A template is "maximal" if there is no benefit of solving it with higher CP
+                            let required_progress = self.settings.max_progress();
+                            let required_quality = self.settings.max_quality().saturating_sub(
+                                self.iq_quality_lut[usize::from(state.effects.inner_quiet())],
+                            );
+                            #[cfg(test)]
+                            assert!(!pareto_front.is_empty());
+                            pareto_front.last().is_some_and(|value| {
+                                value.first >= required_progress && value.second >= required_quality
+                            })
+                        };
+                        if template_is_maximal {
+                            template.max_cp = cp;
+                        }
+                        chunk.push(state, pareto_front);
+                        (pf_builder, chunk)
+                    },
+                )
+                .map(|(_, chunk)| chunk)
+                .collect_vec_list();
+            self.solved_states
+                .extend(solved_states.into_iter().flatten());
         }
-        self.precomputed_states = self.solved_states.len();
-        log::debug!(
-            "QualityUbSolver - templates: {}, precomputed_states: {}",
-            all_templates.len(),
-            self.solved_states.len()
+        if cp + 2 <= self.settings.max_cp() {
+            self.next_precompute_cp = cp + 2;
+            self.precompute_phases.push(templates);
+            return;
+        }
+        self.maximal_templates.extend(
+            templates
+                .into_iter()
+                .map(|template| (template.data, template.max_cp)),
         );
+        self.next_precompute_cp = min_cp;
+        if self.precompute_phases.is_empty() {
+            self.precomputed_states = self.solved_states.len();
+            self.precompute_done = true;
+            log::debug!(
+                "QualityUbSolver - precomputed_states: {}",
+                self.solved_states.len()
+            );
+        }
+    }
+
+    pub fn precompute_done(&self) -> bool {
+        self.precompute_done
     }
 
+    /// Solves the state from its already solved child states, leaving its Pareto front at the top of `pareto_front_builder`.
     fn solve_precompute_state(
         &self,
         pareto_front_builder: &mut ParetoFrontBuilder,
         state: ReducedState,
-    ) -> Box<[ParetoValue]> {
+    ) {
         pareto_front_builder.clear();
         pareto_front_builder.push_empty();
         for action in FULL_SEARCH_ACTIONS {
@@ -212,7 +259,6 @@ impl QualityUbSolver {
                 }
             }
         }
-        Box::from(pareto_front_builder.peek().unwrap())
     }
 
     /// Returns an upper-bound on the maximum Quality achievable from this state while also maxing out Progress.
@@ -291,7 +337,7 @@ impl QualityUbSolver {
                 break;
             }
         }
-        let pareto_front = Box::from(self.pareto_front_builder.peek().unwrap());
+        let pareto_front = self.pareto_front_builder.peek().unwrap();
         self.solved_states.insert(state, pareto_front);
         Ok(())
     }
@@ -334,7 +380,7 @@ impl QualityUbSolver {
         QualityUbSolverStats {
             parallel_states: self.precomputed_states,
             sequential_states: self.solved_states.len() - self.precomputed_states,
-            pareto_values: self.solved_states.values().map(|value| value.len()).sum(),
+            pareto_values: self.solved_states.num_values(),
         }
     }
 }
@@ -8,13 +8,25 @@ use raphael_sim::*;
 /// A high enough value to make sure that no action combo fails due to missing durability.
 const MAX_DURABILITY: u16 = 100;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ReducedState {
     pub cp: u16,
     pub compressed_unreliable_quality: u8,
     pub effects: Effects,
 }
 
+const _: () = assert!(std::mem::size_of::<ReducedState>() == 8);
+
+impl std::hash::Hash for ReducedState {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        // Hash all fields as a single word instead of one word per field.
+        let packed = u64::from(self.cp)
+            | (u64::from(self.compressed_unreliable_quality) << 16)
+            | (u64::from(self.effects.into_bits()) << 32);
+        state.write_u64(packed);
+    }
+}
+
 impl ReducedState {
     pub fn from_state(
         mut state: SimulationState,
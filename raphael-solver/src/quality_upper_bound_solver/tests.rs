@@ -1,7 +1,7 @@
 use raphael_sim::*;
 
 use crate::{
-    SolverSettings,
+    AtomicFlag, SolverSettings,
     actions::{FULL_SEARCH_ACTIONS, use_action_combo},
     test_utils::*,
 };
@@ -11,7 +11,7 @@ use super::QualityUbSolver;
 fn solve(simulator_settings: Settings, actions: &[Action]) -> u32 {
     let mut state = SimulationState::from_macro(&simulator_settings, actions).unwrap();
     state.effects.set_combo(Combo::None);
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let mut solver = QualityUbSolver::new(solver_settings, Default::default());
     solver.quality_upper_bound(state).unwrap()
 }
@@ -30,6 +30,7 @@ fn test_manipulation_refund() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
     let result = solve(settings, &[Action::Manipulation]);
     assert_eq!(result, 4975);
@@ -80,7 +81,52 @@ fn consistency(max_durability: u16, allowed_actions: ActionMask) {
         allowed_actions,
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     check_consistency(solver_settings);
 }
+
+#[test]
+fn resume_interrupted_precompute() {
+    let simulator_settings = Settings {
+        max_progress: 2000,
+        max_quality: 2000,
+        max_durability: 60,
+        max_cp: 600,
+        base_progress: 100,
+        base_quality: 100,
+        job_level: 100,
+        allowed_actions: WITH_SPECIALIST_ACTIONS,
+        adversarial: false,
+        backload_progress: false,
+        traits: TraitOverrides::none(),
+    };
+    let solver_settings = SolverSettings::new(simulator_settings);
+    let interrupt_signal = AtomicFlag::new();
+    let mut solver = QualityUbSolver::new(solver_settings, interrupt_signal.clone());
+    for _ in 0..100 {
+        solver.precompute_next_cp();
+    }
+    interrupt_signal.set();
+    solver.precompute();
+    assert!(!solver.precompute_done());
+    interrupt_signal.clear();
+    solver.precompute();
+    assert!(solver.precompute_done());
+
+    let mut expected_solver = QualityUbSolver::new(solver_settings, AtomicFlag::new());
+    expected_solver.precompute();
+    assert_eq!(
+        solver.runtime_stats().parallel_states,
+        expected_solver.runtime_stats().parallel_states
+    );
+    for state in generate_random_states(solver_settings, 10_000)
+        .filter(|state| state.effects.combo() == Combo::None)
+    {
+        assert_eq!(
+            solver.quality_upper_bound(state).unwrap(),
+            expected_solver.quality_upper_bound(state).unwrap()
+        );
+    }
+}
@@ -1,7 +1,7 @@
 mod solver;
 mod state;
 
-pub use solver::{StepLbSolver, StepLbSolverStats};
+pub use solver::{PrecomputeProgress, StepLbSolver, StepLbSolverStats};
 
 #[cfg(test)]
 mod tests;
@@ -1,4 +1,5 @@
 use std::num::NonZeroU8;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use crate::{
     SolverException, SolverSettings,
@@ -9,13 +10,21 @@ use raphael_sim::*;
 use rayon::iter::{
     FromParallelIterator, IntoParallelIterator, IntoParallelRefIterator, ParallelIterator,
 };
-use rustc_hash::FxHashSet;
 
 use super::state::ReducedState;
 
 type ParetoValue = utils::ParetoValue<u32, u32>;
 type ParetoFrontBuilder = utils::ParetoFrontBuilder<u32, u32>;
-type SolvedStates = rustc_hash::FxHashMap<ReducedState, Box<[ParetoValue]>>;
+type SolvedStates = utils::SolvedStates<ReducedState, ParetoValue>;
+type SolvedStatesChunk = utils::SolvedStatesChunk<ReducedState, ParetoValue>;
+
+/// Progress of the step lower bound precompute, which solves the remaining templates once for each step budget
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrecomputeProgress {
+    pub step_budget: u8,
+    pub templates_solved: usize,
+    pub templates_total: usize,
+}
 
 #[derive(Debug, Clone, Copy)]
 pub struct StepLbSolverStats {
@@ -28,6 +37,7 @@ pub struct StepLbSolver {
     interrupt_signal: utils::AtomicFlag,
     solved_states: SolvedStates,
     precompute_templates: Vec<Template>,
+    next_precompute_step_budget: NonZeroU8,
     iq_quality_lut: [u32; 11],
     largest_progress_increase: u32,
 }
@@ -42,6 +52,7 @@ impl StepLbSolver {
             interrupt_signal,
             solved_states: SolvedStates::default(),
             precompute_templates: Self::generate_precompute_templates(&settings),
+            next_precompute_step_budget: NonZeroU8::new(1).unwrap(),
             iq_quality_lut,
             largest_progress_increase: largest_single_action_progress_increase(&settings),
         }
@@ -82,33 +93,59 @@ impl StepLbSolver {
         templates.into_iter().collect()
     }
 
-    pub fn precompute(&mut self) {
-        let mut next_precompute_step_budget = NonZeroU8::new(1).unwrap();
+    /// Solves the templates one step budget at a time until all of them are solved.
+    /// When interrupted, the step budgets that were fully solved are kept and calling this again resumes from the next step budget.
+    pub fn precompute(&mut self, progress_callback: &(dyn Fn(PrecomputeProgress) + Sync)) {
         while !self.precompute_templates.is_empty() && !self.interrupt_signal.is_set() {
             // A lot of templates map to the same state at lower step budgets due to effect and durability optimizations.
             // Here we deduplicate the instantiated templates to avoid solving duplicate states.
-            let instantiated_templates: FxHashSet<ReducedState> = self
+            let instantiated_templates: utils::WordHashSet<ReducedState> = self
                 .precompute_templates
                 .iter()
-                .map(|template| template.instantiate(next_precompute_step_budget))
+                .map(|template| template.instantiate(self.next_precompute_step_budget))
                 .collect();
 
+            let step_budget = self.next_precompute_step_budget.get();
+            let templates_total = instantiated_templates.len();
+            let templates_solved = AtomicUsize::new(0);
+            progress_callback(PrecomputeProgress {
+                step_budget,
+                templates_solved: 0,
+                templates_total,
+            });
+
             let init = || {
-                ParetoFrontBuilder::new(self.settings.max_progress(), self.settings.max_quality())
+                (
+                    ParetoFrontBuilder::new(
+                        self.settings.max_progress(),
+                        self.settings.max_quality(),
+                    ),
+                    SolvedStatesChunk::default(),
+                )
             };
             let solved_templates = instantiated_templates
                 .into_par_iter()
-                .map_init(init, |pareto_front_builder, state| {
-                    let pareto_front = self.solve_precompute_state(pareto_front_builder, state);
-                    (state, pareto_front)
+                .fold(init, |(mut pareto_front_builder, mut chunk), state| {
+                    self.solve_precompute_state(&mut pareto_front_builder, state);
+                    chunk.push(state, pareto_front_builder.peek().unwrap());
+                    let solved = templates_solved.fetch_add(1, Ordering::Relaxed) + 1;
+                    if solved.is_multiple_of(1 << 12) {
+                        progress_callback(PrecomputeProgress {
+                            step_budget,
+                            templates_solved: solved,
+                            templates_total,
+                        });
+                    }
+                    (pareto_front_builder, chunk)
                 })
+                .map(|(_, chunk)| chunk)
                 .collect_vec_list();
 
             self.solved_states
                 .extend(solved_templates.into_iter().flatten());
 
             let filtered_templates = self.precompute_templates.par_iter().filter(|template| {
-                let state = template.instantiate(next_precompute_step_budget);
+                let state = template.instantiate(self.next_precompute_step_budget);
                 let pareto_front = self.solved_states.get(&state).unwrap();
                 // Values are sorted Progress-increaasing and Quality-decreasing.
                 // The last value is the value with the most Progress.
@@ -127,7 +164,7 @@ impl StepLbSolver {
             });
             self.precompute_templates = Vec::from_par_iter(filtered_templates.copied());
 
-            next_precompute_step_budget = next_precompute_step_budget.saturating_add(1);
+            self.next_precompute_step_budget = self.next_precompute_step_budget.saturating_add(1);
 
             log::trace!(
                 "StepLbSolver - templates: {}, solved_states: {}",
@@ -137,11 +174,16 @@ impl StepLbSolver {
         }
     }
 
+    pub fn precompute_done(&self) -> bool {
+        self.precompute_templates.is_empty()
+    }
+
+    /// Solves the state from its already solved child states, leaving its Pareto front at the top of `pareto_front_builder`.
     fn solve_precompute_state(
         &self,
         pareto_front_builder: &mut ParetoFrontBuilder,
         state: ReducedState,
-    ) -> Box<[ParetoValue]> {
+    ) {
         pareto_front_builder.clear();
         pareto_front_builder.push_empty();
         for action in FULL_SEARCH_ACTIONS {
@@ -182,23 +224,23 @@ impl StepLbSolver {
                 }
             }
         }
-        Box::from(pareto_front_builder.peek().unwrap())
     }
 
     pub fn step_lower_bound(
         &mut self,
         state: SimulationState,
         hint: u8,
+        quality_target: u32,
     ) -> Result<u8, SolverException> {
-        if !state.effects.allow_quality_actions() && state.quality < self.settings.max_quality() {
+        if !state.effects.allow_quality_actions() && state.quality < quality_target {
             return Ok(u8::MAX);
         }
         let mut hint = NonZeroU8::try_from(std::cmp::max(hint, 1)).unwrap();
         while self
             .quality_upper_bound(state, hint)?
-            .is_none_or(|quality_ub| quality_ub < self.settings.max_quality())
+            .is_none_or(|quality_ub| quality_ub < quality_target)
         {
-            hint = hint.checked_add(1).unwrap();
+            hint = hint.checked_add(1).ok_or(SolverException::TableOverflow)?;
         }
         Ok(hint.get())
     }
@@ -222,16 +264,16 @@ impl StepLbSolver {
                 .map(|value| state.quality + value.second);
             Ok(quality_ub)
         } else {
-            Err(SolverException::InternalError(
-                "StepLbSolver: Unknown state queried.".to_string(),
-            ))
+            Err(SolverException::UnknownStateQueried {
+                state: format!("{reduced_state:?}"),
+            })
         }
     }
 
     pub fn runtime_stats(&self) -> StepLbSolverStats {
         StepLbSolverStats {
             parallel_states: self.solved_states.len(),
-            pareto_values: self.solved_states.values().map(|value| value.len()).sum(),
+            pareto_values: self.solved_states.num_values(),
         }
     }
 }
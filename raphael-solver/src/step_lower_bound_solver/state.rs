@@ -2,13 +2,25 @@ use std::num::NonZeroU8;
 
 use raphael_sim::*;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ReducedState {
     pub steps_budget: NonZeroU8,
     pub durability: u16,
     pub effects: Effects,
 }
 
+const _: () = assert!(std::mem::size_of::<ReducedState>() == 8);
+
+impl std::hash::Hash for ReducedState {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        // Hash all fields as a single word instead of one word per field.
+        let packed = u64::from(self.steps_budget.get())
+            | (u64::from(self.durability) << 8)
+            | (u64::from(self.effects.into_bits()) << 32);
+        state.write_u64(packed);
+    }
+}
+
 impl ReducedState {
     pub fn optimize_action_mask(settings: &mut Settings) {
         settings.allowed_actions = settings
@@ -13,11 +13,13 @@ use super::*;
 /// It is admissible if the step-lb of a state is never greater than the step count of a reachable final state.
 fn check_consistency(solver_settings: SolverSettings) {
     let mut solver = StepLbSolver::new(solver_settings, AtomicFlag::default());
-    solver.precompute();
+    solver.precompute(&|_| {});
     for state in generate_random_states(solver_settings, 1_000_000)
         .filter(|state| state.effects.combo() == Combo::None)
     {
-        let state_step_lb = solver.step_lower_bound(state, 0).unwrap();
+        let state_step_lb = solver
+            .step_lower_bound(state, 0, solver_settings.max_quality())
+            .unwrap();
         for action in FULL_SEARCH_ACTIONS {
             if let Ok(child_state) = use_action_combo(&solver_settings, state, action) {
                 let child_step_lb = if child_state.is_final(&solver_settings.simulator_settings) {
@@ -29,7 +31,9 @@ fn check_consistency(solver_settings: SolverSettings) {
                         u8::MAX
                     }
                 } else {
-                    solver.step_lower_bound(child_state, 0).unwrap()
+                    solver
+                        .step_lower_bound(child_state, 0, solver_settings.max_quality())
+                        .unwrap()
                 };
                 if state_step_lb > child_step_lb.saturating_add(action.steps()) {
                     dbg!(state, action, state_step_lb, child_step_lb);
@@ -56,7 +60,8 @@ fn consistency(max_durability: u16, allowed_actions: ActionMask) {
         allowed_actions,
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     check_consistency(solver_settings);
 }
@@ -1,8 +1,13 @@
 mod atomic_flag;
 mod pareto_front_builder;
+mod solved_states;
+mod word_hasher;
 
 pub use atomic_flag::AtomicFlag;
 pub use pareto_front_builder::{ParetoFrontBuilder, ParetoValue};
+pub use solved_states::{SolvedStates, SolvedStatesChunk};
+pub use word_hasher::{WordHashSet, WordHasher};
+
 use raphael_sim::*;
 
 use crate::{
@@ -22,6 +27,10 @@ impl ScopedTimer {
             timer: web_time::Instant::now(),
         }
     }
+
+    pub fn elapsed(&self) -> std::time::Duration {
+        self.timer.elapsed()
+    }
 }
 
 impl Drop for ScopedTimer {
@@ -98,6 +107,10 @@ pub fn compute_iq_quality_lut(settings: &SolverSettings) -> [u32; 11] {
         // TODO: implement this for heart and soul
         return [0; 11];
     }
+    if settings.opener_conditions.contains(&Condition::Poor) {
+        // Actions in a Poor step increase Quality by less than the minimum assumed for the Inner Quiet stacks they give
+        return [0; 11];
+    }
     let mut result = [u32::MAX; 11];
     result[0] = 0;
     for iq in 0..10 {
@@ -0,0 +1,152 @@
+use std::hash::{BuildHasher, BuildHasherDefault, Hash};
+
+use super::WordHasher;
+
+/// Memoization table that maps states to their Pareto fronts, stored as a structure of arrays.
+///
+/// The `i`-th inserted state is `keys[i]` and its front is `values[offsets[i]..offsets[i + 1]]`,
+/// so instead of a separate allocation per front, all fronts are stored back to back in a single buffer.
+/// The hash table itself only holds compact `u32` indices into these arrays, which keeps it small enough
+/// for the hot lookup loops to mostly hit the cache, and a lookup only touches the key it compares against.
+pub struct SolvedStates<K, V> {
+    /// Open-addressing table with linear probing, each slot is an index into `keys` or `EMPTY_SLOT`
+    slots: Box<[u32]>,
+    keys: Vec<K>,
+    offsets: Vec<u32>,
+    values: Vec<V>,
+}
+
+const EMPTY_SLOT: u32 = u32::MAX;
+const MIN_SLOTS: usize = 16;
+
+impl<K: Hash + Eq, V: Copy> SolvedStates<K, V> {
+    fn home_slot(&self, key: &K) -> usize {
+        let hash = BuildHasherDefault::<WordHasher>::default().hash_one(key);
+        // `slots.len()` is a power of two
+        hash as usize & (self.slots.len() - 1)
+    }
+
+    fn find_index(&self, key: &K) -> Option<usize> {
+        // no slots are allocated before the first insert
+        if self.slots.is_empty() {
+            return None;
+        }
+        let mask = self.slots.len() - 1;
+        let mut slot = self.home_slot(key);
+        loop {
+            match self.slots[slot] {
+                EMPTY_SLOT => return None,
+                index if self.keys[index as usize] == *key => return Some(index as usize),
+                _ => slot = (slot + 1) & mask,
+            }
+        }
+    }
+
+    pub fn get(&self, key: &K) -> Option<&[V]> {
+        let index = self.find_index(key)?;
+        let begin = self.offsets[index] as usize;
+        let end = self.offsets[index + 1] as usize;
+        Some(&self.values[begin..end])
+    }
+
+    #[cfg(test)]
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.find_index(key).is_some()
+    }
+
+    /// Inserts the front of a state that hasn't been inserted yet.
+    pub fn insert(&mut self, key: K, values: &[V]) {
+        debug_assert!(self.find_index(&key).is_none());
+        // keep the load factor at most 3/4, so that probe sequences stay short
+        if 4 * (self.keys.len() + 1) > 3 * self.slots.len() {
+            self.grow();
+        }
+        let index = u32::try_from(self.keys.len()).unwrap();
+        assert_ne!(index, EMPTY_SLOT);
+        self.place(self.home_slot(&key), index);
+        self.keys.push(key);
+        self.values.extend_from_slice(values);
+        self.offsets.push(u32::try_from(self.values.len()).unwrap());
+    }
+
+    fn place(&mut self, mut slot: usize, index: u32) {
+        let mask = self.slots.len() - 1;
+        while self.slots[slot] != EMPTY_SLOT {
+            slot = (slot + 1) & mask;
+        }
+        self.slots[slot] = index;
+    }
+
+    fn grow(&mut self) {
+        let num_slots = std::cmp::max(MIN_SLOTS, 2 * self.slots.len());
+        self.slots = vec![EMPTY_SLOT; num_slots].into_boxed_slice();
+        for index in 0..self.keys.len() {
+            let slot = self.home_slot(&self.keys[index]);
+            self.place(slot, index as u32);
+        }
+    }
+
+    /// Number of states in the table.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Total number of values over all fronts.
+    pub fn num_values(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl<K, V> Default for SolvedStates<K, V> {
+    fn default() -> Self {
+        Self {
+            slots: Box::default(),
+            keys: Vec::new(),
+            offsets: vec![0],
+            values: Vec::new(),
+        }
+    }
+}
+
+/// States and their Pareto fronts in insertion order, laid out like [`SolvedStates`] but without the hash table.
+///
+/// Fronts that are solved in parallel are written into one chunk per thread, which is then appended to a [`SolvedStates`].
+pub struct SolvedStatesChunk<K, V> {
+    keys: Vec<K>,
+    offsets: Vec<u32>,
+    values: Vec<V>,
+}
+
+impl<K, V: Copy> SolvedStatesChunk<K, V> {
+    pub fn push(&mut self, key: K, values: &[V]) {
+        self.keys.push(key);
+        self.values.extend_from_slice(values);
+        self.offsets.push(u32::try_from(self.values.len()).unwrap());
+    }
+}
+
+impl<K, V> Default for SolvedStatesChunk<K, V> {
+    fn default() -> Self {
+        Self {
+            keys: Vec::new(),
+            offsets: vec![0],
+            values: Vec::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq, V: Copy> Extend<SolvedStatesChunk<K, V>> for SolvedStates<K, V> {
+    fn extend<I: IntoIterator<Item = SolvedStatesChunk<K, V>>>(&mut self, iter: I) {
+        for chunk in iter {
+            for (index, key) in chunk.keys.into_iter().enumerate() {
+                let begin = chunk.offsets[index] as usize;
+                let end = chunk.offsets[index + 1] as usize;
+                self.insert(key, &chunk.values[begin..end]);
+            }
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use std::hash::{BuildHasherDefault, Hasher};
+
+pub type WordHashSet<K> = std::collections::HashSet<K, BuildHasherDefault<WordHasher>>;
+
+const MULTIPLIER: u64 = 0x517c_c1b7_2722_0a95;
+
+/// Hasher for small fixed-size keys that hash themselves as a single `u64`.
+///
+/// A plain multiplicative hash (like FxHash) only propagates entropy towards the high bits,
+/// which leaves the low bits used for bucket selection poorly distributed when the whole key is one word.
+/// Folding the high half of the full 128-bit product back into the low half fixes that at the cost of one extra XOR.
+#[derive(Default)]
+pub struct WordHasher {
+    hash: u64,
+}
+
+impl Hasher for WordHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.write_u64(u64::from(byte));
+        }
+    }
+
+    fn write_u64(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(MULTIPLIER);
+    }
+
+    fn finish(&self) -> u64 {
+        let product = u128::from(self.hash) * u128::from(MULTIPLIER);
+        (product as u64) ^ ((product >> 64) as u64)
+    }
+}
@@ -1,6 +1,9 @@
 use expect_test::expect;
 use raphael_sim::*;
-use raphael_solver::{AtomicFlag, MacroSolver, SolverSettings};
+use raphael_solver::{
+    ActionLimits, AtomicFlag, DecisionTree, MacroSolver, OpenerConditionsError, OpenerPreference,
+    SolverException, SolverSettings,
+};
 
 #[derive(Debug, Clone, Copy)]
 #[allow(dead_code)]
@@ -55,6 +58,31 @@ fn test_with_settings(
     expected_runtime_stats.assert_debug_eq(&solver.runtime_stats());
 }
 
+fn solve_with(solver_settings: SolverSettings) -> Vec<Action> {
+    MacroSolver::new(
+        solver_settings,
+        Box::new(|_| {}),
+        Box::new(|_| {}),
+        AtomicFlag::new(),
+    )
+    .solve()
+    .unwrap()
+}
+
+const SETTINGS: Settings = Settings {
+    max_cp: 400,
+    max_durability: 60,
+    max_progress: 2000,
+    max_quality: 1000,
+    base_progress: 100,
+    base_quality: 100,
+    job_level: 90,
+    allowed_actions: ActionMask::regular(),
+    adversarial: false,
+    backload_progress: false,
+    traits: TraitOverrides::none(),
+};
+
 #[test]
 fn unsolvable() {
     let simulator_settings = Settings {
@@ -68,8 +96,9 @@ fn unsolvable() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         None
     "#]];
@@ -108,8 +137,9 @@ fn zero_quality() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -155,8 +185,9 @@ fn max_quality() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -202,8 +233,9 @@ fn large_progress_quality_increase() {
         allowed_actions: ActionMask::all(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -249,8 +281,9 @@ fn backload_progress_single_delicate_synthesis() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: true,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -270,15 +303,590 @@ fn backload_progress_single_delicate_synthesis() {
                 pareto_buckets_squared_size_sum: 0,
             },
             quality_ub_stats: QualityUbSolverStats {
-                parallel_states: 8965,
+                parallel_states: 9397,
                 sequential_states: 0,
-                pareto_values: 8965,
+                pareto_values: 9397,
             },
             step_lb_stats: StepLbSolverStats {
-                parallel_states: 1596,
-                pareto_values: 1596,
+                parallel_states: 1116,
+                pareto_values: 1116,
             },
         }
     "#]];
     test_with_settings(solver_settings, expected_score, expected_runtime_stats);
 }
+
+#[test]
+fn solve_from_mid_craft_state() {
+    let simulator_settings = SETTINGS;
+    let solver_settings = SolverSettings::new(simulator_settings);
+    let mut solver = MacroSolver::new(
+        solver_settings,
+        Box::new(|_| {}),
+        Box::new(|_| {}),
+        AtomicFlag::new(),
+    );
+    let actions = solver.solve().unwrap();
+    let precompute_stats = solver.runtime_stats();
+
+    let (prefix, _) = actions.split_at(4);
+    let state = SimulationState::from_macro(&simulator_settings, prefix).unwrap();
+    let continuation = solver.solve_from(state).unwrap();
+    let final_state = SimulationState::from_macro(
+        &simulator_settings,
+        &[prefix, continuation.as_slice()].concat(),
+    )
+    .unwrap();
+    assert!(final_state.progress >= solver_settings.max_progress());
+    assert!(final_state.quality >= solver_settings.max_quality());
+
+    // precomputed tables are reused instead of being computed again
+    let runtime_stats = solver.runtime_stats();
+    assert_eq!(
+        runtime_stats.quality_ub_stats.parallel_states,
+        precompute_stats.quality_ub_stats.parallel_states
+    );
+    assert_eq!(
+        runtime_stats.step_lb_stats.parallel_states,
+        precompute_stats.step_lb_stats.parallel_states
+    );
+}
+
+#[test]
+fn solve_for_quality_matches_separate_solves() {
+    let simulator_settings = SETTINGS;
+    let solver_settings = SolverSettings::new(simulator_settings);
+    let mut shared_solver = MacroSolver::new(
+        solver_settings,
+        Box::new(|_| {}),
+        Box::new(|_| {}),
+        AtomicFlag::new(),
+    );
+    for quality_target in [550, 750, 950, 1000] {
+        let actions = shared_solver.solve_for_quality(quality_target).unwrap();
+        let final_state = SimulationState::from_macro(&simulator_settings, &actions).unwrap();
+        assert!(final_state.progress >= solver_settings.max_progress());
+        assert!(final_state.quality >= quality_target);
+
+        let separate_settings = SolverSettings::new(Settings {
+            max_quality: quality_target as u16,
+            ..simulator_settings
+        });
+        let expected_actions = MacroSolver::new(
+            separate_settings,
+            Box::new(|_| {}),
+            Box::new(|_| {}),
+            AtomicFlag::new(),
+        )
+        .solve()
+        .unwrap();
+        assert_eq!(actions.len(), expected_actions.len());
+        assert_eq!(
+            actions.iter().map(|action| action.time_cost()).sum::<u8>(),
+            expected_actions
+                .iter()
+                .map(|action| action.time_cost())
+                .sum::<u8>()
+        );
+    }
+}
+
+#[test]
+fn opener_preference() {
+    let simulator_settings = SETTINGS;
+    let solve = |opener| {
+        let solver_settings = SolverSettings {
+            opener,
+            ..SolverSettings::new(simulator_settings)
+        };
+        let actions = solve_with(solver_settings);
+        let final_state = SimulationState::from_macro(&simulator_settings, &actions).unwrap();
+        assert!(final_state.progress >= solver_settings.max_progress());
+        actions
+    };
+    let unconstrained = solve(OpenerPreference::Any);
+    for opener in [Action::Reflect, Action::MuscleMemory] {
+        assert_eq!(solve(OpenerPreference::Force(opener))[0], opener);
+    }
+    let actions = solve(OpenerPreference::Forbid(unconstrained[0]));
+    assert_ne!(actions[0], unconstrained[0]);
+}
+
+#[test]
+fn opener_conditions() {
+    let simulator_settings = Settings {
+        max_cp: 300,
+        max_quality: 3000,
+        ..SETTINGS
+    };
+    let solve = |opener_conditions: [Condition; 2]| {
+        let solver_settings = SolverSettings::new(simulator_settings)
+            .with_opener_conditions(opener_conditions, false)
+            .unwrap();
+        let actions = solve_with(solver_settings);
+        let (final_state, errors) = SimulationState::from_macro_in_conditions(
+            &simulator_settings,
+            &actions,
+            &opener_conditions,
+        );
+        assert!(errors.iter().all(Result::is_ok));
+        assert!(final_state.progress >= solver_settings.max_progress());
+        final_state.quality
+    };
+    let normal_quality = solve([Condition::Normal; 2]);
+    assert!(normal_quality < u32::from(simulator_settings.max_quality));
+    assert!(solve([Condition::Good, Condition::Normal]) >= normal_quality);
+    assert!(solve([Condition::Normal, Condition::Good]) >= normal_quality);
+    assert!(solve([Condition::Excellent, Condition::Poor]) > normal_quality);
+
+    let with_opener_conditions = |opener_conditions: [Condition; 2], expert_recipe: bool| {
+        SolverSettings::new(simulator_settings)
+            .with_opener_conditions(opener_conditions, expert_recipe)
+            .map(|solver_settings| solver_settings.opener_conditions)
+    };
+    assert_eq!(
+        with_opener_conditions([Condition::Excellent, Condition::Normal], false),
+        Err(OpenerConditionsError::ImpossibleSequence { step: 1 })
+    );
+    // the step after the opener is Normal, so it can't follow an Excellent second step
+    assert_eq!(
+        with_opener_conditions([Condition::Normal, Condition::Excellent], false),
+        Err(OpenerConditionsError::ImpossibleSequence { step: 2 })
+    );
+    assert_eq!(
+        with_opener_conditions([Condition::Good, Condition::Poor], false),
+        Err(OpenerConditionsError::ImpossibleSequence { step: 1 })
+    );
+    assert_eq!(
+        with_opener_conditions([Condition::Pliant, Condition::Normal], false),
+        Err(OpenerConditionsError::UnavailableCondition(
+            Condition::Pliant
+        ))
+    );
+    assert_eq!(
+        with_opener_conditions([Condition::Pliant, Condition::Primed], true),
+        Ok([Condition::Pliant, Condition::Primed])
+    );
+    assert_eq!(
+        with_opener_conditions([Condition::Excellent, Condition::Poor], true),
+        Err(OpenerConditionsError::UnavailableCondition(
+            Condition::Excellent
+        ))
+    );
+}
+
+#[test]
+fn min_synthesis_durability() {
+    let simulator_settings = SETTINGS;
+    let solver_settings = SolverSettings {
+        min_synthesis_durability: 11,
+        ..SolverSettings::new(simulator_settings)
+    };
+    let actions = solve_with(solver_settings);
+    let mut state = SimulationState::new(&simulator_settings);
+    for action in actions {
+        let next_state = state
+            .use_action(action, Condition::Normal, &simulator_settings)
+            .unwrap();
+        if next_state.progress > state.progress {
+            assert!(state.durability >= 11);
+        }
+        state = next_state;
+    }
+    assert!(state.progress >= solver_settings.max_progress());
+}
+
+#[test]
+fn min_synthesis_durability_below_unconstrained_quality() {
+    // Finishing the unconstrained rotations takes a Progress action below the minimum Durability
+    let simulator_settings = Settings {
+        max_cp: 80,
+        max_durability: 30,
+        max_progress: 1200,
+        max_quality: 3000,
+        base_progress: 200,
+        ..SETTINGS
+    };
+    let solve = |min_synthesis_durability| {
+        let solver_settings = SolverSettings {
+            min_synthesis_durability,
+            ..SolverSettings::new(simulator_settings)
+        };
+        let actions = solve_with(solver_settings);
+        SimulationState::from_macro(&simulator_settings, &actions)
+            .unwrap()
+            .quality
+    };
+    let unconstrained_quality = solve(0);
+    let constrained_quality = solve(16);
+    assert!(constrained_quality < unconstrained_quality);
+    assert_eq!(constrained_quality, 100);
+}
+
+#[test]
+fn action_limits() {
+    let simulator_settings = SETTINGS;
+    let solve = |action_limits| {
+        let solver_settings = SolverSettings {
+            action_limits,
+            ..SolverSettings::new(simulator_settings)
+        };
+        let actions = solve_with(solver_settings);
+        let final_state = SimulationState::from_macro(&simulator_settings, &actions).unwrap();
+        assert!(final_state.progress >= solver_settings.max_progress());
+        actions
+    };
+    let count_uses = |actions: &[Action], action| actions.iter().filter(|&&a| a == action).count();
+    let unconstrained = solve(ActionLimits::unlimited());
+    let most_used = *unconstrained
+        .iter()
+        .max_by_key(|&&action| count_uses(&unconstrained, action))
+        .unwrap();
+    let limit = count_uses(&unconstrained, most_used) - 1;
+    let actions = solve(ActionLimits::unlimited().with_limit(most_used, limit as u8));
+    assert!(count_uses(&actions, most_used) <= limit);
+}
+
+#[test]
+fn action_limits_below_unconstrained_quality() {
+    // Finishing the unconstrained rotations takes a Veneration, which is not allowed
+    let simulator_settings = Settings {
+        max_cp: 80,
+        max_durability: 30,
+        max_progress: 1000,
+        max_quality: 3000,
+        ..SETTINGS
+    };
+    let solve = |action_limits| {
+        let solver_settings = SolverSettings {
+            action_limits,
+            ..SolverSettings::new(simulator_settings)
+        };
+        let actions = solve_with(solver_settings);
+        SimulationState::from_macro(&simulator_settings, &actions)
+            .unwrap()
+            .quality
+    };
+    let unconstrained_quality = solve(ActionLimits::unlimited());
+    let constrained_quality = solve(ActionLimits::unlimited().with_limit(Action::Veneration, 0));
+    assert!(constrained_quality < unconstrained_quality);
+    assert_eq!(constrained_quality, 100);
+}
+
+#[test]
+fn action_limits_late_use() {
+    // Byregot's Blessing is only worth using once Inner Quiet has stacked up at the end of the craft.
+    // A rotation that uses it early mustn't prune one that reaches a worse state by saving it for later.
+    let simulator_settings = Settings {
+        max_cp: 300,
+        max_progress: 1000,
+        max_quality: 5000,
+        ..SETTINGS
+    };
+    let solve = |action_limits| {
+        let solver_settings = SolverSettings {
+            action_limits,
+            ..SolverSettings::new(simulator_settings)
+        };
+        solve_with(solver_settings)
+    };
+    let unconstrained = solve(ActionLimits::unlimited());
+    let actions = solve(
+        ActionLimits::unlimited()
+            .with_limit(Action::ByregotsBlessing, 1)
+            .with_limit(Action::GreatStrides, 1),
+    );
+    let quality = |actions: &[Action]| {
+        SimulationState::from_macro(&simulator_settings, actions)
+            .unwrap()
+            .quality
+    };
+    assert_eq!(quality(&actions), quality(&unconstrained));
+    expect![[r#"
+        [
+            Reflect,
+            Manipulation,
+            PrudentTouch,
+            Observe,
+            AdvancedTouch,
+            Innovation,
+            BasicTouch,
+            StandardTouch,
+            AdvancedTouch,
+            ByregotsBlessing,
+            Veneration,
+            Groundwork,
+            CarefulSynthesis,
+            CarefulSynthesis,
+        ]
+    "#]]
+    .assert_debug_eq(&actions);
+}
+
+#[test]
+fn poor_opener_conditions() {
+    // Quality gained in a Poor step is below what the lower bound tables assume for the Inner Quiet it gives
+    let simulator_settings = Settings {
+        max_cp: 600,
+        max_durability: 70,
+        max_progress: 3000,
+        max_quality: 6000,
+        base_progress: 250,
+        base_quality: 300,
+        job_level: 100,
+        ..SETTINGS
+    };
+    for opener_conditions in [
+        [Condition::Poor, Condition::Normal],
+        [Condition::Excellent, Condition::Poor],
+    ] {
+        let solver_settings = SolverSettings {
+            opener_conditions,
+            ..SolverSettings::new(simulator_settings)
+        };
+        let actions = solve_with(solver_settings);
+        let (final_state, errors) = SimulationState::from_macro_in_conditions(
+            &simulator_settings,
+            &actions,
+            &opener_conditions,
+        );
+        assert!(errors.iter().all(Result::is_ok));
+        assert!(final_state.progress >= solver_settings.max_progress());
+        assert!(final_state.quality >= solver_settings.max_quality());
+    }
+}
+
+#[test]
+fn warm_start() {
+    let simulator_settings = SETTINGS;
+    let solver_settings = SolverSettings::new(simulator_settings);
+    let new_solver = || {
+        MacroSolver::new(
+            solver_settings,
+            Box::new(|_| {}),
+            Box::new(|_| {}),
+            AtomicFlag::new(),
+        )
+    };
+    let mut cold_solver = new_solver();
+    let expected_actions = cold_solver.solve().unwrap();
+    let cold_stats = cold_solver.runtime_stats();
+
+    // a rotation that doesn't finish the craft is ignored
+    let mut solver = new_solver();
+    solver.set_warm_start(vec![Action::BasicSynthesis]);
+    assert_eq!(solver.solve().unwrap().len(), expected_actions.len());
+
+    let mut solver = new_solver();
+    solver.set_warm_start(expected_actions.clone());
+    let actions = solver.solve().unwrap();
+    assert_eq!(actions.len(), expected_actions.len());
+    assert_eq!(
+        actions.iter().map(|action| action.time_cost()).sum::<u8>(),
+        expected_actions
+            .iter()
+            .map(|action| action.time_cost())
+            .sum::<u8>()
+    );
+    let final_state = SimulationState::from_macro(&simulator_settings, &actions).unwrap();
+    assert!(final_state.quality >= solver_settings.max_quality());
+    // nodes that cannot beat the known rotation are never queued
+    let runtime_stats = solver.runtime_stats();
+    assert!(
+        runtime_stats.search_queue_stats.processed_nodes
+            <= cold_stats.search_queue_stats.processed_nodes
+    );
+    assert!(
+        runtime_stats.search_queue_stats.dropped_nodes
+            < cold_stats.search_queue_stats.dropped_nodes
+    );
+}
+
+#[test]
+fn resume_interrupted_precompute() {
+    let simulator_settings = SETTINGS;
+    let solver_settings = SolverSettings::new(simulator_settings);
+    let interrupt_signal = AtomicFlag::new();
+    let mut solver = MacroSolver::new(
+        solver_settings,
+        Box::new(|_| {}),
+        Box::new(|_| {}),
+        interrupt_signal.clone(),
+    );
+    interrupt_signal.set();
+    assert_eq!(solver.solve(), Err(SolverException::Interrupted));
+    assert!(!solver.precompute_done());
+
+    interrupt_signal.clear();
+    let actions = solver.solve().unwrap();
+    assert!(solver.precompute_done());
+    let expected_actions = solve_with(solver_settings);
+    assert_eq!(actions.len(), expected_actions.len());
+    assert_eq!(
+        actions.iter().map(|action| action.time_cost()).sum::<u8>(),
+        expected_actions
+            .iter()
+            .map(|action| action.time_cost())
+            .sum::<u8>()
+    );
+}
+
+#[test]
+fn precompute_progress() {
+    let simulator_settings = SETTINGS;
+    let solver_settings = SolverSettings::new(simulator_settings);
+    let reports = std::sync::Mutex::new(Vec::new());
+    let mut solver = MacroSolver::new(
+        solver_settings,
+        Box::new(|_| {}),
+        Box::new(|_| {}),
+        AtomicFlag::new(),
+    );
+    solver.set_precompute_callback(Box::new(|progress| reports.lock().unwrap().push(progress)));
+    solver.solve().unwrap();
+    let report_count = reports.lock().unwrap().len();
+    assert!(report_count != 0);
+    assert!(reports.lock().unwrap().windows(2).all(|window| {
+        window[0].step_budget <= window[1].step_budget
+            && window[1].templates_solved <= window[1].templates_total
+    }));
+
+    // the tables are already precomputed, so there is nothing to report
+    solver.solve().unwrap();
+    drop(solver);
+    assert_eq!(reports.into_inner().unwrap().len(), report_count);
+}
+
+#[test]
+fn time_limit() {
+    let simulator_settings = SETTINGS;
+    let solver_settings = SolverSettings::new(simulator_settings);
+    let mut solver = MacroSolver::new(
+        solver_settings,
+        Box::new(|_| {}),
+        Box::new(|_| {}),
+        AtomicFlag::new(),
+    );
+    solver.set_time_limit(Some(std::time::Duration::ZERO));
+    assert_eq!(solver.solve(), Err(SolverException::Timeout));
+
+    // the precomputed tables are complete, so the solver can be used again without a limit
+    solver.set_time_limit(None);
+    assert!(solver.solve().is_ok());
+}
+
+#[test]
+fn decision_tree_folds_branches() {
+    let simulator_settings = SETTINGS;
+    let solver_settings = SolverSettings::new(simulator_settings);
+    let initial_state = SimulationState::new(&simulator_settings);
+    let conditions = [
+        Condition::Normal,
+        Condition::Good,
+        Condition::Excellent,
+        Condition::Poor,
+    ];
+    // only a Good second step changes the rotation
+    let mut solved_conditions = Vec::new();
+    let solve = |settings: SolverSettings, state: SimulationState| {
+        assert!(settings.opener.allows(Action::Reflect));
+        if state == initial_state {
+            return Ok(vec![Action::Reflect, Action::BasicTouch]);
+        }
+        solved_conditions.push(settings.opener_conditions);
+        match settings.opener_conditions[0] {
+            Condition::Good => Ok(vec![Action::PreciseTouch]),
+            _ => Ok(vec![Action::BasicTouch]),
+        }
+    };
+    let tree = DecisionTree::build(solver_settings, initial_state, &conditions, 1, solve).unwrap();
+    // the Normal second step is the one the first rotation was solved for, and Excellent is followed by Poor
+    assert_eq!(
+        solved_conditions,
+        [
+            [Condition::Good, Condition::Normal],
+            [Condition::Excellent, Condition::Poor]
+        ]
+    );
+    // the first step is always Normal
+    assert_eq!(tree.first_step.condition, Condition::Normal);
+    assert_eq!(tree.first_step.action, Action::Reflect);
+    let [good_branch, default_branch] = tree.first_step.next_steps.as_slice() else {
+        panic!("{:?}", tree.first_step.next_steps);
+    };
+    assert_eq!(good_branch.conditions, [Condition::Good]);
+    assert!(!good_branch.is_default);
+    assert_eq!(good_branch.actions, [Action::PreciseTouch]);
+    assert_eq!(
+        default_branch.conditions,
+        [Condition::Normal, Condition::Excellent]
+    );
+    assert!(default_branch.is_default);
+    assert_eq!(default_branch.actions, [Action::BasicTouch]);
+    assert!(good_branch.quality > default_branch.quality);
+    assert!(good_branch.next_steps.is_empty() && default_branch.next_steps.is_empty());
+}
+
+#[test]
+fn decision_tree_branches_every_step() {
+    let simulator_settings = SETTINGS;
+    let solver_settings = SolverSettings::new(simulator_settings);
+    let initial_state = SimulationState::new(&simulator_settings);
+    let conditions = [Condition::Normal, Condition::Good];
+    let mut solves = 0;
+    let solve = |settings: SolverSettings, state: SimulationState| {
+        solves += 1;
+        let first_action = match settings.opener_conditions[0] {
+            Condition::Good => Action::PreciseTouch,
+            _ if state == initial_state => Action::Reflect,
+            _ => Action::BasicTouch,
+        };
+        Ok(vec![
+            first_action,
+            Action::BasicTouch,
+            Action::BasicSynthesis,
+        ])
+    };
+    let tree = DecisionTree::build(solver_settings, initial_state, &conditions, 2, solve).unwrap();
+    // each step after the first is solved again in Good, the Normal steps were solved for by the step before them
+    assert_eq!(solves, 4);
+    let [good_branch, default_branch] = tree.first_step.next_steps.as_slice() else {
+        panic!("{:?}", tree.first_step.next_steps);
+    };
+    assert_eq!(good_branch.actions[0], Action::PreciseTouch);
+    assert_eq!(default_branch.actions[0], Action::BasicTouch);
+    for branch in [good_branch, default_branch] {
+        let [third_step_good, third_step_default] = branch.next_steps.as_slice() else {
+            panic!("{:?}", branch.next_steps);
+        };
+        assert_eq!(third_step_good.conditions, [Condition::Good]);
+        assert_eq!(third_step_good.actions[0], Action::PreciseTouch);
+        assert_eq!(third_step_default.conditions, [Condition::Normal]);
+        assert_eq!(third_step_default.actions, branch.actions[1..]);
+        // the steps after the depth aren't branched on
+        assert!(third_step_good.next_steps.is_empty());
+        assert!(third_step_default.next_steps.is_empty());
+    }
+    assert!(good_branch.next_steps[0].quality > default_branch.next_steps[0].quality);
+}
+
+#[test]
+fn decision_tree_without_rotation() {
+    let simulator_settings = SETTINGS;
+    let solver_settings = SolverSettings::new(simulator_settings);
+    let initial_state = SimulationState::new(&simulator_settings);
+    let solve = |_, _| Ok(vec![Action::BasicSynthesis]);
+    // a Normal step is never followed by Poor, so there is no condition to solve the second step for
+    for conditions in [&[][..], &[Condition::Poor]] {
+        let tree = DecisionTree::build(solver_settings, initial_state, conditions, 1, solve);
+        assert_eq!(tree, Err(SolverException::NoSolution));
+    }
+    let tree = DecisionTree::build(
+        solver_settings,
+        initial_state,
+        &[Condition::Normal, Condition::Good],
+        1,
+        |_, _| Ok(Vec::new()),
+    );
+    assert_eq!(tree, Err(SolverException::NoSolution));
+}
@@ -68,8 +68,9 @@ fn rinascita_3700_3280() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: true,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -115,8 +116,9 @@ fn pactmaker_3240_3130() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: true,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -164,8 +166,9 @@ fn pactmaker_3240_3130_heart_and_soul() {
             .remove(Action::QuickInnovation),
         adversarial: false,
         backload_progress: true,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -211,8 +214,9 @@ fn diadochos_4021_3660() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: true,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -258,8 +262,9 @@ fn indagator_3858_4057() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: true,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -305,8 +310,9 @@ fn rarefied_tacos_de_carne_asada_4785_4758() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: true,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -354,8 +360,9 @@ fn stuffed_peppers_2() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: true,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -405,8 +412,9 @@ fn stuffed_peppers_2_heart_and_soul() {
             .remove(Action::QuickInnovation),
         adversarial: false,
         backload_progress: true,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -456,8 +464,9 @@ fn stuffed_peppers_2_quick_innovation() {
             .remove(Action::HeartAndSoul),
         adversarial: false,
         backload_progress: true,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -503,8 +512,9 @@ fn rakaznar_lapidary_hammer_4462_4391() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: true,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -550,8 +560,9 @@ fn black_star_4048_3997() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: true,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -597,8 +608,9 @@ fn claro_walnut_lumber_4900_4800() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: true,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -644,8 +656,9 @@ fn rakaznar_lapidary_hammer_4900_4800() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: true,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -691,8 +704,9 @@ fn rarefied_tacos_de_carne_asada_4966_4817() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: true,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -738,8 +752,9 @@ fn archeo_kingdom_broadsword_4966_4914() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: true,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -785,8 +800,9 @@ fn hardened_survey_plank_5558_5216() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: true,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -818,3 +834,58 @@ fn hardened_survey_plank_5558_5216() {
     "#]];
     test_with_settings(solver_settings, expected_score, expected_runtime_stats);
 }
+
+/// Test that preferring backloaded Progress never gives up Quality,
+/// and that the rotation is backloaded exactly if strictly backloading Progress doesn't decrease Quality.
+fn test_prefer_backload_progress(simulator_settings: Settings) {
+    let solve = |backload_progress, prefer_backload_progress| {
+        let solver_settings = SolverSettings {
+            simulator_settings: Settings {
+                backload_progress,
+                ..simulator_settings
+            },
+            prefer_backload_progress,
+            ..SolverSettings::new(simulator_settings)
+        };
+        let mut solver = MacroSolver::new(
+            solver_settings,
+            Box::new(|_| {}),
+            Box::new(|_| {}),
+            AtomicFlag::new(),
+        );
+        let actions = solver.solve().unwrap();
+        let final_state = SimulationState::from_macro(&simulator_settings, &actions).unwrap();
+        let quality = std::cmp::min(final_state.quality, solver_settings.max_quality());
+        (solver_settings, actions, quality)
+    };
+    let (_, _, unrestricted_quality) = solve(false, false);
+    let (_, _, backloaded_quality) = solve(true, false);
+    let (solver_settings, actions, preferred_quality) = solve(false, true);
+    assert_eq!(preferred_quality, unrestricted_quality);
+    assert_eq!(
+        is_progress_backloaded(&solver_settings, &actions),
+        backloaded_quality >= unrestricted_quality
+    );
+}
+
+#[test]
+fn prefer_backload_progress() {
+    let simulator_settings = Settings {
+        max_cp: 680,
+        max_durability: 70,
+        max_progress: 5060,
+        max_quality: 12628,
+        base_progress: 229,
+        base_quality: 224,
+        job_level: 90,
+        allowed_actions: ActionMask::regular(),
+        adversarial: false,
+        backload_progress: false,
+        traits: TraitOverrides::none(),
+    };
+    test_prefer_backload_progress(simulator_settings);
+    test_prefer_backload_progress(Settings {
+        max_quality: 4000,
+        ..simulator_settings
+    });
+}
@@ -68,8 +68,9 @@ fn rinascita_3700_3280() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -115,8 +116,9 @@ fn pactmaker_3240_3130() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -164,8 +166,9 @@ fn pactmaker_3240_3130_heart_and_soul() {
             .remove(Action::QuickInnovation),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -211,8 +214,9 @@ fn diadochos_4021_3660() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -258,8 +262,9 @@ fn indagator_3858_4057() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -305,8 +310,9 @@ fn rarefied_tacos_de_carne_asada_4785_4758() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -354,8 +360,9 @@ fn stuffed_peppers_2() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -405,8 +412,9 @@ fn stuffed_peppers_2_heart_and_soul() {
             .remove(Action::QuickInnovation),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -456,8 +464,9 @@ fn stuffed_peppers_2_quick_innovation() {
             .remove(Action::HeartAndSoul),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -503,8 +512,9 @@ fn rakaznar_lapidary_hammer_4462_4391() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -550,8 +560,9 @@ fn black_star_4048_3997() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -597,8 +608,9 @@ fn claro_walnut_lumber_4900_4800() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -644,8 +656,9 @@ fn rakaznar_lapidary_hammer_4900_4800() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -691,8 +704,9 @@ fn rarefied_tacos_de_carne_asada_4966_4817() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -738,8 +752,9 @@ fn archeo_kingdom_broadsword_4966_4914() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -785,8 +800,9 @@ fn hardened_survey_plank_5558_5216() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -832,8 +848,9 @@ fn hardened_survey_plank_5558_5216_heart_and_soul_quick_innovation() {
         allowed_actions: ActionMask::all().remove(Action::TrainedEye),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -849,7 +866,7 @@ fn hardened_survey_plank_5558_5216_heart_and_soul_quick_innovation() {
             finish_states: 270612,
             search_queue_stats: SearchQueueStats {
                 processed_nodes: 28159,
-                dropped_nodes: 316039,
+                dropped_nodes: 316042,
                 pareto_buckets_squared_size_sum: 310984,
             },
             quality_ub_stats: QualityUbSolverStats {
@@ -880,8 +897,9 @@ fn ceviche_4900_4800_no_quality() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -929,8 +947,9 @@ fn ce_high_progress_zero_achieved_quality() {
         allowed_actions: ActionMask::regular(),
         adversarial: false,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -69,6 +69,7 @@ const SETTINGS: Settings = Settings {
         .remove(Action::QuickInnovation),
     adversarial: true,
     backload_progress: false,
+    traits: TraitOverrides::none(),
 };
 
 #[test]
@@ -84,7 +85,7 @@ fn stuffed_peppers() {
         base_quality: 360,
         ..SETTINGS
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -132,8 +133,9 @@ fn test_rare_tacos_2() {
         allowed_actions: ActionMask::regular(),
         adversarial: true,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -185,8 +187,9 @@ fn test_mountain_chromite_ingot_no_manipulation() {
             .remove(Action::QuickInnovation),
         adversarial: true,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -232,8 +235,9 @@ fn test_indagator_3858_4057() {
         allowed_actions: ActionMask::regular(),
         adversarial: true,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -283,8 +287,9 @@ fn test_rare_tacos_4628_4410() {
             .remove(Action::QuickInnovation),
         adversarial: true,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -333,8 +338,9 @@ fn issue_113() {
         allowed_actions: ActionMask::regular(),
         adversarial: true,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -381,8 +387,9 @@ fn issue_118() {
         allowed_actions: ActionMask::regular(),
         adversarial: true,
         backload_progress: false,
+        traits: TraitOverrides::none(),
     };
-    let solver_settings = SolverSettings { simulator_settings };
+    let solver_settings = SolverSettings::new(simulator_settings);
     let expected_score = expect![[r#"
         Some(
             SolutionScore {
@@ -1,42 +1,170 @@
-use std::collections::VecDeque;
-use std::ops::{Deref, DerefMut};
+use std::collections::{HashMap, VecDeque};
 use std::sync::{Arc, Mutex};
 
-use raphael_solver::SolverException;
+use raphael_solver::{OpenerPreference, SolverException};
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
 
 use egui::{Align, CursorIcon, Id, Layout, TextStyle};
 use raphael_data::{Consumable, Locale, action_name, get_job_name};
 
-use raphael_sim::{Action, ActionImpl, HeartAndSoul, Manipulation, QuickInnovation};
+use raphael_sim::{Action, ActionImpl, Condition, HeartAndSoul, Manipulation, QuickInnovation};
 
+use crate::backup::BackupStorage;
 use crate::config::{
-    AppConfig, CrafterConfig, CustomRecipeOverridesConfiguration, QualitySource, QualityTarget,
-    RecipeConfiguration,
+    AppConfig, CrafterConfig, CustomRecipeOverridesConfiguration, Gearset, HqInventory,
+    QualitySource, QualityTarget, RecipeConfiguration, UpdateCheck,
 };
-use crate::{thread_pool, util, widgets::*};
-
-fn load<T: DeserializeOwned>(cc: &eframe::CreationContext<'_>, key: &'static str, default: T) -> T {
-    match cc.storage {
+use crate::launch_options::LaunchOptions;
+use crate::solver_tasks::{
+    SolverCache, SolverContext, SolverEvent, SolverEvents, spawn_batch_solver,
+    spawn_cp_tradeoff_solver, spawn_decision_tree_solver, spawn_hq_sweep_solver, spawn_solver,
+    spawn_tier_solver,
+};
+use crate::translations::{UiLanguage, t};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::window_geometry::WindowGeometrySync;
+use crate::{font_fallback, thread_pool, util, widgets::*};
+
+fn load<T: DeserializeOwned>(
+    storage: Option<&dyn eframe::Storage>,
+    key: &'static str,
+    default: T,
+) -> T {
+    match storage {
         Some(storage) => eframe::get_value(storage, key).unwrap_or(default),
         None => default,
     }
 }
 
-enum SolverEvent {
-    NodesVisited(usize),
-    Actions(Vec<Action>),
-    LoadedFromHistory(),
-    Finished(Option<SolverException>),
+/// Storage keys that are kept when resetting the settings
+const SAVED_ROTATIONS_KEYS: [&str; 2] = ["SAVED_ROTATIONS_CONFIG", "SAVED_ROTATIONS"];
+
+/// Rotation found for one entry of a batch solve
+#[derive(Debug, Clone)]
+pub struct BatchSolution {
+    pub actions: Vec<Action>,
+    /// Reached Quality including the initial Quality
+    pub quality: u32,
 }
 
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct SolverConfig {
     pub quality_target: QualityTarget,
     pub backload_progress: bool,
+    /// Backload Progress only where that doesn't cost any Quality, has no effect if `backload_progress` is set
+    #[serde(default)]
+    pub prefer_backload_progress: bool,
     pub adversarial: bool,
+    /// Exclude Trained Eye even when the job level allows it, e.g. for rotations that also work under level sync
+    #[serde(default)]
+    pub forbid_trained_eye: bool,
+    #[serde(default)]
+    pub opener: OpenerPreference,
+    #[serde(default)]
+    pub min_synthesis_durability: u16,
+    /// Assumed condition of the first two steps, e.g. when waiting for a Good opener
+    #[serde(default)]
+    pub opener_conditions: [Condition; 2],
+    /// CP missing at the start of the craft, e.g. when starting the next craft before CP has regenerated
+    #[serde(default)]
+    pub starting_cp_penalty: u16,
+    /// Durability missing at the start of the craft
+    #[serde(default)]
+    pub starting_durability_penalty: u16,
+}
+
+impl SolverConfig {
+    pub fn macro_solver_settings(
+        &self,
+        simulator_settings: raphael_sim::Settings,
+    ) -> raphael_solver::SolverSettings {
+        raphael_solver::SolverSettings {
+            prefer_backload_progress: self.prefer_backload_progress,
+            opener: self.opener,
+            min_synthesis_durability: self.min_synthesis_durability,
+            opener_conditions: self.opener_conditions,
+            ..raphael_solver::SolverSettings::new(simulator_settings)
+        }
+    }
+
+    /// State at the start of the craft, with the starting penalties subtracted from the max CP and Durability
+    pub fn initial_state(&self, settings: &raphael_sim::Settings) -> raphael_sim::SimulationState {
+        let mut state = raphael_sim::SimulationState::new(settings);
+        state.cp = state.cp.saturating_sub(self.starting_cp_penalty);
+        state.durability = state
+            .durability
+            .saturating_sub(self.starting_durability_penalty);
+        state
+    }
+
+    /// Simulates `actions` from the initial state, in the assumed opener conditions
+    pub fn simulate(
+        &self,
+        settings: &raphael_sim::Settings,
+        actions: &[Action],
+    ) -> (raphael_sim::SimulationState, Vec<Result<(), &'static str>>) {
+        self.initial_state(settings).continue_macro_in_conditions(
+            settings,
+            actions,
+            &self.opener_conditions,
+        )
+    }
+
+    /// Resets the opener conditions that the recipe can't roll to Normal,
+    /// and the second condition if it can't be rolled in a row with the first one and the Normal steps after it
+    pub fn restrict_opener_conditions(&mut self, recipe: raphael_data::Recipe) {
+        let conditions = raphael_data::modeled_conditions(recipe);
+        for condition in &mut self.opener_conditions {
+            if !conditions.contains(condition) {
+                *condition = Condition::Normal;
+            }
+        }
+        let [first_condition, second_condition] = &mut self.opener_conditions;
+        if !second_condition.can_follow(*first_condition)
+            || !Condition::Normal.can_follow(*second_condition)
+        {
+            *second_condition = match first_condition {
+                Condition::Excellent => Condition::Poor,
+                _ => Condition::Normal,
+            };
+        }
+    }
+}
+
+/// Game settings, initial Quality and solver config that a rotation is solved for
+pub type SolveParams = (raphael_sim::Settings, u16, SolverConfig);
+
+/// Rotation shown when the app was closed, restored on the next launch if it was solved for the same params
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LastSolve {
+    actions: Vec<Action>,
+    params_hash: u64,
+    duration: web_time::Duration,
+}
+
+/// Maximum number of recipes solved by the leveling planner, each one is a full solve
+pub const LEVELING_RECIPE_LIMIT: usize = 8;
+
+/// Number of steps after the first whose condition the decision tree branches on, the number of solves grows exponentially with it
+pub const DECISION_TREE_DEPTH: usize = 2;
+
+/// Maximum number of collectables solved by the collectable sweep, each one is solved once per tier
+pub const COLLECTABLE_SWEEP_RECIPE_LIMIT: usize = 6;
+
+/// Approximate time per craft that isn't spent on actions, e.g. for starting the synthesis
+pub const CRAFT_OVERHEAD_SECONDS: u32 = 3;
+
+/// Time it takes to use the actions of the macro, in seconds
+pub fn macro_duration_seconds(actions: &[Action]) -> u32 {
+    actions
+        .iter()
+        .map(|action| u32::from(action.time_cost()))
+        .sum()
 }
 
+/// CP reductions for which the best achievable Quality is shown in the CP tradeoff report
+pub const CP_TRADEOFF_REDUCTIONS: [u16; 5] = [10, 20, 30, 40, 50];
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MinimumStats {
     pub craftsmanship: Option<u16>,
@@ -49,6 +177,7 @@ pub struct MinimumStats {
 struct DevPanelState {
     show_dev_panel: bool,
     render_info_state: RenderInfoState,
+    test_case_export_state: TestCaseExportState,
 }
 
 pub struct MacroSolverApp {
@@ -58,33 +187,94 @@ pub struct MacroSolverApp {
     custom_recipe_overrides_config: CustomRecipeOverridesConfiguration,
     selected_food: Option<Consumable>,
     selected_potion: Option<Consumable>,
+    /// Food and potion that were last used with each job
+    job_consumables: [Option<(Option<Consumable>, Option<Consumable>)>; 8],
+    /// Job that `job_consumables` was last synced for
+    consumables_job: u8,
     crafter_config: CrafterConfig,
     solver_config: SolverConfig,
+    solver_presets: Vec<SolverPreset>,
+    recipe_solver_configs: HashMap<u32, SolverConfig>,
+    /// HQ materials that were last used for each recipe
+    recipe_hq_materials: HashMap<u32, [u8; 6]>,
+    /// Scrips rewarded for reaching each collectable tier, entered by the user for each collectable item
+    collectable_scrip_rewards: HashMap<u32, [u16; 3]>,
+    /// Owned HQ items, the HQ materials are capped at these amounts when set
+    hq_inventory: Option<HqInventory>,
+    hq_inventory_window: HqInventoryWindow,
     macro_view_config: MacroViewConfig,
     saved_rotations_config: SavedRotationsConfig,
     saved_rotations_data: SavedRotationsData,
     saved_rotations_sync_requests: VecDeque<Option<Rotation>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    webhook_config: crate::config::WebhookConfig,
+    #[cfg(not(target_arch = "wasm32"))]
+    saved_rotations_file: crate::file_storage::SavedRotationsFile,
+    #[cfg(target_arch = "wasm32")]
+    saved_rotations_read: Option<crate::web_storage::PendingRead>,
+    #[cfg(target_arch = "wasm32")]
+    /// Whether the saved rotations are stored in IndexedDB instead of localStorage, `None` until the first read has finished
+    indexed_db_available: Option<bool>,
+    #[cfg(target_arch = "wasm32")]
+    /// Saved rotations as last written to IndexedDB, to skip writing unchanged data
+    indexed_db_saved_rotations: Option<String>,
 
     #[cfg(any(debug_assertions, feature = "dev-panel"))]
     dev_panel_state: DevPanelState,
 
-    latest_version: Arc<Mutex<semver::Version>>,
+    latest_release: Arc<Mutex<LatestRelease>>,
     current_version: semver::Version,
+    /// Version whose update notification was dismissed for good
+    skipped_version: Option<String>,
 
     main_window_focused_at: Option<std::time::Instant>,
+    /// Whether the focus was last moved with the keyboard, in which case the focused widget is outlined
+    keyboard_navigation: bool,
+    #[cfg(not(target_arch = "wasm32"))]
+    window_geometry: WindowGeometrySync,
     stats_edit_window_open: bool,
     saved_rotations_window_open: bool,
-    missing_stats_error_window_open: bool,
+    repair_window: RepairWindow,
+    missing_stats_window: MissingStatsWindow,
+    solve_confirmation_window: SolveConfirmationWindow,
+    solve_all_collectable_tiers: bool,
+    tier_results_window: TierResultsWindow,
+    cp_tradeoff_window: CpTradeoffWindow,
+    gearsets: Vec<Gearset>,
+    hq_sweep_window: HqSweepWindow,
+    decision_tree_window: DecisionTreeWindow,
+    leveling_window: LevelingWindow,
+    collectable_sweep_window: CollectableSweepWindow,
+    /// Outdated saved rotations that are being solved again, with the rotations that will replace them once solved
+    rotation_resolves: Vec<(u64, Rotation)>,
+    rotation_resolve_pending: bool,
+    gearset_window: GearsetWindow,
+    config_compare_window: ConfigCompareWindow,
+    backup_window: BackupWindow,
 
     actions: Vec<Action>,
+    /// Actions that precede the actions reported by the solver, used when repairing a rotation
+    solver_action_prefix: Vec<Action>,
     solver_pending: bool,
-    solver_progress: usize,
-    start_time: web_time::Instant,
+    solver_progress: SolveProgress,
     duration: web_time::Duration,
     solver_error: Option<SolverException>,
-
-    solver_events: Arc<Mutex<VecDeque<SolverEvent>>>,
+    /// Crash report written by the panic hook that hasn't been acknowledged yet
+    #[cfg(not(target_arch = "wasm32"))]
+    crash_report_path: Option<std::path::PathBuf>,
+    #[cfg(not(target_arch = "wasm32"))]
+    copy_hotkey: crate::copy_hotkey::CopyHotkey,
+    /// Lower quality target and its Quality, offered for a retry when the last solved rotation fell short of the target
+    target_downgrade: Option<(QualityTarget, u16)>,
+    solve_report: Option<SolveReport>,
+    last_solve_params: Option<SolveParams>,
+    /// Rotations of the last 100% quality solve and of the lower quality targets found with its tables, shown when the quality target is switched
+    target_rotations: Vec<(SolveParams, Vec<Action>)>,
+    workspace_tabs: WorkspaceTabs,
+
+    solver_events: SolverEvents,
     solver_interrupt: raphael_solver::AtomicFlag,
+    solver_cache: SolverCache,
 
     minimum_stats: MinimumStats,
     minimum_stats_params_hash: u64,
@@ -93,69 +283,288 @@ pub struct MacroSolverApp {
 impl MacroSolverApp {
     /// Called once before the first frame.
     pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
-        let app_config = load(cc, "APP_CONFIG", AppConfig::default());
-        cc.egui_ctx
-            .set_zoom_factor(f32::from(app_config.zoom_percentage) * 0.01);
+        let mut app = Self::from_storage(&cc.egui_ctx, cc.storage);
+        fetch_latest_release(app.latest_release.clone(), app.app_config.update_check);
+        // saved rotations are stored in IndexedDB on web, which can only be read asynchronously
+        #[cfg(target_arch = "wasm32")]
+        app.saved_rotations_sync_requests.push_back(None);
+        // saved rotations are stored in a file of their own on native, the ones of the eframe storage were saved by older versions
+        #[cfg(not(target_arch = "wasm32"))]
+        app.saved_rotations_file
+            .sync(&mut app.saved_rotations_data, &app.saved_rotations_config);
+        #[cfg(target_arch = "wasm32")]
+        if let Some(query) = web_sys::window().and_then(|window| window.location().search().ok()) {
+            app.apply_launch_options(&cc.egui_ctx, LaunchOptions::from_query(&query));
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        app.apply_launch_options(
+            &cc.egui_ctx,
+            LaunchOptions::from_args(std::env::args().skip(1)),
+        );
+        app
+    }
 
-        cc.egui_ctx.all_styles_mut(|style| {
+    fn from_storage(ctx: &egui::Context, storage: Option<&dyn eframe::Storage>) -> Self {
+        let app_config = load(storage, "APP_CONFIG", AppConfig::default());
+        ctx.set_zoom_factor(f32::from(app_config.zoom_percentage) * 0.01);
+
+        ctx.all_styles_mut(|style| {
             style.visuals.interact_cursor = Some(CursorIcon::PointingHand);
             style.url_in_tooltip = true;
             style.always_scroll_the_only_direction = false;
             style.spacing.item_spacing = egui::vec2(8.0, 8.0);
         });
 
-        let locale = load(cc, "LOCALE", Locale::CN);
-        set_fonts(&cc.egui_ctx, locale);
-
-        let latest_version = Arc::new(Mutex::new(semver::Version::new(0, 0, 0)));
-        #[cfg(not(target_arch = "wasm32"))]
-        fetch_latest_version(latest_version.clone());
+        let locale = load(storage, "LOCALE", Locale::CN);
+        let ui_language = app_config
+            .ui_language
+            .unwrap_or(UiLanguage::from_locale(locale));
+        // the default solver presets are named in the UI language
+        crate::translations::set_ui_language(ui_language);
+        set_fonts(
+            ctx,
+            locale,
+            ui_language,
+            app_config.output_locale.unwrap_or(locale),
+        );
 
-        Self {
+        let (workspaces, active_workspace) = load(storage, "WORKSPACES", (Vec::new(), 0));
+        let mut app = Self {
             locale,
             app_config,
-            recipe_config: load(cc, "RECIPE_CONFIG", RecipeConfiguration::default()),
+            recipe_config: load(storage, "RECIPE_CONFIG", RecipeConfiguration::default()),
             custom_recipe_overrides_config: load(
-                cc,
+                storage,
                 "CUSTOM_RECIPE_OVERRIDES_CONFIG",
                 CustomRecipeOverridesConfiguration::default(),
             ),
-            selected_food: load(cc, "SELECTED_FOOD", None),
-            selected_potion: load(cc, "SELECTED_POTION", None),
-            crafter_config: load(cc, "CRAFTER_CONFIG", CrafterConfig::default()),
-            solver_config: load(cc, "SOLVER_CONFIG", SolverConfig::default()),
-            macro_view_config: load(cc, "MACRO_VIEW_CONFIG", MacroViewConfig::default()),
+            selected_food: load(storage, "SELECTED_FOOD", None),
+            selected_potion: load(storage, "SELECTED_POTION", None),
+            job_consumables: load(storage, "JOB_CONSUMABLES", [None; 8]),
+            consumables_job: 0,
+            crafter_config: load(storage, "CRAFTER_CONFIG", CrafterConfig::default()),
+            solver_config: load(storage, "SOLVER_CONFIG", SolverConfig::default()),
+            solver_presets: load(storage, "SOLVER_PRESETS", SolverPreset::defaults()),
+            recipe_solver_configs: load(storage, "RECIPE_SOLVER_CONFIGS", HashMap::new()),
+            recipe_hq_materials: load(storage, "RECIPE_HQ_MATERIALS", HashMap::new()),
+            collectable_scrip_rewards: load(storage, "COLLECTABLE_SCRIP_REWARDS", HashMap::new()),
+            hq_inventory: load(storage, "HQ_INVENTORY", None),
+            hq_inventory_window: HqInventoryWindow::default(),
+            macro_view_config: load(storage, "MACRO_VIEW_CONFIG", MacroViewConfig::default()),
             saved_rotations_config: load(
-                cc,
+                storage,
                 "SAVED_ROTATIONS_CONFIG",
                 SavedRotationsConfig::default(),
             ),
-            saved_rotations_data: load(cc, "SAVED_ROTATIONS", SavedRotationsData::default()),
+            saved_rotations_data: load(storage, "SAVED_ROTATIONS", SavedRotationsData::default()),
             saved_rotations_sync_requests: VecDeque::new(),
+            #[cfg(not(target_arch = "wasm32"))]
+            webhook_config: load(
+                storage,
+                "WEBHOOK_CONFIG",
+                crate::config::WebhookConfig::default(),
+            ),
+            #[cfg(not(target_arch = "wasm32"))]
+            saved_rotations_file: crate::file_storage::SavedRotationsFile::default(),
+            #[cfg(target_arch = "wasm32")]
+            saved_rotations_read: None,
+            #[cfg(target_arch = "wasm32")]
+            indexed_db_available: None,
+            #[cfg(target_arch = "wasm32")]
+            indexed_db_saved_rotations: None,
 
             #[cfg(any(debug_assertions, feature = "dev-panel"))]
             dev_panel_state: DevPanelState::default(),
 
-            latest_version: latest_version.clone(),
+            latest_release: Arc::new(Mutex::new(LatestRelease::default())),
             current_version: semver::Version::parse(env!("CARGO_PKG_VERSION")).unwrap(),
+            skipped_version: load(storage, "SKIPPED_VERSION", None),
 
             main_window_focused_at: None,
+            keyboard_navigation: false,
+            #[cfg(not(target_arch = "wasm32"))]
+            window_geometry: WindowGeometrySync::default(),
             stats_edit_window_open: false,
             saved_rotations_window_open: false,
-            missing_stats_error_window_open: false,
+            repair_window: RepairWindow::default(),
+            missing_stats_window: MissingStatsWindow::default(),
+            solve_confirmation_window: SolveConfirmationWindow::default(),
+            solve_all_collectable_tiers: false,
+            tier_results_window: TierResultsWindow::default(),
+            cp_tradeoff_window: CpTradeoffWindow::default(),
+            gearsets: load(storage, "GEARSETS", Vec::new()),
+            hq_sweep_window: HqSweepWindow::default(),
+            decision_tree_window: DecisionTreeWindow::default(),
+            leveling_window: LevelingWindow::default(),
+            collectable_sweep_window: CollectableSweepWindow::default(),
+            rotation_resolves: Vec::new(),
+            rotation_resolve_pending: false,
+            gearset_window: GearsetWindow::default(),
+            config_compare_window: ConfigCompareWindow::default(),
+            backup_window: BackupWindow::default(),
 
             actions: Vec::new(),
+            solver_action_prefix: Vec::new(),
             solver_pending: false,
-            solver_progress: 0,
-            start_time: web_time::Instant::now(),
+            solver_progress: SolveProgress::default(),
             duration: web_time::Duration::ZERO,
             solver_error: None,
-
-            solver_events: Arc::new(Mutex::new(VecDeque::new())),
+            #[cfg(not(target_arch = "wasm32"))]
+            crash_report_path: crate::crash_report::take_pending_report(),
+            #[cfg(not(target_arch = "wasm32"))]
+            copy_hotkey: crate::copy_hotkey::CopyHotkey::new(
+                ctx,
+                load(storage, "COPY_HOTKEY", String::new()),
+            ),
+            target_downgrade: None,
+            solve_report: None,
+            last_solve_params: None,
+            target_rotations: Vec::new(),
+            workspace_tabs: WorkspaceTabs::new(workspaces, active_workspace),
+
+            solver_events: SolverEvents {
+                queue: Arc::new(Mutex::new(VecDeque::new())),
+                ctx: ctx.clone(),
+            },
             solver_interrupt: raphael_solver::AtomicFlag::new(),
+            solver_cache: Arc::new(Mutex::new(None)),
 
             minimum_stats: MinimumStats::default(),
             minimum_stats_params_hash: 0,
+        };
+        app.consumables_job = app.crafter_config.selected_job;
+        app.restore_last_solve(ctx, storage);
+        let workspace = app.current_workspace();
+        app.workspace_tabs.replace_active(workspace);
+        app
+    }
+
+    /// Restores the rotation of the last session as if it had just been solved,
+    /// unless it was solved for different params than the ones that have been restored
+    fn restore_last_solve(&mut self, ctx: &egui::Context, storage: Option<&dyn eframe::Storage>) {
+        let Some(last_solve) = load::<Option<LastSolve>>(storage, "LAST_SOLVE", None) else {
+            return;
+        };
+        if egui::Id::new(self.solve_params()).value() == last_solve.params_hash {
+            self.set_last_solve_params(ctx);
+            self.actions = last_solve.actions;
+            self.duration = last_solve.duration;
+        }
+    }
+
+    /// Params a solve would use right now, compared by the simulator to those of the last solve
+    fn solve_params(&self) -> SolveParams {
+        let game_settings = util::get_game_settings(
+            &self.recipe_config,
+            &self.custom_recipe_overrides_config,
+            &self.solver_config,
+            &self.crafter_config,
+            self.selected_food,
+            self.selected_potion,
+        );
+        let initial_quality = util::get_initial_quality(
+            &self.recipe_config,
+            &self.custom_recipe_overrides_config,
+            &self.crafter_config,
+        );
+        (game_settings, initial_quality, self.solver_config)
+    }
+
+    /// Shows the rotation of another quality target of the last 100% quality solve once the params match it, e.g. after switching the quality target
+    fn load_target_rotation(&mut self, ctx: &egui::Context) {
+        if self.solver_pending || self.target_rotations.is_empty() {
+            return;
+        }
+        let params = self.solve_params();
+        if self.last_solve_params == Some(params) {
+            return;
+        }
+        if let Some((_, actions)) = self
+            .target_rotations
+            .iter()
+            .find(|(target_params, _)| *target_params == params)
+        {
+            self.actions = actions.clone();
+            self.solver_action_prefix = Vec::new();
+            self.target_downgrade = None;
+            self.set_last_solve_params(ctx);
+        }
+    }
+
+    fn set_last_solve_params(&mut self, ctx: &egui::Context) {
+        self.restore_last_solve_params(ctx, Some(self.solve_params()));
+    }
+
+    fn restore_last_solve_params(&mut self, ctx: &egui::Context, params: Option<SolveParams>) {
+        self.last_solve_params = params;
+        ctx.data_mut(|data| match params {
+            Some(params) => data.insert_temp(Id::new("LAST_SOLVE_PARAMS"), params),
+            None => data.remove::<SolveParams>(Id::new("LAST_SOLVE_PARAMS")),
+        });
+    }
+
+    fn current_workspace(&self) -> Workspace {
+        Workspace {
+            recipe_config: self.recipe_config,
+            custom_recipe_overrides_config: self.custom_recipe_overrides_config,
+            selected_food: self.selected_food,
+            selected_potion: self.selected_potion,
+            solver_config: self.solver_config,
+            actions: self.actions.clone(),
+            duration: self.duration,
+            last_solve_params: self.last_solve_params,
+        }
+    }
+
+    /// Selects the food and potion that were last used with the job when switching to another job.
+    /// The selection is kept if it was changed together with the job, e.g. by loading a saved rotation.
+    fn sync_job_consumables(&mut self) {
+        let job = self.crafter_config.selected_job;
+        let consumables = (self.selected_food, self.selected_potion);
+        if job != self.consumables_job
+            && self.job_consumables[usize::from(self.consumables_job)] == Some(consumables)
+            && let Some((food, potion)) = self.job_consumables[usize::from(job)]
+        {
+            self.selected_food = food;
+            self.selected_potion = potion;
+        }
+        self.job_consumables[usize::from(job)] = Some((self.selected_food, self.selected_potion));
+        self.consumables_job = job;
+    }
+
+    fn switch_workspace(&mut self, ctx: &egui::Context, index: usize) {
+        let workspace = self.workspace_tabs.switch(self.current_workspace(), index);
+        self.recipe_config = workspace.recipe_config;
+        self.custom_recipe_overrides_config = workspace.custom_recipe_overrides_config;
+        self.selected_food = workspace.selected_food;
+        self.selected_potion = workspace.selected_potion;
+        self.solver_config = workspace.solver_config;
+        self.actions = workspace.actions;
+        self.duration = workspace.duration;
+        self.solve_report = None;
+        self.restore_last_solve_params(ctx, workspace.last_solve_params);
+    }
+
+    fn draw_workspace_tabs(&mut self, ui: &mut egui::Ui) {
+        let request = ui
+            .add_enabled_ui(!self.solver_pending, |ui| {
+                self.workspace_tabs
+                    .ui(ui, &self.recipe_config.recipe, self.locale)
+            })
+            .inner;
+        match request {
+            Some(WorkspaceRequest::Switch(index)) => self.switch_workspace(ui.ctx(), index),
+            Some(WorkspaceRequest::New) => {
+                let index = self.workspace_tabs.push(self.current_workspace());
+                self.switch_workspace(ui.ctx(), index);
+            }
+            Some(WorkspaceRequest::Close(index)) => {
+                if index == self.workspace_tabs.active() {
+                    self.switch_workspace(ui.ctx(), self.workspace_tabs.neighbor(index));
+                }
+                self.workspace_tabs.remove(index);
+            }
+            None => (),
         }
     }
 }
@@ -163,56 +572,336 @@ impl MacroSolverApp {
 impl eframe::App for MacroSolverApp {
     /// Called each time the UI needs repainting, which may be many times per second.
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        set_fonts(ctx, self.locale);
+        let ui_language = self.ui_language();
+        crate::translations::set_ui_language(ui_language);
+        set_fonts(ctx, self.locale, ui_language, self.output_locale());
+        ItemNameLabel::set_copy_locale(ctx, self.output_locale());
+        let entered_text: String = ctx.input(|input| {
+            input
+                .events
+                .iter()
+                .filter_map(|event| match event {
+                    egui::Event::Text(text) | egui::Event::Paste(text) => Some(text.as_str()),
+                    egui::Event::Ime(egui::ImeEvent::Commit(text)) => Some(text.as_str()),
+                    _ => None,
+                })
+                .collect()
+        });
+        font_fallback::request_glyphs(ctx, &entered_text);
 
         self.set_window_title(ctx);
 
         self.process_solver_events();
 
+        self.load_target_rotation(ctx);
+
         self.process_storage_syncing(ctx, _frame);
 
-        if self
-            .current_version
-            .lt(self.latest_version.lock().unwrap().deref())
-        {
-            egui::Modal::new(egui::Id::new("version_check")).show(ctx, |ui| {
-                let mut latest_version = self.latest_version.lock().unwrap();
-                ui.style_mut().spacing.item_spacing = egui::vec2(3.0, 3.0);
-                ui.horizontal(|ui| {
-                    ui.label(egui::RichText::new("New version available!").strong());
-                    ui.label(format!("(v{})", latest_version.deref()));
-                });
-                ui.add(egui::Hyperlink::from_label_and_url(
-                    "Download from GitHub",
-                    "https://github.com/Asvel/ffxiv-raphael-cn/releases/latest",
-                ));
-                ui.separator();
-                ui.vertical_centered_justified(|ui| {
-                    if ui.button("Close").clicked() {
-                        *latest_version.deref_mut() = semver::Version::new(0, 0, 0);
-                    }
-                });
+        self.sync_job_consumables();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.copy_hotkey.take_pressed() {
+            ctx.data_mut(|data| data.insert_temp(Id::new(COPY_NEXT_MACRO_REQUESTED), true));
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        self.window_geometry
+            .sync(ctx, &mut self.app_config.window_geometry);
+
+        let update_available = {
+            let latest_release = self.latest_release.lock().unwrap();
+            self.current_version < latest_release.version
+                && self
+                    .skipped_version
+                    .as_ref()
+                    .is_none_or(|skipped| *skipped != latest_release.version.to_string())
+        };
+        if update_available {
+            let modal = egui::Modal::new(egui::Id::new("version_check"))
+                .show(ctx, |ui| self.latest_release.lock().unwrap().ui(ui));
+            let mut latest_release = self.latest_release.lock().unwrap();
+            match modal.inner {
+                Some(UpdateRequest::Skip) => {
+                    self.skipped_version = Some(latest_release.version.to_string())
+                }
+                Some(UpdateRequest::Close) => {
+                    latest_release.version = semver::Version::new(0, 0, 0)
+                }
+                None if modal_dismissed(&modal) => {
+                    latest_release.version = semver::Version::new(0, 0, 0)
+                }
+                None => {}
+            }
+        }
+
+        if self.missing_stats_window.open {
+            let modal = egui::Modal::new(egui::Id::new("min_stats_warning")).show(ctx, |ui| {
+                self.missing_stats_window.ui(
+                    ui,
+                    &self.recipe_config.recipe,
+                    *self.crafter_config.active_stats(),
+                    &mut self.selected_food,
+                    &mut self.selected_potion,
+                    self.locale,
+                );
             });
+            if modal_dismissed(&modal) {
+                self.missing_stats_window.open = false;
+            }
         }
 
-        if self.missing_stats_error_window_open {
-            egui::Modal::new(egui::Id::new("min_stats_warning")).show(ctx, |ui| {
-                let req_cms = self.recipe_config.recipe.req_craftsmanship;
-                let req_ctrl = self.recipe_config.recipe.req_control;
-                ui.style_mut().spacing.item_spacing = egui::vec2(3.0, 3.0);
-                ui.label(egui::RichText::new("Error").strong());
-                ui.separator();
-                ui.label("Your stats are below the minimum requirement for this recipe.");
-                ui.label(format!(
-                    "Requirement: {req_cms} Craftsmanship, {req_ctrl} Control."
-                ));
-                ui.separator();
-                ui.vertical_centered_justified(|ui| {
-                    if ui.button("Close").clicked() {
-                        self.missing_stats_error_window_open = false;
-                    }
-                });
+        if self.solve_confirmation_window.open {
+            let game_settings = util::get_game_settings(
+                &self.recipe_config,
+                &self.custom_recipe_overrides_config,
+                &self.solver_config,
+                &self.crafter_config,
+                self.selected_food,
+                self.selected_potion,
+            );
+            let modal = egui::Modal::new(egui::Id::new("solve_confirmation")).show(ctx, |ui| {
+                self.solve_confirmation_window.ui(ui, &game_settings)
+            });
+            if modal.inner {
+                self.solve(ctx);
+            } else if modal_key_pressed(&modal, egui::Key::Escape) {
+                self.solve_confirmation_window.open = false;
+            } else if modal_key_pressed(&modal, egui::Key::Enter) {
+                self.solve_confirmation_window.open = false;
+                self.solve(ctx);
+            }
+        }
+
+        if self.repair_window.open {
+            let repair_target = self.repair_target();
+            let modal = egui::Modal::new(egui::Id::new("rotation_repair")).show(ctx, |ui| {
+                self.repair_window
+                    .ui(ui, &repair_target, self.app_config.keep_solver_tables)
+            });
+            if modal.inner {
+                self.repair(ctx);
+            }
+            if modal_key_pressed(&modal, egui::Key::Escape) {
+                self.repair_window.open = false;
+            }
+        }
+
+        if self.backup_window.open {
+            let modal = egui::Modal::new(egui::Id::new("backup"))
+                .show(ctx, |ui| self.backup_window.ui(ui, self.solver_pending));
+            if modal_key_pressed(&modal, egui::Key::Escape) {
+                self.backup_window.open = false;
+            }
+            if let Some(request) = modal.inner {
+                self.handle_backup_request(ctx, request);
+            }
+        }
+
+        if self.tier_results_window.open && !self.solver_pending {
+            let game_settings = util::get_game_settings(
+                &self.recipe_config,
+                &self.custom_recipe_overrides_config,
+                &self.solver_config,
+                &self.crafter_config,
+                self.selected_food,
+                self.selected_potion,
+            );
+            let item_id = self.recipe_config.recipe.item_id;
+            let mut scrip_rewards = self
+                .collectable_scrip_rewards
+                .get(&item_id)
+                .copied()
+                .unwrap_or_default();
+            let initial_quality = util::get_initial_quality(
+                &self.recipe_config,
+                &self.custom_recipe_overrides_config,
+                &self.crafter_config,
+            );
+            let modal = egui::Modal::new(egui::Id::new("tier_results")).show(ctx, |ui| {
+                self.tier_results_window.ui(
+                    ui,
+                    &game_settings,
+                    initial_quality,
+                    &self.solver_config,
+                    &mut scrip_rewards,
+                )
+            });
+            if scrip_rewards
+                != self
+                    .collectable_scrip_rewards
+                    .get(&item_id)
+                    .copied()
+                    .unwrap_or_default()
+            {
+                self.collectable_scrip_rewards
+                    .insert(item_id, scrip_rewards);
+            }
+            if modal_dismissed(&modal) {
+                self.tier_results_window.open = false;
+            }
+            if let Some((quality_target, actions)) = modal.inner {
+                self.solver_config.quality_target = quality_target;
+                self.actions = actions;
+                self.set_last_solve_params(ctx);
+                self.submit_rotation();
+            }
+        }
+
+        if self.gearset_window.open && !self.solver_pending {
+            let modal = egui::Modal::new(egui::Id::new("gearsets")).show(ctx, |ui| {
+                self.gearset_window.ui(
+                    ui,
+                    &mut self.gearsets,
+                    self.crafter_config.active_stats(),
+                    thread_pool::is_initialized(),
+                )
             });
+            match modal.inner {
+                Some(GearsetRequest::Use(index)) => {
+                    self.crafter_config = self.gearset_crafter_config(&self.gearsets[index]);
+                    self.gearset_window.open = false;
+                }
+                Some(GearsetRequest::Evaluate) => self.evaluate_gearsets(),
+                None => (),
+            }
+            if modal_key_pressed(&modal, egui::Key::Escape) {
+                self.gearset_window.open = false;
+            }
+        }
+
+        if self.hq_inventory_window.open {
+            let modal = egui::Modal::new(egui::Id::new("hq_inventory")).show(ctx, |ui| {
+                self.hq_inventory_window.ui(ui, &mut self.hq_inventory);
+            });
+            if modal_key_pressed(&modal, egui::Key::Escape) {
+                self.hq_inventory_window.open = false;
+            }
+        }
+
+        if self.leveling_window.open && !self.solver_pending {
+            let modal = egui::Modal::new(egui::Id::new("leveling")).show(ctx, |ui| {
+                self.leveling_window.ui(
+                    ui,
+                    *self.crafter_config.active_stats(),
+                    [self.selected_food, self.selected_potion],
+                    self.locale,
+                )
+            });
+            if modal_dismissed(&modal) {
+                self.leveling_window.open = false;
+            }
+            match modal.inner {
+                Some(LevelingRequest::Solve) => self.solve_leveling_recipes(),
+                Some(LevelingRequest::Use(recipe_id, actions)) => {
+                    self.recipe_config
+                        .select_recipe(recipe_id, &mut self.recipe_hq_materials);
+                    self.recipe_config.quality_source = QualitySource::HqMaterialList([0; 6]);
+                    self.custom_recipe_overrides_config.use_custom_recipe = false;
+                    self.solver_config = Self::leveling_solver_config(self.solver_config);
+                    self.actions = actions;
+                    self.set_last_solve_params(ctx);
+                    self.submit_rotation();
+                }
+                None => (),
+            }
+        }
+
+        if self.collectable_sweep_window.open && !self.solver_pending {
+            let modal = egui::Modal::new(egui::Id::new("collectable_sweep")).show(ctx, |ui| {
+                self.collectable_sweep_window.ui(
+                    ui,
+                    *self.crafter_config.active_stats(),
+                    [self.selected_food, self.selected_potion],
+                    &mut self.collectable_scrip_rewards,
+                    self.locale,
+                )
+            });
+            if modal_dismissed(&modal) {
+                self.collectable_sweep_window.open = false;
+            }
+            match modal.inner {
+                Some(CollectableSweepRequest::Solve) => self.solve_collectable_sweep(),
+                Some(CollectableSweepRequest::Use(recipe_id, quality_target, actions)) => {
+                    self.recipe_config
+                        .select_recipe(recipe_id, &mut self.recipe_hq_materials);
+                    self.recipe_config.quality_source = QualitySource::HqMaterialList([0; 6]);
+                    self.custom_recipe_overrides_config.use_custom_recipe = false;
+                    self.solver_config.quality_target = quality_target;
+                    self.solver_config
+                        .restrict_opener_conditions(self.recipe_config.recipe);
+                    self.actions = actions;
+                    self.set_last_solve_params(ctx);
+                    self.submit_rotation();
+                }
+                None => (),
+            }
+        }
+
+        if self.config_compare_window.open && !self.solver_pending {
+            // max CP doesn't depend on the solver configuration
+            let (game_settings, _) = self.solver_settings();
+            let modal = egui::Modal::new(egui::Id::new("compare_configs")).show(ctx, |ui| {
+                self.config_compare_window.ui(
+                    ui,
+                    &self.recipe_config.recipe,
+                    self.solver_config.quality_target,
+                    &game_settings,
+                    self.crafter_config.selected_job,
+                    self.locale,
+                )
+            });
+            if modal_dismissed(&modal) {
+                self.config_compare_window.open = false;
+            }
+            match modal.inner {
+                Some(ConfigCompareRequest::Solve) => self.solve_compare_configs(),
+                Some(ConfigCompareRequest::Use(solver_config, solution)) => {
+                    self.config_compare_window.open = false;
+                    self.solver_config = solver_config;
+                    self.actions = solution.actions;
+                    self.set_last_solve_params(ctx);
+                    self.submit_rotation();
+                }
+                None => (),
+            }
+        }
+
+        if self.cp_tradeoff_window.open && !self.solver_pending {
+            let (game_settings, initial_quality) = self.solver_settings();
+            let modal = egui::Modal::new(egui::Id::new("cp_tradeoff")).show(ctx, |ui| {
+                self.cp_tradeoff_window.ui(
+                    ui,
+                    &game_settings,
+                    initial_quality,
+                    &self.solver_config,
+                    &self.actions,
+                );
+            });
+            if modal_dismissed(&modal) {
+                self.cp_tradeoff_window.open = false;
+            }
+        }
+
+        if self.hq_sweep_window.open && !self.solver_pending {
+            let modal = egui::Modal::new(egui::Id::new("hq_sweep"))
+                .show(ctx, |ui| self.hq_sweep_window.ui(ui));
+            if modal_dismissed(&modal) {
+                self.hq_sweep_window.open = false;
+            }
+            if let Some((hq_ingredients, actions)) = modal.inner {
+                self.recipe_config.quality_source = QualitySource::HqMaterialList(hq_ingredients);
+                self.actions = actions;
+                self.set_last_solve_params(ctx);
+                self.submit_rotation();
+            }
+        }
+
+        if self.decision_tree_window.open && !self.solver_pending {
+            let modal = egui::Modal::new(egui::Id::new("decision_tree"))
+                .show(ctx, |ui| self.decision_tree_window.ui(ui, self.locale));
+            if modal_dismissed(&modal) {
+                self.decision_tree_window.open = false;
+            }
         }
 
         #[cfg(target_arch = "wasm32")]
@@ -220,102 +909,56 @@ impl eframe::App for MacroSolverApp {
             self.solver_error = Some(SolverException::AllocError);
         }
         if let Some(error) = self.solver_error.clone() {
-            egui::Modal::new(egui::Id::new("solver_error")).show(ctx, |ui| {
-                ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
-                let unrecoverable_error;
-                match error {
-                    SolverException::NoSolution => {
-                        ui.label(egui::RichText::new("No solution").strong());
-                        ui.separator();
-                        ui.label("Make sure that the recipe is set correctly and that your stats are enough to craft this item.");
-                        unrecoverable_error = false;
-                    }
-                    SolverException::Interrupted => {
-                        self.solver_error = None;
-                        unrecoverable_error = false;
-                    },
-                    SolverException::InternalError(message) => {
-                        ui.label(egui::RichText::new("Error").strong());
-                        ui.separator();
-                        ui.label(message);
-                        ui.label("This is an internal error. Please submit a bug report :)");
-                        unrecoverable_error = false;
-                    },
-                    #[cfg(target_arch = "wasm32")]
-                    SolverException::AllocError => {
-                        ui.label(egui::RichText::new("Error: Solver ran out of memory!").strong());
-                        ui.separator();
-                        ui.label("The solver reached the 4GB memory limit of 32-bit web assembly and crashed.");
-                        ui.label("Consider enabling fewer memory intensive options.\n");
-                        ui.label("Alternatively, a native version is available from the release page on GitHub.");
-                        ui.label("The native version doesn't have the 4GB limit, in addition to better performance.");
-                        ui.add(
-                            egui::Hyperlink::from_label_and_url(
-                                "View latest release on GitHub",
-                                "https://github.com/Asvel/ffxiv-raphael-cn/releases/latest",
-                            )
-                            .open_in_new_tab(true),
-                        );
-                        unrecoverable_error = true;
-                    }
-                }
-                ui.separator();
-                ui.vertical_centered_justified(|ui| {
-                    if unrecoverable_error {
-                        ui.label("Reload the page to reset the app");
-                    } else if ui.button("Close").clicked() {
-                        self.solver_error = None;
-                    }
-                });
-            });
+            let message = SolverErrorMessage::new(&error);
+            #[cfg(not(target_arch = "wasm32"))]
+            let message = message.with_crash_report(self.crash_report_path.as_deref());
+            let recoverable = message.recoverable();
+            let modal =
+                egui::Modal::new(egui::Id::new("solver_error")).show(ctx, |ui| message.ui(ui));
+            if modal.inner || (recoverable && modal_dismissed(&modal)) {
+                self.solver_error = None;
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            if self.solver_error.is_none() {
+                self.crash_report_path = None;
+            }
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.solver_error.is_none()
+            && let Some(path) = self.crash_report_path.clone()
+        {
+            // the app crashed during an earlier session
+            let modal = egui::Modal::new(egui::Id::new("crash_report"))
+                .show(ctx, |ui| crash_report_notice(ui, &path));
+            if modal.inner || modal_dismissed(&modal) {
+                self.crash_report_path = None;
+            }
         }
 
         if self.solver_pending {
+            // solver events repaint the UI as they arrive, this only keeps the elapsed time ticking
+            ctx.request_repaint_after(std::time::Duration::from_millis(250));
             let interrupt_pending = self.solver_interrupt.is_set();
-            egui::Modal::new(egui::Id::new("solver_busy")).show(ctx, |ui| {
-                ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
-                ui.set_width(180.0);
-                ui.horizontal(|ui| {
-                    ui.spinner();
-                    ui.vertical(|ui| {
-                        ui.horizontal(|ui| {
-                            ui.label(
-                                egui::RichText::new(if interrupt_pending {
-                                    "Cancelling ..."
-                                } else {
-                                    "Solving ..."
-                                })
-                                .strong(),
-                            );
-                            ui.label(format!("({:.2}s)", self.start_time.elapsed().as_secs_f32()));
-                        });
-                        if self.solver_progress == 0 {
-                            ui.label("Computing ...");
-                        } else {
-                            // format with thousands separator
-                            let num = self
-                                .solver_progress
-                                .to_string()
-                                .as_bytes()
-                                .rchunks(3)
-                                .rev()
-                                .map(std::str::from_utf8)
-                                .collect::<Result<Vec<&str>, _>>()
-                                .unwrap()
-                                .join(",");
-                            ui.label(format!("{} nodes visited", num));
-                        }
-                    });
-                });
-
-                ui.vertical_centered_justified(|ui| {
-                    ui.separator();
-                    let response = ui.add_enabled(!interrupt_pending, egui::Button::new("Cancel"));
-                    if response.clicked() {
-                        self.solver_interrupt.set();
-                    }
-                });
+            let initial_quality = util::get_initial_quality(
+                &self.recipe_config,
+                &self.custom_recipe_overrides_config,
+                &self.crafter_config,
+            );
+            let modal = egui::Modal::new(egui::Id::new("solver_busy")).show(ctx, |ui| {
+                self.solver_progress.ui(
+                    ui,
+                    interrupt_pending,
+                    initial_quality,
+                    self.solver_action_prefix.len(),
+                )
             });
+            if modal.inner {
+                self.solver_interrupt.set();
+            }
+            if modal_key_pressed(&modal, egui::Key::Escape) {
+                self.solver_interrupt.set();
+            }
         }
 
         egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
@@ -356,6 +999,11 @@ impl eframe::App for MacroSolverApp {
                                     Locale::CN,
                                     format!("{}", Locale::CN),
                                 );
+                                ui.selectable_value(
+                                    &mut self.locale,
+                                    Locale::TW,
+                                    format!("{}", Locale::TW),
+                                );
                                 ui.selectable_value(
                                     &mut self.locale,
                                     Locale::KR,
@@ -365,7 +1013,7 @@ impl eframe::App for MacroSolverApp {
 
                         ui.add(
                             egui::Hyperlink::from_label_and_url(
-                                "View source on GitHub",
+                                t("View source on GitHub"),
                                 "https://github.com/Asvel/ffxiv-raphael-cn",
                             )
                             .open_in_new_tab(true),
@@ -373,7 +1021,7 @@ impl eframe::App for MacroSolverApp {
                         ui.label("/");
                         ui.add(
                             egui::Hyperlink::from_label_and_url(
-                                "Support mainline author on Ko-fi",
+                                t("Support mainline author on Ko-fi"),
                                 "https://ko-fi.com/konaeakira",
                             )
                             .open_in_new_tab(true),
@@ -405,11 +1053,22 @@ impl eframe::App for MacroSolverApp {
                 .show(ctx, |ui| {
                     ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
                     RenderInfo::new(&mut self.dev_panel_state.render_info_state).ui(ui, _frame);
+                    ui.separator();
+                    ui.add(TestCaseExport::new(
+                        &mut self.dev_panel_state.test_case_export_state,
+                        self.last_solve_params,
+                        RecipeInfo::create_from(
+                            &self.recipe_config.recipe,
+                            &self.custom_recipe_overrides_config,
+                        ),
+                        &self.actions,
+                    ));
                 });
         }
 
         egui::CentralPanel::default().show(ctx, |ui| {
             egui::ScrollArea::both().show(ui, |ui| {
+                self.draw_workspace_tabs(ui);
                 self.draw_simulator_widget(ui);
                 ui.with_layout(
                     Layout::left_to_right(Align::TOP).with_main_wrap(true),
@@ -477,7 +1136,7 @@ impl eframe::App for MacroSolverApp {
         });
 
         egui::Window::new(
-            egui::RichText::new("Edit crafter stats")
+            egui::RichText::new(t("Edit crafter stats"))
                 .strong()
                 .text_style(TextStyle::Body),
         )
@@ -491,8 +1150,9 @@ impl eframe::App for MacroSolverApp {
             ui.add(StatsEdit::new(self.locale, &mut self.crafter_config));
         });
 
+        let mut resolve_outdated_rotations = false;
         egui::Window::new(
-            egui::RichText::new("Saved macros & solve history")
+            egui::RichText::new(t("Saved macros & solve history"))
                 .strong()
                 .text_style(TextStyle::Body),
         )
@@ -501,6 +1161,18 @@ impl eframe::App for MacroSolverApp {
         .default_size((400.0, 600.0))
         .show(ctx, |ui| {
             ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+            let outdated_rotations = self.saved_rotations_data.outdated_rotations(&self.crafter_config).len();
+            if outdated_rotations != 0
+                && ui
+                    .add_enabled(
+                        !self.solver_pending && thread_pool::is_initialized(),
+                        egui::Button::new(format!("Re-solve {} outdated", outdated_rotations)),
+                    )
+                    .on_hover_text("Solve the rotations that were solved with worse stats than the current stats of their job again, the new rotations replace the old ones")
+                    .clicked()
+            {
+                resolve_outdated_rotations = true;
+            }
             ui.add(SavedRotationsWidget::new(
                 self.locale,
                 &mut self.saved_rotations_config,
@@ -514,11 +1186,26 @@ impl eframe::App for MacroSolverApp {
                 &mut self.selected_potion,
             ));
         });
+        if resolve_outdated_rotations {
+            self.resolve_outdated_rotations();
+        }
+
+        ctx.input(|input| {
+            if input.key_pressed(egui::Key::Tab) {
+                self.keyboard_navigation = true;
+            } else if input.pointer.any_pressed() {
+                self.keyboard_navigation = false;
+            }
+        });
+        if self.keyboard_navigation {
+            draw_focus_outline(ctx);
+        }
     }
 
     fn save(&mut self, storage: &mut dyn eframe::Storage) {
         eframe::set_value(storage, "LOCALE", &self.locale);
         eframe::set_value(storage, "APP_CONFIG", &self.app_config);
+        eframe::set_value(storage, "SKIPPED_VERSION", &self.skipped_version);
         eframe::set_value(storage, "RECIPE_CONFIG", &self.recipe_config);
         eframe::set_value(
             storage,
@@ -527,15 +1214,67 @@ impl eframe::App for MacroSolverApp {
         );
         eframe::set_value(storage, "SELECTED_FOOD", &self.selected_food);
         eframe::set_value(storage, "SELECTED_POTION", &self.selected_potion);
+        eframe::set_value(storage, "JOB_CONSUMABLES", &self.job_consumables);
         eframe::set_value(storage, "CRAFTER_CONFIG", &self.crafter_config);
         eframe::set_value(storage, "SOLVER_CONFIG", &self.solver_config);
+        eframe::set_value(storage, "SOLVER_PRESETS", &self.solver_presets);
+        eframe::set_value(
+            storage,
+            "RECIPE_SOLVER_CONFIGS",
+            &self.recipe_solver_configs,
+        );
+        eframe::set_value(storage, "RECIPE_HQ_MATERIALS", &self.recipe_hq_materials);
+        eframe::set_value(
+            storage,
+            "COLLECTABLE_SCRIP_REWARDS",
+            &self.collectable_scrip_rewards,
+        );
+        eframe::set_value(storage, "HQ_INVENTORY", &self.hq_inventory);
         eframe::set_value(storage, "MACRO_VIEW_CONFIG", &self.macro_view_config);
+        eframe::set_value(storage, "GEARSETS", &self.gearsets);
+        let last_solve = self.last_solve_params.map(|params| LastSolve {
+            actions: self.actions.clone(),
+            params_hash: egui::Id::new(params).value(),
+            duration: self.duration,
+        });
+        eframe::set_value(storage, "LAST_SOLVE", &last_solve);
+        eframe::set_value(
+            storage,
+            "WORKSPACES",
+            &self.workspace_tabs.to_stored(self.current_workspace()),
+        );
         eframe::set_value(
             storage,
             "SAVED_ROTATIONS_CONFIG",
             &self.saved_rotations_config,
         );
-        eframe::set_value(storage, "SAVED_ROTATIONS", &self.saved_rotations_data);
+        #[cfg(not(target_arch = "wasm32"))]
+        eframe::set_value(storage, "WEBHOOK_CONFIG", &self.webhook_config);
+        #[cfg(not(target_arch = "wasm32"))]
+        eframe::set_value(storage, "COPY_HOTKEY", &self.copy_hotkey.text);
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.saved_rotations_file
+                .sync(&mut self.saved_rotations_data, &self.saved_rotations_config);
+            // rotations saved before they had a file of their own have been merged, so they are not kept twice
+            storage.set_string("SAVED_ROTATIONS", String::new());
+        }
+        #[cfg(target_arch = "wasm32")]
+        match self.indexed_db_available {
+            Some(true) => {
+                let value = ron::ser::to_string(&self.saved_rotations_data).unwrap();
+                if self.indexed_db_saved_rotations.as_ref() != Some(&value) {
+                    crate::web_storage::write("SAVED_ROTATIONS", value.clone());
+                    self.indexed_db_saved_rotations = Some(value);
+                }
+                // rotations saved before IndexedDB was used have been merged, free the localStorage quota
+                storage.set_string("SAVED_ROTATIONS", String::new());
+            }
+            Some(false) => {
+                eframe::set_value(storage, "SAVED_ROTATIONS", &self.saved_rotations_data);
+            }
+            None => {} // keep the stored rotations until they have been read
+        }
     }
 
     fn auto_save_interval(&self) -> std::time::Duration {
@@ -555,61 +1294,249 @@ impl eframe::App for MacroSolverApp {
 impl MacroSolverApp {
     fn process_solver_events(&mut self) {
         let mut submit_new_rotation = false;
-        let mut solver_events = self.solver_events.lock().unwrap();
+        #[cfg(not(target_arch = "wasm32"))]
+        let mut finished_solve = None;
+        let mut solver_events = self.solver_events.queue.lock().unwrap();
         while let Some(event) = solver_events.pop_front() {
             match event {
-                SolverEvent::NodesVisited(count) => self.solver_progress = count,
-                SolverEvent::Actions(actions) => self.actions = actions,
-                SolverEvent::LoadedFromHistory() => self.solver_progress = usize::MAX,
-                SolverEvent::Finished(exception) => {
-                    self.duration = self.start_time.elapsed();
+                SolverEvent::PrecomputeProgress(progress) => {
+                    self.solver_progress.precompute = Some(progress);
+                }
+                SolverEvent::Progress(progress) => self.solver_progress.set_progress(progress),
+                SolverEvent::Actions(actions) => {
+                    self.actions = [self.solver_action_prefix.as_slice(), &actions].concat();
+                }
+                SolverEvent::TierSolved(quality_target, actions) => {
+                    self.tier_results_window
+                        .push_result(quality_target, actions);
+                }
+                SolverEvent::TargetRotations(rotations) => {
+                    // the rotations are found right after the solved target, whose params are still the last solve params
+                    if let Some(params) = self.last_solve_params {
+                        let (game_settings, initial_quality, solver_config) = params;
+                        self.target_rotations = std::iter::once((params, self.actions.clone()))
+                            .chain(rotations.into_iter().map(|(quality_target, actions)| {
+                                let solver_config = SolverConfig {
+                                    quality_target,
+                                    ..solver_config
+                                };
+                                ((game_settings, initial_quality, solver_config), actions)
+                            }))
+                            .collect();
+                    }
+                }
+                SolverEvent::CpTradeoff(cp_reduction, quality) => {
+                    self.cp_tradeoff_window.push_result(cp_reduction, quality);
+                }
+                SolverEvent::HqSweep(hq_count, solution) => {
+                    self.hq_sweep_window.push_result(hq_count, solution);
+                }
+                SolverEvent::DecisionTree(tree) => self.decision_tree_window.set_tree(tree),
+                SolverEvent::BatchSolved(index, solution) => {
+                    if self.config_compare_window.open {
+                        self.config_compare_window.push_result(index, solution);
+                    } else if self.leveling_window.open {
+                        self.leveling_window.push_result(index, solution);
+                    } else if self.collectable_sweep_window.open {
+                        self.collectable_sweep_window.push_result(index, solution);
+                    } else if self.rotation_resolve_pending {
+                        if let (Some(solution), Some((unique_id, resolved_rotation))) =
+                            (solution, self.rotation_resolves.get(index))
+                        {
+                            let rotation =
+                                resolved_rotation.with_resolved_actions(solution.actions);
+                            self.saved_rotations_data
+                                .replace_rotation(*unique_id, rotation);
+                        }
+                    } else {
+                        let result = match solution {
+                            Some(solution) => GearsetResult::Solved {
+                                quality: solution.quality,
+                                steps: solution.actions.len(),
+                            },
+                            None => GearsetResult::NoSolution,
+                        };
+                        self.gearset_window.push_result(index, result);
+                    }
+                }
+                SolverEvent::MinimumStats(params_hash, minimum_stats) => {
+                    // results computed for parameters that have changed since are dropped
+                    if params_hash == self.minimum_stats_params_hash {
+                        self.minimum_stats = minimum_stats;
+                    }
+                }
+                SolverEvent::LoadedFromHistory() => self.solver_progress.nodes_visited = usize::MAX,
+                SolverEvent::Finished(exception, solve_report) => {
+                    self.duration = self.solver_progress.elapsed();
+                    self.solve_report = solve_report;
                     self.solver_pending = false;
                     self.solver_interrupt.clear();
+                    self.target_downgrade = None;
+                    #[cfg(not(target_arch = "wasm32"))]
+                    {
+                        finished_solve = Some(exception.clone());
+                    }
                     if exception.is_none() {
-                        submit_new_rotation = true;
+                        // rotations found by solving all tiers, comparing solver configurations, sweeping HQ materials or collectables or planning leveling crafts are only saved when picked from the results,
+                        // re-solved rotations replace the outdated ones as they are found,
+                        // and the CP tradeoff report and gearset comparison don't produce a rotation at all
+                        submit_new_rotation = !self.tier_results_window.open
+                            && !self.config_compare_window.open
+                            && !self.leveling_window.open
+                            && !self.collectable_sweep_window.open
+                            && !self.rotation_resolve_pending
+                            && !self.hq_sweep_window.open
+                            && !self.decision_tree_window.open
+                            && !self.cp_tradeoff_window.open
+                            && !self.gearset_window.open;
                     } else {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        if matches!(exception, Some(SolverException::PanicCaught { .. })) {
+                            self.crash_report_path = crate::crash_report::take_pending_report();
+                        }
                         self.solver_error = exception;
                     }
+                    self.rotation_resolve_pending = false;
                 }
             }
         }
         drop(solver_events);
 
         if submit_new_rotation {
-            let game_settings = util::get_game_settings(
-                &self.recipe_config,
-                &self.custom_recipe_overrides_config,
-                &self.solver_config,
-                &self.crafter_config,
-                self.selected_food,
-                self.selected_potion,
-            );
-            self.find_minimum_stats(&game_settings);
-            let new_rotation = Rotation::new(
-                raphael_data::get_recipe_name(
-                    &self.recipe_config.recipe,
-                    false,
-                    self.locale,
-                )
-                .unwrap_or("Unknown item".to_owned()),
-                self.actions.clone(),
-                &self.recipe_config,
-                &self.custom_recipe_overrides_config,
-                &game_settings,
-                &self.solver_config,
-                self.selected_food,
-                self.selected_potion,
-                &self.crafter_config,
-                self.minimum_stats,
+            self.submit_rotation();
+            self.target_downgrade = self.find_target_downgrade();
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(exception) = finished_solve
+            && !self.webhook_config.discord_webhook_url.is_empty()
+            && self.duration.as_secs() >= u64::from(self.webhook_config.min_solve_duration_secs)
+            && exception != Some(SolverException::Interrupted)
+        {
+            send_discord_notification(
+                &self.webhook_config.discord_webhook_url,
+                self.solve_summary(exception.as_ref()),
             );
-            self.saved_rotations_sync_requests.push_back(Some(new_rotation));
         }
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
+    /// Summary of the finished solve for the webhook notification, including the macro if it fits into a Discord message
+    fn solve_summary(&self, exception: Option<&SolverException>) -> String {
+        let item_name =
+            raphael_data::get_recipe_name(&self.recipe_config.recipe, false, self.locale)
+                .unwrap_or("Unknown item".to_owned());
+        let mut lines = vec![format!(
+            "**{item_name}**: solve finished after {}s",
+            self.duration.as_secs()
+        )];
+        match exception {
+            Some(SolverException::NoSolution) => lines.push("No solution found".to_owned()),
+            Some(exception) => lines.push(format!("Solver error: {exception:?}")),
+            None if self.tier_results_window.open => {
+                lines.extend(self.tier_results_window.summary_lines())
+            }
+            None if self.leveling_window.open => {
+                lines.extend(self.leveling_window.summary_lines(self.output_locale()))
+            }
+            None if self.collectable_sweep_window.open => lines.extend(
+                self.collectable_sweep_window
+                    .summary_lines(self.output_locale()),
+            ),
+            None if self.hq_sweep_window.open => lines.extend(self.hq_sweep_window.summary_lines()),
+            None if self.decision_tree_window.open => {
+                lines.extend(self.decision_tree_window.summary_lines(self.locale))
+            }
+            None if self.config_compare_window.open => {
+                lines.extend(self.config_compare_window.summary_lines())
+            }
+            None if self.gearset_window.open => {
+                lines.extend(self.gearset_window.summary_lines(&self.gearsets))
+            }
+            None if self.cp_tradeoff_window.open => {
+                lines.extend(self.cp_tradeoff_window.summary_lines())
+            }
+            None => {
+                let game_settings = util::get_game_settings(
+                    &self.recipe_config,
+                    &self.custom_recipe_overrides_config,
+                    &self.solver_config,
+                    &self.crafter_config,
+                    self.selected_food,
+                    self.selected_potion,
+                );
+                let initial_quality = util::get_initial_quality(
+                    &self.recipe_config,
+                    &self.custom_recipe_overrides_config,
+                    &self.crafter_config,
+                );
+                let (state, _) = self.solver_config.simulate(&game_settings, &self.actions);
+                lines.push(format!(
+                    "Quality: {} / {}, steps: {}",
+                    u32::from(initial_quality) + state.quality,
+                    game_settings.max_quality,
+                    self.actions.len(),
+                ));
+                let macro_text = macro_text(
+                    &self.actions,
+                    &self.macro_view_config,
+                    Some(MacroSimulation {
+                        settings: game_settings,
+                        initial_state: self.solver_config.initial_state(&game_settings),
+                        initial_quality,
+                    }),
+                    &[self.selected_food, self.selected_potion],
+                    self.output_locale(),
+                );
+                let macro_block = format!("```\n{macro_text}\n```");
+                let summary_length: usize = lines.iter().map(|line| line.chars().count() + 1).sum();
+                if summary_length + macro_block.chars().count() <= DISCORD_MESSAGE_MAX_CHARS {
+                    lines.push(macro_block);
+                }
+            }
+        }
+        lines.join("\n")
+    }
+
+    fn submit_rotation(&mut self) {
+        let game_settings = util::get_game_settings(
+            &self.recipe_config,
+            &self.custom_recipe_overrides_config,
+            &self.solver_config,
+            &self.crafter_config,
+            self.selected_food,
+            self.selected_potion,
+        );
+        let minimum_stats = match self.minimum_stats_target_quality(&game_settings) {
+            Some(target_quality) => util::find_minimum_stats(
+                game_settings,
+                &self.solver_config,
+                target_quality,
+                &self.actions,
+                &self.recipe_config.recipe,
+            ),
+            None => MinimumStats::default(),
+        };
+        let new_rotation = Rotation::new(
+            raphael_data::get_recipe_name(&self.recipe_config.recipe, false, self.locale)
+                .unwrap_or("Unknown item".to_owned()),
+            self.actions.clone(),
+            &self.recipe_config,
+            &self.custom_recipe_overrides_config,
+            &game_settings,
+            &self.solver_config,
+            self.selected_food,
+            self.selected_potion,
+            &self.crafter_config,
+            minimum_stats,
+        );
+        self.saved_rotations_sync_requests
+            .push_back(Some(new_rotation));
+    }
+
     fn draw_app_config_menu_button(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
         ui.add_enabled_ui(true, |ui| {
             ui.reset_style();
-            egui::containers::menu::MenuButton::new("⚙ Settings")
+            egui::containers::menu::MenuButton::new(t("⚙ Settings"))
                 .config(
                     egui::containers::menu::MenuConfig::default()
                         .close_behavior(egui::PopupCloseBehavior::CloseOnClickOutside),
@@ -618,7 +1545,7 @@ impl MacroSolverApp {
                     ui.reset_style();
                     ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
                     ui.horizontal(|ui| {
-                        ui.label("Zoom");
+                        ui.label(t("Zoom"));
 
                         let mut zoom_percentage = (ctx.zoom_factor() * 100.0).round() as u16;
                         ui.horizontal(|ui| {
@@ -629,7 +1556,7 @@ impl MacroSolverApp {
                                 }
                             });
                             ui.add_enabled_ui(zoom_percentage != 100, |ui| {
-                                if ui.button("Reset").clicked() {
+                                if ui.button(t("Reset")).clicked() {
                                     zoom_percentage = 100;
                                 }
                             });
@@ -652,19 +1579,56 @@ impl MacroSolverApp {
                         self.app_config.zoom_percentage = zoom_percentage;
                         ctx.set_zoom_factor(f32::from(zoom_percentage) * 0.01);
                     });
-
-                    ui.separator();
+
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        ui.label(t("Theme"));
+                        egui::global_theme_preference_buttons(ui);
+                    });
+                    ui.add(BarStyleSettings::new(&mut self.app_config.bar_style));
+                    ui.separator();
+
+                    ui.horizontal(|ui| {
+                        ui.label(t("Language"));
+                        let selected_text = self
+                            .app_config
+                            .ui_language
+                            .map_or_else(|| t("Same as game data"), |language| t(language.name()));
+                        egui::ComboBox::from_id_salt("UI_LANGUAGE")
+                            .selected_text(selected_text)
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(&mut self.app_config.ui_language, None, t("Same as game data"));
+                                for language in [UiLanguage::EN, UiLanguage::JP, UiLanguage::CN] {
+                                    ui.selectable_value(
+                                        &mut self.app_config.ui_language,
+                                        Some(language),
+                                        t(language.name()),
+                                    );
+                                }
+                            });
+                    });
                     ui.horizontal(|ui| {
-                        ui.label("Theme");
-                        egui::global_theme_preference_buttons(ui);
+                        ui.label(t("Macros & copied names"));
+                        let selected_text = self
+                            .app_config
+                            .output_locale
+                            .map_or_else(|| t("Same as game data").to_owned(), |locale| locale.to_string());
+                        egui::ComboBox::from_id_salt("OUTPUT_LOCALE")
+                            .selected_text(selected_text)
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(&mut self.app_config.output_locale, None, t("Same as game data"));
+                                for locale in [Locale::EN, Locale::DE, Locale::FR, Locale::JP, Locale::CN, Locale::TW, Locale::KR] {
+                                    ui.selectable_value(&mut self.app_config.output_locale, Some(locale), locale.to_string());
+                                }
+                            });
                     });
                     ui.separator();
 
                     ui.horizontal(|ui| {
-                        ui.label("Max solver threads");
+                        ui.label(t("Max solver threads"));
                         ui.add_enabled_ui(!thread_pool::initialization_attempted(), |ui| {
                             let mut auto_thread_count = self.app_config.num_threads.is_none();
-                            if ui.checkbox(&mut auto_thread_count, "Auto").changed() {
+                            if ui.checkbox(&mut auto_thread_count, t("Auto")).changed() {
                                 if auto_thread_count {
                                     self.app_config.num_threads = None;
                                 } else {
@@ -687,6 +1651,43 @@ impl MacroSolverApp {
                             }
                         });
                     });
+                    ui.horizontal(|ui| {
+                        if ui.checkbox(
+                            &mut self.app_config.keep_solver_tables,
+                            "Keep solver tables after solving",
+                        ).changed() && !self.app_config.keep_solver_tables && !self.solver_pending {
+                            *self.solver_cache.lock().unwrap() = None;
+                        }
+                        ui.add(HelpText::new("Keep the precomputed tables of the last solve in memory so that rotations can be repaired from a mid-craft state within seconds.\n  - Increases memory usage while idle."));
+                    });
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut self.app_config.verify_calculations, "Verify calculations");
+                        ui.add(HelpText::new("Cross-check the Progress and Quality calculations of the selected recipe against a second implementation of the game's formulas and rounding rules, and show a warning if they disagree.\nIntended for spotting bugs after game data updates."));
+                    });
+                    ui.separator();
+                    let update_check_settings = UpdateCheckSettings::new(
+                        &mut self.app_config.update_check,
+                        &mut self.skipped_version,
+                    );
+                    if ui.add(update_check_settings).changed() {
+                        *self.latest_release.lock().unwrap() = LatestRelease::default();
+                        fetch_latest_release(
+                            self.latest_release.clone(),
+                            self.app_config.update_check,
+                        );
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    {
+                        ui.separator();
+                        ui.add(WebhookSettings::new(&mut self.webhook_config));
+                        ui.separator();
+                        ui.add(CopyHotkeySettings::new(&mut self.copy_hotkey));
+                        ui.separator();
+                    }
+                    if ui.button(t("Backup & restore")).clicked() {
+                        self.backup_window.show();
+                        ui.close();
+                    }
                     if thread_pool::initialization_attempted() {
                         #[cfg(target_arch = "wasm32")]
                         let app_restart_text = "Reload the page to change max solver threads.";
@@ -716,24 +1717,44 @@ impl MacroSolverApp {
             self.selected_food,
             self.selected_potion,
         );
-        let initial_quality = util::get_initial_quality(&self.recipe_config, &self.crafter_config);
+        let initial_quality = util::get_initial_quality(
+            &self.recipe_config,
+            &self.custom_recipe_overrides_config,
+            &self.crafter_config,
+        );
         let item = raphael_data::ITEMS
             .get(&self.recipe_config.recipe.item_id)
             .copied()
             .unwrap_or_default();
-        self.find_minimum_stats(&game_settings);
+        self.update_minimum_stats(&game_settings);
+        if self.app_config.verify_calculations {
+            let mismatches = raphael_data::verify_game_settings(
+                self.recipe_config.recipe,
+                match self.custom_recipe_overrides_config.use_custom_recipe {
+                    true => Some(self.custom_recipe_overrides_config.custom_recipe_overrides),
+                    false => None,
+                },
+                *self.crafter_config.active_stats(),
+                self.selected_food,
+                self.selected_potion,
+            );
+            if !mismatches.is_empty() {
+                ui.add(CalculationMismatches::new(&mismatches));
+            }
+        }
         ui.add(Simulator::new(
             &game_settings,
             initial_quality,
             self.solver_config,
             &mut self.crafter_config,
-            &self.actions,
+            &mut self.actions,
             &self.recipe_config.recipe,
             &item,
             self.selected_food,
             self.selected_potion,
             &self.minimum_stats,
             self.locale,
+            self.app_config.bar_style,
         ));
     }
 
@@ -742,6 +1763,8 @@ impl MacroSolverApp {
             ui.add(RecipeSelect::new(
                 &mut self.crafter_config,
                 &mut self.solver_config,
+                &self.recipe_solver_configs,
+                &mut self.recipe_hq_materials,
                 &mut self.recipe_config,
                 &mut self.custom_recipe_overrides_config,
                 self.selected_food,
@@ -772,9 +1795,38 @@ impl MacroSolverApp {
                         self.saved_rotations_window_open = true;
                     }
                     ui.add_space(-5.0);
+                    ui.add(SolverPresetsButton::new(&mut self.solver_presets, &mut self.solver_config));
+                    ui.add_space(-5.0);
+                    if ui
+                        .button("🔧")
+                        .on_hover_text("Repair rotation from a mid-craft state")
+                        .clicked()
+                    {
+                        self.repair_window.show(&self.repair_target());
+                    }
+                    ui.add_space(-5.0);
+                    if ui
+                        .add_enabled(
+                            !self.actions.is_empty() && thread_pool::is_initialized(),
+                            egui::Button::new("📉"),
+                        )
+                        .on_hover_text("Show the best achievable Quality with less CP")
+                        .clicked()
+                    {
+                        self.compute_cp_tradeoff();
+                    }
+                    ui.add_space(-5.0);
+                    if ui
+                        .add_enabled(thread_pool::is_initialized(), egui::Button::new("🔀"))
+                        .on_hover_text("Compare two solver configurations side by side")
+                        .clicked()
+                    {
+                        self.config_compare_window.show(self.solver_config);
+                    }
+                    ui.add_space(-5.0);
                     ui.vertical_centered_justified(|ui| {
                         let text_color = ui.ctx().style().visuals.selection.stroke.color;
-                        let text = egui::RichText::new("Solve").color(text_color);
+                        let text = egui::RichText::new(t("Solve")).color(text_color);
                         let fill_color = ui.ctx().style().visuals.selection.bg_fill;
                         let id = egui::Id::new("SOLVE_INITIATED");
                         let mut solve_initiated = ui
@@ -795,11 +1847,31 @@ impl MacroSolverApp {
                         }
                     });
                 });
-                ui.with_layout(Layout::right_to_left(Align::TOP), |ui| {
-                    if self.solver_progress == usize::MAX {
-                        ui.label("Loaded from saved rotations");
-                    } else if self.solver_progress > 0 {
+                ui.with_layout(Layout::top_down(Align::RIGHT), |ui| {
+                    if self.solver_progress.nodes_visited == usize::MAX {
+                        ui.label(t("Loaded from saved rotations"));
+                    } else if self.solver_progress.nodes_visited > 0 {
                         ui.label(format!("Elapsed time: {:.2}s", self.duration.as_secs_f32()));
+                        if let Some(solve_report) = self.solve_report {
+                            solve_report.ui(ui);
+                        }
+                    }
+                    if let Some((quality_target, target_quality)) = self.target_downgrade {
+                        ui.label(
+                            egui::RichText::new("⚠ Quality target not reached")
+                                .small()
+                                .color(ui.visuals().warn_fg_color),
+                        );
+                        let text = format!("Retry with target {}", format_thousands(usize::from(target_quality)));
+                        if ui
+                            .add_enabled(!self.solver_pending, egui::Button::new(text).small())
+                            .on_hover_text("Solve again for the highest target the current rotation reaches, which may take fewer steps")
+                            .clicked()
+                        {
+                            self.solver_config.quality_target = quality_target;
+                            self.target_downgrade = None;
+                            self.on_solve_initiated(ui.ctx());
+                        }
                     }
                 });
                 // fill the remaining space
@@ -810,12 +1882,30 @@ impl MacroSolverApp {
 
     fn draw_configuration_widget(&mut self, ui: &mut egui::Ui) {
         ui.horizontal(|ui| {
-            ui.label(egui::RichText::new("Configuration").strong());
+            ui.label(egui::RichText::new(t("Configuration")).strong());
             ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
                 ui.style_mut().spacing.item_spacing = [4.0, 4.0].into();
                 if ui.button("✏").clicked() {
                     self.stats_edit_window_open = true;
                 }
+                if ui.button("⚖").on_hover_text("Compare gearsets").clicked() {
+                    self.gearset_window.show();
+                }
+                if ui
+                    .button("📈")
+                    .on_hover_text("Plan leveling crafts")
+                    .clicked()
+                {
+                    self.leveling_window.show(self.find_leveling_recipes());
+                }
+                if ui
+                    .button("🎫")
+                    .on_hover_text("Find the collectable with the most scrips per hour")
+                    .clicked()
+                {
+                    self.collectable_sweep_window
+                        .show(self.find_sweep_collectables());
+                }
                 egui::ComboBox::from_id_salt("SELECTED_JOB")
                     .width(20.0)
                     .selected_text(get_job_name(self.crafter_config.selected_job, self.locale))
@@ -838,9 +1928,9 @@ impl MacroSolverApp {
         });
         ui.separator();
 
-        ui.label(egui::RichText::new("Crafter stats").strong());
+        ui.label(egui::RichText::new(t("Crafter stats")).strong());
         ui.horizontal(|ui| {
-            ui.label("Craftsmanship");
+            ui.label(t("Craftsmanship"));
             ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
                 let cms_base = &mut self.crafter_config.active_stats_mut().craftsmanship;
                 let cms_bonus = raphael_data::craftsmanship_bonus(
@@ -857,7 +1947,7 @@ impl MacroSolverApp {
             });
         });
         ui.horizontal(|ui| {
-            ui.label("Control");
+            ui.label(t("Control"));
             ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
                 let control_base = &mut self.crafter_config.active_stats_mut().control;
                 let control_bonus = raphael_data::control_bonus(
@@ -874,7 +1964,7 @@ impl MacroSolverApp {
             });
         });
         ui.horizontal(|ui| {
-            ui.label("CP");
+            ui.label(t("CP"));
             ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
                 let cp_base = &mut self.crafter_config.active_stats_mut().cp;
                 let cp_bonus =
@@ -888,8 +1978,30 @@ impl MacroSolverApp {
                 }
             });
         });
+        let (missing_cms, missing_ctrl) = raphael_data::missing_stats(
+            &self.recipe_config.recipe,
+            *self.crafter_config.active_stats(),
+            &[self.selected_food, self.selected_potion],
+        );
+        if missing_cms != 0 || missing_ctrl != 0 {
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "⚠ Below recipe requirement: missing {missing_cms} Craftsmanship, {missing_ctrl} Control."
+                    ))
+                    .small()
+                    .color(ui.visuals().warn_fg_color),
+                );
+                ui.add(FixConsumablesButton::new(
+                    &self.recipe_config.recipe,
+                    *self.crafter_config.active_stats(),
+                    &mut self.selected_food,
+                    &mut self.selected_potion,
+                ));
+            });
+        }
         ui.horizontal(|ui| {
-            ui.label("Job level");
+            ui.label(t("Job level"));
             ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
                 if ui.add(
                     egui::DragValue::new(&mut self.crafter_config.active_stats_mut().level)
@@ -901,9 +2013,66 @@ impl MacroSolverApp {
         });
         ui.separator();
 
-        ui.label(egui::RichText::new("HQ materials").strong());
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new(t("HQ materials")).strong());
+            if self.custom_recipe_overrides_config.use_custom_recipe {
+                return; // initial quality is set in the custom recipe editor
+            }
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                let mut use_percentage =
+                    matches!(self.recipe_config.quality_source, QualitySource::Percentage(_));
+                if ui.toggle_value(&mut use_percentage, "%")
+                    .on_hover_text("Specify initial quality as a percentage of max quality")
+                    .changed()
+                {
+                    self.recipe_config.quality_source = match use_percentage {
+                        true => QualitySource::Percentage(0),
+                        false => QualitySource::HqMaterialList([0; 6]),
+                    };
+                }
+                if !use_percentage
+                    && ui.add_enabled(!self.actions.is_empty(), egui::Button::new("Fewest").small())
+                        .on_hover_text("Use the fewest HQ materials that still let the current rotation reach the target quality")
+                        .on_disabled_hover_text("Solve or enter a rotation first")
+                        .clicked()
+                {
+                    self.minimize_hq_materials();
+                }
+                if !use_percentage
+                    && ui.add_enabled(thread_pool::is_initialized(), egui::Button::new("Sweep").small())
+                        .on_hover_text("Solve for every number of HQ materials to see where more of them stop mattering")
+                        .clicked()
+                {
+                    self.solve_hq_sweep();
+                }
+                if !use_percentage {
+                    let inventory_text = match self.hq_inventory.is_some() {
+                        true => egui::RichText::new("📦").color(ui.visuals().selection.bg_fill),
+                        false => egui::RichText::new("📦"),
+                    };
+                    if ui.add(egui::Button::new(inventory_text).small())
+                        .on_hover_text("Limit the HQ materials to the ones in your inventory")
+                        .clicked()
+                    {
+                        self.hq_inventory_window.show();
+                    }
+                }
+            });
+        });
         let mut has_hq_ingredient = false;
+        if let QualitySource::Percentage(percentage) = &mut self.recipe_config.quality_source
+            && !self.custom_recipe_overrides_config.use_custom_recipe
+        {
+            has_hq_ingredient = true;
+            ui.horizontal(|ui| {
+                ui.label(t("Initial quality"));
+                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                    ui.add(egui::DragValue::new(percentage).range(0..=100).suffix("%"));
+                });
+            });
+        }
         let recipe_ingredients = self.recipe_config.recipe.ingredients;
+        let hq_inventory = self.hq_inventory.as_ref();
         if let QualitySource::HqMaterialList(provided_ingredients) =
             &mut self.recipe_config.quality_source
         {
@@ -922,10 +2091,28 @@ impl MacroSolverApp {
                                         egui::DragValue::new(&mut max_placeholder),
                                     );
                                     ui.monospace("/");
-                                    ui.add(
+                                    // amounts above what is owned are capped as soon as an inventory is imported
+                                    let owned_amount = hq_inventory
+                                        .map(|inventory| inventory.hq_amount(ingredient.item_id));
+                                    let max_amount =
+                                        owned_amount.map_or(ingredient.amount, |owned_amount| {
+                                            std::cmp::min(owned_amount, ingredient.amount)
+                                        });
+                                    provided_ingredients[index] = std::cmp::min(
+                                        provided_ingredients[index],
+                                        max_amount as u8,
+                                    );
+                                    let response = ui.add_enabled(
+                                        max_amount != 0,
                                         egui::DragValue::new(&mut provided_ingredients[index])
-                                            .range(0..=ingredient.amount),
+                                            .range(0..=max_amount),
                                     );
+                                    if let Some(owned_amount) = owned_amount {
+                                        let hover_text = format!("{} owned in HQ", owned_amount);
+                                        response
+                                            .on_hover_text(&hover_text)
+                                            .on_disabled_hover_text(hover_text);
+                                    }
                                 },
                             );
                         });
@@ -934,11 +2121,11 @@ impl MacroSolverApp {
             }
         }
         if !has_hq_ingredient {
-            ui.label("None");
+            ui.label(t("None"));
         }
         ui.separator();
 
-        ui.label(egui::RichText::new("Actions").strong());
+        ui.label(egui::RichText::new(t("Actions")).strong());
         if self.crafter_config.active_stats().level >= Manipulation::LEVEL_REQUIREMENT {
             if ui.add(egui::Checkbox::new(
                 &mut self.crafter_config.active_stats_mut().manipulation,
@@ -1006,16 +2193,16 @@ impl MacroSolverApp {
                     .color(ui.visuals().warn_fg_color),
                 );
                 ui.add(egui::Hyperlink::from_label_and_url(
-                    egui::RichText::new("Download latest release from GitHub").small(),
+                    egui::RichText::new(t("Download latest release from GitHub")).small(),
                     "https://github.com/Asvel/ffxiv-raphael-cn/releases/latest",
                 ));
             }
         }
         ui.separator();
 
-        ui.label(egui::RichText::new("Solver settings").strong());
+        ui.label(egui::RichText::new(t("Solver settings")).strong());
         ui.horizontal(|ui| {
-            ui.label("Target quality");
+            ui.label(t("Target quality"));
             ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
                 ui.style_mut().spacing.item_spacing = [4.0, 4.0].into();
                 let game_settings = raphael_data::get_game_settings(
@@ -1077,12 +2264,24 @@ impl MacroSolverApp {
             });
         });
 
+        if self.recipe_always_collectable() {
+            ui.horizontal(|ui| {
+                ui.checkbox(
+                    &mut self.solve_all_collectable_tiers,
+                    "Solve all collectable tiers",
+                );
+                ui.add(HelpText::new("Find a rotation for each collectable tier and for max Quality in a single solve, ignoring the Target Quality above.\nThe steps, duration and CP of each rotation are shown in a table, from which one of the rotations can be picked."));
+            });
+        }
+
         ui.horizontal(|ui| {
-            ui.checkbox(
-                &mut self.solver_config.backload_progress,
-                "Backload progress",
-            );
-            ui.add(HelpText::new("Find a rotation that only uses Progress-increasing actions at the end of the rotation.\n  - May decrease achievable Quality.\n  - May increase macro duration."));
+            ui.label("Backload progress");
+            let mut backload_progress = (self.solver_config.backload_progress, self.solver_config.prefer_backload_progress && !self.solver_config.backload_progress);
+            ui.selectable_value(&mut backload_progress, (false, false), "Off");
+            ui.selectable_value(&mut backload_progress, (false, true), "Prefer");
+            ui.selectable_value(&mut backload_progress, (true, false), "Strict");
+            (self.solver_config.backload_progress, self.solver_config.prefer_backload_progress) = backload_progress;
+            ui.add(HelpText::new("Find a rotation that only uses Progress-increasing actions at the end of the rotation.\n  - Prefer: only if that doesn't decrease Quality.\n  - Strict: always, which may decrease achievable Quality.\n  - May increase macro duration."));
         });
 
         if self.recipe_config.recipe.is_expert {
@@ -1105,6 +2304,50 @@ impl MacroSolverApp {
                     .color(ui.visuals().warn_fg_color),
             );
         }
+
+        ui.horizontal(|ui| {
+            ui.checkbox(
+                &mut self.solver_config.forbid_trained_eye,
+                format!("Don't use {}", action_name(Action::TrainedEye, self.locale)),
+            );
+            ui.add(HelpText::new("Find a rotation without Trained Eye, even if the job level is high enough to use it.\nUseful for rotations that should also work when the level gap shrinks, e.g. under level sync."));
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Opener");
+            ui.add(OpenerSelect::new(&mut self.solver_config.opener, self.locale));
+            ui.add(HelpText::new("Force or forbid the first step of the rotation, e.g. to match a fixed opener in your macros or plugins.\nMay decrease achievable Quality or increase macro duration.\nHas no effect when solving from a mid-craft state."));
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Opener condition");
+            ui.add(OpenerConditionSelect::new(&mut self.solver_config, self.recipe_config.recipe));
+            if ui
+                .add_enabled(!self.solver_pending && thread_pool::is_initialized(), egui::Button::new("Decision tree").small())
+                .on_hover_text(format!("Solve each of the steps up to step {} for each condition it can have, to see which actions to use when a single macro can't react to the conditions. The first step of a craft is always Normal.\nNeeds a solve per condition of each of these steps, so it takes many times longer than a single solve.", DECISION_TREE_DEPTH + 1))
+                .clicked()
+            {
+                self.solve_decision_tree();
+            }
+        });
+
+        ui.add(CraftMarginsEdit::new(&mut self.solver_config));
+    }
+
+    fn solver_context(&self) -> SolverContext {
+        SolverContext {
+            events: self.solver_events.clone(),
+            interrupt: self.solver_interrupt.clone(),
+            cache: self.solver_cache.clone(),
+            keep_solver_tables: self.app_config.keep_solver_tables,
+        }
+    }
+
+    fn macro_solver_settings(
+        &self,
+        simulator_settings: raphael_sim::Settings,
+    ) -> raphael_solver::SolverSettings {
+        self.solver_config.macro_solver_settings(simulator_settings)
     }
 
     fn on_solve_initiated(&mut self, ctx: &egui::Context) {
@@ -1126,20 +2369,449 @@ impl MacroSolverApp {
             if craftsmanship + craftsmanship_bonus >= craftsmanship_req
                 && control + control_bonus >= control_req
             {
-                self.solve(ctx);
+                let game_settings = util::get_game_settings(
+                    &self.recipe_config,
+                    &self.custom_recipe_overrides_config,
+                    &self.solver_config,
+                    &self.crafter_config,
+                    self.selected_food,
+                    self.selected_potion,
+                );
+                if util::estimate_solver_memory_usage(&game_settings) > util::solver_memory_budget()
+                {
+                    self.solve_confirmation_window.open = true;
+                } else {
+                    self.solve(ctx);
+                }
             } else {
-                self.missing_stats_error_window_open = true;
+                self.missing_stats_window.open = true;
+            }
+        } else {
+            thread_pool::attempt_initialization(self.app_config.num_threads);
+            ctx.request_repaint();
+        }
+    }
+
+    /// Replaces the HQ materials with the fewest that still let the current rotation reach the target quality.
+    /// Nothing changes if the rotation doesn't finish the craft or can't reach the target even with all materials HQ.
+    fn minimize_hq_materials(&mut self) {
+        let game_settings = util::get_game_settings(
+            &self.recipe_config,
+            &self.custom_recipe_overrides_config,
+            &self.solver_config,
+            &self.crafter_config,
+            self.selected_food,
+            self.selected_potion,
+        );
+        let (state, _) = self.solver_config.simulate(&game_settings, &self.actions);
+        if state.progress < u32::from(game_settings.max_progress) {
+            return;
+        }
+        let target_quality = self
+            .solver_config
+            .quality_target
+            .get_target(game_settings.max_quality);
+        let rotation_quality = u16::try_from(state.quality).unwrap_or(u16::MAX);
+        if let Some(hq_materials) = raphael_data::get_minimal_hq_ingredients(
+            *self.crafter_config.active_stats(),
+            self.recipe_config.recipe,
+            target_quality.saturating_sub(rotation_quality),
+        ) {
+            self.recipe_config.quality_source = QualitySource::HqMaterialList(hq_materials);
+        }
+    }
+
+    fn solver_settings(&self) -> (raphael_sim::Settings, u16) {
+        self.solver_settings_for(&self.solver_config)
+    }
+
+    /// Game settings with the max Quality reduced to what `solver_config` has to reach, and the initial Quality
+    fn solver_settings_for(&self, solver_config: &SolverConfig) -> (raphael_sim::Settings, u16) {
+        let mut game_settings = util::get_game_settings(
+            &self.recipe_config,
+            &self.custom_recipe_overrides_config,
+            solver_config,
+            &self.crafter_config,
+            self.selected_food,
+            self.selected_potion,
+        );
+        let initial_quality = util::get_initial_quality(
+            &self.recipe_config,
+            &self.custom_recipe_overrides_config,
+            &self.crafter_config,
+        );
+        let target_quality = solver_config
+            .quality_target
+            .get_target(game_settings.max_quality);
+        game_settings.max_quality = target_quality.saturating_sub(initial_quality);
+        (game_settings, initial_quality)
+    }
+
+    fn create_backup(&mut self) -> BackupStorage {
+        let mut backup = BackupStorage::default();
+        eframe::App::save(self, &mut backup);
+        // not part of the eframe storage on web
+        eframe::set_value(&mut backup, "SAVED_ROTATIONS", &self.saved_rotations_data);
+        backup
+    }
+
+    fn ui_language(&self) -> UiLanguage {
+        self.app_config
+            .ui_language
+            .unwrap_or(UiLanguage::from_locale(self.locale))
+    }
+
+    fn output_locale(&self) -> Locale {
+        self.app_config.output_locale.unwrap_or(self.locale)
+    }
+
+    fn apply_launch_options(&mut self, ctx: &egui::Context, options: LaunchOptions) {
+        if let Some(recipe_id) = options.recipe_id {
+            let recipe = raphael_data::RECIPES[&recipe_id];
+            self.crafter_config.selected_job = recipe.job_id;
+            if let Some(solver_config) = self.recipe_solver_configs.get(&recipe_id) {
+                self.solver_config = *solver_config;
+            }
+            self.recipe_config
+                .select_recipe(recipe_id, &mut self.recipe_hq_materials);
+            self.custom_recipe_overrides_config.use_custom_recipe = false;
+        }
+        if options.craftsmanship.is_some() || options.control.is_some() || options.cp.is_some() {
+            let stats = self.crafter_config.active_stats_mut();
+            stats.craftsmanship = options.craftsmanship.unwrap_or(stats.craftsmanship);
+            stats.control = options.control.unwrap_or(stats.control);
+            stats.cp = options.cp.unwrap_or(stats.cp);
+            self.crafter_config.detach_from_job();
+        }
+        if let Some(food) = options.food {
+            self.selected_food = food;
+        }
+        if let Some(potion) = options.potion {
+            self.selected_potion = potion;
+        }
+        if options.solve {
+            // picked up by the solve button, which waits for the thread pool to be initialized
+            ctx.data_mut(|data| data.insert_temp(Id::new("SOLVE_INITIATED"), true));
+        }
+    }
+
+    fn restore_backup(&mut self, ctx: &egui::Context, backup: &BackupStorage) {
+        let latest_release = self.latest_release.clone();
+        #[cfg(target_arch = "wasm32")]
+        let indexed_db_available = self.indexed_db_available;
+        // the restored app registers its own hotkey
+        #[cfg(not(target_arch = "wasm32"))]
+        self.copy_hotkey.unregister();
+        *self = Self::from_storage(ctx, Some(backup));
+        self.latest_release = latest_release;
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.indexed_db_available = indexed_db_available;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.window_geometry.skip_restore();
+            // the rotations in the storage file must not be merged back into the restored ones
+            self.saved_rotations_file.skip_stored_rotations();
+        }
+    }
+
+    fn restore_backup_text(&mut self, ctx: &egui::Context, text: &str) {
+        match BackupStorage::from_ron(text) {
+            Ok(backup) => {
+                self.restore_backup(ctx, &backup);
+                self.backup_window.open = true;
+                self.backup_window
+                    .set_status(Ok("Backup restored".to_owned()));
+            }
+            Err(error) => self.backup_window.set_status(Err(error.to_owned())),
+        }
+    }
+
+    fn handle_backup_request(&mut self, ctx: &egui::Context, request: BackupRequest) {
+        match request {
+            #[cfg(not(target_arch = "wasm32"))]
+            BackupRequest::ExportToFile => {
+                let text = self.create_backup().to_ron();
+                self.backup_window
+                    .set_status(crate::backup::backup_file_path().map_or_else(
+                        || Err("Failed to find the home directory".to_owned()),
+                        |path| match std::fs::write(&path, text) {
+                            Ok(()) => Ok(format!("Backup saved to {}", path.display())),
+                            Err(error) => Err(format!("Failed to save backup: {error}")),
+                        },
+                    ));
+            }
+            BackupRequest::CopyToClipboard => {
+                ctx.copy_text(self.create_backup().to_ron());
+                self.backup_window
+                    .set_status(Ok("Backup copied to clipboard".to_owned()));
+            }
+            BackupRequest::Restore(text) => self.restore_backup_text(ctx, &text),
+            BackupRequest::ResetSettings => {
+                let mut backup = self.create_backup();
+                backup.retain_keys(&SAVED_ROTATIONS_KEYS);
+                self.restore_backup(ctx, &backup);
+                self.backup_window.open = true;
+                self.backup_window
+                    .set_status(Ok("Settings reset".to_owned()));
+            }
+        }
+    }
+
+    fn repair_target(&self) -> RepairTarget {
+        let (game_settings, initial_quality) = self.solver_settings();
+        RepairTarget {
+            actions: self.actions.clone(),
+            solver_config: self.solver_config,
+            game_settings,
+            recipe_settings: util::get_game_settings(
+                &self.recipe_config,
+                &self.custom_recipe_overrides_config,
+                &self.solver_config,
+                &self.crafter_config,
+                self.selected_food,
+                self.selected_potion,
+            ),
+            initial_quality,
+        }
+    }
+
+    fn repair(&mut self, ctx: &egui::Context) {
+        let (action_prefix, state) = self.repair_window.repaired_state(&self.repair_target());
+        let (game_settings, _) = self.solver_settings();
+
+        self.solver_pending = true;
+        self.solver_interrupt.clear();
+        self.set_last_solve_params(ctx);
+        self.actions = Vec::new();
+        self.solver_action_prefix = action_prefix;
+        self.solver_progress.start();
+        spawn_solver(
+            self.macro_solver_settings(game_settings),
+            state,
+            Vec::new(),
+            Vec::new(),
+            &self.solver_context(),
+        );
+    }
+
+    fn solve(&mut self, ctx: &egui::Context) {
+        self.solver_pending = true;
+        self.solver_interrupt.clear();
+
+        if !self.custom_recipe_overrides_config.use_custom_recipe {
+            self.recipe_solver_configs
+                .insert(self.recipe_config.recipe_id, self.solver_config);
+        }
+
+        if self.solve_all_collectable_tiers && self.recipe_always_collectable() {
+            self.solve_collectable_tiers();
+            return;
+        }
+
+        let mut game_settings = util::get_game_settings(
+            &self.recipe_config,
+            &self.custom_recipe_overrides_config,
+            &self.solver_config,
+            &self.crafter_config,
+            self.selected_food,
+            self.selected_potion,
+        );
+        let initial_quality = util::get_initial_quality(
+            &self.recipe_config,
+            &self.custom_recipe_overrides_config,
+            &self.crafter_config,
+        );
+        self.set_last_solve_params(ctx);
+
+        if self.saved_rotations_config.load_from_saved_rotations
+            && let Some(actions) = self.saved_rotations_data.find_solved_rotation(
+                &game_settings,
+                initial_quality,
+                &self.solver_config,
+            )
+        {
+            self.solver_action_prefix = Vec::new();
+            self.solver_events.push(SolverEvent::Actions(actions));
+            self.solver_events.push(SolverEvent::LoadedFromHistory());
+            self.solver_events.push(SolverEvent::Finished(None, None));
+        } else {
+            let warm_start = self
+                .saved_rotations_data
+                .find_warm_start_rotation(
+                    &RecipeInfo::create_from(
+                        &self.recipe_config.recipe,
+                        &self.custom_recipe_overrides_config,
+                    ),
+                    &game_settings,
+                    initial_quality,
+                    &self.solver_config,
+                )
+                .unwrap_or_default();
+            let target_quality = self
+                .solver_config
+                .quality_target
+                .get_target(game_settings.max_quality);
+            // the tables of a 100% quality solve also work for the lower quality targets, whose rotations are found with them so that switching the target needs no new solve
+            let lower_quality_targets = match self.solver_config.quality_target {
+                QualityTarget::Full => vec![
+                    QualityTarget::Zero,
+                    QualityTarget::CollectableT1,
+                    QualityTarget::CollectableT2,
+                    QualityTarget::CollectableT3,
+                ],
+                _ => Vec::new(),
             }
+            .into_iter()
+            .map(|quality_target| {
+                let target_quality = quality_target.get_target(game_settings.max_quality);
+                (
+                    quality_target,
+                    u32::from(target_quality.saturating_sub(initial_quality)),
+                )
+            })
+            .collect();
+            game_settings.max_quality = target_quality.saturating_sub(initial_quality) as u16;
+            self.actions = Vec::new();
+            self.solver_action_prefix = Vec::new();
+            self.solver_progress.start();
+            spawn_solver(
+                self.macro_solver_settings(game_settings),
+                self.solver_config.initial_state(&game_settings),
+                warm_start,
+                lower_quality_targets,
+                &self.solver_context(),
+            );
+        }
+    }
+
+    /// The highest target the current rotation reaches if it falls short of the quality target, i.e. the highest collectable tier for collectables
+    fn find_target_downgrade(&self) -> Option<(QualityTarget, u16)> {
+        let game_settings = util::get_game_settings(
+            &self.recipe_config,
+            &self.custom_recipe_overrides_config,
+            &self.solver_config,
+            &self.crafter_config,
+            self.selected_food,
+            self.selected_potion,
+        );
+        let initial_quality = util::get_initial_quality(
+            &self.recipe_config,
+            &self.custom_recipe_overrides_config,
+            &self.crafter_config,
+        );
+        let (state, _) = self.solver_config.simulate(&game_settings, &self.actions);
+        if state.progress < u32::from(game_settings.max_progress) {
+            return None;
+        }
+        let target_quality = self
+            .solver_config
+            .quality_target
+            .get_target(game_settings.max_quality);
+        let reached_quality =
+            u16::try_from(u32::from(initial_quality) + state.quality).unwrap_or(u16::MAX);
+        if reached_quality >= target_quality {
+            return None;
+        }
+        if self.recipe_always_collectable() {
+            // a collectable below the lowest tier can't be turned in, so there is nothing to offer
+            [
+                QualityTarget::CollectableT3,
+                QualityTarget::CollectableT2,
+                QualityTarget::CollectableT1,
+            ]
+            .into_iter()
+            .map(|tier| (tier, tier.get_target(game_settings.max_quality)))
+            .find(|(_, tier_quality)| *tier_quality <= reached_quality)
         } else {
-            thread_pool::attempt_initialization(self.app_config.num_threads);
-            ctx.request_repaint();
+            (reached_quality > initial_quality)
+                .then_some((QualityTarget::Custom(reached_quality), reached_quality))
         }
     }
 
-    fn solve(&mut self, ctx: &egui::Context) {
+    fn recipe_always_collectable(&self) -> bool {
+        raphael_data::ITEMS
+            .get(&self.recipe_config.recipe.item_id)
+            .is_some_and(|item| item.always_collectable)
+    }
+
+    fn solve_collectable_tiers(&mut self) {
+        let mut game_settings = util::get_game_settings(
+            &self.recipe_config,
+            &self.custom_recipe_overrides_config,
+            &self.solver_config,
+            &self.crafter_config,
+            self.selected_food,
+            self.selected_potion,
+        );
+        let initial_quality = util::get_initial_quality(
+            &self.recipe_config,
+            &self.custom_recipe_overrides_config,
+            &self.crafter_config,
+        );
+        let quality_targets = [
+            QualityTarget::CollectableT1,
+            QualityTarget::CollectableT2,
+            QualityTarget::CollectableT3,
+            QualityTarget::Full,
+        ]
+        .map(|quality_target| {
+            let target_quality = quality_target.get_target(game_settings.max_quality);
+            (
+                quality_target,
+                u32::from(target_quality.saturating_sub(initial_quality)),
+            )
+        });
+        // the solver is set up for the highest target so that its precomputed tables can be shared by all tiers
+        game_settings.max_quality = game_settings.max_quality.saturating_sub(initial_quality);
+        self.tier_results_window.show();
+        self.solver_progress.start();
+        spawn_tier_solver(
+            self.macro_solver_settings(game_settings),
+            self.solver_config.initial_state(&game_settings),
+            quality_targets,
+            &self.solver_context(),
+        );
+    }
+
+    fn solve_compare_configs(&mut self) {
+        self.config_compare_window.clear_results();
+        let batch = self
+            .config_compare_window
+            .configs()
+            .iter()
+            .enumerate()
+            .map(|(index, solver_config)| {
+                let (game_settings, initial_quality) = self.solver_settings_for(solver_config);
+                (
+                    index,
+                    solver_config.macro_solver_settings(game_settings),
+                    solver_config.initial_state(&game_settings),
+                    initial_quality,
+                )
+            })
+            .collect();
         self.solver_pending = true;
         self.solver_interrupt.clear();
+        self.solver_progress.start();
+        spawn_batch_solver(batch, &self.solver_context());
+    }
+
+    fn compute_cp_tradeoff(&mut self) {
+        let (game_settings, _) = self.solver_settings();
+        self.cp_tradeoff_window.show();
+        self.solver_pending = true;
+        self.solver_interrupt.clear();
+        self.solver_progress.start();
+        spawn_cp_tradeoff_solver(
+            self.macro_solver_settings(game_settings),
+            self.solver_config.initial_state(&game_settings),
+            &self.solver_context(),
+        );
+    }
 
+    fn solve_hq_sweep(&mut self) {
         let mut game_settings = util::get_game_settings(
             &self.recipe_config,
             &self.custom_recipe_overrides_config,
@@ -1148,143 +2820,440 @@ impl MacroSolverApp {
             self.selected_food,
             self.selected_potion,
         );
-        let initial_quality = util::get_initial_quality(&self.recipe_config, &self.crafter_config);
-        ctx.data_mut(|data| {
-            data.insert_temp(
-                Id::new("LAST_SOLVE_PARAMS"),
-                (game_settings, initial_quality, self.solver_config),
-            );
-        });
+        // the solver is set up as if there were no initial Quality so that its precomputed tables can be shared by all counts
+        game_settings.max_quality = self
+            .solver_config
+            .quality_target
+            .get_target(game_settings.max_quality);
+        self.hq_sweep_window
+            .show(raphael_data::get_best_hq_ingredients_per_count(
+                *self.crafter_config.active_stats(),
+                self.recipe_config.recipe,
+            ));
+        self.solver_pending = true;
+        self.solver_interrupt.clear();
+        self.solver_progress.start();
+        spawn_hq_sweep_solver(
+            self.macro_solver_settings(game_settings),
+            self.solver_config.initial_state(&game_settings),
+            self.hq_sweep_window.initial_qualities(),
+            &self.solver_context(),
+        );
+    }
 
-        if self.saved_rotations_config.load_from_saved_rotations
-            && let Some(actions) = self.saved_rotations_data.find_solved_rotation(
+    fn solve_decision_tree(&mut self) {
+        let (game_settings, initial_quality) = self.solver_settings();
+        self.decision_tree_window.show(
+            initial_quality,
+            game_settings.max_quality.saturating_add(initial_quality),
+        );
+        self.solver_pending = true;
+        self.solver_interrupt.clear();
+        self.solver_progress.start();
+        spawn_decision_tree_solver(
+            self.macro_solver_settings(game_settings),
+            self.solver_config.initial_state(&game_settings),
+            raphael_data::modeled_conditions(self.recipe_config.recipe),
+            DECISION_TREE_DEPTH,
+            &self.solver_context(),
+        );
+    }
+
+    /// Solves the saved rotations that were solved with worse stats again, using the current stats of their job and their original solver configuration
+    fn resolve_outdated_rotations(&mut self) {
+        let mut batch = Vec::new();
+        self.rotation_resolves.clear();
+        for unique_id in self
+            .saved_rotations_data
+            .outdated_rotations(&self.crafter_config)
+        {
+            let Some(rotation) = self.saved_rotations_data.get(unique_id) else {
+                continue;
+            };
+            let (Some(recipe), Some(solve_info)) =
+                (rotation.recipe().copied(), &rotation.solve_info)
+            else {
+                continue;
+            };
+            let crafter_stats = self.crafter_config.crafter_stats[usize::from(recipe.job_id)];
+            let custom_recipe_overrides = match &rotation.recipe_info {
+                Some(RecipeInfo::CustomRecipe(_, config)) if config.use_custom_recipe => {
+                    Some(config.custom_recipe_overrides)
+                }
+                _ => None,
+            };
+            let (food, potion) = rotation.consumables();
+            let solver_config = solve_info.solver_config;
+            let mut game_settings = raphael_data::get_game_settings(
+                recipe,
+                custom_recipe_overrides,
+                crafter_stats,
+                food,
+                potion,
+            );
+            util::apply_solver_config(&mut game_settings, &solver_config);
+            // only the initial Quality from HQ materials depends on the stats
+            let initial_quality = match rotation.quality_source {
+                Some(QualitySource::HqMaterialList(hq_materials)) => {
+                    raphael_data::get_initial_quality(crafter_stats, recipe, hq_materials)
+                }
+                _ => solve_info.initial_quality,
+            };
+            let mut resolved_rotation = rotation.new_copy();
+            resolved_rotation.solver = solver_description(&solver_config);
+            resolved_rotation.crafter_stats = crafter_stats;
+            resolved_rotation.solve_info = Some(SolveInfo::new(
                 &game_settings,
                 initial_quality,
+                &solver_config,
+            ));
+            let mut solver_game_settings = game_settings;
+            solver_game_settings.max_quality = solver_config
+                .quality_target
+                .get_target(game_settings.max_quality)
+                .saturating_sub(initial_quality);
+            batch.push((
+                self.rotation_resolves.len(),
+                solver_config.macro_solver_settings(solver_game_settings),
+                solver_config.initial_state(&solver_game_settings),
+                initial_quality,
+            ));
+            self.rotation_resolves.push((unique_id, resolved_rotation));
+        }
+        if batch.is_empty() {
+            return;
+        }
+        self.rotation_resolve_pending = true;
+        self.solver_pending = true;
+        self.solver_interrupt.clear();
+        self.solver_progress.start();
+        spawn_batch_solver(batch, &self.solver_context());
+    }
+
+    /// Recipes of the selected job at the highest recipe level the crafter meets all requirements for.
+    /// Expert and level-scaling recipes are left out since they aren't crafted for experience.
+    fn find_leveling_recipes(&self) -> Vec<u32> {
+        self.find_highest_level_recipes(|_| true)
+            .into_iter()
+            .take(LEVELING_RECIPE_LIMIT)
+            .collect()
+    }
+
+    /// Collectables of the selected job at the highest level the crafter meets all requirements for, highest recipe level first,
+    /// which for a crafter at the max level are the collectables of the current patch
+    fn find_sweep_collectables(&self) -> Vec<u32> {
+        let mut recipe_ids = self.find_highest_level_recipes(|recipe| {
+            raphael_data::ITEMS
+                .get(&recipe.item_id)
+                .is_some_and(|item| item.always_collectable)
+        });
+        recipe_ids.sort_by_key(|recipe_id| {
+            std::cmp::Reverse(raphael_data::RECIPES[recipe_id].recipe_level)
+        });
+        recipe_ids.truncate(COLLECTABLE_SWEEP_RECIPE_LIMIT);
+        recipe_ids
+    }
+
+    /// Recipes of the selected job that pass `filter`, at the highest job level of those the crafter meets all requirements for.
+    /// Expert and level-scaling recipes are left out.
+    fn find_highest_level_recipes(
+        &self,
+        filter: impl Fn(&raphael_data::Recipe) -> bool,
+    ) -> Vec<u32> {
+        let crafter_stats = *self.crafter_config.active_stats();
+        let candidates: Vec<(u32, u8)> = raphael_data::RECIPES
+            .entries()
+            .filter(|(_, recipe)| {
+                recipe.job_id == self.crafter_config.selected_job
+                    && !recipe.is_expert
+                    && recipe.max_level_scaling == 0
+                    && filter(recipe)
+                    && raphael_data::RLVLS[usize::from(recipe.recipe_level)].job_level
+                        <= crafter_stats.level
+                    && raphael_data::missing_stats(
+                        recipe,
+                        crafter_stats,
+                        &[self.selected_food, self.selected_potion],
+                    ) == (0, 0)
+            })
+            .map(|(recipe_id, recipe)| {
+                (
+                    *recipe_id,
+                    raphael_data::RLVLS[usize::from(recipe.recipe_level)].job_level,
+                )
+            })
+            .collect();
+        let highest_level = candidates.iter().map(|(_, job_level)| *job_level).max();
+        candidates
+            .into_iter()
+            .filter(|(_, job_level)| Some(*job_level) == highest_level)
+            .map(|(recipe_id, _)| recipe_id)
+            .collect()
+    }
+
+    /// Leveling crafts are solved for full Quality without HQ materials, adversarially and without assumed opener conditions,
+    /// so that the listed Quality is reached no matter how the conditions roll
+    fn leveling_solver_config(mut solver_config: SolverConfig) -> SolverConfig {
+        solver_config.quality_target = QualityTarget::Full;
+        solver_config.adversarial = true;
+        solver_config.opener_conditions = [Condition::Normal; 2];
+        solver_config
+    }
+
+    fn solve_leveling_recipes(&mut self) {
+        let solver_config = Self::leveling_solver_config(self.solver_config);
+        let custom_recipe_overrides_config = CustomRecipeOverridesConfiguration::default();
+        let batch = self
+            .leveling_window
+            .recipe_ids()
+            .iter()
+            .enumerate()
+            .map(|(index, recipe_id)| {
+                let recipe_config = RecipeConfiguration {
+                    recipe_id: *recipe_id,
+                    recipe: raphael_data::RECIPES[recipe_id],
+                    quality_source: QualitySource::HqMaterialList([0; 6]),
+                };
+                let game_settings = util::get_game_settings(
+                    &recipe_config,
+                    &custom_recipe_overrides_config,
+                    &solver_config,
+                    &self.crafter_config,
+                    self.selected_food,
+                    self.selected_potion,
+                );
+                (
+                    index,
+                    solver_config.macro_solver_settings(game_settings),
+                    solver_config.initial_state(&game_settings),
+                    0,
+                )
+            })
+            .collect();
+        self.leveling_window.clear_results();
+        self.solver_pending = true;
+        self.solver_interrupt.clear();
+        self.solver_progress.start();
+        spawn_batch_solver(batch, &self.solver_context());
+    }
+
+    fn solve_collectable_sweep(&mut self) {
+        // collectables are solved without HQ materials, once per tier with the max Quality lowered to the Quality of the tier,
+        // which bounds each solve like solving for the tier as the target
+        let custom_recipe_overrides_config = CustomRecipeOverridesConfiguration::default();
+        let batch = self
+            .collectable_sweep_window
+            .tier_solves()
+            .map(|(index, recipe_id, quality_target)| {
+                let recipe_config = RecipeConfiguration {
+                    recipe_id,
+                    recipe: raphael_data::RECIPES[&recipe_id],
+                    quality_source: QualitySource::HqMaterialList([0; 6]),
+                };
+                let mut solver_config = SolverConfig {
+                    quality_target,
+                    ..self.solver_config
+                };
+                solver_config.restrict_opener_conditions(recipe_config.recipe);
+                let mut game_settings = util::get_game_settings(
+                    &recipe_config,
+                    &custom_recipe_overrides_config,
+                    &solver_config,
+                    &self.crafter_config,
+                    self.selected_food,
+                    self.selected_potion,
+                );
+                game_settings.max_quality = quality_target.get_target(game_settings.max_quality);
+                (
+                    index,
+                    solver_config.macro_solver_settings(game_settings),
+                    solver_config.initial_state(&game_settings),
+                    0,
+                )
+            })
+            .collect();
+        self.collectable_sweep_window.clear_results();
+        self.solver_pending = true;
+        self.solver_interrupt.clear();
+        self.solver_progress.start();
+        spawn_batch_solver(batch, &self.solver_context());
+    }
+
+    /// Crafter configuration with the stats of the gearset, other stats such as the job level are kept
+    fn gearset_crafter_config(&self, gearset: &Gearset) -> CrafterConfig {
+        let mut crafter_config = self.crafter_config;
+        let stats = crafter_config.active_stats_mut();
+        stats.craftsmanship = gearset.craftsmanship;
+        stats.control = gearset.control;
+        stats.cp = gearset.cp;
+        crafter_config.detach_from_job();
+        crafter_config
+    }
+
+    fn evaluate_gearsets(&mut self) {
+        self.gearset_window.clear_results();
+        let mut gearset_settings = Vec::new();
+        for (index, gearset) in self.gearsets.iter().enumerate() {
+            let crafter_config = self.gearset_crafter_config(gearset);
+            let (missing_cms, missing_ctrl) = raphael_data::missing_stats(
+                &self.recipe_config.recipe,
+                *crafter_config.active_stats(),
+                &[self.selected_food, self.selected_potion],
+            );
+            if missing_cms != 0 || missing_ctrl != 0 {
+                self.gearset_window
+                    .push_result(index, GearsetResult::BelowRequirement);
+                continue;
+            }
+            let mut game_settings = util::get_game_settings(
+                &self.recipe_config,
+                &self.custom_recipe_overrides_config,
                 &self.solver_config,
-            )
-        {
-            let mut solver_events = self.solver_events.lock().unwrap();
-            solver_events.push_back(SolverEvent::Actions(actions));
-            solver_events.push_back(SolverEvent::LoadedFromHistory());
-            solver_events.push_back(SolverEvent::Finished(None));
-        } else {
+                &crafter_config,
+                self.selected_food,
+                self.selected_potion,
+            );
+            let initial_quality = util::get_initial_quality(
+                &self.recipe_config,
+                &self.custom_recipe_overrides_config,
+                &crafter_config,
+            );
             let target_quality = self
                 .solver_config
                 .quality_target
                 .get_target(game_settings.max_quality);
-            game_settings.max_quality = target_quality.saturating_sub(initial_quality) as u16;
-            self.actions = Vec::new();
-            self.solver_progress = 0;
-            self.start_time = web_time::Instant::now();
-            spawn_solver(
-                game_settings,
-                self.solver_events.clone(),
-                self.solver_interrupt.clone(),
-            );
+            game_settings.max_quality = target_quality.saturating_sub(initial_quality);
+            gearset_settings.push((
+                index,
+                self.macro_solver_settings(game_settings),
+                self.solver_config.initial_state(&game_settings),
+                initial_quality,
+            ));
         }
+        self.solver_pending = true;
+        self.solver_interrupt.clear();
+        self.solver_progress.start();
+        spawn_batch_solver(gearset_settings, &self.solver_context());
     }
 
-    fn find_minimum_stats(&mut self, game_settings: &raphael_sim::Settings) {
-        if self.solver_pending || self.custom_recipe_overrides_config.use_base_increase_overrides {
+    /// Keeps the minimum stats in sync with the rotation, recomputing them in the background whenever their parameters change
+    fn update_minimum_stats(&mut self, game_settings: &raphael_sim::Settings) {
+        let Some(target_quality) = self.minimum_stats_target_quality(game_settings) else {
             if self.minimum_stats_params_hash != 0 {
                 self.minimum_stats_params_hash = 0;
                 self.minimum_stats = MinimumStats::default();
             }
             return;
-        }
-
-        let mut game_settings = *game_settings;
-        let initial_quality = util::get_initial_quality(&self.recipe_config, &self.crafter_config);
-
-        let target_progress = game_settings.max_progress as u32;
-        let target_quality = self
-            .solver_config
-            .quality_target
-            .get_target(game_settings.max_quality)
-            .saturating_sub(initial_quality) as u32;
+        };
 
-        let params_hash = egui::Id::new((&game_settings, target_quality, &self.actions)).value();
+        let params_hash = egui::Id::new((
+            game_settings,
+            self.solver_config,
+            target_quality,
+            &self.actions,
+        ))
+        .value();
         if self.minimum_stats_params_hash == params_hash {
             return;
         }
         self.minimum_stats_params_hash = params_hash;
 
-        let initial_state = raphael_sim::SimulationState::new(&game_settings);
-
-        let mut actual_result = initial_state;
-        for action in &self.actions {
-            actual_result = actual_result
-                .use_action(*action, raphael_sim::Condition::Normal, &game_settings)
-                .unwrap_or(actual_result);
-        }
-        if actual_result.progress < target_progress {
-            self.minimum_stats = MinimumStats::default();
+        let game_settings = *game_settings;
+        let solver_config = self.solver_config;
+        let actions = self.actions.clone();
+        let recipe = self.recipe_config.recipe;
+        if !thread_pool::is_initialized() {
+            self.minimum_stats = util::find_minimum_stats(
+                game_settings,
+                &solver_config,
+                target_quality,
+                &actions,
+                &recipe,
+            );
             return;
         }
-        self.minimum_stats.cp = Some(game_settings.max_cp - actual_result.cp);
+        let solver_events = self.solver_events.clone();
+        rayon::spawn(move || {
+            let minimum_stats = util::find_minimum_stats(
+                game_settings,
+                &solver_config,
+                target_quality,
+                &actions,
+                &recipe,
+            );
+            solver_events.push(SolverEvent::MinimumStats(params_hash, minimum_stats));
+        });
+    }
 
-        let (mut min_progress, mut max_progress) = (1u16, game_settings.base_progress);
-        let (mut min_quality, mut max_quality, mut can_target_quality) =
-            if actual_result.quality >= target_quality {
-                (1u16, game_settings.base_quality, true)
-            } else {
-                (game_settings.base_quality, game_settings.base_quality * 3 / 2, false)
-            };
-        if target_quality == 0 || self.actions[0] == Action::TrainedEye {
-            max_quality = 1;
-        }
-        while min_progress + 1 < max_progress || min_quality + 1 < max_quality {
-            let mut state = initial_state;
-            game_settings.base_progress = (min_progress + max_progress) / 2;
-            game_settings.base_quality = (min_quality + max_quality) / 2;
-            for action in &self.actions {
-                state = state
-                    .use_action(*action, raphael_sim::Condition::Normal, &game_settings)
-                    .unwrap_or(state);
-            }
-            if state.progress < target_progress {
-                min_progress = game_settings.base_progress;
-            } else {
-                max_progress = game_settings.base_progress;
-            }
-            if state.quality < target_quality {
-                min_quality = game_settings.base_quality;
-            } else {
-                max_quality = game_settings.base_quality;
-                can_target_quality = true;
-            }
+    /// Quality the rotation has to add to the initial quality, or `None` if no minimum stats should be shown
+    fn minimum_stats_target_quality(&self, game_settings: &raphael_sim::Settings) -> Option<u32> {
+        if self.solver_pending || self.custom_recipe_overrides_config.use_base_increase_overrides {
+            return None;
         }
-
-        let max_level_scaling = self.recipe_config.recipe.max_level_scaling;
-        let rlvl = if max_level_scaling != 0 {
-            let job_level = std::cmp::min(max_level_scaling, game_settings.job_level);
-            raphael_data::LEVEL_ADJUST_TABLE[job_level as usize] as usize
-        } else {
-            self.recipe_config.recipe.recipe_level as usize
-        };
-        let rlvl_record = raphael_data::RLVLS[rlvl];
-        let mut craftsmanship = max_progress as f32;
-        let mut control = max_quality as f32;
-        if game_settings.job_level <= rlvl_record.job_level {
-            craftsmanship = craftsmanship * 100.0 / rlvl_record.progress_mod as f32;
-            control = control * 100.0 / rlvl_record.quality_mod as f32;
-        }
-        craftsmanship = (craftsmanship - 2.0) * rlvl_record.progress_div as f32 / 10.0;
-        control = (control - 35.0) * rlvl_record.quality_div as f32 / 10.0;
-        self.minimum_stats.craftsmanship = Some(craftsmanship.ceil() as u16);
-        self.minimum_stats.control = if can_target_quality {
-            Some(control.ceil() as u16)
-        } else {
-            None
-        };
+        let initial_quality = util::get_initial_quality(
+            &self.recipe_config,
+            &self.custom_recipe_overrides_config,
+            &self.crafter_config,
+        );
+        Some(
+            self.solver_config
+                .quality_target
+                .get_target(game_settings.max_quality)
+                .saturating_sub(initial_quality) as u32,
+        )
     }
 
     fn draw_macro_output_widget(&mut self, ui: &mut egui::Ui) {
-        ui.add(MacroView::new(
-            &mut self.actions,
-            &mut self.macro_view_config,
-            self.locale,
-        ));
+        let output_locale = self.output_locale();
+        let game_settings = util::get_game_settings(
+            &self.recipe_config,
+            &self.custom_recipe_overrides_config,
+            &self.solver_config,
+            &self.crafter_config,
+            self.selected_food,
+            self.selected_potion,
+        );
+        let initial_quality = util::get_initial_quality(
+            &self.recipe_config,
+            &self.custom_recipe_overrides_config,
+            &self.crafter_config,
+        );
+        ui.add(
+            MacroView::new(
+                &mut self.actions,
+                &mut self.macro_view_config,
+                output_locale,
+            )
+            .with_simulation(MacroSimulation {
+                settings: game_settings,
+                initial_state: self.solver_config.initial_state(&game_settings),
+                initial_quality,
+            })
+            .with_consumables(self.selected_food, self.selected_potion),
+        );
+        if !self.actions.is_empty() {
+            ui.add(ConditionEstimate::new(
+                &game_settings,
+                initial_quality,
+                &self.solver_config,
+                &self.actions,
+                &self.recipe_config,
+                &self.custom_recipe_overrides_config,
+            ));
+        }
+        if self.solver_config.quality_target == QualityTarget::Zero
+            && !self.custom_recipe_overrides_config.use_custom_recipe
+        {
+            ui.add(QuickSynthesisAdvisor::new(
+                &game_settings,
+                &self.solver_config,
+                &self.actions,
+                self.recipe_config.recipe,
+                *self.crafter_config.active_stats(),
+                self.selected_food,
+                self.selected_potion,
+            ));
+        }
     }
 
     fn experimental_warning_text() -> &'static str {
@@ -1324,38 +3293,33 @@ impl MacroSolverApp {
         });
 
         #[cfg(not(target_arch = "wasm32"))]
-        // native storage caches values forever, we can only read it from file manually
         let mut sync_saved_rotations = || {
-            match eframe::storage_dir("Raphael XIV") {
-                Some(path) => {
-                    let uri = format!("file://{}", path.join("app.ron").to_str().unwrap());
-                    match ctx.try_load_bytes(&uri) {
-                        Ok(egui::load::BytesPoll::Ready { bytes, .. }) => {
-                            type KV = std::collections::HashMap<String, String>;
-                            if let Ok(kv) = ron::de::from_bytes::<KV>(&bytes) {
-                                if let Some(value) = kv.get("SAVED_ROTATIONS") {
-                                    if let Ok(value) = ron::de::from_str(value) {
-                                        self.saved_rotations_data = value;
-                                    }
-                                }
-                            }
-                            for loader in ctx.loaders().bytes.lock().iter() {
-                                loader.forget(&uri);
-                            }
-                            true
-                        },
-                        _ => false,  // waiting file IO
-                    }
-                },
-                None => true,  // no storage, continue subsequent operations as success
-            }
+            self.saved_rotations_file
+                .sync(&mut self.saved_rotations_data, &self.saved_rotations_config);
+            true
         };
         #[cfg(target_arch = "wasm32")]
-        // web storage retrieves value from browser directly
+        // saved rotations are read from IndexedDB in the background
         let mut sync_saved_rotations = || {
+            let pending_read = self
+                .saved_rotations_read
+                .get_or_insert_with(|| crate::web_storage::read("SAVED_ROTATIONS", ctx.clone()));
+            let Some(result) = pending_read.lock().unwrap().take() else {
+                return false; // waiting IndexedDB
+            };
+            self.saved_rotations_read = None;
+            self.indexed_db_available = Some(result.is_ok());
+            if let Ok(Some(value)) = result
+                && let Ok(value) = ron::de::from_str(&value)
+            {
+                self.saved_rotations_data
+                    .merge(value, &self.saved_rotations_config);
+            }
+            // web storage retrieves value from browser directly
             if let Some(storage) = _frame.storage() {
                 if let Some(value) = eframe::get_value(storage, "SAVED_ROTATIONS") {
-                    self.saved_rotations_data = value;
+                    self.saved_rotations_data
+                        .merge(value, &self.saved_rotations_config);
                 }
             }
             true
@@ -1372,12 +3336,53 @@ impl MacroSolverApp {
     }
 }
 
-fn set_fonts(ctx: &egui::Context, locale: Locale) {
+/// Returns whether `key` was pressed while the modal is the top-most one.
+/// Enter is left to the focused widget if there is one, e.g. to press a focused button.
+pub(crate) fn modal_key_pressed<T>(modal: &egui::ModalResponse<T>, key: egui::Key) -> bool {
+    let ctx = &modal.response.ctx;
+    if !modal.is_top_modal || modal.any_popup_open {
+        return false;
+    }
+    let focused_in_modal = ctx
+        .memory(egui::Memory::focused)
+        .and_then(|id| ctx.read_response(id))
+        .is_some_and(|response| response.layer_id == modal.response.layer_id);
+    if key == egui::Key::Enter && focused_in_modal {
+        return false;
+    }
+    ctx.input_mut(|input| input.consume_key(egui::Modifiers::NONE, key))
+}
+
+/// Escape or Enter closes modals that only show information
+fn modal_dismissed<T>(modal: &egui::ModalResponse<T>) -> bool {
+    modal_key_pressed(modal, egui::Key::Escape) || modal_key_pressed(modal, egui::Key::Enter)
+}
+
+/// Outlines the focused widget, since most widgets look the same whether they have focus or not
+fn draw_focus_outline(ctx: &egui::Context) {
+    let Some(response) = ctx
+        .memory(egui::Memory::focused)
+        .and_then(|id| ctx.read_response(id))
+    else {
+        return;
+    };
+    let stroke = ctx.style().visuals.selection.stroke;
+    ctx.layer_painter(response.layer_id).rect_stroke(
+        response.rect.expand(1.0),
+        2.0,
+        egui::Stroke::new(stroke.width.max(1.5), stroke.color),
+        egui::StrokeKind::Outside,
+    );
+}
+
+fn set_fonts(ctx: &egui::Context, locale: Locale, ui_language: UiLanguage, output_locale: Locale) {
+    let fallback_font = font_fallback::loaded_font();
+    let current = (locale, ui_language, output_locale, fallback_font.is_some());
     let egui_id_current = egui::Id::new("font_locale");
-    if ctx.data(|data| data.get_temp(egui_id_current)) == Some(locale) {
+    if ctx.data(|data| data.get_temp(egui_id_current)) == Some(current) {
         return;
     }
-    ctx.data_mut(|data| data.insert_temp(egui_id_current, locale));
+    ctx.data_mut(|data| data.insert_temp(egui_id_current, current));
 
     let mut fonts = egui::FontDefinitions::default();
     let mut add_font = |font_name: &'static str, font_data: egui::FontData| {
@@ -1392,6 +3397,29 @@ fn set_fonts(ctx: &egui::Context, locale: Locale) {
             "../assets/fonts/XIV_Icon_Recreations/XIV_Icon_Recreations.ttf"
         ))
     );
+    // fonts added first take precedence, so that game data is shown in the glyph style of its locale
+    add_locale_font(&mut add_font, locale);
+    let ui_locale = match ui_language {
+        UiLanguage::EN => locale,
+        UiLanguage::JP => Locale::JP,
+        UiLanguage::CN => Locale::CN,
+    };
+    if ui_locale != locale {
+        add_locale_font(&mut add_font, ui_locale);
+    }
+    if output_locale != locale && output_locale != ui_locale {
+        add_locale_font(&mut add_font, output_locale);
+    }
+    if let Some(fallback_font) = fallback_font {
+        fonts.font_data.insert("Fallback".to_owned(), fallback_font);
+        for family in fonts.families.values_mut() {
+            family.push("Fallback".to_owned());
+        }
+    }
+    ctx.set_fonts(fonts);
+}
+
+fn add_locale_font(add_font: &mut impl FnMut(&'static str, egui::FontData), locale: Locale) {
     match locale {
         Locale::JP => add_font(
             "MPLUS1Code",
@@ -1409,6 +3437,20 @@ fn set_fonts(ctx: &egui::Context, locale: Locale) {
                 ..Default::default()
             })
         ),
+        Locale::TW => {
+            add_font(
+                "NotoSansTC",
+                egui::FontData::from_static(include_bytes!(
+                    "../assets/fonts/Noto_Sans_SC/subset_tw.ttf"
+                ))
+                .tweak(egui::FontTweak {
+                    baseline_offset_factor: -0.05,
+                    ..Default::default()
+                }),
+            );
+            // items without a Traditional Chinese name are shown with their Simplified Chinese name
+            add_locale_font(add_font, Locale::CN);
+        }
         Locale::KR => add_font(
             "NotoSansKR",
             egui::FontData::from_static(include_bytes!(
@@ -1421,69 +3463,53 @@ fn set_fonts(ctx: &egui::Context, locale: Locale) {
         ),
         _ => (),
     }
-    ctx.set_fonts(fonts);
 }
 
-fn spawn_solver(
-    simulator_settings: raphael_sim::Settings,
-    solver_events: Arc<Mutex<VecDeque<SolverEvent>>>,
-    solver_interrupt: raphael_solver::AtomicFlag,
-) {
-    let events = solver_events.clone();
-    let solution_callback = move |actions: &[raphael_sim::Action]| {
-        let event = SolverEvent::Actions(actions.to_vec());
-        events.lock().unwrap().push_back(event);
-    };
-    let events = solver_events.clone();
-    let progress_callback = move |progress: usize| {
-        let event = SolverEvent::NodesVisited(progress);
-        events.lock().unwrap().push_back(event);
-    };
-    rayon::spawn(move || {
-        let solver_settings = raphael_solver::SolverSettings { simulator_settings };
-        log::debug!("Spawning solver: {solver_settings:?}");
-        let mut macro_solver = raphael_solver::MacroSolver::new(
-            solver_settings,
-            Box::new(solution_callback),
-            Box::new(progress_callback),
-            solver_interrupt,
-        );
-        match macro_solver.solve() {
-            Ok(actions) => {
-                let mut solver_events = solver_events.lock().unwrap();
-                solver_events.push_back(SolverEvent::Actions(actions));
-                solver_events.push_back(SolverEvent::Finished(None));
-            }
-            Err(exception) => solver_events
-                .lock()
-                .unwrap()
-                .push_back(SolverEvent::Finished(Some(exception))),
-        }
-    });
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn fetch_latest_version(latest_version: Arc<Mutex<semver::Version>>) {
+fn fetch_latest_release(latest_release: Arc<Mutex<LatestRelease>>, update_check: UpdateCheck) {
     #[derive(Deserialize)]
     struct ApiResponse {
         tag_name: String,
+        #[serde(default)]
+        body: Option<String>,
     }
-    let request =
-        ehttp::Request::get("https://api.github.com/repos/Asvel/ffxiv-raphael-cn/releases/latest");
+    let url = match update_check {
+        UpdateCheck::Disabled => return,
+        UpdateCheck::Stable => {
+            "https://api.github.com/repos/Asvel/ffxiv-raphael-cn/releases/latest"
+        }
+        // unlike the latest release, the list of releases includes pre-releases, newest first
+        UpdateCheck::Prerelease => {
+            "https://api.github.com/repos/Asvel/ffxiv-raphael-cn/releases?per_page=1"
+        }
+    };
     ehttp::fetch(
-        request,
-        move |result: ehttp::Result<ehttp::Response>| match result {
-            Ok(response) => match response.json::<ApiResponse>() {
-                Ok(data) => match semver::Version::parse(data.tag_name.trim_start_matches('v')) {
-                    Ok(version) => {
-                        log::debug!("Latest version: {}", version);
-                        *latest_version.lock().unwrap() = version;
+        ehttp::Request::get(url),
+        move |result: ehttp::Result<ehttp::Response>| {
+            let release = result.and_then(|response| {
+                let release = match update_check {
+                    UpdateCheck::Prerelease => response
+                        .json::<Vec<ApiResponse>>()
+                        .map(|releases| releases.into_iter().next()),
+                    _ => response.json::<ApiResponse>().map(Some),
+                };
+                release.map_err(|err| err.to_string())
+            });
+            match release {
+                Ok(Some(data)) => {
+                    match semver::Version::parse(data.tag_name.trim_start_matches('v')) {
+                        Ok(version) => {
+                            log::debug!("Latest version: {}", version);
+                            *latest_release.lock().unwrap() = LatestRelease {
+                                version,
+                                notes: data.body.unwrap_or_default(),
+                            };
+                        }
+                        Err(err) => log::error!("{err}"),
                     }
-                    Err(err) => log::error!("{err}"),
-                },
+                }
+                Ok(None) => {}
                 Err(err) => log::error!("{err}"),
-            },
-            Err(err) => log::error!("{err}"),
+            }
         },
     );
 }
@@ -0,0 +1,43 @@
+use std::collections::HashMap;
+
+/// In-memory storage with the same keys and values as the eframe storage of the app.
+/// The app state is written to it with `eframe::App::save` and read from it like it is read on startup.
+#[derive(Debug, Default)]
+pub struct BackupStorage {
+    kv: HashMap<String, String>,
+}
+
+impl BackupStorage {
+    pub fn from_ron(text: &str) -> Result<Self, &'static str> {
+        ron::de::from_str(text)
+            .map(|kv| Self { kv })
+            .map_err(|_| "The backup is invalid or damaged")
+    }
+
+    pub fn to_ron(&self) -> String {
+        ron::ser::to_string_pretty(&self.kv, ron::ser::PrettyConfig::default()).unwrap()
+    }
+
+    /// Keeps only the given keys, e.g. to reset everything except the saved rotations
+    pub fn retain_keys(&mut self, keys: &[&str]) {
+        self.kv.retain(|key, _| keys.contains(&key.as_str()));
+    }
+}
+
+impl eframe::Storage for BackupStorage {
+    fn get_string(&self, key: &str) -> Option<String> {
+        self.kv.get(key).cloned()
+    }
+
+    fn set_string(&mut self, key: &str, value: String) {
+        self.kv.insert(key.to_owned(), value);
+    }
+
+    fn flush(&mut self) {}
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Location of exported backups, a place that is easy to find unlike the eframe storage directory
+pub fn backup_file_path() -> Option<std::path::PathBuf> {
+    std::env::home_dir().map(|path| path.join("raphael-xiv-backup.ron"))
+}
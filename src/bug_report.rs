@@ -0,0 +1,43 @@
+//! Machine-readable context for bug reports about solver errors.
+//!
+//! The context is JSON so that the failing solve can be reproduced from it, e.g. by deserializing the settings in a test case.
+
+use std::sync::Mutex;
+
+use raphael_solver::{SolverException, SolverSettings};
+use serde::Serialize;
+
+/// Settings of the most recently spawned solver, since most errors and crashes happen while solving
+static SOLVER_SETTINGS: Mutex<Option<SolverSettings>> = Mutex::new(None);
+
+#[derive(Serialize)]
+struct BugReportContext<'a> {
+    version: &'static str,
+    platform: String,
+    exception: &'a SolverException,
+    solver_settings: Option<SolverSettings>,
+}
+
+pub fn set_solver_settings(settings: &SolverSettings) {
+    // the mutex may be poisoned if a panic happened while holding it
+    *SOLVER_SETTINGS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(*settings);
+}
+
+pub fn solver_settings() -> Option<SolverSettings> {
+    *SOLVER_SETTINGS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// The exception together with the version, platform and the settings of the most recently spawned solver
+pub fn context(exception: &SolverException) -> String {
+    let context = BugReportContext {
+        version: env!("CARGO_PKG_VERSION"),
+        platform: format!("{} {}", std::env::consts::OS, std::env::consts::ARCH),
+        exception,
+        solver_settings: solver_settings(),
+    };
+    serde_json::to_string_pretty(&context).unwrap_or_else(|err| err.to_string())
+}
@@ -1,18 +1,41 @@
+use std::collections::HashMap;
 use std::num::NonZeroUsize;
 
-use raphael_data::{CrafterStats, CustomRecipeOverrides, Recipe};
+use raphael_data::{CrafterStats, CustomRecipeOverrides, Locale, Recipe};
 use serde::{Deserialize, Serialize};
 
+use crate::translations::UiLanguage;
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum QualitySource {
     HqMaterialList([u8; 6]),
     Value(u16),
+    /// Percentage of the recipe's max quality
+    Percentage(u8),
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct AppConfig {
     pub zoom_percentage: u16,
     pub num_threads: Option<NonZeroUsize>,
+    #[serde(default)]
+    pub keep_solver_tables: bool,
+    /// Cross-check the game settings and the simulator against a reference implementation of the game's formulas
+    #[serde(default)]
+    pub verify_calculations: bool,
+    #[serde(default)]
+    pub window_geometry: Option<WindowGeometry>,
+    /// `None` follows the locale of the game data
+    #[serde(default)]
+    pub ui_language: Option<UiLanguage>,
+    /// Locale of macros and copied item names, for sharing them with players of other regions.
+    /// `None` follows the locale of the game data
+    #[serde(default)]
+    pub output_locale: Option<Locale>,
+    #[serde(default)]
+    pub bar_style: BarStyle,
+    #[serde(default)]
+    pub update_check: UpdateCheck,
 }
 
 impl Default for AppConfig {
@@ -20,10 +43,268 @@ impl Default for AppConfig {
         Self {
             zoom_percentage: 100,
             num_threads: None,
+            keep_solver_tables: false,
+            verify_calculations: false,
+            window_geometry: None,
+            ui_language: None,
+            output_locale: None,
+            bar_style: BarStyle::default(),
+            update_check: UpdateCheck::default(),
+        }
+    }
+}
+
+/// Releases that the app looks for on GitHub when launched
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UpdateCheck {
+    Disabled,
+    #[default]
+    Stable,
+    /// Also notify about pre-releases
+    Prerelease,
+}
+
+impl UpdateCheck {
+    pub const ALL: [Self; 3] = [Self::Disabled, Self::Stable, Self::Prerelease];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Disabled => "Never",
+            Self::Stable => "Stable releases",
+            Self::Prerelease => "Including pre-releases",
+        }
+    }
+}
+
+/// Size and position of the native window, in points
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WindowGeometry {
+    pub inner_size: [f32; 2],
+    pub outer_position: Option<[f32; 2]>,
+    /// Size of the monitor the window was on, the position is only restored on a monitor of the same size
+    pub monitor_size: Option<[f32; 2]>,
+    pub maximized: bool,
+}
+
+/// Appearance of the Progress, Quality, Durability and CP bars in the simulator
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BarStyle {
+    pub palette: BarPalette,
+    /// Draws a different pattern over each bar, so that they can be told apart without colors
+    pub patterns: bool,
+    #[serde(default)]
+    pub number_format: BarNumberFormat,
+    /// Leaves out the minimum Craftsmanship and Control, which don't fit on narrow bars
+    #[serde(default)]
+    pub hide_minimum_stats: bool,
+}
+
+/// How the values are shown on the simulator bars
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BarNumberFormat {
+    /// e.g. "12345 / 14000"
+    #[default]
+    Raw,
+    /// e.g. "12.3k / 14.0k"
+    Compact,
+    /// e.g. "88%"
+    Percentage,
+}
+
+impl BarNumberFormat {
+    pub const ALL: [Self; 3] = [Self::Raw, Self::Compact, Self::Percentage];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Raw => "Raw values",
+            Self::Compact => "Compact values",
+            Self::Percentage => "Percentage",
         }
     }
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BarPalette {
+    /// Same color for all bars
+    #[default]
+    Default,
+    /// Okabe-Ito colors, which can be distinguished with the common types of color vision deficiency
+    Colorblind,
+    /// Saturated colors with outlined bars and black or white text
+    HighContrast,
+}
+
+impl BarPalette {
+    pub const ALL: [Self; 3] = [Self::Default, Self::Colorblind, Self::HighContrast];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::Colorblind => "Colorblind-friendly",
+            Self::HighContrast => "High contrast",
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Discord webhook that is notified when a long solve finishes, for users who walk away from the computer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    #[serde(default)]
+    pub discord_webhook_url: String,
+    /// Solves that finish sooner are not notified
+    #[serde(default = "WebhookConfig::default_min_solve_duration")]
+    pub min_solve_duration_secs: u32,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl WebhookConfig {
+    fn default_min_solve_duration() -> u32 {
+        60
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            discord_webhook_url: String::new(),
+            min_solve_duration_secs: Self::default_min_solve_duration(),
+        }
+    }
+}
+
+/// Crafter stats of a gearset from a gear planner such as xivgear, to be compared with other gearsets
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Gearset {
+    pub name: String,
+    pub craftsmanship: u16,
+    pub control: u16,
+    pub cp: u16,
+}
+
+impl Gearset {
+    /// Parses a line such as `BiS 5000/5000/600` or `BiS, 5000, 5000, 600`.
+    /// The last three numbers are craftsmanship, control and CP, the text before them is the name.
+    pub fn from_line(line: &str) -> Option<Self> {
+        let is_separator = |c: char| c.is_whitespace() || matches!(c, ',' | '/' | ':' | ';');
+        let mut rest = line.trim_end_matches(is_separator);
+        let mut stats = [0; 3];
+        for stat in stats.iter_mut().rev() {
+            let start = rest
+                .char_indices()
+                .rfind(|(_, c)| is_separator(*c))
+                .map_or(0, |(index, c)| index + c.len_utf8());
+            *stat = rest[start..].parse().ok()?;
+            rest = rest[..start].trim_end_matches(is_separator);
+        }
+        let [craftsmanship, control, cp] = stats;
+        Some(Self {
+            name: rest.trim_start().to_owned(),
+            craftsmanship,
+            control,
+            cp,
+        })
+    }
+}
+
+/// HQ items owned by the crafter, imported from an inventory export of e.g. Allagan Tools or Teamcraft
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HqInventory {
+    /// Amount of HQ items by lowercase item name
+    amounts: HashMap<String, u32>,
+}
+
+impl HqInventory {
+    /// Parses comma or tab separated values with a header row.
+    /// The name, amount and HQ columns are found by their header and rows of NQ items are skipped.
+    /// Names that end with the HQ icon are counted as HQ even without an HQ column.
+    pub fn from_csv(text: &str) -> Result<Self, &'static str> {
+        let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+        let header = lines.next().ok_or("The inventory is empty")?;
+        let separator = if header.contains('\t') { '\t' } else { ',' };
+        let columns = split_csv_line(header, separator);
+        let find_column = |names: &[&str]| {
+            columns
+                .iter()
+                .position(|column| names.contains(&column.to_lowercase().as_str()))
+        };
+        let name_column =
+            find_column(&["name", "item", "item name"]).ok_or("No item name column")?;
+        let amount_column = find_column(&["quantity", "qty", "amount", "count", "total"])
+            .ok_or("No quantity column")?;
+        let hq_column = find_column(&["hq", "is hq", "quality"]);
+        let mut amounts = HashMap::new();
+        for line in lines {
+            let fields = split_csv_line(line, separator);
+            let Some(name) = fields.get(name_column) else {
+                continue;
+            };
+            let has_hq_icon = name.ends_with(raphael_data::HQ_ICON_CHAR);
+            let name = name.trim_end_matches(raphael_data::HQ_ICON_CHAR).trim_end();
+            let is_hq = has_hq_icon
+                || hq_column
+                    .and_then(|column| fields.get(column))
+                    .is_some_and(|value| {
+                        matches!(
+                            value.to_lowercase().as_str(),
+                            "hq" | "true" | "yes" | "y" | "1"
+                        )
+                    });
+            let amount: u32 = fields
+                .get(amount_column)
+                .and_then(|amount| amount.replace([',', '.', ' '], "").parse().ok())
+                .ok_or("Invalid quantity")?;
+            if is_hq {
+                *amounts.entry(name.to_lowercase()).or_default() += amount;
+            }
+        }
+        Ok(Self { amounts })
+    }
+
+    /// Owned amount of the item in HQ, the item is looked up by its name in all languages
+    pub fn hq_amount(&self, item_id: u32) -> u32 {
+        [
+            Locale::EN,
+            Locale::DE,
+            Locale::FR,
+            Locale::JP,
+            Locale::CN,
+            Locale::TW,
+            Locale::KR,
+        ]
+        .into_iter()
+        .filter_map(|locale| raphael_data::get_item_name_raw(item_id, locale))
+        .find_map(|name| self.amounts.get(&name.to_lowercase()))
+        .copied()
+        .unwrap_or(0)
+    }
+
+    /// Number of distinct HQ items
+    pub fn item_count(&self) -> usize {
+        self.amounts.len()
+    }
+}
+
+/// Splits a line of comma separated values, separators inside double quotes are part of the field
+fn split_csv_line(line: &str, separator: char) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            c if c == separator && !quoted => fields.push(String::new()),
+            c => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields.iter().map(|field| field.trim().to_owned()).collect()
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct CustomRecipeOverridesConfiguration {
     pub use_custom_recipe: bool,
@@ -33,14 +314,49 @@ pub struct CustomRecipeOverridesConfiguration {
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct RecipeConfiguration {
+    /// Id of the selected recipe, which a custom recipe keeps since it is edited from the selected recipe and shares its ingredients
+    #[serde(default)]
+    pub recipe_id: u32,
     pub recipe: Recipe,
     pub quality_source: QualitySource,
 }
 
+impl RecipeConfiguration {
+    /// Switches to the given recipe. The HQ materials of the current recipe are remembered in `recipe_hq_materials`
+    /// and the ones last used for the new recipe are restored.
+    pub fn select_recipe(
+        &mut self,
+        recipe_id: u32,
+        recipe_hq_materials: &mut HashMap<u32, [u8; 6]>,
+    ) {
+        if let QualitySource::HqMaterialList(hq_materials) = self.quality_source {
+            recipe_hq_materials.insert(self.recipe_id, hq_materials);
+        }
+        let recipe = raphael_data::RECIPES[&recipe_id];
+        let hq_materials = recipe_hq_materials
+            .get(&recipe_id)
+            .copied()
+            .unwrap_or_else(|| {
+                if raphael_data::is_cosmic_recipe(recipe.item_id) {
+                    recipe.ingredients.map(|ingredient| ingredient.amount as u8)
+                } else {
+                    [0; 6]
+                }
+            });
+        *self = Self {
+            recipe_id,
+            recipe,
+            quality_source: QualitySource::HqMaterialList(hq_materials),
+        };
+    }
+}
+
 impl Default for RecipeConfiguration {
     fn default() -> Self {
+        let (recipe_id, recipe) = raphael_data::RECIPES.entries().next().unwrap();
         Self {
-            recipe: *raphael_data::RECIPES.values().next().unwrap(),
+            recipe_id: *recipe_id,
+            recipe: *recipe,
             quality_source: QualitySource::HqMaterialList([0; 6]),
         }
     }
@@ -65,6 +381,15 @@ impl CrafterConfig {
         }
     }
 
+    /// Stats that would be active after switching to the given job
+    pub fn job_stats(&self, job_id: u8) -> &CrafterStats {
+        if self.is_detached() {
+            &self.working_stats
+        } else {
+            &self.crafter_stats[job_id as usize]
+        }
+    }
+
     pub fn active_stats_mut(&mut self) -> &mut CrafterStats {
         // egui::Checkbox doesn't support value setter, so we have to make a copy preemptively
         if let Some(job) = self.attached_job {
@@ -104,7 +429,7 @@ impl Default for CrafterConfig {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum QualityTarget {
     Zero,
     CollectableT1,
@@ -0,0 +1,105 @@
+//! System-wide hotkey for native builds that copies the next macro while another window, e.g. the game, is focused.
+//!
+//! Global hotkeys are supported on Windows, macOS and X11. Where they aren't, e.g. on Wayland, the hotkey can't be set.
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState, hotkey::HotKey};
+
+/// Reason why a hotkey couldn't be registered
+pub enum CopyHotkeyError {
+    /// Global hotkeys are not available on this system
+    Unsupported,
+    /// The hotkey text couldn't be parsed
+    Invalid(String),
+    /// The hotkey was rejected by the system, e.g. because another application already uses it
+    Unavailable(String),
+}
+
+pub struct CopyHotkey {
+    /// Hotkey as entered by the user, e.g. "ctrl+shift+KeyC". Empty if no hotkey is set
+    pub text: String,
+    /// Reason why `text` couldn't be registered
+    pub error: Option<CopyHotkeyError>,
+    manager: Option<GlobalHotKeyManager>,
+    registered: Option<HotKey>,
+    pressed: Arc<AtomicBool>,
+}
+
+impl CopyHotkey {
+    /// Must be called on the main thread, which runs the event loop that the hotkey events are delivered to
+    pub fn new(ctx: &egui::Context, text: String) -> Self {
+        let pressed = Arc::new(AtomicBool::new(false));
+        let manager = match GlobalHotKeyManager::new() {
+            Ok(manager) => {
+                let pressed = pressed.clone();
+                let ctx = ctx.clone();
+                GlobalHotKeyEvent::set_event_handler(Some(move |event: GlobalHotKeyEvent| {
+                    if event.state() == HotKeyState::Pressed {
+                        pressed.store(true, Ordering::Relaxed);
+                        // the app isn't repainted on its own while it is unfocused
+                        ctx.request_repaint();
+                    }
+                }));
+                Some(manager)
+            }
+            Err(error) => {
+                log::warn!("Global hotkeys are not available: {error}");
+                None
+            }
+        };
+        let mut copy_hotkey = Self {
+            text,
+            error: None,
+            manager,
+            registered: None,
+            pressed,
+        };
+        copy_hotkey.register();
+        copy_hotkey
+    }
+
+    /// Replaces the registered hotkey with the one in `text`
+    pub fn register(&mut self) {
+        self.error = None;
+        self.unregister();
+        if self.text.trim().is_empty() {
+            return;
+        }
+        let Some(manager) = &self.manager else {
+            self.error = Some(CopyHotkeyError::Unsupported);
+            return;
+        };
+        let result = self
+            .text
+            .trim()
+            .parse::<HotKey>()
+            .map_err(|error| CopyHotkeyError::Invalid(error.to_string()))
+            .and_then(|hotkey| {
+                manager
+                    .register(hotkey)
+                    .map(|()| hotkey)
+                    .map_err(|error| CopyHotkeyError::Unavailable(error.to_string()))
+            });
+        match result {
+            Ok(hotkey) => self.registered = Some(hotkey),
+            Err(error) => self.error = Some(error),
+        }
+    }
+
+    pub fn unregister(&mut self) {
+        if let (Some(manager), Some(hotkey)) = (&self.manager, self.registered.take())
+            && let Err(error) = manager.unregister(hotkey)
+        {
+            log::warn!("Unable to unregister global hotkey: {error}");
+        }
+    }
+
+    /// Whether the hotkey was pressed since the last call
+    pub fn take_pressed(&self) -> bool {
+        self.pressed.swap(false, Ordering::Relaxed)
+    }
+}
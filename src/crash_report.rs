@@ -0,0 +1,68 @@
+//! Local crash reports for native builds.
+//!
+//! Reports are only ever written to the app storage folder so that users can attach them to a bug report themselves, nothing is uploaded.
+//! The path of the latest report is kept in a marker file until the app shows it, so that crashes that take down the whole app are reported on the next launch.
+
+use std::path::PathBuf;
+
+use raphael_solver::SolverException;
+
+const PENDING_REPORT_FILE_NAME: &str = "crash_report_pending.txt";
+
+/// Chains a panic hook that writes a crash report in front of the currently installed hook
+pub fn install_panic_hook() {
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if let Err(error) = write_report(info) {
+            log::error!("Failed to write crash report: {error}");
+        }
+        previous_hook(info);
+    }));
+}
+
+/// Path of the latest crash report that hasn't been shown to the user yet
+pub fn take_pending_report() -> Option<PathBuf> {
+    let marker_path = eframe::storage_dir("Raphael XIV")?.join(PENDING_REPORT_FILE_NAME);
+    let report_path = std::fs::read_to_string(&marker_path).ok()?;
+    std::fs::remove_file(&marker_path).ok();
+    Some(PathBuf::from(report_path))
+}
+
+fn write_report(info: &std::panic::PanicHookInfo) -> std::io::Result<()> {
+    let mut path = eframe::storage_dir("Raphael XIV")
+        .ok_or_else(|| std::io::Error::other("no storage folder"))?;
+    std::fs::create_dir_all(&path)?;
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    path.push(format!("crash_report_{timestamp}.txt"));
+
+    // the settings of the most recently spawned solver are included since most crashes happen while solving
+    let solver_settings = crate::bug_report::solver_settings().map_or_else(
+        || "No solver was spawned".to_owned(),
+        |settings| format!("{settings:#?}"),
+    );
+    let context = crate::bug_report::context(&SolverException::PanicCaught {
+        message: info.to_string(),
+    });
+    let report = format!(
+        "Raphael XIV {version} crash report\n\
+         Platform: {os} {arch}\n\
+         Thread: {thread}\n\n\
+         {info}\n\n\
+         Backtrace:\n{backtrace}\n\n\
+         Solver settings:\n{solver_settings}\n\n\
+         Bug report context:\n{context}\n",
+        version = env!("CARGO_PKG_VERSION"),
+        os = std::env::consts::OS,
+        arch = std::env::consts::ARCH,
+        thread = std::thread::current().name().unwrap_or("<unnamed>"),
+        backtrace = std::backtrace::Backtrace::force_capture(),
+    );
+    std::fs::write(&path, report)?;
+    std::fs::write(
+        path.with_file_name(PENDING_REPORT_FILE_NAME),
+        path.to_string_lossy().as_bytes(),
+    )
+}
@@ -0,0 +1,142 @@
+//! File storage of the saved rotations on native, kept apart from the eframe storage file.
+//! eframe writes its file without a lock at a time of its choosing, so the saved rotations get a file of their own that is read, merged and written while a lock file is held.
+//! This way, an app instance never overwrites rotations that another instance has saved in the meantime.
+
+use std::path::{Path, PathBuf};
+
+use crate::widgets::{SavedRotationsConfig, SavedRotationsData};
+
+const FILE_NAME: &str = "saved_rotations.ron";
+const LOCK_FILE_NAME: &str = "saved_rotations.ron.lock";
+
+fn storage_dir() -> Option<PathBuf> {
+    eframe::storage_dir("Raphael XIV")
+}
+
+#[derive(Debug, Default)]
+pub struct SavedRotationsFile {
+    /// Content of the file when it was last read or written by this instance.
+    /// It is compared by content rather than by modification time, which stays the same when two instances write it within the same clock tick.
+    synced: Option<String>,
+}
+
+impl SavedRotationsFile {
+    /// Merges the rotations that other app instances have written since the last sync into `data`, then writes `data` to the file if it has changed.
+    pub fn sync(&mut self, data: &mut SavedRotationsData, config: &SavedRotationsConfig) {
+        if let Some(storage_dir) = storage_dir() {
+            self.sync_in(&storage_dir, data, config);
+        }
+    }
+
+    fn sync_in(
+        &mut self,
+        storage_dir: &Path,
+        data: &mut SavedRotationsData,
+        config: &SavedRotationsConfig,
+    ) {
+        if std::fs::create_dir_all(storage_dir).is_err() {
+            return;
+        }
+        let Ok(lock_file) = std::fs::File::create(storage_dir.join(LOCK_FILE_NAME)) else {
+            return;
+        };
+        if let Err(error) = lock_file.lock() {
+            log::warn!("Failed to lock the saved rotations file: {error}");
+            return;
+        }
+        let path = storage_dir.join(FILE_NAME);
+        let stored_text = std::fs::read_to_string(&path).ok();
+        if stored_text != self.synced
+            && let Some(stored_text) = &stored_text
+            && let Ok(stored_data) = ron::de::from_str(stored_text)
+        {
+            data.merge(stored_data, config);
+        }
+        let Ok(text) = ron::ser::to_string(data) else {
+            return;
+        };
+        if stored_text.as_ref() == Some(&text) {
+            self.synced = stored_text;
+            return;
+        }
+        // written to a temporary file first, so that a crash mid-write doesn't leave a damaged file behind
+        let temp_path = storage_dir.join(format!("{FILE_NAME}.tmp"));
+        match std::fs::write(&temp_path, &text).and_then(|()| std::fs::rename(&temp_path, &path)) {
+            Ok(()) => self.synced = Some(text),
+            Err(error) => log::warn!("Failed to write the saved rotations file: {error}"),
+        }
+        // the lock is released when `lock_file` is dropped, after the file has been written
+    }
+
+    /// Skips merging the rotations that are currently in the file, e.g. because the saved rotations have been restored from a backup and should replace them.
+    pub fn skip_stored_rotations(&mut self) {
+        self.synced = storage_dir()
+            .and_then(|storage_dir| std::fs::read_to_string(storage_dir.join(FILE_NAME)).ok());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use raphael_data::CrafterStats;
+    use raphael_sim::Action;
+
+    use super::{FILE_NAME, SavedRotationsFile};
+    use crate::widgets::{Rotation, SavedRotationsConfig, SavedRotationsData};
+
+    fn rotation(unique_id: u64, actions: Vec<Action>) -> Rotation {
+        Rotation {
+            unique_id,
+            name: String::new(),
+            solver: String::new(),
+            actions,
+            recipe_info: None,
+            quality_source: None,
+            solve_info: None,
+            food: None,
+            potion: None,
+            crafter_stats: CrafterStats::default(),
+            minimum_stats: Default::default(),
+            saved_at: unique_id,
+        }
+    }
+
+    #[test]
+    fn test_sync_within_same_clock_tick() {
+        let storage_dir = std::env::temp_dir().join(format!(
+            "raphael-saved-rotations-test-{}",
+            std::process::id()
+        ));
+        let path = storage_dir.join(FILE_NAME);
+        let config = SavedRotationsConfig::default();
+
+        let mut file_a = SavedRotationsFile::default();
+        let mut data_a = SavedRotationsData::default();
+        data_a.add_solved_rotation(rotation(1, vec![Action::BasicSynthesis]), &config);
+        file_a.sync_in(&storage_dir, &mut data_a, &config);
+        let modified = std::fs::metadata(&path).unwrap().modified().unwrap();
+
+        let mut file_b = SavedRotationsFile::default();
+        let mut data_b = SavedRotationsData::default();
+        data_b.add_solved_rotation(rotation(2, vec![Action::BasicTouch]), &config);
+        file_b.sync_in(&storage_dir, &mut data_b, &config);
+        assert!(data_b.get(1).is_some());
+        // the second write happens within the same clock tick as the first one
+        std::fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+
+        data_a.add_solved_rotation(rotation(3, vec![Action::Veneration]), &config);
+        file_a.sync_in(&storage_dir, &mut data_a, &config);
+        assert!(data_a.get(2).is_some());
+        let stored_data: SavedRotationsData =
+            ron::de::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        for unique_id in [1, 2, 3] {
+            assert!(stored_data.get(unique_id).is_some());
+        }
+
+        std::fs::remove_dir_all(&storage_dir).unwrap();
+    }
+}
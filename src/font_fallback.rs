@@ -0,0 +1,106 @@
+//! The bundled CJK font subsets only cover the characters that appear in the game data and the UI.
+//! User-entered text such as rotation and preset names can contain other characters,
+//! in which case a font with a wider coverage is loaded in the background and added as the last fallback.
+
+use std::sync::{Arc, Mutex};
+
+enum State {
+    NotRequested,
+    Loading,
+    Loaded(Arc<egui::FontData>),
+    Unavailable,
+}
+
+static STATE: Mutex<State> = Mutex::new(State::NotRequested);
+
+/// Fonts that are commonly installed and cover most CJK characters, the first one that exists is used
+#[cfg(not(target_arch = "wasm32"))]
+const SYSTEM_FONT_PATHS: &[&str] = &[
+    "C:\\Windows\\Fonts\\msyh.ttc",
+    "C:\\Windows\\Fonts\\YuGothR.ttc",
+    "C:\\Windows\\Fonts\\malgun.ttf",
+    "/System/Library/Fonts/Hiragino Sans GB.ttc",
+    "/System/Library/Fonts/AppleSDGothicNeo.ttc",
+    "/usr/share/fonts/opentype/noto/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/noto-cjk/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/google-noto-cjk/NotoSansCJK-Regular.ttc",
+    "/usr/share/fonts/truetype/wqy/wqy-microhei.ttc",
+];
+
+/// Full version of the font that the CN subset is generated from, served next to the app
+#[cfg(target_arch = "wasm32")]
+const WEB_FONT_FILE: &str = "NotoSansSC-Light.ttf";
+
+/// Starts loading the fallback font if the current fonts can't display all characters of `text`.
+/// The fallback font is only loaded once, later calls don't do anything.
+pub fn request_glyphs(ctx: &egui::Context, text: &str) {
+    if !matches!(*STATE.lock().unwrap(), State::NotRequested) {
+        return;
+    }
+    let font_id = egui::TextStyle::Body.resolve(&ctx.style());
+    // whitespace and control characters don't need glyphs
+    let text: String = text
+        .chars()
+        .filter(|c| !c.is_control() && !c.is_whitespace())
+        .collect();
+    if ctx.fonts_mut(|fonts| fonts.has_glyphs(&font_id, &text)) {
+        return;
+    }
+    log::debug!("Loading fallback font for \"{text}\"");
+    *STATE.lock().unwrap() = State::Loading;
+    let ctx = ctx.clone();
+    load(move |font| {
+        *STATE.lock().unwrap() = font.map_or(State::Unavailable, |font| {
+            State::Loaded(Arc::new(egui::FontData::from_owned(font)))
+        });
+        ctx.request_repaint();
+    });
+}
+
+/// The fallback font, once it has been loaded
+pub fn loaded_font() -> Option<Arc<egui::FontData>> {
+    match &*STATE.lock().unwrap() {
+        State::Loaded(font) => Some(font.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn load(on_done: impl FnOnce(Option<Vec<u8>>) + Send + 'static) {
+    std::thread::spawn(move || {
+        let font = SYSTEM_FONT_PATHS
+            .iter()
+            .find_map(|path| std::fs::read(path).ok());
+        if font.is_none() {
+            log::warn!("No fallback font found on the system");
+        }
+        on_done(font);
+    });
+}
+
+#[cfg(target_arch = "wasm32")]
+fn load(on_done: impl FnOnce(Option<Vec<u8>>) + 'static) {
+    wasm_bindgen_futures::spawn_local(async move {
+        let url = format!("{}/{WEB_FONT_FILE}", env!("BASE_URL"));
+        let font = fetch(&url)
+            .await
+            .inspect_err(|error| log::warn!("Failed to fetch fallback font: {error:?}"))
+            .ok();
+        on_done(font);
+    });
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn fetch(url: &str) -> Result<Vec<u8>, web_sys::wasm_bindgen::JsValue> {
+    use web_sys::wasm_bindgen::JsCast;
+    let window = web_sys::window().ok_or("window is unavailable")?;
+    let response: web_sys::Response =
+        wasm_bindgen_futures::JsFuture::from(window.fetch_with_str(url))
+            .await?
+            .dyn_into()?;
+    if !response.ok() {
+        return Err(format!("HTTP {}", response.status()).into());
+    }
+    let buffer = wasm_bindgen_futures::JsFuture::from(response.array_buffer()?).await?;
+    Ok(js_sys::Uint8Array::new(&buffer).to_vec())
+}
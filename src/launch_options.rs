@@ -0,0 +1,125 @@
+use raphael_data::{Consumable, MEALS, POTIONS, RECIPES};
+
+/// Initial configuration given to the app when it is launched, e.g. from a link on a guide or community site.
+/// Anything that is not specified keeps its stored value.
+#[derive(Debug, Default, Clone)]
+pub struct LaunchOptions {
+    pub recipe_id: Option<u32>,
+    pub craftsmanship: Option<u16>,
+    pub control: Option<u16>,
+    pub cp: Option<u16>,
+    /// `Some(None)` clears the selected food
+    pub food: Option<Option<Consumable>>,
+    /// `Some(None)` clears the selected potion
+    pub potion: Option<Option<Consumable>>,
+    /// Start solving right after the options are applied
+    pub solve: bool,
+}
+
+impl LaunchOptions {
+    /// Parses a URL query string such as `?item=44232&cms=5000&ctrl=5000&cp=600&food=44091,HQ&solve`.
+    /// Unknown keys are ignored and invalid values are logged and skipped, so that a bad link still opens the app.
+    #[cfg(target_arch = "wasm32")]
+    pub fn from_query(query: &str) -> Self {
+        let mut options = Self::default();
+        for pair in query.trim_start_matches('?').split('&') {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            if let Err(error) = options.set(key, value) {
+                log::warn!("Ignoring launch option \"{pair}\": {error}");
+            }
+        }
+        options
+    }
+
+    /// Parses command-line arguments such as `--item 44232 --stats 5000,5000,600 --food 44091,HQ --solve`.
+    /// Options take the same values as the URL query parameters, either as the next argument or after `=`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_args(mut args: impl Iterator<Item = String>) -> Self {
+        let mut options = Self::default();
+        while let Some(arg) = args.next() {
+            let Some(option) = arg.strip_prefix("--") else {
+                log::warn!("Ignoring command-line argument \"{arg}\"");
+                continue;
+            };
+            let (key, value) = match option.split_once('=') {
+                Some((key, value)) => (key, value.to_owned()),
+                None if option == "solve" => (option, String::new()),
+                None => (option, args.next().unwrap_or_default()),
+            };
+            if let Err(error) = options.set(key, &value) {
+                log::warn!("Ignoring launch option \"{key}\": {error}");
+            }
+        }
+        options
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let parse_u16 = |value: &str| {
+            value
+                .parse::<u16>()
+                .map_err(|_| format!("\"{value}\" is not a valid number"))
+        };
+        match key {
+            "" => {}
+            "item" => self.recipe_id = Some(find_recipe_by_item(parse_id(value)?)?),
+            "recipe" => {
+                let recipe_id = parse_id(value)?;
+                if !RECIPES.contains_key(&recipe_id) {
+                    return Err(format!("Unable to find recipe with recipe ID {recipe_id}"));
+                }
+                self.recipe_id = Some(recipe_id);
+            }
+            "cms" => self.craftsmanship = Some(parse_u16(value)?),
+            "ctrl" => self.control = Some(parse_u16(value)?),
+            "cp" => self.cp = Some(parse_u16(value)?),
+            "stats" => {
+                let stats: Vec<&str> = value.split(',').collect();
+                let [craftsmanship, control, cp] = stats[..] else {
+                    return Err(format!("\"{value}\" is not in the format CMS,CTRL,CP"));
+                };
+                self.craftsmanship = Some(parse_u16(craftsmanship)?);
+                self.control = Some(parse_u16(control)?);
+                self.cp = Some(parse_u16(cp)?);
+            }
+            "food" => self.food = Some(parse_consumable(value, MEALS)?),
+            "potion" => self.potion = Some(parse_consumable(value, POTIONS)?),
+            "solve" => self.solve = !matches!(value, "0" | "false"),
+            _ => return Err("Unknown option".to_owned()),
+        }
+        Ok(())
+    }
+}
+
+fn parse_id(value: &str) -> Result<u32, String> {
+    value
+        .parse()
+        .map_err(|_| format!("\"{value}\" is not a valid ID"))
+}
+
+fn find_recipe_by_item(item_id: u32) -> Result<u32, String> {
+    RECIPES
+        .entries()
+        .find(|(_, recipe)| recipe.item_id == item_id)
+        .map(|(recipe_id, _)| *recipe_id)
+        .ok_or_else(|| format!("Unable to find recipe for item ID {item_id}"))
+}
+
+/// Parses `<item ID>` or `<item ID>,HQ` in the same format as the CLI, an empty value means no consumable.
+fn parse_consumable(value: &str, candidates: &[Consumable]) -> Result<Option<Consumable>, String> {
+    if value.is_empty() {
+        return Ok(None);
+    }
+    let (item_id, hq) = match value.split_once(',') {
+        Some((item_id, quality)) if quality.eq_ignore_ascii_case("HQ") => (item_id, true),
+        Some((item_id, quality)) if quality.eq_ignore_ascii_case("NQ") => (item_id, false),
+        Some(_) => return Err(format!("\"{value}\" is not a valid consumable")),
+        None => (value, false),
+    };
+    let item_id = parse_id(item_id)?;
+    candidates
+        .iter()
+        .find(|consumable| consumable.item_id == item_id && consumable.hq == hq)
+        .copied()
+        .map(Some)
+        .ok_or_else(|| format!("Unable to find consumable with item ID {item_id}"))
+}
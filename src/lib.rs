@@ -4,10 +4,28 @@ pub use wasm_bindgen_rayon::init_thread_pool;
 mod app;
 pub use app::MacroSolverApp;
 
+mod backup;
+mod bug_report;
 mod config;
+#[cfg(not(target_arch = "wasm32"))]
+mod copy_hotkey;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod crash_report;
+#[cfg(not(target_arch = "wasm32"))]
+mod file_storage;
+mod font_fallback;
+mod launch_options;
+mod solver_tasks;
+#[cfg(any(debug_assertions, feature = "dev-panel"))]
+mod test_case_export;
 mod thread_pool;
+mod translations;
 mod util;
+#[cfg(target_arch = "wasm32")]
+mod web_storage;
 mod widgets;
+#[cfg(not(target_arch = "wasm32"))]
+mod window_geometry;
 
 #[cfg(target_arch = "wasm32")]
 pub static OOM_PANIC_OCCURED: std::sync::atomic::AtomicBool =
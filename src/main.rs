@@ -23,7 +23,7 @@ fn init_logging() {
         .init();
 
     // Ensure panics are logged when detached, since the default hook outputs to stderr
-    // Backtraces are currently not generated
+    // Backtraces are written to the crash report instead
     std::panic::set_hook(Box::new(|info| {
         log::error!("{}", info);
     }));
@@ -49,6 +49,7 @@ fn init_logging() {
 #[cfg(not(target_arch = "wasm32"))]
 fn main() -> eframe::Result<()> {
     init_logging();
+    raphael_xiv::crash_report::install_panic_hook();
 
     let present_mode =
         if let Some(present_mode_env_var) = std::env::var("RAPHAEL_PRESENT_MODE").ok() {
@@ -88,6 +89,8 @@ fn main() -> eframe::Result<()> {
             .with_inner_size([400.0, 300.0])
             .with_min_inner_size([300.0, 220.0]),
         wgpu_options,
+        // the window geometry is stored in the app config instead
+        persist_window: false,
         ..Default::default()
     };
     eframe::run_native(
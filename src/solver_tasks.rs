@@ -0,0 +1,541 @@
+//! Solves that run on the thread pool and report back to the UI through [`SolverEvents`]
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use raphael_sim::{Action, Condition};
+use raphael_solver::SolverException;
+
+use crate::app::{BatchSolution, CP_TRADEOFF_REDUCTIONS, MinimumStats};
+use crate::config::QualityTarget;
+use crate::widgets::SolveReport;
+
+pub enum SolverEvent {
+    PrecomputeProgress(raphael_solver::PrecomputeProgress),
+    Progress(raphael_solver::SolverProgress),
+    Actions(Vec<Action>),
+    TierSolved(QualityTarget, Option<Vec<Action>>),
+    TargetRotations(Vec<(QualityTarget, Vec<Action>)>),
+    CpTradeoff(u16, Option<u32>),
+    HqSweep(usize, Option<BatchSolution>),
+    DecisionTree(raphael_solver::DecisionTree),
+    BatchSolved(usize, Option<BatchSolution>),
+    MinimumStats(u64, MinimumStats),
+    LoadedFromHistory(),
+    Finished(Option<SolverException>, Option<SolveReport>),
+}
+
+/// Queue through which background work reports back to the UI
+#[derive(Clone)]
+pub struct SolverEvents {
+    pub queue: Arc<Mutex<VecDeque<SolverEvent>>>,
+    pub ctx: egui::Context,
+}
+
+impl SolverEvents {
+    /// Queues `event` and wakes up the UI to process it, as the UI isn't repainted continuously while a solve runs
+    pub fn push(&self, event: SolverEvent) {
+        self.queue.lock().unwrap().push_back(event);
+        self.ctx.request_repaint();
+    }
+}
+
+/// Solver kept alive after a solve so that its precomputed tables can be reused for rotation repair.
+pub type SolverCache = Arc<Mutex<Option<raphael_solver::MacroSolver<'static>>>>;
+
+/// What every background solve shares with the UI: the event queue, the interrupt flag and the cached solver
+#[derive(Clone)]
+pub struct SolverContext {
+    pub events: SolverEvents,
+    pub interrupt: raphael_solver::AtomicFlag,
+    pub cache: SolverCache,
+    pub keep_solver_tables: bool,
+}
+
+impl SolverContext {
+    /// The cache if solver tables are kept after solving
+    fn kept_cache(&self) -> Option<SolverCache> {
+        self.keep_solver_tables.then(|| self.cache.clone())
+    }
+}
+
+type SolutionCallback = Box<dyn Fn(&[raphael_sim::Action]) + Send + Sync>;
+type ProgressCallback = Box<dyn Fn(raphael_solver::SolverProgress) + Send + Sync>;
+
+/// Solves for the Quality in `solver_settings`.
+/// The rotations of `lower_quality_targets` are then found with the same tables and reported together, so that switching to one of these targets doesn't need another solve.
+pub fn spawn_solver(
+    solver_settings: raphael_solver::SolverSettings,
+    initial_state: raphael_sim::SimulationState,
+    warm_start: Vec<Action>,
+    lower_quality_targets: Vec<(QualityTarget, u32)>,
+    context: &SolverContext,
+) {
+    let SolverContext {
+        events: solver_events,
+        interrupt: solver_interrupt,
+        cache: solver_cache,
+        keep_solver_tables,
+    } = context.clone();
+    let events = solver_events.clone();
+    let solution_callback = move |actions: &[raphael_sim::Action]| {
+        let event = SolverEvent::Actions(actions.to_vec());
+        events.push(event);
+    };
+    let events = solver_events.clone();
+    let progress_callback = move |progress: raphael_solver::SolverProgress| {
+        let event = SolverEvent::Progress(progress);
+        events.push(event);
+    };
+    rayon::spawn(move || {
+        // the cache holds a solver with an interrupted precompute even if solver tables aren't kept, so that solving again resumes the precompute
+        let mut macro_solver = prepare_solver(
+            solver_settings,
+            Box::new(solution_callback),
+            Box::new(progress_callback),
+            &solver_events,
+            solver_interrupt,
+            Some(&solver_cache),
+        );
+        macro_solver.set_warm_start(warm_start);
+        let result = macro_solver.solve_from(initial_state);
+        let mut solve_report = SolveReport::default();
+        solve_report.add_solve(&macro_solver);
+        match result {
+            Ok(actions) => {
+                solver_events.push(SolverEvent::Actions(actions));
+                let mut tables_intact = true;
+                if !lower_quality_targets.is_empty() {
+                    // intermediate solutions are not reported because they would overwrite the rotation of the solved target
+                    macro_solver.set_callbacks(
+                        Box::new(|_| {}),
+                        batch_progress_callback(solver_events.clone()),
+                    );
+                    let mut target_rotations = Vec::new();
+                    for (quality_target, target_quality) in lower_quality_targets {
+                        let result =
+                            macro_solver.solve_from_for_quality(initial_state, target_quality);
+                        solve_report.add_solve(&macro_solver);
+                        match result {
+                            Ok(actions) => target_rotations.push((quality_target, actions)),
+                            Err(SolverException::NoSolution) => (),
+                            // the rotation of the solved target is still shown, e.g. if the solve is cancelled while the lower targets are solved
+                            Err(exception) => {
+                                tables_intact =
+                                    !matches!(exception, SolverException::PanicCaught { .. });
+                                break;
+                            }
+                        }
+                    }
+                    solver_events.push(SolverEvent::TargetRotations(target_rotations));
+                }
+                // the solver is dropped instead of cached if a panic may have left it with incomplete tables
+                if keep_solver_tables && tables_intact {
+                    *solver_cache.lock().unwrap() = Some(macro_solver);
+                }
+                solver_events.push(SolverEvent::Finished(None, Some(solve_report)));
+            }
+            Err(exception) => {
+                if exception == SolverException::Interrupted
+                    && (keep_solver_tables || !macro_solver.precompute_done())
+                {
+                    *solver_cache.lock().unwrap() = Some(macro_solver);
+                }
+                solver_events.push(SolverEvent::Finished(Some(exception), Some(solve_report)));
+            }
+        }
+    });
+}
+
+/// Forwards the progress of the precompute phase to the busy modal
+fn precompute_callback(
+    solver_events: SolverEvents,
+) -> Box<dyn Fn(raphael_solver::PrecomputeProgress) + Send + Sync> {
+    Box::new(move |progress| solver_events.push(SolverEvent::PrecomputeProgress(progress)))
+}
+
+/// Takes the cached solver out of the cache, dropping it early if it can't be reused with `solver_settings`.
+fn take_cached_solver(
+    solver_cache: Option<&SolverCache>,
+    solver_settings: &raphael_solver::SolverSettings,
+) -> Option<raphael_solver::MacroSolver<'static>> {
+    solver_cache
+        .and_then(|cache| cache.lock().unwrap().take())
+        .filter(|solver| solver.settings() == solver_settings)
+}
+
+/// Takes the cached solver if it can be reused with `solver_settings` or creates a new one, and wires up the callbacks
+fn prepare_solver(
+    solver_settings: raphael_solver::SolverSettings,
+    solution_callback: SolutionCallback,
+    progress_callback: ProgressCallback,
+    solver_events: &SolverEvents,
+    solver_interrupt: raphael_solver::AtomicFlag,
+    solver_cache: Option<&SolverCache>,
+) -> raphael_solver::MacroSolver<'static> {
+    let mut macro_solver = match take_cached_solver(solver_cache, &solver_settings) {
+        Some(mut macro_solver) => {
+            log::debug!("Reusing cached solver: {solver_settings:?}");
+            macro_solver.set_callbacks(solution_callback, progress_callback);
+            macro_solver
+        }
+        None => {
+            log::debug!("Spawning solver: {solver_settings:?}");
+            raphael_solver::MacroSolver::new(
+                solver_settings,
+                solution_callback,
+                progress_callback,
+                solver_interrupt,
+            )
+        }
+    };
+    crate::bug_report::set_solver_settings(macro_solver.settings());
+    macro_solver.set_precompute_callback(precompute_callback(solver_events.clone()));
+    macro_solver
+}
+
+/// Progress of one of several solves, without the best solution of the single solve which would be misleading in the busy modal
+fn batch_progress_callback(solver_events: SolverEvents) -> ProgressCallback {
+    Box::new(move |progress| {
+        let event = SolverEvent::Progress(raphael_solver::SolverProgress {
+            best_solution: None,
+            ..progress
+        });
+        solver_events.push(event);
+    })
+}
+
+/// Solves each entry of the batch, e.g. the selected recipe for each gearset, one at a time to limit memory usage
+pub fn spawn_batch_solver(
+    batch: Vec<(
+        usize,
+        raphael_solver::SolverSettings,
+        raphael_sim::SimulationState,
+        u16,
+    )>,
+    context: &SolverContext,
+) {
+    let solver_events = context.events.clone();
+    let solver_interrupt = context.interrupt.clone();
+    rayon::spawn(move || {
+        let mut solve_report = SolveReport::default();
+        for (index, solver_settings, initial_state, initial_quality) in batch {
+            let simulator_settings = solver_settings.simulator_settings;
+            let mut macro_solver = prepare_solver(
+                solver_settings,
+                Box::new(|_| {}),
+                batch_progress_callback(solver_events.clone()),
+                &solver_events,
+                solver_interrupt.clone(),
+                None,
+            );
+            let result = macro_solver.solve_from(initial_state);
+            solve_report.add_solve(&macro_solver);
+            let solution = match result {
+                Ok(actions) => {
+                    let (state, _) = initial_state.continue_macro_in_conditions(
+                        &simulator_settings,
+                        &actions,
+                        &solver_settings.opener_conditions,
+                    );
+                    let quality =
+                        std::cmp::min(state.quality, u32::from(simulator_settings.max_quality));
+                    Some(BatchSolution {
+                        actions,
+                        quality: u32::from(initial_quality) + quality,
+                    })
+                }
+                Err(SolverException::NoSolution) => None,
+                Err(exception) => {
+                    solver_events.push(SolverEvent::Finished(Some(exception), Some(solve_report)));
+                    return;
+                }
+            };
+            solver_events.push(SolverEvent::BatchSolved(index, solution));
+        }
+        solver_events.push(SolverEvent::Finished(None, Some(solve_report)));
+    });
+}
+
+pub fn spawn_cp_tradeoff_solver(
+    solver_settings: raphael_solver::SolverSettings,
+    initial_state: raphael_sim::SimulationState,
+    context: &SolverContext,
+) {
+    let solver_events = context.events.clone();
+    let solver_interrupt = context.interrupt.clone();
+    let solver_cache = context.kept_cache();
+    rayon::spawn(move || {
+        let simulator_settings = solver_settings.simulator_settings;
+        // intermediate solutions are not reported because they would overwrite the current rotation
+        let mut macro_solver = prepare_solver(
+            solver_settings,
+            Box::new(|_| {}),
+            batch_progress_callback(solver_events.clone()),
+            &solver_events,
+            solver_interrupt,
+            solver_cache.as_ref(),
+        );
+        let mut solve_report = SolveReport::default();
+        for cp_reduction in CP_TRADEOFF_REDUCTIONS {
+            let mut state = initial_state;
+            state.cp = state.cp.saturating_sub(cp_reduction);
+            let result = macro_solver.solve_from(state);
+            solve_report.add_solve(&macro_solver);
+            let quality = match result {
+                Ok(actions) => actions
+                    .iter()
+                    .zip(Condition::sequence(&solver_settings.opener_conditions))
+                    .try_fold(state, |state, (action, condition)| {
+                        state.use_action(*action, condition, &simulator_settings)
+                    })
+                    .ok()
+                    .map(|state| {
+                        std::cmp::min(state.quality, u32::from(simulator_settings.max_quality))
+                    }),
+                Err(SolverException::NoSolution) => None,
+                // the solver is dropped instead of cached, a panic may have left it with incomplete tables
+                Err(exception) => {
+                    solver_events.push(SolverEvent::Finished(Some(exception), Some(solve_report)));
+                    return;
+                }
+            };
+            solver_events.push(SolverEvent::CpTradeoff(cp_reduction, quality));
+        }
+        if let Some(cache) = solver_cache {
+            *cache.lock().unwrap() = Some(macro_solver);
+        }
+        solver_events.push(SolverEvent::Finished(None, Some(solve_report)));
+    });
+}
+
+/// Solves for the target Quality once per initial Quality, the target is lowered instead of changing the settings so that all solves share the precomputed tables
+pub fn spawn_hq_sweep_solver(
+    solver_settings: raphael_solver::SolverSettings,
+    initial_state: raphael_sim::SimulationState,
+    initial_qualities: Vec<u16>,
+    context: &SolverContext,
+) {
+    let solver_events = context.events.clone();
+    let solver_interrupt = context.interrupt.clone();
+    let solver_cache = context.kept_cache();
+    rayon::spawn(move || {
+        let simulator_settings = solver_settings.simulator_settings;
+        let target_quality = u32::from(simulator_settings.max_quality);
+        // intermediate solutions are not reported because they would overwrite the current rotation
+        let mut macro_solver = prepare_solver(
+            solver_settings,
+            Box::new(|_| {}),
+            batch_progress_callback(solver_events.clone()),
+            &solver_events,
+            solver_interrupt,
+            solver_cache.as_ref(),
+        );
+        let mut solve_report = SolveReport::default();
+        for (hq_count, initial_quality) in initial_qualities.into_iter().enumerate() {
+            let result = macro_solver.solve_from_for_quality(
+                initial_state,
+                target_quality.saturating_sub(u32::from(initial_quality)),
+            );
+            solve_report.add_solve(&macro_solver);
+            let solution = match result {
+                Ok(actions) => {
+                    let (state, _) = initial_state.continue_macro_in_conditions(
+                        &simulator_settings,
+                        &actions,
+                        &solver_settings.opener_conditions,
+                    );
+                    let quality =
+                        std::cmp::min(u32::from(initial_quality) + state.quality, target_quality);
+                    Some(BatchSolution { actions, quality })
+                }
+                Err(SolverException::NoSolution) => None,
+                // the solver is dropped instead of cached, a panic may have left it with incomplete tables
+                Err(exception) => {
+                    solver_events.push(SolverEvent::Finished(Some(exception), Some(solve_report)));
+                    return;
+                }
+            };
+            solver_events.push(SolverEvent::HqSweep(hq_count, solution));
+        }
+        if let Some(cache) = solver_cache {
+            *cache.lock().unwrap() = Some(macro_solver);
+        }
+        solver_events.push(SolverEvent::Finished(None, Some(solve_report)));
+    });
+}
+
+pub fn spawn_tier_solver(
+    solver_settings: raphael_solver::SolverSettings,
+    initial_state: raphael_sim::SimulationState,
+    quality_targets: [(QualityTarget, u32); 4],
+    context: &SolverContext,
+) {
+    let solver_events = context.events.clone();
+    let solver_interrupt = context.interrupt.clone();
+    rayon::spawn(move || {
+        // intermediate solutions are not reported because they would overwrite the current rotation
+        let mut macro_solver = prepare_solver(
+            solver_settings,
+            Box::new(|_| {}),
+            batch_progress_callback(solver_events.clone()),
+            &solver_events,
+            solver_interrupt,
+            None,
+        );
+        let mut solve_report = SolveReport::default();
+        for (quality_target, target_quality) in quality_targets {
+            let result = macro_solver.solve_from_for_quality(initial_state, target_quality);
+            solve_report.add_solve(&macro_solver);
+            let event = match result {
+                Ok(actions) => SolverEvent::TierSolved(quality_target, Some(actions)),
+                Err(SolverException::NoSolution) => SolverEvent::TierSolved(quality_target, None),
+                Err(exception) => SolverEvent::Finished(Some(exception), Some(solve_report)),
+            };
+            let finished = matches!(event, SolverEvent::Finished(..));
+            solver_events.push(event);
+            if finished {
+                return;
+            }
+        }
+        solver_events.push(SolverEvent::Finished(None, Some(solve_report)));
+    });
+}
+
+/// Solves a rotation for each condition of the steps that the decision tree branches on, see [`raphael_solver::DecisionTree::build`]
+pub fn spawn_decision_tree_solver(
+    solver_settings: raphael_solver::SolverSettings,
+    initial_state: raphael_sim::SimulationState,
+    conditions: Vec<Condition>,
+    depth: usize,
+    context: &SolverContext,
+) {
+    let solver_events = context.events.clone();
+    let solver_interrupt = context.interrupt.clone();
+    rayon::spawn(move || {
+        let mut solve_report = SolveReport::default();
+        // each combination of conditions needs its own tables, which are kept for the consecutive solves in the same conditions
+        let mut cached_solver: Option<raphael_solver::MacroSolver> = None;
+        let result = raphael_solver::DecisionTree::build(
+            solver_settings,
+            initial_state,
+            &conditions,
+            depth,
+            |solver_settings, state| {
+                let macro_solver = match cached_solver.take() {
+                    Some(macro_solver) if *macro_solver.settings() == solver_settings => {
+                        macro_solver
+                    }
+                    _ => {
+                        // intermediate solutions are not reported because they would overwrite the current rotation
+                        prepare_solver(
+                            solver_settings,
+                            Box::new(|_| {}),
+                            batch_progress_callback(solver_events.clone()),
+                            &solver_events,
+                            solver_interrupt.clone(),
+                            None,
+                        )
+                    }
+                };
+                let macro_solver = cached_solver.insert(macro_solver);
+                let result = macro_solver.solve_from_in_conditions(state);
+                solve_report.add_solve(macro_solver);
+                result
+            },
+        );
+        match result {
+            Ok(tree) => {
+                solver_events.push(SolverEvent::DecisionTree(tree));
+                solver_events.push(SolverEvent::Finished(None, Some(solve_report)));
+            }
+            Err(exception) => {
+                solver_events.push(SolverEvent::Finished(Some(exception), Some(solve_report)))
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::sync::{Arc, Mutex};
+
+    use raphael_sim::{ActionMask, Settings, SimulationState, TraitOverrides};
+    use raphael_solver::{AtomicFlag, MacroSolver, SolverSettings};
+
+    use super::{SolverContext, SolverEvent, SolverEvents, spawn_solver};
+    use crate::config::QualityTarget;
+
+    const SETTINGS: Settings = Settings {
+        max_cp: 400,
+        max_durability: 60,
+        max_progress: 2000,
+        max_quality: 1000,
+        base_progress: 100,
+        base_quality: 100,
+        job_level: 90,
+        allowed_actions: ActionMask::regular(),
+        adversarial: false,
+        backload_progress: false,
+        traits: TraitOverrides::none(),
+    };
+
+    #[test]
+    fn test_target_rotations() {
+        let solver_events = SolverEvents {
+            queue: Arc::new(Mutex::new(VecDeque::new())),
+            ctx: egui::Context::default(),
+        };
+        let context = SolverContext {
+            events: solver_events.clone(),
+            interrupt: AtomicFlag::new(),
+            cache: Arc::new(Mutex::new(None)),
+            keep_solver_tables: true,
+        };
+        let lower_quality_targets = vec![
+            (QualityTarget::Zero, 0),
+            (QualityTarget::CollectableT1, 550),
+        ];
+        spawn_solver(
+            SolverSettings::new(SETTINGS),
+            SimulationState::new(&SETTINGS),
+            Vec::new(),
+            lower_quality_targets.clone(),
+            &context,
+        );
+        let mut target_rotations = None;
+        loop {
+            let Some(event) = solver_events.queue.lock().unwrap().pop_front() else {
+                std::thread::sleep(std::time::Duration::from_millis(10));
+                continue;
+            };
+            match event {
+                SolverEvent::TargetRotations(rotations) => target_rotations = Some(rotations),
+                SolverEvent::Finished(exception, _) => {
+                    assert_eq!(exception, None);
+                    break;
+                }
+                _ => (),
+            }
+        }
+        // the rotations of the lower targets are found with the tables of the full quality solve
+        let target_rotations =
+            target_rotations.expect("Expected the rotations of the lower targets");
+        assert_eq!(target_rotations.len(), lower_quality_targets.len());
+        for ((quality_target, actions), (expected_target, target_quality)) in
+            target_rotations.iter().zip(&lower_quality_targets)
+        {
+            assert_eq!(quality_target, expected_target);
+            let state = SimulationState::from_macro(&SETTINGS, actions).unwrap();
+            assert!(state.progress >= u32::from(SETTINGS.max_progress));
+            assert!(state.quality >= *target_quality);
+        }
+        let solver_cache = context.cache.lock().unwrap();
+        assert!(
+            solver_cache
+                .as_ref()
+                .is_some_and(MacroSolver::precompute_done)
+        );
+    }
+}
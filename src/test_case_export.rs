@@ -0,0 +1,124 @@
+//! Exports a solve as a self-contained integration test for `raphael-solver`, so that users can contribute failing cases as they are.
+
+use raphael_sim::{ActionMask, SimulationState};
+use raphael_solver::{OpenerPreference, SolverSettings};
+
+fn action_mask(mask: ActionMask) -> String {
+    format!("ActionMask::from_bits({:#x})", mask.to_bits())
+}
+
+fn opener(opener: OpenerPreference) -> String {
+    match opener {
+        OpenerPreference::Any => "OpenerPreference::Any".to_owned(),
+        OpenerPreference::Force(action) => format!("OpenerPreference::Force(Action::{action:?})"),
+        OpenerPreference::Forbid(action) => format!("OpenerPreference::Forbid(Action::{action:?})"),
+    }
+}
+
+/// Test file that solves from `initial_state` with `solver_settings` and checks that the solver still finds `actions` and the state they end in.
+/// Fails if `actions` don't simulate without errors, since the expected final state couldn't be computed.
+/// Action limits are not exported, the app never sets any.
+pub fn export_test_case(
+    test_name: &str,
+    solver_settings: &SolverSettings,
+    initial_state: SimulationState,
+    actions: &[raphael_sim::Action],
+) -> Result<String, &'static str> {
+    let settings = &solver_settings.simulator_settings;
+    let (state, errors) = initial_state.continue_macro_in_conditions(
+        settings,
+        actions,
+        &solver_settings.opener_conditions,
+    );
+    errors.into_iter().collect::<Result<Vec<_>, _>>()?;
+    let actions = actions
+        .iter()
+        .map(|action| format!("            Action::{action:?},\n"))
+        .collect::<String>();
+    let [first_condition, second_condition] = solver_settings.opener_conditions;
+    Ok(format!(
+        "//! Exported from Raphael XIV v{version}
+//! Copy this file to `raphael-solver/tests/` to run it with the other solver tests.
+
+use raphael_sim::*;
+use raphael_solver::{{AtomicFlag, MacroSolver, OpenerPreference, SolverSettings}};
+
+#[test]
+fn {test_name}() {{
+    let simulator_settings = Settings {{
+        max_cp: {max_cp},
+        max_durability: {max_durability},
+        max_progress: {max_progress},
+        max_quality: {max_quality},
+        base_progress: {base_progress},
+        base_quality: {base_quality},
+        job_level: {job_level},
+        allowed_actions: {allowed_actions},
+        adversarial: {adversarial},
+        backload_progress: {backload_progress},
+        traits: TraitOverrides {{
+            enhanced_actions: {enhanced_actions},
+            unenhanced_actions: {unenhanced_actions},
+            unlocked_actions: {unlocked_actions},
+        }},
+    }};
+    let solver_settings = SolverSettings {{
+        prefer_backload_progress: {prefer_backload_progress},
+        opener: {opener},
+        min_synthesis_durability: {min_synthesis_durability},
+        opener_conditions: [Condition::{first_condition:?}, Condition::{second_condition:?}],
+        ..SolverSettings::new(simulator_settings)
+    }};
+    let mut solver = MacroSolver::new(
+        solver_settings,
+        Box::new(|_| {{}}),
+        Box::new(|_| {{}}),
+        AtomicFlag::new(),
+    );
+    // starting penalties are exported as a lower CP and Durability of the initial state
+    let mut initial_state = SimulationState::new(&simulator_settings);
+    initial_state.cp = {initial_cp};
+    initial_state.durability = {initial_durability};
+    let actions = solver.solve_from(initial_state).unwrap();
+    assert_eq!(
+        actions,
+        [
+{actions}        ]
+    );
+    let (final_state, errors) = initial_state.continue_macro_in_conditions(
+        &simulator_settings,
+        &actions,
+        &solver_settings.opener_conditions,
+    );
+    assert!(errors.iter().all(Result::is_ok));
+    assert_eq!(final_state.progress, {progress});
+    assert_eq!(final_state.quality, {quality});
+    assert_eq!(final_state.durability, {durability});
+    assert_eq!(final_state.cp, {cp});
+}}
+",
+        version = env!("CARGO_PKG_VERSION"),
+        max_cp = settings.max_cp,
+        max_durability = settings.max_durability,
+        max_progress = settings.max_progress,
+        max_quality = settings.max_quality,
+        base_progress = settings.base_progress,
+        base_quality = settings.base_quality,
+        job_level = settings.job_level,
+        allowed_actions = action_mask(settings.allowed_actions),
+        adversarial = settings.adversarial,
+        backload_progress = settings.backload_progress,
+        enhanced_actions = action_mask(settings.traits.enhanced_actions),
+        unenhanced_actions = action_mask(settings.traits.unenhanced_actions),
+        unlocked_actions = action_mask(settings.traits.unlocked_actions),
+        prefer_backload_progress = solver_settings.prefer_backload_progress,
+        opener = opener(solver_settings.opener),
+        min_synthesis_durability = solver_settings.min_synthesis_durability,
+        initial_cp = initial_state.cp,
+        initial_durability = initial_state.durability,
+        progress = state.progress,
+        quality = state.quality,
+        durability = state.durability,
+        cp = state.cp,
+    ))
+}
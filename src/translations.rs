@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use raphael_data::Locale;
+use serde::{Deserialize, Serialize};
+
+/// Language of the UI text, which can differ from the locale of the game data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UiLanguage {
+    EN,
+    JP,
+    CN,
+}
+
+impl UiLanguage {
+    pub fn from_locale(locale: Locale) -> Self {
+        match locale {
+            Locale::JP => Self::JP,
+            Locale::CN => Self::CN,
+            _ => Self::EN,
+        }
+    }
+
+    /// English name of the language, to be passed through [`t`]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::EN => "English",
+            Self::JP => "Japanese",
+            Self::CN => "Chinese",
+        }
+    }
+}
+
+static CURRENT_LANGUAGE: AtomicU8 = AtomicU8::new(UiLanguage::EN as u8);
+
+/// Sets the language that [`t`] translates to, called at the start of every frame
+pub fn set_ui_language(language: UiLanguage) {
+    CURRENT_LANGUAGE.store(language as u8, Ordering::Relaxed);
+}
+
+/// Translates an English UI text to the current UI language.
+/// Texts without a translation are shown in English.
+pub fn t(text: &'static str) -> &'static str {
+    let translations: &HashMap<&str, &str> = match CURRENT_LANGUAGE.load(Ordering::Relaxed) {
+        language if language == UiLanguage::JP as u8 => &TRANSLATIONS_JP,
+        language if language == UiLanguage::CN as u8 => &TRANSLATIONS_CN,
+        _ => return text,
+    };
+    translations.get(text).copied().unwrap_or(text)
+}
+
+static TRANSLATIONS_JP: LazyLock<HashMap<&str, &str>> =
+    LazyLock::new(|| TRANSLATIONS.iter().map(|(en, jp, _)| (*en, *jp)).collect());
+static TRANSLATIONS_CN: LazyLock<HashMap<&str, &str>> =
+    LazyLock::new(|| TRANSLATIONS.iter().map(|(en, _, cn)| (*en, *cn)).collect());
+
+/// (English, Japanese, Chinese)
+/// The characters used here must be covered by the font subsets, see `raphael-data-updater`.
+#[rustfmt::skip]
+const TRANSLATIONS: &[(&str, &str, &str)] = &[
+    // top panel
+    ("View source on GitHub", "GitHubでソースを見る", "在 GitHub 上查看源代码"),
+    ("Support mainline author on Ko-fi", "Ko-fiで本家の作者を支援", "在 Ko-fi 上支持上游作者"),
+    ("⚙ Settings", "⚙ 設定", "⚙ 设置"),
+    ("Zoom", "ズーム", "缩放"),
+    ("Reset", "リセット", "重置"),
+    ("Theme", "テーマ", "主题"),
+    ("Language", "表示言語", "界面语言"),
+    ("Same as game data", "ゲームデータと同じ", "与游戏数据相同"),
+    ("English", "英語", "英语"),
+    ("Japanese", "日本語", "日语"),
+    ("Chinese", "中国語", "中文"),
+    ("Macros & copied names", "マクロとコピーする名前", "宏与复制的名称"),
+    ("Simulator bars", "シミュレーターのバー", "模拟器进度条"),
+    ("Default", "デフォルト", "默认"),
+    ("Colorblind-friendly", "色覚多様性対応", "色盲友好"),
+    ("High contrast", "ハイコントラスト", "高对比度"),
+    ("Patterns", "模様", "图案"),
+    ("Max solver threads", "ソルバーの最大スレッド数", "求解器最大线程数"),
+    ("Global copy hotkey", "コピー用グローバルホットキー", "系统级复制热键"),
+    ("Copies the next macro, even while the game is focused, so that long rotations can be pasted without switching windows.\nModifiers and the key are joined with \"+\", keys are named as on a US keyboard, e.g. \"alt+Digit1\" or \"ctrl+shift+F5\". Leave empty to disable.", "ゲームにフォーカスがあっても次のマクロをコピーするので、長いローテーションもウィンドウを切り換えることなくペーストできます。\n修飾キーとキーは\"+\"でつなぎ、キー名はUSキーボードの表記です（\"alt+Digit1\"、\"ctrl+shift+F5\" など）。空にするとオフになります。", "即使游戏窗口处于焦点，也能复制下一个宏，无需切换窗口即可粘贴较长的技能循环。\n修饰键与按键用\"+\"连接，按键按美式键盘命名，像\"alt+Digit1\"或\"ctrl+shift+F5\"这样。留空就会禁用。"),
+    ("Global hotkeys are not supported on this system", "このシステムではグローバルホットキーを使用できません", "当前系统不支持系统级热键"),
+    ("Invalid hotkey", "ホットキーが正しくありません", "无效的热键"),
+    ("Unable to register hotkey", "ホットキーを設定できません", "无法设置热键"),
+    ("Auto", "自動", "自动"),
+    ("Backup & restore", "バックアップと復元", "备份与恢复"),
+    ("Download latest release from GitHub", "GitHubから最新版をダウンロード", "从 GitHub 下载最新版本"),
+    ("New version available!", "新しいバージョンがあります!", "有新版本可用！"),
+    // configuration
+    ("Configuration", "構成", "配置"),
+    ("Edit crafter stats", "クラフターステータスを編集", "编辑制作者属性"),
+    ("Crafter stats", "クラフターステータス", "制作者属性"),
+    ("Craftsmanship", "作業精度", "作业精度"),
+    ("Control", "加工精度", "加工精度"),
+    ("CP", "CP", "制作力"),
+    ("Job level", "ジョブレベル", "职业等级"),
+    ("HQ materials", "HQ素材", "优质素材"),
+    ("Initial quality", "初期品質", "初期品质"),
+    ("None", "なし", "无"),
+    ("Actions", "アクション", "技能"),
+    ("Fix for me", "自動選択", "自动选择"),
+    ("Select the cheapest food and potion that meet the recipe requirements", "レシピの条件を満たす最も安い食事と薬品を選択", "选择满足配方要求的最便宜的食物和药品"),
+    ("No combination of food and potion meets the recipe requirements", "レシピの条件を満たす食事と薬品の組み合わせはありません", "没有能满足配方要求的食物和药品组合"),
+    // solver
+    ("Solver settings", "ソルバー設定", "求解器设置"),
+    ("Target quality", "目標品質", "目标品质"),
+    ("Solver presets", "ソルバープリセット", "求解器预设"),
+    ("No saved presets", "保存したプリセットはありません", "没有已保存的预设"),
+    ("Preset name", "プリセット名", "预设名称"),
+    ("Max quality", "最高品質", "最高品质"),
+    ("Fast collectables", "収集品（高速）", "快速收藏品"),
+    ("Reliable", "確実", "可靠"),
+    ("Save current", "現在の設定を保存", "保存当前设置"),
+    ("Solve", "計算", "求解"),
+    ("Computing ...", "計算中…", "计算中……"),
+    ("Cancel", "キャンセル", "取消"),
+    ("Close", "閉じる", "关闭"),
+    ("Loaded from saved rotations", "保存したローテーションから読み込みました", "已从保存的技能循环载入"),
+    ("Saved macros & solve history", "保存したマクロと計算履歴", "已保存的宏与求解历史"),
+    ("Repair rotation from a mid-craft state", "製作途中の状態からローテーションを修正", "从制作中途的状态修复技能循环"),
+    ("Show the best achievable Quality with less CP", "より少ないCPで達成できる最高品質を表示", "显示使用更少制作力时可达到的最高品质"),
+    // modals
+    ("Error", "エラー", "错误"),
+    ("Warning", "警告", "警告"),
+    ("Your stats are below the minimum requirement for this recipe.", "ステータスがこのレシピの必要条件を満たしていません。", "你的属性低于该配方的最低要求。"),
+    ("The following consumables would meet the requirement:", "次の消費アイテムで条件を満たせます:", "使用以下消耗品可以满足要求："),
+    ("This may cause your system to run out of memory.", "システムのメモリが不足する可能性があります。", "这可能会导致系统内存不足。"),
+    ("This may reach the 4GB memory limit of 32-bit web assembly and crash the solver.", "32ビットWebAssemblyの4GBメモリ制限に達し、ソルバーがクラッシュする可能性があります。", "这可能会达到 32 位 WebAssembly 的 4GB 内存上限并导致求解器崩溃。"),
+    ("Solve anyway", "それでも計算する", "仍然求解"),
+    ("No solution", "解が見つかりません", "无解"),
+    ("Make sure that the recipe is set correctly and that your stats are enough to craft this item.", "レシピが正しく設定され、ステータスがこのアイテムの製作に十分であることを確認してください。", "请确认配方设置正确，且你的属性足以制作该物品。"),
+    ("This is an internal error. Please submit a bug report :)", "内部エラーです。バグ報告をお願いします :)", "这是一个内部错误，请提交错误报告 :)"),
+    ("Error: Solver ran out of memory!", "エラー: ソルバーのメモリが不足しました!", "错误：求解器内存不足！"),
+    ("The solver reached the 4GB memory limit of 32-bit web assembly and crashed.", "ソルバーが32ビットWebAssemblyの4GBメモリ制限に達し、クラッシュしました。", "求解器达到了 32 位 WebAssembly 的 4GB 内存上限并已崩溃。"),
+    ("Consider enabling fewer memory intensive options.\n", "メモリを多く使うオプションを減らしてください。\n", "请考虑少启用一些占用内存较多的选项。\n"),
+    ("Alternatively, a native version is available from the release page on GitHub.", "または、GitHubのリリースページからネイティブ版を入手できます。", "或者，也可以从 GitHub 的发布页面下载本地版本。"),
+    ("The native version doesn't have the 4GB limit, in addition to better performance.", "ネイティブ版には4GBの制限がなく、性能も優れています。", "本地版本没有 4GB 的限制，而且性能更好。"),
+    ("Reload the page to reset the app", "ページを再読み込みしてアプリをリセットしてください", "重新加载页面以重置应用"),
+    // rotation repair
+    ("Repair rotation", "ローテーション修正", "修复技能循环"),
+    ("Enter the in-game state after the last action that went as planned.", "予定どおりに実行できた最後のアクション後のゲーム内の状態を入力してください。", "输入最后一个按计划执行的技能之后的游戏内状态。"),
+    ("Completed steps", "完了したステップ数", "已完成步数"),
+    ("Progress", "工数", "进展"),
+    ("Quality", "品質", "品质"),
+    ("Durability", "耐久", "耐久"),
+    ("Repair", "修正", "修复"),
+    // collectable tiers and CP tradeoff
+    ("Collectable tiers", "収集品の段階", "收藏品档位"),
+    ("Target", "目標", "目标"),
+    ("Steps", "ステップ", "步数"),
+    ("Duration", "所要時間", "耗时"),
+    ("Use", "使用", "使用"),
+    ("Quality vs. CP", "品質とCP", "品质与制作力"),
+    // decision tree
+    ("Decision tree", "決定木", "决策树"),
+    ("Copy as text", "テキストとしてコピー", "复制为文本"),
+];
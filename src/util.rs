@@ -1,7 +1,7 @@
-use raphael_data::Consumable;
+use raphael_data::{Consumable, CrafterStats, Recipe};
 
 use crate::{
-    app::SolverConfig,
+    app::{MinimumStats, SolverConfig},
     config::{
         CrafterConfig, CustomRecipeOverridesConfiguration, QualitySource, RecipeConfiguration,
     },
@@ -9,6 +9,7 @@ use crate::{
 
 pub fn get_initial_quality(
     recipe_config: &RecipeConfiguration,
+    custom_recipe_overrides_config: &CustomRecipeOverridesConfiguration,
     crafter_config: &CrafterConfig,
 ) -> u16 {
     match recipe_config.quality_source {
@@ -18,9 +19,80 @@ pub fn get_initial_quality(
             hq_materials,
         ),
         QualitySource::Value(quality) => quality,
+        QualitySource::Percentage(percentage) => {
+            let max_quality = match custom_recipe_overrides_config.use_custom_recipe {
+                true => {
+                    custom_recipe_overrides_config
+                        .custom_recipe_overrides
+                        .max_quality_override
+                }
+                false => {
+                    raphael_data::get_game_settings(
+                        recipe_config.recipe,
+                        None,
+                        *crafter_config.active_stats(),
+                        None,
+                        None,
+                    )
+                    .max_quality
+                }
+            };
+            (u32::from(max_quality) * u32::from(percentage) / 100) as u16
+        }
     }
 }
 
+/// Finds foods and potions that would make the crafter meet the recipe's stat requirements when swapped in for the current selection.
+/// Lower item level consumables are listed first since those are usually cheaper.
+pub fn suggest_consumables(
+    recipe: &Recipe,
+    crafter_stats: CrafterStats,
+    selected_food: Option<Consumable>,
+    selected_potion: Option<Consumable>,
+) -> Vec<Consumable> {
+    const MAX_SUGGESTIONS_PER_KIND: usize = 3;
+    let find_suggestions = |candidates: &[Consumable], other: Option<Consumable>| {
+        let mut suggestions: Vec<Consumable> = candidates
+            .iter()
+            .filter(|consumable| {
+                raphael_data::missing_stats(recipe, crafter_stats, &[Some(**consumable), other])
+                    == (0, 0)
+            })
+            .copied()
+            .collect();
+        suggestions.sort_by_key(|consumable| (consumable.item_level, consumable.hq));
+        suggestions.truncate(MAX_SUGGESTIONS_PER_KIND);
+        suggestions
+    };
+    let mut suggestions = find_suggestions(raphael_data::MEALS, selected_potion);
+    suggestions.extend(find_suggestions(raphael_data::POTIONS, selected_food));
+    suggestions
+}
+
+/// Finds the food and potion combination with the lowest total item level that makes the crafter meet the recipe's stat requirements.
+pub fn find_cheapest_consumables(
+    recipe: &Recipe,
+    crafter_stats: CrafterStats,
+) -> Option<(Option<Consumable>, Option<Consumable>)> {
+    let meals = std::iter::once(None).chain(raphael_data::MEALS.iter().copied().map(Some));
+    meals
+        .flat_map(|food| {
+            let potions =
+                std::iter::once(None).chain(raphael_data::POTIONS.iter().copied().map(Some));
+            potions.map(move |potion| (food, potion))
+        })
+        .filter(|(food, potion)| {
+            raphael_data::missing_stats(recipe, crafter_stats, &[*food, *potion]) == (0, 0)
+        })
+        .min_by_key(|(food, potion)| {
+            let item_level =
+                |consumable: &Option<Consumable>| consumable.map_or(0, |c| c.item_level);
+            let hq_count =
+                usize::from(food.is_some_and(|c| c.hq)) + usize::from(potion.is_some_and(|c| c.hq));
+            (item_level(food) + item_level(potion), hq_count)
+        })
+}
+
 pub fn get_game_settings(
     recipe_config: &RecipeConfiguration,
     custom_recipe_overrides_config: &CustomRecipeOverridesConfiguration,
@@ -40,7 +112,189 @@ pub fn get_game_settings(
         selected_potion,
     );
 
+    apply_solver_config(&mut game_settings, solver_config);
+    game_settings
+}
+
+/// Applies the parts of the solver configuration that are part of the game settings
+pub fn apply_solver_config(
+    game_settings: &mut raphael_sim::Settings,
+    solver_config: &SolverConfig,
+) {
     game_settings.adversarial = solver_config.adversarial;
     game_settings.backload_progress = solver_config.backload_progress;
-    game_settings
+    if solver_config.forbid_trained_eye {
+        game_settings.allowed_actions = game_settings
+            .allowed_actions
+            .remove(raphael_sim::Action::TrainedEye);
+    }
+}
+
+/// Finds the lowest stats with which `actions` still reach the target Progress and Quality
+pub fn find_minimum_stats(
+    mut game_settings: raphael_sim::Settings,
+    solver_config: &SolverConfig,
+    target_quality: u32,
+    actions: &[raphael_sim::Action],
+    recipe: &Recipe,
+) -> MinimumStats {
+    let target_progress = game_settings.max_progress as u32;
+    let initial_state = solver_config.initial_state(&game_settings);
+
+    let (actual_result, _) = solver_config.simulate(&game_settings, actions);
+    if actual_result.progress < target_progress {
+        return MinimumStats::default();
+    }
+
+    let (mut min_progress, mut max_progress) = (1u16, game_settings.base_progress);
+    let (mut min_quality, mut max_quality, mut can_target_quality) =
+        if actual_result.quality >= target_quality {
+            (1u16, game_settings.base_quality, true)
+        } else {
+            (
+                game_settings.base_quality,
+                game_settings.base_quality * 3 / 2,
+                false,
+            )
+        };
+    if target_quality == 0 || actions[0] == raphael_sim::Action::TrainedEye {
+        max_quality = 1;
+    }
+    while min_progress + 1 < max_progress || min_quality + 1 < max_quality {
+        game_settings.base_progress = (min_progress + max_progress) / 2;
+        game_settings.base_quality = (min_quality + max_quality) / 2;
+        let (state, _) = solver_config.simulate(&game_settings, actions);
+        if state.progress < target_progress {
+            min_progress = game_settings.base_progress;
+        } else {
+            max_progress = game_settings.base_progress;
+        }
+        if state.quality < target_quality {
+            min_quality = game_settings.base_quality;
+        } else {
+            max_quality = game_settings.base_quality;
+            can_target_quality = true;
+        }
+    }
+
+    let rlvl = raphael_data::effective_recipe_level(recipe, game_settings.job_level);
+    let rlvl_record = raphael_data::RLVLS[rlvl as usize];
+    let mut craftsmanship = max_progress as f32;
+    let mut control = max_quality as f32;
+    if game_settings.job_level <= rlvl_record.job_level {
+        craftsmanship = craftsmanship * 100.0 / rlvl_record.progress_mod as f32;
+        control = control * 100.0 / rlvl_record.quality_mod as f32;
+    }
+    craftsmanship = (craftsmanship - 2.0) * rlvl_record.progress_div as f32 / 10.0;
+    control = (control - 35.0) * rlvl_record.quality_div as f32 / 10.0;
+    MinimumStats {
+        craftsmanship: Some(craftsmanship.ceil() as u16),
+        control: can_target_quality.then_some(control.ceil() as u16),
+        cp: Some(initial_state.cp - actual_result.cp),
+    }
+}
+
+/// Chances of the conditions the recipe rolls, which can be overridden for custom recipes
+pub fn get_condition_probabilities(
+    recipe_config: &RecipeConfiguration,
+    custom_recipe_overrides_config: &CustomRecipeOverridesConfiguration,
+) -> raphael_sim::ConditionProbabilities {
+    raphael_data::condition_probabilities(
+        recipe_config.recipe,
+        match custom_recipe_overrides_config.use_custom_recipe {
+            true => Some(custom_recipe_overrides_config.custom_recipe_overrides),
+            false => None,
+        },
+    )
+}
+
+/// Rough estimate of the peak memory usage of the solver in bytes.
+///
+/// The constants are fitted to the peak resident memory of `raphael-cli solve` on a level 690 custom recipe
+/// (6600 Progress, 12000 Quality) with 5000 Craftsmanship and 4800 Control, see `tests` below for the measured values.
+/// The estimate is only meant to flag unusually heavy solves, so the factors are rounded up where the measurements differ.
+pub fn estimate_solver_memory_usage(game_settings: &raphael_sim::Settings) -> u64 {
+    // measured between 60 KiB and 99 KiB per CP and 5 Durability
+    const BYTES_PER_RESOURCE_UNIT: u64 = 100 * 1024;
+    let durability_units = u64::from(game_settings.max_durability / 5);
+    let mut estimate = u64::from(game_settings.max_cp) * durability_units * BYTES_PER_RESOURCE_UNIT;
+    // Heart and Soul and Quick Innovation together measured 4.4x
+    if game_settings
+        .allowed_actions
+        .has(raphael_sim::Action::HeartAndSoul)
+    {
+        estimate *= 2;
+    }
+    if game_settings
+        .allowed_actions
+        .has(raphael_sim::Action::QuickInnovation)
+    {
+        estimate *= 2;
+    }
+    // measured 1.5x
+    if game_settings.adversarial {
+        estimate *= 2;
+    }
+    estimate
+}
+
+/// Estimated memory usage above which the user is asked to confirm before solving.
+pub fn solver_memory_budget() -> u64 {
+    #[cfg(target_arch = "wasm32")]
+    return 2560 * 1024 * 1024; // leave some headroom below the 4GB limit of 32-bit web assembly
+    #[cfg(not(target_arch = "wasm32"))]
+    return 8 * 1024 * 1024 * 1024;
+}
+
+#[cfg(test)]
+mod tests {
+    use raphael_sim::{Action, ActionMask, Settings, TraitOverrides};
+
+    use super::estimate_solver_memory_usage;
+
+    fn settings(
+        max_cp: u16,
+        max_durability: u16,
+        allowed_actions: ActionMask,
+        adversarial: bool,
+    ) -> Settings {
+        Settings {
+            max_cp,
+            max_durability,
+            max_progress: 6600,
+            max_quality: 12000,
+            base_progress: 266,
+            base_quality: 266,
+            job_level: 100,
+            allowed_actions,
+            adversarial,
+            backload_progress: false,
+            traits: TraitOverrides::none(),
+        }
+    }
+
+    #[test]
+    fn test_estimate_solver_memory_usage() {
+        const MIB: u64 = 1024 * 1024;
+        let specialist_actions = ActionMask::regular()
+            .add(Action::HeartAndSoul)
+            .add(Action::QuickInnovation);
+        // peak resident memory of `raphael-cli solve` for the same settings
+        let measurements = [
+            (settings(500, 40, ActionMask::regular(), false), 385 * MIB),
+            (settings(600, 70, ActionMask::regular(), false), 815 * MIB),
+            (settings(700, 80, ActionMask::regular(), false), 657 * MIB),
+            (settings(600, 70, specialist_actions, false), 3547 * MIB),
+            (settings(600, 70, ActionMask::regular(), true), 1256 * MIB),
+        ];
+        for (settings, measured) in measurements {
+            let estimate = estimate_solver_memory_usage(&settings);
+            assert!(
+                estimate > measured * 3 / 4 && estimate < measured * 2,
+                "estimate of {} MiB is off from the measured {} MiB for {settings:?}",
+                estimate / MIB,
+                measured / MIB,
+            );
+        }
+    }
 }
@@ -0,0 +1,133 @@
+//! IndexedDB storage for values that are too large for localStorage, which is limited to a few MB per site.
+//! IndexedDB is asynchronous, so reads are started in the background and polled by the app.
+//! Writes are queued and run one after the other on a single database connection, so that an older value can't overwrite a newer one.
+
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use web_sys::wasm_bindgen::{JsCast, JsValue, closure::Closure};
+
+const DATABASE_NAME: &str = "raphael-xiv";
+const OBJECT_STORE_NAME: &str = "storage";
+
+/// Result of a background read, `None` until the read has finished.
+/// The read fails if IndexedDB is unavailable, e.g. in private browsing mode of some browsers.
+pub type PendingRead = Arc<Mutex<Option<Result<Option<String>, String>>>>;
+
+fn request_to_future(request: &web_sys::IdbRequest) -> wasm_bindgen_futures::JsFuture {
+    let request = request.clone();
+    let promise = js_sys::Promise::new(&mut |resolve, reject| {
+        let success_request = request.clone();
+        let on_success = Closure::once_into_js(move |_: web_sys::Event| {
+            let result = success_request.result().unwrap_or(JsValue::UNDEFINED);
+            resolve.call1(&JsValue::NULL, &result).ok();
+        });
+        let error_request = request.clone();
+        let on_error = Closure::once_into_js(move |_: web_sys::Event| {
+            let error = match error_request.error() {
+                Ok(Some(error)) => JsValue::from(error),
+                _ => JsValue::NULL,
+            };
+            reject.call1(&JsValue::NULL, &error).ok();
+        });
+        request.set_onsuccess(Some(on_success.unchecked_ref()));
+        request.set_onerror(Some(on_error.unchecked_ref()));
+    });
+    wasm_bindgen_futures::JsFuture::from(promise)
+}
+
+thread_local! {
+    /// Connection shared by all reads and writes, opened by the first of them
+    static DATABASE: RefCell<Option<web_sys::IdbDatabase>> = const { RefCell::new(None) };
+    /// Values waiting to be written, at most one per key since only the latest value of a key needs to be written
+    static PENDING_WRITES: RefCell<VecDeque<(&'static str, String)>> = const { RefCell::new(VecDeque::new()) };
+    /// Whether a task is writing the pending values
+    static WRITER_RUNNING: Cell<bool> = const { Cell::new(false) };
+}
+
+async fn open_database() -> Result<web_sys::IdbDatabase, JsValue> {
+    let factory = web_sys::window()
+        .ok_or_else(|| JsValue::from_str("No window"))?
+        .indexed_db()?
+        .ok_or_else(|| JsValue::from_str("IndexedDB is not supported"))?;
+    let open_request = factory.open_with_u32(DATABASE_NAME, 1)?;
+    let upgrade_request = open_request.clone();
+    let on_upgrade_needed = Closure::once_into_js(move |_: web_sys::Event| {
+        if let Ok(database) = upgrade_request.result() {
+            let database: web_sys::IdbDatabase = database.unchecked_into();
+            database.create_object_store(OBJECT_STORE_NAME).ok();
+        }
+    });
+    open_request.set_onupgradeneeded(Some(on_upgrade_needed.unchecked_ref()));
+    let database = request_to_future(&open_request).await?;
+    Ok(database.unchecked_into())
+}
+
+async fn database() -> Result<web_sys::IdbDatabase, JsValue> {
+    if let Some(database) = DATABASE.with_borrow(Clone::clone) {
+        return Ok(database);
+    }
+    let database = open_database().await?;
+    // another read or write may have opened the database in the meantime
+    Ok(DATABASE.with_borrow_mut(|cached| cached.get_or_insert(database).clone()))
+}
+
+async fn get(key: &str) -> Result<Option<String>, JsValue> {
+    let database = database().await?;
+    let transaction = database.transaction_with_str(OBJECT_STORE_NAME)?;
+    let request = transaction
+        .object_store(OBJECT_STORE_NAME)?
+        .get(&JsValue::from_str(key))?;
+    Ok(request_to_future(&request).await?.as_string())
+}
+
+async fn put(key: &str, value: &str) -> Result<(), JsValue> {
+    let database = database().await?;
+    let transaction = database
+        .transaction_with_str_and_mode(OBJECT_STORE_NAME, web_sys::IdbTransactionMode::Readwrite)?;
+    let request = transaction
+        .object_store(OBJECT_STORE_NAME)?
+        .put_with_key(&JsValue::from_str(value), &JsValue::from_str(key))?;
+    request_to_future(&request).await?;
+    Ok(())
+}
+
+/// Starts reading `key` in the background. A repaint is requested once the value is available.
+pub fn read(key: &'static str, ctx: egui::Context) -> PendingRead {
+    let pending_read = Arc::new(Mutex::new(None));
+    let result = pending_read.clone();
+    wasm_bindgen_futures::spawn_local(async move {
+        let value = get(key).await.map_err(|error| {
+            log::error!("Failed to read {key} from IndexedDB: {error:?}");
+            format!("{error:?}")
+        });
+        *result.lock().unwrap() = Some(value);
+        ctx.request_repaint();
+    });
+    pending_read
+}
+
+/// Writes `value` to `key` in the background, after the writes that have been started before
+pub fn write(key: &'static str, value: String) {
+    PENDING_WRITES.with_borrow_mut(|pending_writes| {
+        match pending_writes
+            .iter_mut()
+            .find(|(pending_key, _)| *pending_key == key)
+        {
+            Some((_, pending_value)) => *pending_value = value,
+            None => pending_writes.push_back((key, value)),
+        }
+    });
+    if WRITER_RUNNING.replace(true) {
+        return;
+    }
+    wasm_bindgen_futures::spawn_local(async {
+        while let Some((key, value)) = PENDING_WRITES.with_borrow_mut(VecDeque::pop_front) {
+            if let Err(error) = put(key, &value).await {
+                log::error!("Failed to write {key} to IndexedDB: {error:?}");
+            }
+        }
+        WRITER_RUNNING.set(false);
+    });
+}
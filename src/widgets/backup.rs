@@ -0,0 +1,143 @@
+use egui::TextStyle;
+
+use crate::translations::t;
+
+/// Request from the backup window that needs the rest of the app to be carried out
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BackupRequest {
+    #[cfg(not(target_arch = "wasm32"))]
+    ExportToFile,
+    CopyToClipboard,
+    /// Restore the backup with the given text
+    Restore(String),
+    /// Reset the settings but keep the saved macros
+    ResetSettings,
+}
+
+/// Window to back up and restore all settings, saved macros and the solve history
+#[derive(Debug, Default)]
+pub struct BackupWindow {
+    pub open: bool,
+    text: String,
+    /// Outcome of the last backup or restore
+    status: Option<Result<String, String>>,
+}
+
+impl BackupWindow {
+    pub fn show(&mut self) {
+        self.open = true;
+        self.text = String::new();
+        self.status = None;
+    }
+
+    pub fn set_status(&mut self, status: Result<String, String>) {
+        self.status = Some(status);
+    }
+
+    pub fn ui(&mut self, ui: &mut egui::Ui, solver_pending: bool) -> Option<BackupRequest> {
+        let mut request = None;
+        ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+        ui.set_width(320.0);
+        ui.label(egui::RichText::new(t("Backup & restore")).strong());
+        ui.separator();
+        ui.label("A backup contains all settings, saved macros and the solve history.");
+        ui.horizontal(|ui| {
+            #[cfg(not(target_arch = "wasm32"))]
+            if ui.button("Export to file").clicked() {
+                request = Some(BackupRequest::ExportToFile);
+            }
+            if ui.button("Copy to clipboard").clicked() {
+                request = Some(BackupRequest::CopyToClipboard);
+            }
+        });
+        ui.separator();
+        ui.label("To restore a backup, drop the backup file onto this window or paste the backup below. All current settings, saved macros and the solve history are replaced.");
+        egui::ScrollArea::vertical()
+            .max_height(80.0)
+            .show(ui, |ui| {
+                ui.add(
+                    egui::TextEdit::multiline(&mut self.text)
+                        .font(TextStyle::Monospace)
+                        .desired_width(f32::INFINITY)
+                        .desired_rows(3),
+                );
+            });
+        ui.add_enabled_ui(!solver_pending, |ui| {
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(
+                        !self.text.is_empty(),
+                        egui::Button::new("Restore pasted backup"),
+                    )
+                    .clicked()
+                {
+                    request = Some(BackupRequest::Restore(std::mem::take(&mut self.text)));
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                if let Some(path) = crate::backup::backup_file_path().filter(|path| path.exists())
+                    && ui
+                        .button("Restore exported file")
+                        .on_hover_text(path.display().to_string())
+                        .clicked()
+                {
+                    match std::fs::read_to_string(&path) {
+                        Ok(text) => request = Some(BackupRequest::Restore(text)),
+                        Err(error) => {
+                            self.status = Some(Err(format!("Failed to read backup: {error}")))
+                        }
+                    }
+                }
+            });
+            if ui.button("Reset settings, keep saved macros").clicked() {
+                request = Some(BackupRequest::ResetSettings);
+            }
+        });
+        if solver_pending {
+            ui.label(
+                egui::RichText::new("⚠ Unavailable while the solver is running.")
+                    .small()
+                    .color(ui.visuals().warn_fg_color),
+            );
+        }
+
+        let dropped_file = ui.input(|input| input.raw.dropped_files.first().cloned());
+        if let Some(file) = dropped_file
+            && !solver_pending
+        {
+            let text = match (&file.bytes, &file.path) {
+                (Some(bytes), _) => {
+                    String::from_utf8(bytes.to_vec()).map_err(|error| error.to_string())
+                }
+                (None, Some(path)) => {
+                    std::fs::read_to_string(path).map_err(|error| error.to_string())
+                }
+                (None, None) => Err("File is not accessible".to_owned()),
+            };
+            match text {
+                Ok(text) => request = Some(BackupRequest::Restore(text)),
+                Err(error) => self.status = Some(Err(format!("Failed to read backup: {error}"))),
+            }
+        }
+
+        match &self.status {
+            Some(Ok(message)) => {
+                ui.label(egui::RichText::new(message).small());
+            }
+            Some(Err(message)) => {
+                ui.label(
+                    egui::RichText::new(format!("⚠ {message}"))
+                        .small()
+                        .color(ui.visuals().warn_fg_color),
+                );
+            }
+            None => {}
+        }
+        ui.separator();
+        ui.vertical_centered_justified(|ui| {
+            if ui.button(t("Close")).clicked() {
+                self.open = false;
+            }
+        });
+        request
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{
+    config::{BarNumberFormat, BarPalette, BarStyle},
+    translations::t,
+};
+
+/// Rows of the settings menu that change the appearance of the simulator bars
+pub struct BarStyleSettings<'a> {
+    bar_style: &'a mut BarStyle,
+}
+
+impl<'a> BarStyleSettings<'a> {
+    pub fn new(bar_style: &'a mut BarStyle) -> Self {
+        Self { bar_style }
+    }
+}
+
+impl egui::Widget for BarStyleSettings<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        ui.vertical(|ui| {
+            ui.horizontal(|ui| {
+                ui.label(t("Simulator bars"));
+                egui::ComboBox::from_id_salt("BAR_PALETTE")
+                    .selected_text(t(self.bar_style.palette.name()))
+                    .show_ui(ui, |ui| {
+                        for palette in BarPalette::ALL {
+                            ui.selectable_value(&mut self.bar_style.palette, palette, t(palette.name()));
+                        }
+                    });
+                ui.checkbox(&mut self.bar_style.patterns, t("Patterns"));
+            });
+            ui.horizontal(|ui| {
+                ui.label(t("Bar text"));
+                egui::ComboBox::from_id_salt("BAR_NUMBER_FORMAT")
+                    .selected_text(t(self.bar_style.number_format.name()))
+                    .show_ui(ui, |ui| {
+                        for number_format in BarNumberFormat::ALL {
+                            ui.selectable_value(&mut self.bar_style.number_format, number_format, t(number_format.name()));
+                        }
+                    });
+                let mut show_minimum_stats = !self.bar_style.hide_minimum_stats;
+                ui.checkbox(&mut show_minimum_stats, t("Minimum stats"))
+                    .on_hover_text(t("Append the minimum Craftsmanship and Control to the Progress and Quality bars"));
+                self.bar_style.hide_minimum_stats = !show_minimum_stats;
+            });
+        })
+        .response
+    }
+}
@@ -0,0 +1,33 @@
+use raphael_data::CalculationMismatch;
+
+use crate::translations::t;
+
+/// Warns that the Progress and Quality calculations disagree with the reference implementation
+pub struct CalculationMismatches<'a> {
+    mismatches: &'a [CalculationMismatch],
+}
+
+impl<'a> CalculationMismatches<'a> {
+    pub fn new(mismatches: &'a [CalculationMismatch]) -> Self {
+        Self { mismatches }
+    }
+}
+
+impl egui::Widget for CalculationMismatches<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        ui.group(|ui| {
+            ui.label(
+                egui::RichText::new("⚠ Calculations disagree with the reference implementation")
+                    .strong()
+                    .color(ui.visuals().warn_fg_color),
+            );
+            for mismatch in self.mismatches {
+                ui.label(mismatch.to_string());
+            }
+            ui.label(t(
+                "This is an internal error. Please submit a bug report :)",
+            ));
+        })
+        .response
+    }
+}
@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+
+use raphael_data::{Consumable, CrafterStats, Locale};
+use raphael_sim::Action;
+
+use super::{
+    HelpText, ItemNameLabel,
+    collectable_tiers::{COLLECTABLE_TIERS, scrip_reward, scrips_per_hour},
+};
+use crate::{
+    app::{BatchSolution, COLLECTABLE_SWEEP_RECIPE_LIMIT, macro_duration_seconds},
+    config::QualityTarget,
+    thread_pool,
+    translations::t,
+};
+
+/// Request from the collectable sweep that needs the rest of the app to be carried out
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CollectableSweepRequest {
+    /// Solve each tier of all listed collectables
+    Solve,
+    /// Select the recipe with the given id and use the rotation for the tier
+    Use(u32, QualityTarget, Vec<Action>),
+}
+
+/// Window that solves each tier of the collectables at the highest level the crafter can craft,
+/// to find the collectable and tier with the most scrips per hour
+#[derive(Debug, Default)]
+pub struct CollectableSweepWindow {
+    pub open: bool,
+    /// Collectables that are solved by the sweep
+    recipe_ids: Vec<u32>,
+    /// Results by batch index, see [`Self::tier_solves`]
+    results: Vec<(usize, Option<BatchSolution>)>,
+}
+
+impl CollectableSweepWindow {
+    pub fn show(&mut self, recipe_ids: Vec<u32>) {
+        self.open = true;
+        self.recipe_ids = recipe_ids;
+        self.results.clear();
+    }
+
+    /// Batch index, recipe id and tier of each solve of the sweep
+    pub fn tier_solves(&self) -> impl Iterator<Item = (usize, u32, QualityTarget)> + '_ {
+        self.recipe_ids
+            .iter()
+            .flat_map(|recipe_id| {
+                COLLECTABLE_TIERS.map(|quality_target| (*recipe_id, quality_target))
+            })
+            .enumerate()
+            .map(|(index, (recipe_id, quality_target))| (index, recipe_id, quality_target))
+    }
+
+    pub fn clear_results(&mut self) {
+        self.results.clear();
+    }
+
+    pub fn push_result(&mut self, index: usize, solution: Option<BatchSolution>) {
+        self.results.push((index, solution));
+    }
+
+    fn result(&self, index: usize) -> Option<&Option<BatchSolution>> {
+        self.results
+            .iter()
+            .find(|(result_index, _)| *result_index == index)
+            .map(|(_, solution)| solution)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// Results for the webhook notification of the finished solve
+    pub fn summary_lines(&self, locale: Locale) -> Vec<String> {
+        self.tier_solves()
+            .filter_map(|(index, recipe_id, quality_target)| {
+                let name = raphael_data::get_item_name(
+                    raphael_data::RECIPES[&recipe_id].item_id,
+                    false,
+                    locale,
+                )
+                .unwrap_or_default();
+                let line = self.result(index)?.as_ref().map_or_else(
+                    || format!("{name} ({quality_target}): no solution"),
+                    |solution| {
+                        format!(
+                            "{name} ({quality_target}): {} steps, {} seconds",
+                            solution.actions.len(),
+                            macro_duration_seconds(&solution.actions)
+                        )
+                    },
+                );
+                Some(line)
+            })
+            .collect()
+    }
+
+    pub fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        crafter_stats: CrafterStats,
+        consumables: [Option<Consumable>; 2],
+        collectable_scrip_rewards: &mut HashMap<u32, [u16; 3]>,
+        locale: Locale,
+    ) -> Option<CollectableSweepRequest> {
+        let mut request = None;
+        ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+        ui.set_width(480.0);
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new("Collectable scrips").strong());
+            ui.add(HelpText::new("The scrip reward of each tier is entered per collectable, as listed by the collectables appraiser, and shared with the collectable tiers window.\nThe scrips per hour count the reward of the highest tier the rotation reaches and the time of its actions plus the time to start the craft."));
+        });
+        ui.separator();
+        ui.label(format!(
+            "Collectables at the highest level that can be crafted with the current stats, solved for each tier without HQ materials and with the current solver settings (at most {}).",
+            COLLECTABLE_SWEEP_RECIPE_LIMIT,
+        ));
+        let max_quality = |recipe_id: u32| {
+            raphael_data::get_game_settings(
+                raphael_data::RECIPES[&recipe_id],
+                None,
+                crafter_stats,
+                consumables[0],
+                consumables[1],
+            )
+            .max_quality
+        };
+        let scrips_per_hour = |recipe_id: u32, solution: &BatchSolution| {
+            let item_id = raphael_data::RECIPES[&recipe_id].item_id;
+            let scrip_rewards = collectable_scrip_rewards
+                .get(&item_id)
+                .copied()
+                .unwrap_or_default();
+            let reward = scrip_reward(scrip_rewards, solution.quality, max_quality(recipe_id));
+            scrips_per_hour(reward, &solution.actions)
+        };
+        let best = self
+            .tier_solves()
+            .filter_map(|(index, recipe_id, quality_target)| {
+                let solution = self.result(index)?.as_ref()?;
+                Some((
+                    scrips_per_hour(recipe_id, solution),
+                    recipe_id,
+                    quality_target,
+                ))
+            })
+            .max_by_key(|(scrips_per_hour, _, _)| *scrips_per_hour)
+            .filter(|(scrips_per_hour, _, _)| *scrips_per_hour != 0);
+        if let Some((scrips_per_hour, recipe_id, quality_target)) = best {
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new("★ Best:").strong());
+                ui.add(ItemNameLabel::new(
+                    raphael_data::RECIPES[&recipe_id].item_id,
+                    false,
+                    locale,
+                ));
+                ui.label(
+                    egui::RichText::new(format!("{quality_target}, {scrips_per_hour} scrips/h"))
+                        .strong(),
+                );
+            });
+        }
+        let mut rewards_changed = Vec::new();
+        egui::Grid::new("collectable_sweep_grid")
+            .striped(true)
+            .num_columns(6)
+            .show(ui, |ui| {
+                ui.label(egui::RichText::new(t("Recipe")).strong());
+                ui.label(egui::RichText::new(t("Target")).strong());
+                ui.label(egui::RichText::new(t("Scrip rewards")).strong());
+                ui.label(egui::RichText::new(t("Duration")).strong());
+                ui.label(egui::RichText::new(t("Scrips/h")).strong());
+                ui.end_row();
+                for (index, recipe_id, quality_target) in self.tier_solves() {
+                    let item_id = raphael_data::RECIPES[&recipe_id].item_id;
+                    let tier_index = index % COLLECTABLE_TIERS.len();
+                    if tier_index == 0 {
+                        ui.add(ItemNameLabel::new(item_id, false, locale));
+                    } else {
+                        ui.label("");
+                    }
+                    ui.label(format!("{}", quality_target));
+                    let mut scrip_rewards = collectable_scrip_rewards
+                        .get(&item_id)
+                        .copied()
+                        .unwrap_or_default();
+                    if ui
+                        .add(egui::DragValue::new(&mut scrip_rewards[tier_index]))
+                        .changed()
+                    {
+                        rewards_changed.push((item_id, scrip_rewards));
+                    }
+                    match self.result(index) {
+                        Some(Some(solution)) => {
+                            ui.label(format!(
+                                "{} seconds",
+                                macro_duration_seconds(&solution.actions)
+                            ));
+                            let scrips_per_hour = scrips_per_hour(recipe_id, solution);
+                            if best.is_some_and(|(_, best_recipe_id, best_quality_target)| {
+                                best_recipe_id == recipe_id && best_quality_target == quality_target
+                            }) {
+                                ui.label(
+                                    egui::RichText::new(format!("★ {}", scrips_per_hour)).strong(),
+                                )
+                                .on_hover_text("Most scrips per hour");
+                            } else {
+                                ui.label(format!("{}", scrips_per_hour));
+                            }
+                            if ui.button(t("Use")).clicked() {
+                                request = Some(CollectableSweepRequest::Use(
+                                    recipe_id,
+                                    quality_target,
+                                    solution.actions.clone(),
+                                ));
+                            }
+                        }
+                        Some(None) => {
+                            ui.label(t("No solution"));
+                        }
+                        None => {
+                            ui.label("—");
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+        for (item_id, scrip_rewards) in rewards_changed {
+            collectable_scrip_rewards.insert(item_id, scrip_rewards);
+        }
+        if self.recipe_ids.is_empty() {
+            ui.label(t("None"));
+        }
+        ui.separator();
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(
+                    !self.recipe_ids.is_empty() && thread_pool::is_initialized(),
+                    egui::Button::new(t("Solve")),
+                )
+                .clicked()
+            {
+                request = Some(CollectableSweepRequest::Solve);
+            }
+            if ui.button(t("Close")).clicked() {
+                self.open = false;
+            }
+        });
+        if matches!(request, Some(CollectableSweepRequest::Use(..))) {
+            self.open = false;
+        }
+        request
+    }
+}
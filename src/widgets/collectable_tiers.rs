@@ -0,0 +1,174 @@
+use egui::{Align, Layout};
+use raphael_sim::{Action, Settings};
+
+use super::HelpText;
+use crate::{
+    app::{CRAFT_OVERHEAD_SECONDS, SolverConfig, macro_duration_seconds},
+    config::QualityTarget,
+    translations::t,
+};
+
+/// Collectable tiers that scrips are rewarded for, in the order of the scrip rewards
+pub const COLLECTABLE_TIERS: [QualityTarget; 3] = [
+    QualityTarget::CollectableT1,
+    QualityTarget::CollectableT2,
+    QualityTarget::CollectableT3,
+];
+
+/// Scrips rewarded for a collectable that reaches `quality`, which is the reward of the highest tier whose threshold it reaches
+pub(super) fn scrip_reward(scrip_rewards: [u16; 3], quality: u32, max_quality: u16) -> u16 {
+    COLLECTABLE_TIERS
+        .iter()
+        .zip(scrip_rewards)
+        .filter(|(quality_target, _)| quality >= u32::from(quality_target.get_target(max_quality)))
+        .map(|(_, reward)| reward)
+        .next_back()
+        .unwrap_or(0)
+}
+
+/// Scrips per hour when crafting the collectable over and over with the macro
+pub(super) fn scrips_per_hour(reward: u16, actions: &[Action]) -> u32 {
+    u32::from(reward) * 3600 / (macro_duration_seconds(actions) + CRAFT_OVERHEAD_SECONDS)
+}
+
+/// Window that lists the rotations for each collectable tier of the selected recipe
+#[derive(Debug, Default)]
+pub struct TierResultsWindow {
+    pub open: bool,
+    results: Vec<(QualityTarget, Option<Vec<Action>>)>,
+}
+
+impl TierResultsWindow {
+    pub fn show(&mut self) {
+        self.open = true;
+        self.results.clear();
+    }
+
+    pub fn push_result(&mut self, quality_target: QualityTarget, actions: Option<Vec<Action>>) {
+        self.results.push((quality_target, actions));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// Results for the webhook notification of the finished solve
+    pub fn summary_lines(&self) -> Vec<String> {
+        self.results
+            .iter()
+            .map(|(quality_target, actions)| {
+                actions.as_ref().map_or_else(
+                    || format!("{quality_target}: no solution"),
+                    |actions| format!("{quality_target}: {} steps", actions.len()),
+                )
+            })
+            .collect()
+    }
+
+    /// Draws the window, returns the tier and rotation that were picked to be used
+    pub fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        game_settings: &Settings,
+        initial_quality: u16,
+        solver_config: &SolverConfig,
+        scrip_rewards: &mut [u16; 3],
+    ) -> Option<(QualityTarget, Vec<Action>)> {
+        ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+        ui.label(egui::RichText::new(t("Collectable tiers")).strong());
+        ui.separator();
+        ui.horizontal(|ui| {
+            ui.label(t("Scrip rewards"));
+            ui.add(HelpText::new("Scrips rewarded for turning in the collectable at each tier, as listed by the collectables appraiser.\nUsed to estimate the scrips per hour of each rotation, which is rewarded for the highest tier its Quality reaches, so a max Quality rotation gets the reward of the third tier."));
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                for reward in scrip_rewards.iter_mut().rev() {
+                    ui.add(egui::DragValue::new(reward));
+                }
+            });
+        });
+        let scrip_rewards = *scrip_rewards;
+        let scrips_per_hour = |actions: &[Action]| {
+            let (state, _) = solver_config.simulate(game_settings, actions);
+            let quality = u32::from(initial_quality) + state.quality;
+            let reward = scrip_reward(scrip_rewards, quality, game_settings.max_quality);
+            scrips_per_hour(reward, actions)
+        };
+        let best_scrips_per_hour = self
+            .results
+            .iter()
+            .filter_map(|(_, actions)| Some(scrips_per_hour(actions.as_ref()?)))
+            .max()
+            .filter(|scrips_per_hour| *scrips_per_hour != 0);
+        let mut picked_result = None;
+        egui::Grid::new("tier_results_grid")
+            .striped(true)
+            .num_columns(6)
+            .show(ui, |ui| {
+                ui.label(egui::RichText::new(t("Target")).strong());
+                ui.label(egui::RichText::new(t("Steps")).strong());
+                ui.label(egui::RichText::new(t("Duration")).strong());
+                ui.label(egui::RichText::new(t("CP")).strong());
+                ui.label(egui::RichText::new(t("Scrips/h")).strong());
+                ui.end_row();
+                for (index, (quality_target, actions)) in self.results.iter().enumerate() {
+                    ui.label(format!("{}", quality_target));
+                    match actions {
+                        Some(actions) => {
+                            let initial_state = solver_config.initial_state(game_settings);
+                            let (state, _) = solver_config.simulate(game_settings, actions);
+                            ui.label(format!("{}", actions.len()));
+                            ui.label(format!("{} seconds", macro_duration_seconds(actions)));
+                            ui.label(format!("{}", initial_state.cp - state.cp));
+                            let scrips_per_hour = scrips_per_hour(actions);
+                            if best_scrips_per_hour == Some(scrips_per_hour) {
+                                ui.label(
+                                    egui::RichText::new(format!("★ {}", scrips_per_hour)).strong(),
+                                )
+                                .on_hover_text("Most scrips per hour");
+                            } else {
+                                ui.label(format!("{}", scrips_per_hour));
+                            }
+                            if ui.button(t("Use")).clicked() {
+                                picked_result = Some(index);
+                            }
+                        }
+                        None => {
+                            ui.label(t("No solution"));
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+        if self.results.len() < 4 {
+            ui.label(
+                egui::RichText::new("⚠ Not all tiers were solved.")
+                    .small()
+                    .color(ui.visuals().warn_fg_color),
+            );
+        }
+        ui.separator();
+        ui.vertical_centered_justified(|ui| {
+            if ui.button(t("Close")).clicked() {
+                self.open = false;
+            }
+        });
+
+        let index = picked_result?;
+        let (quality_target, actions) = self.results[index].clone();
+        self.open = false;
+        Some((quality_target, actions.unwrap_or_default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::scrip_reward;
+
+    #[test]
+    fn scrip_reward_of_reached_tier() {
+        let scrip_rewards = [100, 120, 150];
+        assert_eq!(scrip_reward(scrip_rewards, 5499, 10000), 0);
+        assert_eq!(scrip_reward(scrip_rewards, 5500, 10000), 100);
+        assert_eq!(scrip_reward(scrip_rewards, 9000, 10000), 120);
+        assert_eq!(scrip_reward(scrip_rewards, 9500, 10000), 150);
+        // max Quality is rewarded for the highest tier
+        assert_eq!(scrip_reward(scrip_rewards, 10000, 10000), 150);
+    }
+}
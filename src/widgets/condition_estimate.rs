@@ -0,0 +1,129 @@
+use raphael_sim::{Action, ConditionProbabilities, Settings};
+
+use crate::{
+    app::SolverConfig,
+    config::{CustomRecipeOverridesConfiguration, RecipeConfiguration},
+    util,
+};
+
+use super::HelpText;
+
+/// Number of crafts simulated for the estimate
+pub(super) const ESTIMATE_CRAFTS: u32 = 10_000;
+
+/// Estimates how often the macro completes and reaches the quality target when the conditions are rolled at random
+pub struct ConditionEstimate<'a> {
+    settings: &'a Settings,
+    initial_quality: u16,
+    solver_config: &'a SolverConfig,
+    actions: &'a [Action],
+    recipe_config: &'a RecipeConfiguration,
+    custom_recipe_overrides_config: &'a CustomRecipeOverridesConfiguration,
+}
+
+impl<'a> ConditionEstimate<'a> {
+    pub fn new(
+        settings: &'a Settings,
+        initial_quality: u16,
+        solver_config: &'a SolverConfig,
+        actions: &'a [Action],
+        recipe_config: &'a RecipeConfiguration,
+        custom_recipe_overrides_config: &'a CustomRecipeOverridesConfiguration,
+    ) -> Self {
+        Self {
+            settings,
+            initial_quality,
+            solver_config,
+            actions,
+            recipe_config,
+            custom_recipe_overrides_config,
+        }
+    }
+
+    fn chances_source(&self) -> &'static str {
+        match (
+            self.custom_recipe_overrides_config.use_custom_recipe
+                && self
+                    .custom_recipe_overrides_config
+                    .custom_recipe_overrides
+                    .condition_probabilities_override
+                    .is_some(),
+            self.recipe_config.recipe.is_expert,
+        ) {
+            (true, _) => "The chances are overridden in the custom recipe settings.",
+            (false, true) => "The chances are the same approximate values for every expert recipe.",
+            (false, false) => {
+                "The chances are the same approximate values for every regular recipe."
+            }
+        }
+    }
+}
+
+/// Chances of the conditions that can roll, e.g. "Good: 25%, Excellent: 4%, the step after Excellent is always Poor."
+fn chances_text(probabilities: ConditionProbabilities) -> String {
+    let chances: Vec<_> = probabilities
+        .chances()
+        .iter()
+        .filter(|(_, chance)| *chance != 0)
+        .map(|(condition, chance)| format!("{condition:?}: {chance}%"))
+        .collect();
+    match (chances.is_empty(), probabilities.excellent != 0) {
+        (true, _) => "Every step is Normal.".to_owned(),
+        (false, true) => format!(
+            "{}, the step after Excellent is always Poor.",
+            chances.join(", ")
+        ),
+        (false, false) => format!("{}.", chances.join(", ")),
+    }
+}
+
+impl egui::Widget for ConditionEstimate<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let initial_state = self.solver_config.initial_state(self.settings);
+        let target_quality = self
+            .solver_config
+            .quality_target
+            .get_target(self.settings.max_quality)
+            .saturating_sub(self.initial_quality);
+        let probabilities = util::get_condition_probabilities(
+            self.recipe_config,
+            self.custom_recipe_overrides_config,
+        );
+        let id = egui::Id::new((
+            "CONDITION_ESTIMATE",
+            self.settings,
+            initial_state,
+            self.actions,
+            target_quality,
+            probabilities,
+        ));
+        // simulating thousands of crafts is too slow to do every frame, so the result is cached
+        let estimate = ui.data_mut(|data| {
+            *data.get_temp_mut_or_insert_with(id, || {
+                raphael_sim::estimate_macro(
+                    self.settings,
+                    initial_state,
+                    self.actions,
+                    u32::from(target_quality),
+                    probabilities,
+                    ESTIMATE_CRAFTS,
+                )
+            })
+        });
+        let percentage = |count: u32| f64::from(count) * 100.0 / f64::from(estimate.crafts);
+        ui.group(|ui| {
+            ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new("Random conditions").strong());
+                ui.add(HelpText::new(format!("Outcome of the macro over {} simulated crafts in which the condition of each step is rolled at random, starting with a Normal step.\n{} Actions that can't be used in a step are skipped.\n{} The game's per-recipe condition chances are not imported, so a recipe that rolls differently in the game needs a custom recipe with overridden chances.", ESTIMATE_CRAFTS, chances_text(probabilities), self.chances_source())));
+            });
+            ui.separator();
+            ui.label(format!("Completes: {:.1}%", percentage(estimate.completed)));
+            ui.label(format!(
+                "Reaches the quality target: {:.1}%",
+                percentage(estimate.reached_target)
+            ));
+        })
+        .response
+    }
+}
@@ -0,0 +1,278 @@
+use raphael_data::{Locale, Recipe, action_name};
+use raphael_sim::{Action, Settings};
+
+use super::get_action_icon;
+use crate::{
+    app::{BatchSolution, SolverConfig},
+    config::QualityTarget,
+    thread_pool,
+    translations::t,
+};
+
+/// Request from the configuration comparison window that needs the rest of the app to be carried out
+#[derive(Debug, Clone)]
+pub enum ConfigCompareRequest {
+    /// Solve the recipe with both configurations
+    Solve,
+    /// Use the configuration and its rotation
+    Use(SolverConfig, BatchSolution),
+}
+
+/// Window that solves the selected recipe with two solver configurations and shows the rotations side by side
+#[derive(Debug, Default)]
+pub struct ConfigCompareWindow {
+    pub open: bool,
+    configs: [SolverConfig; 2],
+    results: Vec<(usize, Option<BatchSolution>)>,
+}
+
+impl ConfigCompareWindow {
+    pub fn show(&mut self, solver_config: SolverConfig) {
+        self.open = true;
+        self.configs = [solver_config; 2];
+        // the most common comparison, which is also a single click away from any other
+        self.configs[1].backload_progress = !solver_config.backload_progress;
+        self.results.clear();
+    }
+
+    pub fn configs(&self) -> &[SolverConfig; 2] {
+        &self.configs
+    }
+
+    pub fn clear_results(&mut self) {
+        self.results.clear();
+    }
+
+    pub fn push_result(&mut self, index: usize, solution: Option<BatchSolution>) {
+        self.results.push((index, solution));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// Results for the webhook notification of the finished comparison
+    pub fn summary_lines(&self) -> Vec<String> {
+        self.results
+            .iter()
+            .map(|(index, solution)| {
+                let name = ["A", "B"][*index];
+                solution.as_ref().map_or_else(
+                    || format!("Configuration {name}: no solution"),
+                    |solution| {
+                        format!(
+                            "Configuration {name}: {} quality, {} steps",
+                            solution.quality,
+                            solution.actions.len()
+                        )
+                    },
+                )
+            })
+            .collect()
+    }
+
+    pub fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        recipe: &Recipe,
+        selected_quality_target: QualityTarget,
+        game_settings: &Settings,
+        job_id: u8,
+        locale: Locale,
+    ) -> Option<ConfigCompareRequest> {
+        let mut request = None;
+        ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+        ui.set_width(560.0);
+        ui.label(egui::RichText::new("Compare solver configurations").strong());
+        ui.separator();
+        for solver_config in &mut self.configs {
+            if recipe.is_expert {
+                solver_config.adversarial = false;
+            }
+            solver_config.restrict_opener_conditions(*recipe);
+        }
+        let previous_configs = self.configs;
+        let mut quality_targets = vec![
+            QualityTarget::Zero,
+            QualityTarget::CollectableT1,
+            QualityTarget::CollectableT2,
+            QualityTarget::CollectableT3,
+            QualityTarget::Full,
+        ];
+        if let QualityTarget::Custom(_) = selected_quality_target {
+            quality_targets.push(selected_quality_target);
+        }
+        let is_expert = recipe.is_expert;
+        let trained_eye_text = format!("Don't use {}", action_name(Action::TrainedEye, locale));
+        egui::Grid::new("compare_configs_grid")
+            .striped(true)
+            .num_columns(3)
+            .min_col_width(160.0)
+            .show(ui, |ui| {
+                ui.label("");
+                ui.label(egui::RichText::new("A").strong());
+                ui.label(egui::RichText::new("B").strong());
+                ui.end_row();
+                ui.label(t("Target quality"));
+                for (index, solver_config) in self.configs.iter_mut().enumerate() {
+                    egui::ComboBox::from_id_salt(("COMPARE_TARGET_QUALITY", index))
+                        .selected_text(format!("{}", solver_config.quality_target))
+                        .show_ui(ui, |ui| {
+                            for quality_target in &quality_targets {
+                                ui.selectable_value(
+                                    &mut solver_config.quality_target,
+                                    *quality_target,
+                                    format!("{}", quality_target),
+                                );
+                            }
+                        });
+                }
+                ui.end_row();
+                ui.label("Backload progress");
+                for solver_config in &mut self.configs {
+                    ui.horizontal(|ui| {
+                        let mut backload_progress = (
+                            solver_config.backload_progress,
+                            solver_config.prefer_backload_progress
+                                && !solver_config.backload_progress,
+                        );
+                        ui.selectable_value(&mut backload_progress, (false, false), "Off");
+                        ui.selectable_value(&mut backload_progress, (false, true), "Prefer");
+                        ui.selectable_value(&mut backload_progress, (true, false), "Strict");
+                        (
+                            solver_config.backload_progress,
+                            solver_config.prefer_backload_progress,
+                        ) = backload_progress;
+                    });
+                }
+                ui.end_row();
+                ui.label("Ensure 100% reliability");
+                for solver_config in &mut self.configs {
+                    ui.add_enabled(
+                        !is_expert,
+                        egui::Checkbox::without_text(&mut solver_config.adversarial),
+                    );
+                }
+                ui.end_row();
+                ui.label(&trained_eye_text);
+                for solver_config in &mut self.configs {
+                    ui.checkbox(&mut solver_config.forbid_trained_eye, "");
+                }
+                ui.end_row();
+            });
+        if self.configs != previous_configs {
+            self.results.clear();
+        }
+
+        let solutions: [Option<Option<&BatchSolution>>; 2] = std::array::from_fn(|index| {
+            self.results
+                .iter()
+                .find(|(result_index, _)| *result_index == index)
+                .map(|(_, solution)| solution.as_ref())
+        });
+        if !self.results.is_empty() {
+            ui.separator();
+            egui::Grid::new("compare_results_grid")
+                .striped(true)
+                .num_columns(3)
+                .min_col_width(160.0)
+                .show(ui, |ui| {
+                    // max CP doesn't depend on the solver configuration
+                    // Quality, steps, duration and CP of each rotation
+                    let metrics: [_; 2] = std::array::from_fn(|index| {
+                        let solver_config = &self.configs[index];
+                        solutions[index].map(|solution| {
+                            solution.map(|solution| {
+                                let initial_state = solver_config.initial_state(game_settings);
+                                let (state, _) =
+                                    solver_config.simulate(game_settings, &solution.actions);
+                                let duration = solution
+                                    .actions
+                                    .iter()
+                                    .map(|action| action.time_cost())
+                                    .sum::<u8>();
+                                [
+                                    solution.quality.to_string(),
+                                    solution.actions.len().to_string(),
+                                    format!("{} seconds", duration),
+                                    (initial_state.cp - state.cp).to_string(),
+                                ]
+                            })
+                        })
+                    });
+                    for (row, name) in [t("Quality"), t("Steps"), t("Duration"), t("CP")]
+                        .into_iter()
+                        .enumerate()
+                    {
+                        ui.label(egui::RichText::new(name).strong());
+                        for metrics in &metrics {
+                            match metrics {
+                                Some(Some(metrics)) => {
+                                    ui.label(&metrics[row]);
+                                }
+                                Some(None) => {
+                                    ui.label(t("No solution"));
+                                }
+                                None => {
+                                    ui.label("");
+                                }
+                            }
+                        }
+                        ui.end_row();
+                    }
+                    ui.label("");
+                    for (index, solution) in solutions.iter().enumerate() {
+                        if let Some(Some(solution)) = solution {
+                            if ui.button(t("Use")).clicked() {
+                                request = Some(ConfigCompareRequest::Use(
+                                    self.configs[index],
+                                    (*solution).clone(),
+                                ));
+                            }
+                        } else {
+                            ui.label("");
+                        }
+                    }
+                    ui.end_row();
+                });
+            ui.columns(2, |columns| {
+                for (ui, solution) in columns.iter_mut().zip(solutions) {
+                    ui.horizontal_wrapped(|ui| {
+                        ui.spacing_mut().item_spacing = egui::vec2(3.0, 3.0);
+                        for action in solution
+                            .flatten()
+                            .map_or(&[][..], |solution| &solution.actions)
+                        {
+                            ui.add(
+                                get_action_icon(*action, job_id)
+                                    .fit_to_exact_size(egui::vec2(24.0, 24.0))
+                                    .corner_radius(3.0),
+                            )
+                            .on_hover_text(action_name(*action, locale));
+                        }
+                    });
+                }
+            });
+            if self.results.len() < self.configs.len() {
+                ui.label(
+                    egui::RichText::new("⚠ Not all configurations were solved.")
+                        .small()
+                        .color(ui.visuals().warn_fg_color),
+                );
+            }
+        }
+        ui.separator();
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(
+                    thread_pool::is_initialized(),
+                    egui::Button::new("Solve both"),
+                )
+                .clicked()
+            {
+                request = Some(ConfigCompareRequest::Solve);
+            }
+            if ui.button(t("Close")).clicked() {
+                self.open = false;
+            }
+        });
+        request
+    }
+}
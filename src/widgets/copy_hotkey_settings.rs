@@ -0,0 +1,49 @@
+use crate::copy_hotkey::{CopyHotkey, CopyHotkeyError};
+use crate::translations::t;
+
+use super::HelpText;
+
+/// Row of the settings menu for the global hotkey that copies the next macro
+pub struct CopyHotkeySettings<'a> {
+    copy_hotkey: &'a mut CopyHotkey,
+}
+
+impl<'a> CopyHotkeySettings<'a> {
+    pub fn new(copy_hotkey: &'a mut CopyHotkey) -> Self {
+        Self { copy_hotkey }
+    }
+}
+
+impl egui::Widget for CopyHotkeySettings<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        ui.vertical(|ui| {
+            ui.horizontal(|ui| {
+                ui.label(t("Global copy hotkey"));
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut self.copy_hotkey.text)
+                        .hint_text("ctrl+shift+KeyC")
+                        .desired_width(120.0),
+                );
+                if response.lost_focus() {
+                    self.copy_hotkey.register();
+                }
+                ui.add(HelpText::new(t("Copies the next macro, even while the game is focused, so that long rotations can be pasted without switching windows.\nModifiers and the key are joined with \"+\", keys are named as on a US keyboard, e.g. \"alt+Digit1\" or \"ctrl+shift+F5\". Leave empty to disable.")));
+            });
+            if let Some(error) = &self.copy_hotkey.error {
+                let text = match error {
+                    CopyHotkeyError::Unsupported => {
+                        t("Global hotkeys are not supported on this system").to_owned()
+                    }
+                    CopyHotkeyError::Invalid(details) => {
+                        format!("{}: {details}", t("Invalid hotkey"))
+                    }
+                    CopyHotkeyError::Unavailable(details) => {
+                        format!("{}: {details}", t("Unable to register hotkey"))
+                    }
+                };
+                ui.label(egui::RichText::new(format!("⚠ {text}")).small().color(ui.visuals().warn_fg_color));
+            }
+        })
+        .response
+    }
+}
@@ -0,0 +1,99 @@
+use raphael_sim::{Action, Settings};
+
+use crate::{
+    app::{CP_TRADEOFF_REDUCTIONS, SolverConfig},
+    translations::t,
+};
+
+/// Window that shows the best achievable Quality with less CP than the crafter has
+#[derive(Debug, Default)]
+pub struct CpTradeoffWindow {
+    pub open: bool,
+    /// Best Quality, excluding the initial Quality, for each solved CP reduction
+    results: Vec<(u16, Option<u32>)>,
+}
+
+impl CpTradeoffWindow {
+    pub fn show(&mut self) {
+        self.open = true;
+        self.results.clear();
+    }
+
+    pub fn push_result(&mut self, cp_reduction: u16, quality: Option<u32>) {
+        self.results.push((cp_reduction, quality));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// Results for the webhook notification of the finished solve
+    pub fn summary_lines(&self) -> Vec<String> {
+        self.results
+            .iter()
+            .map(|(cp_reduction, quality)| {
+                quality.map_or_else(
+                    || format!("-{cp_reduction} CP: no solution"),
+                    |quality| format!("-{cp_reduction} CP: {quality} quality"),
+                )
+            })
+            .collect()
+    }
+
+    /// Draws the window, the first row is the Quality of the current rotation
+    pub fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        game_settings: &Settings,
+        initial_quality: u16,
+        solver_config: &SolverConfig,
+        actions: &[Action],
+    ) {
+        ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+        ui.set_width(300.0);
+        ui.label(egui::RichText::new(t("Quality vs. CP")).strong());
+        ui.separator();
+        let target_quality = u32::from(initial_quality) + u32::from(game_settings.max_quality);
+        let (state, _) = solver_config.simulate(game_settings, actions);
+        let current_quality =
+            std::cmp::min(u32::from(initial_quality) + state.quality, target_quality);
+        let rows = std::iter::once((0, Some(current_quality))).chain(self.results.iter().map(
+            |(cp_reduction, quality)| {
+                (
+                    *cp_reduction,
+                    quality.map(|quality| u32::from(initial_quality) + quality),
+                )
+            },
+        ));
+        for (cp_reduction, quality) in rows {
+            ui.horizontal(|ui| {
+                ui.add_sized(
+                    egui::vec2(50.0, 0.0),
+                    egui::Label::new(format!("−{} CP", cp_reduction)),
+                );
+                match quality {
+                    Some(quality) => {
+                        let fraction = quality as f32 / std::cmp::max(target_quality, 1) as f32;
+                        ui.add(
+                            egui::ProgressBar::new(fraction)
+                                .text(format!("{} / {}", quality, target_quality)),
+                        );
+                    }
+                    None => {
+                        ui.label(t("No solution"));
+                    }
+                }
+            });
+        }
+        if self.results.len() < CP_TRADEOFF_REDUCTIONS.len() {
+            ui.label(
+                egui::RichText::new("⚠ Not all CP amounts were solved.")
+                    .small()
+                    .color(ui.visuals().warn_fg_color),
+            );
+        }
+        ui.separator();
+        ui.vertical_centered_justified(|ui| {
+            if ui.button(t("Close")).clicked() {
+                self.open = false;
+            }
+        });
+    }
+}
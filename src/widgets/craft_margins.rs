@@ -0,0 +1,33 @@
+use crate::app::SolverConfig;
+
+use super::HelpText;
+
+/// Rows of the solver settings that leave room for mistakes or for a craft that doesn't start fresh
+pub struct CraftMarginsEdit<'a> {
+    solver_config: &'a mut SolverConfig,
+}
+
+impl<'a> CraftMarginsEdit<'a> {
+    pub fn new(solver_config: &'a mut SolverConfig) -> Self {
+        Self { solver_config }
+    }
+}
+
+impl egui::Widget for CraftMarginsEdit<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        ui.vertical(|ui| {
+            ui.horizontal(|ui| {
+                ui.label("Durability margin");
+                ui.add(egui::DragValue::new(&mut self.solver_config.min_synthesis_durability).range(0..=80));
+                ui.add(HelpText::new("Only use Progress-increasing actions while Durability is at least this high, so that the rotation doesn't rely on finishing at exactly 0 or 5 Durability.\nMakes manually executed rotations more forgiving, e.g. 11 leaves room for one wasted 10 Durability action.\n  - May decrease achievable Quality.\n  - May increase macro duration."));
+            });
+            ui.horizontal(|ui| {
+                ui.label("Starting penalty");
+                ui.add(egui::DragValue::new(&mut self.solver_config.starting_cp_penalty).range(0..=999).prefix("CP −"));
+                ui.add(egui::DragValue::new(&mut self.solver_config.starting_durability_penalty).range(0..=75).speed(0.2).prefix("Durability −"));
+                ui.add(HelpText::new("CP and Durability missing at the start of the craft, e.g. when starting the next craft before CP has regenerated.\nThe solver and the simulator start from the reduced values, while the max CP and Durability stay the same.\nHas no effect when solving from a mid-craft state."));
+            });
+        })
+        .response
+    }
+}
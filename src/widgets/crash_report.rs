@@ -0,0 +1,37 @@
+use std::path::Path;
+
+use crate::translations::t;
+
+/// Points the user to a crash report, which is never uploaded by the app itself
+pub fn crash_report_label(ui: &mut egui::Ui, path: &Path) {
+    ui.label("A crash report was saved to:");
+    ui.horizontal(|ui| {
+        ui.label(
+            egui::RichText::new(path.display().to_string())
+                .small()
+                .monospace(),
+        );
+        if ui.button("Copy path").clicked() {
+            ui.ctx().copy_text(path.display().to_string());
+        }
+    });
+    ui.add(
+        egui::Hyperlink::from_label_and_url(
+            "Attach it to an issue on GitHub",
+            "https://github.com/Asvel/ffxiv-raphael-cn/issues",
+        )
+        .open_in_new_tab(true),
+    );
+}
+
+/// Draws the notice about a crash during an earlier session, returns `true` if it was closed
+pub fn crash_report_notice(ui: &mut egui::Ui, path: &Path) -> bool {
+    ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+    ui.label(egui::RichText::new(t("Error")).strong());
+    ui.separator();
+    ui.label("Raphael crashed the last time it was used.");
+    crash_report_label(ui, path);
+    ui.separator();
+    ui.vertical_centered_justified(|ui| ui.button(t("Close")).clicked())
+        .inner
+}
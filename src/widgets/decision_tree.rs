@@ -0,0 +1,180 @@
+use raphael_data::{Locale, action_name};
+use raphael_sim::{Action, Condition};
+use raphael_solver::{DecisionTree, StepBranch};
+
+use crate::{app::DECISION_TREE_DEPTH, translations::t};
+
+/// Window that shows which actions to use depending on the condition of each step, as a single macro can't react to the conditions
+#[derive(Debug, Default)]
+pub struct DecisionTreeWindow {
+    pub open: bool,
+    tree: Option<DecisionTree>,
+    /// Initial Quality and max Quality of the recipe the tree was solved for
+    initial_quality: u16,
+    max_quality: u16,
+}
+
+fn conditions_text(conditions: &[Condition]) -> String {
+    conditions
+        .iter()
+        .map(|condition| format!("{:?}", condition))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn actions_text(actions: &[Action], locale: Locale) -> String {
+    actions
+        .iter()
+        .map(|action| action_name(*action, locale))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Label of a branch among the branches of its step, the default branch is only taken "otherwise" if there are other branches
+fn branch_label(branches: &[StepBranch], branch: &StepBranch) -> String {
+    if branch.is_default && branches.len() > 1 {
+        "otherwise".to_owned()
+    } else {
+        format!("on {}", conditions_text(&branch.conditions))
+    }
+}
+
+impl DecisionTreeWindow {
+    pub fn show(&mut self, initial_quality: u16, max_quality: u16) {
+        self.open = true;
+        self.tree = None;
+        self.initial_quality = initial_quality;
+        self.max_quality = max_quality;
+    }
+
+    pub fn set_tree(&mut self, tree: DecisionTree) {
+        self.tree = Some(tree);
+    }
+
+    fn quality(&self, branch: &StepBranch) -> u32 {
+        std::cmp::min(
+            u32::from(self.initial_quality) + branch.quality,
+            u32::from(self.max_quality),
+        )
+    }
+
+    /// Line of a branch, which lists the rest of the rotation if the tree doesn't branch any further after it
+    fn branch_text(
+        &self,
+        branches: &[StepBranch],
+        branch: &StepBranch,
+        step: usize,
+        locale: Locale,
+    ) -> String {
+        let label = branch_label(branches, branch);
+        if branch.next_steps.is_empty() {
+            format!(
+                "Step {step} {label}: {} (Quality {}/{})",
+                actions_text(&branch.actions, locale),
+                self.quality(branch),
+                self.max_quality
+            )
+        } else {
+            format!(
+                "Step {step} {label}: {}",
+                action_name(branch.actions[0], locale)
+            )
+        }
+    }
+
+    fn push_branch_lines(
+        &self,
+        lines: &mut Vec<String>,
+        branches: &[StepBranch],
+        step: usize,
+        locale: Locale,
+    ) {
+        for branch in branches {
+            let indent = "  ".repeat(step - 1);
+            lines.push(indent + &self.branch_text(branches, branch, step, locale));
+            self.push_branch_lines(lines, &branch.next_steps, step + 1, locale);
+        }
+    }
+
+    /// The tree as text, with a line per branch indented below the branch of the step before it
+    fn text_lines(&self, locale: Locale) -> Vec<String> {
+        let Some(tree) = &self.tree else {
+            return Vec::new();
+        };
+        let first_step = &tree.first_step;
+        let mut lines = vec![format!(
+            "Step 1 on {:?}: {}",
+            first_step.condition,
+            action_name(first_step.action, locale)
+        )];
+        self.push_branch_lines(&mut lines, &first_step.next_steps, 2, locale);
+        lines
+    }
+
+    fn branches_ui(&self, ui: &mut egui::Ui, branches: &[StepBranch], step: usize, locale: Locale) {
+        for branch in branches {
+            ui.add(egui::Label::new(self.branch_text(branches, branch, step, locale)).wrap());
+            if !branch.next_steps.is_empty() {
+                ui.indent(&branch.conditions, |ui| {
+                    self.branches_ui(ui, &branch.next_steps, step + 1, locale);
+                });
+            }
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// Results for the webhook notification of the finished solve
+    pub fn summary_lines(&self, locale: Locale) -> Vec<String> {
+        self.text_lines(locale)
+    }
+
+    pub fn ui(&mut self, ui: &mut egui::Ui, locale: Locale) {
+        ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+        ui.set_max_width(600.0);
+        ui.label(egui::RichText::new(t("Decision tree")).strong());
+        ui.label(
+            egui::RichText::new(format!("Actions to use depending on the condition of each step up to step {}. All later steps are assumed to be Normal.", DECISION_TREE_DEPTH + 1))
+                .small(),
+        );
+        ui.separator();
+        match &self.tree {
+            Some(tree) => {
+                egui::ScrollArea::vertical()
+                    .max_height(400.0)
+                    .show(ui, |ui| {
+                        let first_step = &tree.first_step;
+                        ui.label(
+                            egui::RichText::new(format!(
+                                "Step 1 on {:?}: {}",
+                                first_step.condition,
+                                action_name(first_step.action, locale)
+                            ))
+                            .strong(),
+                        );
+                        ui.indent("decision_tree_second_step", |ui| {
+                            self.branches_ui(ui, &first_step.next_steps, 2, locale);
+                        });
+                    });
+            }
+            None => {
+                ui.label(
+                    egui::RichText::new("⚠ The decision tree wasn't solved.")
+                        .small()
+                        .color(ui.visuals().warn_fg_color),
+                );
+            }
+        }
+        ui.separator();
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(self.tree.is_some(), egui::Button::new(t("Copy as text")))
+                .clicked()
+            {
+                ui.ctx().copy_text(self.text_lines(locale).join("\n"));
+            }
+            if ui.button(t("Close")).clicked() {
+                self.open = false;
+            }
+        });
+    }
+}
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use egui::{
     Align, Id, Layout, Widget,
     util::cache::{ComputerMut, FrameCache},
@@ -10,13 +12,13 @@ use super::{ItemNameLabel, util};
 #[derive(Default)]
 struct FoodFinder {}
 
-impl ComputerMut<(&str, Locale), Vec<usize>> for FoodFinder {
-    fn compute(&mut self, (text, locale): (&str, Locale)) -> Vec<usize> {
-        find_meals(text, locale)
+impl ComputerMut<(&str, Locale), Arc<[usize]>> for FoodFinder {
+    fn compute(&mut self, (text, locale): (&str, Locale)) -> Arc<[usize]> {
+        find_meals(text, locale).into()
     }
 }
 
-type FoodSearchCache<'a> = FrameCache<Vec<usize>, FoodFinder>;
+type FoodSearchCache<'a> = FrameCache<Arc<[usize]>, FoodFinder>;
 
 pub struct FoodSelect<'a> {
     crafter_stats: CrafterStats,
@@ -92,7 +94,7 @@ impl Widget for FoodSelect<'_> {
                 }
                 ui.separator();
 
-                let mut search_result = Vec::new();
+                let mut search_result = Arc::default();
                 ui.ctx().memory_mut(|mem| {
                     let search_cache = mem.caches.cache::<FoodSearchCache<'_>>();
                     search_result = search_cache.get((&search_text, self.locale));
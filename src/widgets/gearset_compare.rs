@@ -0,0 +1,227 @@
+use raphael_data::CrafterStats;
+
+use crate::{config::Gearset, font_fallback, translations::t};
+
+/// Outcome of evaluating a gearset for the selected recipe
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GearsetResult {
+    BelowRequirement,
+    NoSolution,
+    Solved { quality: u32, steps: usize },
+}
+
+/// Request from the gearset window that needs the rest of the app to be carried out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GearsetRequest {
+    /// Use the stats of the gearset with the given index
+    Use(usize),
+    /// Evaluate all gearsets for the selected recipe
+    Evaluate,
+}
+
+/// Window that compares gearsets imported from a gear planner for the selected recipe
+#[derive(Debug, Default)]
+pub struct GearsetWindow {
+    pub open: bool,
+    import_text: String,
+    import_error: Option<String>,
+    results: Vec<(usize, GearsetResult)>,
+}
+
+impl GearsetWindow {
+    pub fn show(&mut self) {
+        self.open = true;
+        self.results.clear();
+        self.import_error = None;
+    }
+
+    pub fn clear_results(&mut self) {
+        self.results.clear();
+    }
+
+    pub fn push_result(&mut self, index: usize, result: GearsetResult) {
+        self.results.push((index, result));
+    }
+
+    /// Gearset results from best to worst: highest quality first, then fewest steps
+    pub fn ranked_results(&self) -> Vec<(usize, GearsetResult)> {
+        let mut results = self.results.clone();
+        results.sort_by_key(|(index, result)| match result {
+            GearsetResult::Solved { quality, steps } => {
+                (0, std::cmp::Reverse(*quality), *steps, *index)
+            }
+            GearsetResult::NoSolution => (1, std::cmp::Reverse(0), 0, *index),
+            GearsetResult::BelowRequirement => (2, std::cmp::Reverse(0), 0, *index),
+        });
+        results
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// Results for the webhook notification of the finished evaluation
+    pub fn summary_lines(&self, gearsets: &[Gearset]) -> Vec<String> {
+        self.ranked_results()
+            .into_iter()
+            .map(|(index, result)| {
+                let name = &gearsets[index].name;
+                match result {
+                    GearsetResult::Solved { quality, steps } => {
+                        format!("{name}: {quality} quality, {steps} steps")
+                    }
+                    GearsetResult::NoSolution => format!("{name}: no solution"),
+                    GearsetResult::BelowRequirement => format!("{name}: below recipe requirement"),
+                }
+            })
+            .collect()
+    }
+
+    pub fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        gearsets: &mut Vec<Gearset>,
+        current_stats: &CrafterStats,
+        can_evaluate: bool,
+    ) -> Option<GearsetRequest> {
+        let mut request = None;
+        ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+        ui.set_width(460.0);
+        ui.label(egui::RichText::new("Compare gearsets").strong());
+        ui.separator();
+        if gearsets.is_empty() {
+            ui.label("No gearsets");
+        }
+        let rows: Vec<(usize, Option<GearsetResult>)> = if self.results.is_empty() {
+            (0..gearsets.len()).map(|index| (index, None)).collect()
+        } else {
+            self.ranked_results()
+                .into_iter()
+                .map(|(index, result)| (index, Some(result)))
+                .collect()
+        };
+        let mut removed_gearset = None;
+        egui::Grid::new("gearsets_grid")
+            .striped(true)
+            .num_columns(7)
+            .show(ui, |ui| {
+                ui.label(egui::RichText::new("Name").strong());
+                ui.label(egui::RichText::new(t("Craftsmanship")).strong());
+                ui.label(egui::RichText::new(t("Control")).strong());
+                ui.label(egui::RichText::new(t("CP")).strong());
+                ui.label(egui::RichText::new(t("Quality")).strong());
+                ui.label(egui::RichText::new(t("Steps")).strong());
+                ui.label("");
+                ui.end_row();
+                for (index, result) in rows {
+                    let gearset = &gearsets[index];
+                    font_fallback::request_glyphs(ui.ctx(), &gearset.name);
+                    ui.label(&gearset.name);
+                    ui.label(gearset.craftsmanship.to_string());
+                    ui.label(gearset.control.to_string());
+                    ui.label(gearset.cp.to_string());
+                    match result {
+                        Some(GearsetResult::Solved { quality, steps }) => {
+                            ui.label(quality.to_string());
+                            ui.label(steps.to_string());
+                        }
+                        Some(GearsetResult::NoSolution) => {
+                            ui.label(t("No solution"));
+                            ui.label("");
+                        }
+                        Some(GearsetResult::BelowRequirement) => {
+                            ui.label(
+                                egui::RichText::new("Below recipe requirement")
+                                    .color(ui.visuals().warn_fg_color),
+                            );
+                            ui.label("");
+                        }
+                        None => {
+                            ui.label("");
+                            ui.label("");
+                        }
+                    }
+                    ui.horizontal(|ui| {
+                        if ui.button(t("Use")).clicked() {
+                            request = Some(GearsetRequest::Use(index));
+                        }
+                        if ui.button("🗑").clicked() {
+                            removed_gearset = Some(index);
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+        if let Some(index) = removed_gearset {
+            gearsets.remove(index);
+            self.results.clear();
+        }
+        if self.results.len() < gearsets.len() && !self.results.is_empty() {
+            ui.label(
+                egui::RichText::new("⚠ Not all gearsets were evaluated.")
+                    .small()
+                    .color(ui.visuals().warn_fg_color),
+            );
+        }
+        ui.separator();
+        ui.label("Paste one gearset per line, e.g. the stats from xivgear as \"Name, Craftsmanship, Control, CP\".");
+        ui.add(
+            egui::TextEdit::multiline(&mut self.import_text)
+                .hint_text("Crafting BiS 5408/5138/664")
+                .desired_rows(3)
+                .desired_width(f32::INFINITY),
+        );
+        ui.horizontal(|ui| {
+            if ui.button("Import").clicked() {
+                let mut invalid_lines = Vec::new();
+                for (line_number, line) in self.import_text.lines().enumerate() {
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+                    match Gearset::from_line(line) {
+                        Some(mut gearset) => {
+                            if gearset.name.is_empty() {
+                                gearset.name = format!("Gearset {}", gearsets.len() + 1);
+                            }
+                            gearsets.push(gearset);
+                        }
+                        None => invalid_lines.push((line_number + 1).to_string()),
+                    }
+                }
+                self.results.clear();
+                self.import_error = match invalid_lines.is_empty() {
+                    true => None,
+                    false => Some(format!("Invalid lines: {}", invalid_lines.join(", "))),
+                };
+                if self.import_error.is_none() {
+                    self.import_text.clear();
+                }
+            }
+            if ui.button("Add current stats").clicked() {
+                gearsets.push(Gearset {
+                    name: format!("Gearset {}", gearsets.len() + 1),
+                    craftsmanship: current_stats.craftsmanship,
+                    control: current_stats.control,
+                    cp: current_stats.cp,
+                });
+                self.results.clear();
+            }
+            if let Some(error) = &self.import_error {
+                ui.label(egui::RichText::new(error).color(ui.visuals().error_fg_color));
+            }
+        });
+        ui.separator();
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(
+                    !gearsets.is_empty() && can_evaluate,
+                    egui::Button::new("Evaluate for the selected recipe"),
+                )
+                .clicked()
+            {
+                request = Some(GearsetRequest::Evaluate);
+            }
+            if ui.button(t("Close")).clicked() {
+                self.open = false;
+            }
+        });
+        request
+    }
+}
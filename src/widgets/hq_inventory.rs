@@ -0,0 +1,69 @@
+use crate::{config::HqInventory, translations::t};
+
+/// Window to import the HQ items of an inventory export, to which the HQ materials are limited
+#[derive(Debug, Default)]
+pub struct HqInventoryWindow {
+    pub open: bool,
+    text: String,
+    /// Outcome of the last import
+    status: Option<Result<String, String>>,
+}
+
+impl HqInventoryWindow {
+    pub fn show(&mut self) {
+        self.open = true;
+        self.status = None;
+    }
+
+    pub fn ui(&mut self, ui: &mut egui::Ui, hq_inventory: &mut Option<HqInventory>) {
+        ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+        ui.set_width(360.0);
+        ui.label(egui::RichText::new("HQ inventory").strong());
+        ui.separator();
+        ui.label("Paste an inventory export, e.g. a CSV from Allagan Tools or Teamcraft. It needs a header row with the item name, quantity and HQ columns.");
+        ui.add(
+            egui::TextEdit::multiline(&mut self.text)
+                .hint_text("Name,Quantity,HQ")
+                .desired_rows(4)
+                .desired_width(f32::INFINITY),
+        );
+        ui.horizontal(|ui| {
+            if ui.button("Import").clicked() {
+                match HqInventory::from_csv(&self.text) {
+                    Ok(inventory) => {
+                        self.status =
+                            Some(Ok(format!("Imported {} HQ items", inventory.item_count())));
+                        *hq_inventory = Some(inventory);
+                        self.text.clear();
+                    }
+                    Err(error) => self.status = Some(Err(error.to_owned())),
+                }
+            }
+            if ui
+                .add_enabled(
+                    hq_inventory.is_some(),
+                    egui::Button::new("Forget inventory"),
+                )
+                .clicked()
+            {
+                *hq_inventory = None;
+                self.status = Some(Ok("HQ materials are no longer limited".to_owned()));
+            }
+        });
+        match &self.status {
+            Some(Ok(message)) => {
+                ui.label(message);
+            }
+            Some(Err(error)) => {
+                ui.label(egui::RichText::new(error).color(ui.visuals().error_fg_color));
+            }
+            None => (),
+        }
+        ui.separator();
+        ui.vertical_centered_justified(|ui| {
+            if ui.button(t("Close")).clicked() {
+                self.open = false;
+            }
+        });
+    }
+}
@@ -0,0 +1,140 @@
+use raphael_sim::Action;
+
+use crate::{app::BatchSolution, translations::t};
+
+/// Window that compares the rotations for each number of HQ materials
+#[derive(Debug, Default)]
+pub struct HqSweepWindow {
+    pub open: bool,
+    /// Best HQ materials and the resulting initial Quality for each number of HQ materials
+    ingredients: Vec<([u8; 6], u16)>,
+    results: Vec<(usize, Option<BatchSolution>)>,
+}
+
+impl HqSweepWindow {
+    pub fn show(&mut self, ingredients: Vec<([u8; 6], u16)>) {
+        self.open = true;
+        self.ingredients = ingredients;
+        self.results.clear();
+    }
+
+    /// Initial Quality for each number of HQ materials, in the order in which they are solved
+    pub fn initial_qualities(&self) -> Vec<u16> {
+        self.ingredients
+            .iter()
+            .map(|(_, initial_quality)| *initial_quality)
+            .collect()
+    }
+
+    pub fn push_result(&mut self, hq_count: usize, solution: Option<BatchSolution>) {
+        self.results.push((hq_count, solution));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// Results for the webhook notification of the finished solve
+    pub fn summary_lines(&self) -> Vec<String> {
+        self.results
+            .iter()
+            .map(|(hq_count, solution)| {
+                solution.as_ref().map_or_else(
+                    || format!("{hq_count} HQ materials: no solution"),
+                    |solution| {
+                        format!(
+                            "{hq_count} HQ materials: {} quality, {} steps",
+                            solution.quality,
+                            solution.actions.len()
+                        )
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Draws the window, returns the HQ materials and rotation that were picked to be used
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> Option<([u8; 6], Vec<Action>)> {
+        ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+        ui.label(egui::RichText::new(t("HQ materials")).strong());
+        ui.separator();
+        // the fewest HQ materials after which more of them neither raise the Quality nor save steps
+        let outcome = |solution: &Option<BatchSolution>| {
+            solution
+                .as_ref()
+                .map(|solution| (solution.quality, solution.actions.len()))
+        };
+        let saturation_count = self.results.last().and_then(|(_, last_solution)| {
+            let last_outcome = outcome(last_solution)?;
+            self.results
+                .iter()
+                .rev()
+                .take_while(|(_, solution)| outcome(solution) == Some(last_outcome))
+                .last()
+                .map(|(hq_count, _)| *hq_count)
+        });
+        let mut picked_result = None;
+        egui::Grid::new("hq_sweep_grid")
+            .striped(true)
+            .num_columns(5)
+            .show(ui, |ui| {
+                ui.label(egui::RichText::new(t("HQ")).strong());
+                ui.label(egui::RichText::new(t("Initial quality")).strong());
+                ui.label(egui::RichText::new(t("Quality")).strong());
+                ui.label(egui::RichText::new(t("Steps")).strong());
+                ui.label("");
+                ui.end_row();
+                for (index, (hq_count, solution)) in self.results.iter().enumerate() {
+                    let (hq_ingredients, initial_quality) = self.ingredients[*hq_count];
+                    let hq_count_text = egui::RichText::new(format!("{}", hq_count));
+                    ui.label(match saturation_count == Some(*hq_count) {
+                        true => hq_count_text.strong(),
+                        false => hq_count_text,
+                    })
+                    .on_hover_text(format!("{:?}", hq_ingredients));
+                    ui.label(format!("{}", initial_quality));
+                    match solution {
+                        Some(solution) => {
+                            ui.label(format!("{}", solution.quality));
+                            ui.label(format!("{}", solution.actions.len()));
+                            if ui.button(t("Use")).clicked() {
+                                picked_result = Some(index);
+                            }
+                        }
+                        None => {
+                            ui.label(t("No solution"));
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+        if let Some(saturation_count) = saturation_count
+            && saturation_count + 1 < self.ingredients.len()
+        {
+            ui.label(format!(
+                "More than {} HQ materials make no difference.",
+                saturation_count
+            ));
+        }
+        if self.results.len() < self.ingredients.len() {
+            ui.label(
+                egui::RichText::new("⚠ Not all HQ material counts were solved.")
+                    .small()
+                    .color(ui.visuals().warn_fg_color),
+            );
+        }
+        ui.separator();
+        ui.vertical_centered_justified(|ui| {
+            if ui.button(t("Close")).clicked() {
+                self.open = false;
+            }
+        });
+
+        let index = picked_result?;
+        let (hq_count, solution) = self.results[index].clone();
+        self.open = false;
+        Some((
+            self.ingredients[hq_count].0,
+            solution
+                .map(|solution| solution.actions)
+                .unwrap_or_default(),
+        ))
+    }
+}
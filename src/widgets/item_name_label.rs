@@ -1,8 +1,11 @@
-use raphael_data::{Locale, get_item_name};
+use raphael_data::{Locale, get_item_name, get_item_name_raw};
+
+use super::util;
 
 pub struct ItemNameLabel {
     item_id: u32,
     text: String,
+    locale: Locale,
 }
 
 impl ItemNameLabel {
@@ -10,6 +13,7 @@ impl ItemNameLabel {
         Self {
             item_id,
             text: get_item_name(item_id, hq, locale).unwrap_or("Unknown item".to_owned()),
+            locale,
         }
     }
     pub fn from_recipe(recipe: &raphael_data::Recipe, hq: bool, locale: Locale) -> Self {
@@ -17,8 +21,18 @@ impl ItemNameLabel {
             item_id: recipe.item_id,
             text: raphael_data::get_recipe_name(recipe, hq, locale)
                 .unwrap_or("Unknown item".to_owned()),
+            locale,
         }
     }
+
+    /// Sets the locale of copied item names, which can differ from the locale the names are shown in
+    pub fn set_copy_locale(ctx: &egui::Context, locale: Locale) {
+        ctx.data_mut(|data| data.insert_temp(Self::copy_locale_id(), locale));
+    }
+
+    fn copy_locale_id() -> egui::Id {
+        egui::Id::new("item_name_copy_locale")
+    }
 }
 
 impl egui::Widget for ItemNameLabel {
@@ -36,16 +50,23 @@ impl egui::Widget for ItemNameLabel {
             )
         };
 
-        response.context_menu(|ui| {
+        util::context_menu(&response, true, |ui| {
             if ui.input(|i| i.key_pressed(egui::Key::Escape)) {
                 ui.close();
             }
             let mut selection_made = false;
             if ui.button("Copy item name").clicked() {
-                let copy_item_name = self
-                    .text
-                    .trim_end_matches([' ', raphael_data::HQ_ICON_CHAR, raphael_data::CL_ICON_CHAR])
-                    .to_string();
+                let copy_locale = ui
+                    .ctx()
+                    .data(|data| data.get_temp(Self::copy_locale_id()))
+                    .unwrap_or(self.locale);
+                let copy_item_name = if copy_locale == self.locale {
+                    self.text.as_str()
+                } else {
+                    get_item_name_raw(self.item_id, copy_locale).unwrap_or(&self.text)
+                }
+                .trim_end_matches([' ', raphael_data::HQ_ICON_CHAR, raphael_data::CL_ICON_CHAR])
+                .to_string();
                 ui.ctx().copy_text(copy_item_name);
                 ui.close();
                 selection_made = true;
@@ -0,0 +1,163 @@
+use raphael_data::{Consumable, CrafterStats, Locale};
+use raphael_sim::Action;
+
+use super::ItemNameLabel;
+use crate::{
+    app::{BatchSolution, LEVELING_RECIPE_LIMIT},
+    thread_pool,
+    translations::t,
+};
+
+/// Request from the leveling planner that needs the rest of the app to be carried out
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LevelingRequest {
+    /// Solve all listed recipes
+    Solve,
+    /// Select the recipe with the given id and use the rotation for it
+    Use(u32, Vec<Action>),
+}
+
+/// Window that solves the recipes at the highest level the crafter can craft, to pick one to level with
+#[derive(Debug, Default)]
+pub struct LevelingWindow {
+    pub open: bool,
+    /// Recipes that are solved by the leveling planner, the results refer to them by index
+    recipe_ids: Vec<u32>,
+    results: Vec<(usize, Option<BatchSolution>)>,
+}
+
+impl LevelingWindow {
+    pub fn show(&mut self, recipe_ids: Vec<u32>) {
+        self.open = true;
+        self.recipe_ids = recipe_ids;
+        self.results.clear();
+    }
+
+    pub fn recipe_ids(&self) -> &[u32] {
+        &self.recipe_ids
+    }
+
+    pub fn clear_results(&mut self) {
+        self.results.clear();
+    }
+
+    pub fn push_result(&mut self, index: usize, solution: Option<BatchSolution>) {
+        self.results.push((index, solution));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// Results for the webhook notification of the finished solve
+    pub fn summary_lines(&self, locale: Locale) -> Vec<String> {
+        self.results
+            .iter()
+            .map(|(index, solution)| {
+                let name = raphael_data::get_item_name(
+                    raphael_data::RECIPES[&self.recipe_ids[*index]].item_id,
+                    false,
+                    locale,
+                )
+                .unwrap_or_default();
+                solution.as_ref().map_or_else(
+                    || format!("{name}: no solution"),
+                    |solution| {
+                        format!(
+                            "{name}: {} quality, {} steps",
+                            solution.quality,
+                            solution.actions.len()
+                        )
+                    },
+                )
+            })
+            .collect()
+    }
+
+    pub fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        crafter_stats: CrafterStats,
+        consumables: [Option<Consumable>; 2],
+        locale: Locale,
+    ) -> Option<LevelingRequest> {
+        let mut request = None;
+        ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+        ui.set_width(420.0);
+        ui.label(egui::RichText::new("Leveling crafts").strong());
+        ui.separator();
+        ui.label(format!(
+            "Recipes at the highest level that can be crafted with the current stats, solved without HQ materials and with the current solver settings (at most {}).\nThe solves ensure 100% reliability, so the listed Quality is reached no matter how the conditions roll.",
+            LEVELING_RECIPE_LIMIT,
+        ));
+        egui::Grid::new("leveling_grid")
+            .striped(true)
+            .num_columns(4)
+            .show(ui, |ui| {
+                ui.label(egui::RichText::new(t("Recipe")).strong());
+                ui.label(egui::RichText::new(t("Quality")).strong());
+                ui.label(egui::RichText::new(t("Steps")).strong());
+                ui.end_row();
+                for (index, recipe_id) in self.recipe_ids.iter().enumerate() {
+                    let recipe = raphael_data::RECIPES[recipe_id];
+                    ui.add(ItemNameLabel::new(recipe.item_id, false, locale));
+                    let result = self
+                        .results
+                        .iter()
+                        .find(|(result_index, _)| *result_index == index)
+                        .map(|(_, solution)| solution);
+                    match result {
+                        Some(Some(solution)) => {
+                            let max_quality = raphael_data::get_game_settings(
+                                recipe,
+                                None,
+                                crafter_stats,
+                                consumables[0],
+                                consumables[1],
+                            )
+                            .max_quality;
+                            let hq_text = if solution.quality >= u32::from(max_quality) {
+                                "HQ"
+                            } else {
+                                "NQ"
+                            };
+                            ui.label(format!("{} ({})", solution.quality, hq_text));
+                            ui.label(format!("{}", solution.actions.len()));
+                            if ui.button(t("Use")).clicked() {
+                                request = Some(LevelingRequest::Use(
+                                    *recipe_id,
+                                    solution.actions.clone(),
+                                ));
+                            }
+                        }
+                        Some(None) => {
+                            ui.label(t("No solution"));
+                        }
+                        None => {
+                            ui.label("—");
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+        if self.recipe_ids.is_empty() {
+            ui.label(t("None"));
+        }
+        ui.separator();
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(
+                    !self.recipe_ids.is_empty() && thread_pool::is_initialized(),
+                    egui::Button::new(t("Solve")),
+                )
+                .clicked()
+            {
+                request = Some(LevelingRequest::Solve);
+            }
+            if ui.button(t("Close")).clicked() {
+                self.open = false;
+            }
+        });
+        if matches!(request, Some(LevelingRequest::Use(..))) {
+            self.open = false;
+        }
+        request
+    }
+}
@@ -1,5 +1,5 @@
 use egui::{Align, Id, Layout, Widget};
-use raphael_data::{Locale, action_name};
+use raphael_data::{Consumable, Locale, action_name};
 use raphael_sim::Action;
 use serde::{Deserialize, Serialize};
 
@@ -17,6 +17,14 @@ pub struct MacroViewConfig {
     notification_config: MacroNotificationConfig,
     #[serde(default)]
     macro_lock: bool,
+    #[serde(default)]
+    macro_icon: bool,
+    /// Echo the expected Progress and Quality at the end of each macro except the last one
+    #[serde(default)]
+    checkpoints: bool,
+    /// Echo the food and potion the macro was solved with at the start of the first macro
+    #[serde(default)]
+    consumables_reminder: bool,
 }
 
 impl Default for MacroViewConfig {
@@ -28,6 +36,9 @@ impl Default for MacroViewConfig {
             notification_enabled: false,
             notification_config: MacroNotificationConfig::default(),
             macro_lock: false,
+            macro_icon: false,
+            checkpoints: false,
+            consumables_reminder: false,
         }
     }
 }
@@ -65,30 +76,136 @@ struct MacroTextBox {
     text: String,
 }
 
+/// Macro that was last copied with "Copy next", which is reset when the actions of the macros change
+#[derive(Clone, Copy)]
+struct CopyNextState {
+    macros_id: Id,
+    copied_index: usize,
+}
+
+/// Temp data key that makes the macro view copy the next macro, e.g. when a global hotkey was pressed
+pub const COPY_NEXT_MACRO_REQUESTED: &str = "COPY_NEXT_MACRO_REQUESTED";
+
+const COPY_NEXT_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut::new(
+    egui::Modifiers::COMMAND.plus(egui::Modifiers::SHIFT),
+    egui::Key::C,
+);
+
+/// Echo lines that are added to a macro besides its actions
+#[derive(Clone, Copy)]
+struct MacroEchoLines<'a> {
+    /// Food and potion reminder at the start of the macro
+    consumables: Option<&'a str>,
+    /// Expected Progress and Quality at the end of the macro
+    checkpoint: Option<&'a str>,
+}
+
+/// Time it takes to execute `actions`, including the extra delay if delays are part of the macro
+fn macro_duration(actions: &[Action], config: &MacroViewConfig) -> u32 {
+    let extra_delay = match config.include_delay {
+        true => config.extra_delay,
+        false => 0,
+    };
+    actions
+        .iter()
+        .map(|action| u32::from(action.time_cost() + extra_delay))
+        .sum()
+}
+
 fn format_custom_notification(notification_format: &str, index: usize, max_index: usize) -> String {
     notification_format
         .replace("{index}", &index.to_string())
         .replace("{max_index}", &max_index.to_string())
 }
 
+/// Checkpoint line for the end of each macro except the last one, from simulating the macros in Normal condition.
+/// The checkpoint after the last Quality increase says so, as the remaining macros only finish the Progress.
+fn checkpoint_lines(chunks: &[&[Action]], simulation: &MacroSimulation) -> Vec<Option<String>> {
+    let MacroSimulation {
+        settings,
+        initial_state,
+        initial_quality,
+    } = simulation;
+    let actions = chunks.concat();
+    let (final_state, _) = initial_state.continue_macro_in_conditions(settings, &actions, &[]);
+    let mut completed_steps = 0;
+    chunks
+        .iter()
+        .enumerate()
+        .map(|(index, chunk)| {
+            completed_steps += chunk.len();
+            if index + 1 == chunks.len() {
+                return None;
+            }
+            let (state, _) = initial_state.continue_macro_in_conditions(
+                settings,
+                &actions[..completed_steps],
+                &[],
+            );
+            let quality = std::cmp::min(
+                u32::from(*initial_quality) + state.quality,
+                u32::from(settings.max_quality),
+            );
+            Some(
+                match state.quality != 0 && state.quality == final_state.quality {
+                    true => format!("/echo Quality phase done, expect ~{} quality", quality),
+                    false => format!(
+                        "/echo Checkpoint {}: expect ~{} progress, ~{} quality",
+                        index + 1,
+                        state.progress,
+                        quality
+                    ),
+                },
+            )
+        })
+        .collect()
+}
+
+/// Reminder line listing the food and potion with their names in `locale`, `None` if neither is used
+fn consumables_line(consumables: &[Option<Consumable>], locale: Locale) -> Option<String> {
+    let names: Vec<String> = consumables
+        .iter()
+        .flatten()
+        .filter_map(|consumable| {
+            raphael_data::get_item_name(consumable.item_id, consumable.hq, locale)
+        })
+        .collect();
+    match names.is_empty() {
+        true => None,
+        false => Some(format!("/echo Consumables: {}", names.join(", "))),
+    }
+}
+
 impl MacroTextBox {
     pub fn new(
         index: usize,
         max_index: usize,
         actions: &[Action],
         config: &MacroViewConfig,
+        echo_lines: MacroEchoLines,
         newline: &'static str,
         locale: Locale,
     ) -> Self {
+        let q = match locale {
+            Locale::JP | Locale::CN | Locale::TW => "",
+            _ => "\"",
+        };
         let mut lines: Vec<String> = Vec::new();
         if config.macro_lock {
             lines.push("/macrolock ".to_string());
         }
+        if config.macro_icon
+            && let Some(first_action) = actions.first()
+        {
+            lines.push(format!(
+                "/micon {q}{}{q} craftaction",
+                action_name(*first_action, locale)
+            ));
+        }
+        if let Some(consumables) = echo_lines.consumables {
+            lines.push(consumables.to_owned());
+        }
         lines.extend(actions.iter().map(|action| {
-            let q = match locale {
-                Locale::JP | Locale::CN => "",
-                _ => "\"",
-            };
             if config.include_delay {
                 format!(
                     "/ac {q}{}{q} <wait.{}>",
@@ -99,6 +216,11 @@ impl MacroTextBox {
                 format!("/ac {q}{}{q}", action_name(*action, locale))
             }
         }));
+        if let Some(checkpoint) = echo_lines.checkpoint
+            && lines.len() < 15
+        {
+            lines.push(checkpoint.to_owned());
+        }
         if config.notification_enabled && lines.len() < 15 {
             if config.notification_config.default_notification {
                 lines.push(format!(
@@ -147,10 +269,111 @@ impl Widget for MacroTextBox {
     }
 }
 
+/// Splits `actions` into in-game macros, leaving room for the macro lock, icon, checkpoint and notification lines,
+/// as well as the consumables reminder in the first macro if there is one
+fn split_macro<'a>(
+    actions: &'a [Action],
+    config: &MacroViewConfig,
+    consumables_reminder: bool,
+) -> Vec<&'a [Action]> {
+    let mut chunks = Vec::new();
+    let mut remaining_actions = actions;
+    while !remaining_actions.is_empty() {
+        let max_chunk_size = if config.split_macro {
+            let chunk_size = 15
+                - usize::from(config.macro_lock)
+                - usize::from(config.macro_icon)
+                - usize::from(config.checkpoints)
+                - usize::from(consumables_reminder && chunks.is_empty());
+            let avoid_notif = config.notification_config.avoid_single_action_macro
+                && remaining_actions.len() == chunk_size;
+            let empty_last = !config.notification_config.default_notification
+                && config.notification_config.different_last_notification
+                && config
+                    .notification_config
+                    .custom_last_notification_format
+                    .is_empty()
+                && remaining_actions.len() <= chunk_size;
+            let has_notif = config.notification_enabled && !avoid_notif && !empty_last;
+            chunk_size - usize::from(has_notif)
+        } else {
+            usize::MAX
+        };
+        let (this_chunk, remaining) =
+            remaining_actions.split_at(std::cmp::min(max_chunk_size, remaining_actions.len()));
+        chunks.push(this_chunk);
+        remaining_actions = remaining;
+    }
+    chunks
+}
+
+/// Actions that go into the macros, Careful Observation can't be used in macros so it is left out
+fn macro_actions(actions: &[Action]) -> Vec<Action> {
+    actions
+        .iter()
+        .copied()
+        .filter(|action| *action != Action::CarefulObservation)
+        .collect()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Text of all macros as shown in the macro view, separated by empty lines.
+/// `simulation` holds the game settings, initial state and initial Quality used for the checkpoint lines.
+pub fn macro_text(
+    actions: &[Action],
+    config: &MacroViewConfig,
+    simulation: Option<MacroSimulation>,
+    consumables: &[Option<Consumable>],
+    locale: Locale,
+) -> String {
+    let consumables_line =
+        consumables_line(consumables, locale).filter(|_| config.consumables_reminder);
+    let actions = macro_actions(actions);
+    let chunks = split_macro(&actions, config, consumables_line.is_some());
+    let num_chunks = chunks.len();
+    let checkpoints = match (config.checkpoints, simulation) {
+        (true, Some(simulation)) => checkpoint_lines(&chunks, &simulation),
+        _ => vec![None; num_chunks],
+    };
+    chunks
+        .into_iter()
+        .zip(checkpoints)
+        .enumerate()
+        .map(|(index, (actions, checkpoint))| {
+            let echo_lines = MacroEchoLines {
+                consumables: consumables_line.as_deref().filter(|_| index == 0),
+                checkpoint: checkpoint.as_deref(),
+            };
+            MacroTextBox::new(
+                index + 1,
+                num_chunks,
+                actions,
+                config,
+                echo_lines,
+                "\n",
+                locale,
+            )
+            .text
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Game settings, initial state and initial Quality for the checkpoint lines
+#[derive(Debug, Clone, Copy)]
+pub struct MacroSimulation {
+    pub settings: raphael_sim::Settings,
+    pub initial_state: raphael_sim::SimulationState,
+    pub initial_quality: u16,
+}
+
 pub struct MacroView<'a> {
     actions: &'a mut Vec<Action>,
     config: &'a mut MacroViewConfig,
     locale: Locale,
+    simulation: Option<MacroSimulation>,
+    /// Food and potion for the consumables reminder
+    consumables: [Option<Consumable>; 2],
 }
 
 impl<'a> MacroView<'a> {
@@ -163,11 +386,80 @@ impl<'a> MacroView<'a> {
             actions,
             config,
             locale,
+            simulation: None,
+            consumables: [None; 2],
         }
     }
+
+    /// Enables the checkpoint lines, which are generated by simulating the macro
+    pub fn with_simulation(mut self, simulation: MacroSimulation) -> Self {
+        self.simulation = Some(simulation);
+        self
+    }
+
+    /// Sets the food and potion for the consumables reminder
+    pub fn with_consumables(
+        mut self,
+        food: Option<Consumable>,
+        potion: Option<Consumable>,
+    ) -> Self {
+        self.consumables = [food, potion];
+        self
+    }
 }
 
 impl MacroView<'_> {
+    /// Copies the macros one after the other on successive clicks or shortcut presses, starting over after the last one.
+    /// The button is only shown if there are multiple macros, the shortcut and copy requests also work for a single one.
+    /// `macros_id` identifies the actions of the macros, so that the order is kept when only their text changes, e.g. when the macro language changes.
+    /// The clipboard is left as it is until the next copy, which then copies the macro in the new language.
+    fn copy_next_macro(ui: &mut egui::Ui, macros_id: Id, text_boxes: &[MacroTextBox]) {
+        let state_id = Id::new("MACRO_COPY_NEXT");
+        let mut copied_index = ui
+            .data(|data| data.get_temp::<CopyNextState>(state_id))
+            .filter(|state| state.macros_id == macros_id && state.copied_index < text_boxes.len())
+            .map(|state| state.copied_index);
+        let next_index = copied_index.map_or(0, |index| (index + 1) % text_boxes.len());
+        let mut copy_requested = ui.input_mut(|input| input.consume_shortcut(&COPY_NEXT_SHORTCUT))
+            || ui.data_mut(|data| {
+                data.remove_temp::<bool>(Id::new(COPY_NEXT_MACRO_REQUESTED))
+                    .unwrap_or(false)
+            });
+        ui.horizontal(|ui| {
+            if text_boxes.len() > 1 {
+                let shortcut_text = ui.ctx().format_shortcut(&COPY_NEXT_SHORTCUT);
+                copy_requested |= ui
+                    .button(format!("Copy macro {}", next_index + 1))
+                    .on_hover_text(format!(
+                        "Copies the macros in order on successive clicks ({shortcut_text})"
+                    ))
+                    .clicked();
+            }
+            if copy_requested {
+                ui.ctx().copy_text(text_boxes[next_index].text.clone());
+                ui.data_mut(|data| {
+                    data.insert_temp(
+                        state_id,
+                        CopyNextState {
+                            macros_id,
+                            copied_index: next_index,
+                        },
+                    )
+                });
+                copied_index = Some(next_index);
+            }
+            if let Some(index) = copied_index
+                && text_boxes.len() > 1
+            {
+                ui.label(format!(
+                    "Macro {}/{} is on the clipboard",
+                    index + 1,
+                    text_boxes.len()
+                ));
+            }
+        });
+    }
+
     fn macro_notification_menu(ui: &mut egui::Ui, notification_cfg: &mut MacroNotificationConfig) {
         ui.style_mut().spacing.item_spacing.y = 3.0;
         ui.horizontal(|ui| {
@@ -234,6 +526,7 @@ impl MacroView<'_> {
 
 impl Widget for MacroView<'_> {
     fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let macro_actions = macro_actions(self.actions);
         ui.group(|ui| {
             ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
             ui.vertical(|ui| {
@@ -246,14 +539,13 @@ impl Widget for MacroView<'_> {
                         {
                             self.actions.clear();
                         }
-                        let duration = self
-                            .actions
+                        let duration = macro_actions
                             .iter()
                             .map(|action| action.time_cost())
                             .sum::<u8>();
                         ui.label(format!(
                             "{} steps, {} seconds",
-                            self.actions.len(),
+                            macro_actions.len(),
                             duration
                         ));
                     });
@@ -269,6 +561,15 @@ impl Widget for MacroView<'_> {
                 ui.horizontal(|ui| {
                     ui.checkbox(&mut self.config.split_macro, "Split macro");
                     ui.checkbox(&mut self.config.macro_lock, "Macro lock");
+                    ui.checkbox(&mut self.config.macro_icon, "Macro icon")
+                        .on_hover_text("Use the first action of each macro as the macro icon");
+                    ui.add_enabled(
+                        self.config.split_macro,
+                        egui::Checkbox::new(&mut self.config.checkpoints, "Checkpoints"),
+                    )
+                    .on_hover_text("Echo the expected Progress and Quality at the end of each macro so that you can check mid-craft that the macro is on track");
+                    ui.checkbox(&mut self.config.consumables_reminder, "Consumables reminder")
+                        .on_hover_text("Echo the food and potion the macro was solved with at the start of the first macro");
                 });
                 ui.horizontal(|ui| {
                     ui.add(egui::Checkbox::new(
@@ -293,44 +594,57 @@ impl Widget for MacroView<'_> {
                 });
                 ui.separator();
 
-                let mut chunks = Vec::new();
-                let mut remaining_actions = self.actions.as_slice();
-                while !remaining_actions.is_empty() {
-                    let max_chunk_size = if self.config.split_macro {
-                        let chunk_size = 15 - usize::from(self.config.macro_lock);
-                        let avoid_notif = self.config.notification_config.avoid_single_action_macro
-                            && remaining_actions.len() == chunk_size;
-                        let empty_last = !self.config.notification_config.default_notification
-                            && self.config.notification_config.different_last_notification
-                            && self.config.notification_config
-                                .custom_last_notification_format.is_empty()
-                            && remaining_actions.len() <= chunk_size;
-                        let has_notif = self.config.notification_enabled && !avoid_notif
-                            && !empty_last;
-                        chunk_size - usize::from(has_notif)
-                    } else {
-                        usize::MAX
-                    };
-                    let (this_chunk, remaining) = remaining_actions
-                        .split_at(std::cmp::min(max_chunk_size, remaining_actions.len()));
-                    chunks.push(this_chunk);
-                    remaining_actions = remaining;
-                }
+                let consumables_line = consumables_line(&self.consumables, self.locale)
+                    .filter(|_| self.config.consumables_reminder);
+                let chunks = split_macro(&macro_actions, self.config, consumables_line.is_some());
 
                 let newline = match ui.ctx().os() {
                     egui::os::OperatingSystem::Mac => "\n",
                     _ => "\r\n",
                 };
                 let num_chunks = chunks.len();
-                for (index, actions) in chunks.into_iter().enumerate() {
-                    ui.add(MacroTextBox::new(
-                        index + 1,
-                        num_chunks,
-                        actions,
-                        self.config,
-                        newline,
-                        self.locale,
-                    ));
+                if num_chunks > 1 {
+                    let mut breakdown: Vec<String> = chunks
+                        .iter()
+                        .enumerate()
+                        .map(|(index, actions)| {
+                            format!("Macro {}: {}s", index + 1, macro_duration(actions, self.config))
+                        })
+                        .collect();
+                    breakdown.push(format!("total {}s", macro_duration(&macro_actions, self.config)));
+                    ui.label(breakdown.join(", "));
+                } else if num_chunks == 1 {
+                    ui.label(format!("Total: {}s", macro_duration(&macro_actions, self.config)));
+                }
+                if macro_actions.len() != self.actions.len() {
+                    ui.label(
+                        egui::RichText::new("Careful Observation can't be used in macros and is left out.")
+                            .small()
+                            .color(ui.visuals().warn_fg_color),
+                    );
+                }
+                let checkpoints = match (self.config.checkpoints, self.simulation) {
+                    (true, Some(simulation)) => checkpoint_lines(&chunks, &simulation),
+                    _ => vec![None; num_chunks],
+                };
+                let macros_id = Id::new(&chunks);
+                let text_boxes: Vec<MacroTextBox> = chunks
+                    .into_iter()
+                    .zip(checkpoints)
+                    .enumerate()
+                    .map(|(index, (actions, checkpoint))| {
+                        let echo_lines = MacroEchoLines {
+                            consumables: consumables_line.as_deref().filter(|_| index == 0),
+                            checkpoint: checkpoint.as_deref(),
+                        };
+                        MacroTextBox::new(index + 1, num_chunks, actions, self.config, echo_lines, newline, self.locale)
+                    })
+                    .collect();
+                if !text_boxes.is_empty() {
+                    Self::copy_next_macro(ui, macros_id, &text_boxes);
+                }
+                for text_box in text_boxes {
+                    ui.add(text_box);
                 }
 
                 if self.actions.is_empty() {
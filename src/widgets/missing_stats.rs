@@ -0,0 +1,142 @@
+use raphael_data::{Consumable, CrafterStats, Locale, Recipe};
+
+use crate::{translations::t, util};
+
+use super::ItemNameLabel;
+
+/// Button that selects the cheapest food and potion that meet the recipe requirements.
+/// The response is marked as changed when the consumables were applied.
+pub struct FixConsumablesButton<'a> {
+    recipe: &'a Recipe,
+    crafter_stats: CrafterStats,
+    selected_food: &'a mut Option<Consumable>,
+    selected_potion: &'a mut Option<Consumable>,
+}
+
+impl<'a> FixConsumablesButton<'a> {
+    pub fn new(
+        recipe: &'a Recipe,
+        crafter_stats: CrafterStats,
+        selected_food: &'a mut Option<Consumable>,
+        selected_potion: &'a mut Option<Consumable>,
+    ) -> Self {
+        Self {
+            recipe,
+            crafter_stats,
+            selected_food,
+            selected_potion,
+        }
+    }
+}
+
+impl egui::Widget for FixConsumablesButton<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let id = egui::Id::new((
+            "FIX_CONSUMABLES",
+            self.recipe.req_craftsmanship,
+            self.recipe.req_control,
+            self.crafter_stats.craftsmanship,
+            self.crafter_stats.control,
+        ));
+        // searching all combinations is too slow to do every frame, so the result is cached
+        let cheapest_consumables = ui.data_mut(|data| {
+            *data.get_temp_mut_or_insert_with(id, || {
+                util::find_cheapest_consumables(self.recipe, self.crafter_stats)
+            })
+        });
+        let response = ui.add_enabled(
+            cheapest_consumables.is_some(),
+            egui::Button::new(t("Fix for me")),
+        );
+        let mut response = match cheapest_consumables {
+            Some(_) => response.on_hover_text(
+                "Select the cheapest food and potion that meet the recipe requirements",
+            ),
+            None => response.on_disabled_hover_text(
+                "No combination of food and potion meets the recipe requirements",
+            ),
+        };
+        if response.clicked()
+            && let Some((food, potion)) = cheapest_consumables
+        {
+            *self.selected_food = food;
+            *self.selected_potion = potion;
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+/// Window shown instead of solving when the stats are below the recipe requirements
+#[derive(Debug, Default)]
+pub struct MissingStatsWindow {
+    pub open: bool,
+}
+
+impl MissingStatsWindow {
+    pub fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        recipe: &Recipe,
+        crafter_stats: CrafterStats,
+        selected_food: &mut Option<Consumable>,
+        selected_potion: &mut Option<Consumable>,
+        locale: Locale,
+    ) {
+        ui.style_mut().spacing.item_spacing = egui::vec2(3.0, 3.0);
+        ui.label(egui::RichText::new(t("Error")).strong());
+        ui.separator();
+        ui.label(t(
+            "Your stats are below the minimum requirement for this recipe.",
+        ));
+        ui.label(format!(
+            "Requirement: {} Craftsmanship, {} Control.",
+            recipe.req_craftsmanship, recipe.req_control
+        ));
+        let (missing_cms, missing_ctrl) =
+            raphael_data::missing_stats(recipe, crafter_stats, &[*selected_food, *selected_potion]);
+        ui.label(format!(
+            "Missing (including consumables): {missing_cms} Craftsmanship, {missing_ctrl} Control."
+        ));
+        let suggestions =
+            util::suggest_consumables(recipe, crafter_stats, *selected_food, *selected_potion);
+        if !suggestions.is_empty() {
+            ui.separator();
+            ui.label(t("The following consumables would meet the requirement:"));
+            for consumable in suggestions {
+                ui.horizontal(|ui| {
+                    ui.add(ItemNameLabel::new(
+                        consumable.item_id,
+                        consumable.hq,
+                        locale,
+                    ));
+                    ui.label(
+                        egui::RichText::new(consumable.effect_string(
+                            crafter_stats.craftsmanship,
+                            crafter_stats.control,
+                            crafter_stats.cp,
+                        ))
+                        .small(),
+                    );
+                });
+            }
+        }
+        ui.separator();
+        ui.vertical_centered_justified(|ui| {
+            if ui
+                .add(FixConsumablesButton::new(
+                    recipe,
+                    crafter_stats,
+                    selected_food,
+                    selected_potion,
+                ))
+                .changed()
+            {
+                self.open = false;
+            }
+            if ui.button(t("Close")).clicked() {
+                self.open = false;
+            }
+        });
+    }
+}
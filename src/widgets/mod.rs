@@ -1,5 +1,7 @@
 mod macro_view;
-pub use macro_view::{MacroView, MacroViewConfig};
+#[cfg(not(target_arch = "wasm32"))]
+pub use macro_view::macro_text;
+pub use macro_view::{COPY_NEXT_MACRO_REQUESTED, MacroSimulation, MacroView, MacroViewConfig};
 
 mod simulator;
 pub use simulator::Simulator;
@@ -24,7 +26,8 @@ pub use item_name_label::ItemNameLabel;
 
 mod saved_rotations;
 pub use saved_rotations::{
-    Rotation, SavedRotationsConfig, SavedRotationsData, SavedRotationsWidget,
+    RecipeInfo, Rotation, SavedRotationsConfig, SavedRotationsData, SavedRotationsWidget,
+    SolveInfo, solver_description,
 };
 
 #[cfg(any(debug_assertions, feature = "dev-panel"))]
@@ -32,4 +35,100 @@ mod render_info;
 #[cfg(any(debug_assertions, feature = "dev-panel"))]
 pub use render_info::{RenderInfo, RenderInfoState};
 
+#[cfg(any(debug_assertions, feature = "dev-panel"))]
+mod test_case_export;
+#[cfg(any(debug_assertions, feature = "dev-panel"))]
+pub use test_case_export::{TestCaseExport, TestCaseExportState};
+
+mod missing_stats;
+pub use missing_stats::{FixConsumablesButton, MissingStatsWindow};
+
+mod solve_confirmation;
+pub use solve_confirmation::SolveConfirmationWindow;
+
+mod solver_presets;
+pub use solver_presets::{SolverPreset, SolverPresetsButton};
+
+mod rotation_repair;
+pub use rotation_repair::{RepairTarget, RepairWindow};
+
+mod collectable_tiers;
+pub use collectable_tiers::TierResultsWindow;
+
+mod collectable_sweep;
+pub use collectable_sweep::{CollectableSweepRequest, CollectableSweepWindow};
+
+mod cp_tradeoff;
+pub use cp_tradeoff::CpTradeoffWindow;
+
+mod backup;
+pub use backup::{BackupRequest, BackupWindow};
+
+mod bar_style_settings;
+pub use bar_style_settings::BarStyleSettings;
+
+mod gearset_compare;
+pub use gearset_compare::{GearsetRequest, GearsetResult, GearsetWindow};
+
+mod opener_select;
+pub use opener_select::OpenerSelect;
+
+mod craft_margins;
+pub use craft_margins::CraftMarginsEdit;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod webhook_settings;
+#[cfg(not(target_arch = "wasm32"))]
+pub use webhook_settings::{DISCORD_MESSAGE_MAX_CHARS, WebhookSettings, send_discord_notification};
+
+mod condition_estimate;
+pub use condition_estimate::ConditionEstimate;
+
+mod solve_progress;
+pub use solve_progress::{SolveProgress, SolveReport};
+
+mod update_notice;
+pub use update_notice::{LatestRelease, UpdateCheckSettings, UpdateRequest};
+
+mod workspace_tabs;
+pub use workspace_tabs::{Workspace, WorkspaceRequest, WorkspaceTabs};
+
+mod config_compare;
+pub use config_compare::{ConfigCompareRequest, ConfigCompareWindow};
+
+mod opener_conditions;
+pub use opener_conditions::OpenerConditionSelect;
+
+mod hq_sweep;
+pub use hq_sweep::HqSweepWindow;
+
+mod leveling_planner;
+pub use leveling_planner::{LevelingRequest, LevelingWindow};
+
+mod decision_tree;
+pub use decision_tree::DecisionTreeWindow;
+
+mod quick_synthesis;
+pub use quick_synthesis::QuickSynthesisAdvisor;
+
+mod hq_inventory;
+pub use hq_inventory::HqInventoryWindow;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod crash_report;
+#[cfg(not(target_arch = "wasm32"))]
+pub use crash_report::crash_report_notice;
+
+mod solver_error;
+pub use solver_error::SolverErrorMessage;
+
+mod calculation_mismatches;
+pub use calculation_mismatches::CalculationMismatches;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod copy_hotkey_settings;
+#[cfg(not(target_arch = "wasm32"))]
+pub use copy_hotkey_settings::CopyHotkeySettings;
+
 mod util;
+pub use util::{format_thousands, get_action_icon};
@@ -0,0 +1,52 @@
+use raphael_data::Recipe;
+use raphael_sim::Condition;
+
+use crate::app::SolverConfig;
+
+use super::HelpText;
+
+/// Selects the assumed condition of the first two steps, out of the conditions the recipe can roll
+pub struct OpenerConditionSelect<'a> {
+    solver_config: &'a mut SolverConfig,
+    recipe: Recipe,
+}
+
+impl<'a> OpenerConditionSelect<'a> {
+    pub fn new(solver_config: &'a mut SolverConfig, recipe: Recipe) -> Self {
+        Self {
+            solver_config,
+            recipe,
+        }
+    }
+}
+
+impl egui::Widget for OpenerConditionSelect<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        self.solver_config.restrict_opener_conditions(self.recipe);
+        let opener_conditions = &mut self.solver_config.opener_conditions;
+        let first_condition = opener_conditions[0];
+        let conditions = raphael_data::modeled_conditions(self.recipe);
+        ui.horizontal(|ui| {
+            for (step, condition) in opener_conditions.iter_mut().enumerate() {
+                ui.add_enabled_ui(step == 0 || first_condition != Condition::Excellent, |ui| {
+                    egui::ComboBox::from_id_salt(("OPENER_CONDITION", step))
+                        .width(80.0)
+                        .selected_text(format!("{:?}", condition))
+                        .show_ui(ui, |ui| {
+                            // the second step is followed by Normal steps, see `SolverSettings::with_opener_conditions`
+                            let options = conditions.iter().filter(|option| {
+                                step == 0
+                                    || option.can_follow(first_condition)
+                                        && Condition::Normal.can_follow(**option)
+                            });
+                            for &option in options {
+                                ui.selectable_value(condition, option, format!("{:?}", option));
+                            }
+                        });
+                });
+            }
+            ui.add(HelpText::new("Assumed condition of the first and second step, e.g. when waiting for a Good condition before starting the rotation.\nAll later steps are assumed to be Normal. Tricks of the Trade, Intensive Synthesis and Precise Touch can be used without Heart and Soul in a Good or Excellent step.\nOnly the conditions the recipe can roll are offered, e.g. expert recipes never roll Excellent or Poor.\nExcellent is always followed by Poor and Poor only follows Excellent, so the second step can't be Excellent. An expert recipe whose recipe level has no imported condition flags only offers Normal and Good.\nHas no effect when solving from a mid-craft state."));
+        })
+        .response
+    }
+}
@@ -0,0 +1,44 @@
+use raphael_data::{Locale, action_name};
+use raphael_sim::Action;
+use raphael_solver::OpenerPreference;
+
+/// Forces or forbids the first action of the rotation
+pub struct OpenerSelect<'a> {
+    opener: &'a mut OpenerPreference,
+    locale: Locale,
+}
+
+impl<'a> OpenerSelect<'a> {
+    pub fn new(opener: &'a mut OpenerPreference, locale: Locale) -> Self {
+        Self { opener, locale }
+    }
+
+    fn opener_text(&self, opener: OpenerPreference) -> String {
+        match opener {
+            OpenerPreference::Any => "Any".to_owned(),
+            OpenerPreference::Force(action) => action_name(action, self.locale).to_owned(),
+            OpenerPreference::Forbid(action) => format!("Not {}", action_name(action, self.locale)),
+        }
+    }
+}
+
+impl egui::Widget for OpenerSelect<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let selected_text = self.opener_text(*self.opener);
+        egui::ComboBox::from_id_salt("OPENER")
+            .selected_text(selected_text)
+            .show_ui(ui, |ui| {
+                for opener in [
+                    OpenerPreference::Any,
+                    OpenerPreference::Force(Action::Reflect),
+                    OpenerPreference::Force(Action::MuscleMemory),
+                    OpenerPreference::Forbid(Action::Reflect),
+                    OpenerPreference::Forbid(Action::MuscleMemory),
+                ] {
+                    let text = self.opener_text(opener);
+                    ui.selectable_value(self.opener, opener, text);
+                }
+            })
+            .response
+    }
+}
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use egui::{
     Align, Id, Layout, Widget,
     util::cache::{ComputerMut, FrameCache},
@@ -10,13 +12,13 @@ use super::{ItemNameLabel, util};
 #[derive(Default)]
 struct PotionFinder {}
 
-impl ComputerMut<(&str, Locale), Vec<usize>> for PotionFinder {
-    fn compute(&mut self, (text, locale): (&str, Locale)) -> Vec<usize> {
-        find_potions(text, locale)
+impl ComputerMut<(&str, Locale), Arc<[usize]>> for PotionFinder {
+    fn compute(&mut self, (text, locale): (&str, Locale)) -> Arc<[usize]> {
+        find_potions(text, locale).into()
     }
 }
 
-type PotionSearchCache<'a> = FrameCache<Vec<usize>, PotionFinder>;
+type PotionSearchCache<'a> = FrameCache<Arc<[usize]>, PotionFinder>;
 
 pub struct PotionSelect<'a> {
     crafter_stats: CrafterStats,
@@ -95,7 +97,7 @@ impl Widget for PotionSelect<'_> {
                 }
                 ui.separator();
 
-                let mut search_result = Vec::new();
+                let mut search_result = Arc::default();
                 ui.ctx().memory_mut(|mem| {
                     let search_cache = mem.caches.cache::<PotionSearchCache<'_>>();
                     search_result = search_cache.get((&search_text, self.locale));
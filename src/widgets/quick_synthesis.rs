@@ -0,0 +1,136 @@
+use raphael_data::{Consumable, CrafterStats, Recipe};
+use raphael_sim::{Action, MacroEstimate, Settings};
+
+use crate::app::{CRAFT_OVERHEAD_SECONDS, SolverConfig, macro_duration_seconds};
+
+use super::{HelpText, condition_estimate::ESTIMATE_CRAFTS};
+
+/// Compares the macro with Quick Synthesis for recipes where Quality doesn't matter
+pub struct QuickSynthesisAdvisor<'a> {
+    settings: &'a Settings,
+    solver_config: &'a SolverConfig,
+    actions: &'a [Action],
+    recipe: Recipe,
+    crafter_stats: CrafterStats,
+    food: Option<Consumable>,
+    potion: Option<Consumable>,
+}
+
+impl<'a> QuickSynthesisAdvisor<'a> {
+    pub fn new(
+        settings: &'a Settings,
+        solver_config: &'a SolverConfig,
+        actions: &'a [Action],
+        recipe: Recipe,
+        crafter_stats: CrafterStats,
+        food: Option<Consumable>,
+        potion: Option<Consumable>,
+    ) -> Self {
+        Self {
+            settings,
+            solver_config,
+            actions,
+            recipe,
+            crafter_stats,
+            food,
+            potion,
+        }
+    }
+}
+
+/// Expected time spent per successfully crafted item, counting the time of the failed attempts
+fn seconds_per_item(seconds_per_attempt: u32, estimate: MacroEstimate) -> Option<f64> {
+    if estimate.completed == 0 {
+        return None;
+    }
+    Some(
+        f64::from(seconds_per_attempt) * f64::from(estimate.crafts) / f64::from(estimate.completed),
+    )
+}
+
+fn outcome_text(estimate: MacroEstimate, seconds_per_item: Option<f64>) -> String {
+    seconds_per_item.map_or_else(
+        || "never succeeds".to_owned(),
+        |seconds| {
+            format!(
+                "succeeds in {:.1}% of crafts, about {:.1} seconds per item",
+                f64::from(estimate.completed) * 100.0 / f64::from(estimate.crafts),
+                seconds
+            )
+        },
+    )
+}
+
+impl egui::Widget for QuickSynthesisAdvisor<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let initial_state = self.solver_config.initial_state(self.settings);
+        let macro_seconds_per_attempt =
+            macro_duration_seconds(self.actions) + CRAFT_OVERHEAD_SECONDS;
+        let id = egui::Id::new((
+            "QUICK_SYNTHESIS_ESTIMATE",
+            self.settings,
+            initial_state,
+            self.actions,
+            self.recipe.item_id,
+        ));
+        // simulating thousands of crafts is too slow to do every frame, so the result is cached
+        let (quick_synthesis_estimate, macro_estimate) = ui.data_mut(|data| {
+            *data.get_temp_mut_or_insert_with(id, || {
+                let quick_synthesis_estimate = raphael_data::estimate_quick_synthesis(
+                    self.recipe,
+                    self.crafter_stats,
+                    self.food,
+                    self.potion,
+                    ESTIMATE_CRAFTS,
+                );
+                let macro_estimate = raphael_sim::estimate_macro(
+                    self.settings,
+                    initial_state,
+                    self.actions,
+                    0,
+                    raphael_data::condition_probabilities(self.recipe, None),
+                    ESTIMATE_CRAFTS,
+                );
+                (quick_synthesis_estimate, macro_estimate)
+            })
+        });
+        let quick_synthesis_seconds = quick_synthesis_estimate.and_then(|estimate| {
+            seconds_per_item(raphael_data::QUICK_SYNTHESIS_SECONDS_PER_ATTEMPT, estimate)
+        });
+        let macro_seconds = seconds_per_item(macro_seconds_per_attempt, macro_estimate);
+        ui.group(|ui| {
+            ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new("Quick Synthesis").strong());
+                ui.add(HelpText::new(format!("Outcome over {} simulated crafts in which the condition of each step is rolled at random.\nQuick Synthesis is estimated as using Basic Synthesis until the item is complete or the durability runs out.\nIt never produces HQ items and isn't available for expert recipes.\nThe time per item counts the time spent on failed attempts.", ESTIMATE_CRAFTS)));
+            });
+            ui.separator();
+            if let Some(estimate) = quick_synthesis_estimate {
+                ui.label(format!(
+                    "Quick Synthesis: {}",
+                    outcome_text(estimate, quick_synthesis_seconds)
+                ));
+            } else {
+                ui.label("Quick Synthesis: not available for this recipe");
+            }
+            ui.label(format!(
+                "Macro: {}",
+                outcome_text(macro_estimate, macro_seconds)
+            ));
+            let recommendation = match (quick_synthesis_seconds, macro_seconds) {
+                (Some(quick_synthesis_seconds), Some(macro_seconds)) => {
+                    if quick_synthesis_seconds <= macro_seconds {
+                        "Use Quick Synthesis, it is faster per item."
+                    } else {
+                        "Use the macro, it is faster per item."
+                    }
+                }
+                (Some(_), None) => "Use Quick Synthesis.",
+                (None, Some(_)) => "Use the macro.",
+                (None, None) => "Solve a macro that completes the craft.",
+            };
+            ui.label(egui::RichText::new(recommendation).strong());
+        })
+        .response
+    }
+}
@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
 use egui::{
     Align, Id, Layout, Widget,
     util::cache::{ComputerMut, FrameCache},
@@ -7,6 +10,7 @@ use raphael_data::{
     Consumable, CustomRecipeOverrides, Ingredient, Locale, RLVLS, find_recipes, get_game_settings,
     get_job_name,
 };
+use raphael_sim::ConditionProbabilities;
 
 use crate::config::{
     CrafterConfig, CustomRecipeOverridesConfiguration, QualitySource, RecipeConfiguration,
@@ -17,17 +21,36 @@ use super::{ItemNameLabel, util};
 #[derive(Default)]
 struct RecipeFinder {}
 
-impl ComputerMut<(&str, Locale), Vec<u32>> for RecipeFinder {
-    fn compute(&mut self, (text, locale): (&str, Locale)) -> Vec<u32> {
-        find_recipes(text, locale)
+impl ComputerMut<(&str, Locale, u8), Arc<[u32]>> for RecipeFinder {
+    fn compute(&mut self, (text, locale, selected_job): (&str, Locale, u8)) -> Arc<[u32]> {
+        let mut search_result = find_recipes(text, locale);
+        if !text.is_empty() {
+            let mut other_job: Vec<_> = search_result
+                .extract_if(.., |recipe_id| {
+                    raphael_data::RECIPES[recipe_id].job_id != selected_job
+                })
+                .collect();
+            search_result.append(&mut other_job);
+        }
+        search_result.into()
     }
 }
 
-type SearchCache<'a> = FrameCache<Vec<u32>, RecipeFinder>;
+/// Whether the crafter's level is high enough for `recipe`
+fn meets_level(recipe: &raphael_data::Recipe, crafter_stats: raphael_data::CrafterStats) -> bool {
+    // level-scaling recipes can be crafted at any level
+    recipe.max_level_scaling != 0
+        || crafter_stats.level >= RLVLS[recipe.recipe_level as usize].job_level
+}
+
+// shared so that the table, which only lays out the visible rows, doesn't copy the whole result every frame
+type SearchCache<'a> = FrameCache<Arc<[u32]>, RecipeFinder>;
 
 pub struct RecipeSelect<'a> {
     crafter_config: &'a mut CrafterConfig,
     solver_config: &'a mut crate::app::SolverConfig,
+    recipe_solver_configs: &'a HashMap<u32, crate::app::SolverConfig>,
+    recipe_hq_materials: &'a mut HashMap<u32, [u8; 6]>,
     recipe_config: &'a mut RecipeConfiguration,
     custom_recipe_overrides_config: &'a mut CustomRecipeOverridesConfiguration,
     selected_food: Option<Consumable>, // used for base prog/qual display
@@ -39,6 +62,8 @@ impl<'a> RecipeSelect<'a> {
     pub fn new(
         crafter_config: &'a mut CrafterConfig,
         solver_config: &'a mut crate::app::SolverConfig,
+        recipe_solver_configs: &'a HashMap<u32, crate::app::SolverConfig>,
+        recipe_hq_materials: &'a mut HashMap<u32, [u8; 6]>,
         recipe_config: &'a mut RecipeConfiguration,
         custom_recipe_overrides_config: &'a mut CustomRecipeOverridesConfiguration,
         selected_food: Option<Consumable>,
@@ -48,6 +73,8 @@ impl<'a> RecipeSelect<'a> {
         Self {
             crafter_config,
             solver_config,
+            recipe_solver_configs,
+            recipe_hq_materials,
             recipe_config,
             custom_recipe_overrides_config,
             selected_food,
@@ -64,32 +91,50 @@ impl<'a> RecipeSelect<'a> {
             }
         });
 
-        if egui::TextEdit::singleline(&mut search_text)
-            .desired_width(f32::INFINITY)
-            .hint_text("🔍 Search")
-            .ui(ui)
-            .changed()
-        {
-            search_text = search_text.replace('\0', "");
-        }
+        let mut craftable_only = ui.ctx().data_mut(|data| {
+            *data.get_persisted_mut_or_default::<bool>(Id::new("RECIPE_SEARCH_CRAFTABLE_ONLY"))
+        });
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut craftable_only, "Craftable")
+                .on_hover_text("Only show recipes that your job level and stats, including the selected food and potion, are high enough for");
+            if egui::TextEdit::singleline(&mut search_text)
+                .desired_width(f32::INFINITY)
+                .hint_text("🔍 Search")
+                .ui(ui)
+                .changed()
+            {
+                search_text = search_text.replace('\0', "");
+            }
+        });
         ui.separator();
 
-        let mut search_result = Vec::new();
+        let mut search_result = Arc::default();
         ui.ctx().memory_mut(|mem| {
             let search_cache = mem.caches.cache::<SearchCache<'_>>();
-            search_result = search_cache.get((&search_text, self.locale));
+            search_result =
+                search_cache.get((&search_text, self.locale, self.crafter_config.selected_job));
         });
-        if !search_text.is_empty() {
-            let mut other_job: Vec<_> = search_result.extract_if(.., |recipe_id| {
-                raphael_data::RECIPES[recipe_id].job_id != self.crafter_config.selected_job
-            }).collect();
-            search_result.append(&mut other_job);
-        }
 
         ui.ctx().data_mut(|data| {
             data.insert_persisted(Id::new("RECIPE_SEARCH_TEXT"), search_text);
+            data.insert_persisted(Id::new("RECIPE_SEARCH_CRAFTABLE_ONLY"), craftable_only);
         });
 
+        if craftable_only {
+            let consumables = [self.selected_food, self.selected_potion];
+            search_result = search_result
+                .iter()
+                .copied()
+                .filter(|recipe_id| {
+                    let recipe = raphael_data::RECIPES[recipe_id];
+                    let crafter_stats = *self.crafter_config.job_stats(recipe.job_id);
+                    meets_level(&recipe, crafter_stats)
+                        && raphael_data::missing_stats(&recipe, crafter_stats, &consumables)
+                            == (0, 0)
+                })
+                .collect();
+        }
+
         let line_height = ui.spacing().interact_size.y;
         let line_spacing = ui.spacing().item_spacing.y;
         let table_height = 6.3 * line_height + 6.0 * line_spacing;
@@ -97,8 +142,8 @@ impl<'a> RecipeSelect<'a> {
         // Column::remainder().clip(true) is buggy when resizing the table
         // manually calculate the width of the last col to avoid janky behavior when resizing tables
         // this is a workaround until this bug is fixed in egui_extras
-        let spacing = 2.0 * ui.spacing().item_spacing.x;
-        let item_name_width = (ui.available_width() - 42.0 - 28.0 - spacing).max(0.0);
+        let spacing = 4.0 * ui.spacing().item_spacing.x;
+        let item_name_width = (ui.available_width() - 42.0 - 28.0 - 28.0 - 80.0 - spacing).max(0.0);
 
         let table = egui_extras::TableBuilder::new(ui)
             .id_salt("RECIPE_SELECT_TABLE")
@@ -106,6 +151,8 @@ impl<'a> RecipeSelect<'a> {
             .striped(true)
             .column(Column::exact(42.0))
             .column(Column::exact(28.0))
+            .column(Column::exact(28.0))
+            .column(Column::exact(80.0))
             .column(Column::exact(item_name_width))
             .min_scrolled_height(table_height)
             .max_scroll_height(table_height);
@@ -113,31 +160,59 @@ impl<'a> RecipeSelect<'a> {
             body.rows(line_height, search_result.len(), |mut row| {
                 let recipe_id = search_result[row.index()];
                 let recipe = raphael_data::RECIPES[&recipe_id];
+                let job_level = RLVLS[recipe.recipe_level as usize].job_level;
+                let crafter_stats = *self.crafter_config.job_stats(recipe.job_id);
+                let meets_level = meets_level(&recipe, crafter_stats);
+                let (missing_craftsmanship, missing_control) = raphael_data::missing_stats(
+                    &recipe,
+                    crafter_stats,
+                    &[self.selected_food, self.selected_potion],
+                );
+                let craftable = meets_level && missing_craftsmanship == 0 && missing_control == 0;
                 row.col(|ui| {
                     if ui.button("Select").clicked() {
                         self.crafter_config.selected_job = recipe.job_id;
-                        if self.recipe_config.recipe.quality_factor != recipe.quality_factor
+                        if let Some(solver_config) = self.recipe_solver_configs.get(&recipe_id) {
+                            *self.solver_config = *solver_config;
+                        } else if self.recipe_config.recipe.quality_factor != recipe.quality_factor
                             || self.recipe_config.recipe.recipe_level != recipe.recipe_level
                         {
                             self.solver_config.quality_target =
                                 crate::config::QualityTarget::default();
                         }
-                        *self.recipe_config = RecipeConfiguration {
-                            recipe,
-                            quality_source: QualitySource::HqMaterialList(
-                                if raphael_data::is_cosmic_recipe(recipe.item_id) {
-                                    recipe.ingredients.map(|ingredient| ingredient.amount as u8)
-                                } else {
-                                    [0; 6]
-                                }
-                            ),
-                        }
+                        self.recipe_config.select_recipe(recipe_id, self.recipe_hq_materials);
                     }
                 });
                 row.col(|ui| {
                     ui.label(get_job_name(recipe.job_id, self.locale));
                 });
                 row.col(|ui| {
+                    let text = egui::RichText::new(job_level.to_string());
+                    let text = if meets_level { text } else { text.color(ui.visuals().error_fg_color) };
+                    ui.label(text)
+                        .on_hover_text(format!("Recipe level {}", recipe.recipe_level));
+                });
+                row.col(|ui| {
+                    if recipe.req_craftsmanship == 0 && recipe.req_control == 0 {
+                        return;
+                    }
+                    let text = egui::RichText::new(format!("{} / {}", recipe.req_craftsmanship, recipe.req_control));
+                    if missing_craftsmanship == 0 && missing_control == 0 {
+                        ui.label(text).on_hover_text("Required Craftsmanship / Control");
+                    } else {
+                        ui.label(text.color(ui.visuals().error_fg_color)).on_hover_text(format!(
+                            "Required Craftsmanship / Control\nMissing: {missing_craftsmanship} / {missing_control}"
+                        ));
+                    }
+                });
+                row.col(|ui| {
+                    if !craftable {
+                        ui.visuals_mut().override_text_color = Some(ui.visuals().weak_text_color());
+                    }
+                    if recipe.is_expert {
+                        ui.label(egui::RichText::new("Expert").small().strong())
+                            .on_hover_text("Expert recipe");
+                    }
                     ui.add(ItemNameLabel::from_recipe(&recipe, false, self.locale));
                 });
             });
@@ -175,8 +250,9 @@ impl<'a> RecipeSelect<'a> {
                         }
                     });
                 });
+                let level_synced = self.recipe_config.recipe.max_level_scaling != 0;
                 ui.horizontal(|ui| {
-                    ui.add_enabled_ui(!use_base_increase_overrides, |ui| {
+                    ui.add_enabled_ui(!use_base_increase_overrides && !level_synced, |ui| {
                         ui.label("Recipe Level:");
                         let mut rlvl_drag_value_widget =
                             egui::DragValue::new(&mut self.recipe_config.recipe.recipe_level)
@@ -187,6 +263,28 @@ impl<'a> RecipeSelect<'a> {
                         ui.add(rlvl_drag_value_widget);
                     });
                 });
+                ui.horizontal(|ui| {
+                    ui.add_enabled_ui(!use_base_increase_overrides, |ui| {
+                        let mut level_synced = level_synced;
+                        if ui
+                            .checkbox(&mut level_synced, "Level sync:")
+                            .on_hover_text("Sync the recipe level to the job level, up to the given level, as for the level-scaling recipes of e.g. the Ishgardian Restoration.\nCan't be combined with overriding the per 100% efficiency values, which turns level sync off.")
+                            .on_disabled_hover_text("Level sync can't be combined with overriding the per 100% efficiency values, since the overrides are for a fixed recipe level.\nTurn off \"Override per 100% efficiency values\" to sync the recipe level.")
+                            .changed()
+                        {
+                            self.recipe_config.recipe.max_level_scaling = match level_synced {
+                                true => self.crafter_config.active_stats().level.clamp(1, 100),
+                                false => 0,
+                            };
+                        }
+                        ui.add_enabled(
+                            level_synced,
+                            egui::DragValue::new(&mut self.recipe_config.recipe.max_level_scaling)
+                                .range(1..=100)
+                                .prefix("up to "),
+                        );
+                    });
+                });
                 ui.horizontal(|ui| {
                     ui.label("Progress:");
                     ui.add(egui::DragValue::new(
@@ -199,14 +297,26 @@ impl<'a> RecipeSelect<'a> {
                         &mut custom_recipe_overrides.max_quality_override,
                     ));
                 });
-                if let QualitySource::Value(initial_quality) =
-                    &mut self.recipe_config.quality_source
-                {
-                    ui.horizontal(|ui| {
-                        ui.label("Initial Quality:");
-                        ui.add(egui::DragValue::new(initial_quality));
-                    });
-                }
+                ui.horizontal(|ui| {
+                    ui.label("Initial Quality:");
+                    match &mut self.recipe_config.quality_source {
+                        QualitySource::Value(initial_quality) => {
+                            ui.add(egui::DragValue::new(initial_quality));
+                        }
+                        QualitySource::Percentage(percentage) => {
+                            ui.add(egui::DragValue::new(percentage).range(0..=100).suffix("%"));
+                        }
+                        QualitySource::HqMaterialList(_) => (),
+                    }
+                    let mut use_percentage =
+                        matches!(self.recipe_config.quality_source, QualitySource::Percentage(_));
+                    if ui.toggle_value(&mut use_percentage, "%").changed() {
+                        self.recipe_config.quality_source = match use_percentage {
+                            true => QualitySource::Percentage(0),
+                            false => QualitySource::Value(0),
+                        };
+                    }
+                });
                 ui.horizontal(|ui| {
                     ui.label("Durability:");
                     ui.add(
@@ -215,10 +325,77 @@ impl<'a> RecipeSelect<'a> {
                     );
                 });
                 ui.checkbox(&mut self.recipe_config.recipe.is_expert, "Expert recipe");
+                let is_expert = self.recipe_config.recipe.is_expert;
+                let mut use_condition_overrides =
+                    custom_recipe_overrides.condition_probabilities_override.is_some();
+                if ui
+                    .checkbox(&mut use_condition_overrides, "Override condition chances")
+                    .on_hover_text(format!(
+                        "Chances of the conditions used to estimate how often the macro completes.\nWithout overrides, every regular recipe uses Good {}% and Excellent {}%, and every expert recipe Good {}%, Centered {}%, Sturdy {}%, Pliant {}%, Malleable {}% and Primed {}%, out of the conditions it can roll. The game's per-recipe condition chances are not imported.",
+                        ConditionProbabilities::REGULAR_RECIPE.good,
+                        ConditionProbabilities::REGULAR_RECIPE.excellent,
+                        ConditionProbabilities::EXPERT_RECIPE.good,
+                        ConditionProbabilities::EXPERT_RECIPE.centered,
+                        ConditionProbabilities::EXPERT_RECIPE.sturdy,
+                        ConditionProbabilities::EXPERT_RECIPE.pliant,
+                        ConditionProbabilities::EXPERT_RECIPE.malleable,
+                        ConditionProbabilities::EXPERT_RECIPE.primed,
+                    ))
+                    .changed()
+                {
+                    custom_recipe_overrides.condition_probabilities_override =
+                        use_condition_overrides.then(|| {
+                            raphael_data::condition_probabilities(self.recipe_config.recipe, None)
+                        });
+                }
+                let mut default_probabilities =
+                    raphael_data::condition_probabilities(self.recipe_config.recipe, None);
+                let probabilities = custom_recipe_overrides
+                    .condition_probabilities_override
+                    .as_mut()
+                    .unwrap_or(&mut default_probabilities);
+                // expert recipes roll their own conditions instead of Excellent and Poor
+                *probabilities = match is_expert {
+                    true => ConditionProbabilities {
+                        excellent: 0,
+                        ..*probabilities
+                    },
+                    false => ConditionProbabilities {
+                        good: probabilities.good,
+                        excellent: probabilities.excellent,
+                        ..ConditionProbabilities::REGULAR_RECIPE
+                    },
+                };
+                let total = probabilities.total();
+                ui.add_enabled_ui(use_condition_overrides, |ui| {
+                    for (label, chance, enabled) in [
+                        ("Good:", &mut probabilities.good, true),
+                        ("Excellent:", &mut probabilities.excellent, !is_expert),
+                        ("Centered:", &mut probabilities.centered, is_expert),
+                        ("Sturdy:", &mut probabilities.sturdy, is_expert),
+                        ("Pliant:", &mut probabilities.pliant, is_expert),
+                        ("Malleable:", &mut probabilities.malleable, is_expert),
+                        ("Primed:", &mut probabilities.primed, is_expert),
+                    ] {
+                        let max_chance = 100u16.saturating_sub(total - u16::from(*chance));
+                        ui.horizontal(|ui| {
+                            ui.label(label);
+                            ui.add_enabled(
+                                enabled,
+                                egui::DragValue::new(chance)
+                                    .range(0..=max_chance)
+                                    .suffix("%"),
+                            );
+                        });
+                    }
+                });
             });
             ui.separator();
             ui.vertical(|ui| {
-                let mut rlvl = RLVLS[self.recipe_config.recipe.recipe_level as usize];
+                let mut rlvl = RLVLS[raphael_data::effective_recipe_level(
+                    &self.recipe_config.recipe,
+                    self.crafter_config.active_stats().level,
+                ) as usize];
                 ui.add_enabled_ui(!use_base_increase_overrides, |ui| {
                     ui.horizontal(|ui| {
                         ui.label("Progress divider");
@@ -285,6 +462,8 @@ impl<'a> RecipeSelect<'a> {
                         .custom_recipe_overrides_config
                         .use_base_increase_overrides
                     {
+                        // the overrides are for a fixed level
+                        self.recipe_config.recipe.max_level_scaling = 0;
                         custom_recipe_overrides.base_progress_override =
                             Some(default_game_settings.base_progress);
                         custom_recipe_overrides.base_quality_override =
@@ -333,7 +512,6 @@ impl Widget for RecipeSelect<'_> {
 
                                 self.recipe_config.recipe.req_craftsmanship = 0;
                                 self.recipe_config.recipe.req_control = 0;
-                                self.recipe_config.recipe.max_level_scaling = 0;
                                 self.recipe_config.recipe.material_factor = 0;
                                 self.recipe_config.recipe.ingredients = [Ingredient::default(); 6];
 
@@ -0,0 +1,271 @@
+use egui::{Align, Id, Layout};
+use raphael_sim::{Action, Combo, Effects, Settings, SimulationState};
+
+use super::HelpText;
+use crate::{app::SolverConfig, translations::t};
+
+/// Rotation to repair and the settings it was solved for
+pub struct RepairTarget {
+    pub actions: Vec<Action>,
+    pub solver_config: SolverConfig,
+    /// Game settings with the max Quality reduced to what the quality target requires
+    pub game_settings: Settings,
+    /// Game settings of the recipe, which state strings are copied with
+    pub recipe_settings: Settings,
+    pub initial_quality: u16,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct RepairParams {
+    completed_steps: usize,
+    progress: u32,
+    quality: u32,
+    durability: u16,
+    cp: u16,
+    /// Effects such as Inner Quiet stacks and remaining buff durations
+    effects: Effects,
+    /// State pasted as a share string, used instead of the state after the completed steps
+    pasted_state: Option<SimulationState>,
+}
+
+impl Default for RepairParams {
+    fn default() -> Self {
+        Self {
+            completed_steps: 0,
+            progress: 0,
+            quality: 0,
+            durability: 0,
+            cp: 0,
+            effects: Effects::new(),
+            pasted_state: None,
+        }
+    }
+}
+
+/// Window in which the in-game state of a craft that went off plan is entered, to solve the rest of the craft from it
+#[derive(Debug, Default)]
+pub struct RepairWindow {
+    pub open: bool,
+    params: RepairParams,
+    state_string: String,
+}
+
+impl RepairWindow {
+    /// Opens the window with the state after the first half of the rotation
+    pub fn show(&mut self, target: &RepairTarget) {
+        self.open = true;
+        self.reset(target, target.actions.len() / 2);
+    }
+
+    fn reset(&mut self, target: &RepairTarget, completed_steps: usize) {
+        let game_settings = &target.game_settings;
+        let completed_steps = std::cmp::min(completed_steps, target.actions.len());
+        let (state, _) = target
+            .solver_config
+            .initial_state(game_settings)
+            .continue_macro_in_conditions(game_settings, &target.actions[..completed_steps], &[]);
+        self.params = RepairParams {
+            completed_steps,
+            progress: state.progress,
+            quality: u32::from(target.initial_quality) + state.quality,
+            durability: state.durability,
+            cp: state.cp,
+            effects: state.effects,
+            pasted_state: None,
+        };
+        self.state_string = String::new();
+    }
+
+    fn paste_state(&mut self, target: &RepairTarget) -> Result<(), &'static str> {
+        let state =
+            SimulationState::from_share_string(&self.state_string, &target.recipe_settings)?;
+        self.params = RepairParams {
+            completed_steps: 0,
+            progress: state.progress,
+            quality: u32::from(target.initial_quality) + state.quality,
+            durability: state.durability,
+            cp: state.cp,
+            effects: state.effects,
+            pasted_state: Some(state),
+        };
+        Ok(())
+    }
+
+    /// Actions that went as planned and the entered state after them, from which the rest of the craft is solved
+    pub fn repaired_state(&self, target: &RepairTarget) -> (Vec<Action>, SimulationState) {
+        let game_settings = &target.game_settings;
+        let params = self.params;
+        let action_prefix = target.actions[..params.completed_steps].to_vec();
+        let mut state = params.pasted_state.unwrap_or_else(|| {
+            target
+                .solver_config
+                .initial_state(game_settings)
+                .continue_macro_in_conditions(game_settings, &action_prefix, &[])
+                .0
+        });
+        state.progress = params.progress;
+        state.quality = params
+            .quality
+            .saturating_sub(u32::from(target.initial_quality));
+        state.unreliable_quality = 0;
+        state.durability = params.durability;
+        state.cp = params.cp;
+        state.effects = params.effects;
+        // a state with effects is past the first step even if the steps that applied them aren't part of the rotation, e.g. a manually executed opener
+        if state.effects.combo() == Combo::SynthesisBegin && has_active_buffs(state.effects) {
+            state.effects.set_combo(Combo::None);
+        }
+        (action_prefix, state)
+    }
+
+    /// Draws the window, returns whether the rotation should be repaired from the entered state
+    pub fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        target: &RepairTarget,
+        keep_solver_tables: bool,
+    ) -> bool {
+        let mut repair = false;
+        ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+        ui.set_width(260.0);
+        ui.label(egui::RichText::new(t("Repair rotation")).strong());
+        ui.separator();
+        ui.label(t(
+            "Enter the in-game state after the last action that went as planned.",
+        ));
+        ui.label(
+            egui::RichText::new("Set the effects with 0 completed steps to continue after an opener that was executed manually.")
+                .small(),
+        );
+        let mut completed_steps = self.params.completed_steps;
+        ui.horizontal(|ui| {
+            ui.label(t("Completed steps"));
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                ui.add(egui::DragValue::new(&mut completed_steps).range(0..=target.actions.len()));
+            });
+        });
+        if completed_steps != self.params.completed_steps {
+            self.reset(target, completed_steps);
+        }
+        ui.horizontal(|ui| {
+            ui.label("State string");
+            ui.add(HelpText::new("Paste a state copied from the context menu of an action in the simulator, e.g. when asking someone else how to continue a craft.\nThe state must have been copied with the same recipe, crafter stats and solver settings."));
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut self.state_string)
+                        .desired_width(120.0),
+                );
+                if response.changed() && !self.state_string.trim().is_empty() {
+                    let result = self.paste_state(target);
+                    ui.ctx().data_mut(|data| {
+                        data.insert_temp(Id::new("REPAIR_STATE_STRING_ERROR"), result.err());
+                    });
+                }
+            });
+        });
+        let state_string_error = ui.ctx().data(|data| {
+            data.get_temp::<Option<&'static str>>(Id::new("REPAIR_STATE_STRING_ERROR"))
+                .flatten()
+        });
+        if let Some(error) = state_string_error
+            && !self.state_string.trim().is_empty()
+        {
+            ui.label(
+                egui::RichText::new(format!("⚠ {error}"))
+                    .small()
+                    .color(ui.visuals().warn_fg_color),
+            );
+        }
+        let params = &mut self.params;
+        ui.horizontal(|ui| {
+            ui.label(t("Progress"));
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                ui.add(egui::DragValue::new(&mut params.progress));
+            });
+        });
+        ui.horizontal(|ui| {
+            ui.label(t("Quality"));
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                ui.add(egui::DragValue::new(&mut params.quality));
+            });
+        });
+        ui.horizontal(|ui| {
+            ui.label(t("Durability"));
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                ui.add(egui::DragValue::new(&mut params.durability));
+            });
+        });
+        ui.horizontal(|ui| {
+            ui.label(t("CP"));
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                ui.add(egui::DragValue::new(&mut params.cp));
+            });
+        });
+        ui.collapsing(t("Effects"), |ui| {
+            let effects = &mut params.effects;
+            let mut inner_quiet = effects.inner_quiet();
+            draw_effect_duration(ui, t("Inner Quiet"), &mut inner_quiet, 10);
+            effects.set_inner_quiet(inner_quiet);
+            let mut innovation = effects.innovation();
+            draw_effect_duration(ui, t("Innovation"), &mut innovation, 4);
+            effects.set_innovation(innovation);
+            let mut veneration = effects.veneration();
+            draw_effect_duration(ui, t("Veneration"), &mut veneration, 4);
+            effects.set_veneration(veneration);
+            let mut great_strides = effects.great_strides();
+            draw_effect_duration(ui, t("Great Strides"), &mut great_strides, 3);
+            effects.set_great_strides(great_strides);
+            let mut muscle_memory = effects.muscle_memory();
+            draw_effect_duration(ui, t("Muscle Memory"), &mut muscle_memory, 5);
+            effects.set_muscle_memory(muscle_memory);
+            let mut waste_not = effects.waste_not();
+            draw_effect_duration(ui, t("Waste Not"), &mut waste_not, 8);
+            effects.set_waste_not(waste_not);
+            let mut manipulation = effects.manipulation();
+            draw_effect_duration(ui, t("Manipulation"), &mut manipulation, 8);
+            effects.set_manipulation(manipulation);
+        });
+        if !keep_solver_tables {
+            ui.label(
+                egui::RichText::new("⚠ \"Keep solver tables after solving\" is disabled. Repairing takes as long as a full solve.")
+                    .small()
+                    .color(ui.visuals().warn_fg_color),
+            );
+        }
+        ui.separator();
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered_justified(|ui| {
+                if ui.button(t("Cancel")).clicked() {
+                    self.open = false;
+                }
+            });
+            columns[1].vertical_centered_justified(|ui| {
+                if ui.button(t("Repair")).clicked() {
+                    self.open = false;
+                    repair = true;
+                }
+            });
+        });
+        repair
+    }
+}
+
+/// Remaining duration (or stacks) of an effect, where 0 means inactive
+fn draw_effect_duration(ui: &mut egui::Ui, label: &str, value: &mut u8, max: u8) {
+    ui.horizontal(|ui| {
+        ui.label(label);
+        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+            ui.add(egui::DragValue::new(value).range(0..=max));
+        });
+    });
+}
+
+fn has_active_buffs(effects: Effects) -> bool {
+    effects.inner_quiet() != 0
+        || effects.innovation() != 0
+        || effects.veneration() != 0
+        || effects.great_strides() != 0
+        || effects.muscle_memory() != 0
+        || effects.waste_not() != 0
+        || effects.manipulation() != 0
+}
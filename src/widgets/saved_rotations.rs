@@ -12,6 +12,8 @@ use crate::{
     config::{
         CrafterConfig, CustomRecipeOverridesConfiguration, QualitySource, RecipeConfiguration,
     },
+    font_fallback,
+    util::find_minimum_stats,
 };
 
 use super::util;
@@ -22,6 +24,16 @@ fn generate_unique_rotation_id() -> u64 {
     hasher.finish()
 }
 
+/// Milliseconds since the Unix epoch, comparable between app instances
+fn current_timestamp() -> u64 {
+    web_time::SystemTime::now()
+        .duration_since(web_time::UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_millis() as u64)
+}
+
+/// Number of deleted rotation ids that are remembered so that merging doesn't bring deleted rotations back
+const MAX_DELETED_ROTATIONS: usize = 1000;
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum RecipeInfo {
     NormalRecipe(u32),
@@ -71,9 +83,37 @@ impl SolveInfo {
             solver_config: *solver_config,
         }
     }
+
+    /// State at the start of the craft, with the initial Quality and the starting penalties of the solver config
+    fn initial_state(&self) -> SimulationState {
+        SimulationState {
+            quality: u32::from(self.initial_quality),
+            ..self.solver_config.initial_state(&self.game_settings)
+        }
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Version and options of the solver as shown for saved rotations
+pub fn solver_description(solver_config: &SolverConfig) -> String {
+    format!(
+        "Raphael v{}{}{}",
+        env!("CARGO_PKG_VERSION"),
+        match (
+            solver_config.backload_progress,
+            solver_config.prefer_backload_progress
+        ) {
+            (true, _) => " +backload",
+            (false, true) => " +prefer-backload",
+            (false, false) => "",
+        },
+        match solver_config.adversarial {
+            true => " +adversarial",
+            false => "",
+        },
+    )
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Rotation {
     pub unique_id: u64,
     pub name: String,
@@ -90,6 +130,9 @@ pub struct Rotation {
     pub crafter_stats: CrafterStats,
     #[serde(default)]
     pub minimum_stats: MinimumStats,
+    /// Used to decide which copy to keep when merging the saved rotations of multiple app instances
+    #[serde(default)]
+    pub saved_at: u64,
 }
 
 impl Rotation {
@@ -105,23 +148,15 @@ impl Rotation {
         crafter_config: &CrafterConfig,
         minimum_stats: MinimumStats,
     ) -> Self {
-        let solver_params = format!(
-            "Raphael v{}{}{}",
-            env!("CARGO_PKG_VERSION"),
-            match solver_config.backload_progress {
-                true => " +backload",
-                false => "",
-            },
-            match solver_config.adversarial {
-                true => " +adversarial",
-                false => "",
-            },
+        let initial_quality = crate::util::get_initial_quality(
+            recipe_config,
+            custom_recipe_overrides_configuration,
+            crafter_config,
         );
-        let initial_quality = crate::util::get_initial_quality(recipe_config, crafter_config);
         Self {
             unique_id: generate_unique_rotation_id(),
             name: name.into(),
-            solver: solver_params,
+            solver: solver_description(solver_config),
             actions,
             recipe_info: Some(RecipeInfo::create_from(
                 &recipe_config.recipe,
@@ -137,33 +172,103 @@ impl Rotation {
             potion: potion.map(|consumable| (consumable.item_id, consumable.hq)),
             crafter_stats: *crafter_config.active_stats(),
             minimum_stats,
+            saved_at: current_timestamp(),
         }
     }
 }
 
-impl Clone for Rotation {
-    fn clone(&self) -> Self {
+impl Rotation {
+    /// Copy that is saved as a rotation of its own, e.g. when a rotation of the solve history is pinned or re-solved
+    #[must_use]
+    pub fn new_copy(&self) -> Self {
         Self {
             unique_id: generate_unique_rotation_id(),
-            name: self.name.clone(),
-            solver: self.solver.clone(),
-            actions: self.actions.clone(),
-            recipe_info: self.recipe_info.clone(),
-            quality_source: self.quality_source.clone(),
-            solve_info: self.solve_info.clone(),
-            food: self.food,
-            potion: self.potion,
-            crafter_stats: self.crafter_stats,
-            minimum_stats: self.minimum_stats,
+            saved_at: current_timestamp(),
+            ..self.clone()
         }
     }
+
+    pub fn recipe(&self) -> Option<&Recipe> {
+        self.recipe_info
+            .as_ref()
+            .and_then(|recipe_config| match recipe_config {
+                RecipeInfo::NormalRecipe(recipe_id) => raphael_data::RECIPES.get(recipe_id),
+                RecipeInfo::CustomRecipe(recipe, _) => Some(recipe),
+            })
+    }
+
+    pub fn consumables(&self) -> (Option<Consumable>, Option<Consumable>) {
+        let find_consumable = |consumables: &[Consumable], consumable: Option<(u32, bool)>| {
+            let (item_id, hq) = consumable?;
+            consumables
+                .iter()
+                .find(|item| item.item_id == item_id && item.hq == hq)
+                .copied()
+        };
+        (
+            find_consumable(raphael_data::MEALS, self.food),
+            find_consumable(raphael_data::POTIONS, self.potion),
+        )
+    }
+
+    /// Whether the rotation was solved with worse stats than `crafter_stats`, with the same level and unlocked actions
+    fn is_outdated(&self, crafter_stats: &CrafterStats) -> bool {
+        let saved_stats = &self.crafter_stats;
+        saved_stats.level == crafter_stats.level
+            && saved_stats.manipulation == crafter_stats.manipulation
+            && saved_stats.heart_and_soul == crafter_stats.heart_and_soul
+            && saved_stats.quick_innovation == crafter_stats.quick_innovation
+            && saved_stats.craftsmanship <= crafter_stats.craftsmanship
+            && saved_stats.control <= crafter_stats.control
+            && saved_stats.cp <= crafter_stats.cp
+            && saved_stats != crafter_stats
+    }
+
+    /// Re-solved rotation with the actions that were found for it, the minimum stats are updated to match the actions
+    pub fn with_resolved_actions(&self, actions: Vec<Action>) -> Self {
+        let mut rotation = self.clone();
+        rotation.minimum_stats = match (
+            &rotation.solve_info,
+            rotation.recipe(),
+            &rotation.recipe_info,
+        ) {
+            (_, _, Some(RecipeInfo::CustomRecipe(_, config)))
+                if config.use_base_increase_overrides =>
+            {
+                MinimumStats::default()
+            }
+            (Some(solve_info), Some(recipe), _) => {
+                let target_quality = solve_info
+                    .solver_config
+                    .quality_target
+                    .get_target(solve_info.game_settings.max_quality)
+                    .saturating_sub(solve_info.initial_quality);
+                find_minimum_stats(
+                    solve_info.game_settings,
+                    &solve_info.solver_config,
+                    u32::from(target_quality),
+                    &actions,
+                    recipe,
+                )
+            }
+            _ => MinimumStats::default(),
+        };
+        rotation.actions = actions;
+        rotation
+    }
 }
 
 impl PartialEq for Rotation {
     fn eq(&self, other: &Self) -> bool {
-        // unique_id & name are skipped
+        self.actions == other.actions && self.has_same_setup(other)
+    }
+}
+
+impl Rotation {
+    /// Whether everything but the actions is the same
+    fn has_same_setup(&self, other: &Self) -> bool {
+        // unique_id, name & saved_at are skipped
         self.solver == other.solver
-            && self.actions == other.actions
             && self.recipe_info == other.recipe_info
             && self.quality_source == other.quality_source
             && self.solve_info == other.solve_info
@@ -172,6 +277,80 @@ impl PartialEq for Rotation {
             && self.crafter_stats == other.crafter_stats
             && self.minimum_stats == other.minimum_stats
     }
+
+    /// Whether the rotations are the same up to the order of actions that can be swapped without changing the outcome, e.g. two buffs used back to back.
+    /// Rotations without solve info can't be simulated and are only equivalent if they are equal.
+    fn is_equivalent(&self, other: &Self) -> bool {
+        if !self.has_same_setup(other) {
+            return false;
+        }
+        if self.actions == other.actions {
+            return true;
+        }
+        let Some(solve_info) = &self.solve_info else {
+            return false;
+        };
+        // canonicalizing simulates the rotation many times, so rotations that don't use the same actions are ruled out first
+        let sorted_actions = |actions: &[Action]| {
+            let mut actions = actions.to_vec();
+            actions.sort_by_key(|&action| action as u8);
+            actions
+        };
+        sorted_actions(&self.actions) == sorted_actions(&other.actions)
+            && canonical_actions(&self.actions, solve_info)
+                == canonical_actions(&other.actions, solve_info)
+    }
+}
+
+/// Splits the rotation into runs of pairwise-commuting actions and sorts each run by action id,
+/// so that rotations that only differ in the order of commuting actions end up with the same actions.
+///
+/// Two actions commute if swapping them doesn't change the state the rotation ends in, simulated from the real initial state of the craft.
+/// Each run holds every action that can be moved to the front of the actions left, as long as they pairwise commute.
+/// Rotations that can't be simulated without errors are left as they are.
+fn canonical_actions(actions: &[Action], solve_info: &SolveInfo) -> Vec<Action> {
+    let simulate = |actions: &[Action]| {
+        let (state, errors) = solve_info.initial_state().continue_macro_in_conditions(
+            &solve_info.game_settings,
+            actions,
+            &solve_info.solver_config.opener_conditions,
+        );
+        errors.iter().all(Result::is_ok).then_some(state)
+    };
+    let Some(final_state) = simulate(actions) else {
+        return actions.to_vec();
+    };
+    let ends_in_final_state = |actions: &[Action]| simulate(actions) == Some(final_state);
+    let mut canonical = Vec::with_capacity(actions.len());
+    let mut remaining = actions.to_vec();
+    while !remaining.is_empty() {
+        // actions that can be moved in front of all the remaining actions before them
+        let (run, rest): (Vec<usize>, Vec<usize>) = (0..remaining.len()).partition(|&index| {
+            let mut reordered = canonical.clone();
+            reordered.push(remaining[index]);
+            reordered.extend(remaining[..index].iter().chain(&remaining[index + 1..]));
+            ends_in_final_state(&reordered)
+        });
+        let mut run: Vec<Action> = run.into_iter().map(|index| remaining[index]).collect();
+        let rest: Vec<Action> = rest.into_iter().map(|index| remaining[index]).collect();
+        run.sort_by_key(|&action| action as u8);
+        let arrangement = |run: &[Action]| [canonical.as_slice(), run, rest.as_slice()].concat();
+        let is_commuting = (0..run.len()).all(|first| {
+            (first + 1..run.len()).all(|second| {
+                let mut swapped = run.clone();
+                swapped.swap(first, second);
+                ends_in_final_state(&arrangement(&swapped))
+            })
+        }) && ends_in_final_state(&arrangement(&run));
+        if is_commuting {
+            canonical.extend(run);
+            remaining = rest;
+        } else {
+            // the first action can always be taken on its own
+            canonical.push(remaining.remove(0));
+        }
+    }
+    canonical
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -195,6 +374,59 @@ impl std::fmt::Display for LoadOperation {
     }
 }
 
+impl LoadOperation {
+    fn loads_recipe(self) -> bool {
+        self != Self::LoadRotation
+    }
+
+    fn loads_consumables(self) -> bool {
+        matches!(
+            self,
+            Self::LoadRotationRecipeConsumables | Self::LoadRotationRecipeConsumablesConfiguration
+        )
+    }
+
+    fn loads_configuration(self) -> bool {
+        self == Self::LoadRotationRecipeConsumablesConfiguration
+    }
+}
+
+/// A setting that loading a saved rotation overwrites, with its current and its saved value
+struct SettingChange {
+    setting: &'static str,
+    current: String,
+    saved: String,
+}
+
+fn quality_source_description(quality_source: QualitySource) -> String {
+    match quality_source {
+        QualitySource::HqMaterialList(hq_materials) => format!(
+            "HQ materials {}",
+            hq_materials.map(|amount| amount.to_string()).join("/")
+        ),
+        QualitySource::Value(quality) => quality.to_string(),
+        QualitySource::Percentage(percentage) => format!("{percentage}%"),
+    }
+}
+
+fn crafter_stats_description(stats: &CrafterStats) -> String {
+    let mut description = format!(
+        "Level {}, {} CMS, {} Control, {} CP",
+        stats.level, stats.craftsmanship, stats.control, stats.cp
+    );
+    for (unlocked, action) in [
+        (stats.manipulation, "Manipulation"),
+        (stats.heart_and_soul, "Heart and Soul"),
+        (stats.quick_innovation, "Quick Innovation"),
+    ] {
+        if unlocked {
+            description.push_str(", ");
+            description.push_str(action);
+        }
+    }
+    description
+}
+
 impl Default for LoadOperation {
     fn default() -> Self {
         Self::LoadRotation
@@ -222,14 +454,41 @@ impl Default for SavedRotationsConfig {
 pub struct SavedRotationsData {
     pinned: Vec<Rotation>,
     solve_history: VecDeque<Rotation>,
+    #[serde(default)]
+    deleted_rotations: Vec<u64>,
+}
+
+/// Adds `other_rotations` that are neither deleted nor already in `rotations`.
+/// Rotations with the same id or the same content are considered the same, the more recently saved one is kept.
+fn merge_rotations(
+    rotations: &mut Vec<Rotation>,
+    other_rotations: impl IntoIterator<Item = Rotation>,
+    deleted_rotations: &[u64],
+) {
+    for other_rotation in other_rotations {
+        if deleted_rotations.contains(&other_rotation.unique_id) {
+            continue;
+        }
+        match rotations.iter_mut().find(|rotation| {
+            rotation.unique_id == other_rotation.unique_id || **rotation == other_rotation
+        }) {
+            Some(rotation) => {
+                if other_rotation.saved_at > rotation.saved_at {
+                    *rotation = other_rotation;
+                }
+            }
+            None => rotations.push(other_rotation),
+        }
+    }
 }
 
 impl SavedRotationsData {
+    /// Adds the rotation to the front of the solve history, replacing an equivalent rotation that was solved before
     pub fn add_solved_rotation(&mut self, rotation: Rotation, config: &SavedRotationsConfig) {
         if let Some(index) = self
             .solve_history
             .iter()
-            .position(|saved_rotation| *saved_rotation == rotation)
+            .position(|saved_rotation| saved_rotation.is_equivalent(&rotation))
         {
             self.solve_history.remove(index);
         }
@@ -240,6 +499,75 @@ impl SavedRotationsData {
         }
     }
 
+    fn mark_deleted(&mut self, unique_id: u64) {
+        self.deleted_rotations.push(unique_id);
+        let excess = self
+            .deleted_rotations
+            .len()
+            .saturating_sub(MAX_DELETED_ROTATIONS);
+        self.deleted_rotations.drain(..excess);
+    }
+
+    /// Merges rotations saved by another app instance into this one, so that neither instance drops rotations of the other when saving.
+    pub fn merge(&mut self, other: Self, config: &SavedRotationsConfig) {
+        for unique_id in other.deleted_rotations {
+            if !self.deleted_rotations.contains(&unique_id) {
+                self.mark_deleted(unique_id);
+            }
+        }
+        let deleted_rotations = &self.deleted_rotations;
+        self.pinned
+            .retain(|rotation| !deleted_rotations.contains(&rotation.unique_id));
+        merge_rotations(&mut self.pinned, other.pinned, deleted_rotations);
+
+        let mut solve_history: Vec<_> = std::mem::take(&mut self.solve_history)
+            .into_iter()
+            .filter(|rotation| !deleted_rotations.contains(&rotation.unique_id))
+            .collect();
+        merge_rotations(&mut solve_history, other.solve_history, deleted_rotations);
+        solve_history.sort_by_key(|rotation| std::cmp::Reverse(rotation.saved_at));
+        solve_history.truncate(config.max_history_size);
+        self.solve_history = solve_history.into();
+    }
+
+    /// Ids of the rotations that were solved with worse stats than the current stats of their job.
+    /// Only rotations that can be solved again, i.e. that have a recipe and solve info, are included.
+    pub fn outdated_rotations(&self, crafter_config: &CrafterConfig) -> Vec<u64> {
+        self.pinned
+            .iter()
+            .chain(self.solve_history.iter())
+            .filter(|rotation| {
+                rotation.solve_info.is_some()
+                    && rotation.recipe().is_some_and(|recipe| {
+                        rotation
+                            .is_outdated(&crafter_config.crafter_stats[usize::from(recipe.job_id)])
+                    })
+            })
+            .map(|rotation| rotation.unique_id)
+            .collect()
+    }
+
+    pub fn get(&self, unique_id: u64) -> Option<&Rotation> {
+        self.pinned
+            .iter()
+            .chain(self.solve_history.iter())
+            .find(|rotation| rotation.unique_id == unique_id)
+    }
+
+    /// Replaces a rotation with its re-solved version, which keeps its place among the saved macros or in the solve history
+    pub fn replace_rotation(&mut self, unique_id: u64, rotation: Rotation) {
+        if let Some(saved_rotation) = self
+            .pinned
+            .iter_mut()
+            .chain(self.solve_history.iter_mut())
+            .find(|saved_rotation| saved_rotation.unique_id == unique_id)
+        {
+            *saved_rotation = rotation;
+            // otherwise merging with another app instance would bring the old rotation back
+            self.mark_deleted(unique_id);
+        }
+    }
+
     pub fn find_solved_rotation(
         &self,
         game_settings: &Settings,
@@ -265,6 +593,25 @@ impl SavedRotationsData {
             self.pinned.iter().find_map(find_and_map_rotation)
         }
     }
+
+    /// A rotation for the same recipe to warm-start the solver with, preferring one that was solved with the same settings.
+    /// Rotations solved with other stats or options are fine, since the solver ignores rotations that don't work with the current settings.
+    pub fn find_warm_start_rotation(
+        &self,
+        recipe_info: &RecipeInfo,
+        game_settings: &Settings,
+        initial_quality: u16,
+        solver_config: &SolverConfig,
+    ) -> Option<Vec<Action>> {
+        self.find_solved_rotation(game_settings, initial_quality, solver_config)
+            .or_else(|| {
+                self.solve_history
+                    .iter()
+                    .chain(self.pinned.iter())
+                    .find(|rotation| rotation.recipe_info.as_ref() == Some(recipe_info))
+                    .map(|rotation| rotation.actions.clone())
+            })
+    }
 }
 
 struct RotationWidget<'a> {
@@ -320,16 +667,16 @@ impl<'a> RotationWidget<'a> {
     fn show_rotation_title(&mut self, ui: &mut egui::Ui, collapsed: &mut bool) {
         ui.horizontal(|ui| {
             util::collapse_temporary(ui, self.id_salt("collapsed").into(), collapsed);
-            ui.label(egui::RichText::new(
-                match &self.rotation.recipe_info {
-                    Some(RecipeInfo::NormalRecipe(recipe_id)) => {
-                        raphael_data::RECIPES.get(recipe_id).and_then(|recipe| {
-                            raphael_data::get_recipe_name(recipe, false, self.locale)
-                        })
-                    }
-                    _ => None,
-                }.unwrap_or(self.rotation.name.clone())
-            ).strong());
+            let title = match &self.rotation.recipe_info {
+                Some(RecipeInfo::NormalRecipe(recipe_id)) => raphael_data::RECIPES
+                    .get(recipe_id)
+                    .and_then(|recipe| raphael_data::get_recipe_name(recipe, false, self.locale)),
+                _ => None,
+            }
+            .unwrap_or(self.rotation.name.clone());
+            font_fallback::request_glyphs(ui.ctx(), &title);
+            ui.label(egui::RichText::new(title).strong())
+                .on_hover_ui(|ui| self.show_rotation_preview(ui));
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 if ui.add(egui::Button::new("🗑")).clicked() {
                     *self.deleted = true;
@@ -344,7 +691,7 @@ impl<'a> RotationWidget<'a> {
                 ui.add_space(-3.0);
                 let load_button_response = ui.button("Load");
                 let mut selected_load_operation = None;
-                load_button_response.context_menu(|ui| {
+                util::context_menu(&load_button_response, false, |ui| {
                     if ui.input(|i| i.key_pressed(egui::Key::Escape)) {
                         ui.close();
                     }
@@ -374,25 +721,11 @@ impl<'a> RotationWidget<'a> {
                     selected_load_operation = Some(self.config.default_load_operation);
                 }
                 if let Some(load_operation) = selected_load_operation {
-                    match load_operation {
-                        LoadOperation::LoadRotation => {
-                            self.actions.clone_from(&self.rotation.actions);
-                        }
-                        LoadOperation::LoadRotationRecipe => {
-                            self.actions.clone_from(&self.rotation.actions);
-                            self.load_saved_recipe();
-                        }
-                        LoadOperation::LoadRotationRecipeConsumables => {
-                            self.actions.clone_from(&self.rotation.actions);
-                            self.load_saved_recipe();
-                            self.load_saved_consumables();
-                        }
-                        LoadOperation::LoadRotationRecipeConsumablesConfiguration => {
-                            self.actions.clone_from(&self.rotation.actions);
-                            self.load_saved_recipe();
-                            self.load_saved_consumables();
-                            self.load_saved_configuration();
-                        }
+                    match self.setting_changes(load_operation).is_empty() {
+                        true => self.load(load_operation),
+                        false => ui.data_mut(|data| {
+                            data.insert_temp(self.id_salt("pending_load").into(), load_operation)
+                        }),
                     }
                 }
                 let duration = self
@@ -406,7 +739,28 @@ impl<'a> RotationWidget<'a> {
                     self.rotation.actions.len(),
                     duration
                 ));
-                if self.rotation.minimum_stats.cp.is_some() {
+                let computed_minimum_stats_id = egui::Id::new(self.id_salt("minimum_stats"));
+                let minimum_stats = match self.rotation.minimum_stats.cp {
+                    Some(_) => Some(self.rotation.minimum_stats),
+                    None => {
+                        ui.data(|data| data.get_temp::<MinimumStats>(computed_minimum_stats_id))
+                    }
+                };
+                if minimum_stats.is_none()
+                    && self.rotation.solve_info.is_some()
+                    && ui
+                        .small_button("Min stats")
+                        .on_hover_text(
+                            "Compute the minimum Craftsmanship, Control and CP this macro needs",
+                        )
+                        .clicked()
+                {
+                    let minimum_stats = self.compute_minimum_stats();
+                    ui.data_mut(|data| data.insert_temp(computed_minimum_stats_id, minimum_stats));
+                }
+                if let Some(minimum_stats) = minimum_stats
+                    && minimum_stats.cp.is_some()
+                {
                     ui.add_space(-5.0);
                     let display = |stat: Option<u16>| -> String {
                         match stat {
@@ -416,9 +770,9 @@ impl<'a> RotationWidget<'a> {
                     };
                     ui.label(format!(
                         "{}/{}/{},",
-                        display(self.rotation.minimum_stats.craftsmanship),
-                        display(self.rotation.minimum_stats.control),
-                        display(self.rotation.minimum_stats.cp),
+                        display(minimum_stats.craftsmanship),
+                        display(minimum_stats.control),
+                        display(minimum_stats.cp),
                     ));
                 }
                 if let Some(solve) = &self.rotation.solve_info && solve.initial_quality > 0 {
@@ -429,6 +783,231 @@ impl<'a> RotationWidget<'a> {
         });
     }
 
+    /// Minimum stats from the settings the rotation was solved with, for rotations that were saved without them.
+    /// Rotations that don't complete, have no solve info or use base increase overrides have no minimum stats.
+    fn compute_minimum_stats(&self) -> MinimumStats {
+        let (Some(solve_info), Some(recipe)) = (&self.rotation.solve_info, self.get_recipe())
+        else {
+            return MinimumStats::default();
+        };
+        if let Some(RecipeInfo::CustomRecipe(_, config)) = &self.rotation.recipe_info
+            && config.use_base_increase_overrides
+        {
+            return MinimumStats::default();
+        }
+        let target_quality = solve_info
+            .solver_config
+            .quality_target
+            .get_target(solve_info.game_settings.max_quality)
+            .saturating_sub(solve_info.initial_quality);
+        find_minimum_stats(
+            solve_info.game_settings,
+            &solve_info.solver_config,
+            u32::from(target_quality),
+            &self.rotation.actions,
+            recipe,
+        )
+    }
+
+    fn load(&mut self, load_operation: LoadOperation) {
+        self.actions.clone_from(&self.rotation.actions);
+        if load_operation.loads_recipe() {
+            self.load_saved_recipe();
+        }
+        if load_operation.loads_consumables() {
+            self.load_saved_consumables();
+        }
+        if load_operation.loads_configuration() {
+            self.load_saved_configuration();
+        }
+    }
+
+    /// Settings that `load_operation` would change, empty if it only replaces the actions
+    fn setting_changes(&self, load_operation: LoadOperation) -> Vec<SettingChange> {
+        let mut changes = Vec::new();
+        let mut push = |setting, changed: bool, current: String, saved: String| {
+            if changed {
+                changes.push(SettingChange {
+                    setting,
+                    current,
+                    saved,
+                });
+            }
+        };
+        let recipe_name = |recipe: &Recipe| {
+            raphael_data::get_recipe_name(recipe, false, self.locale)
+                .unwrap_or("Unknown item".to_owned())
+        };
+        if load_operation.loads_recipe() {
+            let current_recipe = &self.recipe_config.recipe;
+            if let Some(recipe) = self.rotation.recipe() {
+                push(
+                    "Recipe",
+                    current_recipe != recipe,
+                    recipe_name(current_recipe),
+                    recipe_name(recipe),
+                );
+                push(
+                    "Job",
+                    self.crafter_config.selected_job != recipe.job_id,
+                    raphael_data::get_job_name(self.crafter_config.selected_job, self.locale)
+                        .to_owned(),
+                    raphael_data::get_job_name(recipe.job_id, self.locale).to_owned(),
+                );
+            }
+            let saved_overrides = match &self.rotation.recipe_info {
+                Some(RecipeInfo::CustomRecipe(_, custom_recipe_overrides_config)) => {
+                    Some(*custom_recipe_overrides_config)
+                }
+                Some(RecipeInfo::NormalRecipe(_)) => Some(CustomRecipeOverridesConfiguration {
+                    use_custom_recipe: false,
+                    ..*self.custom_recipe_overrides_config
+                }),
+                None => None,
+            };
+            if let Some(saved_overrides) = saved_overrides {
+                let description = |use_custom_recipe: bool| match use_custom_recipe {
+                    true => "On",
+                    false => "Off",
+                };
+                let current_description =
+                    description(self.custom_recipe_overrides_config.use_custom_recipe);
+                let saved_description = description(saved_overrides.use_custom_recipe);
+                push(
+                    "Custom recipe",
+                    *self.custom_recipe_overrides_config != saved_overrides,
+                    current_description.to_owned(),
+                    match current_description == saved_description {
+                        // only the overrides differ
+                        true => format!("{saved_description}, with the saved overrides"),
+                        false => saved_description.to_owned(),
+                    },
+                );
+            }
+            let current_quality_source = self.recipe_config.quality_source;
+            let saved_quality_source =
+                match (&self.rotation.quality_source, &self.rotation.recipe_info) {
+                    (Some(quality_source), _) => *quality_source,
+                    (None, Some(RecipeInfo::CustomRecipe(..))) => QualitySource::Value(0),
+                    (None, _) => match self.rotation.recipe() {
+                        Some(recipe) if recipe.item_id != current_recipe.item_id => {
+                            QualitySource::HqMaterialList([0; 6])
+                        }
+                        _ => current_quality_source,
+                    },
+                };
+            push(
+                "Initial quality",
+                current_quality_source != saved_quality_source,
+                quality_source_description(current_quality_source),
+                quality_source_description(saved_quality_source),
+            );
+        }
+        if load_operation.loads_consumables() {
+            let (food, potion) = self.rotation.consumables();
+            let name = |consumable: Option<Consumable>| {
+                self.get_consumable_name(
+                    consumable.map(|consumable| (consumable.item_id, consumable.hq)),
+                )
+            };
+            push(
+                "Food",
+                *self.selected_food != food,
+                name(*self.selected_food),
+                name(food),
+            );
+            push(
+                "Potion",
+                *self.selected_potion != potion,
+                name(*self.selected_potion),
+                name(potion),
+            );
+        }
+        if load_operation.loads_configuration() {
+            let current_stats = self.crafter_config.active_stats();
+            push(
+                "Crafter stats",
+                *current_stats != self.rotation.crafter_stats,
+                crafter_stats_description(current_stats),
+                crafter_stats_description(&self.rotation.crafter_stats),
+            );
+            if let Some(solve_info) = &self.rotation.solve_info {
+                push(
+                    "Solver",
+                    *self.solver_config != solve_info.solver_config,
+                    solver_description(self.solver_config),
+                    solver_description(&solve_info.solver_config),
+                );
+            }
+        }
+        changes
+    }
+
+    /// Lists the settings that the pending load operation would change, which is either confirmed, reduced to loading the actions only, or cancelled
+    fn show_load_confirmation(&mut self, ctx: &egui::Context) {
+        let pending_load_id = egui::Id::new(self.id_salt("pending_load"));
+        let Some(load_operation) = ctx.data(|data| data.get_temp::<LoadOperation>(pending_load_id))
+        else {
+            return;
+        };
+        let mut selected_load_operation = None;
+        let mut cancelled = false;
+        let modal =
+            egui::Modal::new(egui::Id::new(self.id_salt("load_confirmation"))).show(ctx, |ui| {
+                ui.style_mut().spacing.item_spacing = egui::vec2(3.0, 3.0);
+                ui.label(egui::RichText::new(load_operation.to_string()).strong());
+                ui.separator();
+                ui.label("This will change the following settings:");
+                egui::Grid::new(self.id_salt("load_confirmation_changes"))
+                    .spacing(egui::vec2(8.0, 3.0))
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for change in self.setting_changes(load_operation) {
+                            ui.label(change.setting);
+                            ui.label(change.current);
+                            ui.label("→");
+                            ui.label(change.saved);
+                            ui.end_row();
+                        }
+                    });
+                ui.separator();
+                ui.columns(3, |columns| {
+                    columns[0].vertical_centered_justified(|ui| {
+                        if ui.button("Cancel").clicked() {
+                            cancelled = true;
+                        }
+                    });
+                    columns[1].vertical_centered_justified(|ui| {
+                        if ui
+                            .button("Actions only")
+                            .on_hover_text(
+                                "Load the actions of the rotation and keep all current settings",
+                            )
+                            .clicked()
+                        {
+                            selected_load_operation = Some(LoadOperation::LoadRotation);
+                        }
+                    });
+                    columns[2].vertical_centered_justified(|ui| {
+                        if ui.button("Load").clicked() {
+                            selected_load_operation = Some(load_operation);
+                        }
+                    });
+                });
+            });
+        if crate::app::modal_key_pressed(&modal, egui::Key::Escape) {
+            cancelled = true;
+        } else if crate::app::modal_key_pressed(&modal, egui::Key::Enter) {
+            selected_load_operation = Some(load_operation);
+        }
+        if let Some(load_operation) = selected_load_operation {
+            self.load(load_operation);
+        }
+        if cancelled || selected_load_operation.is_some() {
+            ctx.data_mut(|data| data.remove_temp::<LoadOperation>(pending_load_id));
+        }
+    }
+
     fn load_saved_recipe(&mut self) {
         if let Some(recipe_configuration) = &self.rotation.recipe_info {
             match recipe_configuration {
@@ -438,6 +1017,7 @@ impl<'a> RotationWidget<'a> {
                             self.recipe_config.quality_source =
                                 QualitySource::HqMaterialList([0; 6]);
                         }
+                        self.recipe_config.recipe_id = *recipe_id;
                         self.recipe_config.recipe = *recipe;
                         self.crafter_config.selected_job = recipe.job_id;
                         self.custom_recipe_overrides_config.use_custom_recipe = false;
@@ -449,6 +1029,7 @@ impl<'a> RotationWidget<'a> {
                     *self.recipe_config = RecipeConfiguration {
                         recipe: *recipe,
                         quality_source: QualitySource::Value(0),
+                        ..*self.recipe_config
                     };
                     *self.custom_recipe_overrides_config = *custom_recipe_overrides_config;
                     self.crafter_config.selected_job = recipe.job_id;
@@ -461,18 +1042,7 @@ impl<'a> RotationWidget<'a> {
     }
 
     fn load_saved_consumables(&mut self) {
-        *self.selected_food = self.rotation.food.and_then(|(item_id, hq)| {
-            raphael_data::MEALS
-                .iter()
-                .find(|food| food.item_id == item_id && food.hq == hq)
-                .copied()
-        });
-        *self.selected_potion = self.rotation.potion.and_then(|(item_id, hq)| {
-            raphael_data::POTIONS
-                .iter()
-                .find(|potion| potion.item_id == item_id && potion.hq == hq)
-                .copied()
-        });
+        (*self.selected_food, *self.selected_potion) = self.rotation.consumables();
     }
 
     fn load_saved_configuration(&mut self) {
@@ -535,6 +1105,112 @@ impl<'a> RotationWidget<'a> {
         self.show_info_row(ui, "Solver", &self.rotation.solver);
     }
 
+    /// Re-simulates the rotation with the stats of another job, e.g. to check whether a shared macro still works with worse gear
+    fn show_rotation_validation(&self, ui: &mut egui::Ui) {
+        let Some(recipe) = self.get_recipe().copied() else {
+            return;
+        };
+        let id = egui::Id::new(self.id_salt("validation"));
+        let (mut job_id, mut nq_consumables) = ui
+            .data(|data| data.get_temp::<(u8, bool)>(id))
+            .unwrap_or((recipe.job_id, false));
+        ui.horizontal(|ui| {
+            let used_width = ui.label("Validate with").rect.width();
+            ui.add_space(96.0 - used_width);
+            egui::ComboBox::from_id_salt(self.id_salt("validation_job"))
+                .width(40.0)
+                .selected_text(raphael_data::get_job_name(job_id, self.locale))
+                .show_ui(ui, |ui| {
+                    for job in 0..8 {
+                        ui.selectable_value(
+                            &mut job_id,
+                            job,
+                            raphael_data::get_job_name(job, self.locale),
+                        );
+                    }
+                });
+            ui.label("stats");
+            ui.checkbox(&mut nq_consumables, "NQ food and potion");
+        });
+        ui.data_mut(|data| data.insert_temp(id, (job_id, nq_consumables)));
+
+        let crafter_stats = CrafterStats {
+            // the job level and unlocked actions are kept so that only the gear differs
+            craftsmanship: self.crafter_config.crafter_stats[usize::from(job_id)].craftsmanship,
+            control: self.crafter_config.crafter_stats[usize::from(job_id)].control,
+            cp: self.crafter_config.crafter_stats[usize::from(job_id)].cp,
+            ..self.rotation.crafter_stats
+        };
+        let find_consumable = |consumables: &[Consumable], consumable: Option<(u32, bool)>| {
+            let (item_id, hq) = consumable?;
+            consumables
+                .iter()
+                .find(|item| item.item_id == item_id && item.hq == (hq && !nq_consumables))
+                .copied()
+        };
+        let food = find_consumable(raphael_data::MEALS, self.rotation.food);
+        let potion = find_consumable(raphael_data::POTIONS, self.rotation.potion);
+        let custom_recipe_overrides = match &self.rotation.recipe_info {
+            Some(RecipeInfo::CustomRecipe(_, config)) if config.use_custom_recipe => {
+                Some(config.custom_recipe_overrides)
+            }
+            _ => None,
+        };
+        let settings = raphael_data::get_game_settings(
+            recipe,
+            custom_recipe_overrides,
+            crafter_stats,
+            food,
+            potion,
+        );
+        let initial_quality = match self.rotation.quality_source {
+            Some(QualitySource::HqMaterialList(hq_materials)) => {
+                raphael_data::get_initial_quality(crafter_stats, recipe, hq_materials)
+            }
+            Some(QualitySource::Value(quality)) => quality,
+            Some(QualitySource::Percentage(percentage)) => {
+                (u32::from(settings.max_quality) * u32::from(percentage) / 100) as u16
+            }
+            None => self
+                .rotation
+                .solve_info
+                .as_ref()
+                .map_or(0, |solve_info| solve_info.initial_quality),
+        };
+        let (state, errors) = self.rotation.solve_info.as_ref().map_or_else(
+            || SimulationState::from_macro_continue_on_error(&settings, &self.rotation.actions),
+            |solve_info| {
+                solve_info
+                    .solver_config
+                    .simulate(&settings, &self.rotation.actions)
+            },
+        );
+        let failed_actions = errors.iter().filter(|error| error.is_err()).count();
+        let quality = std::cmp::min(
+            u32::from(initial_quality) + state.quality,
+            u32::from(settings.max_quality),
+        );
+        let result = if state.progress < u32::from(settings.max_progress) {
+            egui::RichText::new(format!(
+                "⚠ Doesn't complete ({} / {} progress)",
+                state.progress, settings.max_progress
+            ))
+            .color(ui.visuals().warn_fg_color)
+        } else if failed_actions != 0 {
+            egui::RichText::new(format!(
+                "⚠ Completes, but {} actions fail ({} / {} quality)",
+                failed_actions, quality, settings.max_quality
+            ))
+            .color(ui.visuals().warn_fg_color)
+        } else {
+            egui::RichText::new(format!(
+                "Completes with {} / {} quality",
+                quality, settings.max_quality
+            ))
+        };
+        self.show_info_row(ui, "Result", result);
+    }
+
     fn show_rotation_actions(&self, ui: &mut egui::Ui) {
         let job_id = self.get_recipe().map_or(0, |recipe| recipe.job_id);
         egui::ScrollArea::horizontal()
@@ -552,14 +1228,50 @@ impl<'a> RotationWidget<'a> {
             });
     }
 
+    /// Shown when hovering the title, so that long rotations can be compared without scrolling through their actions or loading them
+    fn show_rotation_preview(&self, ui: &mut egui::Ui) {
+        ui.style_mut().spacing.item_spacing = egui::vec2(3.0, 3.0);
+        let steps = format!("{} steps", self.rotation.actions.len());
+        match &self.rotation.solve_info {
+            Some(solve_info) => {
+                let settings = &solve_info.game_settings;
+                let initial_state = solve_info.solver_config.initial_state(settings);
+                let (state, _) = solve_info
+                    .solver_config
+                    .simulate(settings, &self.rotation.actions);
+                let quality = std::cmp::min(
+                    u32::from(solve_info.initial_quality) + state.quality,
+                    u32::from(settings.max_quality),
+                );
+                ui.label(format!(
+                    "{} / {} quality, {}, {} / {} CP",
+                    quality,
+                    settings.max_quality,
+                    steps,
+                    initial_state.cp - state.cp,
+                    initial_state.cp
+                ));
+            }
+            None => {
+                ui.label(steps);
+            }
+        }
+        ui.separator();
+        let job_id = self.get_recipe().map_or(0, |recipe| recipe.job_id);
+        // ten icons per row, including the spacing between them
+        ui.set_max_width(10.0 * 33.0);
+        ui.horizontal_wrapped(|ui| {
+            for action in &self.rotation.actions {
+                let image = util::get_action_icon(*action, job_id)
+                    .fit_to_exact_size(egui::Vec2::new(30.0, 30.0))
+                    .corner_radius(4.0);
+                ui.add(image);
+            }
+        });
+    }
+
     fn get_recipe(&self) -> Option<&Recipe> {
-        self.rotation
-            .recipe_info
-            .as_ref()
-            .and_then(|recipe_config| match recipe_config {
-                RecipeInfo::NormalRecipe(recipe_id) => raphael_data::RECIPES.get(recipe_id),
-                RecipeInfo::CustomRecipe(recipe, _) => Some(recipe),
-            })
+        self.rotation.recipe()
     }
 }
 
@@ -570,9 +1282,12 @@ impl egui::Widget for RotationWidget<'_> {
             ui.vertical(|ui| {
                 let mut collapsed = true;
                 self.show_rotation_title(ui, &mut collapsed);
+                self.show_load_confirmation(ui.ctx());
                 if !collapsed {
                     ui.separator();
                     self.show_rotation_info(ui);
+                    ui.separator();
+                    self.show_rotation_validation(ui);
                 }
                 ui.separator();
                 self.show_rotation_actions(ui);
@@ -670,6 +1385,7 @@ impl egui::Widget for SavedRotationsWidget<'_> {
                     if self.rotations.pinned.is_empty() {
                         ui.label("No saved macros");
                     }
+                    let mut deleted_rotations = Vec::new();
                     self.rotations.pinned.retain(|rotation| {
                         let mut deleted = false;
                         ui.add(RotationWidget::new(
@@ -686,8 +1402,14 @@ impl egui::Widget for SavedRotationsWidget<'_> {
                             self.selected_food,
                             self.selected_potion,
                         ));
+                        if deleted {
+                            deleted_rotations.push(rotation.unique_id);
+                        }
                         !deleted
                     });
+                    for unique_id in deleted_rotations {
+                        self.rotations.mark_deleted(unique_id);
+                    }
                 });
 
                 ui.add_space(5.0);
@@ -723,6 +1445,7 @@ impl egui::Widget for SavedRotationsWidget<'_> {
                     if self.rotations.solve_history.is_empty() {
                         ui.label("No solve history");
                     }
+                    let mut deleted_rotations = Vec::new();
                     self.rotations.solve_history.retain(|rotation| {
                         let mut pinned = false;
                         let mut deleted = false;
@@ -747,13 +1470,109 @@ impl egui::Widget for SavedRotationsWidget<'_> {
                                     .collect();
                                 self.rotations.pinned.append(&mut item_same);
                             }
-                            self.rotations.pinned.push(rotation.clone());
+                            self.rotations.pinned.push(rotation.new_copy());
+                        }
+                        if pinned || deleted {
+                            // pinned rotations are copied, so the original is deleted from the history
+                            deleted_rotations.push(rotation.unique_id);
                         }
                         !pinned && !deleted
                     });
+                    for unique_id in deleted_rotations {
+                        self.rotations.mark_deleted(unique_id);
+                    }
                 });
             });
         })
         .response
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use raphael_sim::{Action, ActionMask, Settings, TraitOverrides};
+
+    use super::{SolveInfo, canonical_actions};
+    use crate::app::SolverConfig;
+
+    const SETTINGS: Settings = Settings {
+        max_cp: 600,
+        max_durability: 70,
+        max_progress: 3000,
+        max_quality: 12000,
+        base_progress: 250,
+        base_quality: 260,
+        job_level: 100,
+        allowed_actions: ActionMask::all(),
+        adversarial: false,
+        backload_progress: false,
+        traits: TraitOverrides::none(),
+    };
+
+    /// Great Strides and Innovation (index 8 and 9) both only affect Byregot's Blessing before they run out
+    const ROTATION: [Action; 15] = [
+        Action::Reflect,
+        Action::Manipulation,
+        Action::WasteNot,
+        Action::Innovation,
+        Action::BasicTouch,
+        Action::StandardTouch,
+        Action::AdvancedTouch,
+        Action::PrudentTouch,
+        Action::GreatStrides,
+        Action::Innovation,
+        Action::ByregotsBlessing,
+        Action::Veneration,
+        Action::Groundwork,
+        Action::Groundwork,
+        Action::CarefulSynthesis,
+    ];
+
+    fn swapped(first: usize, second: usize) -> Vec<Action> {
+        let mut actions = ROTATION.to_vec();
+        actions.swap(first, second);
+        actions
+    }
+
+    #[test]
+    fn test_commuting_buffs() {
+        let solve_info = SolveInfo::new(&SETTINGS, 0, &SolverConfig::default());
+        let canonical = canonical_actions(&ROTATION, &solve_info);
+        assert_eq!(canonical_actions(&swapped(8, 9), &solve_info), canonical);
+        assert_eq!(canonical_actions(&canonical, &solve_info), canonical);
+        // the initial Quality doesn't change which actions commute
+        let solve_info = SolveInfo::new(&SETTINGS, 1000, &SolverConfig::default());
+        assert_eq!(canonical_actions(&ROTATION, &solve_info), canonical);
+        assert_eq!(canonical_actions(&swapped(8, 9), &solve_info), canonical);
+    }
+
+    #[test]
+    fn test_non_commuting_buffs() {
+        let solve_info = SolveInfo::new(&SETTINGS, 0, &SolverConfig::default());
+        // Innovation only boosts Basic Touch if it is used first
+        assert_ne!(
+            canonical_actions(&swapped(3, 4), &solve_info),
+            canonical_actions(&ROTATION, &solve_info)
+        );
+        // Veneration boosts the Groundworks, Byregot's Blessing ends the Great Strides
+        assert_ne!(
+            canonical_actions(&swapped(10, 11), &solve_info),
+            canonical_actions(&ROTATION, &solve_info)
+        );
+    }
+
+    #[test]
+    fn test_starting_penalties() {
+        let solver_config = SolverConfig {
+            starting_cp_penalty: 400,
+            ..SolverConfig::default()
+        };
+        let solve_info = SolveInfo::new(&SETTINGS, 0, &solver_config);
+        // the rotation runs out of CP, so it isn't reordered
+        assert_eq!(canonical_actions(&ROTATION, &solve_info), ROTATION);
+        assert_eq!(
+            canonical_actions(&swapped(8, 9), &solve_info),
+            swapped(8, 9)
+        );
+    }
+}
@@ -1,9 +1,11 @@
+use std::fmt::Write;
+
 use raphael_data::{Item, Locale, Recipe, Consumable};
-use raphael_sim::{Action, Settings, SimulationState};
+use raphael_sim::{Action, Condition, Effects, IncreaseFactors, Settings, SimulationState};
 
 use crate::{
     app::{SolverConfig, MinimumStats},
-    config::{CrafterConfig, QualityTarget},
+    config::{BarNumberFormat, BarPalette, BarStyle, CrafterConfig, QualityTarget},
 };
 
 use super::{HelpText, util};
@@ -13,13 +15,15 @@ pub struct Simulator<'a> {
     initial_quality: u16,
     solver_config: SolverConfig,
     crafter_config: &'a mut CrafterConfig,
-    actions: &'a [Action],
+    /// Careful Observation can be added to and removed from the rotation from the context menu of its steps
+    actions: &'a mut Vec<Action>,
     recipe: &'a Recipe,
     item: &'a Item,
     food: Option<Consumable>,
     potion: Option<Consumable>,
     minimum_stats: &'a MinimumStats,
     locale: Locale,
+    bar_style: BarStyle,
 }
 
 impl<'a> Simulator<'a> {
@@ -28,13 +32,14 @@ impl<'a> Simulator<'a> {
         initial_quality: u16,
         solver_config: SolverConfig,
         crafter_config: &'a mut CrafterConfig,
-        actions: &'a [Action],
+        actions: &'a mut Vec<Action>,
         recipe: &'a Recipe,
         item: &'a Item,
         food: Option<Consumable>,
         potion: Option<Consumable>,
         minimum_stats: &'a MinimumStats,
         locale: Locale,
+        bar_style: BarStyle,
     ) -> Self {
         Self {
             settings,
@@ -48,7 +53,229 @@ impl<'a> Simulator<'a> {
             food,
             potion,
             minimum_stats,
+            bar_style,
+        }
+    }
+}
+
+/// Change to the rotation picked from the context menu of a step in the simulator
+#[derive(Debug, Clone, Copy)]
+enum CarefulObservationEdit {
+    /// Use Careful Observation before the action at the index
+    Insert(usize),
+    Remove(usize),
+}
+
+/// Number of the step each action is used in, `None` for Careful Observation, which doesn't use up a step
+fn step_numbers(actions: &[Action]) -> impl Iterator<Item = Option<usize>> + '_ {
+    actions.iter().scan(0, |steps, action| {
+        Some((*action != Action::CarefulObservation).then(|| {
+            *steps += 1;
+            *steps
+        }))
+    })
+}
+
+/// Resources used by a rotation, accumulated over the actions that could be executed
+#[derive(Debug, Clone, Copy, Default)]
+struct RotationSummary {
+    steps: u32,
+    cp_spent: u32,
+    cp_restored: u32,
+    durability_consumed: u32,
+    durability_restored: u32,
+    synthesis_actions: u32,
+    touch_actions: u32,
+    other_actions: u32,
+    /// Number of steps that were executed while the buff was active
+    buff_steps: [u32; BUFFS.len()],
+}
+
+/// Stat bonus of the consumables for a base stat
+type StatBonus = fn(u16, &[Option<Consumable>]) -> u16;
+
+/// Remaining duration of a buff
+type BuffDuration = fn(&Effects) -> u8;
+
+const BUFFS: [(&str, BuffDuration); 6] = [
+    ("Innovation", Effects::innovation),
+    ("Great Strides", Effects::great_strides),
+    ("Veneration", Effects::veneration),
+    ("Muscle Memory", Effects::muscle_memory),
+    ("Waste Not", Effects::waste_not),
+    ("Manipulation", Effects::manipulation),
+];
+
+impl RotationSummary {
+    fn new(settings: &Settings, actions: &[Action], solver_config: &SolverConfig) -> Self {
+        let mut summary = Self::default();
+        let mut state = solver_config.initial_state(settings);
+        // an action that fails doesn't use up the step, so the condition only changes after a used one
+        let mut conditions = Condition::sequence(&solver_config.opener_conditions).peekable();
+        for action in actions {
+            // Careful Observation doesn't use up a step and leaves the state unchanged
+            if *action == Action::CarefulObservation {
+                continue;
+            }
+            let condition = *conditions.peek().unwrap();
+            let Ok(next_state) = state.use_action(*action, condition, settings) else {
+                continue;
+            };
+            conditions.next();
+            summary.steps += 1;
+            if next_state.cp < state.cp {
+                summary.cp_spent += u32::from(state.cp - next_state.cp);
+            } else {
+                summary.cp_restored += u32::from(next_state.cp - state.cp);
+            }
+            // Manipulation restores durability after the action is used, so the durability cost is taken from the same action used without Manipulation
+            let mut state_without_manipulation = state;
+            state_without_manipulation.effects.set_manipulation(0);
+            let durability_consumed = state_without_manipulation
+                .use_action(*action, condition, settings)
+                .map_or(0, |without_manipulation| {
+                    state
+                        .durability
+                        .saturating_sub(without_manipulation.durability)
+                });
+            summary.durability_consumed += u32::from(durability_consumed);
+            summary.durability_restored +=
+                u32::from(next_state.durability + durability_consumed - state.durability);
+            let action_data = action.data();
+            if action_data.progress_potency != 0 {
+                summary.synthesis_actions += 1;
+            } else if action_data.quality_potency != 0 {
+                summary.touch_actions += 1;
+            } else {
+                summary.other_actions += 1;
+            }
+            for (buff_steps, (_, buff_duration)) in summary.buff_steps.iter_mut().zip(BUFFS) {
+                if buff_duration(&state.effects) != 0 {
+                    *buff_steps += 1;
+                }
+            }
+            state = next_state;
+        }
+        summary
+    }
+}
+
+/// Quality that the adversarial simulator doesn't count on for a step that increases Quality
+#[derive(Debug, Clone, Copy)]
+struct StepReliability {
+    /// Whether the previous step increased Quality, so a Poor condition here would follow an Excellent one
+    guarded: bool,
+    /// Difference between the Quality increase in a Normal condition and the increase assumed in the worst case
+    quality_at_risk: u32,
+}
+
+/// Per-step breakdown of the worst case assumed by the adversarial simulator, to show where a 100% reliable rotation gives up Quality
+#[derive(Debug, Clone, Default)]
+struct ReliabilityReport {
+    /// `None` for steps that don't increase Quality or can't be executed
+    steps: Vec<Option<StepReliability>>,
+    worst_case_quality: u32,
+    normal_quality: u32,
+}
+
+impl ReliabilityReport {
+    fn new(settings: &Settings, actions: &[Action], solver_config: &SolverConfig) -> Self {
+        let normal_settings = Settings {
+            adversarial: false,
+            ..*settings
+        };
+        let mut report = Self::default();
+        let mut state = solver_config.initial_state(settings);
+        let mut normal_state = solver_config.initial_state(&normal_settings);
+        // an action that fails doesn't use up the step, so the condition only changes after a used one
+        let mut conditions = Condition::sequence(&solver_config.opener_conditions).peekable();
+        for action in actions {
+            let condition = *conditions.peek().unwrap();
+            let (Ok(next_state), Ok(next_normal_state)) = (
+                state.use_action(*action, condition, settings),
+                normal_state.use_action(*action, condition, &normal_settings),
+            ) else {
+                report.steps.push(None);
+                continue;
+            };
+            let normal_increase = next_normal_state.quality - normal_state.quality;
+            let worst_case_increase = next_state.quality - state.quality;
+            report
+                .steps
+                .push((normal_increase != 0).then_some(StepReliability {
+                    guarded: state.effects.adversarial_guard(),
+                    quality_at_risk: normal_increase.saturating_sub(worst_case_increase),
+                }));
+            conditions.next();
+            state = next_state;
+            normal_state = next_normal_state;
+        }
+        report.worst_case_quality = state.quality;
+        report.normal_quality = normal_state.quality;
+        report
+    }
+}
+
+/// Change of the final Quality when an Excellent condition, and the Poor condition that always follows it, hits a step
+#[derive(Debug, Clone, Copy)]
+struct ExcellentPlacement {
+    step: usize,
+    quality_change: i64,
+}
+
+/// Worst and best placement of a single Excellent condition among the steps after the opener, found by re-simulating the rotation for each placement
+#[derive(Debug, Clone, Copy, Default)]
+struct ConditionSensitivity {
+    worst: Option<ExcellentPlacement>,
+    best: Option<ExcellentPlacement>,
+}
+
+impl ConditionSensitivity {
+    fn new(
+        settings: &Settings,
+        initial_quality: u16,
+        actions: &[Action],
+        solver_config: &SolverConfig,
+    ) -> Self {
+        let opener_conditions = &solver_config.opener_conditions;
+        // Quality above the max doesn't matter, so only the capped Quality is compared
+        let capped_quality = |conditions: &[Condition]| {
+            let (state, _) = solver_config
+                .initial_state(settings)
+                .continue_macro_in_conditions(settings, actions, conditions);
+            let quality = u32::from(initial_quality) + state.quality;
+            i64::from(std::cmp::min(quality, u32::from(settings.max_quality)))
+        };
+        let base_conditions: Vec<Condition> = Condition::sequence(opener_conditions)
+            .take(actions.len())
+            .collect();
+        let base_quality = capped_quality(&base_conditions);
+        let mut sensitivity = Self::default();
+        // the conditions of the opener are assumed, so an Excellent can only appear after it
+        for step in opener_conditions.len()..actions.len() {
+            let mut conditions = base_conditions.clone();
+            conditions[step] = Condition::Excellent;
+            if let Some(condition) = conditions.get_mut(step + 1) {
+                *condition = Condition::Poor;
+            }
+            let placement = ExcellentPlacement {
+                step,
+                quality_change: capped_quality(&conditions) - base_quality,
+            };
+            if sensitivity
+                .worst
+                .is_none_or(|worst| placement.quality_change < worst.quality_change)
+            {
+                sensitivity.worst = Some(placement);
+            }
+            if sensitivity
+                .best
+                .is_none_or(|best| placement.quality_change > best.quality_change)
+            {
+                sensitivity.best = Some(placement);
+            }
         }
+        sensitivity
     }
 }
 
@@ -67,6 +294,54 @@ impl Simulator<'_> {
         })
     }
 
+    /// Minimum stats with and without the selected consumables as a text block, e.g. for planning threads on Discord
+    fn minimum_stats_summary(&self) -> String {
+        let consumables = [self.food, self.potion];
+        let mut lines = vec![
+            raphael_data::get_recipe_name(self.recipe, false, self.locale)
+                .unwrap_or("Unknown item".to_owned()),
+        ];
+        let stats: [(&str, Option<u16>, u16, StatBonus); 3] = [
+            (
+                "Craftsmanship",
+                self.minimum_stats.craftsmanship,
+                self.recipe.req_craftsmanship,
+                raphael_data::craftsmanship_bonus,
+            ),
+            (
+                "Control",
+                self.minimum_stats.control,
+                self.recipe.req_control,
+                raphael_data::control_bonus,
+            ),
+            ("CP", self.minimum_stats.cp, 0, raphael_data::cp_bonus),
+        ];
+        for (stat_name, minimum_stat, req_stat, calc_bonus) in stats {
+            let Some(stat) = minimum_stat else {
+                continue;
+            };
+            let stat = stat.max(req_stat);
+            let crafter_stat = stat.saturating_sub(calc_bonus(stat, &consumables));
+            let mut line = format!("Minimum {stat_name}: {stat}");
+            if crafter_stat != stat {
+                write!(line, " ({crafter_stat} without consumables)").unwrap();
+            }
+            if req_stat != 0 {
+                write!(line, ", recipe requires {req_stat}").unwrap();
+            }
+            lines.push(line);
+        }
+        for (label, consumable) in [("Food", self.food), ("Potion", self.potion)] {
+            if let Some(consumable) = consumable {
+                let name =
+                    raphael_data::get_item_name(consumable.item_id, consumable.hq, self.locale)
+                        .unwrap_or("Unknown item".to_owned());
+                lines.push(format!("{label}: {name}"));
+            }
+        }
+        lines.join("\n")
+    }
+
     fn draw_simulation(&mut self, ui: &mut egui::Ui, state: &SimulationState) {
         ui.group(|ui| {
             ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
@@ -102,6 +377,7 @@ impl Simulator<'_> {
                 let text_size = egui::vec2(max_text_width, ui.spacing().interact_size.y);
                 let text_layout = egui::Layout::right_to_left(egui::Align::Center);
 
+                let minimum_stats_summary = self.minimum_stats_summary();
                 let add_context_menu = |
                     response: &egui::Response,
                     minimum_stat: Option<u16>,
@@ -111,7 +387,7 @@ impl Simulator<'_> {
                     set_config_stat: &mut dyn FnMut(u16),
                 | {
                     if let Some(mut stat) = minimum_stat {
-                        response.interact(egui::Sense::click()).context_menu(|ui| {
+                        util::context_menu(&response.interact(egui::Sense::click()), true, |ui| {
                             if ui.input(|i| i.key_pressed(egui::Key::Escape)) {
                                 ui.close();
                             }
@@ -143,6 +419,11 @@ impl Simulator<'_> {
                                 set_config_stat(crafter_stat);
                                 ui.close();
                             }
+                            ui.separator();
+                            if ui.button("Copy full summary").clicked() {
+                                ui.ctx().copy_text(minimum_stats_summary.clone());
+                                ui.close();
+                            }
                         });
                     }
                 };
@@ -151,18 +432,19 @@ impl Simulator<'_> {
                     ui.allocate_ui_with_layout(text_size, text_layout, |ui| {
                         ui.label("Progress");
                     });
-                    let response = ui.add(
-                        egui::ProgressBar::new(
-                            state.progress as f32 / self.settings.max_progress as f32,
-                        )
-                        .text(progress_bar_text(
+                    let response = add_bar(
+                        ui,
+                        self.bar_style,
+                        Bar::Progress,
+                        state.progress as f32 / self.settings.max_progress as f32,
+                        progress_bar_text(
                             state.progress,
                             u32::from(self.settings.max_progress),
                             self.minimum_stats.craftsmanship,
                             self.recipe.req_craftsmanship,
                             "Craftsmanship",
-                        ))
-                        .corner_radius(0),
+                            self.bar_style,
+                        ),
                     );
                     add_context_menu(
                         &response,
@@ -182,16 +464,19 @@ impl Simulator<'_> {
                         ui.label("Quality");
                     });
                     let quality = u32::from(self.initial_quality) + state.quality;
-                    let response = ui.add(
-                        egui::ProgressBar::new(quality as f32 / self.settings.max_quality as f32)
-                            .text(progress_bar_text(
-                                quality,
-                                u32::from(self.settings.max_quality),
-                                self.minimum_stats.control,
-                                self.recipe.req_control,
-                                "Control",
-                            ))
-                            .corner_radius(0),
+                    let response = add_bar(
+                        ui,
+                        self.bar_style,
+                        Bar::Quality,
+                        quality as f32 / self.settings.max_quality as f32,
+                        progress_bar_text(
+                            quality,
+                            u32::from(self.settings.max_quality),
+                            self.minimum_stats.control,
+                            self.recipe.req_control,
+                            "Control",
+                            self.bar_style,
+                        ),
                     );
                     add_context_menu(
                         &response,
@@ -210,18 +495,19 @@ impl Simulator<'_> {
                     ui.allocate_ui_with_layout(text_size, text_layout, |ui| {
                         ui.label("Durability");
                     });
-                    ui.add(
-                        egui::ProgressBar::new(
-                            state.durability as f32 / self.settings.max_durability as f32,
-                        )
-                        .text(progress_bar_text(
-                            state.durability,
-                            self.settings.max_durability,
+                    add_bar(
+                        ui,
+                        self.bar_style,
+                        Bar::Durability,
+                        state.durability as f32 / self.settings.max_durability as f32,
+                        progress_bar_text(
+                            u32::from(state.durability),
+                            u32::from(self.settings.max_durability),
                             None,
                             0,
                             "",
-                        ))
-                        .corner_radius(0),
+                            self.bar_style,
+                        ),
                     );
                 });
 
@@ -229,16 +515,19 @@ impl Simulator<'_> {
                     ui.allocate_ui_with_layout(text_size, text_layout, |ui| {
                         ui.label("CP");
                     });
-                    let response = ui.add(
-                        egui::ProgressBar::new(state.cp as f32 / self.settings.max_cp as f32)
-                            .text(progress_bar_text(
-                                state.cp,
-                                self.settings.max_cp,
-                                None,
-                                0,
-                                "",
-                            ))
-                            .corner_radius(0),
+                    let response = add_bar(
+                        ui,
+                        self.bar_style,
+                        Bar::Cp,
+                        state.cp as f32 / self.settings.max_cp as f32,
+                        progress_bar_text(
+                            u32::from(state.cp),
+                            u32::from(self.settings.max_cp),
+                            None,
+                            0,
+                            "",
+                            self.bar_style,
+                        ),
                     );
                     add_context_menu(
                         &response,
@@ -291,7 +580,179 @@ impl Simulator<'_> {
         });
     }
 
-    fn draw_actions(&self, ui: &mut egui::Ui, errors: &[Result<(), &str>]) {
+    fn draw_summary(&self, ui: &mut egui::Ui) {
+        let summary = RotationSummary::new(self.settings, self.actions, &self.solver_config);
+        ui.group(|ui| {
+            ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+            ui.set_width(ui.available_width());
+            ui.vertical(|ui| {
+                ui.label(format!(
+                    "CP: {} spent, {} restored    Durability: {} consumed, {} restored",
+                    summary.cp_spent,
+                    summary.cp_restored,
+                    summary.durability_consumed,
+                    summary.durability_restored,
+                ));
+                ui.label(format!(
+                    "Actions: {} synthesis, {} touch, {} other",
+                    summary.synthesis_actions, summary.touch_actions, summary.other_actions,
+                ));
+                let buff_uptimes: Vec<String> = BUFFS
+                    .iter()
+                    .zip(summary.buff_steps)
+                    .filter(|(_, buff_steps)| *buff_steps != 0)
+                    .map(|((name, _), buff_steps)| {
+                        format!("{} {}%", name, 100 * buff_steps / summary.steps)
+                    })
+                    .collect();
+                ui.label(match buff_uptimes.is_empty() {
+                    true => "Buff uptime: None".to_owned(),
+                    false => format!("Buff uptime: {}", buff_uptimes.join(", ")),
+                });
+                if self.settings.adversarial {
+                    self.draw_reliability_summary(ui);
+                }
+                if raphael_data::modeled_conditions(*self.recipe).contains(&Condition::Excellent) {
+                    self.draw_condition_sensitivity(ui);
+                }
+                self.draw_exact_math(ui);
+            });
+        });
+    }
+
+    fn draw_reliability_summary(&self, ui: &mut egui::Ui) {
+        let report = ReliabilityReport::new(self.settings, self.actions, &self.solver_config);
+        let initial_quality = u32::from(self.initial_quality);
+        let target_quality = u32::from(
+            self.solver_config
+                .quality_target
+                .get_target(self.settings.max_quality),
+        );
+        let worst_case_quality = initial_quality + report.worst_case_quality;
+        let slack = match worst_case_quality >= target_quality {
+            true => format!("{} above the target", worst_case_quality - target_quality),
+            false => format!("{} below the target", target_quality - worst_case_quality),
+        };
+        let risky_steps = report
+            .steps
+            .iter()
+            .flatten()
+            .filter(|step| step.quality_at_risk != 0)
+            .count();
+        ui.label(format!(
+            "Worst case Quality: {worst_case_quality} ({slack}), {} in Normal conditions",
+            initial_quality + report.normal_quality,
+        ))
+        .on_hover_text(format!(
+            "The worst case assumes a Poor condition for {risky_steps} unguarded Quality step(s), marked on the action icons.\nA Quality step right after another one is guarded, because a Poor condition only follows an Excellent one."
+        ));
+    }
+
+    fn draw_condition_sensitivity(&self, ui: &mut egui::Ui) {
+        let id = egui::Id::new("SHOW_CONDITION_SENSITIVITY");
+        let mut show = ui.data_mut(|data| *data.get_persisted_mut_or_default::<bool>(id));
+        ui.horizontal(|ui| {
+            if ui.checkbox(&mut show, "Excellent/Poor impact").changed() {
+                ui.data_mut(|data| data.insert_persisted(id, show));
+            }
+            ui.add(HelpText::new("Re-simulates the rotation with an Excellent condition at each step after the opener, followed by the Poor condition that always comes after it, and shows the placements that change the final Quality the most."));
+            if !show {
+                return;
+            }
+            let sensitivity = ConditionSensitivity::new(
+                self.settings,
+                self.initial_quality,
+                self.actions,
+                &self.solver_config,
+            );
+            let (Some(worst), Some(best)) = (sensitivity.worst, sensitivity.best) else {
+                ui.label("No steps after the opener");
+                return;
+            };
+            let describe = |placement: ExcellentPlacement| match placement.quality_change {
+                0 => format!("no change (step {})", placement.step + 1),
+                change if change < 0 => format!("costs {} Quality (step {})", -change, placement.step + 1),
+                change => format!("gains {} Quality (step {})", change, placement.step + 1),
+            };
+            ui.label(format!("worst placement {}, best {}", describe(worst), describe(best)));
+        });
+    }
+
+    fn draw_exact_math(&self, ui: &mut egui::Ui) {
+        let id = egui::Id::new("SHOW_EXACT_MATH");
+        let mut show = ui.data_mut(|data| *data.get_persisted_mut_or_default::<bool>(id));
+        ui.horizontal(|ui| {
+            if ui.checkbox(&mut show, "Show exact math").changed() {
+                ui.data_mut(|data| data.insert_persisted(id, show));
+            }
+            ui.add(HelpText::new("Breaks down the Progress and Quality increase of each step into base value, potency, buff modifier and condition modifier.\nLike in the game, the factors are multiplied and the result is floored once.\nWith \"Ensure 100% reliability\", the simulated Quality can be lower than the increase shown here."));
+        });
+        if !show {
+            return;
+        }
+        egui::ScrollArea::vertical()
+            .id_salt("EXACT_MATH")
+            .max_height(200.0)
+            .show(ui, |ui| {
+                egui::Grid::new("EXACT_MATH_GRID")
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for header in ["Step", "Action", "Condition", "Progress", "Quality"] {
+                            ui.label(egui::RichText::new(header).strong());
+                        }
+                        ui.end_row();
+                        let mut state = self.solver_config.initial_state(self.settings);
+                        let conditions = Condition::sequence(&self.solver_config.opener_conditions);
+                        for ((action, condition), step) in self
+                            .actions
+                            .iter()
+                            .zip(conditions)
+                            .zip(step_numbers(self.actions))
+                        {
+                            let Ok(next_state) =
+                                state.use_action(*action, condition, self.settings)
+                            else {
+                                break;
+                            };
+                            let factors = state.increase_factors(*action, condition, self.settings);
+                            ui.label(step.map_or_else(String::new, |step| step.to_string()));
+                            ui.label(raphael_data::action_name(*action, self.locale));
+                            ui.label(format!("{:?}", condition));
+                            ui.label(progress_math(
+                                self.settings,
+                                condition,
+                                state.effects,
+                                factors,
+                            ));
+                            ui.label(quality_math(
+                                self.settings,
+                                *action,
+                                condition,
+                                state.effects,
+                                factors,
+                            ));
+                            ui.end_row();
+                            state = next_state;
+                        }
+                    });
+            });
+    }
+
+    /// Draws the actions of the rotation, returns the change to the rotation that was picked from the context menu of a step
+    fn draw_actions(
+        &self,
+        ui: &mut egui::Ui,
+        errors: &[Result<(), &str>],
+    ) -> Option<CarefulObservationEdit> {
+        let reliability_report = match self.settings.adversarial {
+            true => ReliabilityReport::new(self.settings, self.actions, &self.solver_config),
+            false => ReliabilityReport::default(),
+        };
+        let careful_observation_allowed = self
+            .settings
+            .allowed_actions
+            .has(Action::CarefulObservation);
+        let mut edit = None;
         ui.group(|ui| {
             ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
             egui::ScrollArea::horizontal().show(ui, |ui| {
@@ -299,8 +760,8 @@ impl Simulator<'_> {
                 ui.set_width(ui.available_width());
                 ui.horizontal(|ui| {
                     ui.style_mut().spacing.item_spacing = egui::vec2(3.0, 8.0);
-                    for (step_index, (action, error)) in
-                        self.actions.iter().zip(errors.iter()).enumerate()
+                    for ((index, (action, error)), step) in
+                        self.actions.iter().zip(errors.iter()).enumerate().zip(step_numbers(self.actions))
                     {
                         let image =
                             util::get_action_icon(*action, self.crafter_config.selected_job)
@@ -310,9 +771,24 @@ impl Simulator<'_> {
                                     Ok(_) => egui::Color32::WHITE,
                                     Err(_) => egui::Color32::DARK_GRAY,
                                 });
-                        let response = ui
-                            .add(image)
-                            .on_hover_text(raphael_data::action_name(*action, self.locale));
+                        let mut hover_text = raphael_data::action_name(*action, self.locale).to_owned();
+                        if let Some(Some(step)) = reliability_report.steps.get(index) {
+                            if step.quality_at_risk != 0 {
+                                write!(hover_text, "\nWorst case: Poor condition, {} Quality less than in Normal condition", step.quality_at_risk).unwrap();
+                            } else if step.guarded {
+                                hover_text.push_str("\nWorst case: no Quality lost, guarded by the previous Quality step");
+                            }
+                        }
+                        let response = ui.add(image).on_hover_text(hover_text);
+                        if let Some(Some(step)) = reliability_report.steps.get(index)
+                            && step.quality_at_risk != 0
+                        {
+                            ui.painter().circle_filled(
+                                response.rect.right_top() + egui::vec2(-4.0, 4.0),
+                                3.5,
+                                ui.visuals().warn_fg_color,
+                            );
+                        }
                         if error.is_err() {
                             egui::Image::new(egui::include_image!(
                                 "../../assets/action-icons/disabled.webp"
@@ -320,8 +796,31 @@ impl Simulator<'_> {
                             .tint(egui::Color32::GRAY)
                             .paint_at(ui, response.rect);
                         }
+                        util::context_menu(&response.interact(egui::Sense::click()), true, |ui| {
+                            let Some(step) = step else {
+                                if ui.button("Remove Careful Observation").clicked() {
+                                    edit = Some(CarefulObservationEdit::Remove(index));
+                                    ui.close();
+                                }
+                                return;
+                            };
+                            if ui.button(format!("Copy state after step {step}")).clicked() {
+                                let (state, _) = self.solver_config.simulate(self.settings, &self.actions[..=index]);
+                                ui.ctx().copy_text(state.to_share_string(self.settings));
+                                ui.close();
+                            }
+                            if careful_observation_allowed
+                                && ui.button(format!("Careful Observation before step {step}"))
+                                    .on_hover_text("Reroll the condition of the step without using it up, e.g. when crafting manually and waiting for a Good condition.\nOnly specialists can use it, up to 3 times per synthesis, and it can't be used in macros.")
+                                    .clicked()
+                            {
+                                edit = Some(CarefulObservationEdit::Insert(index));
+                                ui.close();
+                            }
+                        });
                         let mut step_count_ui = ui.new_child(egui::UiBuilder::default());
-                        let step_count_text = egui::RichText::new((step_index + 1).to_string())
+                        // Careful Observation doesn't use up a step, so it has no step number
+                        let step_count_text = egui::RichText::new(step.map_or_else(String::new, |step| step.to_string()))
                             .color(egui::Color32::BLACK)
                             .size(12.0);
                         let text_offset_adjust = step_count_text.text().len() as f32 * 2.5;
@@ -350,16 +849,27 @@ impl Simulator<'_> {
                 });
             });
         });
+        edit
     }
 }
 
 impl egui::Widget for Simulator<'_> {
     fn ui(mut self, ui: &mut egui::Ui) -> egui::Response {
-        let (state, errors) =
-            SimulationState::from_macro_continue_on_error(self.settings, self.actions);
+        let (state, errors) = self.solver_config.simulate(self.settings, self.actions);
         ui.vertical(|ui| {
             self.draw_simulation(ui, &state);
-            self.draw_actions(ui, &errors);
+            match self.draw_actions(ui, &errors) {
+                Some(CarefulObservationEdit::Insert(index)) => {
+                    self.actions.insert(index, Action::CarefulObservation);
+                }
+                Some(CarefulObservationEdit::Remove(index)) => {
+                    self.actions.remove(index);
+                }
+                None => (),
+            }
+            if !self.actions.is_empty() {
+                self.draw_summary(ui);
+            }
         })
         .response
     }
@@ -376,18 +886,242 @@ fn text_width(ui: &mut egui::Ui, text: impl Into<String>) -> f32 {
     })
 }
 
-fn progress_bar_text<T: Copy + std::fmt::Display>(
-    value: T,
-    maximum: T,
+#[derive(Clone, Copy)]
+enum Bar {
+    Progress,
+    Quality,
+    Durability,
+    Cp,
+}
+
+/// Fill color of each bar, `None` uses the selection color of the theme for all bars.
+/// The fills are dark in dark mode and light in light mode, so that the text stays readable over both the filled and the empty part of the bar.
+fn bar_fill(palette: BarPalette, bar: Bar, dark_mode: bool) -> Option<egui::Color32> {
+    let [dark, light] = match (palette, bar) {
+        (BarPalette::Default, _) => return None,
+        (BarPalette::Colorblind, Bar::Progress) => [[0, 114, 178], [128, 185, 216]],
+        (BarPalette::Colorblind, Bar::Quality) => [[166, 111, 0], [242, 207, 128]],
+        (BarPalette::Colorblind, Bar::Durability) => [[0, 122, 90], [128, 206, 185]],
+        (BarPalette::Colorblind, Bar::Cp) => [[158, 79, 126], [229, 188, 211]],
+        (BarPalette::HighContrast, Bar::Progress) => [[0, 80, 230], [140, 190, 255]],
+        (BarPalette::HighContrast, Bar::Quality) => [[190, 90, 0], [255, 195, 90]],
+        (BarPalette::HighContrast, Bar::Durability) => [[0, 125, 50], [120, 225, 150]],
+        (BarPalette::HighContrast, Bar::Cp) => [[150, 0, 200], [230, 150, 255]],
+    };
+    let [r, g, b] = if dark_mode { dark } else { light };
+    Some(egui::Color32::from_rgb(r, g, b))
+}
+
+fn add_bar(
+    ui: &mut egui::Ui,
+    style: BarStyle,
+    bar: Bar,
+    progress: f32,
+    text: String,
+) -> egui::Response {
+    let progress = progress.clamp(0.0, 1.0);
+    let dark_mode = ui.visuals().dark_mode;
+    let text_color = match dark_mode {
+        true => egui::Color32::WHITE,
+        false => egui::Color32::BLACK,
+    };
+    let mut progress_bar = egui::ProgressBar::new(progress).corner_radius(0);
+    progress_bar = match bar_fill(style.palette, bar, dark_mode) {
+        Some(fill) => progress_bar
+            .fill(fill)
+            .text(egui::RichText::new(text).color(text_color)),
+        None => progress_bar.text(text),
+    };
+    let response = ui.add(progress_bar);
+    let rect = response.rect;
+    if style.patterns {
+        let filled_rect =
+            egui::Rect::from_min_size(rect.min, egui::vec2(rect.width() * progress, rect.height()));
+        paint_bar_pattern(
+            &ui.painter().with_clip_rect(filled_rect),
+            filled_rect,
+            bar,
+            text_color.gamma_multiply(0.3),
+        );
+    }
+    if style.palette == BarPalette::HighContrast {
+        ui.painter().rect_stroke(
+            rect,
+            0,
+            egui::Stroke::new(1.0, text_color),
+            egui::StrokeKind::Inside,
+        );
+    }
+    response
+}
+
+/// Progress: forward diagonal lines, Quality: backward diagonal lines, Durability: vertical lines, CP: dots
+fn paint_bar_pattern(painter: &egui::Painter, rect: egui::Rect, bar: Bar, color: egui::Color32) {
+    const SPACING: f32 = 6.0;
+    let stroke = egui::Stroke::new(1.5, color);
+    let height = rect.height();
+    let mut x = rect.left() - height;
+    while x < rect.right() {
+        match bar {
+            Bar::Progress => {
+                painter.line_segment(
+                    [
+                        egui::pos2(x, rect.bottom()),
+                        egui::pos2(x + height, rect.top()),
+                    ],
+                    stroke,
+                );
+            }
+            Bar::Quality => {
+                painter.line_segment(
+                    [
+                        egui::pos2(x, rect.top()),
+                        egui::pos2(x + height, rect.bottom()),
+                    ],
+                    stroke,
+                );
+            }
+            Bar::Durability => {
+                painter.vline(x + height, rect.y_range(), stroke);
+            }
+            Bar::Cp => {
+                let mut y = rect.top() + SPACING / 2.0;
+                while y < rect.bottom() {
+                    painter.circle_filled(egui::pos2(x + height, y), 1.5, color);
+                    y += SPACING;
+                }
+            }
+        }
+        x += SPACING;
+    }
+}
+
+/// Active buffs appended to a modifier, e.g. " (Inner Quiet 3, Innovation)"
+fn buff_note(buffs: &[String]) -> String {
+    match buffs.is_empty() {
+        true => String::new(),
+        false => format!(" ({})", buffs.join(", ")),
+    }
+}
+
+fn progress_math(
+    settings: &Settings,
+    condition: Condition,
+    effects: Effects,
+    factors: IncreaseFactors,
+) -> String {
+    if factors.progress_increase == 0 {
+        return "-".to_owned();
+    }
+    let mut buffs = Vec::new();
+    if effects.veneration() != 0 {
+        buffs.push("Veneration".to_owned());
+    }
+    if effects.muscle_memory() != 0 {
+        buffs.push("Muscle Memory".to_owned());
+    }
+    let condition_multiplier = f64::from(factors.progress_condition_modifier) / 2.0;
+    let exact = f64::from(settings.base_progress)
+        * f64::from(factors.progress_potency)
+        * f64::from(factors.progress_effect_modifier)
+        * condition_multiplier
+        / 10_000.0;
+    // only the conditions that change Progress are shown, unlike for Quality
+    let condition_note = match factors.progress_condition_modifier {
+        2 => String::new(),
+        _ => format!(" × {condition_multiplier} ({condition:?})"),
+    };
+    format!(
+        "{} × {}% × {}%{}{} = {:.2}, floored {}",
+        settings.base_progress,
+        factors.progress_potency,
+        factors.progress_effect_modifier,
+        buff_note(&buffs),
+        condition_note,
+        exact,
+        factors.progress_increase,
+    )
+}
+
+fn quality_math(
+    settings: &Settings,
+    action: Action,
+    condition: Condition,
+    effects: Effects,
+    factors: IncreaseFactors,
+) -> String {
+    if factors.quality_increase == 0 {
+        return "-".to_owned();
+    }
+    if action == Action::TrainedEye {
+        return format!(
+            "{} (Trained Eye maxes out Quality)",
+            factors.quality_increase
+        );
+    }
+    let mut buffs = Vec::new();
+    if effects.inner_quiet() != 0 {
+        buffs.push(format!("Inner Quiet {}", effects.inner_quiet()));
+    }
+    if effects.innovation() != 0 {
+        buffs.push("Innovation".to_owned());
+    }
+    if effects.great_strides() != 0 {
+        buffs.push("Great Strides".to_owned());
+    }
+    let condition_multiplier = f64::from(factors.quality_condition_modifier) / 2.0;
+    let exact = f64::from(settings.base_quality)
+        * f64::from(factors.quality_potency)
+        * f64::from(factors.quality_effect_modifier)
+        * condition_multiplier
+        / 10_000.0;
+    format!(
+        "{} × {}% × {}%{} × {} ({:?}) = {:.2}, floored {}",
+        settings.base_quality,
+        factors.quality_potency,
+        factors.quality_effect_modifier,
+        buff_note(&buffs),
+        condition_multiplier,
+        condition,
+        exact,
+        factors.quality_increase,
+    )
+}
+
+/// Value with one decimal in thousands from 1000 on, e.g. "12.4k"
+fn compact_number(value: u32) -> String {
+    match value < 1000 {
+        true => value.to_string(),
+        false => format!("{:.1}k", f64::from(value) / 1000.0),
+    }
+}
+
+fn progress_bar_text(
+    value: u32,
+    maximum: u32,
     minimum_stat: Option<u16>,
     required_stat: u16,
     stat_name: &str,
+    style: BarStyle,
 ) -> String {
-    match minimum_stat {
+    let values = match style.number_format {
+        BarNumberFormat::Raw => format!("{value: >5} / {maximum}"),
+        BarNumberFormat::Compact => {
+            format!("{} / {}", compact_number(value), compact_number(maximum))
+        }
+        // floored, so that 100% means that the maximum has been reached
+        BarNumberFormat::Percentage => match maximum {
+            0 => "-".to_owned(),
+            _ => format!("{}%", u64::from(value) * 100 / u64::from(maximum)),
+        },
+    };
+    match minimum_stat.filter(|_| !style.hide_minimum_stats) {
         Some(stat) => match stat >= required_stat {
-          true => format!("{value: >5} / {maximum} ({stat_name} ≥{stat})"),
-          false => format!("{value: >5} / {maximum} ({stat_name} ≥{stat} theoretically, recipe requires {required_stat})"),
+            true => format!("{values} ({stat_name} ≥{stat})"),
+            false => format!(
+                "{values} ({stat_name} ≥{stat} theoretically, recipe requires {required_stat})"
+            ),
         },
-        None => format!("{value: >5} / {maximum}"),
+        None => values,
     }
 }
@@ -0,0 +1,46 @@
+use raphael_sim::Settings;
+
+use crate::{translations::t, util};
+
+/// Window asking for confirmation before a solve that is estimated to use more memory than the budget
+#[derive(Debug, Default)]
+pub struct SolveConfirmationWindow {
+    pub open: bool,
+}
+
+impl SolveConfirmationWindow {
+    /// Draws the window, returns `true` if the solve should be started anyway
+    pub fn ui(&mut self, ui: &mut egui::Ui, game_settings: &Settings) -> bool {
+        let mut confirmed = false;
+        let estimate = util::estimate_solver_memory_usage(game_settings);
+        ui.style_mut().spacing.item_spacing = egui::vec2(3.0, 3.0);
+        ui.label(egui::RichText::new(t("Warning")).strong());
+        ui.separator();
+        ui.label(format!(
+            "The solver is estimated to use about {:.1}GB of memory with the current configuration.",
+            estimate as f64 / (1024.0 * 1024.0 * 1024.0)
+        ));
+        #[cfg(target_arch = "wasm32")]
+        ui.label(t(
+            "This may reach the 4GB memory limit of 32-bit web assembly and crash the solver.",
+        ));
+        #[cfg(not(target_arch = "wasm32"))]
+        ui.label(t("This may cause your system to run out of memory."));
+        ui.label("Solving may also take a long time. Consider disabling specialist actions or \"Ensure 100% reliability\".");
+        ui.separator();
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered_justified(|ui| {
+                if ui.button(t("Cancel")).clicked() {
+                    self.open = false;
+                }
+            });
+            columns[1].vertical_centered_justified(|ui| {
+                if ui.button(t("Solve anyway")).clicked() {
+                    self.open = false;
+                    confirmed = true;
+                }
+            });
+        });
+        confirmed
+    }
+}
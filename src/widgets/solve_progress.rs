@@ -0,0 +1,264 @@
+use raphael_solver::{MacroSolver, PrecomputeProgress, SolutionMetrics, SolverProgress};
+
+use crate::translations::t;
+
+use super::util::format_thousands;
+
+/// Where the time of a solve went, shown in the details under the elapsed time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SolveReport {
+    precompute: web_time::Duration,
+    search: web_time::Duration,
+    quality_ub_states: usize,
+    step_lb_states: usize,
+    search_nodes: usize,
+}
+
+impl SolveReport {
+    /// Adds the last solve of `macro_solver`, so that a report can cover several solves
+    pub fn add_solve(&mut self, macro_solver: &MacroSolver<'_>) {
+        let timings = macro_solver.timings();
+        let stats = macro_solver.runtime_stats();
+        self.precompute += timings.quality_ub_precompute + timings.step_lb_precompute;
+        self.search += timings.search;
+        self.quality_ub_states = std::cmp::max(
+            self.quality_ub_states,
+            stats.quality_ub_stats.parallel_states + stats.quality_ub_stats.sequential_states,
+        );
+        self.step_lb_states =
+            std::cmp::max(self.step_lb_states, stats.step_lb_stats.parallel_states);
+        self.search_nodes += stats.search_queue_stats.processed_nodes;
+    }
+
+    pub fn ui(&self, ui: &mut egui::Ui) {
+        egui::CollapsingHeader::new("Details")
+            .id_salt("SOLVE_REPORT")
+            .show(ui, |ui| {
+                egui::Grid::new("SOLVE_REPORT_GRID").num_columns(2).show(ui, |ui| {
+                    ui.label("Precompute");
+                    ui.label(format!("{:.2}s", self.precompute.as_secs_f32()));
+                    ui.end_row();
+                    ui.label("Search");
+                    ui.label(format!("{:.2}s", self.search.as_secs_f32()));
+                    ui.end_row();
+                    ui.label("Quality table");
+                    ui.label(format!("{} states", format_thousands(self.quality_ub_states)));
+                    ui.end_row();
+                    ui.label("Step table");
+                    ui.label(format!("{} states", format_thousands(self.step_lb_states)));
+                    ui.end_row();
+                    ui.label("Search nodes");
+                    ui.label(format_thousands(self.search_nodes));
+                    ui.end_row();
+                });
+                let summary = if self.search_nodes == 0 {
+                    // the solve ended before the search, e.g. because Progress can't be maxed out
+                    None
+                } else if self.precompute.is_zero() {
+                    Some("Reused the tables of the previous solve.")
+                } else if self.precompute > self.search {
+                    Some("Most time went into precomputing tables, which grow with CP, Durability and the available actions.")
+                } else {
+                    Some("Most time went into the search, which grows with the number of rotations that could still reach the target.")
+                };
+                if let Some(summary) = summary {
+                    ui.label(egui::RichText::new(summary).small());
+                }
+            });
+    }
+}
+
+/// Point in time of a running solve that the throughput is measured from
+#[derive(Debug, Clone, Copy)]
+struct ThroughputSample {
+    time: web_time::Instant,
+    nodes_visited: usize,
+    /// CPU time of the whole process, `None` if it can't be queried
+    #[cfg(not(target_arch = "wasm32"))]
+    cpu_time: Option<cpu_time::ProcessTime>,
+}
+
+/// Search rate and thread utilization of the running solve, measured between samples taken while the busy modal is shown
+#[derive(Debug, Clone, Copy, Default)]
+struct SolveThroughput {
+    last_sample: Option<ThroughputSample>,
+    nodes_per_second: Option<f64>,
+    /// CPU time used relative to the time available to the threads of the pool, only measured on native
+    thread_utilization: Option<f64>,
+}
+
+impl SolveThroughput {
+    /// Minimum time between samples, so that the shown rates don't jump with every repaint
+    const SAMPLE_INTERVAL: web_time::Duration = web_time::Duration::from_secs(1);
+
+    fn sample(&mut self, nodes_visited: usize) {
+        let sample = ThroughputSample {
+            time: web_time::Instant::now(),
+            nodes_visited,
+            #[cfg(not(target_arch = "wasm32"))]
+            cpu_time: cpu_time::ProcessTime::try_now().ok(),
+        };
+        if let Some(last_sample) = self.last_sample {
+            let elapsed = sample.time.duration_since(last_sample.time);
+            if elapsed < Self::SAMPLE_INTERVAL {
+                return;
+            }
+            let seconds = elapsed.as_secs_f64();
+            // the count starts over with each solve of a batch, and is 0 during the precompute
+            self.nodes_per_second = (nodes_visited > last_sample.nodes_visited)
+                .then(|| (nodes_visited - last_sample.nodes_visited) as f64 / seconds);
+            #[cfg(not(target_arch = "wasm32"))]
+            if let (Some(last_cpu_time), Some(cpu_time)) = (last_sample.cpu_time, sample.cpu_time) {
+                let cpu_seconds = cpu_time.duration_since(last_cpu_time).as_secs_f64();
+                self.thread_utilization =
+                    Some(cpu_seconds / seconds / rayon::current_num_threads() as f64);
+            }
+        }
+        self.last_sample = Some(sample);
+    }
+}
+
+/// Progress of the running solve, shown in the busy modal
+#[derive(Debug)]
+pub struct SolveProgress {
+    start_time: web_time::Instant,
+    /// Nodes visited by the search, `usize::MAX` if the rotation was loaded from the saved rotations
+    pub nodes_visited: usize,
+    /// Progress of the precompute phase, cleared once the search starts reporting progress
+    pub precompute: Option<PrecomputeProgress>,
+    /// Best rotation found so far by the running solve
+    best_solution: Option<SolutionMetrics>,
+    throughput: SolveThroughput,
+}
+
+impl Default for SolveProgress {
+    fn default() -> Self {
+        Self {
+            start_time: web_time::Instant::now(),
+            nodes_visited: 0,
+            precompute: None,
+            best_solution: None,
+            throughput: SolveThroughput::default(),
+        }
+    }
+}
+
+impl SolveProgress {
+    /// Resets the progress for a solve that starts now
+    pub fn start(&mut self) {
+        self.nodes_visited = 0;
+        self.precompute = None;
+        self.best_solution = None;
+        self.throughput = SolveThroughput::default();
+        self.start_time = web_time::Instant::now();
+    }
+
+    pub fn elapsed(&self) -> web_time::Duration {
+        self.start_time.elapsed()
+    }
+
+    pub fn set_progress(&mut self, progress: SolverProgress) {
+        self.precompute = None;
+        self.nodes_visited = progress.nodes_visited;
+        self.best_solution = progress.best_solution;
+    }
+
+    /// Draws the content of the busy modal, returns whether the solve should be cancelled.
+    /// `action_prefix_len` is the number of actions that precede the rotation of the solver, e.g. when repairing a rotation.
+    pub fn ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        interrupt_pending: bool,
+        initial_quality: u16,
+        action_prefix_len: usize,
+    ) -> bool {
+        if self.nodes_visited != usize::MAX {
+            self.throughput.sample(self.nodes_visited);
+        }
+        ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+        ui.set_width(180.0);
+        ui.horizontal(|ui| {
+            draw_spinner(ui);
+            ui.vertical(|ui| {
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new(if interrupt_pending {
+                            "Cancelling ..."
+                        } else {
+                            "Solving ..."
+                        })
+                        .strong(),
+                    );
+                    ui.label(format!("({:.2}s)", self.elapsed().as_secs_f32()));
+                });
+                if let Some(progress) = self.precompute {
+                    ui.label(format!(
+                        "Precompute: templates {}/{} at step budget {}",
+                        format_thousands(progress.templates_solved),
+                        format_thousands(progress.templates_total),
+                        progress.step_budget
+                    ));
+                    let fraction =
+                        progress.templates_solved as f32 / progress.templates_total.max(1) as f32;
+                    ui.add(egui::ProgressBar::new(fraction).desired_height(4.0));
+                } else if self.nodes_visited == 0 {
+                    ui.label(t("Computing ..."));
+                } else {
+                    ui.label(format!(
+                        "Search: {} nodes visited",
+                        format_thousands(self.nodes_visited)
+                    ));
+                }
+                if let Some(nodes_per_second) = self.throughput.nodes_per_second {
+                    ui.label(format!("{} nodes/s", format_thousands(nodes_per_second as usize)));
+                }
+                if let Some(thread_utilization) = self.throughput.thread_utilization {
+                    ui.label(format!(
+                        "Thread utilization: {:.0}% of {}",
+                        thread_utilization * 100.0,
+                        rayon::current_num_threads()
+                    ))
+                    .on_hover_text("CPU time used by the app relative to the time available to the solver threads.\nIf it stays well below 100%, more threads won't make the solve faster.");
+                }
+                if let Some(best_solution) = self.best_solution {
+                    let quality = u32::from(initial_quality) + best_solution.quality;
+                    let steps = action_prefix_len + usize::from(best_solution.steps);
+                    ui.label(format!(
+                        "Best so far: {} quality in {} steps",
+                        format_thousands(quality as usize),
+                        steps
+                    ));
+                }
+            });
+        });
+
+        ui.vertical_centered_justified(|ui| {
+            ui.separator();
+            ui.add_enabled(!interrupt_pending, egui::Button::new(t("Cancel")))
+                .clicked()
+        })
+        .inner
+    }
+}
+
+/// Paints the same arc as `egui::Spinner`, but without requesting a repaint on every frame.
+/// The arc only advances when something else repaints the UI.
+fn draw_spinner(ui: &mut egui::Ui) {
+    let size = ui.style().spacing.interact_size.y;
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(size, size), egui::Sense::hover());
+    let radius = rect.height() / 2.0 - 2.0;
+    let n_points = (radius.round() as u32).clamp(8, 128);
+    let time = ui.input(|input| input.time);
+    let start_angle = time * std::f64::consts::TAU;
+    let end_angle = start_angle + 240f64.to_radians() * time.sin();
+    let points = (0..n_points)
+        .map(|i| {
+            let angle =
+                egui::emath::lerp(start_angle..=end_angle, f64::from(i) / f64::from(n_points));
+            let (sin, cos) = angle.sin_cos();
+            rect.center() + radius * egui::vec2(cos as f32, sin as f32)
+        })
+        .collect();
+    let stroke = egui::Stroke::new(3.0, ui.visuals().strong_text_color());
+    ui.painter().add(egui::Shape::line(points, stroke));
+}
@@ -0,0 +1,124 @@
+use raphael_solver::SolverException;
+
+use crate::translations::t;
+
+/// Explains why the last solve failed
+pub struct SolverErrorMessage<'a> {
+    error: &'a SolverException,
+    /// Crash report written for a solver panic
+    #[cfg(not(target_arch = "wasm32"))]
+    crash_report_path: Option<&'a std::path::Path>,
+}
+
+impl<'a> SolverErrorMessage<'a> {
+    pub fn new(error: &'a SolverException) -> Self {
+        Self {
+            error,
+            #[cfg(not(target_arch = "wasm32"))]
+            crash_report_path: None,
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_crash_report(self, crash_report_path: Option<&'a std::path::Path>) -> Self {
+        Self {
+            crash_report_path,
+            ..self
+        }
+    }
+
+    /// Whether the message can be closed, an unrecoverable error needs the page to be reloaded
+    pub fn recoverable(&self) -> bool {
+        match self.error {
+            #[cfg(target_arch = "wasm32")]
+            SolverException::AllocError => false,
+            _ => true,
+        }
+    }
+
+    /// Draws the message, returns `true` if it was closed
+    pub fn ui(self, ui: &mut egui::Ui) -> bool {
+        ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+        match self.error {
+            SolverException::NoSolution => {
+                ui.label(egui::RichText::new(t("No solution")).strong());
+                ui.separator();
+                ui.label(t("Make sure that the recipe is set correctly and that your stats are enough to craft this item."));
+            }
+            SolverException::Interrupted => return true,
+            SolverException::UnknownStateQueried { state } => {
+                ui.label(egui::RichText::new(t("Error")).strong());
+                ui.separator();
+                ui.label("The solver queried a state that is missing from its precomputed tables.");
+                ui.label(egui::RichText::new(state).small().monospace());
+                ui.label(t(
+                    "This is an internal error. Please submit a bug report :)",
+                ));
+                bug_report_context_button(ui, self.error);
+            }
+            SolverException::TableOverflow => {
+                ui.label(egui::RichText::new(t("Error")).strong());
+                ui.separator();
+                ui.label("The rotation for this recipe needs more steps than the solver's tables can hold.");
+                ui.label("Consider using HQ materials, better stats or a lower quality target.");
+            }
+            SolverException::PanicCaught { message } => {
+                ui.label(egui::RichText::new(t("Error")).strong());
+                ui.separator();
+                ui.label("The solver crashed:");
+                ui.label(egui::RichText::new(message).small().monospace());
+                ui.label(t(
+                    "This is an internal error. Please submit a bug report :)",
+                ));
+                bug_report_context_button(ui, self.error);
+                #[cfg(not(target_arch = "wasm32"))]
+                if let Some(path) = self.crash_report_path {
+                    super::crash_report::crash_report_label(ui, path);
+                }
+            }
+            SolverException::Timeout => {
+                ui.label(egui::RichText::new(t("Error")).strong());
+                ui.separator();
+                ui.label("The solver didn't finish within its time limit.");
+            }
+            #[cfg(target_arch = "wasm32")]
+            SolverException::AllocError => {
+                ui.label(egui::RichText::new(t("Error: Solver ran out of memory!")).strong());
+                ui.separator();
+                ui.label(t(
+                    "The solver reached the 4GB memory limit of 32-bit web assembly and crashed.",
+                ));
+                ui.label(t("Consider enabling fewer memory intensive options.\n"));
+                ui.label(t(
+                    "Alternatively, a native version is available from the release page on GitHub.",
+                ));
+                ui.label(t("The native version doesn't have the 4GB limit, in addition to better performance."));
+                ui.add(
+                    egui::Hyperlink::from_label_and_url(
+                        "View latest release on GitHub",
+                        "https://github.com/Asvel/ffxiv-raphael-cn/releases/latest",
+                    )
+                    .open_in_new_tab(true),
+                );
+            }
+        }
+        ui.separator();
+        let recoverable = self.recoverable();
+        ui.vertical_centered_justified(|ui| {
+            if recoverable {
+                ui.button(t("Close")).clicked()
+            } else {
+                ui.label(t("Reload the page to reset the app"));
+                false
+            }
+        })
+        .inner
+    }
+}
+
+/// Copies the JSON context of the error, which lets the failing solve be reproduced from a bug report
+fn bug_report_context_button(ui: &mut egui::Ui, exception: &SolverException) {
+    if ui.button("Copy bug report context").clicked() {
+        ui.ctx().copy_text(crate::bug_report::context(exception));
+    }
+}
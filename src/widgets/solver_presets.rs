@@ -0,0 +1,141 @@
+use raphael_sim::Condition;
+use raphael_solver::OpenerPreference;
+use serde::{Deserialize, Serialize};
+
+use crate::{app::SolverConfig, config::QualityTarget, font_fallback, translations::t};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SolverPreset {
+    pub name: String,
+    pub solver_config: SolverConfig,
+}
+
+impl SolverPreset {
+    fn new(name: impl Into<String>, solver_config: SolverConfig) -> Self {
+        Self {
+            name: name.into(),
+            solver_config,
+        }
+    }
+
+    /// Built-in presets, named in the current UI language
+    pub fn defaults() -> Vec<Self> {
+        vec![
+            Self::new(t("Max quality"), SolverConfig::default()),
+            Self::new(
+                t("Fast collectables"),
+                SolverConfig {
+                    quality_target: QualityTarget::CollectableT1,
+                    backload_progress: true,
+                    prefer_backload_progress: false,
+                    adversarial: false,
+                    forbid_trained_eye: false,
+                    opener: OpenerPreference::Any,
+                    min_synthesis_durability: 0,
+                    opener_conditions: [Condition::Normal; 2],
+                    starting_cp_penalty: 0,
+                    starting_durability_penalty: 0,
+                },
+            ),
+            Self::new(
+                t("Reliable"),
+                SolverConfig {
+                    quality_target: QualityTarget::Full,
+                    backload_progress: false,
+                    prefer_backload_progress: false,
+                    adversarial: true,
+                    forbid_trained_eye: false,
+                    opener: OpenerPreference::Any,
+                    min_synthesis_durability: 0,
+                    opener_conditions: [Condition::Normal; 2],
+                    starting_cp_penalty: 0,
+                    starting_durability_penalty: 0,
+                },
+            ),
+        ]
+    }
+}
+
+/// Menu button to apply, save and delete solver presets
+pub struct SolverPresetsButton<'a> {
+    presets: &'a mut Vec<SolverPreset>,
+    solver_config: &'a mut SolverConfig,
+}
+
+impl<'a> SolverPresetsButton<'a> {
+    pub fn new(presets: &'a mut Vec<SolverPreset>, solver_config: &'a mut SolverConfig) -> Self {
+        Self {
+            presets,
+            solver_config,
+        }
+    }
+}
+
+impl egui::Widget for SolverPresetsButton<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        egui::containers::menu::MenuButton::new("🔖")
+            .config(
+                egui::containers::menu::MenuConfig::default()
+                    .close_behavior(egui::PopupCloseBehavior::CloseOnClickOutside),
+            )
+            .ui(ui, |ui| {
+                ui.reset_style();
+                ui.style_mut().spacing.item_spacing = egui::vec2(8.0, 3.0);
+                ui.label(egui::RichText::new(t("Solver presets")).strong());
+                ui.separator();
+                if self.presets.is_empty() {
+                    ui.label(t("No saved presets"));
+                }
+                let mut deleted_preset = None;
+                for (index, preset) in self.presets.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        if ui.button("🗑").clicked() {
+                            deleted_preset = Some(index);
+                        }
+                        let selected = preset.solver_config == *self.solver_config;
+                        font_fallback::request_glyphs(ui.ctx(), &preset.name);
+                        if ui.selectable_label(selected, &preset.name).clicked() {
+                            *self.solver_config = preset.solver_config;
+                            ui.close();
+                        }
+                    });
+                }
+                if let Some(index) = deleted_preset {
+                    self.presets.remove(index);
+                }
+                ui.separator();
+                ui.horizontal(|ui| {
+                    let id = egui::Id::new("SOLVER_PRESET_NAME");
+                    let mut name = ui.data(|data| data.get_temp::<String>(id).unwrap_or_default());
+                    ui.add(
+                        egui::TextEdit::singleline(&mut name)
+                            .desired_width(120.0)
+                            .hint_text(t("Preset name")),
+                    );
+                    let trimmed_name = name.trim();
+                    if ui
+                        .add_enabled(
+                            !trimmed_name.is_empty(),
+                            egui::Button::new(t("Save current")),
+                        )
+                        .clicked()
+                    {
+                        match self
+                            .presets
+                            .iter_mut()
+                            .find(|preset| preset.name == trimmed_name)
+                        {
+                            Some(preset) => preset.solver_config = *self.solver_config,
+                            None => self
+                                .presets
+                                .push(SolverPreset::new(trimmed_name, *self.solver_config)),
+                        }
+                        ui.data_mut(|data| data.remove::<String>(id));
+                    } else {
+                        ui.data_mut(|data| data.insert_temp(id, name));
+                    }
+                });
+            })
+            .0
+    }
+}
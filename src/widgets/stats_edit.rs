@@ -1,9 +1,12 @@
 use egui::Widget;
-use raphael_data::{Locale, action_name, get_job_name};
+use raphael_data::{CrafterStats, Locale, action_name, get_job_name};
 use raphael_sim::Action;
 
 use crate::config::CrafterConfig;
 
+/// Specialist actions in the compact format, in the order of the `CrafterStats` fields
+const SPECIALIST_ACTION_CHARS: [char; 3] = ['M', 'H', 'Q'];
+
 pub struct StatsEdit<'a> {
     locale: Locale,
     crafter_config: &'a mut CrafterConfig,
@@ -18,8 +21,23 @@ impl<'a> StatsEdit<'a> {
     }
 }
 
+impl StatsEdit<'_> {
+    fn import_file_contents(&mut self, contents: std::io::Result<Vec<u8>>) {
+        let crafter_stats = contents
+            .map_err(|error| error.to_string())
+            .and_then(|bytes| String::from_utf8(bytes).map_err(|error| error.to_string()))
+            .and_then(|text| {
+                parse_crafter_stats(&text).ok_or_else(|| "Invalid crafter config".to_owned())
+            });
+        match crafter_stats {
+            Ok(crafter_stats) => self.crafter_config.crafter_stats = crafter_stats,
+            Err(error) => log::error!("Failed to import crafter config: {error}"),
+        }
+    }
+}
+
 impl Widget for StatsEdit<'_> {
-    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+    fn ui(mut self, ui: &mut egui::Ui) -> egui::Response {
         ui.vertical(|ui| {
             for job_id in 0..8 {
                 if job_id != 0 {
@@ -31,9 +49,34 @@ impl Widget for StatsEdit<'_> {
                         self.crafter_config.crafter_stats[job_id as usize] =
                             *self.crafter_config.active_stats();
                     }
-                    if ui.button("Copy to all jobs").clicked() {
+                    ui.menu_button("Copy to", |ui| {
                         let stats = self.crafter_config.crafter_stats[job_id as usize];
-                        self.crafter_config.crafter_stats = [stats; 8];
+                        if ui.button("All jobs").clicked() {
+                            self.crafter_config.crafter_stats = [stats; 8];
+                            ui.close();
+                        }
+                        ui.separator();
+                        for other_job_id in (0..8).filter(|other_job_id| *other_job_id != job_id) {
+                            if ui.button(get_job_name(other_job_id, self.locale)).clicked() {
+                                self.crafter_config.crafter_stats[other_job_id as usize] = stats;
+                                ui.close();
+                            }
+                        }
+                    });
+                    let mut input_string = String::new();
+                    let input_response = ui.add(
+                        egui::TextEdit::singleline(&mut input_string)
+                            .id_salt(("stats_paste", job_id))
+                            .desired_width(f32::INFINITY)
+                            .hint_text("📋 4882/4411/601"),
+                    );
+                    if input_response.changed()
+                        && let Some((craftsmanship, control, cp)) = parse_stats(&input_string)
+                    {
+                        let stats = &mut self.crafter_config.crafter_stats[job_id as usize];
+                        stats.craftsmanship = craftsmanship;
+                        stats.control = control;
+                        stats.cp = cp;
                     }
                 });
                 let stats = &mut self.crafter_config.crafter_stats[job_id as usize];
@@ -71,7 +114,7 @@ impl Widget for StatsEdit<'_> {
                     ui.add_enabled(button_enabled, egui::Button::new("🗐 Copy crafter config"));
                 if button_response.clicked() {
                     ui.ctx()
-                        .copy_text(ron::to_string(&self.crafter_config.crafter_stats).unwrap());
+                        .copy_text(to_compact_string(&self.crafter_config.crafter_stats));
                     ui.ctx().animate_bool_with_time(copy_id, true, 0.0);
                 }
 
@@ -83,14 +126,49 @@ impl Widget for StatsEdit<'_> {
                     egui::TextEdit::singleline(input_string)
                         .hint_text("📋 Paste config here to load"),
                 );
-                if input_response.changed() {
-                    if let Ok(crafter_stats) = ron::from_str(input_string) {
-                        self.crafter_config.crafter_stats = crafter_stats;
-                        ui.ctx().animate_bool_with_time(paste_id, true, 0.0);
-                    }
+                if input_response.changed()
+                    && let Some(crafter_stats) = parse_crafter_stats(input_string)
+                {
+                    self.crafter_config.crafter_stats = crafter_stats;
+                    ui.ctx().animate_bool_with_time(paste_id, true, 0.0);
                 }
             });
 
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(path) = stats_file_path() {
+                ui.horizontal(|ui| {
+                    if ui
+                        .button("Export to file")
+                        .on_hover_text(path.display().to_string())
+                        .clicked()
+                    {
+                        let json = serde_json::to_string_pretty(&*self.crafter_config).unwrap();
+                        if let Err(error) = std::fs::write(&path, json) {
+                            log::error!("Failed to export crafter config: {error}");
+                        }
+                    }
+                    if ui
+                        .add_enabled(path.exists(), egui::Button::new("Import exported file"))
+                        .clicked()
+                    {
+                        self.import_file_contents(std::fs::read(&path));
+                    }
+                });
+            }
+            ui.label(
+                egui::RichText::new("Drop an exported file onto this window to import it.").small(),
+            );
+            let dropped_file = ui
+                .ctx()
+                .input(|input| input.raw.dropped_files.first().cloned());
+            if let Some(file) = dropped_file {
+                match (file.bytes, file.path) {
+                    (Some(bytes), _) => self.import_file_contents(Ok(bytes.to_vec())),
+                    (None, Some(path)) => self.import_file_contents(std::fs::read(path)),
+                    (None, None) => log::error!("Dropped file is not accessible"),
+                }
+            }
+
             // sync working stats continuously while stats window is open
             if !self.crafter_config.is_detached() {
                 self.crafter_config.reset_to_job();
@@ -99,3 +177,80 @@ impl Widget for StatsEdit<'_> {
         .response
     }
 }
+
+/// Parses Craftsmanship, Control and CP written as "4882/4411/601"
+fn parse_stats(text: &str) -> Option<(u16, u16, u16)> {
+    let mut values = text
+        .split('/')
+        .map(|value| value.trim().parse::<u16>().ok());
+    let craftsmanship = values.next()??;
+    let control = values.next()??;
+    let cp = values.next()??;
+    let in_range = (1..=9999).contains(&craftsmanship)
+        && (1..=9999).contains(&control)
+        && (1..=999).contains(&cp);
+    (values.next().is_none() && in_range).then_some((craftsmanship, control, cp))
+}
+
+/// Stats of all jobs in a single line that is short enough to share in chat,
+/// e.g. `4900/4800/620/100/MH` for each job, separated by `;`, with the initials of the unlocked specialist actions last
+fn to_compact_string(crafter_stats: &[CrafterStats; 8]) -> String {
+    let jobs: Vec<String> = crafter_stats
+        .iter()
+        .map(|stats| {
+            let specialist_actions: String = SPECIALIST_ACTION_CHARS
+                .iter()
+                .zip([
+                    stats.manipulation,
+                    stats.heart_and_soul,
+                    stats.quick_innovation,
+                ])
+                .filter_map(|(c, unlocked)| unlocked.then_some(*c))
+                .collect();
+            format!(
+                "{}/{}/{}/{}/{specialist_actions}",
+                stats.craftsmanship, stats.control, stats.cp, stats.level
+            )
+        })
+        .collect();
+    jobs.join(";")
+}
+
+fn from_compact_string(text: &str) -> Option<[CrafterStats; 8]> {
+    let mut crafter_stats = [CrafterStats::default(); 8];
+    let mut jobs = text.split(';');
+    for stats in &mut crafter_stats {
+        let (base_stats, rest) = jobs.next()?.trim().rsplit_once('/')?;
+        let (base_stats, level) = base_stats.rsplit_once('/')?;
+        (stats.craftsmanship, stats.control, stats.cp) = parse_stats(base_stats)?;
+        stats.level = level
+            .parse()
+            .ok()
+            .filter(|level| (1..=100).contains(level))?;
+        if !rest.chars().all(|c| SPECIALIST_ACTION_CHARS.contains(&c)) {
+            return None;
+        }
+        stats.manipulation = rest.contains(SPECIALIST_ACTION_CHARS[0]);
+        stats.heart_and_soul = rest.contains(SPECIALIST_ACTION_CHARS[1]);
+        stats.quick_innovation = rest.contains(SPECIALIST_ACTION_CHARS[2]);
+    }
+    jobs.next().is_none().then_some(crafter_stats)
+}
+
+/// Accepts the compact format, exported files and the format that older versions copied to the clipboard
+fn parse_crafter_stats(text: &str) -> Option<[CrafterStats; 8]> {
+    let text = text.trim();
+    from_compact_string(text)
+        .or_else(|| {
+            serde_json::from_str::<CrafterConfig>(text)
+                .ok()
+                .map(|crafter_config| crafter_config.crafter_stats)
+        })
+        .or_else(|| ron::from_str(text).ok())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+/// Location of exported crafter configs, next to exported backups
+fn stats_file_path() -> Option<std::path::PathBuf> {
+    std::env::home_dir().map(|path| path.join("raphael-xiv-stats.json"))
+}
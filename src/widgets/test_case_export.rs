@@ -0,0 +1,105 @@
+use raphael_sim::Action;
+
+use crate::app::SolveParams;
+
+use super::RecipeInfo;
+
+#[derive(Debug, Default)]
+pub struct TestCaseExportState {
+    /// Result of the last export, shown under the button
+    status: Option<Result<String, String>>,
+}
+
+/// Dev panel section that writes the last solve as a test for `raphael-solver/tests/`
+pub struct TestCaseExport<'a> {
+    state: &'a mut TestCaseExportState,
+    last_solve_params: Option<SolveParams>,
+    recipe_info: RecipeInfo,
+    actions: &'a [Action],
+}
+
+impl<'a> TestCaseExport<'a> {
+    pub fn new(
+        state: &'a mut TestCaseExportState,
+        last_solve_params: Option<SolveParams>,
+        recipe_info: RecipeInfo,
+        actions: &'a [Action],
+    ) -> Self {
+        Self {
+            state,
+            last_solve_params,
+            recipe_info,
+            actions,
+        }
+    }
+
+    /// Copies the test case to the clipboard, and also writes it to the storage folder on native
+    fn export(&self, ctx: &egui::Context) -> Result<String, String> {
+        let Some((mut game_settings, initial_quality, solver_config)) = self.last_solve_params
+        else {
+            return Err("No solve to export".to_owned());
+        };
+        // same reduction of the max Quality as when solving
+        let target_quality = solver_config
+            .quality_target
+            .get_target(game_settings.max_quality);
+        game_settings.max_quality = target_quality.saturating_sub(initial_quality);
+        let test_name = match self.recipe_info {
+            RecipeInfo::NormalRecipe(recipe_id) => format!("recipe_{recipe_id}"),
+            RecipeInfo::CustomRecipe(..) => "custom_recipe".to_owned(),
+        };
+        let test_case = crate::test_case_export::export_test_case(
+            &test_name,
+            &solver_config.macro_solver_settings(game_settings),
+            solver_config.initial_state(&game_settings),
+            self.actions,
+        )
+        .map_err(|error| format!("The rotation doesn't simulate: {error}"))?;
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let path = eframe::storage_dir("Raphael XIV")
+                .ok_or("No storage folder")?
+                .join(format!("{test_name}.rs"));
+            std::fs::write(&path, &test_case).map_err(|error| error.to_string())?;
+            ctx.copy_text(test_case);
+            Ok(format!(
+                "Saved to {} and copied to clipboard",
+                path.display()
+            ))
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            ctx.copy_text(test_case);
+            Ok("Copied to clipboard".to_owned())
+        }
+    }
+}
+
+impl egui::Widget for TestCaseExport<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        ui.vertical(|ui| {
+            ui.label(egui::RichText::new("Solver test case").strong());
+            if ui
+                .add_enabled(
+                    self.last_solve_params.is_some() && !self.actions.is_empty(),
+                    egui::Button::new("Export last solve"),
+                )
+                .on_hover_text("Write the settings and rotation of the last solve as a test for raphael-solver/tests/")
+                .on_disabled_hover_text("Solve a rotation first")
+                .clicked()
+            {
+                self.state.status = Some(self.export(ui.ctx()));
+            }
+            match &self.state.status {
+                Some(Ok(message)) => {
+                    ui.label(message);
+                }
+                Some(Err(error)) => {
+                    ui.label(egui::RichText::new(error).color(ui.visuals().error_fg_color));
+                }
+                None => (),
+            }
+        })
+        .response
+    }
+}
@@ -0,0 +1,151 @@
+use crate::config::UpdateCheck;
+use crate::translations::t;
+
+/// Newest release on GitHub, whose version is reset to 0.0.0 once the update notification has been closed
+#[derive(Debug)]
+pub struct LatestRelease {
+    pub version: semver::Version,
+    /// Release notes in Markdown
+    pub notes: String,
+}
+
+impl Default for LatestRelease {
+    fn default() -> Self {
+        Self {
+            version: semver::Version::new(0, 0, 0),
+            notes: String::new(),
+        }
+    }
+}
+
+/// Request from the update notification that needs the rest of the app to be carried out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateRequest {
+    /// Don't notify about this version again
+    Skip,
+    Close,
+}
+
+impl LatestRelease {
+    /// Draws the update notification with the release notes
+    pub fn ui(&self, ui: &mut egui::Ui) -> Option<UpdateRequest> {
+        let mut request = None;
+        ui.style_mut().spacing.item_spacing = egui::vec2(3.0, 3.0);
+        ui.set_max_width(400.0);
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new(t("New version available!")).strong());
+            ui.label(format!("(v{})", self.version));
+        });
+        #[cfg(not(target_arch = "wasm32"))]
+        ui.add(egui::Hyperlink::from_label_and_url(
+            "Download from GitHub",
+            "https://github.com/Asvel/ffxiv-raphael-cn/releases/latest",
+        ));
+        // the service worker serves the app from the network first, so reloading the page is enough to update
+        #[cfg(target_arch = "wasm32")]
+        if ui.link("Reload the page to update").clicked()
+            && let Some(window) = web_sys::window()
+        {
+            window.location().reload().ok();
+        }
+        if !self.notes.trim().is_empty() {
+            ui.separator();
+            egui::ScrollArea::vertical()
+                .max_height(240.0)
+                .show(ui, |ui| {
+                    draw_release_notes(ui, &self.notes);
+                });
+        }
+        ui.separator();
+        ui.vertical_centered_justified(|ui| {
+            if ui.button("Skip this version").clicked() {
+                request = Some(UpdateRequest::Skip);
+            }
+            if ui.button(t("Close")).clicked() {
+                request = Some(UpdateRequest::Close);
+            }
+        });
+        request
+    }
+}
+
+/// Rows of the settings menu that choose which releases to be notified about.
+/// The response is marked as changed when the releases to look for have changed and need to be fetched again.
+pub struct UpdateCheckSettings<'a> {
+    update_check: &'a mut UpdateCheck,
+    skipped_version: &'a mut Option<String>,
+}
+
+impl<'a> UpdateCheckSettings<'a> {
+    pub fn new(update_check: &'a mut UpdateCheck, skipped_version: &'a mut Option<String>) -> Self {
+        Self {
+            update_check,
+            skipped_version,
+        }
+    }
+}
+
+impl egui::Widget for UpdateCheckSettings<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let previous_update_check = *self.update_check;
+        let mut response = ui
+            .vertical(|ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Check for updates");
+                    egui::ComboBox::from_id_salt("UPDATE_CHECK")
+                        .selected_text(self.update_check.name())
+                        .show_ui(ui, |ui| {
+                            for update_check in UpdateCheck::ALL {
+                                ui.selectable_value(
+                                    self.update_check,
+                                    update_check,
+                                    update_check.name(),
+                                );
+                            }
+                        });
+                });
+                if let Some(skipped_version) = self.skipped_version.as_ref() {
+                    let label = format!("Skipping v{skipped_version}");
+                    ui.horizontal(|ui| {
+                        ui.label(label);
+                        if ui.small_button("Undo").clicked() {
+                            *self.skipped_version = None;
+                        }
+                    });
+                }
+            })
+            .response;
+        if *self.update_check != previous_update_check {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+/// Renders the subset of Markdown used in release notes: headings, list items and paragraphs.
+/// Inline formatting is stripped, as labels can't mix styles.
+fn draw_release_notes(ui: &mut egui::Ui, notes: &str) {
+    for line in notes.lines().map(str::trim_end) {
+        let text = line.replace("**", "").replace('`', "");
+        let trimmed = text.trim_start();
+        if trimmed.is_empty() {
+            ui.add_space(4.0);
+        } else if trimmed.starts_with('#') {
+            let heading = trimmed.trim_start_matches('#').trim_start();
+            ui.label(egui::RichText::new(heading).strong());
+        } else if let Some(item) = trimmed
+            .strip_prefix("- ")
+            .or_else(|| trimmed.strip_prefix("* "))
+        {
+            // nested items are indented by two spaces per level
+            let indent = (text.len() - trimmed.len()) / 2;
+            ui.horizontal_top(|ui| {
+                ui.add_space(8.0 * indent as f32);
+                ui.label("•");
+                ui.add(egui::Label::new(item).wrap());
+            });
+        } else {
+            ui.add(egui::Label::new(trimmed).wrap());
+        }
+    }
+}
@@ -1,5 +1,37 @@
 use raphael_sim::*;
 
+/// Like [`egui::Response::context_menu`], but the menu can also be opened with Shift+F10 while the widget has keyboard focus.
+/// With `open_on_activate`, Enter and Space open the menu as well, for widgets that don't do anything when clicked.
+pub fn context_menu<R>(
+    response: &egui::Response,
+    open_on_activate: bool,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> Option<egui::InnerResponse<R>> {
+    let ctx = &response.ctx;
+    let popup_id = egui::Popup::default_response_id(response);
+    let opened_by_keyboard_id = popup_id.with("opened_by_keyboard");
+    let open_key_pressed = ctx.input(|input| {
+        (input.modifiers.shift && input.key_pressed(egui::Key::F10))
+            || (open_on_activate
+                && (input.key_pressed(egui::Key::Enter) || input.key_pressed(egui::Key::Space)))
+    });
+    if response.has_focus() && open_key_pressed {
+        egui::Popup::open_id(ctx, popup_id);
+        ctx.data_mut(|data| data.insert_temp(opened_by_keyboard_id, true));
+    } else if response.secondary_clicked() {
+        ctx.data_mut(|data| data.remove::<bool>(opened_by_keyboard_id));
+    }
+    let opened_by_keyboard = ctx.data(|data| data.get_temp(opened_by_keyboard_id)) == Some(true);
+    if opened_by_keyboard && egui::Popup::is_id_open(ctx, popup_id) {
+        // there is no pointer position to open at, so the menu is placed below the widget instead
+        egui::Popup::menu(response)
+            .open_memory(None)
+            .show(add_contents)
+    } else {
+        egui::Popup::context_menu(response).show(add_contents)
+    }
+}
+
 pub fn collapse_persisted(ui: &mut egui::Ui, id: egui::Id, collapsed: &mut bool) {
     *collapsed = ui.data_mut(|data| *data.get_persisted_mut_or(id, *collapsed));
     let button_text = match collapsed {
@@ -22,70 +54,54 @@ pub fn collapse_temporary(ui: &mut egui::Ui, id: egui::Id, collapsed: &mut bool)
     }
 }
 
-#[cfg(target_arch = "wasm32")]
 pub fn get_action_icon(action: Action, job_id: u8) -> egui::Image<'static> {
-    let image_path = format!(
-        "{}/action-icons/{}/{}.webp",
-        env!("BASE_URL"),
-        raphael_data::get_job_name(job_id, raphael_data::Locale::EN),
-        raphael_data::action_name(action, raphael_data::Locale::EN)
-    );
-    egui::Image::new(image_path)
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-macro_rules! action_icon {
-    ( $name:literal, $job_id:expr ) => {
-        match $job_id {
-            0 => egui::include_image!(concat!("../../assets/action-icons/CRP/", $name, ".webp")),
-            1 => egui::include_image!(concat!("../../assets/action-icons/BSM/", $name, ".webp")),
-            2 => egui::include_image!(concat!("../../assets/action-icons/ARM/", $name, ".webp")),
-            3 => egui::include_image!(concat!("../../assets/action-icons/GSM/", $name, ".webp")),
-            4 => egui::include_image!(concat!("../../assets/action-icons/LTW/", $name, ".webp")),
-            5 => egui::include_image!(concat!("../../assets/action-icons/WVR/", $name, ".webp")),
-            6 => egui::include_image!(concat!("../../assets/action-icons/ALC/", $name, ".webp")),
-            7 => egui::include_image!(concat!("../../assets/action-icons/CUL/", $name, ".webp")),
-            _ => {
-                log::warn!("Unknown job id {}. Falling back to job id 0.", $job_id);
-                egui::include_image!(concat!("../../assets/action-icons/CRP/", $name, ".webp"))
-            }
-        }
+    // Unknown jobs fall back to the icons of Carpenter
+    let job_id = if job_id < raphael_data::ACTION_ICON_ATLAS_COUNT {
+        job_id
+    } else {
+        0
     };
+    // Every job has its own atlas, which the image loaders only decode once it's first shown
+    #[cfg(target_arch = "wasm32")]
+    let image = egui::Image::new(format!(
+        "{}/action-icons/atlas-{job_id}.webp",
+        env!("BASE_URL")
+    ));
+    #[cfg(not(target_arch = "wasm32"))]
+    let image = egui::Image::new(match job_id {
+        1 => egui::include_image!("../../assets/action-icons/atlas-1.webp"),
+        2 => egui::include_image!("../../assets/action-icons/atlas-2.webp"),
+        3 => egui::include_image!("../../assets/action-icons/atlas-3.webp"),
+        4 => egui::include_image!("../../assets/action-icons/atlas-4.webp"),
+        5 => egui::include_image!("../../assets/action-icons/atlas-5.webp"),
+        6 => egui::include_image!("../../assets/action-icons/atlas-6.webp"),
+        7 => egui::include_image!("../../assets/action-icons/atlas-7.webp"),
+        _ => egui::include_image!("../../assets/action-icons/atlas-0.webp"),
+    });
+
+    let atlas = &raphael_data::ACTION_ICON_ATLAS;
+    let icon = atlas
+        .find(action)
+        .expect("every action has an icon in the atlas");
+    let (width, height) = (atlas.width as f32, atlas.height as f32);
+    let uv = egui::Rect::from_min_size(
+        egui::pos2(icon.x as f32 / width, 0.0),
+        egui::vec2(
+            atlas.icon_size as f32 / width,
+            atlas.icon_size as f32 / height,
+        ),
+    );
+    image.uv(uv)
 }
 
-#[cfg(not(target_arch = "wasm32"))]
-pub fn get_action_icon(action: Action, job_id: u8) -> egui::Image<'static> {
-    egui::Image::new(match action {
-        Action::BasicSynthesis => action_icon!("Basic Synthesis", job_id),
-        Action::BasicTouch => action_icon!("Basic Touch", job_id),
-        Action::MasterMend => action_icon!("Master's Mend", job_id),
-        Action::Observe => action_icon!("Observe", job_id),
-        Action::TricksOfTheTrade => action_icon!("Tricks of the Trade", job_id),
-        Action::WasteNot => action_icon!("Waste Not", job_id),
-        Action::Veneration => action_icon!("Veneration", job_id),
-        Action::StandardTouch => action_icon!("Standard Touch", job_id),
-        Action::GreatStrides => action_icon!("Great Strides", job_id),
-        Action::Innovation => action_icon!("Innovation", job_id),
-        Action::WasteNot2 => action_icon!("Waste Not II", job_id),
-        Action::ByregotsBlessing => action_icon!("Byregot's Blessing", job_id),
-        Action::PreciseTouch => action_icon!("Precise Touch", job_id),
-        Action::MuscleMemory => action_icon!("Muscle Memory", job_id),
-        Action::CarefulSynthesis => action_icon!("Careful Synthesis", job_id),
-        Action::Manipulation => action_icon!("Manipulation", job_id),
-        Action::PrudentTouch => action_icon!("Prudent Touch", job_id),
-        Action::AdvancedTouch => action_icon!("Advanced Touch", job_id),
-        Action::Reflect => action_icon!("Reflect", job_id),
-        Action::PreparatoryTouch => action_icon!("Preparatory Touch", job_id),
-        Action::Groundwork => action_icon!("Groundwork", job_id),
-        Action::DelicateSynthesis => action_icon!("Delicate Synthesis", job_id),
-        Action::IntensiveSynthesis => action_icon!("Intensive Synthesis", job_id),
-        Action::TrainedEye => action_icon!("Trained Eye", job_id),
-        Action::HeartAndSoul => action_icon!("Heart and Soul", job_id),
-        Action::PrudentSynthesis => action_icon!("Prudent Synthesis", job_id),
-        Action::TrainedFinesse => action_icon!("Trained Finesse", job_id),
-        Action::RefinedTouch => action_icon!("Refined Touch", job_id),
-        Action::QuickInnovation => action_icon!("Quick Innovation", job_id),
-        Action::ImmaculateMend => action_icon!("Immaculate Mend", job_id),
-        Action::TrainedPerfection => action_icon!("Trained Perfection", job_id),
-    })
+/// Formats a number with thousands separators
+pub fn format_thousands(num: usize) -> String {
+    num.to_string()
+        .as_bytes()
+        .rchunks(3)
+        .rev()
+        .map(std::str::from_utf8)
+        .collect::<Result<Vec<&str>, _>>()
+        .unwrap()
+        .join(",")
 }
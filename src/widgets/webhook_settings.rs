@@ -0,0 +1,82 @@
+use serde::Serialize;
+
+use crate::config::WebhookConfig;
+
+use super::HelpText;
+
+/// Longer messages are rejected by Discord, so they are cut off
+pub const DISCORD_MESSAGE_MAX_CHARS: usize = 2000;
+
+/// Rows of the settings menu for the Discord webhook that is notified when a long solve finishes
+pub struct WebhookSettings<'a> {
+    webhook_config: &'a mut WebhookConfig,
+}
+
+impl<'a> WebhookSettings<'a> {
+    pub fn new(webhook_config: &'a mut WebhookConfig) -> Self {
+        Self { webhook_config }
+    }
+}
+
+impl egui::Widget for WebhookSettings<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        ui.vertical(|ui| {
+            ui.horizontal(|ui| {
+                ui.label("Discord webhook");
+
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.webhook_config.discord_webhook_url)
+                        .hint_text("https://discord.com/api/webhooks/...")
+                        .desired_width(200.0),
+                );
+                ui.add(HelpText::new("Post a summary of the result to a Discord channel when a solve finishes, e.g. to get notified on your phone.\nThe webhook URL can be created in the integration settings of a Discord channel."));
+            });
+            ui.add_enabled_ui(!self.webhook_config.discord_webhook_url.is_empty(), |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Only for solves longer than");
+                    ui.add(
+                        egui::DragValue::new(&mut self.webhook_config.min_solve_duration_secs)
+                            .range(1..=3600)
+                            .suffix(" s"),
+                    );
+                    if ui.button("Send test").clicked() {
+                        send_discord_notification(
+                            &self.webhook_config.discord_webhook_url,
+                            "Test notification from Raphael XIV".to_owned(),
+                        );
+                    }
+                });
+            });
+        })
+        .response
+    }
+}
+
+pub fn send_discord_notification(webhook_url: &str, content: String) {
+    #[derive(Serialize)]
+    struct WebhookMessage {
+        content: String,
+    }
+    let content = content.chars().take(DISCORD_MESSAGE_MAX_CHARS).collect();
+    let request = match ehttp::Request::json(webhook_url, &WebhookMessage { content }) {
+        Ok(request) => request,
+        Err(err) => {
+            log::error!("{err}");
+            return;
+        }
+    };
+    ehttp::fetch(
+        request,
+        |result: ehttp::Result<ehttp::Response>| match result {
+            Ok(response) if !response.ok => {
+                log::error!(
+                    "Discord webhook: {} {}",
+                    response.status,
+                    response.status_text
+                );
+            }
+            Ok(_) => {}
+            Err(err) => log::error!("{err}"),
+        },
+    );
+}
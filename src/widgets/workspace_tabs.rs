@@ -0,0 +1,133 @@
+use raphael_data::{Consumable, Locale, Recipe};
+use raphael_sim::Action;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::{SolveParams, SolverConfig},
+    config::{CustomRecipeOverridesConfiguration, RecipeConfiguration},
+};
+
+/// Recipe, consumables, solver settings and result of a tab, stashed while another tab is active
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workspace {
+    pub recipe_config: RecipeConfiguration,
+    pub custom_recipe_overrides_config: CustomRecipeOverridesConfiguration,
+    pub selected_food: Option<Consumable>,
+    pub selected_potion: Option<Consumable>,
+    pub solver_config: SolverConfig,
+    pub actions: Vec<Action>,
+    pub duration: web_time::Duration,
+    pub last_solve_params: Option<SolveParams>,
+}
+
+/// Request from the workspace tabs that needs the rest of the app to be carried out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkspaceRequest {
+    Switch(usize),
+    /// Open a new tab with a copy of the active one
+    New,
+    Close(usize),
+}
+
+/// Tabs, of which the active one is only kept up to date while switching tabs and saving
+#[derive(Debug)]
+pub struct WorkspaceTabs {
+    workspaces: Vec<Workspace>,
+    active: usize,
+}
+
+impl WorkspaceTabs {
+    /// Tabs as they were stored, [`Self::replace_active`] has to be called before they are used
+    pub fn new(workspaces: Vec<Workspace>, active: usize) -> Self {
+        Self { workspaces, active }
+    }
+
+    /// Replaces the active tab with `current`, or the stored tabs with only `current` if there was no active tab
+    pub fn replace_active(&mut self, current: Workspace) {
+        match self.workspaces.get_mut(self.active) {
+            Some(active_workspace) => *active_workspace = current,
+            None => {
+                self.workspaces = vec![current];
+                self.active = 0;
+            }
+        }
+    }
+
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    /// All tabs and the index of the active one, for saving them
+    pub fn to_stored(&self, current: Workspace) -> (Vec<Workspace>, usize) {
+        let mut workspaces = self.workspaces.clone();
+        workspaces[self.active] = current;
+        (workspaces, self.active)
+    }
+
+    /// Stashes `current` as the active tab and returns the tab at `index`, which becomes the active one
+    pub fn switch(&mut self, current: Workspace, index: usize) -> Workspace {
+        self.workspaces[self.active] = current;
+        self.active = index;
+        self.workspaces[index].clone()
+    }
+
+    /// Adds a tab at the end, returns its index
+    pub fn push(&mut self, workspace: Workspace) -> usize {
+        self.workspaces.push(workspace);
+        self.workspaces.len() - 1
+    }
+
+    /// Tab that becomes active when the active tab at `index` is closed
+    pub fn neighbor(&self, index: usize) -> usize {
+        if index + 1 < self.workspaces.len() {
+            index + 1
+        } else {
+            index - 1
+        }
+    }
+
+    /// Removes the tab at `index`, which must not be the active one
+    pub fn remove(&mut self, index: usize) {
+        self.workspaces.remove(index);
+        if self.active > index {
+            self.active -= 1;
+        }
+    }
+
+    /// Draws a tab for each workspace, the active tab is named after `active_recipe` as it isn't kept up to date
+    pub fn ui(
+        &self,
+        ui: &mut egui::Ui,
+        active_recipe: &Recipe,
+        locale: Locale,
+    ) -> Option<WorkspaceRequest> {
+        let mut request = None;
+        ui.horizontal_wrapped(|ui| {
+            for (index, workspace) in self.workspaces.iter().enumerate() {
+                let active = index == self.active;
+                let recipe = match active {
+                    true => active_recipe,
+                    false => &workspace.recipe_config.recipe,
+                };
+                let name = raphael_data::get_recipe_name(recipe, false, locale)
+                    .unwrap_or("Unknown item".to_owned());
+                if ui.selectable_label(active, name).clicked() && !active {
+                    request = Some(WorkspaceRequest::Switch(index));
+                }
+                if self.workspaces.len() > 1
+                    && ui.small_button("×").on_hover_text("Close tab").clicked()
+                {
+                    request = Some(WorkspaceRequest::Close(index));
+                }
+            }
+            if ui
+                .small_button("+")
+                .on_hover_text("New tab with a copy of the current one")
+                .clicked()
+            {
+                request = Some(WorkspaceRequest::New);
+            }
+        });
+        request
+    }
+}
@@ -0,0 +1,74 @@
+//! Restores the native window geometry stored in the app config and keeps it up to date
+
+use crate::config::WindowGeometry;
+
+#[derive(Default)]
+pub struct WindowGeometrySync {
+    restored: bool,
+    /// Window rect before the stored geometry was restored, the geometry isn't recorded until the window has changed
+    unrestored_rect: Option<egui::Rect>,
+}
+
+impl WindowGeometrySync {
+    /// Keeps the current window geometry instead of restoring the stored one
+    pub fn skip_restore(&mut self) {
+        self.restored = true;
+    }
+
+    pub fn sync(&mut self, ctx: &egui::Context, window_geometry: &mut Option<WindowGeometry>) {
+        let viewport = ctx.input(|input| input.viewport().clone());
+        let monitor_size = viewport.monitor_size.map(<[f32; 2]>::from);
+        if !self.restored {
+            // the monitor size is only known once the window has been created
+            self.restored = true;
+            if let Some(geometry) = *window_geometry {
+                self.unrestored_rect = viewport.inner_rect;
+                let mut inner_size = egui::Vec2::from(geometry.inner_size);
+                if let Some(monitor_size) = viewport.monitor_size {
+                    inner_size = inner_size.min(monitor_size);
+                }
+                ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(inner_size));
+                // the monitor may have been disconnected or rearranged, in which case the window is left where the OS puts it
+                if let Some(outer_position) = geometry.outer_position
+                    && monitor_size.is_some()
+                    && geometry.monitor_size == monitor_size
+                {
+                    ctx.send_viewport_cmd(egui::ViewportCommand::OuterPosition(
+                        outer_position.into(),
+                    ));
+                }
+                if geometry.maximized {
+                    ctx.send_viewport_cmd(egui::ViewportCommand::Maximized(true));
+                }
+            }
+            return;
+        }
+        if self.unrestored_rect.is_some() {
+            if viewport.inner_rect == self.unrestored_rect {
+                return;
+            }
+            self.unrestored_rect = None;
+        }
+        if viewport.minimized == Some(true) || viewport.fullscreen == Some(true) {
+            return;
+        }
+        let Some(inner_rect) = viewport.inner_rect else {
+            return;
+        };
+        let maximized = viewport.maximized == Some(true);
+        let geometry = match *window_geometry {
+            // keep the size to return to when the window is unmaximized
+            Some(geometry) if maximized => WindowGeometry {
+                maximized,
+                ..geometry
+            },
+            _ => WindowGeometry {
+                inner_size: inner_rect.size().into(),
+                outer_position: viewport.outer_rect.map(|rect| rect.min.into()),
+                monitor_size,
+                maximized,
+            },
+        };
+        *window_geometry = Some(geometry);
+    }
+}